@@ -58,13 +58,12 @@ fn generate_pb_rs() {
     copy_tests("src/v2");
 
     copy_tests("src/v3");
-    // TODO
-    fs::remove_file("src/v3/test_issue_190_pb.proto").expect("rm");
-    fs::remove_file("src/v3/test_map_carllerche_pb.proto").expect("rm");
-    fs::remove_file("src/v3/test_map_carllerche.rs").expect("rm");
 
     gen_in_dir_pure("src/v2");
     gen_in_dir_pure("src/v3");
+
+    // features not supported by `protoc` used in `protobuf-test`
+    gen_in_dir_pure("src/pure_v3");
 }
 
 
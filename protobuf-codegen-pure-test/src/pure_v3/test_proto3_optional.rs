@@ -0,0 +1,63 @@
+use protobuf::*;
+use protobuf::text_format;
+
+use protobuf_test_common::*;
+
+use super::test_proto3_optional_pb::*;
+
+
+#[test]
+fn test_zero_values_are_written() {
+    let mut m = TestProto3Optional::new();
+    test_serialize_deserialize("", &m);
+
+    m.set_count(0);
+    m.set_name(String::new());
+    m.set_color(Color::RED);
+    test_serialize_deserialize("08 00 12 00 18 00", &m);
+    assert!(m.has_count());
+    assert!(m.has_name());
+    assert!(m.has_color());
+
+    m.clear_count();
+    assert!(!m.has_count());
+    assert_eq!(0, m.get_count());
+    test_serialize_deserialize("12 00 18 00", &m);
+}
+
+#[test]
+fn test_plain_field_and_oneof() {
+    let mut m = TestProto3Optional::new();
+    m.set_plain(0);
+    test_serialize_deserialize("", &m);
+
+    m.set_id(0);
+    test_serialize_deserialize("30 00", &m);
+    m.set_label("l".to_owned());
+    assert!(!m.has_id());
+    test_serialize_deserialize("3a 01 6c", &m);
+}
+
+#[test]
+fn test_reflect() {
+    let descriptor = TestProto3Optional::new().descriptor();
+    let count = descriptor.field_by_name("count");
+    let plain = descriptor.field_by_name("plain");
+
+    let mut m = TestProto3Optional::new();
+    m.set_count(0);
+    assert!(count.has_field(&m));
+    assert!(!plain.has_field(&m));
+
+    let oneofs: Vec<_> = descriptor.oneofs().iter().map(|o| o.is_synthetic()).collect();
+    assert_eq!(vec![false, true, true, true, true], oneofs);
+}
+
+#[test]
+fn test_text_format() {
+    let mut m = TestProto3Optional::new();
+    m.set_count(0);
+    m.mut_inner();
+    assert_eq!("count: 0 inner {}", text_format::print_to_string(&m));
+    assert_eq!(m, text_format::parse_from_str("count: 0 inner {}").unwrap());
+}
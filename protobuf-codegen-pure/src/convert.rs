@@ -1,547 +1,883 @@
-//! Convert protobuf_parser model to rust-protobuf model
-
-use std::iter;
-
-use protobuf_parser;
-use protobuf;
-
-
-enum MessageOrEnum {
-    Message,
-    Enum,
+//! Convert parsed `.proto` file to `FileDescriptorProto` like `protoc` does.
+//!
+//! Conversion is done in three steps:
+//! * declarations are converted as written, type names are relative
+//!   and options are stored in `uninterpreted_option`
+//! * type names are resolved
+//! * options are interpreted
+
+use std::error::Error;
+use std::f32;
+use std::f64;
+use std::fmt;
+
+use protobuf::descriptor::DescriptorProto;
+use protobuf::descriptor::DescriptorProto_ExtensionRange;
+use protobuf::descriptor::DescriptorProto_ReservedRange;
+use protobuf::descriptor::EnumDescriptorProto;
+use protobuf::descriptor::EnumValueDescriptorProto;
+use protobuf::descriptor::FieldDescriptorProto;
+use protobuf::descriptor::FieldDescriptorProto_Label;
+use protobuf::descriptor::FieldDescriptorProto_Type;
+use protobuf::descriptor::FileDescriptorProto;
+use protobuf::descriptor::MethodDescriptorProto;
+use protobuf::descriptor::OneofDescriptorProto;
+use protobuf::descriptor::ServiceDescriptorProto;
+use protobuf::descriptor::UninterpretedOption;
+use protobuf::descriptor::UninterpretedOption_NamePart;
+
+use model;
+use model::FieldType;
+use model::ImportVis;
+use model::Loc;
+use model::ProtobufConstant;
+use model::ProtobufOption;
+use model::Rule;
+use model::Syntax;
+use option_interpreter::OptionInterpreter;
+use symbols::Symbol;
+use symbols::Symbols;
+
+
+/// Error converting parsed file to descriptor
+#[derive(Debug)]
+pub struct ConvertError {
+    message: String,
 }
 
-impl MessageOrEnum {
-    fn descriptor_type(&self) -> protobuf::descriptor::FieldDescriptorProto_Type {
-        match *self {
-            MessageOrEnum::Message => protobuf::descriptor::FieldDescriptorProto_Type::TYPE_MESSAGE,
-            MessageOrEnum::Enum => protobuf::descriptor::FieldDescriptorProto_Type::TYPE_ENUM,
-        }
+impl ConvertError {
+    pub fn new(message: String) -> ConvertError {
+        ConvertError { message }
     }
-}
-
 
-#[derive(Debug, Eq, PartialEq)]
-struct RelativePath {
-    path: String,
+    /// Prepend element description to the message
+    pub fn context(self, element: &str) -> ConvertError {
+        ConvertError::new(format!("{}: {}", element, self.message))
+    }
 }
 
-impl RelativePath {
-    fn empty() -> RelativePath {
-        RelativePath::new(String::new())
+impl fmt::Display for ConvertError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.message)
     }
+}
 
-    fn is_empty(&self) -> bool {
-        self.path.is_empty()
+impl Error for ConvertError {
+    fn description(&self) -> &str {
+        &self.message
     }
+}
 
-    fn new(path: String) -> RelativePath {
-        assert!(!path.starts_with("."));
+pub type ConvertResult<T> = Result<T, ConvertError>;
 
-        RelativePath {
-            path
-        }
-    }
 
-    fn append(&self, simple: &str) -> RelativePath {
-        if self.path.is_empty() {
-            RelativePath::new(simple.to_owned())
+/// Name of synthetic message of map field, `map<..> foo_bar` has type `FooBarEntry`
+fn map_entry_name(field_name: &str) -> String {
+    let mut r = String::new();
+    let mut capitalize_next = true;
+    for c in field_name.chars() {
+        if c == '_' {
+            capitalize_next = true;
+        } else if capitalize_next {
+            r.push(c.to_ascii_uppercase());
+            capitalize_next = false;
         } else {
-            RelativePath::new(format!("{}.{}", self.path, simple))
+            r.push(c);
         }
     }
+    r.push_str("Entry");
+    r
+}
 
-    fn split(&self) -> Option<(String, RelativePath)> {
-        if self.is_empty() {
-            None
+/// Default `json_name` of a field: `foo_bar` is `fooBar`
+fn json_name(field_name: &str) -> String {
+    let mut r = String::new();
+    let mut capitalize_next = false;
+    for c in field_name.chars() {
+        if c == '_' {
+            capitalize_next = true;
+        } else if capitalize_next {
+            r.push(c.to_ascii_uppercase());
+            capitalize_next = false;
         } else {
-            Some(match self.path.find('.') {
-                Some(dot) => {
-                    (
-                        self.path[..dot].to_owned(),
-                        RelativePath::new(self.path[dot+1..].to_owned())
-                    )
-                }
-                None => {
-                    (
-                        self.path.clone(),
-                        RelativePath::empty()
-                    )
-                }
-            })
+            r.push(c);
         }
     }
+    r
 }
 
-
-#[derive(Clone, Eq, PartialEq, Debug)]
-struct AbsolutePath {
-    path: String,
+fn join(scope: &str, name: &str) -> String {
+    if scope.is_empty() {
+        name.to_owned()
+    } else {
+        format!("{}.{}", scope, name)
+    }
 }
 
-impl AbsolutePath {
-    fn root() -> AbsolutePath {
-        AbsolutePath::new(String::new())
+/// C `printf("%.{precision}g", value)` for finite values
+fn format_g(value: f64, precision: usize) -> String {
+    fn trim_zeros(s: &str) -> &str {
+        if s.contains('.') {
+            s.trim_right_matches('0').trim_right_matches('.')
+        } else {
+            s
+        }
     }
 
-    fn new(path: String) -> AbsolutePath {
-        assert!(path.is_empty() || path.starts_with("."));
-        assert!(!path.ends_with("."));
-        AbsolutePath { path }
+    let e = format!("{:.*e}", precision - 1, value);
+    let e_pos = e.find('e').unwrap();
+    let exp: i32 = e[e_pos + 1..].parse().unwrap();
+    if exp < -4 || exp >= precision as i32 {
+        let sign = if exp < 0 { '-' } else { '+' };
+        format!("{}e{}{:02}", trim_zeros(&e[..e_pos]), sign, exp.abs())
+    } else {
+        let f = format!("{:.*}", (precision as i32 - 1 - exp) as usize, value);
+        trim_zeros(&f).to_owned()
     }
+}
 
-    fn from_path_without_dot(path: &str) -> AbsolutePath {
-        if path.is_empty() {
-            AbsolutePath::root()
+/// Shortest of `%.15g` and `%.17g` which parses back to the same value
+fn format_double(value: f64) -> String {
+    if value.is_nan() {
+        "nan".to_owned()
+    } else if value.is_infinite() {
+        if value > 0.0 { "inf" } else { "-inf" }.to_owned()
+    } else {
+        let s = format_g(value, 15);
+        if s.parse::<f64>() == Ok(value) {
+            s
         } else {
-            assert!(!path.starts_with("."));
-            assert!(!path.ends_with("."));
-            AbsolutePath::new(format!(".{}", path))
+            format_g(value, 17)
         }
     }
+}
 
-    fn from_path_maybe_dot(path: &str) -> AbsolutePath {
-        if path.starts_with(".") {
-            AbsolutePath::new(path.to_owned())
+/// Shortest of `%.6g` and `%.9g` which parses back to the same value
+fn format_float(value: f32) -> String {
+    if value.is_nan() || value.is_infinite() {
+        format_double(value as f64)
+    } else {
+        let s = format_g(value as f64, 6);
+        if s.parse::<f32>() == Ok(value) {
+            s
         } else {
-            AbsolutePath::from_path_without_dot(path)
-        }
-    }
-
-    fn push_simple(&mut self, simple: &str) {
-        assert!(!simple.is_empty());
-        assert!(!simple.contains('.'));
-        self.path.push('.');
-        self.path.push_str(simple);
-    }
-
-    fn push_relative(&mut self, relative: &RelativePath) {
-        if !relative.is_empty() {
-            self.path.push('.');
-            self.path.push_str(&relative.path);
+            format_g(value as f64, 9)
         }
     }
+}
 
-    fn remove_prefix(&self, prefix: &AbsolutePath) -> Option<RelativePath> {
-        if self.path.starts_with(&prefix.path) {
-            let rem = &self.path[prefix.path.len()..];
-            if rem.is_empty() {
-                return Some(RelativePath::empty());
-            }
-            if rem.starts_with('.') {
-                return Some(RelativePath::new(rem[1..].to_owned()));
-            }
+/// Escape bytes like C++ `CEscape` does
+fn c_escape(bytes: &[u8]) -> String {
+    let mut r = String::new();
+    for &b in bytes {
+        match b {
+            b'\n' => r.push_str("\\n"),
+            b'\r' => r.push_str("\\r"),
+            b'\t' => r.push_str("\\t"),
+            b'"' => r.push_str("\\\""),
+            b'\'' => r.push_str("\\'"),
+            b'\\' => r.push_str("\\\\"),
+            0x20 ... 0x7e => r.push(b as char),
+            _ => r.push_str(&format!("\\{:03o}", b)),
         }
-        None
     }
+    r
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-
-    #[test]
-    fn absolute_path_push_simple() {
-        let mut foo = AbsolutePath::new(".foo".to_owned());
-        foo.push_simple("bar");
-        assert_eq!(AbsolutePath::new(".foo.bar".to_owned()), foo);
+fn scalar_field_type(typ: &FieldType) -> Option<FieldDescriptorProto_Type> {
+    Some(match *typ {
+        FieldType::Int32 => FieldDescriptorProto_Type::TYPE_INT32,
+        FieldType::Int64 => FieldDescriptorProto_Type::TYPE_INT64,
+        FieldType::Uint32 => FieldDescriptorProto_Type::TYPE_UINT32,
+        FieldType::Uint64 => FieldDescriptorProto_Type::TYPE_UINT64,
+        FieldType::Sint32 => FieldDescriptorProto_Type::TYPE_SINT32,
+        FieldType::Sint64 => FieldDescriptorProto_Type::TYPE_SINT64,
+        FieldType::Bool => FieldDescriptorProto_Type::TYPE_BOOL,
+        FieldType::Fixed64 => FieldDescriptorProto_Type::TYPE_FIXED64,
+        FieldType::Sfixed64 => FieldDescriptorProto_Type::TYPE_SFIXED64,
+        FieldType::Double => FieldDescriptorProto_Type::TYPE_DOUBLE,
+        FieldType::String => FieldDescriptorProto_Type::TYPE_STRING,
+        FieldType::Bytes => FieldDescriptorProto_Type::TYPE_BYTES,
+        FieldType::Fixed32 => FieldDescriptorProto_Type::TYPE_FIXED32,
+        FieldType::Sfixed32 => FieldDescriptorProto_Type::TYPE_SFIXED32,
+        FieldType::Float => FieldDescriptorProto_Type::TYPE_FLOAT,
+        FieldType::MessageOrEnum(..) | FieldType::Map(..) | FieldType::Group(..) => return None,
+    })
+}
 
-        let mut foo = AbsolutePath::root();
-        foo.push_simple("bar");
-        assert_eq!(AbsolutePath::new(".bar".to_owned()), foo);
-    }
+/// Options other than `default` and `json_name` are interpreted after name resolution
+fn uninterpreted_options(options: &[ProtobufOption]) -> Vec<UninterpretedOption> {
+    options.iter().map(|option| {
+        let mut output = UninterpretedOption::new();
+        for part in &option.name {
+            let mut name_part = UninterpretedOption_NamePart::new();
+            name_part.set_name_part(part.name.clone());
+            name_part.set_is_extension(part.is_extension);
+            output.mut_name().push(name_part);
+        }
+        match option.value {
+            ProtobufConstant::U64(v) => output.set_positive_int_value(v),
+            ProtobufConstant::I64(v) => output.set_negative_int_value(v),
+            ProtobufConstant::F64(v) => output.set_double_value(v),
+            ProtobufConstant::Ident(ref v) => output.set_identifier_value(v.clone()),
+            ProtobufConstant::String(ref v) => output.set_string_value(v.clone()),
+            ProtobufConstant::BracedExpr(ref v) => output.set_aggregate_value(v.clone()),
+        }
+        output
+    }).collect()
+}
 
-    #[test]
-    fn absolute_path_remove_prefix() {
-        assert_eq!(
-            Some(RelativePath::empty()),
-            AbsolutePath::new(".foo".to_owned())
-                .remove_prefix(&AbsolutePath::new(".foo".to_owned())));
-        assert_eq!(
-            Some(RelativePath::new("bar".to_owned())),
-            AbsolutePath::new(".foo.bar".to_owned())
-                .remove_prefix(&AbsolutePath::new(".foo".to_owned())));
-        assert_eq!(
-            Some(RelativePath::new("baz.qux".to_owned())),
-            AbsolutePath::new(".foo.bar.baz.qux".to_owned())
-                .remove_prefix(&AbsolutePath::new(".foo.bar".to_owned())));
-        assert_eq!(
-            None,
-            AbsolutePath::new(".foo.barbaz".to_owned())
-                .remove_prefix(&AbsolutePath::new(".foo.bar".to_owned())));
-    }
+/// Set `uninterpreted_option` of `options` message of the element
+/// if there are any options
+macro_rules! set_uninterpreted_options {
+    ($output:expr, $options:expr) => {
+        let options = uninterpreted_options($options);
+        if !options.is_empty() {
+            $output.mut_options().set_uninterpreted_option(options.into());
+        }
+    };
 }
 
 
-enum LookupScope<'a> {
-    File(&'a protobuf_parser::FileDescriptor),
-    Message(&'a protobuf_parser::Message),
+/// Converts declarations as written
+struct Converter {
+    syntax: Syntax,
 }
 
-impl<'a> LookupScope<'a> {
-    fn messages(&self) -> &[protobuf_parser::Message] {
-        match self {
-            &LookupScope::File(file) => &file.messages,
-            &LookupScope::Message(messasge) => &messasge.messages,
+impl Converter {
+    fn integer_default(value: &ProtobufConstant, min: i64, max: u64) -> Result<String, String> {
+        match *value {
+            ProtobufConstant::U64(v) if v <= max => Ok(v.to_string()),
+            ProtobufConstant::I64(..) if min == 0 => {
+                Err("unsigned fields can't have negative default values".to_owned())
+            }
+            ProtobufConstant::I64(v) if v >= min => Ok(v.to_string()),
+            ProtobufConstant::U64(..) | ProtobufConstant::I64(..) => {
+                Err("integer is out of range".to_owned())
+            }
+            _ => Err("expecting integer".to_owned()),
         }
     }
 
-    fn enums(&self) -> &[protobuf_parser::Enumeration] {
-        match self {
-            &LookupScope::File(file) => &file.enums,
-            &LookupScope::Message(messasge) => &messasge.enums,
+    fn float_default(value: &ProtobufConstant) -> Result<f64, String> {
+        match *value {
+            ProtobufConstant::U64(v) => Ok(v as f64),
+            ProtobufConstant::I64(v) => Ok(v as f64),
+            ProtobufConstant::F64(v) => Ok(v),
+            ProtobufConstant::Ident(ref v) if v == "inf" => Ok(f64::INFINITY),
+            ProtobufConstant::Ident(ref v) if v == "nan" => Ok(f64::NAN),
+            _ => Err("expecting number".to_owned()),
         }
     }
 
-    fn members(&self) -> Vec<(&str, MessageOrEnum)> {
-        let mut r = Vec::new();
-        r.extend(self.enums().into_iter().map(|e| (&e.name[..], MessageOrEnum::Enum)));
-        r.extend(self.messages().into_iter().map(|e| (&e.name[..], MessageOrEnum::Message)));
-        r
+    /// Default value formatted like `protoc` stores it in `default_value`
+    fn default_value(&self, field: &FieldDescriptorProto, value: &ProtobufConstant)
+        -> Result<String, String>
+    {
+        if self.syntax == Syntax::Proto3 {
+            return Err("explicit default values are not allowed in proto3".to_owned());
+        }
+        if field.get_label() == FieldDescriptorProto_Label::LABEL_REPEATED {
+            return Err("repeated fields can't have default values".to_owned());
+        }
+        if !field.has_field_type() {
+            // enum or message, unknown until name resolution
+            return match *value {
+                ProtobufConstant::Ident(ref v) => Ok(v.clone()),
+                _ => Err("expecting enum value name".to_owned()),
+            };
+        }
+        let i32_min = i32::min_value() as i64;
+        let i32_max = i32::max_value() as u64;
+        let i64_min = i64::min_value();
+        let i64_max = i64::max_value() as u64;
+        match field.get_field_type() {
+            FieldDescriptorProto_Type::TYPE_INT32 |
+            FieldDescriptorProto_Type::TYPE_SINT32 |
+            FieldDescriptorProto_Type::TYPE_SFIXED32 => {
+                Converter::integer_default(value, i32_min, i32_max)
+            }
+            FieldDescriptorProto_Type::TYPE_INT64 |
+            FieldDescriptorProto_Type::TYPE_SINT64 |
+            FieldDescriptorProto_Type::TYPE_SFIXED64 => {
+                Converter::integer_default(value, i64_min, i64_max)
+            }
+            FieldDescriptorProto_Type::TYPE_UINT32 |
+            FieldDescriptorProto_Type::TYPE_FIXED32 => {
+                Converter::integer_default(value, 0, u32::max_value() as u64)
+            }
+            FieldDescriptorProto_Type::TYPE_UINT64 |
+            FieldDescriptorProto_Type::TYPE_FIXED64 => {
+                Converter::integer_default(value, 0, u64::max_value())
+            }
+            FieldDescriptorProto_Type::TYPE_FLOAT => {
+                Ok(format_float(Converter::float_default(value)? as f32))
+            }
+            FieldDescriptorProto_Type::TYPE_DOUBLE => {
+                Ok(format_double(Converter::float_default(value)?))
+            }
+            FieldDescriptorProto_Type::TYPE_BOOL => match *value {
+                ProtobufConstant::Ident(ref v) if v == "true" || v == "false" => Ok(v.clone()),
+                _ => Err("expecting `true` or `false`".to_owned()),
+            },
+            FieldDescriptorProto_Type::TYPE_STRING => match *value {
+                ProtobufConstant::String(ref v) => {
+                    String::from_utf8(v.clone()).map_err(|_| "string is not valid UTF-8".to_owned())
+                }
+                _ => Err("expecting string".to_owned()),
+            },
+            FieldDescriptorProto_Type::TYPE_BYTES => match *value {
+                ProtobufConstant::String(ref v) => Ok(c_escape(v)),
+                _ => Err("expecting string".to_owned()),
+            },
+            FieldDescriptorProto_Type::TYPE_GROUP |
+            FieldDescriptorProto_Type::TYPE_MESSAGE |
+            FieldDescriptorProto_Type::TYPE_ENUM => {
+                Err("messages can't have default values".to_owned())
+            }
+        }
     }
 
-    fn resolve_message_or_enum(&self, current_path: &AbsolutePath, path: &RelativePath)
-        -> Option<(AbsolutePath, MessageOrEnum)>
+    fn field(&self, input: &model::Field, oneof_index: Option<i32>)
+        -> ConvertResult<FieldDescriptorProto>
     {
-        let (first, rem) = match path.split() {
-            Some(x) => x,
-            None => return None,
-        };
+        let mut output = FieldDescriptorProto::new();
+        output.set_name(match input.typ {
+            // `group Foo` declares field `foo` of nested type `Foo`
+            FieldType::Group(..) => input.name.to_lowercase(),
+            _ => input.name.clone(),
+        });
+        output.set_number(input.number);
 
-        if rem.is_empty() {
-            for member in self.members() {
-                if member.0 == first {
-                    let mut result_path = current_path.clone();
-                    result_path.push_simple(member.0);
-                    return Some((result_path, member.1));
-                }
+        output.set_label(match (input.rule, &input.typ) {
+            (_, &FieldType::Map(..)) | (Some(Rule::Repeated), _) => {
+                FieldDescriptorProto_Label::LABEL_REPEATED
             }
-            None
-        } else {
-            for message in self.messages() {
-                if message.name == first {
-                    let mut message_path = current_path.clone();
-                    message_path.push_simple(&message.name);
-                    let message_scope = LookupScope::Message(message);
-                    return message_scope.resolve_message_or_enum(&message_path, &rem);
-                }
+            (Some(Rule::Required), _) => FieldDescriptorProto_Label::LABEL_REQUIRED,
+            _ => FieldDescriptorProto_Label::LABEL_OPTIONAL,
+        });
+        if self.syntax == Syntax::Proto3 && input.rule == Some(Rule::Optional) {
+            output.set_proto3_optional(true);
+        }
+
+        match input.typ {
+            FieldType::MessageOrEnum(ref name) => output.set_type_name(name.clone()),
+            FieldType::Map(..) => output.set_type_name(map_entry_name(&input.name)),
+            FieldType::Group(ref group) => {
+                output.set_field_type(FieldDescriptorProto_Type::TYPE_GROUP);
+                output.set_type_name(group.name.clone());
             }
-            None
+            ref t => output.set_field_type(scalar_field_type(t).unwrap()),
         }
-    }
 
-}
+        if let Some(oneof_index) = oneof_index {
+            output.set_oneof_index(oneof_index);
+        }
 
+        let error = |message: String| {
+            ConvertError::new(format!("{}: field `{}`: {}", input.loc, input.name, message))
+        };
 
-struct Resolver<'a> {
-    current_file: &'a protobuf_parser::FileDescriptor,
-    deps: &'a [protobuf_parser::FileDescriptor],
-}
+        let mut options = Vec::new();
+        for option in &input.options {
+            if option.is_simple("default") {
+                if output.has_default_value() {
+                    return Err(error("option `default` is already set".to_owned()));
+                }
+                let default = self.default_value(&output, &option.value).map_err(&error)?;
+                output.set_default_value(default);
+            } else if option.is_simple("json_name") {
+                if output.has_json_name() {
+                    return Err(error("option `json_name` is already set".to_owned()));
+                }
+                match option.value {
+                    ProtobufConstant::String(ref v) => match String::from_utf8(v.clone()) {
+                        Ok(v) => output.set_json_name(v),
+                        Err(..) => return Err(error("string is not valid UTF-8".to_owned())),
+                    },
+                    _ => return Err(error("expecting string for `json_name`".to_owned())),
+                }
+            } else {
+                options.push(option.clone());
+            }
+        }
+        set_uninterpreted_options!(output, &options);
 
-impl<'a> Resolver<'a> {
-    fn map_entry_name_for_field_name(field_name: &str) -> String {
-        format!("{}_MapEntry", field_name)
+        Ok(output)
     }
 
-    fn map_entry_field(
-        &self,
-        name: &str,
-        number: i32,
-        field_type: &protobuf_parser::FieldType,
-        path_in_file: &RelativePath)
-        -> protobuf::descriptor::FieldDescriptorProto
-    {
-        let mut output = protobuf::descriptor::FieldDescriptorProto::new();
-        output.set_name(name.to_owned());
-        output.set_number(number);
-
-        let (t, t_name) = self.field_type(name, field_type, path_in_file);
-        output.set_field_type(t);
-        if let Some(t_name) = t_name {
-            output.set_type_name(t_name.path);
+    fn extension(&self, input: &model::Extension) -> ConvertResult<FieldDescriptorProto> {
+        if input.field.options.iter().any(|o| o.is_simple("json_name")) {
+            return Err(ConvertError::new(format!(
+                "{}: field `{}`: option `json_name` is not allowed on extension fields",
+                input.field.loc, input.field.name)));
         }
-        
-        output
+        let mut output = self.field(&input.field, None)?;
+        // extensions always track presence
+        output.clear_proto3_optional();
+        output.set_extendee(input.extendee.clone());
+        Ok(output)
     }
 
-    fn map_entry_message(
-        &self,
-        field_name: &str,
-        key: &protobuf_parser::FieldType,
-        value: &protobuf_parser::FieldType,
-        path_in_file: &RelativePath)
-        -> protobuf::descriptor::DescriptorProto
+    /// Key and value fields of map entry are the same as in `protoc`
+    fn map_entry(&self, field: &model::Field, key: &FieldType, value: &FieldType)
+        -> ConvertResult<DescriptorProto>
     {
-        let mut output = protobuf::descriptor::DescriptorProto::new();
-
+        let mut output = DescriptorProto::new();
+        output.set_name(map_entry_name(&field.name));
+        for &(name, number, typ) in &[("key", 1, key), ("value", 2, value)] {
+            let entry_field = model::Field {
+                name: name.to_owned(),
+                rule: Some(Rule::Optional),
+                typ: typ.clone(),
+                number,
+                options: Vec::new(),
+                loc: field.loc,
+            };
+            let mut entry_field = self.field(&entry_field, None)?;
+            entry_field.clear_proto3_optional();
+            output.mut_field().push(entry_field);
+        }
         output.mut_options().set_map_entry(true);
-        output.set_name(Resolver::map_entry_name_for_field_name(field_name));
-        output.mut_field().push(self.map_entry_field("key", 1, key, path_in_file));
-        output.mut_field().push(self.map_entry_field("value", 2, value, path_in_file));
+        Ok(output)
+    }
 
-        output
+    /// Oneof of proto3 `optional` field named like `protoc` does: `_name`,
+    /// prefixed with `X` until it does not clash with other fields or oneofs
+    fn synthetic_oneof_name(
+        field_name: &str,
+        fields: &[FieldDescriptorProto],
+        oneofs: &[OneofDescriptorProto])
+        -> String
+    {
+        let mut name = if field_name.starts_with('_') {
+            field_name.to_owned()
+        } else {
+            format!("_{}", field_name)
+        };
+        while fields.iter().any(|f| f.get_name() == name) ||
+            oneofs.iter().any(|o| o.get_name() == name)
+        {
+            name = format!("X{}", name);
+        }
+        name
     }
 
-    fn message(&self, input: &protobuf_parser::Message, path_in_file: &RelativePath)
-        -> protobuf::descriptor::DescriptorProto
+    /// Messages of groups and map entries are placed among nested messages
+    /// in declaration order
+    fn nested_messages(&self, messages: &[model::Message], fields: &[&model::Field])
+        -> ConvertResult<Vec<DescriptorProto>>
     {
-        let nested_path_in_file = path_in_file.append(&input.name);
+        let mut nested: Vec<(Loc, DescriptorProto)> = Vec::new();
+        for message in messages {
+            nested.push((message.loc, self.message(message)?));
+        }
+        for field in fields {
+            match field.typ {
+                FieldType::Map(ref t) => {
+                    nested.push((field.loc, self.map_entry(field, &t.0, &t.1)?));
+                }
+                FieldType::Group(ref group) => nested.push((field.loc, self.message(group)?)),
+                _ => {}
+            }
+        }
+        nested.sort_by_key(|n| n.0);
+        Ok(nested.into_iter().map(|n| n.1).collect())
+    }
 
-        let mut output = protobuf::descriptor::DescriptorProto::new();
+    fn message(&self, input: &model::Message) -> ConvertResult<DescriptorProto> {
+        let mut output = DescriptorProto::new();
         output.set_name(input.name.clone());
 
-        let nested_messages_regular = input.messages.iter()
-            .map(|m| self.message(m, &nested_path_in_file));
-
-        let nested_messages_map = input.fields.iter().filter_map(|f| {
-            match f.typ {
-                protobuf_parser::FieldType::Map(ref t) => {
-                    Some(self.map_entry_message(&f.name, &t.0, &t.1, path_in_file))
+        for f in &input.fields {
+            match *f {
+                model::FieldOrOneOf::Field(ref f) => {
+                    let field = self.field(f, None)?;
+                    output.mut_field().push(field);
+                }
+                model::FieldOrOneOf::OneOf(ref o) => {
+                    let oneof_index = output.get_oneof_decl().len() as i32;
+                    for f in &o.fields {
+                        let field = self.field(f, Some(oneof_index))?;
+                        output.mut_field().push(field);
+                    }
+                    let mut oneof = OneofDescriptorProto::new();
+                    oneof.set_name(o.name.clone());
+                    set_uninterpreted_options!(oneof, &o.options);
+                    output.mut_oneof_decl().push(oneof);
                 }
-                _ => None,
             }
-        });
+        }
 
-        output.set_nested_type(nested_messages_regular.chain(nested_messages_map).collect());
+        // proto3 `optional` field is the only member of synthetic oneof
+        // placed after real oneofs
+        for i in 0..output.get_field().len() {
+            if output.get_field()[i].get_proto3_optional() {
+                let mut oneof = OneofDescriptorProto::new();
+                oneof.set_name(Converter::synthetic_oneof_name(
+                    output.get_field()[i].get_name(),
+                    output.get_field(),
+                    output.get_oneof_decl()));
+                let oneof_index = output.get_oneof_decl().len() as i32;
+                output.mut_oneof_decl().push(oneof);
+                output.mut_field()[i].set_oneof_index(oneof_index);
+            }
+        }
 
-        output.set_enum_type(input.enums.iter().map(|e| self.enumeration(e)).collect());
+        let fields: Vec<&model::Field> = input.all_fields().into_iter()
+            .chain(input.extensions.iter().map(|e| &e.field))
+            .collect();
+        output.set_nested_type(self.nested_messages(&input.messages, &fields)?.into());
 
-        {
-            let regular_fields = input.fields.iter()
-                .map(|f| self.field(f, None, &nested_path_in_file));
+        for e in &input.enums {
+            output.mut_enum_type().push(self.enumeration(e));
+        }
 
-            let oneof_fields = input.oneofs.iter().enumerate()
-                .flat_map(|(oneof_index, oneof)| {
-                    let oneof_index = oneof_index as i32;
-                    oneof.fields.iter().zip(iter::repeat(oneof_index))
-                        .map(|(f, oneof_index)| self.field(f, Some(oneof_index), &nested_path_in_file))
-                });
+        for range in &input.extension_ranges {
+            let mut extension_range = DescriptorProto_ExtensionRange::new();
+            extension_range.set_start(range.from);
+            extension_range.set_end(range.to + 1);
+            output.mut_extension_range().push(extension_range);
+        }
 
-            output.set_field(regular_fields.chain(oneof_fields).collect());
+        for extension in &input.extensions {
+            output.mut_extension().push(self.extension(extension)?);
         }
 
-        let oneofs = input.oneofs.iter()
-            .map(|o| self.oneof(o))
-            .collect();
-        output.set_oneof_decl(oneofs);
+        for range in &input.reserved_nums {
+            let mut reserved_range = DescriptorProto_ReservedRange::new();
+            reserved_range.set_start(range.from);
+            reserved_range.set_end(range.to + 1);
+            output.mut_reserved_range().push(reserved_range);
+        }
+        for name in &input.reserved_names {
+            output.mut_reserved_name().push(name.clone());
+        }
+
+        set_uninterpreted_options!(output, &input.options);
+
+        Ok(output)
+    }
 
+    fn enumeration(&self, input: &model::Enumeration) -> EnumDescriptorProto {
+        let mut output = EnumDescriptorProto::new();
+        output.set_name(input.name.clone());
+        for v in &input.values {
+            let mut value = EnumValueDescriptorProto::new();
+            value.set_name(v.name.clone());
+            value.set_number(v.number);
+            set_uninterpreted_options!(value, &v.options);
+            output.mut_value().push(value);
+        }
+        set_uninterpreted_options!(output, &input.options);
         output
     }
 
-    fn field(&self, input: &protobuf_parser::Field, oneof_index: Option<i32>, path_in_file: &RelativePath)
-        -> protobuf::descriptor::FieldDescriptorProto
-    {
-        let mut output = protobuf::descriptor::FieldDescriptorProto::new();
+    fn service(&self, input: &model::Service) -> ServiceDescriptorProto {
+        let mut output = ServiceDescriptorProto::new();
         output.set_name(input.name.clone());
+        for m in &input.methods {
+            let mut method = MethodDescriptorProto::new();
+            method.set_name(m.name.clone());
+            method.set_input_type(m.input_type.clone());
+            method.set_output_type(m.output_type.clone());
+            if m.client_streaming {
+                method.set_client_streaming(true);
+            }
+            if m.server_streaming {
+                method.set_server_streaming(true);
+            }
+            set_uninterpreted_options!(method, &m.options);
+            output.mut_method().push(method);
+        }
+        set_uninterpreted_options!(output, &input.options);
+        output
+    }
 
-        if let protobuf_parser::FieldType::Map(..) = input.typ {
-            output.set_label(protobuf::descriptor::FieldDescriptorProto_Label::LABEL_REPEATED);
-        } else {
-            output.set_label(label(input.rule));
+    fn file(&self, name: &str, input: &model::FileDescriptor)
+        -> ConvertResult<FileDescriptorProto>
+    {
+        let mut output = FileDescriptorProto::new();
+        output.set_name(name.to_owned());
+        if !input.package.is_empty() {
+            output.set_package(input.package.clone());
         }
 
-        let (t, t_name) = self.field_type(&input.name, &input.typ, path_in_file);
-        output.set_field_type(t);
-        if let Some(t_name) = t_name {
-            output.set_type_name(t_name.path);
+        for (i, import) in input.imports.iter().enumerate() {
+            output.mut_dependency().push(import.path.clone());
+            match import.vis {
+                ImportVis::Default => {}
+                ImportVis::Public => output.mut_public_dependency().push(i as i32),
+                ImportVis::Weak => output.mut_weak_dependency().push(i as i32),
+            }
         }
 
-        output.set_number(input.number);
-        if let Some(ref default) = input.default {
-            let default = match output.get_field_type() {
-                protobuf::descriptor::FieldDescriptorProto_Type::TYPE_STRING => {
-                    if default.starts_with('"') && default.ends_with('"') {
-                        default[1..default.len() - 1]
-                            // TODO: properly decode
-                            .replace("\\n", "\n")
-                            .replace("\\t", "\t")
-                    } else {
-                        default.clone()
-                    }
-                }
-                protobuf::descriptor::FieldDescriptorProto_Type::TYPE_BYTES => {
-                    if default.starts_with('"') && default.ends_with('"') {
-                        default[1..default.len() - 1].to_owned()
-                    } else {
-                        default.clone()
-                    }
-                }
-                _ => {
-                    default.clone()
-                }
-            };
-            output.set_default_value(default);
+        // groups declared in top-level `extend` are top-level messages
+        let extension_fields: Vec<_> = input.extensions.iter().map(|e| &e.field).collect();
+        output.set_message_type(self.nested_messages(&input.messages, &extension_fields)?.into());
+
+        for e in &input.enums {
+            output.mut_enum_type().push(self.enumeration(e));
         }
-        if let Some(packed) = input.packed {
-            output.mut_options().set_packed(packed);
+        for s in &input.services {
+            output.mut_service().push(self.service(s));
+        }
+        for extension in &input.extensions {
+            output.mut_extension().push(self.extension(extension)?);
         }
 
-        if let Some(oneof_index) = oneof_index {
-            output.set_oneof_index(oneof_index);
+        set_uninterpreted_options!(output, &input.options);
+
+        if input.syntax == Syntax::Proto3 {
+            output.set_syntax("proto3".to_owned());
         }
 
-        output.mut_options().set_deprecated(input.deprecated);
-        output
+        Ok(output)
     }
+}
 
-    fn all_files(&self) -> Vec<&protobuf_parser::FileDescriptor> {
-        iter::once(self.current_file).chain(self.deps).collect()
+
+/// Replaces relative type names with full names
+struct Resolver<'a> {
+    symbols: &'a Symbols,
+}
+
+impl<'a> Resolver<'a> {
+    fn message_name(&self, name: &str, relative_to: &str) -> ConvertResult<String> {
+        match self.symbols.lookup(name, relative_to, false)? {
+            (full_name, &Symbol::Message) => Ok(format!(".{}", full_name)),
+            _ => Err(ConvertError::new(format!("`{}` is not a message type", name))),
+        }
     }
 
-    fn current_file_package_files(&self) -> Vec<&protobuf_parser::FileDescriptor> {
-        self.all_files().into_iter()
-            .filter(|f| f.package == self.current_file.package)
-            .collect()
+    fn field(&self, scope: &str, field: &mut FieldDescriptorProto) -> ConvertResult<()> {
+        let full_name = join(scope, field.get_name());
+        self.field_impl(&full_name, field)
+            .map_err(|e| e.context(&format!("field `{}`", full_name)))
     }
 
-    fn resolve_message_or_enum(&self, name: &str, _path_in_file: &RelativePath)
-        -> (AbsolutePath, MessageOrEnum)
-    {
-        // find message or enum in current package
-        if !name.starts_with(".") {
-            for file in self.current_file_package_files() {
-                if let Some((n, t)) = LookupScope::File(file).resolve_message_or_enum(
-                    &AbsolutePath::from_path_without_dot(&file.package),
-                    &RelativePath::new(name.to_owned()))
-                {
-                    return (n, t)
-                }
-            }
+    fn field_impl(&self, full_name: &str, field: &mut FieldDescriptorProto) -> ConvertResult<()> {
+        if field.has_extendee() {
+            let extendee = self.message_name(field.get_extendee(), full_name)?;
+            field.set_extendee(extendee);
         }
 
-        // find message or enum in root package
-        {
-            let absolute_path = AbsolutePath::from_path_maybe_dot(name);
-            for file in self.all_files() {
-                let file_package = AbsolutePath::from_path_without_dot(&file.package);
-                if let Some(relative) = absolute_path.remove_prefix(&file_package) {
-                    if let Some((n, t)) = LookupScope::File(file).resolve_message_or_enum(
-                        &file_package,
-                        &relative)
+        if !field.has_type_name() {
+            return Ok(());
+        }
+
+        let type_name = field.get_type_name().to_owned();
+        let resolved = {
+            let (resolved, symbol) = self.symbols.lookup(&type_name, full_name, true)?;
+            match *symbol {
+                Symbol::Message => {
+                    if field.has_default_value() {
+                        return Err(ConvertError::new(
+                            "messages can't have default values".to_owned()));
+                    }
+                    if !field.has_field_type() {
+                        field.set_field_type(FieldDescriptorProto_Type::TYPE_MESSAGE);
+                    }
+                }
+                Symbol::Enum(ref values) => {
+                    if field.get_field_type() == FieldDescriptorProto_Type::TYPE_GROUP {
+                        return Err(ConvertError::new(
+                            format!("`{}` is not a message type", type_name)));
+                    }
+                    if field.has_default_value() &&
+                        !values.iter().any(|v| v == field.get_default_value())
                     {
-                        return (n, t)
+                        return Err(ConvertError::new(format!(
+                            "enum type `{}` has no value named `{}`",
+                            resolved, field.get_default_value())));
                     }
+                    field.set_field_type(FieldDescriptorProto_Type::TYPE_ENUM);
                 }
+                _ => return Err(ConvertError::new(format!("`{}` is not a type", type_name))),
             }
-        }
-
-        // TODO: find names in outer messages
-        panic!("couldn't find message or enum {} when parsing {}", name, self.current_file.package);
+            resolved
+        };
+        field.set_type_name(format!(".{}", resolved));
+        Ok(())
     }
 
-    fn field_type(&self, name: &str, input: &protobuf_parser::FieldType, path_in_file: &RelativePath)
-        -> (protobuf::descriptor::FieldDescriptorProto_Type, Option<AbsolutePath>)
-    {
-        match *input {
-            protobuf_parser::FieldType::Bool =>
-                (protobuf::descriptor::FieldDescriptorProto_Type::TYPE_BOOL, None),
-            protobuf_parser::FieldType::Int32 =>
-                (protobuf::descriptor::FieldDescriptorProto_Type::TYPE_INT32, None),
-            protobuf_parser::FieldType::Int64 =>
-                (protobuf::descriptor::FieldDescriptorProto_Type::TYPE_INT64, None),
-            protobuf_parser::FieldType::Uint32 =>
-                (protobuf::descriptor::FieldDescriptorProto_Type::TYPE_UINT32, None),
-            protobuf_parser::FieldType::Uint64 =>
-                (protobuf::descriptor::FieldDescriptorProto_Type::TYPE_UINT64, None),
-            protobuf_parser::FieldType::Sint32 =>
-                (protobuf::descriptor::FieldDescriptorProto_Type::TYPE_SINT32, None),
-            protobuf_parser::FieldType::Sint64 =>
-                (protobuf::descriptor::FieldDescriptorProto_Type::TYPE_SINT64, None),
-            protobuf_parser::FieldType::Fixed32 =>
-                (protobuf::descriptor::FieldDescriptorProto_Type::TYPE_FIXED32, None),
-            protobuf_parser::FieldType::Fixed64 =>
-                (protobuf::descriptor::FieldDescriptorProto_Type::TYPE_FIXED64, None),
-            protobuf_parser::FieldType::Sfixed32 =>
-                (protobuf::descriptor::FieldDescriptorProto_Type::TYPE_SFIXED32, None),
-            protobuf_parser::FieldType::Sfixed64 =>
-                (protobuf::descriptor::FieldDescriptorProto_Type::TYPE_SFIXED64, None),
-            protobuf_parser::FieldType::Float =>
-                (protobuf::descriptor::FieldDescriptorProto_Type::TYPE_FLOAT, None),
-            protobuf_parser::FieldType::Double =>
-                (protobuf::descriptor::FieldDescriptorProto_Type::TYPE_DOUBLE, None),
-            protobuf_parser::FieldType::String =>
-                (protobuf::descriptor::FieldDescriptorProto_Type::TYPE_STRING, None),
-            protobuf_parser::FieldType::Bytes =>
-                (protobuf::descriptor::FieldDescriptorProto_Type::TYPE_BYTES, None),
-            protobuf_parser::FieldType::MessageOrEnum(ref name) => {
-                let (name, me) = self.resolve_message_or_enum(&name, path_in_file);
-                (me.descriptor_type(), Some(name))
-            }
-            protobuf_parser::FieldType::Map(..) => {
-                let mut type_name = AbsolutePath::from_path_without_dot(&self.current_file.package);
-                type_name.push_relative(path_in_file);
-                type_name.push_simple(&Resolver::map_entry_name_for_field_name(name));
-                (
-                    protobuf::descriptor::FieldDescriptorProto_Type::TYPE_MESSAGE,
-                    Some(type_name)
-                )
-            }
-            protobuf_parser::FieldType::Group(..) => {
-                (protobuf::descriptor::FieldDescriptorProto_Type::TYPE_GROUP, None)
-            }
+    fn message(&self, scope: &str, message: &mut DescriptorProto) -> ConvertResult<()> {
+        let full_name = join(scope, message.get_name());
+        for field in message.mut_field().iter_mut() {
+            self.field(&full_name, field)?;
+        }
+        for nested in message.mut_nested_type().iter_mut() {
+            self.message(&full_name, nested)?;
         }
+        for extension in message.mut_extension().iter_mut() {
+            self.field(&full_name, extension)?;
+        }
+        Ok(())
+    }
+
+    fn service(&self, scope: &str, service: &mut ServiceDescriptorProto) -> ConvertResult<()> {
+        let service_name = join(scope, service.get_name());
+        for method in service.mut_method().iter_mut() {
+            let full_name = join(&service_name, method.get_name());
+            let context = |e: ConvertError| e.context(&format!("method `{}`", full_name));
+            let input_type = self.message_name(method.get_input_type(), &full_name)
+                .map_err(&context)?;
+            let output_type = self.message_name(method.get_output_type(), &full_name)
+                .map_err(&context)?;
+            method.set_input_type(input_type);
+            method.set_output_type(output_type);
+        }
+        Ok(())
     }
 
-    fn enum_value(&self, name: &str, number: i32) -> protobuf::descriptor::EnumValueDescriptorProto {
-        let mut output = protobuf::descriptor::EnumValueDescriptorProto::new();
-        output.set_name(name.to_owned());
-        output.set_number(number);
-        output
+    fn file(&self, file: &mut FileDescriptorProto) -> ConvertResult<()> {
+        let package = file.get_package().to_owned();
+        for message in file.mut_message_type().iter_mut() {
+            self.message(&package, message)?;
+        }
+        for extension in file.mut_extension().iter_mut() {
+            self.field(&package, extension)?;
+        }
+        for service in file.mut_service().iter_mut() {
+            self.service(&package, service)?;
+        }
+        Ok(())
     }
+}
 
-    fn enumeration(&self, input: &protobuf_parser::Enumeration) -> protobuf::descriptor::EnumDescriptorProto {
-        let mut output = protobuf::descriptor::EnumDescriptorProto::new();
-        output.set_name(input.name.clone());
-        output.set_value(input.values.iter().map(|v| self.enum_value(&v.name, v.number)).collect());
-        output
-    }
 
-    fn oneof(&self, input: &protobuf_parser::OneOf) -> protobuf::descriptor::OneofDescriptorProto {
-        let mut output = protobuf::descriptor::OneofDescriptorProto::new();
-        output.set_name(input.name.clone());
-        output
+fn set_json_names(fields: &mut [FieldDescriptorProto]) {
+    for field in fields {
+        if !field.has_json_name() {
+            let json_name = json_name(field.get_name());
+            field.set_json_name(json_name);
+        }
     }
 }
 
-fn syntax(input: protobuf_parser::Syntax) -> String {
-    match input {
-        protobuf_parser::Syntax::Proto2 => "proto2".to_owned(),
-        protobuf_parser::Syntax::Proto3 => "proto3".to_owned(),
+fn set_message_json_names(message: &mut DescriptorProto) {
+    set_json_names(message.mut_field());
+    set_json_names(message.mut_extension());
+    for nested in message.mut_nested_type().iter_mut() {
+        set_message_json_names(nested);
     }
 }
 
-fn label(input: protobuf_parser::Rule) -> protobuf::descriptor::FieldDescriptorProto_Label {
-    match input {
-        protobuf_parser::Rule::Optional =>
-            protobuf::descriptor::FieldDescriptorProto_Label::LABEL_OPTIONAL,
-        protobuf_parser::Rule::Required =>
-            protobuf::descriptor::FieldDescriptorProto_Label::LABEL_REQUIRED,
-        protobuf_parser::Rule::Repeated =>
-            protobuf::descriptor::FieldDescriptorProto_Label::LABEL_REPEATED,
-    }
-}
 
+/// Convert parsed file to descriptor.
+///
+/// `deps` are descriptors of all files imported by this file directly or indirectly.
 pub fn file_descriptor(
-    name: String,
-    input: &protobuf_parser::FileDescriptor,
-    deps: &[protobuf_parser::FileDescriptor])
-    -> protobuf::descriptor::FileDescriptorProto
+    name: &str,
+    input: &model::FileDescriptor,
+    deps: &[FileDescriptorProto])
+    -> ConvertResult<FileDescriptorProto>
 {
-    let resolver = Resolver {
-        current_file: &input,
-        deps,
-    };
+    let mut output = Converter { syntax: input.syntax }.file(name, input)?;
+
+    Resolver { symbols: &Symbols::new(&output, deps)? }.file(&mut output)?;
+
+    // extensions are looked up with resolved extendees
+    let symbols = Symbols::new(&output, deps)?;
+    OptionInterpreter::new(&symbols, deps, &output).file(&mut output)?;
+
+    // like `protoc --descriptor_set_out`, all fields have `json_name`
+    for message in output.mut_message_type().iter_mut() {
+        set_message_json_names(message);
+    }
+    set_json_names(output.mut_extension());
+
+    Ok(output)
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_names() {
+        assert_eq!("FooBarEntry", map_entry_name("foo_bar"));
+        assert_eq!("FooBarEntry", map_entry_name("_foo__bar"));
+        assert_eq!("ABcEntry", map_entry_name("a_bc"));
+        assert_eq!("fooBar", json_name("foo_bar"));
+        assert_eq!("FooBar", json_name("_foo__bar"));
+        assert_eq!("fooBAR", json_name("foo_bAR"));
+    }
 
-    let mut output = protobuf::descriptor::FileDescriptorProto::new();
-    output.set_name(name);
-    output.set_package(input.package.clone());
-    output.set_syntax(syntax(input.syntax));
+    #[test]
+    fn test_format_double() {
+        assert_eq!("0", format_double(0.0));
+        assert_eq!("-0", format_double(-0.0));
+        assert_eq!("1", format_double(1.0));
+        assert_eq!("1.5", format_double(1.5));
+        assert_eq!("0.1", format_double(0.1));
+        assert_eq!("100000", format_double(1e5));
+        assert_eq!("1e+15", format_double(1e15));
+        assert_eq!("123456789012345", format_double(123456789012345.0));
+        assert_eq!("0.0001", format_double(1e-4));
+        assert_eq!("1e-05", format_double(1e-5));
+        assert_eq!("0.30000000000000004", format_double(0.1 + 0.2));
+        assert_eq!("1.7976931348623157e+308", format_double(f64::MAX));
+        assert_eq!("-inf", format_double(f64::NEG_INFINITY));
+        assert_eq!("nan", format_double(f64::NAN));
+    }
 
-    let messages = input.messages.iter()
-        .map(|m| resolver.message(m, &RelativePath::empty()))
-        .collect();
-    output.set_message_type(messages);
+    #[test]
+    fn test_format_float() {
+        assert_eq!("1.5", format_float(1.5));
+        assert_eq!("0.1", format_float(0.1));
+        assert_eq!("3.40282347e+38", format_float(f32::MAX));
+        assert_eq!("1e+10", format_float(1e10));
+        assert_eq!("16777216", format_float(16777216.0));
+        assert_eq!("inf", format_float(f32::INFINITY));
+    }
+
+    fn convert_error(input: &str) -> String {
+        let parsed = model::FileDescriptor::parse(input).unwrap();
+        let mut hidden = FileDescriptorProto::new();
+        hidden.set_name("hidden.proto".to_owned());
+        hidden.set_package("hidden".to_owned());
+        hidden.mut_message_type().push_default().set_name("Hidden".to_owned());
+        let deps = [protobuf::descriptor::file_descriptor_proto().clone(), hidden];
+        match file_descriptor("test.proto", &parsed, &deps) {
+            Ok(..) => panic!("must fail: {}", input),
+            Err(e) => e.to_string(),
+        }
+    }
 
-    output.set_enum_type(input.enums.iter().map(|e| resolver.enumeration(e)).collect());
-    output
+    #[test]
+    fn test_errors() {
+        let cases = [
+            ("message A { optional B b = 1; }", "field `A.b`: `B` is not defined"),
+            ("message A { optional hidden.Hidden h = 1; }",
+                "field `A.h`: `hidden.Hidden` seems to be defined in `hidden.proto`, \
+                which is not imported by `test.proto`"),
+            ("message A { optional int32 a = 1 [default = \"x\"]; }",
+                "1:13: field `a`: expecting integer"),
+            ("enum E { X = 0; } message A { optional E e = 1 [default = Y]; }",
+                "field `A.e`: enum type `E` has no value named `Y`"),
+            ("message B {} message A { optional B b = 1 [default = X]; }",
+                "field `A.b`: messages can't have default values"),
+            ("extend B { optional int32 e = 100; }", "field `e`: `B` is not defined"),
+            ("message A {} service S { rpc M(A) returns (B); }",
+                "method `S.M`: `B` is not defined"),
+            ("option java_package = 1;",
+                "file `test.proto`: option `java_package`: value must be quoted string"),
+            ("option foo = 1;",
+                "file `test.proto`: option `foo`: \
+                message `google.protobuf.FileOptions` has no field `foo`"),
+            ("option (foo) = 1;", "file `test.proto`: option `(foo)`: `foo` is not defined"),
+            ("option java_package = \"a\"; option java_package = \"b\";",
+                "file `test.proto`: option `java_package`: option is already set"),
+        ];
+        for &(input, expected) in &cases {
+            assert_eq!(expected, convert_error(input), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_c_escape() {
+        assert_eq!("a\\n\\\"\\'\\\\\\000\\377~", c_escape(b"a\n\"'\\\0\xff~"));
+    }
 }
@@ -1,8 +1,11 @@
-extern crate protobuf_parser;
 extern crate protobuf;
 extern crate protobuf_codegen;
 
+mod model;
+mod parser;
 mod convert;
+mod symbols;
+mod option_interpreter;
 
 use std::collections::HashMap;
 use std::path::Path;
@@ -10,6 +13,8 @@ use std::io;
 use std::io::Read;
 use std::fs;
 
+use protobuf::descriptor::FileDescriptorProto;
+
 pub use protobuf_codegen::Customize;
 
 
@@ -40,13 +45,13 @@ pub(crate) fn relative_path_to_protobuf_path(path: &Path) -> String {
 
 #[derive(Clone)]
 struct FileDescriptorPair {
-    parsed: protobuf_parser:: FileDescriptor,
-    descriptor: protobuf::descriptor::FileDescriptorProto,
+    parsed: model::FileDescriptor,
+    descriptor: FileDescriptorProto,
 }
 
 struct Run<'a> {
     parsed_files: HashMap<String, FileDescriptorPair>,
-    args: Args<'a>,
+    includes: &'a [&'a str],
 }
 
 impl<'a> Run<'a> {
@@ -65,11 +70,11 @@ impl<'a> Run<'a> {
 
     fn get_all_deps_already_parsed(
         &self,
-        parsed: &protobuf_parser::FileDescriptor,
+        parsed: &model::FileDescriptor,
         result: &mut HashMap<String, FileDescriptorPair>)
     {
-        for import in &parsed.import_paths {
-            self.get_file_and_all_deps_already_parsed(import, result);
+        for import in &parsed.imports {
+            self.get_file_and_all_deps_already_parsed(&import.path, result);
         }
     }
 
@@ -78,26 +83,30 @@ impl<'a> Run<'a> {
             return Ok(());
         }
 
-        let mut content = Vec::new();
-        fs::File::open(fs_path)?.read_to_end(&mut content)?;
+        let mut content = String::new();
+        fs::File::open(fs_path)?.read_to_string(&mut content)?;
 
-        let parsed = protobuf_parser::FileDescriptor::parse(content)
+        let parsed = model::FileDescriptor::parse(&content)
             .map_err(|e| {
                 io::Error::new(io::ErrorKind::Other,
-                    format!("failed to parse {:?}: {:?}", fs_path, e))
+                    format!("failed to parse {:?}: {}", fs_path, e))
             })?;
 
-        for import_path in &parsed.import_paths {
-            self.add_imported_file(import_path)?;
+        for import in &parsed.imports {
+            self.add_imported_file(&import.path)?;
         }
 
         let mut this_file_deps = HashMap::new();
         self.get_all_deps_already_parsed(&parsed, &mut this_file_deps);
 
-        let this_file_deps: Vec<_> = this_file_deps.into_iter().map(|(_, v)| v.parsed).collect();
+        let this_file_deps: Vec<_> =
+            this_file_deps.into_iter().map(|(_, v)| v.descriptor).collect();
 
-        let descriptor = convert::file_descriptor(
-            protobuf_path.to_owned(), &parsed, &this_file_deps);
+        let descriptor = convert::file_descriptor(protobuf_path, &parsed, &this_file_deps)
+            .map_err(|e| {
+                io::Error::new(io::ErrorKind::Other,
+                    format!("failed to convert {:?}: {}", fs_path, e))
+            })?;
 
         self.parsed_files.insert(
             protobuf_path.to_owned(), FileDescriptorPair { parsed, descriptor });
@@ -106,7 +115,7 @@ impl<'a> Run<'a> {
     }
 
     fn add_imported_file(&mut self, protobuf_path: &str) -> io::Result<()> {
-        for include_dir in self.args.includes {
+        for include_dir in self.includes {
             let fs_path = Path::new(include_dir).join(protobuf_path);
             if fs_path.exists() {
                 return self.add_file(protobuf_path, &fs_path)
@@ -115,11 +124,11 @@ impl<'a> Run<'a> {
 
         Err(io::Error::new(io::ErrorKind::Other,
              format!("protobuf path {:?} is not found in import path {:?}",
-                 protobuf_path, self.args.includes)))
+                 protobuf_path, self.includes)))
     }
 
     fn add_fs_file(&mut self, fs_path: &Path) -> io::Result<String> {
-        let relative_path = self.args.includes.iter()
+        let relative_path = self.includes.iter()
             .filter_map(|include_dir| fs_path.strip_prefix(include_dir).ok())
             .next();
 
@@ -132,37 +141,130 @@ impl<'a> Run<'a> {
             None => {
                 Err(io::Error::new(io::ErrorKind::Other,
                     format!("file {:?} must reside in include path {:?}",
-                        fs_path, self.args.includes)))
+                        fs_path, self.includes)))
             }
         }
     }
 }
 
-/// Like `protoc --rust_out=...` but without requiring `protoc` or `protoc-gen-rust`
-/// commands in `$PATH`.
-pub fn run(args: Args) -> io::Result<()> {
-    let mut run = Run { parsed_files: HashMap::new(), args };
+/// Parse `.proto` files and convert them to descriptors.
+///
+/// Returns descriptors of input files and all files they import,
+/// and protobuf paths of input files.
+fn parse_and_convert(includes: &[&str], input: &[&str])
+    -> io::Result<(Vec<FileDescriptorProto>, Vec<String>)>
+{
+    let mut run = Run { parsed_files: HashMap::new(), includes };
 
     let mut relative_paths = Vec::new();
 
-    for input in run.args.input {
+    for input in input {
         relative_paths.push(run.add_fs_file(&Path::new(input))?);
     }
 
     let file_descriptors: Vec<_> =
         run.parsed_files.into_iter().map(|(_, v)| v.descriptor).collect();
 
+    Ok((file_descriptors, relative_paths))
+}
+
+/// Like `protoc --rust_out=...` but without requiring `protoc` or `protoc-gen-rust`
+/// commands in `$PATH`.
+pub fn run(args: Args) -> io::Result<()> {
+    let (file_descriptors, relative_paths) = parse_and_convert(args.includes, args.input)?;
+
     protobuf_codegen::gen_and_write(
         &file_descriptors,
         &relative_paths,
-        &Path::new(&run.args.out_dir),
-        &run.args.customize)
+        &Path::new(&args.out_dir),
+        &args.customize)
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
 
+    use protobuf::descriptor::FileDescriptorSet;
+    use protobuf::reflect::DescriptorPool;
+    use protobuf::text_format;
+
+    /// Convert `.proto` files and compare descriptors with `test-data/{first input}.pbtxt`:
+    /// `FileDescriptorSet` in text format, like `protoc --descriptor_set_out` writes,
+    /// see `test-data/README.md`
+    fn check_golden(includes: &[&str], input: &[&str]) {
+        let (files, relative_paths) = parse_and_convert(includes, input).unwrap();
+
+        let mut actual = FileDescriptorSet::new();
+        for path in &relative_paths {
+            actual.mut_file().push(files.iter().find(|f| f.get_name() == path).unwrap().clone());
+        }
+
+        // custom options are extensions declared in the converted files
+        let mut all = FileDescriptorSet::new();
+        all.set_file(files.iter().cloned().collect());
+        let mut pool = DescriptorPool::new();
+        pool.add_file_descriptor_set(all).unwrap();
+
+        let golden_path = format!("test-data/{}", relative_paths[0].replace(".proto", ".pbtxt"));
+        let mut golden = String::new();
+        fs::File::open(&golden_path).and_then(|mut f| f.read_to_string(&mut golden))
+            .expect(&golden_path);
+        let expected: FileDescriptorSet = text_format::parse_from_str_with_pool(&golden, &pool)
+            .expect(&golden_path);
+
+        // custom options are stored as unknown fields, their binary representation
+        // depends on how options are written, so only compare text format
+        assert_eq!(
+            text_format::print_to_string_pretty_with_pool(&expected, &pool),
+            text_format::print_to_string_pretty_with_pool(&actual, &pool),
+            "{}",
+            golden_path);
+    }
+
+    #[test]
+    fn test_golden_well_known_types() {
+        let names = [
+            "any", "api", "duration", "empty", "field_mask",
+            "source_context", "struct", "timestamp", "type", "wrappers",
+        ];
+        for name in &names {
+            check_golden(&["../proto"], &[&format!("../proto/google/protobuf/{}.proto", name)]);
+        }
+    }
+
+    #[test]
+    fn test_golden_proto2() {
+        check_golden(&["test-data"], &["test-data/test_proto2.proto"]);
+    }
+
+    #[test]
+    fn test_golden_proto3() {
+        check_golden(&["test-data"], &["test-data/test_proto3.proto"]);
+    }
+
+    #[test]
+    fn test_golden_custom_options() {
+        check_golden(&["test-data", "../proto"], &["test-data/test_custom_options.proto"]);
+    }
+
+    #[test]
+    fn test_bundled_descriptors() {
+        let bundled = [
+            ("google/protobuf/descriptor.proto", protobuf::descriptor::file_descriptor_proto()),
+            ("google/protobuf/compiler/plugin.proto", protobuf::plugin::file_descriptor_proto()),
+            ("rustproto.proto", protobuf::rustproto::file_descriptor_proto()),
+        ];
+        for &(path, expected) in &bundled {
+            let fs_path = format!("../proto/{}", path);
+            let (files, _) = parse_and_convert(&["../proto"], &[&fs_path]).unwrap();
+            let actual = files.iter().find(|f| f.get_name() == path).unwrap();
+
+            let mut expected = expected.clone();
+            expected.clear_source_code_info();
+            assert_eq!(format!("{:#?}", expected), format!("{:#?}", actual), "{}", path);
+        }
+    }
+
     #[cfg(windows)]
     #[test]
     fn test_relative_path_to_protobuf_path_windows() {
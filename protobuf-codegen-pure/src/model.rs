@@ -0,0 +1,234 @@
+//! Parsed `.proto` file: declarations as written, names are not resolved
+
+use std::fmt;
+
+
+/// Position of a declaration in `.proto` file, both line and column are 1-based
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct Loc {
+    pub line: u32,
+    pub col: u32,
+}
+
+impl fmt::Display for Loc {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.col)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Syntax {
+    Proto2,
+    Proto3,
+}
+
+/// Field label
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rule {
+    Optional,
+    Repeated,
+    Required,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldType {
+    Int32,
+    Int64,
+    Uint32,
+    Uint64,
+    Sint32,
+    Sint64,
+    Bool,
+    Fixed64,
+    Sfixed64,
+    Double,
+    String,
+    Bytes,
+    Fixed32,
+    Sfixed32,
+    Float,
+    /// Message or enum name as written, possibly relative
+    MessageOrEnum(String),
+    /// Key and value types
+    Map(Box<(FieldType, FieldType)>),
+    /// Body of the group, message name is the field name
+    Group(Message),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Field {
+    /// For groups it is the name of the group, field name is lowercased
+    pub name: String,
+    /// `None` for fields without label: proto3 singular, oneof and map fields
+    pub rule: Option<Rule>,
+    pub typ: FieldType,
+    pub number: i32,
+    /// Including `default` and `json_name`
+    pub options: Vec<ProtobufOption>,
+    pub loc: Loc,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OneOf {
+    pub name: String,
+    pub fields: Vec<Field>,
+    pub options: Vec<ProtobufOption>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldOrOneOf {
+    Field(Field),
+    OneOf(OneOf),
+}
+
+/// Field declared in `extend` block
+#[derive(Debug, Clone, PartialEq)]
+pub struct Extension {
+    /// Extended message name as written
+    pub extendee: String,
+    pub field: Field,
+}
+
+/// Range of field numbers, both ends are inclusive
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldNumberRange {
+    pub from: i32,
+    pub to: i32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Message {
+    pub name: String,
+    /// Fields and oneofs in declaration order
+    pub fields: Vec<FieldOrOneOf>,
+    pub reserved_nums: Vec<FieldNumberRange>,
+    pub reserved_names: Vec<String>,
+    pub messages: Vec<Message>,
+    pub enums: Vec<Enumeration>,
+    pub options: Vec<ProtobufOption>,
+    pub extension_ranges: Vec<FieldNumberRange>,
+    pub extensions: Vec<Extension>,
+    pub loc: Loc,
+}
+
+impl Message {
+    pub fn new(name: String, loc: Loc) -> Message {
+        Message {
+            name,
+            fields: Vec::new(),
+            reserved_nums: Vec::new(),
+            reserved_names: Vec::new(),
+            messages: Vec::new(),
+            enums: Vec::new(),
+            options: Vec::new(),
+            extension_ranges: Vec::new(),
+            extensions: Vec::new(),
+            loc,
+        }
+    }
+
+    /// Regular fields and fields of oneofs in declaration order
+    pub fn all_fields(&self) -> Vec<&Field> {
+        let mut r = Vec::new();
+        for f in &self.fields {
+            match *f {
+                FieldOrOneOf::Field(ref f) => r.push(f),
+                FieldOrOneOf::OneOf(ref o) => r.extend(&o.fields),
+            }
+        }
+        r
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnumValue {
+    pub name: String,
+    pub number: i32,
+    pub options: Vec<ProtobufOption>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Enumeration {
+    pub name: String,
+    pub values: Vec<EnumValue>,
+    pub options: Vec<ProtobufOption>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Method {
+    pub name: String,
+    pub input_type: String,
+    pub output_type: String,
+    pub client_streaming: bool,
+    pub server_streaming: bool,
+    pub options: Vec<ProtobufOption>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Service {
+    pub name: String,
+    pub methods: Vec<Method>,
+    pub options: Vec<ProtobufOption>,
+}
+
+/// Component of option name: `foo` or `(foo.bar)` in `(foo.bar).foo`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OptionNamePart {
+    pub name: String,
+    pub is_extension: bool,
+}
+
+/// Option value as written
+#[derive(Debug, Clone, PartialEq)]
+pub enum ProtobufConstant {
+    /// Non-negative integer
+    U64(u64),
+    /// Negative integer
+    I64(i64),
+    F64(f64),
+    /// Identifier including `true`, `false`, `inf` and `nan`
+    Ident(String),
+    /// Decoded (and concatenated) string literal
+    String(Vec<u8>),
+    /// Text format message between braces
+    BracedExpr(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProtobufOption {
+    pub name: Vec<OptionNamePart>,
+    pub value: ProtobufConstant,
+}
+
+impl ProtobufOption {
+    /// Option like `default` which is not an extension and has no subfields
+    pub fn is_simple(&self, name: &str) -> bool {
+        self.name.len() == 1 && !self.name[0].is_extension && self.name[0].name == name
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportVis {
+    Default,
+    Public,
+    Weak,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Import {
+    pub path: String,
+    pub vis: ImportVis,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileDescriptor {
+    pub imports: Vec<Import>,
+    /// Empty if not specified
+    pub package: String,
+    pub syntax: Syntax,
+    pub messages: Vec<Message>,
+    pub enums: Vec<Enumeration>,
+    pub extensions: Vec<Extension>,
+    pub services: Vec<Service>,
+    pub options: Vec<ProtobufOption>,
+}
@@ -0,0 +1,468 @@
+//! Interpret `uninterpreted_option` like `protoc` does.
+//!
+//! Built-in options are set with reflection of generated options messages.
+//! Custom options are set on dynamic options messages built from the file
+//! and its dependencies, and then merged into options as unknown fields.
+
+use std::collections::HashSet;
+
+use protobuf::descriptor::DescriptorProto;
+use protobuf::descriptor::EnumDescriptorProto;
+use protobuf::descriptor::EnumOptions;
+use protobuf::descriptor::EnumValueOptions;
+use protobuf::descriptor::FieldDescriptorProto;
+use protobuf::descriptor::FieldOptions;
+use protobuf::descriptor::FileDescriptorProto;
+use protobuf::descriptor::FileDescriptorSet;
+use protobuf::descriptor::FileOptions;
+use protobuf::descriptor::MessageOptions;
+use protobuf::descriptor::MethodOptions;
+use protobuf::descriptor::OneofOptions;
+use protobuf::descriptor::ServiceOptions;
+use protobuf::descriptor::UninterpretedOption;
+use protobuf::reflect::DescriptorPool;
+use protobuf::reflect::FieldDescriptor;
+use protobuf::reflect::MessageDescriptor;
+use protobuf::reflect::ReflectValueBox;
+use protobuf::reflect::RuntimeFieldType;
+use protobuf::reflect::RuntimeType;
+use protobuf::stream::with_coded_output_stream_to_bytes;
+use protobuf::text_format;
+use protobuf::Message;
+
+use convert::ConvertError;
+use convert::ConvertResult;
+use symbols::Symbol;
+use symbols::Symbols;
+
+
+/// Options message of descriptor
+trait Options : Message {
+    fn take_uninterpreted(&mut self) -> Vec<UninterpretedOption>;
+}
+
+macro_rules! impl_options {
+    ($($t:ty),*) => {
+        $(
+            impl Options for $t {
+                fn take_uninterpreted(&mut self) -> Vec<UninterpretedOption> {
+                    self.take_uninterpreted_option().into_vec()
+                }
+            }
+        )*
+    };
+}
+
+impl_options!(
+    FileOptions,
+    MessageOptions,
+    FieldOptions,
+    OneofOptions,
+    EnumOptions,
+    EnumValueOptions,
+    ServiceOptions,
+    MethodOptions
+);
+
+fn join(scope: &str, name: &str) -> String {
+    if scope.is_empty() {
+        name.to_owned()
+    } else {
+        format!("{}.{}", scope, name)
+    }
+}
+
+/// Option name as written: `(foo.bar).baz`
+fn option_name(option: &UninterpretedOption) -> String {
+    let parts: Vec<String> = option.get_name().iter().map(|part| {
+        if part.get_is_extension() {
+            format!("({})", part.get_name_part())
+        } else {
+            part.get_name_part().to_owned()
+        }
+    }).collect();
+    parts.join(".")
+}
+
+/// Value of option of given type
+/// Extensions in aggregate values are resolved in the pool of the file being compiled
+fn option_value(option: &UninterpretedOption, t: &RuntimeType, pool: Option<&DescriptorPool>)
+    -> Result<ReflectValueBox, String>
+{
+    let error = |expected: &str| Err(format!("value must be {}", expected));
+    Ok(match *t {
+        RuntimeType::I32 | RuntimeType::I64 => {
+            let (min, max) = match *t {
+                RuntimeType::I32 => (i32::min_value() as i64, i32::max_value() as u64),
+                _ => (i64::min_value(), i64::max_value() as u64),
+            };
+            let v = if option.has_positive_int_value() {
+                let v = option.get_positive_int_value();
+                if v > max {
+                    return Err("value is out of range".to_owned());
+                }
+                v as i64
+            } else if option.has_negative_int_value() {
+                let v = option.get_negative_int_value();
+                if v < min {
+                    return Err("value is out of range".to_owned());
+                }
+                v
+            } else {
+                return error("integer");
+            };
+            match *t {
+                RuntimeType::I32 => ReflectValueBox::I32(v as i32),
+                _ => ReflectValueBox::I64(v),
+            }
+        }
+        RuntimeType::U32 | RuntimeType::U64 => {
+            if !option.has_positive_int_value() {
+                return error("non-negative integer");
+            }
+            let v = option.get_positive_int_value();
+            match *t {
+                RuntimeType::U32 if v > u32::max_value() as u64 => {
+                    return Err("value is out of range".to_owned());
+                }
+                RuntimeType::U32 => ReflectValueBox::U32(v as u32),
+                _ => ReflectValueBox::U64(v),
+            }
+        }
+        RuntimeType::F32 | RuntimeType::F64 => {
+            let v = if option.has_positive_int_value() {
+                option.get_positive_int_value() as f64
+            } else if option.has_negative_int_value() {
+                option.get_negative_int_value() as f64
+            } else if option.has_double_value() {
+                option.get_double_value()
+            } else {
+                match option.get_identifier_value() {
+                    "inf" => ::std::f64::INFINITY,
+                    "nan" => ::std::f64::NAN,
+                    _ => return error("number"),
+                }
+            };
+            match *t {
+                RuntimeType::F32 => ReflectValueBox::F32(v as f32),
+                _ => ReflectValueBox::F64(v),
+            }
+        }
+        RuntimeType::Bool => match option.get_identifier_value() {
+            "true" => ReflectValueBox::Bool(true),
+            "false" => ReflectValueBox::Bool(false),
+            _ => return error("`true` or `false`"),
+        },
+        RuntimeType::String | RuntimeType::Bytes => {
+            if !option.has_string_value() {
+                return error("quoted string");
+            }
+            let v = option.get_string_value().to_vec();
+            match *t {
+                RuntimeType::String => match String::from_utf8(v) {
+                    Ok(v) => ReflectValueBox::String(v),
+                    Err(..) => return Err("string is not valid UTF-8".to_owned()),
+                },
+                _ => ReflectValueBox::Bytes(v),
+            }
+        }
+        RuntimeType::Enum(e) => {
+            if !option.has_identifier_value() {
+                return error("identifier");
+            }
+            match e.get_value_by_name(option.get_identifier_value()) {
+                Some(v) => ReflectValueBox::Enum(v),
+                None => {
+                    return Err(format!(
+                        "enum type `{}` has no value named `{}`",
+                        e.full_name(), option.get_identifier_value()))
+                }
+            }
+        }
+        RuntimeType::Message(m) => {
+            if !option.has_aggregate_value() {
+                return error("message in braces");
+            }
+            let mut message = m.new_instance();
+            let pool = pool.expect("pool for aggregate value");
+            text_format::merge_from_str_with_pool(&mut *message, option.get_aggregate_value(), pool)
+                .map_err(|e| format!("failed to parse value: {}", e))?;
+            if !message.is_initialized() {
+                return Err("value is missing required fields".to_owned());
+            }
+            ReflectValueBox::Message(message)
+        }
+    })
+}
+
+/// Set value of the last field of `path`, other fields are nested messages
+fn set_path(m: &mut Message, path: &[&FieldDescriptor], value: ReflectValueBox)
+    -> Result<(), String>
+{
+    let field = path[0];
+    if path.len() > 1 {
+        if field.is_extension() {
+            // extension values are not stored in message, so set the whole message
+            let mut nested = match field.runtime_field_type() {
+                RuntimeFieldType::Singular(RuntimeType::Message(d)) => d.new_instance(),
+                _ => unreachable!(),
+            };
+            set_path(&mut *nested, &path[1..], value)?;
+            field.try_set_singular_field(m, ReflectValueBox::Message(nested))
+                .map_err(|e| e.to_string())
+        } else {
+            set_path(field.mut_message(m), &path[1..], value)
+        }
+    } else if field.is_repeated() {
+        field.mut_repeated(m).push(value);
+        Ok(())
+    } else {
+        field.try_set_singular_field(m, value).map_err(|e| e.to_string())
+    }
+}
+
+/// Serialize message which may be partial
+fn message_bytes(m: &Message) -> ConvertResult<Vec<u8>> {
+    with_coded_output_stream_to_bytes(|os| {
+        m.compute_size();
+        m.write_to_with_cached_sizes(os)
+    }).map_err(|e| ConvertError::new(format!("failed to serialize options: {}", e)))
+}
+
+
+pub struct OptionInterpreter<'a> {
+    symbols: &'a Symbols,
+    deps: &'a [FileDescriptorProto],
+    /// The file before options are interpreted
+    file: FileDescriptorProto,
+    /// Pool of dependencies and this file, built on first use of custom option
+    pool: Option<DescriptorPool>,
+}
+
+impl<'a> OptionInterpreter<'a> {
+    pub fn new(symbols: &'a Symbols, deps: &'a [FileDescriptorProto], file: &FileDescriptorProto)
+        -> OptionInterpreter<'a>
+    {
+        OptionInterpreter { symbols, deps, file: file.clone(), pool: None }
+    }
+
+    fn pool(&mut self) -> ConvertResult<&DescriptorPool> {
+        if self.pool.is_none() {
+            let mut set = FileDescriptorSet::new();
+            set.set_file(self.deps.iter().cloned().chain(Some(self.file.clone())).collect());
+            let mut pool = DescriptorPool::new();
+            pool.add_file_descriptor_set(set)
+                .map_err(|e| ConvertError::new(format!("failed to build descriptors: {}", e)))?;
+            self.pool = Some(pool);
+        }
+        Ok(self.pool.as_ref().unwrap())
+    }
+
+    /// Field or extension of message named by option name part
+    fn field(&mut self, message: &'static MessageDescriptor, name: &str, is_extension: bool,
+        relative_to: &str)
+        -> ConvertResult<&'static FieldDescriptor>
+    {
+        if !is_extension {
+            return match message.get_field_by_name(name) {
+                Some(field) => Ok(field),
+                None => Err(ConvertError::new(
+                    format!("message `{}` has no field `{}`", message.full_name(), name))),
+            };
+        }
+
+        let (extendee, number) = match self.symbols.lookup(name, relative_to, false)? {
+            (_, &Symbol::Extension(ref extension)) => {
+                (extension.get_extendee()[1..].to_owned(), extension.get_number())
+            }
+            (full_name, _) => {
+                return Err(ConvertError::new(format!("`{}` is not an extension", full_name)));
+            }
+        };
+        if extendee != message.full_name() {
+            return Err(ConvertError::new(format!(
+                "`{}` is not an extension of `{}`", name, message.full_name())));
+        }
+        let pool = self.pool()?;
+        Ok(pool.find_extension_by_number(&extendee, number as u32).expect("extension in pool"))
+    }
+
+    fn interpret_option<O : Options>(
+        &mut self,
+        options: &mut O,
+        option: &UninterpretedOption,
+        relative_to: &str,
+        set_options: &mut HashSet<String>)
+        -> ConvertResult<()>
+    {
+        let name = option.get_name();
+        if name.is_empty() {
+            return Err(ConvertError::new("option name is empty".to_owned()));
+        }
+        if !name[0].get_is_extension() && name[0].get_name_part() == "uninterpreted_option" {
+            return Err(ConvertError::new(
+                "option must not use reserved name `uninterpreted_option`".to_owned()));
+        }
+
+        let static_descriptor = options.descriptor();
+        let root = if name[0].get_is_extension() {
+            let pool = self.pool()?;
+            pool.find_message_by_full_name(static_descriptor.full_name())
+                .expect("options message in pool")
+        } else {
+            static_descriptor
+        };
+
+        let mut path = Vec::new();
+        let mut resolved_names = Vec::new();
+        let mut message = root;
+        for (i, part) in name.iter().enumerate() {
+            let field =
+                self.field(message, part.get_name_part(), part.get_is_extension(), relative_to)?;
+            path.push(field);
+            resolved_names.push(field.full_name().to_owned());
+            if i + 1 == name.len() {
+                break;
+            }
+            message = match field.runtime_field_type() {
+                RuntimeFieldType::Singular(RuntimeType::Message(m)) => m,
+                RuntimeFieldType::Repeated(RuntimeType::Message(..)) |
+                RuntimeFieldType::Map(..) => {
+                    return Err(ConvertError::new(format!(
+                        "`{}` is a repeated message, it must be initialized using \
+                        an aggregate value", field.full_name())));
+                }
+                _ => {
+                    return Err(ConvertError::new(format!(
+                        "`{}` is an atomic type, not a message", field.full_name())));
+                }
+            };
+        }
+
+        let field = *path.last().unwrap();
+        let t = match field.runtime_field_type() {
+            RuntimeFieldType::Singular(t) => {
+                if !set_options.insert(resolved_names.join(".")) {
+                    return Err(ConvertError::new("option is already set".to_owned()));
+                }
+                t
+            }
+            RuntimeFieldType::Repeated(t) => t,
+            RuntimeFieldType::Map(..) => {
+                return Err(ConvertError::new(
+                    "map options must be initialized using an aggregate value".to_owned()));
+            }
+        };
+        let value = match t {
+            RuntimeType::Message(..) => option_value(option, &t, Some(self.pool()?)),
+            _ => option_value(option, &t, None),
+        };
+        let value = value.map_err(ConvertError::new)?;
+
+        let mut m = root.new_instance();
+        set_path(&mut *m, &path, value).map_err(ConvertError::new)?;
+        let bytes = message_bytes(&*m)?;
+        options.merge_from_bytes(&bytes)
+            .map_err(|e| ConvertError::new(format!("failed to merge options: {}", e)))
+    }
+
+    /// Interpret options of the element named `full_name`,
+    /// extension names are resolved relative to it
+    fn interpret<O : Options>(&mut self, options: &mut O, full_name: &str, element: &str)
+        -> ConvertResult<()>
+    {
+        let element = format!("{} `{}`", element, full_name);
+        self.interpret_relative_to(options, full_name, &element)
+    }
+
+    fn interpret_relative_to<O : Options>(
+        &mut self,
+        options: &mut O,
+        relative_to: &str,
+        element: &str)
+        -> ConvertResult<()>
+    {
+        let mut set_options = HashSet::new();
+        for option in options.take_uninterpreted() {
+            let context = format!("{}: option `{}`", element, option_name(&option));
+            self.interpret_option(options, &option, relative_to, &mut set_options)
+                .map_err(|e| e.context(&context))?;
+        }
+        Ok(())
+    }
+
+    fn fields(&mut self, scope: &str, fields: &mut [FieldDescriptorProto]) -> ConvertResult<()> {
+        for field in fields {
+            if field.has_options() {
+                let full_name = join(scope, field.get_name());
+                self.interpret(field.mut_options(), &full_name, "field")?;
+            }
+        }
+        Ok(())
+    }
+
+    fn enumeration(&mut self, scope: &str, e: &mut EnumDescriptorProto) -> ConvertResult<()> {
+        let full_name = join(scope, e.get_name());
+        if e.has_options() {
+            self.interpret(e.mut_options(), &full_name, "enum")?;
+        }
+        for value in e.mut_value().iter_mut() {
+            if value.has_options() {
+                // enum values are siblings of their enum
+                let value_name = join(scope, value.get_name());
+                self.interpret(value.mut_options(), &value_name, "enum value")?;
+            }
+        }
+        Ok(())
+    }
+
+    fn message(&mut self, scope: &str, message: &mut DescriptorProto) -> ConvertResult<()> {
+        let full_name = join(scope, message.get_name());
+        if message.has_options() {
+            self.interpret(message.mut_options(), &full_name, "message")?;
+        }
+        self.fields(&full_name, message.mut_field())?;
+        for oneof in message.mut_oneof_decl().iter_mut() {
+            if oneof.has_options() {
+                let oneof_name = join(&full_name, oneof.get_name());
+                self.interpret(oneof.mut_options(), &oneof_name, "oneof")?;
+            }
+        }
+        for nested in message.mut_nested_type().iter_mut() {
+            self.message(&full_name, nested)?;
+        }
+        for e in message.mut_enum_type().iter_mut() {
+            self.enumeration(&full_name, e)?;
+        }
+        self.fields(&full_name, message.mut_extension())
+    }
+
+    pub fn file(&mut self, file: &mut FileDescriptorProto) -> ConvertResult<()> {
+        let package = file.get_package().to_owned();
+        if file.has_options() {
+            // name lookup starts in the package, last component is dropped
+            let relative_to = join(&package, "dummy");
+            let element = format!("file `{}`", file.get_name());
+            self.interpret_relative_to(file.mut_options(), &relative_to, &element)?;
+        }
+        for message in file.mut_message_type().iter_mut() {
+            self.message(&package, message)?;
+        }
+        for e in file.mut_enum_type().iter_mut() {
+            self.enumeration(&package, e)?;
+        }
+        for service in file.mut_service().iter_mut() {
+            let service_name = join(&package, service.get_name());
+            if service.has_options() {
+                self.interpret(service.mut_options(), &service_name, "service")?;
+            }
+            for method in service.mut_method().iter_mut() {
+                if method.has_options() {
+                    let method_name = join(&service_name, method.get_name());
+                    self.interpret(method.mut_options(), &method_name, "method")?;
+                }
+            }
+        }
+        self.fields(&package, file.mut_extension())
+    }
+}
@@ -0,0 +1,1294 @@
+//! Parser of `.proto` files.
+//!
+//! Grammar follows `protoc` parser: keywords are not reserved words,
+//! adjacent string literals are concatenated, option values are stored as written.
+
+use std::char;
+use std::error::Error;
+use std::fmt;
+
+use model::Enumeration;
+use model::EnumValue;
+use model::Extension;
+use model::Field;
+use model::FieldNumberRange;
+use model::FieldOrOneOf;
+use model::FieldType;
+use model::FileDescriptor;
+use model::Import;
+use model::ImportVis;
+use model::Loc;
+use model::Message;
+use model::Method;
+use model::OneOf;
+use model::OptionNamePart;
+use model::ProtobufConstant;
+use model::ProtobufOption;
+use model::Rule;
+use model::Service;
+use model::Syntax;
+
+
+/// Largest field number
+pub const FIELD_NUMBER_MAX: i32 = 0x1fffffff;
+
+
+/// Error of parsing `.proto` file
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParserError {
+    pub loc: Loc,
+    pub message: String,
+}
+
+impl fmt::Display for ParserError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.loc, self.message)
+    }
+}
+
+impl Error for ParserError {
+    fn description(&self) -> &str {
+        &self.message
+    }
+}
+
+pub type ParserResult<T> = Result<T, ParserError>;
+
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    /// Single-character punctuation
+    Symbol(char),
+    IntLit(u64),
+    FloatLit(f64),
+    /// Quoted string with escapes decoded
+    StrLit(Vec<u8>),
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Token::Ident(ref s) => write!(f, "`{}`", s),
+            Token::Symbol(c) => write!(f, "`{}`", c),
+            Token::IntLit(v) => write!(f, "`{}`", v),
+            Token::FloatLit(v) => write!(f, "`{}`", v),
+            Token::StrLit(..) => write!(f, "string literal"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct TokenWithLoc {
+    token: Token,
+    loc: Loc,
+    /// Byte offsets of the token in the input
+    start: usize,
+    end: usize,
+}
+
+
+struct Lexer<'a> {
+    input: &'a str,
+    pos: usize,
+    loc: Loc,
+}
+
+fn is_ident_start(c: char) -> bool {
+    c == '_' || c.is_ascii_alphabetic()
+}
+
+fn is_ident_part(c: char) -> bool {
+    c == '_' || c.is_ascii_alphanumeric()
+}
+
+impl<'a> Lexer<'a> {
+    fn new(input: &'a str) -> Lexer<'a> {
+        Lexer {
+            input,
+            pos: 0,
+            loc: Loc { line: 1, col: 1 },
+        }
+    }
+
+    fn rem(&self) -> &'a str {
+        &self.input[self.pos..]
+    }
+
+    fn peek_char(&self) -> Option<char> {
+        self.rem().chars().next()
+    }
+
+    fn next_char(&mut self) -> Option<char> {
+        let c = self.peek_char()?;
+        self.pos += c.len_utf8();
+        if c == '\n' {
+            self.loc.line += 1;
+            self.loc.col = 1;
+        } else {
+            self.loc.col += 1;
+        }
+        Some(c)
+    }
+
+    fn next_char_if<P : Fn(char) -> bool>(&mut self, p: P) -> Option<char> {
+        match self.peek_char() {
+            Some(c) if p(c) => self.next_char(),
+            _ => None,
+        }
+    }
+
+    fn error<T>(&self, loc: Loc, message: &str) -> ParserResult<T> {
+        Err(ParserError { loc, message: message.to_owned() })
+    }
+
+    fn skip_ws_and_comments(&mut self) -> ParserResult<()> {
+        loop {
+            if self.rem().starts_with("//") {
+                while let Some(c) = self.next_char() {
+                    if c == '\n' {
+                        break;
+                    }
+                }
+            } else if self.rem().starts_with("/*") {
+                let loc = self.loc;
+                self.next_char();
+                self.next_char();
+                while !self.rem().starts_with("*/") {
+                    if self.next_char().is_none() {
+                        return self.error(loc, "unterminated block comment");
+                    }
+                }
+                self.next_char();
+                self.next_char();
+            } else if self.next_char_if(|c| c.is_whitespace()).is_none() {
+                return Ok(());
+            }
+        }
+    }
+
+    fn next_digits(&mut self, radix: u32) -> &'a str {
+        let start = self.pos;
+        while self.next_char_if(|c| c.is_digit(radix)).is_some() {}
+        &self.input[start..self.pos]
+    }
+
+    fn next_number(&mut self, loc: Loc) -> ParserResult<Token> {
+        let start = self.pos;
+        let token = if self.rem().starts_with("0x") || self.rem().starts_with("0X") {
+            self.next_char();
+            self.next_char();
+            let digits = self.next_digits(16);
+            if digits.is_empty() {
+                return self.error(loc, "expecting hex digits");
+            }
+            match u64::from_str_radix(digits, 16) {
+                Ok(v) => Token::IntLit(v),
+                Err(..) => return self.error(loc, "integer literal is too large"),
+            }
+        } else {
+            self.next_digits(10);
+            let mut float = false;
+            if self.next_char_if(|c| c == '.').is_some() {
+                float = true;
+                self.next_digits(10);
+            }
+            if self.next_char_if(|c| c == 'e' || c == 'E').is_some() {
+                float = true;
+                self.next_char_if(|c| c == '+' || c == '-');
+                if self.next_digits(10).is_empty() {
+                    return self.error(loc, "expecting exponent digits");
+                }
+            }
+            let text = &self.input[start..self.pos];
+            if float {
+                match text.parse() {
+                    Ok(v) => Token::FloatLit(v),
+                    Err(..) => return self.error(loc, "incorrect float literal"),
+                }
+            } else if text.len() > 1 && text.starts_with('0') {
+                match u64::from_str_radix(&text[1..], 8) {
+                    Ok(v) => Token::IntLit(v),
+                    Err(..) => return self.error(loc, "incorrect octal literal"),
+                }
+            } else {
+                match text.parse() {
+                    Ok(v) => Token::IntLit(v),
+                    Err(..) => return self.error(loc, "integer literal is too large"),
+                }
+            }
+        };
+        if self.peek_char().map_or(false, is_ident_part) {
+            return self.error(loc, "need space between number and identifier");
+        }
+        Ok(token)
+    }
+
+    /// Read up to `max` hex digits after escape prefix
+    fn next_hex_digits(&mut self, max: usize) -> u32 {
+        let mut r = 0;
+        for _ in 0..max {
+            match self.peek_char().and_then(|c| c.to_digit(16)) {
+                Some(d) => {
+                    self.next_char();
+                    r = r * 16 + d;
+                }
+                None => break,
+            }
+        }
+        r
+    }
+
+    fn next_escape(&mut self, loc: Loc, r: &mut Vec<u8>) -> ParserResult<()> {
+        let c = match self.next_char() {
+            Some(c) => c,
+            None => return self.error(loc, "unterminated string literal"),
+        };
+        let b = match c {
+            'a' => b'\x07',
+            'b' => b'\x08',
+            'f' => b'\x0c',
+            'n' => b'\n',
+            'r' => b'\r',
+            't' => b'\t',
+            'v' => b'\x0b',
+            '\\' => b'\\',
+            '\'' => b'\'',
+            '"' => b'"',
+            '?' => b'?',
+            '0'...'7' => {
+                let mut v = c.to_digit(8).unwrap();
+                for _ in 0..2 {
+                    match self.next_char_if(|c| c.is_digit(8)) {
+                        Some(d) => v = v * 8 + d.to_digit(8).unwrap(),
+                        None => break,
+                    }
+                }
+                if v > 0xff {
+                    return self.error(loc, "octal escape is out of range");
+                }
+                v as u8
+            }
+            'x' | 'X' => {
+                if !self.peek_char().map_or(false, |c| c.is_digit(16)) {
+                    return self.error(loc, "expecting hex digits after `\\x`");
+                }
+                self.next_hex_digits(2) as u8
+            }
+            'u' | 'U' => {
+                let len = if c == 'u' { 4 } else { 8 };
+                let start = self.pos;
+                let code = self.next_hex_digits(len);
+                if self.pos - start != len {
+                    return self.error(loc, "incorrect unicode escape");
+                }
+                match char::from_u32(code) {
+                    Some(c) => {
+                        let mut buf = [0; 4];
+                        r.extend(c.encode_utf8(&mut buf).as_bytes());
+                        return Ok(());
+                    }
+                    None => return self.error(loc, "incorrect unicode escape"),
+                }
+            }
+            _ => return self.error(loc, "invalid escape sequence"),
+        };
+        r.push(b);
+        Ok(())
+    }
+
+    fn next_str_lit(&mut self, loc: Loc) -> ParserResult<Vec<u8>> {
+        let quote = self.next_char().unwrap();
+        let mut r = Vec::new();
+        loop {
+            match self.next_char() {
+                None | Some('\n') => return self.error(loc, "unterminated string literal"),
+                Some('\\') => self.next_escape(loc, &mut r)?,
+                Some(c) if c == quote => return Ok(r),
+                Some(c) => {
+                    let mut buf = [0; 4];
+                    r.extend(c.encode_utf8(&mut buf).as_bytes());
+                }
+            }
+        }
+    }
+
+    /// Read next token, `None` on EOF
+    fn next_token(&mut self) -> ParserResult<Option<TokenWithLoc>> {
+        self.skip_ws_and_comments()?;
+        let loc = self.loc;
+        let start = self.pos;
+        let c = match self.peek_char() {
+            Some(c) => c,
+            None => return Ok(None),
+        };
+
+        let token = if is_ident_start(c) {
+            while self.next_char_if(is_ident_part).is_some() {}
+            Token::Ident(self.input[start..self.pos].to_owned())
+        } else if c.is_ascii_digit() ||
+            (c == '.' && self.rem()[1..].starts_with(|c: char| c.is_ascii_digit()))
+        {
+            self.next_number(loc)?
+        } else if c == '"' || c == '\'' {
+            Token::StrLit(self.next_str_lit(loc)?)
+        } else if c.is_ascii_punctuation() {
+            self.next_char();
+            Token::Symbol(c)
+        } else {
+            return self.error(loc, &format!("unexpected character `{}`", c));
+        };
+
+        Ok(Some(TokenWithLoc { token, loc, start, end: self.pos }))
+    }
+}
+
+
+/// Where a field is declared
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FieldContext {
+    Message,
+    OneOf,
+    Extend,
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    tokens: Vec<TokenWithLoc>,
+    /// Index of the next token
+    pos: usize,
+    eof_loc: Loc,
+    syntax: Syntax,
+}
+
+impl<'a> Parser<'a> {
+    fn new(input: &'a str) -> ParserResult<Parser<'a>> {
+        let mut lexer = Lexer::new(input);
+        let mut tokens = Vec::new();
+        while let Some(token) = lexer.next_token()? {
+            tokens.push(token);
+        }
+        Ok(Parser {
+            input,
+            tokens,
+            pos: 0,
+            eof_loc: lexer.loc,
+            syntax: Syntax::Proto2,
+        })
+    }
+
+    fn loc(&self) -> Loc {
+        match self.tokens.get(self.pos) {
+            Some(t) => t.loc,
+            None => self.eof_loc,
+        }
+    }
+
+    fn error<T>(&self, message: String) -> ParserResult<T> {
+        Err(ParserError { loc: self.loc(), message })
+    }
+
+    /// Error about unexpected next token
+    fn expecting<T>(&self, what: &str) -> ParserResult<T> {
+        match self.peek() {
+            Some(t) => self.error(format!("expecting {}, got {}", what, t)),
+            None => self.error(format!("expecting {}, got end of file", what)),
+        }
+    }
+
+    fn peek_nth(&self, n: usize) -> Option<&Token> {
+        self.tokens.get(self.pos + n).map(|t| &t.token)
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.peek_nth(0)
+    }
+
+    fn advance(&mut self) {
+        self.pos += 1;
+    }
+
+    fn lookahead_nth_is_ident(&self, n: usize, ident: &str) -> bool {
+        match self.peek_nth(n) {
+            Some(&Token::Ident(ref s)) => s == ident,
+            _ => false,
+        }
+    }
+
+    fn lookahead_is_ident(&self, ident: &str) -> bool {
+        self.lookahead_nth_is_ident(0, ident)
+    }
+
+    fn lookahead_nth_is_symbol(&self, n: usize, symbol: char) -> bool {
+        self.peek_nth(n) == Some(&Token::Symbol(symbol))
+    }
+
+    fn lookahead_is_symbol(&self, symbol: char) -> bool {
+        self.lookahead_nth_is_symbol(0, symbol)
+    }
+
+    fn next_ident_if_eq(&mut self, ident: &str) -> bool {
+        let r = self.lookahead_is_ident(ident);
+        if r {
+            self.advance();
+        }
+        r
+    }
+
+    fn next_symbol_if_eq(&mut self, symbol: char) -> bool {
+        let r = self.lookahead_is_symbol(symbol);
+        if r {
+            self.advance();
+        }
+        r
+    }
+
+    fn expect_ident_eq(&mut self, ident: &str) -> ParserResult<()> {
+        if !self.next_ident_if_eq(ident) {
+            return self.expecting(&format!("`{}`", ident));
+        }
+        Ok(())
+    }
+
+    /// Consume `}` ending block, error on end of file
+    fn next_block_end(&mut self) -> ParserResult<bool> {
+        if self.peek().is_none() {
+            return self.expecting("`}`");
+        }
+        Ok(self.next_symbol_if_eq('}'))
+    }
+
+    fn expect_symbol(&mut self, symbol: char) -> ParserResult<()> {
+        if !self.next_symbol_if_eq(symbol) {
+            return self.expecting(&format!("`{}`", symbol));
+        }
+        Ok(())
+    }
+
+    fn next_ident(&mut self) -> ParserResult<String> {
+        let ident = match self.peek() {
+            Some(&Token::Ident(ref s)) => s.clone(),
+            _ => return self.expecting("identifier"),
+        };
+        self.advance();
+        Ok(ident)
+    }
+
+    /// `foo.bar.baz`
+    fn next_full_ident(&mut self) -> ParserResult<String> {
+        let mut r = self.next_ident()?;
+        while self.next_symbol_if_eq('.') {
+            r.push('.');
+            r.push_str(&self.next_ident()?);
+        }
+        Ok(r)
+    }
+
+    /// Message or enum name, absolute names start with dot
+    fn next_type_name(&mut self) -> ParserResult<String> {
+        let mut r = String::new();
+        if self.next_symbol_if_eq('.') {
+            r.push('.');
+        }
+        r.push_str(&self.next_full_ident()?);
+        Ok(r)
+    }
+
+    fn next_int_lit(&mut self) -> ParserResult<u64> {
+        let v = match self.peek() {
+            Some(&Token::IntLit(v)) => v,
+            _ => return self.expecting("integer"),
+        };
+        self.advance();
+        Ok(v)
+    }
+
+    fn next_field_number(&mut self) -> ParserResult<i32> {
+        let loc = self.loc();
+        let v = self.next_int_lit()?;
+        if v == 0 || v > FIELD_NUMBER_MAX as u64 {
+            return Err(ParserError {
+                loc,
+                message: format!("field number must be in range 1..{}", FIELD_NUMBER_MAX),
+            });
+        }
+        Ok(v as i32)
+    }
+
+    /// Integer with optional minus sign
+    fn next_i32(&mut self) -> ParserResult<i32> {
+        let loc = self.loc();
+        let neg = self.next_symbol_if_eq('-');
+        let v = self.next_int_lit()? as i64;
+        let v = if neg { -v } else { v };
+        if v < i32::min_value() as i64 || v > i32::max_value() as i64 {
+            return Err(ParserError { loc, message: "integer is out of range".to_owned() });
+        }
+        Ok(v as i32)
+    }
+
+    /// String literal, adjacent literals are concatenated
+    fn next_str_lit(&mut self) -> ParserResult<Vec<u8>> {
+        let mut r = match self.peek() {
+            Some(&Token::StrLit(ref s)) => s.clone(),
+            _ => return self.expecting("string literal"),
+        };
+        self.advance();
+        while let Some(&Token::StrLit(ref s)) = self.peek() {
+            r.extend(s);
+            self.pos += 1;
+        }
+        Ok(r)
+    }
+
+    fn next_utf8_str_lit(&mut self) -> ParserResult<String> {
+        let loc = self.loc();
+        let bytes = self.next_str_lit()?;
+        String::from_utf8(bytes).map_err(|_| {
+            ParserError { loc, message: "string is not valid UTF-8".to_owned() }
+        })
+    }
+
+    /// Text between braces, braces are not included
+    fn next_braced_expr(&mut self) -> ParserResult<String> {
+        let start = self.tokens[self.pos].end;
+        self.expect_symbol('{')?;
+        let mut depth = 1;
+        loop {
+            let end = match self.tokens.get(self.pos) {
+                Some(t) => t.start,
+                None => return self.expecting("`}`"),
+            };
+            if self.next_symbol_if_eq('{') {
+                depth += 1;
+            } else if self.next_symbol_if_eq('}') {
+                depth -= 1;
+                if depth == 0 {
+                    return Ok(self.input[start..end].trim().to_owned());
+                }
+            } else {
+                self.advance();
+            }
+        }
+    }
+
+    fn next_constant(&mut self) -> ParserResult<ProtobufConstant> {
+        if self.next_symbol_if_eq('-') {
+            let c = match self.peek() {
+                Some(&Token::IntLit(v)) => {
+                    if v > i64::max_value() as u64 + 1 {
+                        return self.error("integer is out of range".to_owned());
+                    }
+                    ProtobufConstant::I64((v as i64).wrapping_neg())
+                }
+                Some(&Token::FloatLit(v)) => ProtobufConstant::F64(-v),
+                Some(&Token::Ident(ref s)) if s == "inf" => {
+                    ProtobufConstant::F64(-::std::f64::INFINITY)
+                }
+                Some(&Token::Ident(ref s)) if s == "nan" => ProtobufConstant::F64(::std::f64::NAN),
+                _ => return self.expecting("number"),
+            };
+            self.advance();
+            return Ok(c);
+        }
+
+        let c = match self.peek() {
+            Some(&Token::IntLit(v)) => ProtobufConstant::U64(v),
+            Some(&Token::FloatLit(v)) => ProtobufConstant::F64(v),
+            Some(&Token::Ident(ref s)) => ProtobufConstant::Ident(s.clone()),
+            Some(&Token::StrLit(..)) => return Ok(ProtobufConstant::String(self.next_str_lit()?)),
+            Some(&Token::Symbol('{')) => {
+                return Ok(ProtobufConstant::BracedExpr(self.next_braced_expr()?));
+            }
+            _ => return self.expecting("constant"),
+        };
+        self.advance();
+        Ok(c)
+    }
+
+    /// `foo` or `(foo.bar)`
+    fn next_option_name_part(&mut self) -> ParserResult<OptionNamePart> {
+        if self.next_symbol_if_eq('(') {
+            let name = self.next_type_name()?;
+            self.expect_symbol(')')?;
+            Ok(OptionNamePart { name, is_extension: true })
+        } else {
+            Ok(OptionNamePart { name: self.next_ident()?, is_extension: false })
+        }
+    }
+
+    /// `name = value`
+    fn next_option(&mut self) -> ParserResult<ProtobufOption> {
+        let mut name = vec![self.next_option_name_part()?];
+        while self.next_symbol_if_eq('.') {
+            name.push(self.next_option_name_part()?);
+        }
+        self.expect_symbol('=')?;
+        let value = self.next_constant()?;
+        Ok(ProtobufOption { name, value })
+    }
+
+    /// `option name = value;`
+    fn next_option_statement(&mut self) -> ParserResult<ProtobufOption> {
+        self.expect_ident_eq("option")?;
+        let option = self.next_option()?;
+        self.expect_symbol(';')?;
+        Ok(option)
+    }
+
+    /// Options in brackets after field, enum value or extension range
+    fn next_bracketed_options(&mut self) -> ParserResult<Vec<ProtobufOption>> {
+        let mut options = Vec::new();
+        if self.next_symbol_if_eq('[') {
+            loop {
+                options.push(self.next_option()?);
+                if self.next_symbol_if_eq(']') {
+                    break;
+                }
+                self.expect_symbol(',')?;
+            }
+        }
+        Ok(options)
+    }
+
+    fn next_syntax(&mut self) -> ParserResult<Syntax> {
+        self.expect_ident_eq("syntax")?;
+        self.expect_symbol('=')?;
+        let loc = self.loc();
+        let syntax = match &self.next_str_lit()?[..] {
+            b"proto2" => Syntax::Proto2,
+            b"proto3" => Syntax::Proto3,
+            _ => return Err(ParserError { loc, message: "unknown syntax".to_owned() }),
+        };
+        self.expect_symbol(';')?;
+        Ok(syntax)
+    }
+
+    fn next_import(&mut self) -> ParserResult<Import> {
+        self.expect_ident_eq("import")?;
+        let vis = if self.next_ident_if_eq("public") {
+            ImportVis::Public
+        } else if self.next_ident_if_eq("weak") {
+            ImportVis::Weak
+        } else {
+            ImportVis::Default
+        };
+        let path = self.next_utf8_str_lit()?;
+        self.expect_symbol(';')?;
+        Ok(Import { path, vis })
+    }
+
+    /// `1`, `2 to 5` or `10 to max`
+    fn next_range(&mut self) -> ParserResult<FieldNumberRange> {
+        let from = self.next_field_number()?;
+        let to = if self.next_ident_if_eq("to") {
+            if self.next_ident_if_eq("max") {
+                FIELD_NUMBER_MAX
+            } else {
+                self.next_field_number()?
+            }
+        } else {
+            from
+        };
+        if to < from {
+            return self.error("range end is less than range start".to_owned());
+        }
+        Ok(FieldNumberRange { from, to })
+    }
+
+    fn next_ranges(&mut self) -> ParserResult<Vec<FieldNumberRange>> {
+        let mut ranges = vec![self.next_range()?];
+        while self.next_symbol_if_eq(',') {
+            ranges.push(self.next_range()?);
+        }
+        Ok(ranges)
+    }
+
+    /// `extensions 100 to 199;`
+    fn next_extensions(&mut self) -> ParserResult<Vec<FieldNumberRange>> {
+        self.expect_ident_eq("extensions")?;
+        let ranges = self.next_ranges()?;
+        // `descriptor.proto` of this version has no options of extension ranges
+        self.next_bracketed_options()?;
+        self.expect_symbol(';')?;
+        Ok(ranges)
+    }
+
+    /// `reserved 2, 15, 9 to 11;` or `reserved "foo", "bar";`
+    fn next_reserved(&mut self) -> ParserResult<(Vec<FieldNumberRange>, Vec<String>)> {
+        self.expect_ident_eq("reserved")?;
+        let mut nums = Vec::new();
+        let mut names = Vec::new();
+        if let Some(&Token::StrLit(..)) = self.peek() {
+            names.push(self.next_utf8_str_lit()?);
+            while self.next_symbol_if_eq(',') {
+                names.push(self.next_utf8_str_lit()?);
+            }
+        } else {
+            nums = self.next_ranges()?;
+        }
+        self.expect_symbol(';')?;
+        Ok((nums, names))
+    }
+
+    fn next_label(&mut self, context: FieldContext) -> ParserResult<Option<Rule>> {
+        let loc = self.loc();
+        let rule = if self.next_ident_if_eq("optional") {
+            Rule::Optional
+        } else if self.next_ident_if_eq("repeated") {
+            Rule::Repeated
+        } else if self.next_ident_if_eq("required") {
+            Rule::Required
+        } else {
+            return Ok(None);
+        };
+        let message = if context == FieldContext::OneOf {
+            "fields in oneof must not have labels"
+        } else if rule == Rule::Required && self.syntax == Syntax::Proto3 {
+            "required fields are not allowed in proto3"
+        } else {
+            return Ok(Some(rule));
+        };
+        Err(ParserError { loc, message: message.to_owned() })
+    }
+
+    fn next_field_type(&mut self) -> ParserResult<FieldType> {
+        let t = match self.peek() {
+            Some(&Token::Ident(ref s)) => match &s[..] {
+                "int32" => FieldType::Int32,
+                "int64" => FieldType::Int64,
+                "uint32" => FieldType::Uint32,
+                "uint64" => FieldType::Uint64,
+                "sint32" => FieldType::Sint32,
+                "sint64" => FieldType::Sint64,
+                "fixed32" => FieldType::Fixed32,
+                "fixed64" => FieldType::Fixed64,
+                "sfixed32" => FieldType::Sfixed32,
+                "sfixed64" => FieldType::Sfixed64,
+                "bool" => FieldType::Bool,
+                "string" => FieldType::String,
+                "bytes" => FieldType::Bytes,
+                "float" => FieldType::Float,
+                "double" => FieldType::Double,
+                _ => return Ok(FieldType::MessageOrEnum(self.next_type_name()?)),
+            },
+            Some(&Token::Symbol('.')) => {
+                return Ok(FieldType::MessageOrEnum(self.next_type_name()?));
+            }
+            _ => return self.expecting("field type"),
+        };
+        self.advance();
+        Ok(t)
+    }
+
+    /// `map<key, value>`
+    fn next_map_field_type(&mut self) -> ParserResult<FieldType> {
+        self.expect_ident_eq("map")?;
+        self.expect_symbol('<')?;
+        let loc = self.loc();
+        let key = self.next_field_type()?;
+        match key {
+            FieldType::Float | FieldType::Double | FieldType::Bytes |
+            FieldType::MessageOrEnum(..) => {
+                return Err(ParserError {
+                    loc,
+                    message: "map key must be integral, bool or string".to_owned(),
+                });
+            }
+            _ => {}
+        }
+        self.expect_symbol(',')?;
+        let value = self.next_field_type()?;
+        self.expect_symbol('>')?;
+        Ok(FieldType::Map(Box::new((key, value))))
+    }
+
+    fn next_field(&mut self, context: FieldContext) -> ParserResult<Field> {
+        let loc = self.loc();
+        let rule = self.next_label(context)?;
+
+        let is_map = self.lookahead_is_ident("map") && self.lookahead_nth_is_symbol(1, '<');
+        let is_group = !is_map && self.next_ident_if_eq("group");
+        let typ = if is_map {
+            let message = if context != FieldContext::Message {
+                "map fields are only allowed in messages"
+            } else if rule.is_some() {
+                "map fields must not have labels"
+            } else {
+                ""
+            };
+            if !message.is_empty() {
+                return Err(ParserError { loc, message: message.to_owned() });
+            }
+            Some(self.next_map_field_type()?)
+        } else if is_group {
+            None
+        } else {
+            Some(self.next_field_type()?)
+        };
+
+        if rule.is_none() && !is_map && context != FieldContext::OneOf &&
+            self.syntax == Syntax::Proto2
+        {
+            return Err(ParserError {
+                loc,
+                message: "expecting label: `required`, `optional` or `repeated`".to_owned(),
+            });
+        }
+
+        let name_loc = self.loc();
+        let name = self.next_ident()?;
+        self.expect_symbol('=')?;
+        let number = self.next_field_number()?;
+        let options = self.next_bracketed_options()?;
+
+        let typ = match typ {
+            Some(typ) => {
+                self.expect_symbol(';')?;
+                typ
+            }
+            None => {
+                let message = if self.syntax == Syntax::Proto3 {
+                    "groups are not supported in proto3"
+                } else if !name.starts_with(|c: char| c.is_ascii_uppercase()) {
+                    "group names must start with a capital letter"
+                } else {
+                    ""
+                };
+                if !message.is_empty() {
+                    return Err(ParserError { loc: name_loc, message: message.to_owned() });
+                }
+                let mut group = Message::new(name.clone(), loc);
+                self.next_message_body(&mut group)?;
+                FieldType::Group(group)
+            }
+        };
+
+        Ok(Field { name, rule, typ, number, options, loc })
+    }
+
+    fn next_oneof(&mut self) -> ParserResult<OneOf> {
+        self.expect_ident_eq("oneof")?;
+        let name = self.next_ident()?;
+        let mut oneof = OneOf { name, fields: Vec::new(), options: Vec::new() };
+        self.expect_symbol('{')?;
+        while !self.next_block_end()? {
+            if self.next_symbol_if_eq(';') {
+                continue;
+            } else if self.lookahead_is_ident("option") {
+                oneof.options.push(self.next_option_statement()?);
+            } else {
+                oneof.fields.push(self.next_field(FieldContext::OneOf)?);
+            }
+        }
+        Ok(oneof)
+    }
+
+    /// `extend Foo { ... }`
+    fn next_extend(&mut self, extensions: &mut Vec<Extension>) -> ParserResult<()> {
+        self.expect_ident_eq("extend")?;
+        let extendee = self.next_type_name()?;
+        self.expect_symbol('{')?;
+        while !self.next_block_end()? {
+            if self.next_symbol_if_eq(';') {
+                continue;
+            }
+            let field = self.next_field(FieldContext::Extend)?;
+            extensions.push(Extension { extendee: extendee.clone(), field });
+        }
+        Ok(())
+    }
+
+    fn next_message_body(&mut self, message: &mut Message) -> ParserResult<()> {
+        self.expect_symbol('{')?;
+        while !self.next_block_end()? {
+            if self.next_symbol_if_eq(';') {
+                continue;
+            } else if self.lookahead_is_ident("message") {
+                message.messages.push(self.next_message()?);
+            } else if self.lookahead_is_ident("enum") {
+                message.enums.push(self.next_enum()?);
+            } else if self.lookahead_is_ident("extensions") {
+                message.extension_ranges.extend(self.next_extensions()?);
+            } else if self.lookahead_is_ident("reserved") {
+                let (nums, names) = self.next_reserved()?;
+                message.reserved_nums.extend(nums);
+                message.reserved_names.extend(names);
+            } else if self.lookahead_is_ident("extend") {
+                self.next_extend(&mut message.extensions)?;
+            } else if self.lookahead_is_ident("option") {
+                message.options.push(self.next_option_statement()?);
+            } else if self.lookahead_is_ident("oneof") {
+                message.fields.push(FieldOrOneOf::OneOf(self.next_oneof()?));
+            } else {
+                message.fields.push(FieldOrOneOf::Field(self.next_field(FieldContext::Message)?));
+            }
+        }
+        Ok(())
+    }
+
+    fn next_message(&mut self) -> ParserResult<Message> {
+        let loc = self.loc();
+        self.expect_ident_eq("message")?;
+        let name = self.next_ident()?;
+        let mut message = Message::new(name, loc);
+        self.next_message_body(&mut message)?;
+        Ok(message)
+    }
+
+    fn next_enum_value(&mut self) -> ParserResult<EnumValue> {
+        let name = self.next_ident()?;
+        self.expect_symbol('=')?;
+        let number = self.next_i32()?;
+        let options = self.next_bracketed_options()?;
+        self.expect_symbol(';')?;
+        Ok(EnumValue { name, number, options })
+    }
+
+    fn next_enum(&mut self) -> ParserResult<Enumeration> {
+        self.expect_ident_eq("enum")?;
+        let name = self.next_ident()?;
+        let mut e = Enumeration { name, values: Vec::new(), options: Vec::new() };
+        self.expect_symbol('{')?;
+        while !self.next_block_end()? {
+            if self.next_symbol_if_eq(';') {
+                continue;
+            } else if self.lookahead_is_ident("option") {
+                e.options.push(self.next_option_statement()?);
+            } else if self.lookahead_is_ident("reserved") {
+                // `descriptor.proto` of this version has no reserved enum values
+                self.next_reserved_enum_values()?;
+            } else {
+                e.values.push(self.next_enum_value()?);
+            }
+        }
+        Ok(e)
+    }
+
+    /// Like `reserved` in message, but numbers may be negative
+    fn next_reserved_enum_values(&mut self) -> ParserResult<()> {
+        self.expect_ident_eq("reserved")?;
+        loop {
+            if let Some(&Token::StrLit(..)) = self.peek() {
+                self.next_str_lit()?;
+            } else {
+                self.next_i32()?;
+                if self.next_ident_if_eq("to") && !self.next_ident_if_eq("max") {
+                    self.next_i32()?;
+                }
+            }
+            if !self.next_symbol_if_eq(',') {
+                break;
+            }
+        }
+        self.expect_symbol(';')
+    }
+
+    /// `(stream Foo)` part of method declaration, returns whether it is streaming
+    fn next_method_type(&mut self) -> ParserResult<(String, bool)> {
+        self.expect_symbol('(')?;
+        let stream = self.next_ident_if_eq("stream");
+        let name = self.next_type_name()?;
+        self.expect_symbol(')')?;
+        Ok((name, stream))
+    }
+
+    fn next_method(&mut self) -> ParserResult<Method> {
+        self.expect_ident_eq("rpc")?;
+        let name = self.next_ident()?;
+        let (input_type, client_streaming) = self.next_method_type()?;
+        self.expect_ident_eq("returns")?;
+        let (output_type, server_streaming) = self.next_method_type()?;
+        let mut options = Vec::new();
+        if self.next_symbol_if_eq('{') {
+            while !self.next_block_end()? {
+                if self.next_symbol_if_eq(';') {
+                    continue;
+                }
+                options.push(self.next_option_statement()?);
+            }
+        } else {
+            self.expect_symbol(';')?;
+        }
+        Ok(Method {
+            name,
+            input_type,
+            output_type,
+            client_streaming,
+            server_streaming,
+            options,
+        })
+    }
+
+    fn next_service(&mut self) -> ParserResult<Service> {
+        self.expect_ident_eq("service")?;
+        let name = self.next_ident()?;
+        let mut service = Service { name, methods: Vec::new(), options: Vec::new() };
+        self.expect_symbol('{')?;
+        while !self.next_block_end()? {
+            if self.next_symbol_if_eq(';') {
+                continue;
+            } else if self.lookahead_is_ident("option") {
+                service.options.push(self.next_option_statement()?);
+            } else if self.lookahead_is_ident("rpc") {
+                service.methods.push(self.next_method()?);
+            } else {
+                return self.expecting("`rpc`");
+            }
+        }
+        Ok(service)
+    }
+
+    fn next_file(&mut self) -> ParserResult<FileDescriptor> {
+        // `syntax` must be the first statement
+        if self.lookahead_is_ident("syntax") {
+            self.syntax = self.next_syntax()?;
+        }
+
+        let mut file = FileDescriptor {
+            imports: Vec::new(),
+            package: String::new(),
+            syntax: self.syntax,
+            messages: Vec::new(),
+            enums: Vec::new(),
+            extensions: Vec::new(),
+            services: Vec::new(),
+            options: Vec::new(),
+        };
+        let mut has_package = false;
+
+        while self.peek().is_some() {
+            if self.next_symbol_if_eq(';') {
+                continue;
+            } else if self.lookahead_is_ident("import") {
+                file.imports.push(self.next_import()?);
+            } else if self.lookahead_is_ident("package") {
+                if has_package {
+                    return self.error("multiple package definitions".to_owned());
+                }
+                self.advance();
+                file.package = self.next_full_ident()?;
+                has_package = true;
+                self.expect_symbol(';')?;
+            } else if self.lookahead_is_ident("option") {
+                file.options.push(self.next_option_statement()?);
+            } else if self.lookahead_is_ident("message") {
+                file.messages.push(self.next_message()?);
+            } else if self.lookahead_is_ident("enum") {
+                file.enums.push(self.next_enum()?);
+            } else if self.lookahead_is_ident("service") {
+                file.services.push(self.next_service()?);
+            } else if self.lookahead_is_ident("extend") {
+                self.next_extend(&mut file.extensions)?;
+            } else {
+                return self.expecting("top-level statement");
+            }
+        }
+
+        Ok(file)
+    }
+}
+
+impl FileDescriptor {
+    /// Parse content of `.proto` file
+    pub fn parse(input: &str) -> ParserResult<FileDescriptor> {
+        Parser::new(input)?.next_file()
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(input: &str) -> FileDescriptor {
+        FileDescriptor::parse(input).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    fn parse_error(input: &str) -> String {
+        FileDescriptor::parse(input).unwrap_err().to_string()
+    }
+
+    fn parse_constant(input: &str) -> ProtobufConstant {
+        let file = parse(&format!("option foo = {};", input));
+        file.options[0].value.clone()
+    }
+
+    #[test]
+    fn test_str_lit() {
+        assert_eq!(
+            ProtobufConstant::String(b"a\x01\x0a\x7fb\xffc\"\'\\\n\x08\xd1\x84".to_vec()),
+            parse_constant(r#""a\1\12\177" 'b\xffc' "\"\'\\\n\bф""#));
+        assert_eq!(
+            "1:14: unterminated string literal",
+            parse_error("option foo = \"a\nb\";"));
+        assert_eq!("1:14: invalid escape sequence", parse_error(r#"option foo = "\q";"#));
+    }
+
+    #[test]
+    fn test_number_lit() {
+        assert_eq!(ProtobufConstant::U64(10), parse_constant("10"));
+        assert_eq!(ProtobufConstant::U64(16), parse_constant("0x10"));
+        assert_eq!(ProtobufConstant::U64(8), parse_constant("010"));
+        assert_eq!(ProtobufConstant::U64(0), parse_constant("0"));
+        assert_eq!(ProtobufConstant::I64(-10), parse_constant("-10"));
+        assert_eq!(ProtobufConstant::I64(i64::min_value()), parse_constant("-9223372036854775808"));
+        assert_eq!(ProtobufConstant::U64(u64::max_value()), parse_constant("18446744073709551615"));
+        assert_eq!(ProtobufConstant::F64(1.5), parse_constant("1.5"));
+        assert_eq!(ProtobufConstant::F64(0.5), parse_constant(".5"));
+        assert_eq!(ProtobufConstant::F64(-1e10), parse_constant("-1e10"));
+        assert_eq!(ProtobufConstant::F64(1.0), parse_constant("1."));
+        assert_eq!(ProtobufConstant::F64(-::std::f64::INFINITY), parse_constant("-inf"));
+        assert_eq!(ProtobufConstant::Ident("inf".to_owned()), parse_constant("inf"));
+        assert_eq!(
+            "1:14: need space between number and identifier",
+            parse_error("option foo = 1x;"));
+        assert_eq!(
+            "1:14: integer literal is too large",
+            parse_error("option foo = 18446744073709551616;"));
+    }
+
+    #[test]
+    fn test_option_names() {
+        let file = parse("option (foo.bar).baz.(.qux) = { a: 1 b { c: \"}\" } };");
+        let names: Vec<_> = file.options[0].name.iter()
+            .map(|p| (&p.name[..], p.is_extension))
+            .collect();
+        assert_eq!(
+            vec![("foo.bar", true), ("baz", false), (".qux", true)],
+            names);
+        assert_eq!(
+            ProtobufConstant::BracedExpr("a: 1 b { c: \"}\" }".to_owned()),
+            file.options[0].value);
+    }
+
+    #[test]
+    fn test_message() {
+        let file = parse(r#"
+            syntax = "proto2";
+            package foo.bar;
+            import public "a.proto";
+            import "b.proto";
+            // comment
+            message M {
+                /* block
+                   comment */
+                required int32 a = 1 [default = -1, (ext) = "x"];
+                repeated .foo.Bar b = 2;
+                map<string, M> m = 3;
+                oneof o {
+                    string s = 4;
+                    group G = 5 { optional int32 x = 1; }
+                }
+                extensions 100 to 199, 1000 to max;
+                reserved 6, 8 to 10;
+                reserved "z";
+                extend M { optional int32 e = 100; }
+                message N {}
+                enum E { option allow_alias = true; X = 0; Y = -1 [deprecated = true]; }
+                ;
+            }
+        "#);
+        assert_eq!("foo.bar", file.package);
+        assert_eq!(Syntax::Proto2, file.syntax);
+        assert_eq!(
+            vec![
+                Import { path: "a.proto".to_owned(), vis: ImportVis::Public },
+                Import { path: "b.proto".to_owned(), vis: ImportVis::Default },
+            ],
+            file.imports);
+
+        let m = &file.messages[0];
+        let fields = m.all_fields();
+        assert_eq!(
+            vec!["a", "b", "m", "s", "G"],
+            fields.iter().map(|f| &f.name[..]).collect::<Vec<_>>());
+        assert_eq!(Some(Rule::Required), fields[0].rule);
+        assert_eq!(2, fields[0].options.len());
+        assert_eq!(FieldType::MessageOrEnum(".foo.Bar".to_owned()), fields[1].typ);
+        assert_eq!(
+            FieldType::Map(Box::new((FieldType::String, FieldType::MessageOrEnum("M".to_owned())))),
+            fields[2].typ);
+        assert_eq!(None, fields[3].rule);
+        match fields[4].typ {
+            FieldType::Group(ref g) => assert_eq!("x", g.all_fields()[0].name),
+            ref t => panic!("not a group: {:?}", t),
+        }
+        let oneofs = m.fields.iter().filter(|f| match **f {
+            FieldOrOneOf::OneOf(..) => true,
+            FieldOrOneOf::Field(..) => false,
+        });
+        assert_eq!(1, oneofs.count());
+        assert_eq!(
+            vec![
+                FieldNumberRange { from: 100, to: 199 },
+                FieldNumberRange { from: 1000, to: FIELD_NUMBER_MAX },
+            ],
+            m.extension_ranges);
+        assert_eq!(
+            vec![FieldNumberRange { from: 6, to: 6 }, FieldNumberRange { from: 8, to: 10 }],
+            m.reserved_nums);
+        assert_eq!(vec!["z".to_owned()], m.reserved_names);
+        assert_eq!("M", m.extensions[0].extendee);
+        assert_eq!("N", m.messages[0].name);
+        assert_eq!(-1, m.enums[0].values[1].number);
+        assert_eq!(1, m.enums[0].values[1].options.len());
+        assert_eq!(1, m.enums[0].options.len());
+    }
+
+    #[test]
+    fn test_service() {
+        let file = parse(r#"
+            syntax = "proto3";
+            service S {
+                option deprecated = true;
+                rpc A (Req) returns (stream .p.Resp);
+                rpc B (stream Req) returns (Resp) { option deprecated = true; }
+            }
+        "#);
+        let s = &file.services[0];
+        assert_eq!(1, s.options.len());
+        assert_eq!("A", s.methods[0].name);
+        assert_eq!("Req", s.methods[0].input_type);
+        assert_eq!(".p.Resp", s.methods[0].output_type);
+        assert_eq!((false, true), (s.methods[0].client_streaming, s.methods[0].server_streaming));
+        assert_eq!((true, false), (s.methods[1].client_streaming, s.methods[1].server_streaming));
+        assert_eq!(1, s.methods[1].options.len());
+    }
+
+    #[test]
+    fn test_keywords_as_names() {
+        let file = parse(r#"
+            syntax = "proto3";
+            message message { optional int32 optional = 1; map map = 2; string group = 3; }
+        "#);
+        let fields = file.messages[0].all_fields();
+        assert_eq!(Some(Rule::Optional), fields[0].rule);
+        assert_eq!("optional", fields[0].name);
+        assert_eq!(FieldType::MessageOrEnum("map".to_owned()), fields[1].typ);
+        assert_eq!("group", fields[2].name);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            "1:13: expecting label: `required`, `optional` or `repeated`",
+            parse_error("message M { int32 a = 1; }"));
+        assert_eq!(
+            "1:32: required fields are not allowed in proto3",
+            parse_error("syntax = \"proto3\"; message M { required int32 a = 1; }"));
+        assert_eq!(
+            "1:23: fields in oneof must not have labels",
+            parse_error("message M { oneof o { optional int32 a = 1; } }"));
+        assert_eq!(
+            "1:28: group names must start with a capital letter",
+            parse_error("message M { optional group g = 1 {} }"));
+        assert_eq!(
+            "1:32: field number must be in range 1..536870911",
+            parse_error("message M { optional int32 a = 0; }"));
+        assert_eq!("1:12: expecting `}`, got end of file", parse_error("message M {"));
+        assert_eq!(
+            "1:1: expecting top-level statement, got `foo`",
+            parse_error("foo bar;"));
+    }
+}
@@ -0,0 +1,342 @@
+//! Names declared in a file and its dependencies, looked up like `protoc` does
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use protobuf::descriptor::DescriptorProto;
+use protobuf::descriptor::EnumDescriptorProto;
+use protobuf::descriptor::FieldDescriptorProto;
+use protobuf::descriptor::FileDescriptorProto;
+
+use convert::ConvertError;
+use convert::ConvertResult;
+
+
+pub enum Symbol {
+    Package,
+    Message,
+    /// Enum with its value names
+    Enum(Vec<String>),
+    Service,
+    /// Extension field, `extendee` is already resolved
+    Extension(FieldDescriptorProto),
+    /// Field, oneof, enum value or method: only reserves the name
+    Other,
+}
+
+impl Symbol {
+    /// Symbol which may contain other symbols
+    fn is_aggregate(&self) -> bool {
+        match *self {
+            Symbol::Package | Symbol::Message | Symbol::Enum(..) | Symbol::Service => true,
+            Symbol::Extension(..) | Symbol::Other => false,
+        }
+    }
+
+    pub fn is_type(&self) -> bool {
+        match *self {
+            Symbol::Message | Symbol::Enum(..) => true,
+            _ => false,
+        }
+    }
+}
+
+struct SymbolEntry {
+    symbol: Symbol,
+    /// File where the symbol is declared, first such file for packages
+    file: String,
+}
+
+/// Symbols of a file being converted and all files it imports transitively
+pub struct Symbols {
+    symbols: HashMap<String, SymbolEntry>,
+    /// Name of the file being converted
+    current_file: String,
+    /// The file being converted, its direct imports and public imports of these
+    visible_files: HashSet<String>,
+    /// Packages of visible files
+    visible_packages: Vec<String>,
+}
+
+fn join(scope: &str, name: &str) -> String {
+    if scope.is_empty() {
+        name.to_owned()
+    } else {
+        format!("{}.{}", scope, name)
+    }
+}
+
+impl Symbols {
+    /// `deps` are all files imported by `file` directly or indirectly
+    pub fn new(file: &FileDescriptorProto, deps: &[FileDescriptorProto]) -> ConvertResult<Symbols> {
+        let mut visible_files = HashSet::new();
+        visible_files.insert(file.get_name().to_owned());
+        let mut pending: Vec<&str> = file.get_dependency().iter().map(|d| &d[..]).collect();
+        while let Some(name) = pending.pop() {
+            if !visible_files.insert(name.to_owned()) {
+                continue;
+            }
+            if let Some(dep) = deps.iter().find(|d| d.get_name() == name) {
+                for &index in dep.get_public_dependency() {
+                    pending.push(&dep.get_dependency()[index as usize]);
+                }
+            }
+        }
+
+        let visible_packages = deps.iter()
+            .chain(Some(file))
+            .filter(|f| visible_files.contains(f.get_name()))
+            .map(|f| f.get_package().to_owned())
+            .collect();
+
+        let mut symbols = Symbols {
+            symbols: HashMap::new(),
+            current_file: file.get_name().to_owned(),
+            visible_files,
+            visible_packages,
+        };
+        for f in deps.iter().chain(Some(file)) {
+            symbols.add_file(f)?;
+        }
+        Ok(symbols)
+    }
+
+    fn add(&mut self, full_name: String, symbol: Symbol, file: &str) -> ConvertResult<()> {
+        if let Some(existing) = self.symbols.get(&full_name) {
+            if let (&Symbol::Package, &Symbol::Package) = (&existing.symbol, &symbol) {
+                return Ok(());
+            }
+            return Err(ConvertError::new(if existing.file == file {
+                format!("`{}` is already defined", full_name)
+            } else {
+                format!("`{}` is already defined in file `{}`", full_name, existing.file)
+            }));
+        }
+        let entry = SymbolEntry { symbol, file: file.to_owned() };
+        self.symbols.insert(full_name, entry);
+        Ok(())
+    }
+
+    fn add_file(&mut self, file: &FileDescriptorProto) -> ConvertResult<()> {
+        let name = file.get_name();
+        let package = file.get_package();
+        if !package.is_empty() {
+            let mut end = 0;
+            while let Some(dot) = package[end..].find('.') {
+                end += dot;
+                self.add(package[..end].to_owned(), Symbol::Package, name)?;
+                end += 1;
+            }
+            self.add(package.to_owned(), Symbol::Package, name)?;
+        }
+
+        for message in file.get_message_type() {
+            self.add_message(package, message, name)?;
+        }
+        for e in file.get_enum_type() {
+            self.add_enum(package, e, name)?;
+        }
+        for extension in file.get_extension() {
+            self.add_extension(package, extension, name)?;
+        }
+        for service in file.get_service() {
+            let service_name = join(package, service.get_name());
+            for method in service.get_method() {
+                self.add(join(&service_name, method.get_name()), Symbol::Other, name)?;
+            }
+            self.add(service_name, Symbol::Service, name)?;
+        }
+        Ok(())
+    }
+
+    fn add_message(&mut self, scope: &str, message: &DescriptorProto, file: &str)
+        -> ConvertResult<()>
+    {
+        let full_name = join(scope, message.get_name());
+        self.add(full_name.clone(), Symbol::Message, file)?;
+        for field in message.get_field() {
+            self.add(join(&full_name, field.get_name()), Symbol::Other, file)?;
+        }
+        for oneof in message.get_oneof_decl() {
+            self.add(join(&full_name, oneof.get_name()), Symbol::Other, file)?;
+        }
+        for nested in message.get_nested_type() {
+            self.add_message(&full_name, nested, file)?;
+        }
+        for e in message.get_enum_type() {
+            self.add_enum(&full_name, e, file)?;
+        }
+        for extension in message.get_extension() {
+            self.add_extension(&full_name, extension, file)?;
+        }
+        Ok(())
+    }
+
+    fn add_enum(&mut self, scope: &str, e: &EnumDescriptorProto, file: &str)
+        -> ConvertResult<()>
+    {
+        let value_names: Vec<String> =
+            e.get_value().iter().map(|v| v.get_name().to_owned()).collect();
+        self.add(join(scope, e.get_name()), Symbol::Enum(value_names.clone()), file)?;
+        // enum values are siblings of their enum
+        for value_name in value_names {
+            self.add(join(scope, &value_name), Symbol::Other, file)?;
+        }
+        Ok(())
+    }
+
+    fn add_extension(&mut self, scope: &str, extension: &FieldDescriptorProto, file: &str)
+        -> ConvertResult<()>
+    {
+        let symbol = Symbol::Extension(extension.clone());
+        self.add(join(scope, extension.get_name()), symbol, file)
+    }
+
+    /// Symbol declared in visible file
+    fn find_visible(&self, full_name: &str) -> Option<&Symbol> {
+        let entry = self.symbols.get(full_name)?;
+        if self.visible_files.contains(&entry.file) {
+            return Some(&entry.symbol);
+        }
+        if let Symbol::Package = entry.symbol {
+            // package may also be declared by another visible file
+            let prefix = format!("{}.", full_name);
+            if self.visible_packages.iter().any(|p| p == full_name || p.starts_with(&prefix)) {
+                return Some(&entry.symbol);
+            }
+        }
+        None
+    }
+
+    /// Find symbol by name relative to element `relative_to`: like in C++, name is looked up
+    /// in the scope containing the element, then in outer scopes.
+    ///
+    /// If `types_only` is set, simple names resolved to non-types are skipped.
+    ///
+    /// Returns full name without leading dot and the symbol.
+    pub fn lookup(&self, name: &str, relative_to: &str, types_only: bool)
+        -> ConvertResult<(String, &Symbol)>
+    {
+        let found = if name.starts_with('.') {
+            self.find_visible(&name[1..]).map(|s| (name[1..].to_owned(), s))
+        } else {
+            self.lookup_relative(name, relative_to, types_only)
+        };
+        found.ok_or_else(|| self.not_defined(name))
+    }
+
+    fn lookup_relative(&self, name: &str, relative_to: &str, types_only: bool)
+        -> Option<(String, &Symbol)>
+    {
+        let first_part = match name.find('.') {
+            Some(dot) => &name[..dot],
+            None => name,
+        };
+
+        let mut scope = relative_to;
+        loop {
+            scope = match scope.rfind('.') {
+                Some(dot) => &scope[..dot],
+                None => return self.find_visible(name).map(|s| (name.to_owned(), s)),
+            };
+
+            let first_full_name = join(scope, first_part);
+            if let Some(symbol) = self.find_visible(&first_full_name) {
+                if first_part.len() < name.len() {
+                    // found the first part of compound name, rest must be inside it
+                    if symbol.is_aggregate() {
+                        let full_name = join(scope, name);
+                        return self.find_visible(&full_name).map(|s| (full_name, s));
+                    }
+                } else if !types_only || symbol.is_type() {
+                    return Some((first_full_name, symbol));
+                }
+            }
+        }
+    }
+
+    fn not_defined(&self, name: &str) -> ConvertError {
+        let full_name = name.trim_left_matches('.');
+        let hidden = self.symbols.iter()
+            .find(|&(n, _)| n == full_name || n.ends_with(&format!(".{}", full_name)));
+        match hidden {
+            Some((_, entry)) if !self.visible_files.contains(&entry.file) => {
+                ConvertError::new(format!(
+                    "`{}` seems to be defined in `{}`, which is not imported by `{}`",
+                    name, entry.file, self.current_file))
+            }
+            _ => ConvertError::new(format!("`{}` is not defined", name)),
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use protobuf::descriptor::FieldDescriptorProto;
+
+    fn message(name: &str, nested: Vec<DescriptorProto>) -> DescriptorProto {
+        let mut m = DescriptorProto::new();
+        m.set_name(name.to_owned());
+        m.set_nested_type(nested.into());
+        m
+    }
+
+    fn file(name: &str, package: &str, messages: Vec<DescriptorProto>) -> FileDescriptorProto {
+        let mut f = FileDescriptorProto::new();
+        f.set_name(name.to_owned());
+        f.set_package(package.to_owned());
+        f.set_message_type(messages.into());
+        f
+    }
+
+    fn lookup(symbols: &Symbols, name: &str, relative_to: &str) -> String {
+        match symbols.lookup(name, relative_to, true) {
+            Ok((full_name, _)) => full_name,
+            Err(e) => e.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_lookup() {
+        let dep = file("dep.proto", "foo.bar", vec![message("Dep", vec![])]);
+
+        let mut dep_in_outer = message("Dep", vec![]);
+        let mut field = FieldDescriptorProto::new();
+        field.set_name("Inner".to_owned());
+        dep_in_outer.mut_field().push(field);
+
+        let mut f = file("f.proto", "foo.baz", vec![
+            message("Outer", vec![message("Inner", vec![]), dep_in_outer]),
+            message("Inner", vec![]),
+        ]);
+        f.mut_dependency().push("dep.proto".to_owned());
+        let hidden = file("hidden.proto", "hidden", vec![message("Hidden", vec![])]);
+
+        let symbols = Symbols::new(&f, &[dep, hidden]).unwrap();
+
+        // innermost scope first, field `Outer.Dep.Inner` is not a type
+        assert_eq!("foo.baz.Outer.Inner", lookup(&symbols, "Inner", "foo.baz.Outer.Dep.field"));
+        assert_eq!("foo.baz.Inner", lookup(&symbols, "Inner", "foo.baz.Outer"));
+        assert_eq!("foo.baz.Inner", lookup(&symbols, ".foo.baz.Inner", "foo.baz.Outer.Dep.f"));
+        // first part of compound name is resolved like simple name
+        assert_eq!("foo.bar.Dep", lookup(&symbols, "bar.Dep", "foo.baz.Outer.f"));
+        assert_eq!("`Outer.Dep.E` is not defined",
+            lookup(&symbols, "Outer.Dep.E", "foo.baz.Inner.f"));
+        assert_eq!(
+            "`hidden.Hidden` seems to be defined in `hidden.proto`, \
+            which is not imported by `f.proto`",
+            lookup(&symbols, "hidden.Hidden", "foo.baz.Inner.f"));
+    }
+
+    #[test]
+    fn test_duplicate() {
+        let f = file("f.proto", "foo", vec![message("A", vec![]), message("A", vec![])]);
+        match Symbols::new(&f, &[]) {
+            Err(e) => assert_eq!("`foo.A` is already defined", e.to_string()),
+            Ok(..) => panic!("must fail"),
+        }
+    }
+}
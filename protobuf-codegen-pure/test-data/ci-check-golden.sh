@@ -0,0 +1,22 @@
+#!/bin/sh -ex
+
+# Download protoc $PROTOC_GOLDEN_VERSION, regenerate goldens with it
+# and compare descriptors produced by protobuf-codegen-pure with protoc output
+
+cd $(dirname $0)
+
+test -n "$PROTOC_GOLDEN_VERSION"
+
+dir=$(mktemp -d)
+curl -sL -o $dir/protoc.zip \
+    https://github.com/protocolbuffers/protobuf/releases/download/v$PROTOC_GOLDEN_VERSION/protoc-$PROTOC_GOLDEN_VERSION-linux-x86_64.zip
+unzip -q $dir/protoc.zip -d $dir
+
+PATH="$dir/bin:$PATH" ./regenerate.sh
+
+git diff --stat .
+
+cd ..
+cargo test
+
+# vim: set ts=4 sw=4 et:
@@ -0,0 +1,28 @@
+#!/bin/sh -e
+
+# Regenerate golden descriptors `test_*.pbtxt` with `protoc`,
+# proto3 `optional` fields require `protoc` 3.15 or later
+
+cd $(dirname $0)
+
+die() {
+    echo "$@" >&2
+    exit 1
+}
+
+protoc_ver=$(protoc --version)
+case "$protoc_ver" in
+    "libprotoc 3."1[5-9]* | "libprotoc 3."[2-9][0-9]* | "libprotoc "[1-9][0-9].*) ;;
+    *)
+        die "you need to use protoc 3.15 or later to regenerate goldens, found: $protoc_ver"
+    ;;
+esac
+
+for name in test_proto2 test_proto3 test_custom_options; do
+    protoc -I. -I../../proto --descriptor_set_out=/dev/stdout $name.proto \
+        | protoc -I. -I../../proto --decode=google.protobuf.FileDescriptorSet \
+            google/protobuf/descriptor.proto $name.proto \
+        > $name.pbtxt
+done
+
+# vim: set ts=4 sw=4 et:
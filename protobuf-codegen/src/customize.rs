@@ -17,6 +17,20 @@ pub struct Customize {
     pub carllerche_bytes_for_bytes: Option<bool>,
     /// Use `bytes::Bytes` for `string` fields
     pub carllerche_bytes_for_string: Option<bool>,
+    /// Generate traits, clients and method descriptors for services
+    pub gen_services: Option<bool>,
+    /// Generate borrowed views `FooRef<'a>` of messages
+    pub gen_views: Option<bool>,
+    /// Emit `serde` derives for messages and enums (under `with-serde` feature)
+    pub serde_derive: Option<bool>,
+    /// Generate singular fields of wrapper types like `google.protobuf.Int32Value`
+    /// as `Option` of wrapped value. Wire format, reflection, text format and JSON
+    /// are unchanged: reflection creates wrapper messages when such fields are read.
+    pub wrappers_as_option: Option<bool>,
+    /// Generate proto3 enum fields as `::protobuf::EnumOrUnknown<E>`,
+    /// so unknown enum numbers are kept in the field instead of unknown fields.
+    /// Applies to singular, repeated and map value fields, but not to oneof fields.
+    pub enum_or_unknown: Option<bool>,
 }
 
 impl Customize {
@@ -37,6 +51,21 @@ impl Customize {
         if let Some(v) = that.carllerche_bytes_for_string {
             self.carllerche_bytes_for_string = Some(v);
         }
+        if let Some(v) = that.gen_services {
+            self.gen_services = Some(v);
+        }
+        if let Some(v) = that.gen_views {
+            self.gen_views = Some(v);
+        }
+        if let Some(v) = that.serde_derive {
+            self.serde_derive = Some(v);
+        }
+        if let Some(v) = that.wrappers_as_option {
+            self.wrappers_as_option = Some(v);
+        }
+        if let Some(v) = that.enum_or_unknown {
+            self.enum_or_unknown = Some(v);
+        }
     }
 
     /// Update unset fields of self with fields from other customize
@@ -54,12 +83,18 @@ pub fn customize_from_rustproto_for_message(source: &MessageOptions) -> Customiz
     let generate_accessors = rustproto::exts::generate_accessors.get(source);
     let carllerche_bytes_for_bytes = rustproto::exts::carllerche_bytes_for_bytes.get(source);
     let carllerche_bytes_for_string = rustproto::exts::carllerche_bytes_for_string.get(source);
+    let serde_derive = rustproto::exts::serde_derive.get(source);
     Customize {
         expose_oneof,
         expose_fields,
         generate_accessors,
         carllerche_bytes_for_bytes,
         carllerche_bytes_for_string,
+        gen_services: None,
+        gen_views: None,
+        serde_derive,
+        wrappers_as_option: None,
+        enum_or_unknown: None,
     }
 }
 
@@ -69,12 +104,18 @@ pub fn customize_from_rustproto_for_field(source: &FieldOptions) -> Customize {
     let generate_accessors = rustproto::exts::generate_accessors_field.get(source);
     let carllerche_bytes_for_bytes = rustproto::exts::carllerche_bytes_for_bytes_field.get(source);
     let carllerche_bytes_for_string = rustproto::exts::carllerche_bytes_for_string_field.get(source);
+    let serde_derive = None;
     Customize {
         expose_oneof,
         expose_fields,
         generate_accessors,
         carllerche_bytes_for_bytes,
         carllerche_bytes_for_string,
+        gen_services: None,
+        gen_views: None,
+        serde_derive,
+        wrappers_as_option: None,
+        enum_or_unknown: None,
     }
 }
 
@@ -84,11 +125,21 @@ pub fn customize_from_rustproto_for_file(source: &FileOptions) -> Customize {
     let generate_accessors = rustproto::exts::generate_accessors_all.get(source);
     let carllerche_bytes_for_bytes = rustproto::exts::carllerche_bytes_for_bytes_all.get(source);
     let carllerche_bytes_for_string = rustproto::exts::carllerche_bytes_for_string_all.get(source);
+    let serde_derive = rustproto::exts::serde_derive_all.get(source);
+    let wrappers_as_option = rustproto::exts::wrappers_as_option_all.get(source);
+    let gen_views = rustproto::exts::gen_views_all.get(source);
+    let gen_services = rustproto::exts::gen_services_all.get(source);
+    let enum_or_unknown = rustproto::exts::enum_or_unknown_all.get(source);
     Customize {
         expose_oneof,
         expose_fields,
         generate_accessors,
         carllerche_bytes_for_bytes,
         carllerche_bytes_for_string,
+        gen_services,
+        gen_views,
+        serde_derive,
+        wrappers_as_option,
+        enum_or_unknown,
     }
 }
@@ -5,6 +5,7 @@ use protobuf::descriptorx::*;
 
 use super::code_writer::*;
 use super::customize::Customize;
+use serde;
 
 
 #[derive(Clone)]
@@ -45,13 +46,14 @@ pub struct EnumGen<'a> {
     enum_with_scope: &'a EnumWithScope<'a>,
     type_name: String,
     lite_runtime: bool,
+    customize: Customize,
 }
 
 impl<'a> EnumGen<'a> {
     pub fn new(
         enum_with_scope: &'a EnumWithScope<'a>,
         current_file: &FileDescriptorProto,
-        _customize: &Customize
+        customize: &Customize
     ) -> EnumGen<'a> {
         let rust_name = if enum_with_scope.get_scope().get_file_descriptor().get_name() ==
             current_file.get_name()
@@ -76,6 +78,7 @@ impl<'a> EnumGen<'a> {
                 .get_options()
                 .get_optimize_for() ==
                 FileOptions_OptimizeMode::LITE_RUNTIME,
+            customize: customize.clone(),
         }
     }
 
@@ -146,6 +149,7 @@ impl<'a> EnumGen<'a> {
             );
         }
         w.derive(&derive);
+        serde::write_serde_derive(w, &self.customize);
         let ref type_name = self.type_name;
         w.expr_block(&format!("pub enum {}", type_name), |w| {
             for value in self.values_all() {
@@ -206,6 +210,11 @@ impl<'a> EnumGen<'a> {
                 w.write_line("values");
             });
 
+            w.write_line("");
+            w.def_fn("get_values() -> ::std::option::Option<&'static [Self]>", |w| {
+                w.write_line("::std::option::Option::Some(<Self as ::protobuf::ProtobufEnum>::values())");
+            });
+
             if !self.lite_runtime {
                 w.write_line("");
                 let ref type_name = self.type_name;
@@ -215,6 +224,11 @@ impl<'a> EnumGen<'a> {
                         w.write_line(&format!("::protobuf::reflect::EnumDescriptor::new(\"{}\", file_descriptor_proto())", type_name));
                     });
                 });
+
+                w.write_line("");
+                w.def_fn(&format!("get_enum_descriptor_static(_: ::std::option::Option<{}>) -> ::std::option::Option<&'static ::protobuf::reflect::EnumDescriptor>", type_name), |w| {
+                    w.write_line(&format!("::std::option::Option::Some(<{} as ::protobuf::ProtobufEnum>::enum_descriptor_static(::std::option::Option::None))", type_name));
+                });
             }
         });
     }
@@ -93,3 +93,18 @@ pub fn write_extensions(file: &FileDescriptorProto, root_scope: &RootScope, w: &
         }
     });
 }
+
+
+/// Calls registering extensions of the file in `::protobuf::reflect::registry`
+pub fn write_register_extensions_calls(file: &FileDescriptorProto, w: &mut CodeWriter) {
+    for field in file.get_extension() {
+        if field.get_field_type() == FieldDescriptorProto_Type::TYPE_GROUP {
+            continue;
+        }
+
+        w.write_line(&format!(
+            "exts::{}.register(file_descriptor_proto());",
+            field.get_name()
+        ));
+    }
+}
@@ -12,11 +12,13 @@ use super::code_writer::CodeWriter;
 use super::customize::Customize;
 use super::customize::customize_from_rustproto_for_field;
 use oneof::OneofField;
+use well_known_types;
 
 
 fn type_is_copy(field_type: FieldDescriptorProto_Type) -> bool {
     match field_type {
         FieldDescriptorProto_Type::TYPE_MESSAGE |
+        FieldDescriptorProto_Type::TYPE_GROUP |
         FieldDescriptorProto_Type::TYPE_STRING |
         FieldDescriptorProto_Type::TYPE_BYTES => false,
         _ => true,
@@ -63,7 +65,7 @@ fn field_type_wire_type(field_type: FieldDescriptorProto_Type) -> wire_format::W
         FieldDescriptorProto_Type::TYPE_STRING => WireTypeLengthDelimited,
         FieldDescriptorProto_Type::TYPE_BYTES => WireTypeLengthDelimited,
         FieldDescriptorProto_Type::TYPE_MESSAGE => WireTypeLengthDelimited,
-        FieldDescriptorProto_Type::TYPE_GROUP => WireTypeLengthDelimited, // not true
+        FieldDescriptorProto_Type::TYPE_GROUP => WireTypeStartGroup,
     }
 }
 
@@ -214,20 +216,21 @@ pub struct EntryKeyValue(FieldElem, FieldElem);
 #[derive(Clone, Debug)]
 pub enum FieldElem {
     Primitive(FieldDescriptorProto_Type, PrimitiveTypeVariant),
-    // name, file name, entry
+    // message or group: name, file name, entry
     Message(String, String, Option<Box<EntryKeyValue>>),
     // name, file name, default value
     Enum(String, String, String),
-    Group,
+    // enum stored as `EnumOrUnknown`: name, file name, default value
+    EnumOrUnknown(String, String, String),
 }
 
 impl FieldElem {
     fn proto_type(&self) -> FieldDescriptorProto_Type {
         match *self {
             FieldElem::Primitive(t, ..) => t,
-            FieldElem::Group => FieldDescriptorProto_Type::TYPE_GROUP,
             FieldElem::Message(..) => FieldDescriptorProto_Type::TYPE_MESSAGE,
-            FieldElem::Enum(..) => FieldDescriptorProto_Type::TYPE_ENUM,
+            FieldElem::Enum(..) |
+            FieldElem::EnumOrUnknown(..) => FieldDescriptorProto_Type::TYPE_ENUM,
         }
     }
 
@@ -237,7 +240,8 @@ impl FieldElem {
 
     pub fn rust_storage_type(&self) -> RustType {
         match *self {
-            FieldElem::Primitive(t, PrimitiveTypeVariant::Default) => rust_name(t),
+            FieldElem::Primitive(t, PrimitiveTypeVariant::Default) |
+            FieldElem::Primitive(t, PrimitiveTypeVariant::Wrapper) => rust_name(t),
             FieldElem::Primitive(
                 FieldDescriptorProto_Type::TYPE_STRING,
                 PrimitiveTypeVariant::Carllerche,
@@ -247,11 +251,13 @@ impl FieldElem {
                 PrimitiveTypeVariant::Carllerche,
             ) => RustType::Bytes,
             FieldElem::Primitive(.., PrimitiveTypeVariant::Carllerche) => unreachable!(),
-            FieldElem::Group => RustType::Group,
             FieldElem::Message(ref name, ..) => RustType::Message(name.clone()),
             FieldElem::Enum(ref name, _, ref default_value) => {
                 RustType::Enum(name.clone(), default_value.clone())
             }
+            FieldElem::EnumOrUnknown(ref name, _, ref default_value) => {
+                RustType::EnumOrUnknown(name.clone(), default_value.clone())
+            }
         }
     }
 
@@ -260,15 +266,32 @@ impl FieldElem {
             FieldElem::Primitive(t, v) => ProtobufTypeGen::Primitive(t, v),
             FieldElem::Message(ref name, ..) => ProtobufTypeGen::Message(name.clone()),
             FieldElem::Enum(ref name, ..) => ProtobufTypeGen::Enum(name.clone()),
-            FieldElem::Group => unreachable!(),
+            FieldElem::EnumOrUnknown(ref name, ..) => ProtobufTypeGen::EnumOrUnknown(name.clone()),
         }
     }
 
     /// implementation of ProtobufType trait
-    fn lib_protobuf_type(&self) -> String {
+    pub fn lib_protobuf_type(&self) -> String {
         self.protobuf_type_gen().rust_type()
     }
 
+    /// Store enum as `EnumOrUnknown`, other elements are unchanged
+    fn enum_or_unknown(self) -> FieldElem {
+        match self {
+            FieldElem::Enum(name, file, default_value) => {
+                FieldElem::EnumOrUnknown(name, file, default_value)
+            }
+            elem => elem,
+        }
+    }
+
+    fn is_enum_or_unknown(&self) -> bool {
+        match *self {
+            FieldElem::EnumOrUnknown(..) => true,
+            _ => false,
+        }
+    }
+
     fn primitive_type_variant(&self) -> PrimitiveTypeVariant {
         match self {
             &FieldElem::Primitive(_, v) => v,
@@ -283,9 +306,7 @@ fn field_elem(
     parse_map: bool,
     customize: &Customize,
 ) -> (FieldElem, Option<EnumValueGen>) {
-    if field.field.get_field_type() == FieldDescriptorProto_Type::TYPE_GROUP {
-        (FieldElem::Group, None)
-    } else if field.field.has_type_name() {
+    if field.field.has_type_name() {
         let message_or_enum = root_scope.find_message_or_enum(field.field.get_type_name());
         let file_name = message_or_enum
             .get_scope()
@@ -319,6 +340,10 @@ fn field_elem(
                     None,
                 )
             }
+            (
+                FieldDescriptorProto_Type::TYPE_GROUP,
+                MessageOrEnumWithScope::Message(..),
+            ) => (FieldElem::Message(rust_relative_name, file_name, None), None),
             (
                 FieldDescriptorProto_Type::TYPE_ENUM,
                 MessageOrEnumWithScope::Enum(enum_with_scope),
@@ -408,6 +433,8 @@ pub struct FieldGen<'a> {
     wire_type: wire_format::WireType,
     enum_default_value: Option<EnumValueGen>,
     pub kind: FieldKind,
+    // rust type of wrapper message of field stored as `Option` of wrapped value
+    wrapper_message: Option<String>,
     pub expose_field: bool,
     pub generate_accessors: bool,
 }
@@ -426,25 +453,54 @@ impl<'a> FieldGen<'a> {
         let expose_field = customize.expose_fields.unwrap_or(default_expose_field);
         let generate_accessors = customize.generate_accessors.unwrap_or(true);
 
+        // singular wrapper field is stored as `Option` of wrapped value
+        let wrapped_type = if customize.wrappers_as_option.unwrap_or(false) &&
+            field.field.get_label() != FieldDescriptorProto_Label::LABEL_REPEATED &&
+            field.oneof().is_none()
+        {
+            well_known_types::wrapped_type(field.field.get_type_name())
+        } else {
+            None
+        };
+        let proto_type = wrapped_type.unwrap_or(field.field.get_field_type());
+        let wrapper_message = match (wrapped_type, &elem) {
+            (Some(..), &FieldElem::Message(ref name, ..)) => Some(name.clone()),
+            _ => None,
+        };
+
+        // proto3 enums are open, unknown numbers may be kept in the field
+        let enum_or_unknown = customize.enum_or_unknown.unwrap_or(false) &&
+            field.message.scope.file_scope.syntax() == Syntax::PROTO3;
+        let storage_elem = |elem: FieldElem| match enum_or_unknown {
+            true => elem.enum_or_unknown(),
+            false => elem,
+        };
+
         let kind = if field.field.get_label() == FieldDescriptorProto_Label::LABEL_REPEATED {
             match (elem, true) {
                 // map field
                 (FieldElem::Message(name, _, Some(key_value)), true) => FieldKind::Map(MapField {
                     name: name,
                     key: key_value.0.clone(),
-                    value: key_value.1.clone(),
+                    value: storage_elem(key_value.1.clone()),
                 }),
                 // regular repeated field
                 (elem, _) => FieldKind::Repeated(RepeatedField {
-                    elem,
+                    elem: storage_elem(elem),
                     packed: field.field.get_options().get_packed(),
                 }),
             }
         } else if let Some(oneof) = field.oneof() {
             FieldKind::Oneof(OneofField::parse(&oneof, field.field, elem))
         } else {
+            let elem = match wrapped_type {
+                Some(t) => FieldElem::Primitive(t, PrimitiveTypeVariant::Wrapper),
+                None => storage_elem(elem),
+            };
+            // proto3 `optional` fields track presence like proto2 fields
             let flag = if field.message.scope.file_scope.syntax() == Syntax::PROTO3 &&
-                field.field.get_field_type() != FieldDescriptorProto_Type::TYPE_MESSAGE
+                field.field.get_field_type() != FieldDescriptorProto_Type::TYPE_MESSAGE &&
+                !field.field.get_proto3_optional()
             {
                 SingularFieldFlag::WithoutFlag
             } else {
@@ -462,11 +518,12 @@ impl<'a> FieldGen<'a> {
             root_scope: root_scope,
             syntax: field.message.get_scope().file_scope.syntax(),
             rust_name: field.rust_name(),
-            proto_type: field.field.get_field_type(),
+            proto_type: proto_type,
             wire_type: field_type_wire_type(field.field.get_field_type()),
             enum_default_value: enum_default_value,
             proto_field: field,
             kind: kind,
+            wrapper_message: wrapper_message,
             expose_field: expose_field,
             generate_accessors: generate_accessors,
         }
@@ -497,6 +554,16 @@ impl<'a> FieldGen<'a> {
         }
     }
 
+    /// Singular wrapper message field stored as `Option` of wrapped value
+    pub fn is_wrapper(&self) -> bool {
+        match self.kind {
+            FieldKind::Singular(ref singular) => {
+                singular.elem.primitive_type_variant() == PrimitiveTypeVariant::Wrapper
+            }
+            _ => false,
+        }
+    }
+
     fn is_repeated_not_map(&self) -> bool {
         match self.kind {
             FieldKind::Repeated(..) => true,
@@ -650,6 +717,15 @@ impl<'a> FieldGen<'a> {
         }
     }
 
+    // data is message encoded as length-delimited or as group
+    pub fn is_message_or_group(&self) -> bool {
+        match self.proto_type {
+            FieldDescriptorProto_Type::TYPE_MESSAGE |
+            FieldDescriptorProto_Type::TYPE_GROUP => true,
+            _ => false,
+        }
+    }
+
     // data is enum
     fn is_enum(&self) -> bool {
         match self.proto_type {
@@ -693,7 +769,12 @@ impl<'a> FieldGen<'a> {
     fn default_value_from_proto(&self) -> Option<String> {
         assert!(self.is_singular() || self.is_oneof());
         if self.enum_default_value.is_some() {
-            Some(self.enum_default_value.as_ref().unwrap().rust_name_outer())
+            let enum_value = self.enum_default_value.as_ref().unwrap().rust_name_outer();
+            if self.elem().is_enum_or_unknown() {
+                Some(format!("::protobuf::EnumOrUnknown::new({})", enum_value))
+            } else {
+                Some(enum_value)
+            }
         } else if self.proto_field.field.has_default_value() {
             let proto_default = self.proto_field.field.get_default_value();
             Some(match self.proto_type {
@@ -752,7 +833,7 @@ impl<'a> FieldGen<'a> {
     }
 
     // default value to be returned from fn get_xxx
-    fn get_xxx_default_value_rust(&self) -> String {
+    pub fn get_xxx_default_value_rust(&self) -> String {
         assert!(self.is_singular() || self.is_oneof());
         self.default_value_from_proto()
             .unwrap_or_else(|| self.get_xxx_return_type().default_value())
@@ -772,6 +853,7 @@ impl<'a> FieldGen<'a> {
     pub fn reconstruct_def(&self) -> String {
         let prefix = match (self.proto_field.field.get_label(), self.syntax) {
             (FieldDescriptorProto_Label::LABEL_REPEATED, _) => "repeated ",
+            (_, Syntax::PROTO3) if self.proto_field.field.get_proto3_optional() => "optional ",
             (_, Syntax::PROTO3) => "",
             (FieldDescriptorProto_Label::LABEL_OPTIONAL, _) => "optional ",
             (FieldDescriptorProto_Label::LABEL_REQUIRED, _) => "required ",
@@ -815,7 +897,7 @@ impl<'a> FieldGen<'a> {
                     // TODO: old style, needed because of default instance
 
                     AccessorFn {
-                        name: "make_singular_message_accessor".to_owned(),
+                        name: "make_singular_message_accessor_mut".to_owned(),
                         type_params: vec![name.clone()],
                         style: AccessorStyle::HasGet,
                     }
@@ -827,6 +909,16 @@ impl<'a> FieldGen<'a> {
                     }
                 }
             }
+            FieldKind::Singular(SingularField { ref elem, .. }) if self.is_wrapper() => {
+                AccessorFn {
+                    name: "make_wrapper_accessor".to_owned(),
+                    type_params: vec![
+                        elem.lib_protobuf_type(),
+                        self.wrapper_message.clone().unwrap(),
+                    ],
+                    style: AccessorStyle::Lambda,
+                }
+            }
             FieldKind::Singular(SingularField {
                 ref elem,
                 flag: SingularFieldFlag::WithFlag { .. },
@@ -856,7 +948,7 @@ impl<'a> FieldGen<'a> {
                     t => panic!("unexpected field type: {}", t),
                 };
 
-                let name = format!("make_singular_{}_accessor", suffix);
+                let name = format!("make_singular_{}_accessor_mut", suffix);
 
                 let mut type_params = Vec::new();
                 match elem {
@@ -896,7 +988,8 @@ impl<'a> FieldGen<'a> {
             Some(data_size) => format!("{}", data_size + self.tag_size()),
             None => {
                 match self.proto_type {
-                    FieldDescriptorProto_Type::TYPE_MESSAGE => panic!("not a single-liner"),
+                    FieldDescriptorProto_Type::TYPE_MESSAGE |
+                    FieldDescriptorProto_Type::TYPE_GROUP => panic!("not a single-liner"),
                     FieldDescriptorProto_Type::TYPE_BYTES => {
                         format!(
                             "::protobuf::rt::bytes_size({}, &{})",
@@ -916,8 +1009,13 @@ impl<'a> FieldGen<'a> {
                             &RustType::Ref(ref t) => (**t).clone(),
                             t => t.clone(),
                         };
+                        let size_fn = match param_type {
+                            RustType::EnumOrUnknown(..) => "enum_or_unknown_size",
+                            _ => "enum_size",
+                        };
                         format!(
-                            "::protobuf::rt::enum_size({}, {})",
+                            "::protobuf::rt::{}({}, {})",
+                            size_fn,
                             self.proto_field.number(),
                             var_type.into_target(&param_type, var)
                         )
@@ -968,6 +1066,21 @@ impl<'a> FieldGen<'a> {
                 ));
                 w.write_line(&format!("{}.write_to_with_cached_sizes({})?;", var, os));
             }
+            FieldDescriptorProto_Type::TYPE_GROUP => {
+                w.write_line(&format!(
+                    "{}.write_tag({}, ::protobuf::wire_format::{:?})?;",
+                    os,
+                    self.proto_field.number(),
+                    wire_format::WireTypeStartGroup
+                ));
+                w.write_line(&format!("{}.write_to_with_cached_sizes({})?;", var, os));
+                w.write_line(&format!(
+                    "{}.write_tag({}, ::protobuf::wire_format::{:?})?;",
+                    os,
+                    self.proto_field.number(),
+                    wire_format::WireTypeEndGroup
+                ));
+            }
             _ => {
                 let param_type = self.os_write_fn_param_type();
                 let os_write_fn_suffix = self.os_write_fn_suffix();
@@ -1203,7 +1316,9 @@ impl<'a> FieldGen<'a> {
 
     fn self_field_vec_packed_varint_data_size(&self) -> String {
         assert!(!self.is_fixed());
-        let fn_name = if self.is_enum() {
+        let fn_name = if self.elem().is_enum_or_unknown() {
+            "vec_packed_enum_or_unknown_data_size".to_string()
+        } else if self.is_enum() {
             "vec_packed_enum_data_size".to_string()
         } else {
             let zigzag_suffix = if self.is_zigzag() { "_zigzag" } else { "" };
@@ -1234,7 +1349,9 @@ impl<'a> FieldGen<'a> {
     fn self_field_vec_packed_varint_size(&self) -> String {
         // zero is filtered outside
         assert!(!self.is_fixed());
-        let fn_name = if self.is_enum() {
+        let fn_name = if self.elem().is_enum_or_unknown() {
+            "vec_packed_enum_or_unknown_size".to_string()
+        } else if self.is_enum() {
             "vec_packed_enum_size".to_string()
         } else {
             let zigzag_suffix = if self.is_zigzag() { "_zigzag" } else { "" };
@@ -1274,13 +1391,20 @@ impl<'a> FieldGen<'a> {
         let carllerche = match self.kind.primitive_type_variant() {
             PrimitiveTypeVariant::Carllerche => "carllerche_",
             PrimitiveTypeVariant::Default => "",
+            PrimitiveTypeVariant::Wrapper => unreachable!(),
         };
         let type_name_for_fn = protobuf_name(self.proto_type);
+        // group end tag is matched by field number
+        let number_param = match self.proto_type {
+            FieldDescriptorProto_Type::TYPE_GROUP => format!("{}, ", self.proto_field.number()),
+            _ => String::new(),
+        };
         w.write_line(&format!(
-            "::protobuf::rt::read_{}_{}{}_into(wire_type, is, &mut self.{})?;",
+            "::protobuf::rt::read_{}_{}{}_into({}wire_type, is, &mut self.{})?;",
             singular_or_repeated,
             carllerche,
             type_name_for_fn,
+            number_param,
             self.rust_name
         ));
     }
@@ -1302,8 +1426,14 @@ impl<'a> FieldGen<'a> {
     fn write_merge_from_oneof(&self, f: &OneofField, wire_type_var: &str, w: &mut CodeWriter) {
         self.write_assert_wire_type(wire_type_var, w);
 
+        let read = match self.proto_type {
+            FieldDescriptorProto_Type::TYPE_GROUP => {
+                format!("is.read_group({})", self.proto_field.number())
+            }
+            t => t.read("is"),
+        };
         let typed = RustValueTyped {
-            value: format!("{}?", self.proto_type.read("is")),
+            value: format!("{}?", read),
             rust_type: self.full_storage_iter_elem_type(),
         };
 
@@ -1335,6 +1465,16 @@ impl<'a> FieldGen<'a> {
             _ => panic!(),
         };
 
+        if self.is_wrapper() {
+            w.write_line(&format!(
+                "::protobuf::rt::read_singular_wrapper_into::<{}>({}, is, &mut self.{})?;",
+                field.elem.lib_protobuf_type(),
+                wire_type_var,
+                self.rust_name
+            ));
+            return;
+        }
+
         match field.elem {
             FieldElem::Message(..) |
             FieldElem::Primitive(FieldDescriptorProto_Type::TYPE_STRING, ..) |
@@ -1354,6 +1494,11 @@ impl<'a> FieldGen<'a> {
                     self.proto_field.number()
                 ));
             }
+            FieldElem::EnumOrUnknown(..) => {
+                self.write_assert_wire_type(wire_type_var, w);
+                w.write_line("let tmp = is.read_enum_or_unknown()?;");
+                self.write_self_field_assign_some(w, "tmp");
+            }
             _ => {
                 let read_proc = format!("{}?", self.proto_type.read("is"));
 
@@ -1385,6 +1530,13 @@ impl<'a> FieldGen<'a> {
                     self.proto_field.number()
                 ));
             }
+            FieldElem::EnumOrUnknown(..) => {
+                w.write_line(&format!(
+                    "::protobuf::rt::read_repeated_enum_or_unknown_into({}, is, &mut self.{})?;",
+                    wire_type_var,
+                    self.rust_name
+                ));
+            }
             _ => {
                 w.write_line(&format!(
                     "::protobuf::rt::read_repeated_{}_into({}, is, &mut self.{})?;",
@@ -1440,6 +1592,16 @@ impl<'a> FieldGen<'a> {
                     tag_size
                 ));
             }
+            FieldDescriptorProto_Type::TYPE_GROUP => {
+                // start and end tags
+                let tags_size = 2 * self.tag_size();
+                w.write_line(&format!(
+                    "{} += {} + {}.compute_size();",
+                    sum_var,
+                    tags_size,
+                    item_var
+                ));
+            }
             _ => {
                 w.write_line(&format!(
                     "{} += {};",
@@ -1452,6 +1614,15 @@ impl<'a> FieldGen<'a> {
 
     pub fn write_message_write_field(&self, w: &mut CodeWriter) {
         match self.kind {
+            FieldKind::Singular(SingularField { ref elem, .. }) if self.is_wrapper() => {
+                w.if_let_stmt("Some(ref v)", &self.self_field(), |w| {
+                    w.write_line(&format!(
+                        "::protobuf::rt::write_wrapper_with_cached_size::<{}>({}, v, os)?;",
+                        elem.lib_protobuf_type(),
+                        self.proto_field.number()
+                    ));
+                });
+            }
             FieldKind::Singular(..) => {
                 self.write_if_let_self_field_is_some(w, |v, v_type, w| {
                     self.write_write_element(w, "os", v, v_type);
@@ -1499,6 +1670,16 @@ impl<'a> FieldGen<'a> {
 
     pub fn write_message_compute_field_size(&self, sum_var: &str, w: &mut CodeWriter) {
         match self.kind {
+            FieldKind::Singular(SingularField { ref elem, .. }) if self.is_wrapper() => {
+                w.if_let_stmt("Some(ref v)", &self.self_field(), |w| {
+                    w.write_line(&format!(
+                        "{} += ::protobuf::rt::wrapper_size::<{}>({}, v);",
+                        sum_var,
+                        elem.lib_protobuf_type(),
+                        self.proto_field.number()
+                    ));
+                });
+            }
             FieldKind::Singular(..) => {
                 self.write_if_let_self_field_is_some(w, |v, v_type, w| {
                     match field_type_size(self.proto_type) {
@@ -1554,7 +1735,7 @@ impl<'a> FieldGen<'a> {
     fn write_message_field_get_singular(&self, w: &mut CodeWriter) {
         let get_xxx_return_type = self.get_xxx_return_type();
 
-        if self.proto_type == FieldDescriptorProto_Type::TYPE_MESSAGE {
+        if self.is_message_or_group() {
             let self_field = self.self_field();
             let ref field_type_name = self.elem().rust_storage_type();
             w.write_line(&format!(
@@ -1629,7 +1810,7 @@ impl<'a> FieldGen<'a> {
         });
     }
 
-    fn has_has(&self) -> bool {
+    pub fn has_has(&self) -> bool {
         match self.kind {
             FieldKind::Repeated(..) |
             FieldKind::Map(..) => false,
@@ -20,6 +20,9 @@ mod field;
 mod customize;
 mod extensions;
 mod oneof;
+mod services;
+mod views;
+mod serde;
 
 pub use customize::Customize;
 use customize::customize_from_rustproto_for_file;
@@ -29,6 +32,7 @@ pub mod code_writer;
 use self::message::*;
 use self::enums::*;
 use self::extensions::*;
+use self::services::*;
 use self::code_writer::CodeWriter;
 
 fn escape_byte(s: &mut String, b: u8) {
@@ -99,6 +103,27 @@ fn write_file_descriptor_data(file: &FileDescriptorProto, w: &mut CodeWriter) {
     });
 }
 
+fn write_register_message_types_calls(scope: &Scope, w: &mut CodeWriter) {
+    for message in &scope.get_messages() {
+        // map entries have no generated types
+        if message.map_entry().is_none() {
+            w.write_line(&format!(
+                "::protobuf::reflect::registry::register_message::<{}>();",
+                message.rust_name()
+            ));
+            write_register_message_types_calls(&message.to_scope(), w);
+        }
+    }
+}
+
+fn write_register_message_types(scope: &Scope, w: &mut CodeWriter) {
+    w.comment("Register message types and extensions of this file in `::protobuf::reflect::registry`");
+    w.pub_fn("register_message_types()", |w| {
+        write_register_message_types_calls(scope, w);
+        write_register_extensions_calls(scope.get_file_descriptor(), w);
+    });
+}
+
 fn gen_file(
     file: &FileDescriptorProto,
     _files_map: &HashMap<&str, &FileDescriptorProto>,
@@ -139,7 +164,13 @@ fn gen_file(
 
         write_extensions(file, &root_scope, &mut w);
 
+        if customize.gen_services.unwrap_or(false) {
+            write_services(file, &root_scope, &mut w);
+        }
+
         if file.get_options().get_optimize_for() != FileOptions_OptimizeMode::LITE_RUNTIME {
+            w.write_line("");
+            write_register_message_types(&scope, &mut w);
             w.write_line("");
             write_file_descriptor_data(file, &mut w);
         }
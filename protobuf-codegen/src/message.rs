@@ -9,6 +9,8 @@ use super::customize::Customize;
 use super::customize::customize_from_rustproto_for_message;
 use oneof::OneofGen;
 use oneof::OneofVariantGen;
+use views::MessageViewGen;
+use serde;
 
 
 /// Message info for codegen
@@ -75,7 +77,7 @@ impl<'a> MessageGen<'a> {
     fn message_fields(&'a self) -> Vec<&'a FieldGen> {
         self.fields
             .iter()
-            .filter(|f| f.proto_type == FieldDescriptorProto_Type::TYPE_MESSAGE)
+            .filter(|f| f.is_message_or_group())
             .collect()
     }
 
@@ -86,21 +88,6 @@ impl<'a> MessageGen<'a> {
             .collect()
     }
 
-    fn fields_except_group(&'a self) -> Vec<&'a FieldGen> {
-        self.fields
-            .iter()
-            .filter(|f| f.proto_type != FieldDescriptorProto_Type::TYPE_GROUP)
-            .collect()
-    }
-
-    fn fields_except_oneof_and_group(&'a self) -> Vec<&'a FieldGen> {
-        self.fields
-            .iter()
-            .filter(|f| {
-                !f.is_oneof() && f.proto_type != FieldDescriptorProto_Type::TYPE_GROUP
-            })
-            .collect()
-    }
 
 
     fn write_match_each_oneof_variant<F>(&self, w: &mut CodeWriter, cb: F)
@@ -110,7 +97,7 @@ impl<'a> MessageGen<'a> {
         for oneof in self.oneofs() {
             w.if_let_stmt("::std::option::Option::Some(ref v)", &format!("self.{}", oneof.name())[..], |w| {
                 w.match_block("v", |w| {
-                    for variant in oneof.variants_except_group() {
+                    for variant in oneof.variants() {
                         let ref field = variant.field;
                         let (refv, vtype) =
                             if !field.elem_type_is_copy() {
@@ -130,7 +117,7 @@ impl<'a> MessageGen<'a> {
     fn write_write_to_with_cached_sizes(&self, w: &mut CodeWriter) {
         w.def_fn("write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()>", |w| {
             // To have access to its methods but not polute the name space.
-            for f in self.fields_except_oneof_and_group() {
+            for f in self.fields_except_oneof() {
                 f.write_message_write_field(w);
             }
             self.write_match_each_oneof_variant(w, |w, variant, v, v_type| {
@@ -166,7 +153,7 @@ impl<'a> MessageGen<'a> {
         w.def_fn("compute_size(&self) -> u32", |w| {
             // To have access to its methods but not polute the name space.
             w.write_line("let mut my_size = 0;");
-            for field in self.fields_except_oneof_and_group() {
+            for field in self.fields_except_oneof() {
                 field.write_message_compute_field_size("my_size", w);
             }
             self.write_match_each_oneof_variant(w, |w, variant, v, vtype| {
@@ -181,7 +168,7 @@ impl<'a> MessageGen<'a> {
     }
 
     fn write_field_accessors(&self, w: &mut CodeWriter) {
-        for f in self.fields_except_group() {
+        for f in &self.fields {
             w.write_line("");
             let reconstruct_def = f.reconstruct_def();
             w.comment(&(reconstruct_def + ";"));
@@ -217,7 +204,7 @@ impl<'a> MessageGen<'a> {
             w.while_block("!is.eof()?", |w| {
                 w.write_line(&format!("let (field_number, wire_type) = is.read_tag_unpack()?;"));
                 w.match_block("field_number", |w| {
-                    for f in &self.fields_except_group() {
+                    for f in &self.fields {
                         let number = f.proto_field.number();
                         w.case_block(number.to_string(), |w| {
                             f.write_merge_from_field("wire_type", w);
@@ -249,6 +236,12 @@ impl<'a> MessageGen<'a> {
                 AccessorStyle::HasGet => {
                     w.write_line(&format!("{}::has_{},", self.type_name, field.rust_name));
                     w.write_line(&format!("{}::get_{},", self.type_name, field.rust_name));
+                    if field.is_message_or_group() {
+                        w.write_line(&format!("{}::mut_{},", self.type_name, field.rust_name));
+                    } else {
+                        w.write_line(&format!("{}::set_{},", self.type_name, field.rust_name));
+                    }
+                    w.write_line(&format!("{}::clear_{},", self.type_name, field.rust_name));
                 }
             }
         });
@@ -258,7 +251,7 @@ impl<'a> MessageGen<'a> {
     fn write_descriptor_static(&self, w: &mut CodeWriter) {
         w.def_fn(&format!("descriptor_static(_: ::std::option::Option<{}>) -> &'static ::protobuf::reflect::MessageDescriptor", self.type_name), |w| {
             w.lazy_static_decl_get("descriptor", "::protobuf::reflect::MessageDescriptor", |w| {
-                let fields = self.fields_except_group();
+                let fields = &self.fields;
                 if fields.is_empty() {
                     w.write_line(&format!("let fields = ::std::vec::Vec::new();"));
                 } else {
@@ -334,6 +327,12 @@ impl<'a> MessageGen<'a> {
             w.def_fn("descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor", |w| {
                 w.write_line("::protobuf::Message::descriptor_static(None::<Self>)");
             });
+            if self.lite_runtime {
+                w.write_line("");
+                w.def_fn("descriptor_if_available(&self) -> ::std::option::Option<&'static ::protobuf::reflect::MessageDescriptor>", |w| {
+                    w.write_line("::std::option::Option::None");
+                });
+            }
             w.write_line("");
             w.def_fn(&format!("new() -> {}", self.type_name), |w| {
                 w.write_line(&format!("{}::new()", self.type_name));
@@ -368,7 +367,7 @@ impl<'a> MessageGen<'a> {
         w.impl_for_block("::protobuf::Clear", &self.type_name, |w| {
             w.def_fn("clear(&mut self)", |w| {
                 // TODO: no need to clear oneof fields in loop
-                for f in self.fields_except_group() {
+                for f in &self.fields {
                     let clear_field_func = f.clear_field_func();
                     w.write_line(&format!("self.{}();", clear_field_func));
                 }
@@ -383,34 +382,31 @@ impl<'a> MessageGen<'a> {
             derive.push("Debug");
         }
         w.derive(&derive);
+        serde::write_serde_derive(w, &self.customize);
         w.pub_struct(&self.type_name, |w| {
             if !self.fields_except_oneof().is_empty() {
                 w.comment("message fields");
                 for field in self.fields_except_oneof() {
-                    if field.proto_type == FieldDescriptorProto_Type::TYPE_GROUP {
-                        w.comment(&format!("{}: <group>", &field.rust_name));
+                    let vis = if field.expose_field {
+                        Visibility::Public
                     } else {
-                        let vis = if field.expose_field {
-                            Visibility::Public
-                        } else {
-                            match field.kind {
-                                FieldKind::Repeated(..) => Visibility::Default,
-                                FieldKind::Singular(SingularField { ref flag, .. }) => {
-                                    match *flag {
-                                        SingularFieldFlag::WithFlag { .. } => Visibility::Default,
-                                        SingularFieldFlag::WithoutFlag => Visibility::Public,
-                                    }
+                        match field.kind {
+                            FieldKind::Repeated(..) => Visibility::Default,
+                            FieldKind::Singular(SingularField { ref flag, .. }) => {
+                                match *flag {
+                                    SingularFieldFlag::WithFlag { .. } => Visibility::Default,
+                                    SingularFieldFlag::WithoutFlag => Visibility::Public,
                                 }
-                                FieldKind::Map(..) => Visibility::Public,
-                                FieldKind::Oneof(..) => unreachable!(),
                             }
-                        };
-                        w.field_decl_vis(
-                            vis,
-                            &field.rust_name,
-                            &field.full_storage_type().to_string(),
-                        );
-                    }
+                            FieldKind::Map(..) => Visibility::Public,
+                            FieldKind::Oneof(..) => unreachable!(),
+                        }
+                    };
+                    w.field_decl_vis(
+                        vis,
+                        &field.rust_name,
+                        &field.full_storage_type().to_string(),
+                    );
                 }
             }
             if !self.oneofs().is_empty() {
@@ -425,7 +421,9 @@ impl<'a> MessageGen<'a> {
             }
             w.comment("special fields");
             // TODO: make public
+            serde::write_serde_skip(w, &self.customize);
             w.field_decl("unknown_fields", "::protobuf::UnknownFields");
+            serde::write_serde_skip(w, &self.customize);
             w.field_decl("cached_size", "::protobuf::CachedSize");
         });
     }
@@ -451,6 +449,11 @@ impl<'a> MessageGen<'a> {
         w.write_line("");
         self.write_impl_value(w);
 
+        if self.customize.gen_views.unwrap_or(false) {
+            w.write_line("");
+            MessageViewGen::new(&self.type_name, &self.fields).write(w);
+        }
+
         let mut nested_prefix = self.type_name.to_string();
         nested_prefix.push_str("_");
 
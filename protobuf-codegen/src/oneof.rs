@@ -10,7 +10,7 @@ use protobuf::descriptor::FieldDescriptorProto;
 use message::MessageGen;
 use Customize;
 use code_writer::CodeWriter;
-use protobuf::descriptor::FieldDescriptorProto_Type;
+use serde;
 
 
 // oneof one { ... }
@@ -127,22 +127,18 @@ impl<'a> OneofGen<'a> {
         }
     }
 
-    pub fn variants_except_group(&'a self) -> Vec<OneofVariantGen<'a>> {
+    pub fn variants(&'a self) -> Vec<OneofVariantGen<'a>> {
         self.oneof
             .variants()
             .into_iter()
-            .filter_map(|v| {
+            .map(|v| {
                 let field = self.message
                     .fields
                     .iter()
                     .filter(|f| f.proto_field.name() == v.field.get_name())
                     .next()
                     .expect(&format!("field not found by name: {}", v.field.get_name()));
-                match field.proto_type {
-                    FieldDescriptorProto_Type::TYPE_GROUP => None,
-                    _ => Some(OneofVariantGen::parse(self, v, field)),
-                }
-
+                OneofVariantGen::parse(self, v, field)
             })
             .collect()
     }
@@ -157,8 +153,9 @@ impl<'a> OneofGen<'a> {
             derive.push("Debug");
         }
         w.derive(&derive);
+        serde::write_serde_derive(w, &self.customize);
         w.pub_enum(&self.type_name.to_string(), |w| {
-            for variant in self.variants_except_group() {
+            for variant in self.variants() {
                 w.write_line(&format!(
                     "{}({}),",
                     variant.field.rust_name,
@@ -33,14 +33,14 @@ pub enum RustType {
     Message(String),
     // protobuf enum, not any enum
     Enum(String, String),
+    // protobuf enum wrapped in `EnumOrUnknown`, enum name and default value
+    EnumOrUnknown(String, String),
     // oneof enum
     Oneof(String),
     // bytes::Bytes
     Bytes,
     // chars::Chars
     Chars,
-    // group
-    Group,
 }
 
 impl fmt::Display for RustType {
@@ -73,7 +73,9 @@ impl fmt::Display for RustType {
             RustType::Message(ref name) |
             RustType::Enum(ref name, _) |
             RustType::Oneof(ref name) => write!(f, "{}", name),
-            RustType::Group => write!(f, "<group>"),
+            RustType::EnumOrUnknown(ref name, _) => {
+                write!(f, "::protobuf::EnumOrUnknown<{}>", name)
+            }
             RustType::Bytes => write!(f, "::bytes::Bytes"),
             RustType::Chars => write!(f, "::protobuf::Chars"),
         }
@@ -100,6 +102,8 @@ impl RustType {
             true
         } else if let RustType::Enum(..) = *self {
             true
+        } else if let RustType::EnumOrUnknown(..) = *self {
+            true
         } else {
             false
         }
@@ -180,6 +184,9 @@ impl RustType {
             }
             // Note: default value of enum type may not be equal to default value of field
             RustType::Enum(ref name, ref default) => format!("{}::{}", name, default),
+            RustType::EnumOrUnknown(ref name, ref default) => {
+                format!("::protobuf::EnumOrUnknown::new({}::{})", name, default)
+            }
             _ => panic!("cannot create default value for: {}", *self),
         }
     }
@@ -206,7 +213,8 @@ impl RustType {
             RustType::Bool |
             RustType::Float(..) |
             RustType::Int(..) |
-            RustType::Enum(..) => format!("{} = {}", v, self.default_value()),
+            RustType::Enum(..) |
+            RustType::EnumOrUnknown(..) => format!("{} = {}", v, self.default_value()),
             ref ty => panic!("cannot clear type: {:?}", ty),
         }
     }
@@ -275,6 +283,12 @@ impl RustType {
             (&RustType::Ref(ref t), &RustType::Int(true, 32)) if t.is_enum() => {
                 return Ok(format!("{}.value()", v))
             }
+            (&RustType::EnumOrUnknown(..), &RustType::Int(true, 32)) => {
+                return Ok(format!("{}.value()", v))
+            }
+            (&RustType::Enum(ref x, _), &RustType::EnumOrUnknown(ref y, _)) if x == y => {
+                return Ok(format!("::protobuf::EnumOrUnknown::new({})", v))
+            }
             _ => (),
         };
 
@@ -462,6 +476,8 @@ fn capitalize(s: &str) -> String {
 pub enum PrimitiveTypeVariant {
     Default,
     Carllerche,
+    /// Value of wrapper message like `google.protobuf.Int32Value`
+    Wrapper,
 }
 
 pub enum _CarllercheBytesType {
@@ -474,12 +490,14 @@ pub enum ProtobufTypeGen {
     Primitive(FieldDescriptorProto_Type, PrimitiveTypeVariant),
     Message(String),
     Enum(String),
+    EnumOrUnknown(String),
 }
 
 impl ProtobufTypeGen {
     pub fn rust_type(&self) -> String {
         match self {
-            &ProtobufTypeGen::Primitive(t, PrimitiveTypeVariant::Default) => {
+            &ProtobufTypeGen::Primitive(t, PrimitiveTypeVariant::Default) |
+            &ProtobufTypeGen::Primitive(t, PrimitiveTypeVariant::Wrapper) => {
                 format!(
                     "::protobuf::types::ProtobufType{}",
                     capitalize(protobuf_name(t))
@@ -500,6 +518,9 @@ impl ProtobufTypeGen {
             &ProtobufTypeGen::Enum(ref name) => {
                 format!("::protobuf::types::ProtobufTypeEnum<{}>", name)
             }
+            &ProtobufTypeGen::EnumOrUnknown(ref name) => {
+                format!("::protobuf::types::ProtobufTypeEnumOrUnknown<{}>", name)
+            }
         }
     }
 }
@@ -0,0 +1,28 @@
+use code_writer::CodeWriter;
+use customize::Customize;
+
+
+/// Feature of the crate including generated code which enables `serde` derives
+const SERDE_FEATURE_CFG: &'static str = "feature = \"with-serde\"";
+
+fn serde_derive_enabled(customize: &Customize) -> bool {
+    customize.serde_derive.unwrap_or(false)
+}
+
+/// Write `Serialize` and `Deserialize` derives for message or enum
+pub fn write_serde_derive(w: &mut CodeWriter, customize: &Customize) {
+    if serde_derive_enabled(customize) {
+        w.write_line(&format!(
+            "#[cfg_attr({}, derive(Serialize, Deserialize))]",
+            SERDE_FEATURE_CFG
+        ));
+    }
+}
+
+/// Exclude struct field (unknown fields, cached size) from serialization
+pub fn write_serde_skip(w: &mut CodeWriter, customize: &Customize) {
+    if serde_derive_enabled(customize) {
+        w.write_line(&format!("#[cfg_attr({}, serde(skip))]", SERDE_FEATURE_CFG));
+    }
+}
+
@@ -0,0 +1,279 @@
+use protobuf::descriptor::*;
+use protobuf::descriptorx::*;
+use protobuf::rust;
+use super::code_writer::CodeWriter;
+use super::rust_types_values::*;
+
+
+/// Convert `CamelCase` name to `snake_case`
+fn snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut r = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let prev = chars[i - 1];
+            let next_lower = chars.get(i + 1).map_or(false, |n| n.is_lowercase());
+            // word starts after lowercase letter or digit, or at the last letter of abbreviation
+            if prev.is_lowercase() || prev.is_digit(10) || (prev.is_uppercase() && next_lower) {
+                r.push('_');
+            }
+        }
+        r.extend(c.to_lowercase());
+    }
+    r
+}
+
+
+struct MethodGen<'a> {
+    proto: &'a MethodDescriptorProto,
+    service_name: &'a str,
+    service_full_name: &'a str,
+    input_type: String,
+    output_type: String,
+}
+
+impl<'a> MethodGen<'a> {
+    fn new(
+        proto: &'a MethodDescriptorProto,
+        service_name: &'a str,
+        service_full_name: &'a str,
+        file: &FileDescriptorProto,
+        root_scope: &RootScope,
+    ) -> MethodGen<'a> {
+        MethodGen {
+            proto: proto,
+            service_name: service_name,
+            service_full_name: service_full_name,
+            input_type: type_name_to_rust_relative(proto.get_input_type(), file, false, root_scope),
+            output_type: type_name_to_rust_relative(proto.get_output_type(), file, false, root_scope),
+        }
+    }
+
+    fn rust_name(&self) -> String {
+        let name = snake_case(self.proto.get_name());
+        if rust::is_rust_keyword(&name) {
+            format!("method_{}", name)
+        } else {
+            name
+        }
+    }
+
+    fn const_name(&self) -> String {
+        format!(
+            "METHOD_{}_{}",
+            snake_case(self.service_name).to_uppercase(),
+            snake_case(self.proto.get_name()).to_uppercase()
+        )
+    }
+
+    /// `Unary`, `ClientStreaming`, `ServerStreaming` or `BidiStreaming`
+    fn method_type(&self) -> &'static str {
+        match (self.proto.get_client_streaming(), self.proto.get_server_streaming()) {
+            (false, false) => "Unary",
+            (true, false) => "ClientStreaming",
+            (false, true) => "ServerStreaming",
+            (true, true) => "BidiStreaming",
+        }
+    }
+
+    fn snake_method_type(&self) -> String {
+        snake_case(self.method_type())
+    }
+
+    fn sig(&self) -> String {
+        let req = if self.proto.get_client_streaming() {
+            format!("::protobuf::rpc::RpcStream<{}>", self.input_type)
+        } else {
+            self.input_type.clone()
+        };
+        let resp = if self.proto.get_server_streaming() {
+            format!("::protobuf::rpc::RpcStream<{}>", self.output_type)
+        } else {
+            self.output_type.clone()
+        };
+        format!(
+            "{}(&self, req: {}) -> ::protobuf::rpc::RpcResult<{}>",
+            self.rust_name(),
+            req,
+            resp
+        )
+    }
+
+    fn write_descriptor(&self, w: &mut CodeWriter) {
+        let descriptor_type = format!(
+            "::protobuf::rpc::MethodDescriptor<{}, {}>",
+            self.input_type,
+            self.output_type
+        );
+        w.pub_const(
+            &self.const_name(),
+            &descriptor_type,
+            &format!(
+                "::protobuf::rpc::MethodDescriptor {{ name: \"{}\", full_path: \"/{}/{}\", \
+                 method_type: ::protobuf::rpc::MethodType::{}, phantom: ::std::marker::PhantomData }}",
+                self.proto.get_name(),
+                self.service_full_name,
+                self.proto.get_name(),
+                self.method_type()
+            ),
+        );
+    }
+
+    fn write_trait_method(&self, w: &mut CodeWriter) {
+        w.fn_def(&self.sig());
+    }
+
+    fn write_client_method(&self, w: &mut CodeWriter) {
+        w.def_fn(&self.sig(), |w| {
+            w.write_line(&format!(
+                "::protobuf::rpc::call_{}(&self.transport, &{}, req)",
+                self.snake_method_type(),
+                self.const_name()
+            ));
+        });
+    }
+
+    fn write_add_to_definition(&self, w: &mut CodeWriter) {
+        w.block("{", "}", |w| {
+            w.write_line("let service = service.clone();");
+            w.write_line(&format!(
+                "definition.add_{}(&{}, move |req| service.{}(req));",
+                self.snake_method_type(),
+                self.const_name(),
+                self.rust_name()
+            ));
+        });
+    }
+}
+
+
+struct ServiceGen<'a> {
+    proto: &'a ServiceDescriptorProto,
+    full_name: String,
+    methods: Vec<MethodGen<'a>>,
+}
+
+impl<'a> ServiceGen<'a> {
+    fn new(
+        proto: &'a ServiceDescriptorProto,
+        full_name: &'a str,
+        file: &FileDescriptorProto,
+        root_scope: &RootScope,
+    ) -> ServiceGen<'a> {
+        ServiceGen {
+            proto: proto,
+            full_name: full_name.to_owned(),
+            methods: proto
+                .get_method()
+                .iter()
+                .map(|m| MethodGen::new(m, proto.get_name(), full_name, file, root_scope))
+                .collect(),
+        }
+    }
+
+    fn client_name(&self) -> String {
+        format!("{}Client", self.proto.get_name())
+    }
+
+    fn write_trait(&self, w: &mut CodeWriter) {
+        w.pub_trait(self.proto.get_name(), |w| {
+            for method in &self.methods {
+                method.write_trait_method(w);
+            }
+        });
+    }
+
+    fn write_client(&self, w: &mut CodeWriter) {
+        w.pub_struct(&format!("{}<T>", self.client_name()), |w| {
+            w.field_decl("transport", "T");
+        });
+        w.write_line("");
+        w.expr_block(
+            &format!("impl<T : ::protobuf::rpc::Transport> {}<T>", self.client_name()),
+            |w| {
+                w.pub_fn(&format!("new(transport: T) -> {}<T>", self.client_name()), |w| {
+                    w.write_line(&format!("{} {{ transport: transport }}", self.client_name()));
+                });
+            },
+        );
+        w.write_line("");
+        w.expr_block(
+            &format!(
+                "impl<T : ::protobuf::rpc::Transport> {} for {}<T>",
+                self.proto.get_name(),
+                self.client_name()
+            ),
+            |w| {
+                for (i, method) in self.methods.iter().enumerate() {
+                    if i != 0 {
+                        w.write_line("");
+                    }
+                    method.write_client_method(w);
+                }
+            },
+        );
+    }
+
+    fn write_service_definition(&self, w: &mut CodeWriter) {
+        w.comment("Definition of service implementation for `::protobuf::rpc::Transport` servers");
+        w.pub_fn(
+            &format!(
+                "{}_service_definition<S : {} + Send + Sync + 'static>(service: S) -> \
+                 ::protobuf::rpc::ServiceDefinition",
+                snake_case(self.proto.get_name()),
+                self.proto.get_name()
+            ),
+            |w| {
+                w.write_line("let service = ::std::sync::Arc::new(service);");
+                w.write_line(&format!(
+                    "let mut definition = ::protobuf::rpc::ServiceDefinition::new(\"{}\");",
+                    self.full_name
+                ));
+                for method in &self.methods {
+                    method.write_add_to_definition(w);
+                }
+                w.write_line("definition");
+            },
+        );
+    }
+
+    fn write(&self, w: &mut CodeWriter) {
+        for method in &self.methods {
+            w.write_line("");
+            method.write_descriptor(w);
+        }
+        w.write_line("");
+        self.write_trait(w);
+        w.write_line("");
+        self.write_client(w);
+        w.write_line("");
+        self.write_service_definition(w);
+    }
+}
+
+
+/// Write service traits, clients and method descriptors
+pub fn write_services(file: &FileDescriptorProto, root_scope: &RootScope, w: &mut CodeWriter) {
+    for service in file.get_service() {
+        let full_name = if file.get_package().is_empty() {
+            service.get_name().to_owned()
+        } else {
+            format!("{}.{}", file.get_package(), service.get_name())
+        };
+        ServiceGen::new(service, &full_name, file, root_scope).write(w);
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_snake_case() {
+        assert_eq!("say_hello", snake_case("SayHello"));
+        assert_eq!("get_http_status", snake_case("GetHTTPStatus"));
+        assert_eq!("list2_items", snake_case("List2Items"));
+        assert_eq!("greeter", snake_case("greeter"));
+    }
+}
@@ -0,0 +1,291 @@
+use protobuf::descriptor::*;
+
+use super::code_writer::CodeWriter;
+use super::field::*;
+
+
+/// Name of view type for message type
+fn view_type_name(message_type: &str) -> String {
+    format!("{}Ref", message_type)
+}
+
+/// Messages bundled with `protobuf` crate (well-known types, descriptors) have no views
+fn is_bundled_message(message_type: &str) -> bool {
+    message_type.starts_with("::protobuf::")
+}
+
+/// Implementation of `::protobuf::view::ViewType` for field element
+fn elem_view_type(elem: &FieldElem) -> String {
+    match *elem {
+        FieldElem::Primitive(FieldDescriptorProto_Type::TYPE_STRING, ..) => {
+            "::protobuf::view::ViewTypeStr".to_owned()
+        }
+        FieldElem::Primitive(FieldDescriptorProto_Type::TYPE_BYTES, ..) => {
+            "::protobuf::view::ViewTypeBytes".to_owned()
+        }
+        FieldElem::Message(ref name, ..) if is_bundled_message(name) => {
+            format!("::protobuf::view::ViewTypeOwnedMessage<{}>", name)
+        }
+        FieldElem::Message(ref name, ..) => {
+            format!("::protobuf::view::ViewTypeMessage<{}<'a>>", view_type_name(name))
+        }
+        _ => elem.lib_protobuf_type(),
+    }
+}
+
+
+struct FieldViewGen<'a> {
+    field: &'a FieldGen<'a>,
+}
+
+impl<'a> FieldViewGen<'a> {
+    fn number(&self) -> u32 {
+        self.field.proto_field.field.get_number() as u32
+    }
+
+    // message fields are stored as bytes and parsed in getter
+    fn is_message(&self) -> bool {
+        match *self.field.elem() {
+            FieldElem::Message(..) => true,
+            _ => false,
+        }
+    }
+
+    fn is_repeated(&self) -> bool {
+        match self.field.kind {
+            FieldKind::Repeated(..) => true,
+            _ => false,
+        }
+    }
+
+    // maps, groups and wrappers stored as `Option` are only accessible in owned message,
+    // groups are skipped when parsing views
+    fn is_supported(&self) -> bool {
+        match self.field.kind {
+            FieldKind::Map(..) => false,
+            _ if self.field.is_wrapper() => false,
+            _ => self.field.proto_type != FieldDescriptorProto_Type::TYPE_GROUP,
+        }
+    }
+
+    // type of view field of singular field
+    fn storage_elem_type(&self) -> String {
+        match *self.field.elem() {
+            FieldElem::Primitive(FieldDescriptorProto_Type::TYPE_STRING, ..) => "&'a str".to_owned(),
+            FieldElem::Primitive(FieldDescriptorProto_Type::TYPE_BYTES, ..) |
+            FieldElem::Message(..) => "&'a [u8]".to_owned(),
+            ref elem => elem.rust_storage_type().to_string(),
+        }
+    }
+
+    // type used to read singular field in `parse_view`
+    fn read_view_type(&self) -> String {
+        if self.is_message() {
+            "::protobuf::view::ViewTypeBytes".to_owned()
+        } else {
+            elem_view_type(self.field.elem())
+        }
+    }
+
+    fn write_field_decl(&self, w: &mut CodeWriter) {
+        w.field_decl(
+            &self.field.rust_name,
+            &format!("::std::option::Option<{}>", self.storage_elem_type()),
+        );
+    }
+
+    fn write_getters(&self, w: &mut CodeWriter) {
+        let name = &self.field.rust_name;
+        if self.is_repeated() {
+            let view_type = elem_view_type(self.field.elem());
+            w.pub_fn(
+                &format!("get_{}(&self) -> ::protobuf::view::RepeatedView<'a, {}>", name, view_type),
+                |w| {
+                    w.write_line(&format!(
+                        "::protobuf::view::RepeatedView::new(self.view_bytes, {})",
+                        self.number()
+                    ));
+                },
+            );
+            return;
+        }
+
+        if self.field.has_has() {
+            w.pub_fn(&format!("has_{}(&self) -> bool", name), |w| {
+                w.write_line(&format!("self.{}.is_some()", name));
+            });
+            w.write_line("");
+        }
+
+        if self.is_message() {
+            w.comment("view of the last occurrence of the field, like the regular parser");
+        }
+        match *self.field.elem() {
+            FieldElem::Message(ref message_type, ..) if is_bundled_message(message_type) => {
+                w.pub_fn(
+                    &format!("get_{}(&self) -> ::protobuf::ProtobufResult<{}>", name, message_type),
+                    |w| {
+                        w.write_line(&format!("::protobuf::parse_from_bytes(self.{}.unwrap_or(&[]))", name));
+                    },
+                );
+            }
+            FieldElem::Message(ref message_type, ..) => {
+                w.pub_fn(
+                    &format!(
+                        "get_{}(&self) -> ::protobuf::ProtobufResult<{}<'a>>",
+                        name,
+                        view_type_name(message_type)
+                    ),
+                    |w| {
+                        w.write_line(&format!(
+                            "::protobuf::view::MessageView::parse_view(self.{}.unwrap_or(&[]))",
+                            name
+                        ));
+                    },
+                );
+            }
+            _ => {
+                w.pub_fn(&format!("get_{}(&self) -> {}", name, self.storage_elem_type()), |w| {
+                    w.write_line(&format!(
+                        "self.{}.unwrap_or({})",
+                        name,
+                        self.field.get_xxx_default_value_rust()
+                    ));
+                });
+            }
+        }
+    }
+}
+
+
+/// Borrowed view `FooRef<'a>` of message `Foo`
+pub struct MessageViewGen<'a> {
+    type_name: &'a str,
+    fields: Vec<FieldViewGen<'a>>,
+}
+
+impl<'a> MessageViewGen<'a> {
+    pub fn new(type_name: &'a str, fields: &'a [FieldGen<'a>]) -> MessageViewGen<'a> {
+        MessageViewGen {
+            type_name: type_name,
+            fields: fields
+                .iter()
+                .map(|field| FieldViewGen { field: field })
+                .filter(|f| f.is_supported())
+                .collect(),
+        }
+    }
+
+    fn view_type_name(&self) -> String {
+        view_type_name(self.type_name)
+    }
+
+    fn singular_fields(&self) -> Vec<&FieldViewGen<'a>> {
+        self.fields.iter().filter(|f| !f.is_repeated()).collect()
+    }
+
+    fn write_struct(&self, w: &mut CodeWriter) {
+        w.derive(&["Clone", "Copy", "Debug"]);
+        w.pub_struct(&format!("{}<'a>", self.view_type_name()), |w| {
+            w.field_decl("view_bytes", "&'a [u8]");
+            if !self.singular_fields().is_empty() {
+                w.comment("message fields");
+            }
+            for field in self.singular_fields() {
+                field.write_field_decl(w);
+            }
+        });
+    }
+
+    fn write_impl_self(&self, w: &mut CodeWriter) {
+        w.expr_block(&format!("impl<'a> {}<'a>", self.view_type_name()), |w| {
+            w.pub_fn(
+                &format!("to_owned(&self) -> ::protobuf::ProtobufResult<{}>", self.type_name),
+                |w| {
+                    w.write_line("::protobuf::view::MessageView::to_owned_message(self)");
+                },
+            );
+            for field in &self.fields {
+                w.write_line("");
+                w.comment(&field.field.reconstruct_def());
+                w.write_line("");
+                field.write_getters(w);
+            }
+        });
+    }
+
+    fn write_read_field(&self, field: &FieldViewGen, w: &mut CodeWriter) {
+        let read = format!("is.read_field::<{}>(wire_type)?", field.read_view_type());
+        w.if_let_stmt("::std::option::Option::Some(v)", &read, |w| {
+            w.write_line(&format!("r.{} = ::std::option::Option::Some(v);", field.field.rust_name));
+            if field.field.is_oneof() {
+                let oneof_name = &field.field.oneof().oneof_name;
+                for other in self.singular_fields() {
+                    if other.field.rust_name != field.field.rust_name && other.field.is_oneof() &&
+                        &other.field.oneof().oneof_name == oneof_name
+                    {
+                        w.write_line(&format!("r.{} = ::std::option::Option::None;", other.field.rust_name));
+                    }
+                }
+            }
+        });
+    }
+
+    fn write_parse_view(&self, w: &mut CodeWriter) {
+        let singular_fields = self.singular_fields();
+        w.def_fn(
+            &format!(
+                "parse_view(bytes: &'a [u8]) -> ::protobuf::ProtobufResult<{}<'a>>",
+                self.view_type_name()
+            ),
+            |w| {
+                let decl = if singular_fields.is_empty() { "let r" } else { "let mut r" };
+                w.stmt_block(&format!("{} = {}", decl, self.view_type_name()), |w| {
+                    w.field_entry("view_bytes", "bytes");
+                    for field in &singular_fields {
+                        w.field_entry(&field.field.rust_name, "::std::option::Option::None");
+                    }
+                });
+                w.write_line("let mut is = ::protobuf::view::ViewInputStream::new(bytes);");
+                w.while_block("!is.eof()", |w| {
+                    w.write_line("let (field_number, wire_type) = is.read_tag_unpack()?;");
+                    w.match_block("field_number", |w| {
+                        for field in &singular_fields {
+                            w.case_block(&format!("{}", field.number()), |w| {
+                                self.write_read_field(field, w);
+                            });
+                        }
+                        w.case_block("_", |w| {
+                            w.write_line("is.skip_field(field_number, wire_type)?;");
+                        });
+                    });
+                });
+                w.write_line("::std::result::Result::Ok(r)");
+            },
+        );
+    }
+
+    fn write_impl_message_view(&self, w: &mut CodeWriter) {
+        w.expr_block(
+            &format!("impl<'a> ::protobuf::view::MessageView<'a> for {}<'a>", self.view_type_name()),
+            |w| {
+                w.write_line(&format!("type Owned = {};", self.type_name));
+                w.write_line("");
+                self.write_parse_view(w);
+                w.write_line("");
+                w.def_fn("view_bytes(&self) -> &'a [u8]", |w| {
+                    w.write_line("self.view_bytes");
+                });
+            },
+        );
+    }
+
+    pub fn write(&self, w: &mut CodeWriter) {
+        self.write_struct(w);
+        w.write_line("");
+        self.write_impl_self(w);
+        w.write_line("");
+        self.write_impl_message_view(w);
+    }
+}
+
@@ -1,3 +1,5 @@
+use protobuf::descriptor::FieldDescriptorProto_Type;
+
 static NAMES: &'static [&'static str] = &[
     "Any",
     "Api",
@@ -48,6 +50,22 @@ pub fn is_well_known_type_full(name: &str) -> Option<&str> {
     }
 }
 
+/// Type of `value` field of wrapper type like `.google.protobuf.Int32Value`
+pub fn wrapped_type(name: &str) -> Option<FieldDescriptorProto_Type> {
+    match name {
+        ".google.protobuf.DoubleValue" => Some(FieldDescriptorProto_Type::TYPE_DOUBLE),
+        ".google.protobuf.FloatValue" => Some(FieldDescriptorProto_Type::TYPE_FLOAT),
+        ".google.protobuf.Int64Value" => Some(FieldDescriptorProto_Type::TYPE_INT64),
+        ".google.protobuf.UInt64Value" => Some(FieldDescriptorProto_Type::TYPE_UINT64),
+        ".google.protobuf.Int32Value" => Some(FieldDescriptorProto_Type::TYPE_INT32),
+        ".google.protobuf.UInt32Value" => Some(FieldDescriptorProto_Type::TYPE_UINT32),
+        ".google.protobuf.BoolValue" => Some(FieldDescriptorProto_Type::TYPE_BOOL),
+        ".google.protobuf.StringValue" => Some(FieldDescriptorProto_Type::TYPE_STRING),
+        ".google.protobuf.BytesValue" => Some(FieldDescriptorProto_Type::TYPE_BYTES),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
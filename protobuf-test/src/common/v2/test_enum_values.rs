@@ -13,3 +13,10 @@ fn test_enum_values() {
     ];
     assert_eq!(expected, TestEnumValuesEnum::values());
 }
+
+#[test]
+fn test_enum_get_values_and_descriptor() {
+    assert_eq!(Some(TestEnumValuesEnum::values()), TestEnumValuesEnum::get_values());
+    let d = TestEnumValuesEnum::get_enum_descriptor_static(None).unwrap();
+    assert_eq!("TestEnumValuesEnum", d.name());
+}
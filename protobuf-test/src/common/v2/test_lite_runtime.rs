@@ -1,3 +1,5 @@
+use protobuf::*;
+use protobuf::error::WireError;
 use protobuf_test_common::*;
 
 use super::test_lite_runtime_pb::*;
@@ -11,3 +13,29 @@ fn test_lite_runtime() {
     // test it doesn't crash
     format!("{:?}", m);
 }
+
+#[test]
+fn test_lite_runtime_enum() {
+    assert_eq!(3, EnumTestLiteRuntime::get_values().unwrap().len());
+    assert!(EnumTestLiteRuntime::get_enum_descriptor_static(None).is_none());
+}
+
+#[test]
+fn test_lite_runtime_decode_error() {
+    // invalid UTF-8 in `s`
+    let bytes = [0x08, 0x01, 0x12, 0x02, 0xff, 0xfe];
+    match parse_from_bytes::<TestLiteRuntime>(&bytes) {
+        Err(ProtobufError::WireError(WireError::Utf8Error)) => {}
+        r => panic!("{:?}", r),
+    }
+
+    // no descriptor, so location has field numbers instead of names
+    let options = ParseOptions {
+        locate_errors: true,
+        ..Default::default()
+    };
+    let e = parse_from_bytes_with_options::<TestLiteRuntime>(&bytes, &options).unwrap_err();
+    let location = e.location().unwrap();
+    assert_eq!(Some(2), location.field_number());
+    assert_eq!("2", location.path());
+}
@@ -0,0 +1,76 @@
+use protobuf::*;
+use protobuf::text_format;
+use protobuf::view::MessageView;
+
+use protobuf_test_common::*;
+use protobuf_test_common::hex::decode_hex;
+
+use super::test_group_pb::*;
+
+
+#[test]
+fn test_repeated_group() {
+    let mut m = MessageWithGroup::new();
+    m.set_aaa("a".to_owned());
+    let mut identifier = MessageWithGroup_Identifier::new();
+    identifier.set_iii(1);
+    identifier.set_sss("s".to_owned());
+    m.mut_identifier().push(identifier);
+
+    test_serialize_deserialize("0a 01 61 93 01 98 01 01 a2 01 01 73 94 01", &m);
+}
+
+#[test]
+fn test_nested_group() {
+    let mut m = MessageWithNestedGroup::new();
+    m.mut_location().set_line(2);
+    let mut span = MessageWithNestedGroup_Location_Span::new();
+    span.set_start(3);
+    m.mut_location().mut_span().push(span);
+
+    test_serialize_deserialize("ab 01 b0 01 02 bb 01 c0 01 03 bc 01 ac 01", &m);
+}
+
+#[test]
+fn test_missing_end_group() {
+    let bytes = decode_hex("ab 01 b0 01 02");
+    assert!(parse_from_bytes::<MessageWithNestedGroup>(&bytes).is_err());
+}
+
+#[test]
+fn test_group_text_format() {
+    let m: MessageWithNestedGroup =
+        text_format::parse_from_str("Location { line: 2 Span { start: 3 } }").unwrap();
+    assert_eq!(2, m.get_location().get_line());
+    assert_eq!(3, m.get_location().get_span()[0].get_start());
+
+    let text = text_format::print_to_string(&m);
+    assert_eq!(m, text_format::parse_from_str(&text).unwrap());
+}
+
+#[test]
+fn test_group_view() {
+    let mut m = MessageWithGroup::new();
+    m.set_aaa("a".to_owned());
+    m.mut_identifier().push_default().set_iii(1);
+    m.mut_identifier().push_default().set_sss("s".to_owned());
+    let bytes = m.write_to_bytes().unwrap();
+
+    // groups are skipped in views
+    let view = MessageWithGroupRef::parse_view(&bytes).unwrap();
+    assert_eq!("a", view.get_aaa());
+    assert_eq!(m, view.to_owned().unwrap());
+
+    let mut m = MessageWithNestedGroup::new();
+    m.mut_location().set_line(2);
+    m.mut_location().mut_span().push_default().set_start(3);
+    let bytes = m.write_to_bytes().unwrap();
+    let view = MessageWithNestedGroupRef::parse_view(&bytes).unwrap();
+    assert_eq!(m, view.to_owned().unwrap());
+}
+
+#[test]
+fn test_group_view_missing_end_group() {
+    let bytes = decode_hex("ab 01 b0 01 02");
+    assert!(MessageWithNestedGroupRef::parse_view(&bytes).is_err());
+}
@@ -0,0 +1,50 @@
+// generated code derives `Serialize` and `Deserialize` only with `with-serde` feature
+#![cfg(feature = "with-serde")]
+
+use protobuf::Message;
+
+use serde_json;
+
+use super::test_serde_derive_pb::*;
+
+
+fn shape() -> Shape {
+    let mut m = Shape::new();
+    m.set_name("square".to_owned());
+    m.set_color(Color::GREEN);
+    m.mut_points().push_default().set_x(1);
+    m.mut_points().push_default().set_y(2);
+    m.set_data(b"ab".to_vec());
+    m.mut_corner().set_x(3);
+    m
+}
+
+#[test]
+fn test_round_trip() {
+    let m = shape();
+    let json = serde_json::to_string(&m).unwrap();
+    assert_eq!(m, serde_json::from_str(&json).unwrap());
+
+    let mut m = shape();
+    m.set_radius(5);
+    let json = serde_json::to_string(&m).unwrap();
+    assert_eq!(m, serde_json::from_str(&json).unwrap());
+}
+
+#[test]
+fn test_enum() {
+    assert_eq!("\"GREEN\"", serde_json::to_string(&Color::GREEN).unwrap());
+    assert_eq!(Color::RED, serde_json::from_str::<Color>("\"RED\"").unwrap());
+}
+
+#[test]
+fn test_unknown_fields_skipped() {
+    let mut m = Point::new();
+    m.set_x(1);
+    m.mut_unknown_fields().add_varint(10, 20);
+    let json = serde_json::to_string(&m).unwrap();
+    assert!(!json.contains("unknown_fields"));
+    let parsed: Point = serde_json::from_str(&json).unwrap();
+    assert_eq!(1, parsed.get_x());
+    assert!(parsed.get_unknown_fields().iter().next().is_none());
+}
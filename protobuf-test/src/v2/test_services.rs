@@ -0,0 +1,116 @@
+use protobuf::rpc::*;
+use protobuf::well_known_types::Int32Value;
+use protobuf::well_known_types::StringValue;
+
+use super::test_services_pb::*;
+
+
+fn reply(value: i32) -> CountReply {
+    let mut m = CountReply::new();
+    m.set_value(value);
+    m
+}
+
+fn request(count: i32) -> CountRequest {
+    let mut m = CountRequest::new();
+    m.set_count(count);
+    m
+}
+
+struct CounterImpl;
+
+impl Counter for CounterImpl {
+    fn format(&self, req: Int32Value) -> RpcResult<StringValue> {
+        if req.get_value() < 0 {
+            return Err(RpcError::Other("negative".to_owned()));
+        }
+        let mut r = StringValue::new();
+        r.set_value(format!("{}", req.get_value()));
+        Ok(r)
+    }
+
+    fn count(&self, req: CountRequest) -> RpcResult<RpcStream<CountReply>> {
+        Ok(stream_from_vec((0..req.get_count()).map(reply).collect()))
+    }
+
+    fn sum(&self, req: RpcStream<CountReply>) -> RpcResult<CountReply> {
+        let mut sum = 0;
+        for m in req {
+            sum += m?.get_value();
+        }
+        Ok(reply(sum))
+    }
+
+    fn running_sum(&self, req: RpcStream<CountReply>) -> RpcResult<RpcStream<CountReply>> {
+        let mut sum = 0;
+        Ok(Box::new(req.map(move |m| {
+            sum += m?.get_value();
+            Ok(reply(sum))
+        })))
+    }
+
+    fn method_move(&self, req: CountRequest) -> RpcResult<CountReply> {
+        Ok(reply(req.get_count() + 1))
+    }
+}
+
+fn client() -> CounterClient<LoopbackTransport> {
+    let mut transport = LoopbackTransport::new();
+    transport.add_service(counter_service_definition(CounterImpl));
+    CounterClient::new(transport)
+}
+
+fn values(stream: RpcStream<CountReply>) -> Vec<i32> {
+    stream.map(|m| m.unwrap().get_value()).collect()
+}
+
+#[test]
+fn test_unary() {
+    let client = client();
+    let mut req = Int32Value::new();
+    req.set_value(12);
+    assert_eq!("12", client.format(req).unwrap().get_value());
+
+    let mut req = Int32Value::new();
+    req.set_value(-1);
+    match client.format(req) {
+        Err(RpcError::Other(ref message)) => assert_eq!("negative", message),
+        r => panic!("unexpected result: {:?}", r),
+    }
+
+    assert_eq!(3, client.method_move(request(2)).unwrap().get_value());
+}
+
+#[test]
+fn test_streaming() {
+    let client = client();
+    assert_eq!(vec![0, 1, 2], values(client.count(request(3)).unwrap()));
+
+    let sum = client.sum(stream_from_vec(vec![reply(2), reply(5)])).unwrap();
+    assert_eq!(7, sum.get_value());
+
+    let sums = client.running_sum(stream_from_vec(vec![reply(1), reply(2), reply(3)]));
+    assert_eq!(vec![1, 3, 6], values(sums.unwrap()));
+}
+
+#[test]
+fn test_method_descriptors() {
+    assert_eq!("Format", METHOD_COUNTER_FORMAT.name);
+    assert_eq!("/test_services.Counter/Format", METHOD_COUNTER_FORMAT.full_path);
+    assert_eq!(MethodType::Unary, METHOD_COUNTER_FORMAT.method_type);
+    assert_eq!("google.protobuf.StringValue", METHOD_COUNTER_FORMAT.output_type().full_name());
+    assert_eq!(MethodType::ServerStreaming, METHOD_COUNTER_COUNT.method_type);
+    assert_eq!(MethodType::ClientStreaming, METHOD_COUNTER_SUM.method_type);
+    assert_eq!(MethodType::BidiStreaming, METHOD_COUNTER_RUNNING_SUM.method_type);
+    assert_eq!("test_services.CountRequest", METHOD_COUNTER_MOVE.input_type().full_name());
+    assert_eq!("/test_services.Counter/Move", METHOD_COUNTER_MOVE.full_path);
+}
+
+#[test]
+fn test_unknown_method() {
+    let transport = LoopbackTransport::new();
+    match call_unary(&transport, &METHOD_COUNTER_MOVE, request(1)) {
+        Err(RpcError::UnknownMethod(ref path)) => assert_eq!("/test_services.Counter/Move", path),
+        r => panic!("unexpected result: {:?}", r),
+    }
+}
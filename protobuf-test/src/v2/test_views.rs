@@ -0,0 +1,118 @@
+use protobuf::*;
+use protobuf::descriptor::FieldDescriptorProto;
+use protobuf::view::MessageView;
+
+use super::test_views_pb::*;
+
+
+fn item() -> Item {
+    let mut m = Item::new();
+    m.set_name("foo".to_owned());
+    m.mut_tags().push(b"t1".to_vec());
+    m.mut_tags().push(b"t2".to_vec());
+    m.mut_child().set_name("child".to_owned());
+    m.mut_child().set_count(3);
+    m.mut_children().push_default().set_name("c1".to_owned());
+    m.mut_children().push_default().set_name("c2".to_owned());
+    m.set_values(vec![1, 2, 3]);
+    m.set_color(Color::GREEN);
+    m.set_number(10);
+    let mut field = FieldDescriptorProto::new();
+    field.set_name("f".to_owned());
+    m.mut_fields().push(field);
+    m.mut_counts().insert("k".to_owned(), 1);
+    m
+}
+
+#[test]
+fn test_singular_fields() {
+    let m = item();
+    let bytes = m.write_to_bytes().unwrap();
+    let view = ItemRef::parse_view(&bytes).unwrap();
+
+    let name: &str = view.get_name();
+    assert_eq!("foo", name);
+    // borrowed from input
+    assert_eq!(bytes[2..5].as_ptr(), name.as_ptr());
+    assert!(view.has_name());
+    assert!(!view.has_count());
+    assert_eq!(7, view.get_count());
+    assert_eq!(Color::GREEN, view.get_color());
+
+    let child = view.get_child().unwrap();
+    assert_eq!("child", child.get_name());
+    assert_eq!(3, child.get_count());
+    assert!(!child.has_child());
+}
+
+#[test]
+fn test_oneof() {
+    let mut m = item();
+    let bytes = m.write_to_bytes().unwrap();
+    let view = ItemRef::parse_view(&bytes).unwrap();
+    assert!(view.has_number());
+    assert!(!view.has_text());
+    assert_eq!(10, view.get_number());
+
+    // last value of oneof wins
+    m.set_text("t".to_owned());
+    let mut bytes = item().write_to_bytes().unwrap();
+    bytes.extend(m.write_to_bytes().unwrap());
+    let view = ItemRef::parse_view(&bytes).unwrap();
+    assert!(!view.has_number());
+    assert_eq!("t", view.get_text());
+}
+
+#[test]
+fn test_repeated_fields() {
+    let m = item();
+    let bytes = m.write_to_bytes().unwrap();
+    let view = ItemRef::parse_view(&bytes).unwrap();
+
+    let tags: Vec<&[u8]> = view.get_tags().to_vec().unwrap();
+    assert_eq!(vec![&b"t1"[..], &b"t2"[..]], tags);
+    assert_eq!(vec![1, 2, 3], view.get_values().to_vec().unwrap());
+    let names: Vec<&str> = view
+        .get_children()
+        .iter()
+        .map(|c| c.unwrap().get_name())
+        .collect();
+    assert_eq!(vec!["c1", "c2"], names);
+    let fields = view.get_fields().to_vec().unwrap();
+    assert_eq!(1, fields.len());
+    assert_eq!("f", fields[0].get_name());
+}
+
+#[test]
+fn test_singular_message_last_occurrence() {
+    let mut a = Item::new();
+    a.mut_child().set_name("a".to_owned());
+    let mut b = Item::new();
+    b.mut_child().set_count(3);
+    let mut bytes = a.write_to_bytes().unwrap();
+    bytes.extend(b.write_to_bytes().unwrap());
+
+    let view = ItemRef::parse_view(&bytes).unwrap();
+    let child = view.get_child().unwrap();
+    assert!(!child.has_name());
+    assert_eq!(3, child.get_count());
+
+    let owned = view.to_owned().unwrap();
+    assert!(!owned.get_child().has_name());
+    assert_eq!(3, owned.get_child().get_count());
+}
+
+#[test]
+fn test_to_owned() {
+    let m = item();
+    let bytes = m.write_to_bytes().unwrap();
+    let view = ItemRef::parse_view(&bytes).unwrap();
+    // map fields are not in views, but are kept in owned message
+    assert_eq!(m, view.to_owned().unwrap());
+}
+
+#[test]
+fn test_truncated() {
+    let bytes = item().write_to_bytes().unwrap();
+    assert!(ItemRef::parse_view(&bytes[..bytes.len() - 1]).is_err());
+}
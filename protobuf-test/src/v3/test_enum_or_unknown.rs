@@ -0,0 +1,60 @@
+use protobuf::*;
+use protobuf::reflect::ProtobufValueRef;
+use protobuf::reflect::ReflectError;
+use protobuf::reflect::ReflectFieldRef;
+use protobuf::text_format;
+
+use protobuf_test_common::*;
+use protobuf_test_common::hex::decode_hex;
+
+use super::test_enum_or_unknown_pb::*;
+
+
+#[test]
+fn test_known_values() {
+    let mut m = TestEnumOrUnknown::new();
+    m.set_color(EnumOrUnknown::new(Color::GREEN));
+    m.mut_colors().push(EnumOrUnknown::new(Color::RED));
+    m.mut_colors().push(EnumOrUnknown::new(Color::GREEN));
+    test_serialize_deserialize("08 01 12 02 00 01", &m);
+    assert_eq!(Ok(Color::GREEN), m.get_color().enum_value());
+}
+
+#[test]
+fn test_unknown_values_kept_in_fields() {
+    // color: 5, colors: [1, 7], color_map: {"a": 9}
+    let bytes = decode_hex("08 05 12 02 01 07 1a 05 0a 01 61 10 09");
+    let m: TestEnumOrUnknown = parse_from_bytes(&bytes).unwrap();
+    assert_eq!(Err(5), m.get_color().enum_value());
+    assert_eq!(vec![1, 7], m.get_colors().iter().map(|c| c.value()).collect::<Vec<_>>());
+    assert_eq!(9, m.get_color_map()["a"].value());
+    assert!(m.get_unknown_fields().iter().next().is_none());
+    assert_eq!(bytes, m.write_to_bytes().unwrap());
+}
+
+#[test]
+fn test_oneof_is_not_affected() {
+    // oneof enum fields are generated as regular enums
+    let mut m = TestEnumOrUnknown::new();
+    m.set_oneof_color(Color::GREEN);
+    test_serialize_deserialize("20 01", &m);
+}
+
+#[test]
+fn test_reflect_and_text_format() {
+    let bytes = decode_hex("08 05");
+    let m: TestEnumOrUnknown = parse_from_bytes(&bytes).unwrap();
+    let field = m.descriptor().field_by_name("color");
+    match field.get_reflect(&m) {
+        ReflectFieldRef::Optional(Some(ProtobufValueRef::UnknownEnum(_, 5))) => {}
+        _ => panic!("expecting unknown enum value"),
+    }
+    match field.try_get_enum(&m) {
+        Err(ReflectError::UnknownEnumValue { value: 5, .. }) => {}
+        r => panic!("expecting unknown enum value error: {:?}", r.map(|v| v.name())),
+    }
+    assert_eq!("RED", field.get_enum(&TestEnumOrUnknown::new()).name());
+
+    assert_eq!("color: 5", text_format::print_to_string(&m));
+    assert_eq!(m, text_format::parse_from_str("color: 5").unwrap());
+}
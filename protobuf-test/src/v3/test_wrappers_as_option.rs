@@ -0,0 +1,86 @@
+use protobuf::*;
+use protobuf::reflect::ReflectValueBox;
+use protobuf::reflect::RuntimeFieldType;
+use protobuf::reflect::RuntimeType;
+use protobuf::well_known_types::FieldMask;
+use protobuf::well_known_types::Int32Value;
+use protobuf::well_known_types::StringValue;
+use protobuf::well_known_types_util::field_mask::merge_message_to;
+use protobuf::well_known_types_util::field_mask::trim_message;
+
+use protobuf_test_common::*;
+
+use super::test_wrappers_as_option_pb::*;
+
+#[test]
+fn test_wire_format() {
+    let mut m = TestWrappersAsOption::new();
+    test_serialize_deserialize("", &m);
+
+    m.count = Some(0);
+    test_serialize_deserialize("0a 00", &m);
+
+    m.count = Some(5);
+    m.name = Some("ab".to_owned());
+    m.names.push(StringValue::from("c"));
+    test_serialize_deserialize("0a 02 08 05 12 04 0a 02 61 62 1a 03 0a 01 63", &m);
+}
+
+#[test]
+fn test_reflect_wrapper_type() {
+    let d = TestWrappersAsOption::descriptor_static(None);
+    match d.field_by_name("count").runtime_field_type() {
+        RuntimeFieldType::Singular(RuntimeType::Message(t)) => {
+            assert_eq!("google.protobuf.Int32Value", t.full_name());
+        }
+        _ => panic!("not a message field"),
+    }
+
+    let mut m = TestWrappersAsOption::new();
+    let count = d.field_by_name("count");
+    count.set_singular_field(&mut m, ReflectValueBox::Message(Box::new(Int32Value::from(7))));
+    assert_eq!(Some(7), m.count);
+    assert!(count.has_field(&m));
+    assert!(count.try_get_message(&m).is_err());
+    count.clear_field(&mut m);
+    assert_eq!(None, m.count);
+}
+
+#[test]
+fn test_text_format() {
+    let mut m = TestWrappersAsOption::new();
+    m.count = Some(0);
+    m.name = Some("ab".to_owned());
+    let text = "count {} name {value: \"ab\"}";
+    assert_eq!(text, text_format::print_to_string(&m));
+    assert_eq!(m, text_format::parse_from_str(text).unwrap());
+
+    let parsed: TestWrappersAsOption = text_format::parse_from_str("count { value: 5 }").unwrap();
+    assert_eq!(Some(5), parsed.count);
+}
+
+#[test]
+fn test_json() {
+    let mut m = TestWrappersAsOption::new();
+    m.count = Some(5);
+    m.name = Some("ab".to_owned());
+    let text = "{\"count\":5,\"name\":\"ab\"}";
+    assert_eq!(text, json::print_to_string(&m).unwrap());
+    assert_eq!(m, json::parse_from_str(text).unwrap());
+}
+
+#[test]
+fn test_field_mask() {
+    let mut src = TestWrappersAsOption::new();
+    src.count = Some(5);
+    src.name = Some("ab".to_owned());
+    let mut dst = TestWrappersAsOption::new();
+    dst.count = Some(1);
+    let mask = FieldMask::from_paths(vec!["count", "name.value"]);
+    merge_message_to(&src, &mut dst, &mask, &Default::default()).unwrap();
+    assert_eq!(src, dst);
+
+    trim_message(&mut dst, &FieldMask::from_paths(vec!["count"])).unwrap();
+    assert_eq!(Some(5), dst.count);
+    assert_eq!(None, dst.name);
+}
@@ -0,0 +1,385 @@
+//! Length-delimited message streams for `futures` and `tokio`.
+//!
+//! Each message is prefixed with its length encoded as varint, like
+//! `Message::write_length_delimited_to_writer` writes.
+//!
+//! `LengthDelimitedCodec` is used with `tokio_codec::FramedRead`,
+//! `FramedWrite` or `Framed` to read and write streams of messages.
+//! `read_length_delimited` and `write_length_delimited` read and write a single message.
+
+use std::marker::PhantomData;
+
+use bytes::BufMut;
+use bytes::BytesMut;
+use futures::Async;
+use futures::Future;
+use futures::Poll;
+use tokio_codec::Decoder;
+use tokio_codec::Encoder;
+use tokio_io::AsyncRead;
+use tokio_io::AsyncWrite;
+use tokio_io::io::WriteAll;
+use tokio_io::io::write_all;
+
+use core::Message;
+use core::parse_from_bytes;
+use error::ProtobufError;
+use error::ProtobufResult;
+use error::WireError;
+
+/// Default limit of message length
+pub const DEFAULT_MAX_FRAME_SIZE: usize = 64 << 20;
+
+/// Decode varint at the start of `buf`:
+/// value and its encoded length, `None` if `buf` contains only a part of varint
+fn decode_varint_prefix(buf: &[u8]) -> ProtobufResult<Option<(u64, usize)>> {
+    let mut value = 0u64;
+    for (i, &b) in buf.iter().enumerate() {
+        if i == 10 {
+            return Err(ProtobufError::WireError(WireError::IncorrectVarint));
+        }
+        value |= ((b & 0x7f) as u64) << (7 * i);
+        if b < 0x80 {
+            return Ok(Some((value, i + 1)));
+        }
+    }
+    Ok(None)
+}
+
+fn check_frame_size(len: u64, max_frame_size: usize) -> ProtobufResult<usize> {
+    if len > max_frame_size as u64 {
+        return Err(ProtobufError::WireError(WireError::MessageTooLarge(len)));
+    }
+    Ok(len as usize)
+}
+
+/// Codec of length-delimited messages of type `M`
+pub struct LengthDelimitedCodec<M> {
+    max_frame_size: usize,
+    phantom: PhantomData<M>,
+}
+
+impl<M : Message> LengthDelimitedCodec<M> {
+    pub fn new() -> LengthDelimitedCodec<M> {
+        LengthDelimitedCodec {
+            max_frame_size: DEFAULT_MAX_FRAME_SIZE,
+            phantom: PhantomData,
+        }
+    }
+
+    pub fn max_frame_size(&self) -> usize {
+        self.max_frame_size
+    }
+
+    /// Messages longer than the limit are not read or written
+    pub fn set_max_frame_size(&mut self, max_frame_size: usize) {
+        self.max_frame_size = max_frame_size;
+    }
+}
+
+impl<M : Message> Decoder for LengthDelimitedCodec<M> {
+    type Item = M;
+    type Error = ProtobufError;
+
+    fn decode(&mut self, src: &mut BytesMut) -> ProtobufResult<Option<M>> {
+        let (len, prefix_len) = match decode_varint_prefix(src)? {
+            Some(r) => r,
+            None => return Ok(None),
+        };
+        let len = check_frame_size(len, self.max_frame_size)?;
+        if src.len() < prefix_len + len {
+            src.reserve(prefix_len + len - src.len());
+            return Ok(None);
+        }
+        let frame = src.split_to(prefix_len + len);
+        parse_from_bytes(&frame[prefix_len..]).map(Some)
+    }
+}
+
+impl<M : Message> Encoder for LengthDelimitedCodec<M> {
+    type Item = M;
+    type Error = ProtobufError;
+
+    fn encode(&mut self, item: M, dst: &mut BytesMut) -> ProtobufResult<()> {
+        let size = item.compute_size() as u64;
+        check_frame_size(size, self.max_frame_size)?;
+        let bytes = item.write_length_delimited_to_bytes()?;
+        dst.reserve(bytes.len());
+        dst.put_slice(&bytes);
+        Ok(())
+    }
+}
+
+/// Future returned by `read_length_delimited`
+pub struct ReadLengthDelimited<R, M> {
+    reader: Option<R>,
+    max_frame_size: usize,
+    // length prefix while it is read, then message
+    buf: Vec<u8>,
+    len: Option<usize>,
+    pos: usize,
+    phantom: PhantomData<M>,
+}
+
+/// Read exactly one length-delimited message, nothing after the message is read.
+///
+/// Future resolves to the reader and the message.
+pub fn read_length_delimited<R : AsyncRead, M : Message>(
+    reader: R,
+    max_frame_size: usize,
+) -> ReadLengthDelimited<R, M> {
+    ReadLengthDelimited {
+        reader: Some(reader),
+        max_frame_size: max_frame_size,
+        buf: Vec::new(),
+        len: None,
+        pos: 0,
+        phantom: PhantomData,
+    }
+}
+
+impl<R : AsyncRead, M : Message> ReadLengthDelimited<R, M> {
+    fn poll_read(&mut self) -> Poll<usize, ProtobufError> {
+        let reader = self.reader.as_mut().expect("poll after completion");
+        let n = match self.len {
+            // length prefix is read byte by byte to not read past the message
+            None => {
+                let mut byte = [0];
+                let n = try_ready!(reader.poll_read(&mut byte));
+                self.buf.extend(&byte[..n]);
+                n
+            }
+            Some(_) => try_ready!(reader.poll_read(&mut self.buf[self.pos..])),
+        };
+        if n == 0 {
+            return Err(ProtobufError::WireError(WireError::UnexpectedEof));
+        }
+        Ok(Async::Ready(n))
+    }
+}
+
+impl<R : AsyncRead, M : Message> Future for ReadLengthDelimited<R, M> {
+    type Item = (R, M);
+    type Error = ProtobufError;
+
+    fn poll(&mut self) -> Poll<(R, M), ProtobufError> {
+        loop {
+            match self.len {
+                None => {
+                    if let Some((len, _)) = decode_varint_prefix(&self.buf)? {
+                        let len = check_frame_size(len, self.max_frame_size)?;
+                        self.len = Some(len);
+                        self.buf = vec![0; len];
+                        continue;
+                    }
+                }
+                Some(len) if self.pos == len => {
+                    let message = parse_from_bytes(&self.buf)?;
+                    return Ok(Async::Ready((self.reader.take().unwrap(), message)));
+                }
+                Some(_) => {}
+            }
+            let n = try_ready!(self.poll_read());
+            if self.len.is_some() {
+                self.pos += n;
+            }
+        }
+    }
+}
+
+/// Future returned by `write_length_delimited`
+pub struct WriteLengthDelimited<W> {
+    inner: WriteAll<W, Vec<u8>>,
+}
+
+/// Write message with length prefix.
+///
+/// Future resolves to the writer.
+pub fn write_length_delimited<W : AsyncWrite, M : Message>(
+    writer: W,
+    message: &M,
+) -> ProtobufResult<WriteLengthDelimited<W>> {
+    let bytes = message.write_length_delimited_to_bytes()?;
+    Ok(WriteLengthDelimited { inner: write_all(writer, bytes) })
+}
+
+impl<W : AsyncWrite> Future for WriteLengthDelimited<W> {
+    type Item = W;
+    type Error = ProtobufError;
+
+    fn poll(&mut self) -> Poll<W, ProtobufError> {
+        let (writer, _) = try_ready!(self.inner.poll());
+        Ok(Async::Ready(writer))
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::collections::VecDeque;
+    use std::io;
+    use std::io::Read;
+    use std::io::Write;
+    use std::sync::Arc;
+    use std::sync::Mutex;
+    use std::thread;
+
+    use futures::Sink;
+    use futures::Stream;
+    use futures::task;
+    use futures::task::Task;
+    use tokio_codec::FramedRead;
+    use tokio_codec::FramedWrite;
+
+    use well_known_types::StringValue;
+
+    #[derive(Default)]
+    struct PipeBuf {
+        data: VecDeque<u8>,
+        closed: bool,
+        reader: Option<Task>,
+    }
+
+    /// One direction of in-memory pipe
+    #[derive(Clone, Default)]
+    struct Pipe(Arc<Mutex<PipeBuf>>);
+
+    /// End of in-memory duplex pipe
+    struct DuplexEnd {
+        read: Pipe,
+        write: Pipe,
+    }
+
+    fn duplex() -> (DuplexEnd, DuplexEnd) {
+        let a = Pipe::default();
+        let b = Pipe::default();
+        (
+            DuplexEnd { read: a.clone(), write: b.clone() },
+            DuplexEnd { read: b, write: a },
+        )
+    }
+
+    impl Read for DuplexEnd {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let mut pipe = self.read.0.lock().unwrap();
+            if pipe.data.is_empty() && !pipe.closed {
+                pipe.reader = Some(task::current());
+                return Err(io::ErrorKind::WouldBlock.into());
+            }
+            let n = buf.len().min(pipe.data.len());
+            for (i, b) in pipe.data.drain(..n).enumerate() {
+                buf[i] = b;
+            }
+            Ok(n)
+        }
+    }
+
+    impl Write for DuplexEnd {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            let mut pipe = self.write.0.lock().unwrap();
+            pipe.data.extend(buf);
+            if let Some(task) = pipe.reader.take() {
+                task.notify();
+            }
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl AsyncRead for DuplexEnd {}
+
+    impl AsyncWrite for DuplexEnd {
+        fn shutdown(&mut self) -> Poll<(), io::Error> {
+            Ok(Async::Ready(()))
+        }
+    }
+
+    impl Drop for DuplexEnd {
+        fn drop(&mut self) {
+            let mut pipe = self.write.0.lock().unwrap();
+            pipe.closed = true;
+            if let Some(task) = pipe.reader.take() {
+                task.notify();
+            }
+        }
+    }
+
+    fn string_value(value: &str) -> StringValue {
+        let mut m = StringValue::new();
+        m.set_value(value.to_owned());
+        m
+    }
+
+    #[test]
+    fn test_codec() {
+        let mut codec = LengthDelimitedCodec::<StringValue>::new();
+        let mut buf = BytesMut::new();
+        codec.encode(string_value("ab"), &mut buf).unwrap();
+        codec.encode(string_value(""), &mut buf).unwrap();
+        assert_eq!(&b"\x04\x0a\x02ab\x00"[..], &buf[..]);
+
+        let mut partial = buf.split_to(3);
+        assert!(codec.decode(&mut partial).unwrap().is_none());
+        partial.extend_from_slice(&buf);
+        assert_eq!("ab", codec.decode(&mut partial).unwrap().unwrap().get_value());
+        assert_eq!("", codec.decode(&mut partial).unwrap().unwrap().get_value());
+        assert!(codec.decode(&mut partial).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_codec_max_frame_size() {
+        let mut codec = LengthDelimitedCodec::<StringValue>::new();
+        codec.set_max_frame_size(3);
+        let mut buf = BytesMut::new();
+        assert!(codec.encode(string_value("abc"), &mut buf).is_err());
+        buf.extend_from_slice(b"\x05");
+        match codec.decode(&mut buf) {
+            Err(ProtobufError::WireError(WireError::MessageTooLarge(5))) => {}
+            r => panic!("{:?}", r),
+        }
+    }
+
+    #[test]
+    fn test_framed_over_pipe() {
+        let (client, server) = duplex();
+        let writer = thread::spawn(move || {
+            let messages: Vec<StringValue> = (0..100).map(|i| string_value(&i.to_string())).collect();
+            let messages = ::futures::stream::iter_ok::<_, ProtobufError>(messages);
+            let (_sink, _stream) = FramedWrite::new(client, LengthDelimitedCodec::new())
+                .send_all(messages)
+                .wait()
+                .unwrap();
+        });
+        let received: Vec<StringValue> = FramedRead::new(server, LengthDelimitedCodec::new())
+            .collect()
+            .wait()
+            .unwrap();
+        writer.join().unwrap();
+        assert_eq!(100, received.len());
+        assert_eq!("99", received[99].get_value());
+    }
+
+    #[test]
+    fn test_read_write_length_delimited() {
+        let (client, server) = duplex();
+        let writer = thread::spawn(move || {
+            let client = write_length_delimited(client, &string_value("first")).unwrap().wait().unwrap();
+            write_length_delimited(client, &string_value("second")).unwrap().wait().unwrap();
+        });
+        let (server, first): (_, StringValue) =
+            read_length_delimited(server, DEFAULT_MAX_FRAME_SIZE).wait().unwrap();
+        let (server, second): (_, StringValue) =
+            read_length_delimited(server, DEFAULT_MAX_FRAME_SIZE).wait().unwrap();
+        writer.join().unwrap();
+        assert_eq!("first", first.get_value());
+        assert_eq!("second", second.get_value());
+
+        // writer is closed
+        assert!(read_length_delimited::<_, StringValue>(server, DEFAULT_MAX_FRAME_SIZE).wait().is_err());
+    }
+}
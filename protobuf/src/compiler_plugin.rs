@@ -7,6 +7,7 @@ use std::str;
 use plugin::*;
 use protobuf::parse_from_reader;
 use protobuf::Message;
+use protobuf::ProtobufEnum;
 use protobuf::descriptor::FileDescriptorProto;
 
 
@@ -21,6 +22,9 @@ pub fn plugin_main<F>(gen: F)
     let req = parse_from_reader::<CodeGeneratorRequest>(&mut stdin()).unwrap();
     let result = gen(req.get_proto_file(), req.get_file_to_generate());
     let mut resp = CodeGeneratorResponse::new();
+    // generated code tracks presence of proto3 `optional` fields
+    let features = CodeGeneratorResponse_Feature::FEATURE_PROTO3_OPTIONAL.value() as u64;
+    resp.set_supported_features(features);
     resp.set_file(
         result
             .iter()
@@ -11,11 +11,15 @@ use clear::Clear;
 use reflect::MessageDescriptor;
 use reflect::EnumDescriptor;
 use reflect::EnumValueDescriptor;
+use reflect::ProtobufValueRef;
+use reflect::ReflectFieldRef;
+use descriptor::FieldDescriptorProto_Label;
 use unknown::UnknownFields;
 use stream::WithCodedInputStream;
 use stream::WithCodedOutputStream;
 use stream::CodedInputStream;
 use stream::CodedOutputStream;
+use stream::ParseOptions;
 use stream::with_coded_output_stream_to_bytes;
 use error::ProtobufError;
 use error::ProtobufResult;
@@ -27,6 +31,11 @@ pub trait Message: fmt::Debug + Clear + Any + Send + Sync {
     /// Message descriptor for this message, used for reflection.
     fn descriptor(&self) -> &'static MessageDescriptor;
 
+    /// Message descriptor, `None` for messages generated with `LITE_RUNTIME`.
+    fn descriptor_if_available(&self) -> Option<&'static MessageDescriptor> {
+        Some(self.descriptor())
+    }
+
     /// True iff all required fields are initialized.
     /// Always returns `true` for protobuf 3.
     fn is_initialized(&self) -> bool;
@@ -86,13 +95,14 @@ pub trait Message: fmt::Debug + Clear + Any + Send + Sync {
     /// Update this message object with fields read from given stream.
     fn merge_from_bytes(&mut self, bytes: &[u8]) -> ProtobufResult<()> {
         let mut is = CodedInputStream::from_bytes(bytes);
-        self.merge_from(&mut is)
+        is.merge_located(self, |m, is| m.merge_from(is))
     }
 
     /// Check if all required fields of this object are initialized.
     fn check_initialized(&self) -> ProtobufResult<()> {
         if !self.is_initialized() {
-            Err(ProtobufError::message_not_initialized(self.descriptor().name()))
+            let message = self.descriptor_if_available().map_or("", |d| d.name());
+            Err(ProtobufError::message_not_initialized(message))
         } else {
             Ok(())
         }
@@ -175,8 +185,8 @@ pub trait Message: fmt::Debug + Clear + Any + Send + Sync {
         where Self : Sized
     {
         panic!(
-            "descriptor_static is not implemented for message, \
-             LITE_RUNTIME must be used"
+            "message descriptor is not available: \
+             message is generated with LITE_RUNTIME"
         );
     }
 
@@ -185,10 +195,63 @@ pub trait Message: fmt::Debug + Clear + Any + Send + Sync {
         where Self : Sized;
 }
 
+/// Collect paths of unset required fields of `m` and nested messages
+fn missing_required_fields(m: &Message, path: &str, result: &mut Vec<String>) {
+    for field in m.descriptor().fields() {
+        let field_path = format!("{}.{}", path, field.name());
+        match field.get_reflect(m) {
+            ReflectFieldRef::Optional(None) => {
+                if field.proto().get_label() == FieldDescriptorProto_Label::LABEL_REQUIRED {
+                    result.push(field_path);
+                }
+            }
+            ReflectFieldRef::Optional(Some(ProtobufValueRef::Message(nested))) => {
+                if !nested.is_initialized() {
+                    missing_required_fields(nested, &field_path, result);
+                }
+            }
+            ReflectFieldRef::Optional(Some(..)) => {}
+            ReflectFieldRef::Repeated(repeated) => {
+                for i in 0..repeated.len() {
+                    if let ProtobufValueRef::Message(nested) = repeated.get(i).as_ref() {
+                        if !nested.is_initialized() {
+                            let path = format!("{}[{}]", field_path, i);
+                            missing_required_fields(nested, &path, result);
+                        }
+                    }
+                }
+            }
+            ReflectFieldRef::Map(map) => {
+                for (k, v) in map.reflect_iter() {
+                    if let ProtobufValueRef::Message(nested) = v.as_ref() {
+                        if !nested.is_initialized() {
+                            let key = match k.as_ref() {
+                                ProtobufValueRef::String(s) => format!("{:?}", s),
+                                ProtobufValueRef::U32(v) => v.to_string(),
+                                ProtobufValueRef::U64(v) => v.to_string(),
+                                ProtobufValueRef::I32(v) => v.to_string(),
+                                ProtobufValueRef::I64(v) => v.to_string(),
+                                ProtobufValueRef::Bool(v) => v.to_string(),
+                                _ => "?".to_owned(),
+                            };
+                            let path = format!("{}[{}]", field_path, key);
+                            missing_required_fields(nested, &path, result);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
 pub fn message_down_cast<'a, M : Message + 'a>(m: &'a Message) -> &'a M {
     m.as_any().downcast_ref::<M>().unwrap()
 }
 
+pub fn message_down_cast_mut<'a, M : Message + 'a>(m: &'a mut Message) -> &'a mut M {
+    m.as_any_mut().downcast_mut::<M>().unwrap()
+}
+
 
 /// Trait implemented by all protobuf enum types.
 pub trait ProtobufEnum: Eq + Sized + Copy + 'static {
@@ -200,8 +263,15 @@ pub trait ProtobufEnum: Eq + Sized + Copy + 'static {
     fn from_i32(v: i32) -> Option<Self>;
 
     /// Get all enum values for enum type.
+    ///
+    /// Implemented by generated code, panics for hand-written enums.
     fn values() -> &'static [Self] {
-        panic!();
+        panic!("values are not available: enum is not generated");
+    }
+
+    /// Get all enum values for enum type, `None` for hand-written enums.
+    fn get_values() -> Option<&'static [Self]> {
+        None
     }
 
     /// Get enum value descriptor.
@@ -215,17 +285,49 @@ pub trait ProtobufEnum: Eq + Sized + Copy + 'static {
     }
 
     /// Get enum descriptor by type.
+    ///
+    /// Panics for enums generated with `LITE_RUNTIME` and for hand-written enums.
     // http://stackoverflow.com/q/20342436/15018
     fn enum_descriptor_static(_: Option<Self>) -> &'static EnumDescriptor {
-        panic!();
+        panic!(
+            "enum descriptor is not available: \
+             enum is generated with LITE_RUNTIME or is not generated"
+        );
+    }
+
+    /// Get enum descriptor by type, `None` for enums generated with `LITE_RUNTIME`
+    /// and for hand-written enums.
+    fn get_enum_descriptor_static(_: Option<Self>) -> Option<&'static EnumDescriptor> {
+        None
     }
 }
 
+/// Like `Message::check_initialized`, but error lists paths of missing fields
+pub(crate) fn check_initialized_with_paths(m: &Message) -> ProtobufResult<()> {
+    if m.is_initialized() {
+        return Ok(());
+    }
+    let descriptor = match m.descriptor_if_available() {
+        Some(d) => d,
+        None => return m.check_initialized(),
+    };
+    let mut missing_fields = Vec::new();
+    missing_required_fields(m, descriptor.name(), &mut missing_fields);
+    Err(ProtobufError::MissingFields {
+        message: descriptor.name(),
+        missing_fields: missing_fields,
+    })
+}
+
 /// Parse message from stream.
 pub fn parse_from<M : Message>(is: &mut CodedInputStream) -> ProtobufResult<M> {
     let mut r: M = Message::new();
-    r.merge_from(is)?;
-    r.check_initialized()?;
+    is.merge_located(&mut r, |m, is| m.merge_from(is))?;
+    if is.parse_options().locate_errors {
+        check_initialized_with_paths(&r)?;
+    } else {
+        r.check_initialized()?;
+    }
     Ok(r)
 }
 
@@ -240,6 +342,28 @@ pub fn parse_from_bytes<M : Message>(bytes: &[u8]) -> ProtobufResult<M> {
     bytes.with_coded_input_stream(|is| parse_from::<M>(is))
 }
 
+/// Parse message from reader with size limits.
+pub fn parse_from_reader_with_options<M : Message>(
+    reader: &mut Read,
+    options: &ParseOptions,
+) -> ProtobufResult<M> {
+    reader.with_coded_input_stream(|is| {
+        is.set_parse_options(options.clone());
+        parse_from::<M>(is)
+    })
+}
+
+/// Parse message from byte array with size limits.
+pub fn parse_from_bytes_with_options<M : Message>(
+    bytes: &[u8],
+    options: &ParseOptions,
+) -> ProtobufResult<M> {
+    bytes.with_coded_input_stream(|is| {
+        is.set_parse_options(options.clone());
+        parse_from::<M>(is)
+    })
+}
+
 /// Parse message from `Bytes` object.
 /// Resulting message may share references to the passed bytes object.
 #[cfg(feature = "bytes")]
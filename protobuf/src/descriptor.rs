@@ -22,11 +22,14 @@ use protobuf::Message as Message_imported_for_functions;
 use protobuf::ProtobufEnum as ProtobufEnum_imported_for_functions;
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
 pub struct FileDescriptorSet {
     // message fields
     file: ::protobuf::RepeatedField<FileDescriptorProto>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     cached_size: ::protobuf::CachedSize,
 }
 
@@ -192,6 +195,7 @@ impl ::protobuf::reflect::ProtobufValue for FileDescriptorSet {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
 pub struct FileDescriptorProto {
     // message fields
     name: ::protobuf::SingularField<::std::string::String>,
@@ -207,7 +211,9 @@ pub struct FileDescriptorProto {
     source_code_info: ::protobuf::SingularPtrField<SourceCodeInfo>,
     syntax: ::protobuf::SingularField<::std::string::String>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     cached_size: ::protobuf::CachedSize,
 }
 
@@ -902,6 +908,7 @@ impl ::protobuf::reflect::ProtobufValue for FileDescriptorProto {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
 pub struct DescriptorProto {
     // message fields
     name: ::protobuf::SingularField<::std::string::String>,
@@ -915,7 +922,9 @@ pub struct DescriptorProto {
     reserved_range: ::protobuf::RepeatedField<DescriptorProto_ReservedRange>,
     reserved_name: ::protobuf::RepeatedField<::std::string::String>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     cached_size: ::protobuf::CachedSize,
 }
 
@@ -1516,12 +1525,15 @@ impl ::protobuf::reflect::ProtobufValue for DescriptorProto {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
 pub struct DescriptorProto_ExtensionRange {
     // message fields
     start: ::std::option::Option<i32>,
     end: ::std::option::Option<i32>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     cached_size: ::protobuf::CachedSize,
 }
 
@@ -1715,12 +1727,15 @@ impl ::protobuf::reflect::ProtobufValue for DescriptorProto_ExtensionRange {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
 pub struct DescriptorProto_ReservedRange {
     // message fields
     start: ::std::option::Option<i32>,
     end: ::std::option::Option<i32>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     cached_size: ::protobuf::CachedSize,
 }
 
@@ -1914,6 +1929,7 @@ impl ::protobuf::reflect::ProtobufValue for DescriptorProto_ReservedRange {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
 pub struct FieldDescriptorProto {
     // message fields
     name: ::protobuf::SingularField<::std::string::String>,
@@ -1926,8 +1942,11 @@ pub struct FieldDescriptorProto {
     oneof_index: ::std::option::Option<i32>,
     json_name: ::protobuf::SingularField<::std::string::String>,
     options: ::protobuf::SingularPtrField<FieldOptions>,
+    proto3_optional: ::std::option::Option<bool>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     cached_size: ::protobuf::CachedSize,
 }
 
@@ -2224,6 +2243,25 @@ impl FieldDescriptorProto {
     pub fn get_options(&self) -> &FieldOptions {
         self.options.as_ref().unwrap_or_else(|| FieldOptions::default_instance())
     }
+
+    // optional bool proto3_optional = 17;
+
+    pub fn clear_proto3_optional(&mut self) {
+        self.proto3_optional = ::std::option::Option::None;
+    }
+
+    pub fn has_proto3_optional(&self) -> bool {
+        self.proto3_optional.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_proto3_optional(&mut self, v: bool) {
+        self.proto3_optional = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_proto3_optional(&self) -> bool {
+        self.proto3_optional.unwrap_or(false)
+    }
 }
 
 impl ::protobuf::Message for FieldDescriptorProto {
@@ -2278,6 +2316,13 @@ impl ::protobuf::Message for FieldDescriptorProto {
                 8 => {
                     ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.options)?;
                 },
+                17 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.proto3_optional = ::std::option::Option::Some(tmp);
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -2321,6 +2366,9 @@ impl ::protobuf::Message for FieldDescriptorProto {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         }
+        if let Some(v) = self.proto3_optional {
+            my_size += 3;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -2359,6 +2407,9 @@ impl ::protobuf::Message for FieldDescriptorProto {
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
         }
+        if let Some(v) = self.proto3_optional {
+            os.write_bool(17, v)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -2451,6 +2502,11 @@ impl ::protobuf::Message for FieldDescriptorProto {
                     |m: &FieldDescriptorProto| { &m.options },
                     |m: &mut FieldDescriptorProto| { &mut m.options },
                 ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "proto3_optional",
+                    |m: &FieldDescriptorProto| { &m.proto3_optional },
+                    |m: &mut FieldDescriptorProto| { &mut m.proto3_optional },
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<FieldDescriptorProto>(
                     "FieldDescriptorProto",
                     fields,
@@ -2483,6 +2539,7 @@ impl ::protobuf::Clear for FieldDescriptorProto {
         self.clear_oneof_index();
         self.clear_json_name();
         self.clear_options();
+        self.clear_proto3_optional();
         self.unknown_fields.clear();
     }
 }
@@ -2500,6 +2557,7 @@ impl ::protobuf::reflect::ProtobufValue for FieldDescriptorProto {
 }
 
 #[derive(Clone,PartialEq,Eq,Debug,Hash)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
 pub enum FieldDescriptorProto_Type {
     TYPE_DOUBLE = 1,
     TYPE_FLOAT = 2,
@@ -2574,6 +2632,10 @@ impl ::protobuf::ProtobufEnum for FieldDescriptorProto_Type {
         values
     }
 
+    fn get_values() -> ::std::option::Option<&'static [Self]> {
+        ::std::option::Option::Some(<Self as ::protobuf::ProtobufEnum>::values())
+    }
+
     fn enum_descriptor_static(_: ::std::option::Option<FieldDescriptorProto_Type>) -> &'static ::protobuf::reflect::EnumDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
@@ -2585,6 +2647,10 @@ impl ::protobuf::ProtobufEnum for FieldDescriptorProto_Type {
             })
         }
     }
+
+    fn get_enum_descriptor_static(_: ::std::option::Option<FieldDescriptorProto_Type>) -> ::std::option::Option<&'static ::protobuf::reflect::EnumDescriptor> {
+        ::std::option::Option::Some(<FieldDescriptorProto_Type as ::protobuf::ProtobufEnum>::enum_descriptor_static(::std::option::Option::None))
+    }
 }
 
 impl ::std::marker::Copy for FieldDescriptorProto_Type {
@@ -2597,6 +2663,7 @@ impl ::protobuf::reflect::ProtobufValue for FieldDescriptorProto_Type {
 }
 
 #[derive(Clone,PartialEq,Eq,Debug,Hash)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
 pub enum FieldDescriptorProto_Label {
     LABEL_OPTIONAL = 1,
     LABEL_REQUIRED = 2,
@@ -2626,6 +2693,10 @@ impl ::protobuf::ProtobufEnum for FieldDescriptorProto_Label {
         values
     }
 
+    fn get_values() -> ::std::option::Option<&'static [Self]> {
+        ::std::option::Option::Some(<Self as ::protobuf::ProtobufEnum>::values())
+    }
+
     fn enum_descriptor_static(_: ::std::option::Option<FieldDescriptorProto_Label>) -> &'static ::protobuf::reflect::EnumDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
@@ -2637,6 +2708,10 @@ impl ::protobuf::ProtobufEnum for FieldDescriptorProto_Label {
             })
         }
     }
+
+    fn get_enum_descriptor_static(_: ::std::option::Option<FieldDescriptorProto_Label>) -> ::std::option::Option<&'static ::protobuf::reflect::EnumDescriptor> {
+        ::std::option::Option::Some(<FieldDescriptorProto_Label as ::protobuf::ProtobufEnum>::enum_descriptor_static(::std::option::Option::None))
+    }
 }
 
 impl ::std::marker::Copy for FieldDescriptorProto_Label {
@@ -2649,12 +2724,15 @@ impl ::protobuf::reflect::ProtobufValue for FieldDescriptorProto_Label {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
 pub struct OneofDescriptorProto {
     // message fields
     name: ::protobuf::SingularField<::std::string::String>,
     options: ::protobuf::SingularPtrField<OneofOptions>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     cached_size: ::protobuf::CachedSize,
 }
 
@@ -2879,13 +2957,16 @@ impl ::protobuf::reflect::ProtobufValue for OneofDescriptorProto {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
 pub struct EnumDescriptorProto {
     // message fields
     name: ::protobuf::SingularField<::std::string::String>,
     value: ::protobuf::RepeatedField<EnumValueDescriptorProto>,
     options: ::protobuf::SingularPtrField<EnumOptions>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     cached_size: ::protobuf::CachedSize,
 }
 
@@ -3158,13 +3239,16 @@ impl ::protobuf::reflect::ProtobufValue for EnumDescriptorProto {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
 pub struct EnumValueDescriptorProto {
     // message fields
     name: ::protobuf::SingularField<::std::string::String>,
     number: ::std::option::Option<i32>,
     options: ::protobuf::SingularPtrField<EnumValueOptions>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     cached_size: ::protobuf::CachedSize,
 }
 
@@ -3427,13 +3511,16 @@ impl ::protobuf::reflect::ProtobufValue for EnumValueDescriptorProto {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
 pub struct ServiceDescriptorProto {
     // message fields
     name: ::protobuf::SingularField<::std::string::String>,
     method: ::protobuf::RepeatedField<MethodDescriptorProto>,
     options: ::protobuf::SingularPtrField<ServiceOptions>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     cached_size: ::protobuf::CachedSize,
 }
 
@@ -3706,6 +3793,7 @@ impl ::protobuf::reflect::ProtobufValue for ServiceDescriptorProto {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
 pub struct MethodDescriptorProto {
     // message fields
     name: ::protobuf::SingularField<::std::string::String>,
@@ -3715,7 +3803,9 @@ pub struct MethodDescriptorProto {
     client_streaming: ::std::option::Option<bool>,
     server_streaming: ::std::option::Option<bool>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     cached_size: ::protobuf::CachedSize,
 }
 
@@ -4118,6 +4208,7 @@ impl ::protobuf::reflect::ProtobufValue for MethodDescriptorProto {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
 pub struct FileOptions {
     // message fields
     java_package: ::protobuf::SingularField<::std::string::String>,
@@ -4136,7 +4227,9 @@ pub struct FileOptions {
     csharp_namespace: ::protobuf::SingularField<::std::string::String>,
     uninterpreted_option: ::protobuf::RepeatedField<UninterpretedOption>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     cached_size: ::protobuf::CachedSize,
 }
 
@@ -4895,6 +4988,7 @@ impl ::protobuf::reflect::ProtobufValue for FileOptions {
 }
 
 #[derive(Clone,PartialEq,Eq,Debug,Hash)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
 pub enum FileOptions_OptimizeMode {
     SPEED = 1,
     CODE_SIZE = 2,
@@ -4924,6 +5018,10 @@ impl ::protobuf::ProtobufEnum for FileOptions_OptimizeMode {
         values
     }
 
+    fn get_values() -> ::std::option::Option<&'static [Self]> {
+        ::std::option::Option::Some(<Self as ::protobuf::ProtobufEnum>::values())
+    }
+
     fn enum_descriptor_static(_: ::std::option::Option<FileOptions_OptimizeMode>) -> &'static ::protobuf::reflect::EnumDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
@@ -4935,6 +5033,10 @@ impl ::protobuf::ProtobufEnum for FileOptions_OptimizeMode {
             })
         }
     }
+
+    fn get_enum_descriptor_static(_: ::std::option::Option<FileOptions_OptimizeMode>) -> ::std::option::Option<&'static ::protobuf::reflect::EnumDescriptor> {
+        ::std::option::Option::Some(<FileOptions_OptimizeMode as ::protobuf::ProtobufEnum>::enum_descriptor_static(::std::option::Option::None))
+    }
 }
 
 impl ::std::marker::Copy for FileOptions_OptimizeMode {
@@ -4947,6 +5049,7 @@ impl ::protobuf::reflect::ProtobufValue for FileOptions_OptimizeMode {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
 pub struct MessageOptions {
     // message fields
     message_set_wire_format: ::std::option::Option<bool>,
@@ -4955,7 +5058,9 @@ pub struct MessageOptions {
     map_entry: ::std::option::Option<bool>,
     uninterpreted_option: ::protobuf::RepeatedField<UninterpretedOption>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     cached_size: ::protobuf::CachedSize,
 }
 
@@ -5273,6 +5378,7 @@ impl ::protobuf::reflect::ProtobufValue for MessageOptions {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
 pub struct FieldOptions {
     // message fields
     ctype: ::std::option::Option<FieldOptions_CType>,
@@ -5283,7 +5389,9 @@ pub struct FieldOptions {
     weak: ::std::option::Option<bool>,
     uninterpreted_option: ::protobuf::RepeatedField<UninterpretedOption>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     cached_size: ::protobuf::CachedSize,
 }
 
@@ -5669,6 +5777,7 @@ impl ::protobuf::reflect::ProtobufValue for FieldOptions {
 }
 
 #[derive(Clone,PartialEq,Eq,Debug,Hash)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
 pub enum FieldOptions_CType {
     STRING = 0,
     CORD = 1,
@@ -5698,6 +5807,10 @@ impl ::protobuf::ProtobufEnum for FieldOptions_CType {
         values
     }
 
+    fn get_values() -> ::std::option::Option<&'static [Self]> {
+        ::std::option::Option::Some(<Self as ::protobuf::ProtobufEnum>::values())
+    }
+
     fn enum_descriptor_static(_: ::std::option::Option<FieldOptions_CType>) -> &'static ::protobuf::reflect::EnumDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
@@ -5709,6 +5822,10 @@ impl ::protobuf::ProtobufEnum for FieldOptions_CType {
             })
         }
     }
+
+    fn get_enum_descriptor_static(_: ::std::option::Option<FieldOptions_CType>) -> ::std::option::Option<&'static ::protobuf::reflect::EnumDescriptor> {
+        ::std::option::Option::Some(<FieldOptions_CType as ::protobuf::ProtobufEnum>::enum_descriptor_static(::std::option::Option::None))
+    }
 }
 
 impl ::std::marker::Copy for FieldOptions_CType {
@@ -5721,6 +5838,7 @@ impl ::protobuf::reflect::ProtobufValue for FieldOptions_CType {
 }
 
 #[derive(Clone,PartialEq,Eq,Debug,Hash)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
 pub enum FieldOptions_JSType {
     JS_NORMAL = 0,
     JS_STRING = 1,
@@ -5750,6 +5868,10 @@ impl ::protobuf::ProtobufEnum for FieldOptions_JSType {
         values
     }
 
+    fn get_values() -> ::std::option::Option<&'static [Self]> {
+        ::std::option::Option::Some(<Self as ::protobuf::ProtobufEnum>::values())
+    }
+
     fn enum_descriptor_static(_: ::std::option::Option<FieldOptions_JSType>) -> &'static ::protobuf::reflect::EnumDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
@@ -5761,6 +5883,10 @@ impl ::protobuf::ProtobufEnum for FieldOptions_JSType {
             })
         }
     }
+
+    fn get_enum_descriptor_static(_: ::std::option::Option<FieldOptions_JSType>) -> ::std::option::Option<&'static ::protobuf::reflect::EnumDescriptor> {
+        ::std::option::Option::Some(<FieldOptions_JSType as ::protobuf::ProtobufEnum>::enum_descriptor_static(::std::option::Option::None))
+    }
 }
 
 impl ::std::marker::Copy for FieldOptions_JSType {
@@ -5773,11 +5899,14 @@ impl ::protobuf::reflect::ProtobufValue for FieldOptions_JSType {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
 pub struct OneofOptions {
     // message fields
     uninterpreted_option: ::protobuf::RepeatedField<UninterpretedOption>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     cached_size: ::protobuf::CachedSize,
 }
 
@@ -5943,13 +6072,16 @@ impl ::protobuf::reflect::ProtobufValue for OneofOptions {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
 pub struct EnumOptions {
     // message fields
     allow_alias: ::std::option::Option<bool>,
     deprecated: ::std::option::Option<bool>,
     uninterpreted_option: ::protobuf::RepeatedField<UninterpretedOption>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     cached_size: ::protobuf::CachedSize,
 }
 
@@ -6191,12 +6323,15 @@ impl ::protobuf::reflect::ProtobufValue for EnumOptions {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
 pub struct EnumValueOptions {
     // message fields
     deprecated: ::std::option::Option<bool>,
     uninterpreted_option: ::protobuf::RepeatedField<UninterpretedOption>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     cached_size: ::protobuf::CachedSize,
 }
 
@@ -6400,12 +6535,15 @@ impl ::protobuf::reflect::ProtobufValue for EnumValueOptions {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
 pub struct ServiceOptions {
     // message fields
     deprecated: ::std::option::Option<bool>,
     uninterpreted_option: ::protobuf::RepeatedField<UninterpretedOption>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     cached_size: ::protobuf::CachedSize,
 }
 
@@ -6609,12 +6747,15 @@ impl ::protobuf::reflect::ProtobufValue for ServiceOptions {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
 pub struct MethodOptions {
     // message fields
     deprecated: ::std::option::Option<bool>,
     uninterpreted_option: ::protobuf::RepeatedField<UninterpretedOption>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     cached_size: ::protobuf::CachedSize,
 }
 
@@ -6818,6 +6959,7 @@ impl ::protobuf::reflect::ProtobufValue for MethodOptions {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
 pub struct UninterpretedOption {
     // message fields
     name: ::protobuf::RepeatedField<UninterpretedOption_NamePart>,
@@ -6828,7 +6970,9 @@ pub struct UninterpretedOption {
     string_value: ::protobuf::SingularField<::std::vec::Vec<u8>>,
     aggregate_value: ::protobuf::SingularField<::std::string::String>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     cached_size: ::protobuf::CachedSize,
 }
 
@@ -7261,12 +7405,15 @@ impl ::protobuf::reflect::ProtobufValue for UninterpretedOption {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
 pub struct UninterpretedOption_NamePart {
     // message fields
     name_part: ::protobuf::SingularField<::std::string::String>,
     is_extension: ::std::option::Option<bool>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     cached_size: ::protobuf::CachedSize,
 }
 
@@ -7479,11 +7626,14 @@ impl ::protobuf::reflect::ProtobufValue for UninterpretedOption_NamePart {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
 pub struct SourceCodeInfo {
     // message fields
     location: ::protobuf::RepeatedField<SourceCodeInfo_Location>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     cached_size: ::protobuf::CachedSize,
 }
 
@@ -7649,6 +7799,7 @@ impl ::protobuf::reflect::ProtobufValue for SourceCodeInfo {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
 pub struct SourceCodeInfo_Location {
     // message fields
     path: ::std::vec::Vec<i32>,
@@ -7657,7 +7808,9 @@ pub struct SourceCodeInfo_Location {
     trailing_comments: ::protobuf::SingularField<::std::string::String>,
     leading_detached_comments: ::protobuf::RepeatedField<::std::string::String>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     cached_size: ::protobuf::CachedSize,
 }
 
@@ -8007,11 +8160,14 @@ impl ::protobuf::reflect::ProtobufValue for SourceCodeInfo_Location {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
 pub struct GeneratedCodeInfo {
     // message fields
     annotation: ::protobuf::RepeatedField<GeneratedCodeInfo_Annotation>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     cached_size: ::protobuf::CachedSize,
 }
 
@@ -8177,6 +8333,7 @@ impl ::protobuf::reflect::ProtobufValue for GeneratedCodeInfo {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
 pub struct GeneratedCodeInfo_Annotation {
     // message fields
     path: ::std::vec::Vec<i32>,
@@ -8184,7 +8341,9 @@ pub struct GeneratedCodeInfo_Annotation {
     begin: ::std::option::Option<i32>,
     end: ::std::option::Option<i32>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     cached_size: ::protobuf::CachedSize,
 }
 
@@ -8473,6 +8632,35 @@ impl ::protobuf::reflect::ProtobufValue for GeneratedCodeInfo_Annotation {
     }
 }
 
+// Register message types and extensions of this file in `::protobuf::reflect::registry`
+pub fn register_message_types() {
+    ::protobuf::reflect::registry::register_message::<FileDescriptorSet>();
+    ::protobuf::reflect::registry::register_message::<FileDescriptorProto>();
+    ::protobuf::reflect::registry::register_message::<DescriptorProto>();
+    ::protobuf::reflect::registry::register_message::<DescriptorProto_ExtensionRange>();
+    ::protobuf::reflect::registry::register_message::<DescriptorProto_ReservedRange>();
+    ::protobuf::reflect::registry::register_message::<FieldDescriptorProto>();
+    ::protobuf::reflect::registry::register_message::<OneofDescriptorProto>();
+    ::protobuf::reflect::registry::register_message::<EnumDescriptorProto>();
+    ::protobuf::reflect::registry::register_message::<EnumValueDescriptorProto>();
+    ::protobuf::reflect::registry::register_message::<ServiceDescriptorProto>();
+    ::protobuf::reflect::registry::register_message::<MethodDescriptorProto>();
+    ::protobuf::reflect::registry::register_message::<FileOptions>();
+    ::protobuf::reflect::registry::register_message::<MessageOptions>();
+    ::protobuf::reflect::registry::register_message::<FieldOptions>();
+    ::protobuf::reflect::registry::register_message::<OneofOptions>();
+    ::protobuf::reflect::registry::register_message::<EnumOptions>();
+    ::protobuf::reflect::registry::register_message::<EnumValueOptions>();
+    ::protobuf::reflect::registry::register_message::<ServiceOptions>();
+    ::protobuf::reflect::registry::register_message::<MethodOptions>();
+    ::protobuf::reflect::registry::register_message::<UninterpretedOption>();
+    ::protobuf::reflect::registry::register_message::<UninterpretedOption_NamePart>();
+    ::protobuf::reflect::registry::register_message::<SourceCodeInfo>();
+    ::protobuf::reflect::registry::register_message::<SourceCodeInfo_Location>();
+    ::protobuf::reflect::registry::register_message::<GeneratedCodeInfo>();
+    ::protobuf::reflect::registry::register_message::<GeneratedCodeInfo_Annotation>();
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x20google/protobuf/descriptor.proto\x12\x0fgoogle.protobuf\"M\n\x11Fi\
     leDescriptorSet\x128\n\x04file\x18\x01\x20\x03(\x0b2$.google.protobuf.Fi\
@@ -8505,7 +8693,7 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     ExtensionRange\x12\x14\n\x05start\x18\x01\x20\x01(\x05R\x05start\x12\x10\
     \n\x03end\x18\x02\x20\x01(\x05R\x03end\x1a7\n\rReservedRange\x12\x14\n\
     \x05start\x18\x01\x20\x01(\x05R\x05start\x12\x10\n\x03end\x18\x02\x20\
-    \x01(\x05R\x03end\"\x98\x06\n\x14FieldDescriptorProto\x12\x12\n\x04name\
+    \x01(\x05R\x03end\"\xc1\x06\n\x14FieldDescriptorProto\x12\x12\n\x04name\
     \x18\x01\x20\x01(\tR\x04name\x12\x16\n\x06number\x18\x03\x20\x01(\x05R\
     \x06number\x12A\n\x05label\x18\x04\x20\x01(\x0e2+.google.protobuf.FieldD\
     escriptorProto.LabelR\x05label\x12>\n\x04type\x18\x05\x20\x01(\x0e2*.goo\
@@ -8514,51 +8702,52 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x08extendee\x12#\n\rdefault_value\x18\x07\x20\x01(\tR\x0cdefaultValue\
     \x12\x1f\n\x0boneof_index\x18\t\x20\x01(\x05R\noneofIndex\x12\x1b\n\tjso\
     n_name\x18\n\x20\x01(\tR\x08jsonName\x127\n\x07options\x18\x08\x20\x01(\
-    \x0b2\x1d.google.protobuf.FieldOptionsR\x07options\"\xb6\x02\n\x04Type\
-    \x12\x0f\n\x0bTYPE_DOUBLE\x10\x01\x12\x0e\n\nTYPE_FLOAT\x10\x02\x12\x0e\
-    \n\nTYPE_INT64\x10\x03\x12\x0f\n\x0bTYPE_UINT64\x10\x04\x12\x0e\n\nTYPE_\
-    INT32\x10\x05\x12\x10\n\x0cTYPE_FIXED64\x10\x06\x12\x10\n\x0cTYPE_FIXED3\
-    2\x10\x07\x12\r\n\tTYPE_BOOL\x10\x08\x12\x0f\n\x0bTYPE_STRING\x10\t\x12\
-    \x0e\n\nTYPE_GROUP\x10\n\x12\x10\n\x0cTYPE_MESSAGE\x10\x0b\x12\x0e\n\nTY\
-    PE_BYTES\x10\x0c\x12\x0f\n\x0bTYPE_UINT32\x10\r\x12\r\n\tTYPE_ENUM\x10\
-    \x0e\x12\x11\n\rTYPE_SFIXED32\x10\x0f\x12\x11\n\rTYPE_SFIXED64\x10\x10\
-    \x12\x0f\n\x0bTYPE_SINT32\x10\x11\x12\x0f\n\x0bTYPE_SINT64\x10\x12\"C\n\
-    \x05Label\x12\x12\n\x0eLABEL_OPTIONAL\x10\x01\x12\x12\n\x0eLABEL_REQUIRE\
-    D\x10\x02\x12\x12\n\x0eLABEL_REPEATED\x10\x03\"c\n\x14OneofDescriptorPro\
-    to\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x127\n\x07options\x18\
-    \x02\x20\x01(\x0b2\x1d.google.protobuf.OneofOptionsR\x07options\"\xa2\
-    \x01\n\x13EnumDescriptorProto\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04n\
-    ame\x12?\n\x05value\x18\x02\x20\x03(\x0b2).google.protobuf.EnumValueDesc\
-    riptorProtoR\x05value\x126\n\x07options\x18\x03\x20\x01(\x0b2\x1c.google\
-    .protobuf.EnumOptionsR\x07options\"\x83\x01\n\x18EnumValueDescriptorProt\
-    o\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x12\x16\n\x06number\x18\
-    \x02\x20\x01(\x05R\x06number\x12;\n\x07options\x18\x03\x20\x01(\x0b2!.go\
-    ogle.protobuf.EnumValueOptionsR\x07options\"\xa7\x01\n\x16ServiceDescrip\
-    torProto\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x12>\n\x06method\
-    \x18\x02\x20\x03(\x0b2&.google.protobuf.MethodDescriptorProtoR\x06method\
-    \x129\n\x07options\x18\x03\x20\x01(\x0b2\x1f.google.protobuf.ServiceOpti\
-    onsR\x07options\"\x89\x02\n\x15MethodDescriptorProto\x12\x12\n\x04name\
-    \x18\x01\x20\x01(\tR\x04name\x12\x1d\n\ninput_type\x18\x02\x20\x01(\tR\t\
-    inputType\x12\x1f\n\x0boutput_type\x18\x03\x20\x01(\tR\noutputType\x128\
-    \n\x07options\x18\x04\x20\x01(\x0b2\x1e.google.protobuf.MethodOptionsR\
-    \x07options\x120\n\x10client_streaming\x18\x05\x20\x01(\x08:\x05falseR\
-    \x0fclientStreaming\x120\n\x10server_streaming\x18\x06\x20\x01(\x08:\x05\
-    falseR\x0fserverStreaming\"\x88\x07\n\x0bFileOptions\x12!\n\x0cjava_pack\
-    age\x18\x01\x20\x01(\tR\x0bjavaPackage\x120\n\x14java_outer_classname\
-    \x18\x08\x20\x01(\tR\x12javaOuterClassname\x125\n\x13java_multiple_files\
-    \x18\n\x20\x01(\x08:\x05falseR\x11javaMultipleFiles\x12D\n\x1djava_gener\
-    ate_equals_and_hash\x18\x14\x20\x01(\x08R\x19javaGenerateEqualsAndHashB\
-    \x02\x18\x01\x12:\n\x16java_string_check_utf8\x18\x1b\x20\x01(\x08:\x05f\
-    alseR\x13javaStringCheckUtf8\x12S\n\x0coptimize_for\x18\t\x20\x01(\x0e2)\
-    .google.protobuf.FileOptions.OptimizeMode:\x05SPEEDR\x0boptimizeFor\x12\
-    \x1d\n\ngo_package\x18\x0b\x20\x01(\tR\tgoPackage\x125\n\x13cc_generic_s\
-    ervices\x18\x10\x20\x01(\x08:\x05falseR\x11ccGenericServices\x129\n\x15j\
-    ava_generic_services\x18\x11\x20\x01(\x08:\x05falseR\x13javaGenericServi\
-    ces\x125\n\x13py_generic_services\x18\x12\x20\x01(\x08:\x05falseR\x11pyG\
-    enericServices\x12%\n\ndeprecated\x18\x17\x20\x01(\x08:\x05falseR\ndepre\
-    cated\x12/\n\x10cc_enable_arenas\x18\x1f\x20\x01(\x08:\x05falseR\x0eccEn\
-    ableArenas\x12*\n\x11objc_class_prefix\x18$\x20\x01(\tR\x0fobjcClassPref\
-    ix\x12)\n\x10csharp_namespace\x18%\x20\x01(\tR\x0fcsharpNamespace\x12X\n\
+    \x0b2\x1d.google.protobuf.FieldOptionsR\x07options\x12'\n\x0fproto3_opti\
+    onal\x18\x11\x20\x01(\x08R\x0eproto3Optional\"\xb6\x02\n\x04Type\x12\x0f\
+    \n\x0bTYPE_DOUBLE\x10\x01\x12\x0e\n\nTYPE_FLOAT\x10\x02\x12\x0e\n\nTYPE_\
+    INT64\x10\x03\x12\x0f\n\x0bTYPE_UINT64\x10\x04\x12\x0e\n\nTYPE_INT32\x10\
+    \x05\x12\x10\n\x0cTYPE_FIXED64\x10\x06\x12\x10\n\x0cTYPE_FIXED32\x10\x07\
+    \x12\r\n\tTYPE_BOOL\x10\x08\x12\x0f\n\x0bTYPE_STRING\x10\t\x12\x0e\n\nTY\
+    PE_GROUP\x10\n\x12\x10\n\x0cTYPE_MESSAGE\x10\x0b\x12\x0e\n\nTYPE_BYTES\
+    \x10\x0c\x12\x0f\n\x0bTYPE_UINT32\x10\r\x12\r\n\tTYPE_ENUM\x10\x0e\x12\
+    \x11\n\rTYPE_SFIXED32\x10\x0f\x12\x11\n\rTYPE_SFIXED64\x10\x10\x12\x0f\n\
+    \x0bTYPE_SINT32\x10\x11\x12\x0f\n\x0bTYPE_SINT64\x10\x12\"C\n\x05Label\
+    \x12\x12\n\x0eLABEL_OPTIONAL\x10\x01\x12\x12\n\x0eLABEL_REQUIRED\x10\x02\
+    \x12\x12\n\x0eLABEL_REPEATED\x10\x03\"c\n\x14OneofDescriptorProto\x12\
+    \x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x127\n\x07options\x18\x02\x20\
+    \x01(\x0b2\x1d.google.protobuf.OneofOptionsR\x07options\"\xa2\x01\n\x13E\
+    numDescriptorProto\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x12?\n\
+    \x05value\x18\x02\x20\x03(\x0b2).google.protobuf.EnumValueDescriptorProt\
+    oR\x05value\x126\n\x07options\x18\x03\x20\x01(\x0b2\x1c.google.protobuf.\
+    EnumOptionsR\x07options\"\x83\x01\n\x18EnumValueDescriptorProto\x12\x12\
+    \n\x04name\x18\x01\x20\x01(\tR\x04name\x12\x16\n\x06number\x18\x02\x20\
+    \x01(\x05R\x06number\x12;\n\x07options\x18\x03\x20\x01(\x0b2!.google.pro\
+    tobuf.EnumValueOptionsR\x07options\"\xa7\x01\n\x16ServiceDescriptorProto\
+    \x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x12>\n\x06method\x18\x02\
+    \x20\x03(\x0b2&.google.protobuf.MethodDescriptorProtoR\x06method\x129\n\
+    \x07options\x18\x03\x20\x01(\x0b2\x1f.google.protobuf.ServiceOptionsR\
+    \x07options\"\x89\x02\n\x15MethodDescriptorProto\x12\x12\n\x04name\x18\
+    \x01\x20\x01(\tR\x04name\x12\x1d\n\ninput_type\x18\x02\x20\x01(\tR\tinpu\
+    tType\x12\x1f\n\x0boutput_type\x18\x03\x20\x01(\tR\noutputType\x128\n\
+    \x07options\x18\x04\x20\x01(\x0b2\x1e.google.protobuf.MethodOptionsR\x07\
+    options\x120\n\x10client_streaming\x18\x05\x20\x01(\x08:\x05falseR\x0fcl\
+    ientStreaming\x120\n\x10server_streaming\x18\x06\x20\x01(\x08:\x05falseR\
+    \x0fserverStreaming\"\x88\x07\n\x0bFileOptions\x12!\n\x0cjava_package\
+    \x18\x01\x20\x01(\tR\x0bjavaPackage\x120\n\x14java_outer_classname\x18\
+    \x08\x20\x01(\tR\x12javaOuterClassname\x125\n\x13java_multiple_files\x18\
+    \n\x20\x01(\x08:\x05falseR\x11javaMultipleFiles\x12D\n\x1djava_generate_\
+    equals_and_hash\x18\x14\x20\x01(\x08R\x19javaGenerateEqualsAndHashB\x02\
+    \x18\x01\x12:\n\x16java_string_check_utf8\x18\x1b\x20\x01(\x08:\x05false\
+    R\x13javaStringCheckUtf8\x12S\n\x0coptimize_for\x18\t\x20\x01(\x0e2).goo\
+    gle.protobuf.FileOptions.OptimizeMode:\x05SPEEDR\x0boptimizeFor\x12\x1d\
+    \n\ngo_package\x18\x0b\x20\x01(\tR\tgoPackage\x125\n\x13cc_generic_servi\
+    ces\x18\x10\x20\x01(\x08:\x05falseR\x11ccGenericServices\x129\n\x15java_\
+    generic_services\x18\x11\x20\x01(\x08:\x05falseR\x13javaGenericServices\
+    \x125\n\x13py_generic_services\x18\x12\x20\x01(\x08:\x05falseR\x11pyGene\
+    ricServices\x12%\n\ndeprecated\x18\x17\x20\x01(\x08:\x05falseR\ndeprecat\
+    ed\x12/\n\x10cc_enable_arenas\x18\x1f\x20\x01(\x08:\x05falseR\x0eccEnabl\
+    eArenas\x12*\n\x11objc_class_prefix\x18$\x20\x01(\tR\x0fobjcClassPrefix\
+    \x12)\n\x10csharp_namespace\x18%\x20\x01(\tR\x0fcsharpNamespace\x12X\n\
     \x14uninterpreted_option\x18\xe7\x07\x20\x03(\x0b2$.google.protobuf.Unin\
     terpretedOptionR\x13uninterpretedOption\":\n\x0cOptimizeMode\x12\t\n\x05\
     SPEED\x10\x01\x12\r\n\tCODE_SIZE\x10\x02\x12\x10\n\x0cLITE_RUNTIME\x10\
@@ -8621,7 +8810,7 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x14\n\x05begin\x18\x03\x20\x01(\x05R\x05begin\x12\x10\n\x03end\x18\x04\
     \x20\x01(\x05R\x03endBX\n\x13com.google.protobufB\x10DescriptorProtosH\
     \x01Z\ndescriptor\xa2\x02\x03GPB\xaa\x02\x1aGoogle.Protobuf.ReflectionJ\
-    \xfe\xa4\x02\n\x07\x12\x05'\0\xa3\x06\x01\n\xaa\x0f\n\x01\x0c\x12\x03'\0\
+    \x95\xa9\x02\n\x07\x12\x05'\0\xaf\x06\x01\n\xaa\x0f\n\x01\r\x12\x03'\0\
     \x122\xc1\x0c\x20Protocol\x20Buffers\x20-\x20Google's\x20data\x20interch\
     ange\x20format\n\x20Copyright\x202008\x20Google\x20Inc.\x20\x20All\x20ri\
     ghts\x20reserved.\n\x20https://developers.google.com/protocol-buffers/\n\
@@ -8662,238 +8851,237 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     finitions\x20found\x20in\x20.proto\x20files.\n\x20A\x20valid\x20.proto\
     \x20file\x20can\x20be\x20translated\x20directly\x20to\x20a\x20FileDescri\
     ptorProto\n\x20without\x20any\x20other\x20information\x20(e.g.\x20withou\
-    t\x20reading\x20its\x20imports).\n\n\x08\n\x01\x02\x12\x03)\x08\x17\n\
-    \x08\n\x01\x08\x12\x03*\0!\n\x0b\n\x04\x08\xe7\x07\0\x12\x03*\0!\n\x0c\n\
-    \x05\x08\xe7\x07\0\x02\x12\x03*\x07\x11\n\r\n\x06\x08\xe7\x07\0\x02\0\
-    \x12\x03*\x07\x11\n\x0e\n\x07\x08\xe7\x07\0\x02\0\x01\x12\x03*\x07\x11\n\
-    \x0c\n\x05\x08\xe7\x07\0\x07\x12\x03*\x14\x20\n\x08\n\x01\x08\x12\x03+\0\
-    ,\n\x0b\n\x04\x08\xe7\x07\x01\x12\x03+\0,\n\x0c\n\x05\x08\xe7\x07\x01\
-    \x02\x12\x03+\x07\x13\n\r\n\x06\x08\xe7\x07\x01\x02\0\x12\x03+\x07\x13\n\
-    \x0e\n\x07\x08\xe7\x07\x01\x02\0\x01\x12\x03+\x07\x13\n\x0c\n\x05\x08\
-    \xe7\x07\x01\x07\x12\x03+\x16+\n\x08\n\x01\x08\x12\x03,\01\n\x0b\n\x04\
-    \x08\xe7\x07\x02\x12\x03,\01\n\x0c\n\x05\x08\xe7\x07\x02\x02\x12\x03,\
-    \x07\x1b\n\r\n\x06\x08\xe7\x07\x02\x02\0\x12\x03,\x07\x1b\n\x0e\n\x07\
-    \x08\xe7\x07\x02\x02\0\x01\x12\x03,\x07\x1b\n\x0c\n\x05\x08\xe7\x07\x02\
-    \x07\x12\x03,\x1e0\n\x08\n\x01\x08\x12\x03-\07\n\x0b\n\x04\x08\xe7\x07\
-    \x03\x12\x03-\07\n\x0c\n\x05\x08\xe7\x07\x03\x02\x12\x03-\x07\x17\n\r\n\
-    \x06\x08\xe7\x07\x03\x02\0\x12\x03-\x07\x17\n\x0e\n\x07\x08\xe7\x07\x03\
-    \x02\0\x01\x12\x03-\x07\x17\n\x0c\n\x05\x08\xe7\x07\x03\x07\x12\x03-\x1a\
-    6\n\x08\n\x01\x08\x12\x03.\0!\n\x0b\n\x04\x08\xe7\x07\x04\x12\x03.\0!\n\
-    \x0c\n\x05\x08\xe7\x07\x04\x02\x12\x03.\x07\x18\n\r\n\x06\x08\xe7\x07\
-    \x04\x02\0\x12\x03.\x07\x18\n\x0e\n\x07\x08\xe7\x07\x04\x02\0\x01\x12\
-    \x03.\x07\x18\n\x0c\n\x05\x08\xe7\x07\x04\x07\x12\x03.\x1b\x20\n\x08\n\
-    \x01\x08\x12\x032\0\x1c\n\x81\x01\n\x04\x08\xe7\x07\x05\x12\x032\0\x1c\
-    \x1at\x20descriptor.proto\x20must\x20be\x20optimized\x20for\x20speed\x20\
-    because\x20reflection-based\n\x20algorithms\x20don't\x20work\x20during\
-    \x20bootstrapping.\n\n\x0c\n\x05\x08\xe7\x07\x05\x02\x12\x032\x07\x13\n\
-    \r\n\x06\x08\xe7\x07\x05\x02\0\x12\x032\x07\x13\n\x0e\n\x07\x08\xe7\x07\
-    \x05\x02\0\x01\x12\x032\x07\x13\n\x0c\n\x05\x08\xe7\x07\x05\x03\x12\x032\
-    \x16\x1b\nj\n\x02\x04\0\x12\x046\08\x01\x1a^\x20The\x20protocol\x20compi\
+    t\x20reading\x20its\x20imports).\n\n\x08\n\x01\x03\x12\x03)\x08\x17\n\
+    \x08\n\x01\t\x12\x03*\0!\n\x0b\n\x04\t\xe7\x07\0\x12\x03*\0!\n\x0c\n\x05\
+    \t\xe7\x07\0\x02\x12\x03*\x07\x11\n\r\n\x06\t\xe7\x07\0\x02\0\x12\x03*\
+    \x07\x11\n\x0e\n\x07\t\xe7\x07\0\x02\0\x01\x12\x03*\x07\x11\n\x0c\n\x05\
+    \t\xe7\x07\0\x07\x12\x03*\x14\x20\n\x08\n\x01\t\x12\x03+\0,\n\x0b\n\x04\
+    \t\xe7\x07\x01\x12\x03+\0,\n\x0c\n\x05\t\xe7\x07\x01\x02\x12\x03+\x07\
+    \x13\n\r\n\x06\t\xe7\x07\x01\x02\0\x12\x03+\x07\x13\n\x0e\n\x07\t\xe7\
+    \x07\x01\x02\0\x01\x12\x03+\x07\x13\n\x0c\n\x05\t\xe7\x07\x01\x07\x12\
+    \x03+\x16+\n\x08\n\x01\t\x12\x03,\01\n\x0b\n\x04\t\xe7\x07\x02\x12\x03,\
+    \01\n\x0c\n\x05\t\xe7\x07\x02\x02\x12\x03,\x07\x1b\n\r\n\x06\t\xe7\x07\
+    \x02\x02\0\x12\x03,\x07\x1b\n\x0e\n\x07\t\xe7\x07\x02\x02\0\x01\x12\x03,\
+    \x07\x1b\n\x0c\n\x05\t\xe7\x07\x02\x07\x12\x03,\x1e0\n\x08\n\x01\t\x12\
+    \x03-\07\n\x0b\n\x04\t\xe7\x07\x03\x12\x03-\07\n\x0c\n\x05\t\xe7\x07\x03\
+    \x02\x12\x03-\x07\x17\n\r\n\x06\t\xe7\x07\x03\x02\0\x12\x03-\x07\x17\n\
+    \x0e\n\x07\t\xe7\x07\x03\x02\0\x01\x12\x03-\x07\x17\n\x0c\n\x05\t\xe7\
+    \x07\x03\x07\x12\x03-\x1a6\n\x08\n\x01\t\x12\x03.\0!\n\x0b\n\x04\t\xe7\
+    \x07\x04\x12\x03.\0!\n\x0c\n\x05\t\xe7\x07\x04\x02\x12\x03.\x07\x18\n\r\
+    \n\x06\t\xe7\x07\x04\x02\0\x12\x03.\x07\x18\n\x0e\n\x07\t\xe7\x07\x04\
+    \x02\0\x01\x12\x03.\x07\x18\n\x0c\n\x05\t\xe7\x07\x04\x07\x12\x03.\x1b\
+    \x20\n\x08\n\x01\t\x12\x032\0\x1c\n\x81\x01\n\x04\t\xe7\x07\x05\x12\x032\
+    \0\x1c\x1at\x20descriptor.proto\x20must\x20be\x20optimized\x20for\x20spe\
+    ed\x20because\x20reflection-based\n\x20algorithms\x20don't\x20work\x20du\
+    ring\x20bootstrapping.\n\n\x0c\n\x05\t\xe7\x07\x05\x02\x12\x032\x07\x13\
+    \n\r\n\x06\t\xe7\x07\x05\x02\0\x12\x032\x07\x13\n\x0e\n\x07\t\xe7\x07\
+    \x05\x02\0\x01\x12\x032\x07\x13\n\x0c\n\x05\t\xe7\x07\x05\x03\x12\x032\
+    \x16\x1b\nj\n\x02\x05\0\x12\x046\08\x01\x1a^\x20The\x20protocol\x20compi\
     ler\x20can\x20output\x20a\x20FileDescriptorSet\x20containing\x20the\x20.\
-    proto\n\x20files\x20it\x20parses.\n\n\n\n\x03\x04\0\x01\x12\x036\x08\x19\
-    \n\x0b\n\x04\x04\0\x02\0\x12\x037\x02(\n\x0c\n\x05\x04\0\x02\0\x04\x12\
-    \x037\x02\n\n\x0c\n\x05\x04\0\x02\0\x06\x12\x037\x0b\x1e\n\x0c\n\x05\x04\
-    \0\x02\0\x01\x12\x037\x1f#\n\x0c\n\x05\x04\0\x02\0\x03\x12\x037&'\n/\n\
-    \x02\x04\x01\x12\x04;\0X\x01\x1a#\x20Describes\x20a\x20complete\x20.prot\
-    o\x20file.\n\n\n\n\x03\x04\x01\x01\x12\x03;\x08\x1b\n9\n\x04\x04\x01\x02\
+    proto\n\x20files\x20it\x20parses.\n\n\n\n\x03\x05\0\x01\x12\x036\x08\x19\
+    \n\x0b\n\x04\x05\0\x02\0\x12\x037\x02(\n\x0c\n\x05\x05\0\x02\0\x04\x12\
+    \x037\x02\n\n\x0c\n\x05\x05\0\x02\0\x06\x12\x037\x0b\x1e\n\x0c\n\x05\x05\
+    \0\x02\0\x01\x12\x037\x1f#\n\x0c\n\x05\x05\0\x02\0\x03\x12\x037&'\n/\n\
+    \x02\x05\x01\x12\x04;\0X\x01\x1a#\x20Describes\x20a\x20complete\x20.prot\
+    o\x20file.\n\n\n\n\x03\x05\x01\x01\x12\x03;\x08\x1b\n9\n\x04\x05\x01\x02\
     \0\x12\x03<\x02\x1b\",\x20file\x20name,\x20relative\x20to\x20root\x20of\
-    \x20source\x20tree\n\n\x0c\n\x05\x04\x01\x02\0\x04\x12\x03<\x02\n\n\x0c\
-    \n\x05\x04\x01\x02\0\x05\x12\x03<\x0b\x11\n\x0c\n\x05\x04\x01\x02\0\x01\
-    \x12\x03<\x12\x16\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03<\x19\x1a\n*\n\
-    \x04\x04\x01\x02\x01\x12\x03=\x02\x1e\"\x1d\x20e.g.\x20\"foo\",\x20\"foo\
-    .bar\",\x20etc.\n\n\x0c\n\x05\x04\x01\x02\x01\x04\x12\x03=\x02\n\n\x0c\n\
-    \x05\x04\x01\x02\x01\x05\x12\x03=\x0b\x11\n\x0c\n\x05\x04\x01\x02\x01\
-    \x01\x12\x03=\x12\x19\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03=\x1c\x1d\n\
-    4\n\x04\x04\x01\x02\x02\x12\x03@\x02!\x1a'\x20Names\x20of\x20files\x20im\
-    ported\x20by\x20this\x20file.\n\n\x0c\n\x05\x04\x01\x02\x02\x04\x12\x03@\
-    \x02\n\n\x0c\n\x05\x04\x01\x02\x02\x05\x12\x03@\x0b\x11\n\x0c\n\x05\x04\
-    \x01\x02\x02\x01\x12\x03@\x12\x1c\n\x0c\n\x05\x04\x01\x02\x02\x03\x12\
-    \x03@\x1f\x20\nQ\n\x04\x04\x01\x02\x03\x12\x03B\x02(\x1aD\x20Indexes\x20\
+    \x20source\x20tree\n\n\x0c\n\x05\x05\x01\x02\0\x04\x12\x03<\x02\n\n\x0c\
+    \n\x05\x05\x01\x02\0\x05\x12\x03<\x0b\x11\n\x0c\n\x05\x05\x01\x02\0\x01\
+    \x12\x03<\x12\x16\n\x0c\n\x05\x05\x01\x02\0\x03\x12\x03<\x19\x1a\n*\n\
+    \x04\x05\x01\x02\x01\x12\x03=\x02\x1e\"\x1d\x20e.g.\x20\"foo\",\x20\"foo\
+    .bar\",\x20etc.\n\n\x0c\n\x05\x05\x01\x02\x01\x04\x12\x03=\x02\n\n\x0c\n\
+    \x05\x05\x01\x02\x01\x05\x12\x03=\x0b\x11\n\x0c\n\x05\x05\x01\x02\x01\
+    \x01\x12\x03=\x12\x19\n\x0c\n\x05\x05\x01\x02\x01\x03\x12\x03=\x1c\x1d\n\
+    4\n\x04\x05\x01\x02\x02\x12\x03@\x02!\x1a'\x20Names\x20of\x20files\x20im\
+    ported\x20by\x20this\x20file.\n\n\x0c\n\x05\x05\x01\x02\x02\x04\x12\x03@\
+    \x02\n\n\x0c\n\x05\x05\x01\x02\x02\x05\x12\x03@\x0b\x11\n\x0c\n\x05\x05\
+    \x01\x02\x02\x01\x12\x03@\x12\x1c\n\x0c\n\x05\x05\x01\x02\x02\x03\x12\
+    \x03@\x1f\x20\nQ\n\x04\x05\x01\x02\x03\x12\x03B\x02(\x1aD\x20Indexes\x20\
     of\x20the\x20public\x20imported\x20files\x20in\x20the\x20dependency\x20l\
-    ist\x20above.\n\n\x0c\n\x05\x04\x01\x02\x03\x04\x12\x03B\x02\n\n\x0c\n\
-    \x05\x04\x01\x02\x03\x05\x12\x03B\x0b\x10\n\x0c\n\x05\x04\x01\x02\x03\
-    \x01\x12\x03B\x11\"\n\x0c\n\x05\x04\x01\x02\x03\x03\x12\x03B%'\nz\n\x04\
-    \x04\x01\x02\x04\x12\x03E\x02&\x1am\x20Indexes\x20of\x20the\x20weak\x20i\
+    ist\x20above.\n\n\x0c\n\x05\x05\x01\x02\x03\x04\x12\x03B\x02\n\n\x0c\n\
+    \x05\x05\x01\x02\x03\x05\x12\x03B\x0b\x10\n\x0c\n\x05\x05\x01\x02\x03\
+    \x01\x12\x03B\x11\"\n\x0c\n\x05\x05\x01\x02\x03\x03\x12\x03B%'\nz\n\x04\
+    \x05\x01\x02\x04\x12\x03E\x02&\x1am\x20Indexes\x20of\x20the\x20weak\x20i\
     mported\x20files\x20in\x20the\x20dependency\x20list.\n\x20For\x20Google-\
-    internal\x20migration\x20only.\x20Do\x20not\x20use.\n\n\x0c\n\x05\x04\
-    \x01\x02\x04\x04\x12\x03E\x02\n\n\x0c\n\x05\x04\x01\x02\x04\x05\x12\x03E\
-    \x0b\x10\n\x0c\n\x05\x04\x01\x02\x04\x01\x12\x03E\x11\x20\n\x0c\n\x05\
-    \x04\x01\x02\x04\x03\x12\x03E#%\n6\n\x04\x04\x01\x02\x05\x12\x03H\x02,\
+    internal\x20migration\x20only.\x20Do\x20not\x20use.\n\n\x0c\n\x05\x05\
+    \x01\x02\x04\x04\x12\x03E\x02\n\n\x0c\n\x05\x05\x01\x02\x04\x05\x12\x03E\
+    \x0b\x10\n\x0c\n\x05\x05\x01\x02\x04\x01\x12\x03E\x11\x20\n\x0c\n\x05\
+    \x05\x01\x02\x04\x03\x12\x03E#%\n6\n\x04\x05\x01\x02\x05\x12\x03H\x02,\
     \x1a)\x20All\x20top-level\x20definitions\x20in\x20this\x20file.\n\n\x0c\
-    \n\x05\x04\x01\x02\x05\x04\x12\x03H\x02\n\n\x0c\n\x05\x04\x01\x02\x05\
-    \x06\x12\x03H\x0b\x1a\n\x0c\n\x05\x04\x01\x02\x05\x01\x12\x03H\x1b'\n\
-    \x0c\n\x05\x04\x01\x02\x05\x03\x12\x03H*+\n\x0b\n\x04\x04\x01\x02\x06\
-    \x12\x03I\x02-\n\x0c\n\x05\x04\x01\x02\x06\x04\x12\x03I\x02\n\n\x0c\n\
-    \x05\x04\x01\x02\x06\x06\x12\x03I\x0b\x1e\n\x0c\n\x05\x04\x01\x02\x06\
-    \x01\x12\x03I\x1f(\n\x0c\n\x05\x04\x01\x02\x06\x03\x12\x03I+,\n\x0b\n\
-    \x04\x04\x01\x02\x07\x12\x03J\x02.\n\x0c\n\x05\x04\x01\x02\x07\x04\x12\
-    \x03J\x02\n\n\x0c\n\x05\x04\x01\x02\x07\x06\x12\x03J\x0b!\n\x0c\n\x05\
-    \x04\x01\x02\x07\x01\x12\x03J\")\n\x0c\n\x05\x04\x01\x02\x07\x03\x12\x03\
-    J,-\n\x0b\n\x04\x04\x01\x02\x08\x12\x03K\x02.\n\x0c\n\x05\x04\x01\x02\
-    \x08\x04\x12\x03K\x02\n\n\x0c\n\x05\x04\x01\x02\x08\x06\x12\x03K\x0b\x1f\
-    \n\x0c\n\x05\x04\x01\x02\x08\x01\x12\x03K\x20)\n\x0c\n\x05\x04\x01\x02\
-    \x08\x03\x12\x03K,-\n\x0b\n\x04\x04\x01\x02\t\x12\x03M\x02#\n\x0c\n\x05\
-    \x04\x01\x02\t\x04\x12\x03M\x02\n\n\x0c\n\x05\x04\x01\x02\t\x06\x12\x03M\
-    \x0b\x16\n\x0c\n\x05\x04\x01\x02\t\x01\x12\x03M\x17\x1e\n\x0c\n\x05\x04\
-    \x01\x02\t\x03\x12\x03M!\"\n\xf4\x01\n\x04\x04\x01\x02\n\x12\x03S\x02/\
+    \n\x05\x05\x01\x02\x05\x04\x12\x03H\x02\n\n\x0c\n\x05\x05\x01\x02\x05\
+    \x06\x12\x03H\x0b\x1a\n\x0c\n\x05\x05\x01\x02\x05\x01\x12\x03H\x1b'\n\
+    \x0c\n\x05\x05\x01\x02\x05\x03\x12\x03H*+\n\x0b\n\x04\x05\x01\x02\x06\
+    \x12\x03I\x02-\n\x0c\n\x05\x05\x01\x02\x06\x04\x12\x03I\x02\n\n\x0c\n\
+    \x05\x05\x01\x02\x06\x06\x12\x03I\x0b\x1e\n\x0c\n\x05\x05\x01\x02\x06\
+    \x01\x12\x03I\x1f(\n\x0c\n\x05\x05\x01\x02\x06\x03\x12\x03I+,\n\x0b\n\
+    \x04\x05\x01\x02\x07\x12\x03J\x02.\n\x0c\n\x05\x05\x01\x02\x07\x04\x12\
+    \x03J\x02\n\n\x0c\n\x05\x05\x01\x02\x07\x06\x12\x03J\x0b!\n\x0c\n\x05\
+    \x05\x01\x02\x07\x01\x12\x03J\")\n\x0c\n\x05\x05\x01\x02\x07\x03\x12\x03\
+    J,-\n\x0b\n\x04\x05\x01\x02\x08\x12\x03K\x02.\n\x0c\n\x05\x05\x01\x02\
+    \x08\x04\x12\x03K\x02\n\n\x0c\n\x05\x05\x01\x02\x08\x06\x12\x03K\x0b\x1f\
+    \n\x0c\n\x05\x05\x01\x02\x08\x01\x12\x03K\x20)\n\x0c\n\x05\x05\x01\x02\
+    \x08\x03\x12\x03K,-\n\x0b\n\x04\x05\x01\x02\t\x12\x03M\x02#\n\x0c\n\x05\
+    \x05\x01\x02\t\x04\x12\x03M\x02\n\n\x0c\n\x05\x05\x01\x02\t\x06\x12\x03M\
+    \x0b\x16\n\x0c\n\x05\x05\x01\x02\t\x01\x12\x03M\x17\x1e\n\x0c\n\x05\x05\
+    \x01\x02\t\x03\x12\x03M!\"\n\xf4\x01\n\x04\x05\x01\x02\n\x12\x03S\x02/\
     \x1a\xe6\x01\x20This\x20field\x20contains\x20optional\x20information\x20\
     about\x20the\x20original\x20source\x20code.\n\x20You\x20may\x20safely\
     \x20remove\x20this\x20entire\x20field\x20without\x20harming\x20runtime\n\
     \x20functionality\x20of\x20the\x20descriptors\x20--\x20the\x20informatio\
     n\x20is\x20needed\x20only\x20by\n\x20development\x20tools.\n\n\x0c\n\x05\
-    \x04\x01\x02\n\x04\x12\x03S\x02\n\n\x0c\n\x05\x04\x01\x02\n\x06\x12\x03S\
-    \x0b\x19\n\x0c\n\x05\x04\x01\x02\n\x01\x12\x03S\x1a*\n\x0c\n\x05\x04\x01\
-    \x02\n\x03\x12\x03S-.\n]\n\x04\x04\x01\x02\x0b\x12\x03W\x02\x1e\x1aP\x20\
+    \x05\x01\x02\n\x04\x12\x03S\x02\n\n\x0c\n\x05\x05\x01\x02\n\x06\x12\x03S\
+    \x0b\x19\n\x0c\n\x05\x05\x01\x02\n\x01\x12\x03S\x1a*\n\x0c\n\x05\x05\x01\
+    \x02\n\x03\x12\x03S-.\n]\n\x04\x05\x01\x02\x0b\x12\x03W\x02\x1e\x1aP\x20\
     The\x20syntax\x20of\x20the\x20proto\x20file.\n\x20The\x20supported\x20va\
-    lues\x20are\x20\"proto2\"\x20and\x20\"proto3\".\n\n\x0c\n\x05\x04\x01\
-    \x02\x0b\x04\x12\x03W\x02\n\n\x0c\n\x05\x04\x01\x02\x0b\x05\x12\x03W\x0b\
-    \x11\n\x0c\n\x05\x04\x01\x02\x0b\x01\x12\x03W\x12\x18\n\x0c\n\x05\x04\
-    \x01\x02\x0b\x03\x12\x03W\x1b\x1d\n'\n\x02\x04\x02\x12\x04[\0y\x01\x1a\
-    \x1b\x20Describes\x20a\x20message\x20type.\n\n\n\n\x03\x04\x02\x01\x12\
-    \x03[\x08\x17\n\x0b\n\x04\x04\x02\x02\0\x12\x03\\\x02\x1b\n\x0c\n\x05\
-    \x04\x02\x02\0\x04\x12\x03\\\x02\n\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\
-    \\\x0b\x11\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\\\x12\x16\n\x0c\n\x05\
-    \x04\x02\x02\0\x03\x12\x03\\\x19\x1a\n\x0b\n\x04\x04\x02\x02\x01\x12\x03\
-    ^\x02*\n\x0c\n\x05\x04\x02\x02\x01\x04\x12\x03^\x02\n\n\x0c\n\x05\x04\
-    \x02\x02\x01\x06\x12\x03^\x0b\x1f\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\
-    \x03^\x20%\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03^()\n\x0b\n\x04\x04\
-    \x02\x02\x02\x12\x03_\x02.\n\x0c\n\x05\x04\x02\x02\x02\x04\x12\x03_\x02\
-    \n\n\x0c\n\x05\x04\x02\x02\x02\x06\x12\x03_\x0b\x1f\n\x0c\n\x05\x04\x02\
-    \x02\x02\x01\x12\x03_\x20)\n\x0c\n\x05\x04\x02\x02\x02\x03\x12\x03_,-\n\
-    \x0b\n\x04\x04\x02\x02\x03\x12\x03a\x02+\n\x0c\n\x05\x04\x02\x02\x03\x04\
-    \x12\x03a\x02\n\n\x0c\n\x05\x04\x02\x02\x03\x06\x12\x03a\x0b\x1a\n\x0c\n\
-    \x05\x04\x02\x02\x03\x01\x12\x03a\x1b&\n\x0c\n\x05\x04\x02\x02\x03\x03\
-    \x12\x03a)*\n\x0b\n\x04\x04\x02\x02\x04\x12\x03b\x02-\n\x0c\n\x05\x04\
-    \x02\x02\x04\x04\x12\x03b\x02\n\n\x0c\n\x05\x04\x02\x02\x04\x06\x12\x03b\
-    \x0b\x1e\n\x0c\n\x05\x04\x02\x02\x04\x01\x12\x03b\x1f(\n\x0c\n\x05\x04\
-    \x02\x02\x04\x03\x12\x03b+,\n\x0c\n\x04\x04\x02\x03\0\x12\x04d\x02g\x03\
-    \n\x0c\n\x05\x04\x02\x03\0\x01\x12\x03d\n\x18\n\r\n\x06\x04\x02\x03\0\
-    \x02\0\x12\x03e\x04\x1d\n\x0e\n\x07\x04\x02\x03\0\x02\0\x04\x12\x03e\x04\
-    \x0c\n\x0e\n\x07\x04\x02\x03\0\x02\0\x05\x12\x03e\r\x12\n\x0e\n\x07\x04\
-    \x02\x03\0\x02\0\x01\x12\x03e\x13\x18\n\x0e\n\x07\x04\x02\x03\0\x02\0\
-    \x03\x12\x03e\x1b\x1c\n\r\n\x06\x04\x02\x03\0\x02\x01\x12\x03f\x04\x1b\n\
-    \x0e\n\x07\x04\x02\x03\0\x02\x01\x04\x12\x03f\x04\x0c\n\x0e\n\x07\x04\
-    \x02\x03\0\x02\x01\x05\x12\x03f\r\x12\n\x0e\n\x07\x04\x02\x03\0\x02\x01\
-    \x01\x12\x03f\x13\x16\n\x0e\n\x07\x04\x02\x03\0\x02\x01\x03\x12\x03f\x19\
-    \x1a\n\x0b\n\x04\x04\x02\x02\x05\x12\x03h\x02.\n\x0c\n\x05\x04\x02\x02\
-    \x05\x04\x12\x03h\x02\n\n\x0c\n\x05\x04\x02\x02\x05\x06\x12\x03h\x0b\x19\
-    \n\x0c\n\x05\x04\x02\x02\x05\x01\x12\x03h\x1a)\n\x0c\n\x05\x04\x02\x02\
-    \x05\x03\x12\x03h,-\n\x0b\n\x04\x04\x02\x02\x06\x12\x03j\x02/\n\x0c\n\
-    \x05\x04\x02\x02\x06\x04\x12\x03j\x02\n\n\x0c\n\x05\x04\x02\x02\x06\x06\
-    \x12\x03j\x0b\x1f\n\x0c\n\x05\x04\x02\x02\x06\x01\x12\x03j\x20*\n\x0c\n\
-    \x05\x04\x02\x02\x06\x03\x12\x03j-.\n\x0b\n\x04\x04\x02\x02\x07\x12\x03l\
-    \x02&\n\x0c\n\x05\x04\x02\x02\x07\x04\x12\x03l\x02\n\n\x0c\n\x05\x04\x02\
-    \x02\x07\x06\x12\x03l\x0b\x19\n\x0c\n\x05\x04\x02\x02\x07\x01\x12\x03l\
-    \x1a!\n\x0c\n\x05\x04\x02\x02\x07\x03\x12\x03l$%\n\xaa\x01\n\x04\x04\x02\
+    lues\x20are\x20\"proto2\"\x20and\x20\"proto3\".\n\n\x0c\n\x05\x05\x01\
+    \x02\x0b\x04\x12\x03W\x02\n\n\x0c\n\x05\x05\x01\x02\x0b\x05\x12\x03W\x0b\
+    \x11\n\x0c\n\x05\x05\x01\x02\x0b\x01\x12\x03W\x12\x18\n\x0c\n\x05\x05\
+    \x01\x02\x0b\x03\x12\x03W\x1b\x1d\n'\n\x02\x05\x02\x12\x04[\0y\x01\x1a\
+    \x1b\x20Describes\x20a\x20message\x20type.\n\n\n\n\x03\x05\x02\x01\x12\
+    \x03[\x08\x17\n\x0b\n\x04\x05\x02\x02\0\x12\x03\\\x02\x1b\n\x0c\n\x05\
+    \x05\x02\x02\0\x04\x12\x03\\\x02\n\n\x0c\n\x05\x05\x02\x02\0\x05\x12\x03\
+    \\\x0b\x11\n\x0c\n\x05\x05\x02\x02\0\x01\x12\x03\\\x12\x16\n\x0c\n\x05\
+    \x05\x02\x02\0\x03\x12\x03\\\x19\x1a\n\x0b\n\x04\x05\x02\x02\x01\x12\x03\
+    ^\x02*\n\x0c\n\x05\x05\x02\x02\x01\x04\x12\x03^\x02\n\n\x0c\n\x05\x05\
+    \x02\x02\x01\x06\x12\x03^\x0b\x1f\n\x0c\n\x05\x05\x02\x02\x01\x01\x12\
+    \x03^\x20%\n\x0c\n\x05\x05\x02\x02\x01\x03\x12\x03^()\n\x0b\n\x04\x05\
+    \x02\x02\x02\x12\x03_\x02.\n\x0c\n\x05\x05\x02\x02\x02\x04\x12\x03_\x02\
+    \n\n\x0c\n\x05\x05\x02\x02\x02\x06\x12\x03_\x0b\x1f\n\x0c\n\x05\x05\x02\
+    \x02\x02\x01\x12\x03_\x20)\n\x0c\n\x05\x05\x02\x02\x02\x03\x12\x03_,-\n\
+    \x0b\n\x04\x05\x02\x02\x03\x12\x03a\x02+\n\x0c\n\x05\x05\x02\x02\x03\x04\
+    \x12\x03a\x02\n\n\x0c\n\x05\x05\x02\x02\x03\x06\x12\x03a\x0b\x1a\n\x0c\n\
+    \x05\x05\x02\x02\x03\x01\x12\x03a\x1b&\n\x0c\n\x05\x05\x02\x02\x03\x03\
+    \x12\x03a)*\n\x0b\n\x04\x05\x02\x02\x04\x12\x03b\x02-\n\x0c\n\x05\x05\
+    \x02\x02\x04\x04\x12\x03b\x02\n\n\x0c\n\x05\x05\x02\x02\x04\x06\x12\x03b\
+    \x0b\x1e\n\x0c\n\x05\x05\x02\x02\x04\x01\x12\x03b\x1f(\n\x0c\n\x05\x05\
+    \x02\x02\x04\x03\x12\x03b+,\n\x0c\n\x04\x05\x02\x03\0\x12\x04d\x02g\x03\
+    \n\x0c\n\x05\x05\x02\x03\0\x01\x12\x03d\n\x18\n\r\n\x06\x05\x02\x03\0\
+    \x02\0\x12\x03e\x04\x1d\n\x0e\n\x07\x05\x02\x03\0\x02\0\x04\x12\x03e\x04\
+    \x0c\n\x0e\n\x07\x05\x02\x03\0\x02\0\x05\x12\x03e\r\x12\n\x0e\n\x07\x05\
+    \x02\x03\0\x02\0\x01\x12\x03e\x13\x18\n\x0e\n\x07\x05\x02\x03\0\x02\0\
+    \x03\x12\x03e\x1b\x1c\n\r\n\x06\x05\x02\x03\0\x02\x01\x12\x03f\x04\x1b\n\
+    \x0e\n\x07\x05\x02\x03\0\x02\x01\x04\x12\x03f\x04\x0c\n\x0e\n\x07\x05\
+    \x02\x03\0\x02\x01\x05\x12\x03f\r\x12\n\x0e\n\x07\x05\x02\x03\0\x02\x01\
+    \x01\x12\x03f\x13\x16\n\x0e\n\x07\x05\x02\x03\0\x02\x01\x03\x12\x03f\x19\
+    \x1a\n\x0b\n\x04\x05\x02\x02\x05\x12\x03h\x02.\n\x0c\n\x05\x05\x02\x02\
+    \x05\x04\x12\x03h\x02\n\n\x0c\n\x05\x05\x02\x02\x05\x06\x12\x03h\x0b\x19\
+    \n\x0c\n\x05\x05\x02\x02\x05\x01\x12\x03h\x1a)\n\x0c\n\x05\x05\x02\x02\
+    \x05\x03\x12\x03h,-\n\x0b\n\x04\x05\x02\x02\x06\x12\x03j\x02/\n\x0c\n\
+    \x05\x05\x02\x02\x06\x04\x12\x03j\x02\n\n\x0c\n\x05\x05\x02\x02\x06\x06\
+    \x12\x03j\x0b\x1f\n\x0c\n\x05\x05\x02\x02\x06\x01\x12\x03j\x20*\n\x0c\n\
+    \x05\x05\x02\x02\x06\x03\x12\x03j-.\n\x0b\n\x04\x05\x02\x02\x07\x12\x03l\
+    \x02&\n\x0c\n\x05\x05\x02\x02\x07\x04\x12\x03l\x02\n\n\x0c\n\x05\x05\x02\
+    \x02\x07\x06\x12\x03l\x0b\x19\n\x0c\n\x05\x05\x02\x02\x07\x01\x12\x03l\
+    \x1a!\n\x0c\n\x05\x05\x02\x02\x07\x03\x12\x03l$%\n\xaa\x01\n\x04\x05\x02\
     \x03\x01\x12\x04q\x02t\x03\x1a\x9b\x01\x20Range\x20of\x20reserved\x20tag\
     \x20numbers.\x20Reserved\x20tag\x20numbers\x20may\x20not\x20be\x20used\
     \x20by\n\x20fields\x20or\x20extension\x20ranges\x20in\x20the\x20same\x20\
     message.\x20Reserved\x20ranges\x20may\n\x20not\x20overlap.\n\n\x0c\n\x05\
-    \x04\x02\x03\x01\x01\x12\x03q\n\x17\n\x1b\n\x06\x04\x02\x03\x01\x02\0\
-    \x12\x03r\x04\x1d\"\x0c\x20Inclusive.\n\n\x0e\n\x07\x04\x02\x03\x01\x02\
-    \0\x04\x12\x03r\x04\x0c\n\x0e\n\x07\x04\x02\x03\x01\x02\0\x05\x12\x03r\r\
-    \x12\n\x0e\n\x07\x04\x02\x03\x01\x02\0\x01\x12\x03r\x13\x18\n\x0e\n\x07\
-    \x04\x02\x03\x01\x02\0\x03\x12\x03r\x1b\x1c\n\x1b\n\x06\x04\x02\x03\x01\
-    \x02\x01\x12\x03s\x04\x1b\"\x0c\x20Exclusive.\n\n\x0e\n\x07\x04\x02\x03\
-    \x01\x02\x01\x04\x12\x03s\x04\x0c\n\x0e\n\x07\x04\x02\x03\x01\x02\x01\
-    \x05\x12\x03s\r\x12\n\x0e\n\x07\x04\x02\x03\x01\x02\x01\x01\x12\x03s\x13\
-    \x16\n\x0e\n\x07\x04\x02\x03\x01\x02\x01\x03\x12\x03s\x19\x1a\n\x0b\n\
-    \x04\x04\x02\x02\x08\x12\x03u\x02,\n\x0c\n\x05\x04\x02\x02\x08\x04\x12\
-    \x03u\x02\n\n\x0c\n\x05\x04\x02\x02\x08\x06\x12\x03u\x0b\x18\n\x0c\n\x05\
-    \x04\x02\x02\x08\x01\x12\x03u\x19'\n\x0c\n\x05\x04\x02\x02\x08\x03\x12\
-    \x03u*+\n\x82\x01\n\x04\x04\x02\x02\t\x12\x03x\x02%\x1au\x20Reserved\x20\
+    \x05\x02\x03\x01\x01\x12\x03q\n\x17\n\x1b\n\x06\x05\x02\x03\x01\x02\0\
+    \x12\x03r\x04\x1d\"\x0c\x20Inclusive.\n\n\x0e\n\x07\x05\x02\x03\x01\x02\
+    \0\x04\x12\x03r\x04\x0c\n\x0e\n\x07\x05\x02\x03\x01\x02\0\x05\x12\x03r\r\
+    \x12\n\x0e\n\x07\x05\x02\x03\x01\x02\0\x01\x12\x03r\x13\x18\n\x0e\n\x07\
+    \x05\x02\x03\x01\x02\0\x03\x12\x03r\x1b\x1c\n\x1b\n\x06\x05\x02\x03\x01\
+    \x02\x01\x12\x03s\x04\x1b\"\x0c\x20Exclusive.\n\n\x0e\n\x07\x05\x02\x03\
+    \x01\x02\x01\x04\x12\x03s\x04\x0c\n\x0e\n\x07\x05\x02\x03\x01\x02\x01\
+    \x05\x12\x03s\r\x12\n\x0e\n\x07\x05\x02\x03\x01\x02\x01\x01\x12\x03s\x13\
+    \x16\n\x0e\n\x07\x05\x02\x03\x01\x02\x01\x03\x12\x03s\x19\x1a\n\x0b\n\
+    \x04\x05\x02\x02\x08\x12\x03u\x02,\n\x0c\n\x05\x05\x02\x02\x08\x04\x12\
+    \x03u\x02\n\n\x0c\n\x05\x05\x02\x02\x08\x06\x12\x03u\x0b\x18\n\x0c\n\x05\
+    \x05\x02\x02\x08\x01\x12\x03u\x19'\n\x0c\n\x05\x05\x02\x02\x08\x03\x12\
+    \x03u*+\n\x82\x01\n\x04\x05\x02\x02\t\x12\x03x\x02%\x1au\x20Reserved\x20\
     field\x20names,\x20which\x20may\x20not\x20be\x20used\x20by\x20fields\x20\
     in\x20the\x20same\x20message.\n\x20A\x20given\x20name\x20may\x20only\x20\
-    be\x20reserved\x20once.\n\n\x0c\n\x05\x04\x02\x02\t\x04\x12\x03x\x02\n\n\
-    \x0c\n\x05\x04\x02\x02\t\x05\x12\x03x\x0b\x11\n\x0c\n\x05\x04\x02\x02\t\
-    \x01\x12\x03x\x12\x1f\n\x0c\n\x05\x04\x02\x02\t\x03\x12\x03x\"$\n2\n\x02\
-    \x04\x03\x12\x05|\0\xc7\x01\x01\x1a%\x20Describes\x20a\x20field\x20withi\
-    n\x20a\x20message.\n\n\n\n\x03\x04\x03\x01\x12\x03|\x08\x1c\n\r\n\x04\
-    \x04\x03\x04\0\x12\x05}\x02\x98\x01\x03\n\x0c\n\x05\x04\x03\x04\0\x01\
-    \x12\x03}\x07\x0b\nS\n\x06\x04\x03\x04\0\x02\0\x12\x04\x80\x01\x04\x1c\
+    be\x20reserved\x20once.\n\n\x0c\n\x05\x05\x02\x02\t\x04\x12\x03x\x02\n\n\
+    \x0c\n\x05\x05\x02\x02\t\x05\x12\x03x\x0b\x11\n\x0c\n\x05\x05\x02\x02\t\
+    \x01\x12\x03x\x12\x1f\n\x0c\n\x05\x05\x02\x02\t\x03\x12\x03x\"$\n2\n\x02\
+    \x05\x03\x12\x05|\0\xd3\x01\x01\x1a%\x20Describes\x20a\x20field\x20withi\
+    n\x20a\x20message.\n\n\n\n\x03\x05\x03\x01\x12\x03|\x08\x1c\n\r\n\x04\
+    \x05\x03\x04\0\x12\x05}\x02\x98\x01\x03\n\x0c\n\x05\x05\x03\x04\0\x01\
+    \x12\x03}\x07\x0b\nS\n\x06\x05\x03\x04\0\x02\0\x12\x04\x80\x01\x04\x1c\
     \x1aC\x200\x20is\x20reserved\x20for\x20errors.\n\x20Order\x20is\x20weird\
-    \x20for\x20historical\x20reasons.\n\n\x0f\n\x07\x04\x03\x04\0\x02\0\x01\
-    \x12\x04\x80\x01\x04\x0f\n\x0f\n\x07\x04\x03\x04\0\x02\0\x02\x12\x04\x80\
-    \x01\x1a\x1b\n\x0e\n\x06\x04\x03\x04\0\x02\x01\x12\x04\x81\x01\x04\x1c\n\
-    \x0f\n\x07\x04\x03\x04\0\x02\x01\x01\x12\x04\x81\x01\x04\x0e\n\x0f\n\x07\
-    \x04\x03\x04\0\x02\x01\x02\x12\x04\x81\x01\x1a\x1b\nw\n\x06\x04\x03\x04\
+    \x20for\x20historical\x20reasons.\n\n\x0f\n\x07\x05\x03\x04\0\x02\0\x01\
+    \x12\x04\x80\x01\x04\x0f\n\x0f\n\x07\x05\x03\x04\0\x02\0\x02\x12\x04\x80\
+    \x01\x1a\x1b\n\x0e\n\x06\x05\x03\x04\0\x02\x01\x12\x04\x81\x01\x04\x1c\n\
+    \x0f\n\x07\x05\x03\x04\0\x02\x01\x01\x12\x04\x81\x01\x04\x0e\n\x0f\n\x07\
+    \x05\x03\x04\0\x02\x01\x02\x12\x04\x81\x01\x1a\x1b\nw\n\x06\x05\x03\x04\
     \0\x02\x02\x12\x04\x84\x01\x04\x1c\x1ag\x20Not\x20ZigZag\x20encoded.\x20\
     \x20Negative\x20numbers\x20take\x2010\x20bytes.\x20\x20Use\x20TYPE_SINT6\
-    4\x20if\n\x20negative\x20values\x20are\x20likely.\n\n\x0f\n\x07\x04\x03\
-    \x04\0\x02\x02\x01\x12\x04\x84\x01\x04\x0e\n\x0f\n\x07\x04\x03\x04\0\x02\
-    \x02\x02\x12\x04\x84\x01\x1a\x1b\n\x0e\n\x06\x04\x03\x04\0\x02\x03\x12\
-    \x04\x85\x01\x04\x1c\n\x0f\n\x07\x04\x03\x04\0\x02\x03\x01\x12\x04\x85\
-    \x01\x04\x0f\n\x0f\n\x07\x04\x03\x04\0\x02\x03\x02\x12\x04\x85\x01\x1a\
-    \x1b\nw\n\x06\x04\x03\x04\0\x02\x04\x12\x04\x88\x01\x04\x1c\x1ag\x20Not\
+    4\x20if\n\x20negative\x20values\x20are\x20likely.\n\n\x0f\n\x07\x05\x03\
+    \x04\0\x02\x02\x01\x12\x04\x84\x01\x04\x0e\n\x0f\n\x07\x05\x03\x04\0\x02\
+    \x02\x02\x12\x04\x84\x01\x1a\x1b\n\x0e\n\x06\x05\x03\x04\0\x02\x03\x12\
+    \x04\x85\x01\x04\x1c\n\x0f\n\x07\x05\x03\x04\0\x02\x03\x01\x12\x04\x85\
+    \x01\x04\x0f\n\x0f\n\x07\x05\x03\x04\0\x02\x03\x02\x12\x04\x85\x01\x1a\
+    \x1b\nw\n\x06\x05\x03\x04\0\x02\x04\x12\x04\x88\x01\x04\x1c\x1ag\x20Not\
     \x20ZigZag\x20encoded.\x20\x20Negative\x20numbers\x20take\x2010\x20bytes\
     .\x20\x20Use\x20TYPE_SINT32\x20if\n\x20negative\x20values\x20are\x20like\
-    ly.\n\n\x0f\n\x07\x04\x03\x04\0\x02\x04\x01\x12\x04\x88\x01\x04\x0e\n\
-    \x0f\n\x07\x04\x03\x04\0\x02\x04\x02\x12\x04\x88\x01\x1a\x1b\n\x0e\n\x06\
-    \x04\x03\x04\0\x02\x05\x12\x04\x89\x01\x04\x1c\n\x0f\n\x07\x04\x03\x04\0\
-    \x02\x05\x01\x12\x04\x89\x01\x04\x10\n\x0f\n\x07\x04\x03\x04\0\x02\x05\
-    \x02\x12\x04\x89\x01\x1a\x1b\n\x0e\n\x06\x04\x03\x04\0\x02\x06\x12\x04\
-    \x8a\x01\x04\x1c\n\x0f\n\x07\x04\x03\x04\0\x02\x06\x01\x12\x04\x8a\x01\
-    \x04\x10\n\x0f\n\x07\x04\x03\x04\0\x02\x06\x02\x12\x04\x8a\x01\x1a\x1b\n\
-    \x0e\n\x06\x04\x03\x04\0\x02\x07\x12\x04\x8b\x01\x04\x1c\n\x0f\n\x07\x04\
-    \x03\x04\0\x02\x07\x01\x12\x04\x8b\x01\x04\r\n\x0f\n\x07\x04\x03\x04\0\
-    \x02\x07\x02\x12\x04\x8b\x01\x1a\x1b\n\x0e\n\x06\x04\x03\x04\0\x02\x08\
-    \x12\x04\x8c\x01\x04\x1c\n\x0f\n\x07\x04\x03\x04\0\x02\x08\x01\x12\x04\
-    \x8c\x01\x04\x0f\n\x0f\n\x07\x04\x03\x04\0\x02\x08\x02\x12\x04\x8c\x01\
-    \x1a\x1b\n*\n\x06\x04\x03\x04\0\x02\t\x12\x04\x8d\x01\x04\x1d\"\x1a\x20T\
-    ag-delimited\x20aggregate.\n\n\x0f\n\x07\x04\x03\x04\0\x02\t\x01\x12\x04\
-    \x8d\x01\x04\x0e\n\x0f\n\x07\x04\x03\x04\0\x02\t\x02\x12\x04\x8d\x01\x1a\
-    \x1c\n-\n\x06\x04\x03\x04\0\x02\n\x12\x04\x8e\x01\x04\x1d\"\x1d\x20Lengt\
-    h-delimited\x20aggregate.\n\n\x0f\n\x07\x04\x03\x04\0\x02\n\x01\x12\x04\
-    \x8e\x01\x04\x10\n\x0f\n\x07\x04\x03\x04\0\x02\n\x02\x12\x04\x8e\x01\x1a\
-    \x1c\n#\n\x06\x04\x03\x04\0\x02\x0b\x12\x04\x91\x01\x04\x1d\x1a\x13\x20N\
-    ew\x20in\x20version\x202.\n\n\x0f\n\x07\x04\x03\x04\0\x02\x0b\x01\x12\
-    \x04\x91\x01\x04\x0e\n\x0f\n\x07\x04\x03\x04\0\x02\x0b\x02\x12\x04\x91\
-    \x01\x1a\x1c\n\x0e\n\x06\x04\x03\x04\0\x02\x0c\x12\x04\x92\x01\x04\x1d\n\
-    \x0f\n\x07\x04\x03\x04\0\x02\x0c\x01\x12\x04\x92\x01\x04\x0f\n\x0f\n\x07\
-    \x04\x03\x04\0\x02\x0c\x02\x12\x04\x92\x01\x1a\x1c\n\x0e\n\x06\x04\x03\
-    \x04\0\x02\r\x12\x04\x93\x01\x04\x1d\n\x0f\n\x07\x04\x03\x04\0\x02\r\x01\
-    \x12\x04\x93\x01\x04\r\n\x0f\n\x07\x04\x03\x04\0\x02\r\x02\x12\x04\x93\
-    \x01\x1a\x1c\n\x0e\n\x06\x04\x03\x04\0\x02\x0e\x12\x04\x94\x01\x04\x1d\n\
-    \x0f\n\x07\x04\x03\x04\0\x02\x0e\x01\x12\x04\x94\x01\x04\x11\n\x0f\n\x07\
-    \x04\x03\x04\0\x02\x0e\x02\x12\x04\x94\x01\x1a\x1c\n\x0e\n\x06\x04\x03\
-    \x04\0\x02\x0f\x12\x04\x95\x01\x04\x1d\n\x0f\n\x07\x04\x03\x04\0\x02\x0f\
-    \x01\x12\x04\x95\x01\x04\x11\n\x0f\n\x07\x04\x03\x04\0\x02\x0f\x02\x12\
-    \x04\x95\x01\x1a\x1c\n'\n\x06\x04\x03\x04\0\x02\x10\x12\x04\x96\x01\x04\
-    \x1d\"\x17\x20Uses\x20ZigZag\x20encoding.\n\n\x0f\n\x07\x04\x03\x04\0\
-    \x02\x10\x01\x12\x04\x96\x01\x04\x0f\n\x0f\n\x07\x04\x03\x04\0\x02\x10\
-    \x02\x12\x04\x96\x01\x1a\x1c\n'\n\x06\x04\x03\x04\0\x02\x11\x12\x04\x97\
-    \x01\x04\x1d\"\x17\x20Uses\x20ZigZag\x20encoding.\n\n\x0f\n\x07\x04\x03\
-    \x04\0\x02\x11\x01\x12\x04\x97\x01\x04\x0f\n\x0f\n\x07\x04\x03\x04\0\x02\
-    \x11\x02\x12\x04\x97\x01\x1a\x1c\n\x0e\n\x04\x04\x03\x04\x01\x12\x06\x9a\
-    \x01\x02\xa0\x01\x03\n\r\n\x05\x04\x03\x04\x01\x01\x12\x04\x9a\x01\x07\
-    \x0c\n*\n\x06\x04\x03\x04\x01\x02\0\x12\x04\x9c\x01\x04\x1c\x1a\x1a\x200\
-    \x20is\x20reserved\x20for\x20errors\n\n\x0f\n\x07\x04\x03\x04\x01\x02\0\
-    \x01\x12\x04\x9c\x01\x04\x12\n\x0f\n\x07\x04\x03\x04\x01\x02\0\x02\x12\
-    \x04\x9c\x01\x1a\x1b\n\x0e\n\x06\x04\x03\x04\x01\x02\x01\x12\x04\x9d\x01\
-    \x04\x1c\n\x0f\n\x07\x04\x03\x04\x01\x02\x01\x01\x12\x04\x9d\x01\x04\x12\
-    \n\x0f\n\x07\x04\x03\x04\x01\x02\x01\x02\x12\x04\x9d\x01\x1a\x1b\n8\n\
-    \x06\x04\x03\x04\x01\x02\x02\x12\x04\x9e\x01\x04\x1c\"(\x20TODO(sanjay):\
-    \x20Should\x20we\x20add\x20LABEL_MAP?\n\n\x0f\n\x07\x04\x03\x04\x01\x02\
-    \x02\x01\x12\x04\x9e\x01\x04\x12\n\x0f\n\x07\x04\x03\x04\x01\x02\x02\x02\
-    \x12\x04\x9e\x01\x1a\x1b\n\x0c\n\x04\x04\x03\x02\0\x12\x04\xa2\x01\x02\
-    \x1b\n\r\n\x05\x04\x03\x02\0\x04\x12\x04\xa2\x01\x02\n\n\r\n\x05\x04\x03\
-    \x02\0\x05\x12\x04\xa2\x01\x0b\x11\n\r\n\x05\x04\x03\x02\0\x01\x12\x04\
-    \xa2\x01\x12\x16\n\r\n\x05\x04\x03\x02\0\x03\x12\x04\xa2\x01\x19\x1a\n\
-    \x0c\n\x04\x04\x03\x02\x01\x12\x04\xa3\x01\x02\x1c\n\r\n\x05\x04\x03\x02\
-    \x01\x04\x12\x04\xa3\x01\x02\n\n\r\n\x05\x04\x03\x02\x01\x05\x12\x04\xa3\
-    \x01\x0b\x10\n\r\n\x05\x04\x03\x02\x01\x01\x12\x04\xa3\x01\x11\x17\n\r\n\
-    \x05\x04\x03\x02\x01\x03\x12\x04\xa3\x01\x1a\x1b\n\x0c\n\x04\x04\x03\x02\
-    \x02\x12\x04\xa4\x01\x02\x1b\n\r\n\x05\x04\x03\x02\x02\x04\x12\x04\xa4\
-    \x01\x02\n\n\r\n\x05\x04\x03\x02\x02\x06\x12\x04\xa4\x01\x0b\x10\n\r\n\
-    \x05\x04\x03\x02\x02\x01\x12\x04\xa4\x01\x11\x16\n\r\n\x05\x04\x03\x02\
-    \x02\x03\x12\x04\xa4\x01\x19\x1a\n\x9c\x01\n\x04\x04\x03\x02\x03\x12\x04\
+    ly.\n\n\x0f\n\x07\x05\x03\x04\0\x02\x04\x01\x12\x04\x88\x01\x04\x0e\n\
+    \x0f\n\x07\x05\x03\x04\0\x02\x04\x02\x12\x04\x88\x01\x1a\x1b\n\x0e\n\x06\
+    \x05\x03\x04\0\x02\x05\x12\x04\x89\x01\x04\x1c\n\x0f\n\x07\x05\x03\x04\0\
+    \x02\x05\x01\x12\x04\x89\x01\x04\x10\n\x0f\n\x07\x05\x03\x04\0\x02\x05\
+    \x02\x12\x04\x89\x01\x1a\x1b\n\x0e\n\x06\x05\x03\x04\0\x02\x06\x12\x04\
+    \x8a\x01\x04\x1c\n\x0f\n\x07\x05\x03\x04\0\x02\x06\x01\x12\x04\x8a\x01\
+    \x04\x10\n\x0f\n\x07\x05\x03\x04\0\x02\x06\x02\x12\x04\x8a\x01\x1a\x1b\n\
+    \x0e\n\x06\x05\x03\x04\0\x02\x07\x12\x04\x8b\x01\x04\x1c\n\x0f\n\x07\x05\
+    \x03\x04\0\x02\x07\x01\x12\x04\x8b\x01\x04\r\n\x0f\n\x07\x05\x03\x04\0\
+    \x02\x07\x02\x12\x04\x8b\x01\x1a\x1b\n\x0e\n\x06\x05\x03\x04\0\x02\x08\
+    \x12\x04\x8c\x01\x04\x1c\n\x0f\n\x07\x05\x03\x04\0\x02\x08\x01\x12\x04\
+    \x8c\x01\x04\x0f\n\x0f\n\x07\x05\x03\x04\0\x02\x08\x02\x12\x04\x8c\x01\
+    \x1a\x1b\n*\n\x06\x05\x03\x04\0\x02\t\x12\x04\x8d\x01\x04\x1d\"\x1a\x20T\
+    ag-delimited\x20aggregate.\n\n\x0f\n\x07\x05\x03\x04\0\x02\t\x01\x12\x04\
+    \x8d\x01\x04\x0e\n\x0f\n\x07\x05\x03\x04\0\x02\t\x02\x12\x04\x8d\x01\x1a\
+    \x1c\n-\n\x06\x05\x03\x04\0\x02\n\x12\x04\x8e\x01\x04\x1d\"\x1d\x20Lengt\
+    h-delimited\x20aggregate.\n\n\x0f\n\x07\x05\x03\x04\0\x02\n\x01\x12\x04\
+    \x8e\x01\x04\x10\n\x0f\n\x07\x05\x03\x04\0\x02\n\x02\x12\x04\x8e\x01\x1a\
+    \x1c\n#\n\x06\x05\x03\x04\0\x02\x0b\x12\x04\x91\x01\x04\x1d\x1a\x13\x20N\
+    ew\x20in\x20version\x202.\n\n\x0f\n\x07\x05\x03\x04\0\x02\x0b\x01\x12\
+    \x04\x91\x01\x04\x0e\n\x0f\n\x07\x05\x03\x04\0\x02\x0b\x02\x12\x04\x91\
+    \x01\x1a\x1c\n\x0e\n\x06\x05\x03\x04\0\x02\x0c\x12\x04\x92\x01\x04\x1d\n\
+    \x0f\n\x07\x05\x03\x04\0\x02\x0c\x01\x12\x04\x92\x01\x04\x0f\n\x0f\n\x07\
+    \x05\x03\x04\0\x02\x0c\x02\x12\x04\x92\x01\x1a\x1c\n\x0e\n\x06\x05\x03\
+    \x04\0\x02\r\x12\x04\x93\x01\x04\x1d\n\x0f\n\x07\x05\x03\x04\0\x02\r\x01\
+    \x12\x04\x93\x01\x04\r\n\x0f\n\x07\x05\x03\x04\0\x02\r\x02\x12\x04\x93\
+    \x01\x1a\x1c\n\x0e\n\x06\x05\x03\x04\0\x02\x0e\x12\x04\x94\x01\x04\x1d\n\
+    \x0f\n\x07\x05\x03\x04\0\x02\x0e\x01\x12\x04\x94\x01\x04\x11\n\x0f\n\x07\
+    \x05\x03\x04\0\x02\x0e\x02\x12\x04\x94\x01\x1a\x1c\n\x0e\n\x06\x05\x03\
+    \x04\0\x02\x0f\x12\x04\x95\x01\x04\x1d\n\x0f\n\x07\x05\x03\x04\0\x02\x0f\
+    \x01\x12\x04\x95\x01\x04\x11\n\x0f\n\x07\x05\x03\x04\0\x02\x0f\x02\x12\
+    \x04\x95\x01\x1a\x1c\n'\n\x06\x05\x03\x04\0\x02\x10\x12\x04\x96\x01\x04\
+    \x1d\"\x17\x20Uses\x20ZigZag\x20encoding.\n\n\x0f\n\x07\x05\x03\x04\0\
+    \x02\x10\x01\x12\x04\x96\x01\x04\x0f\n\x0f\n\x07\x05\x03\x04\0\x02\x10\
+    \x02\x12\x04\x96\x01\x1a\x1c\n'\n\x06\x05\x03\x04\0\x02\x11\x12\x04\x97\
+    \x01\x04\x1d\"\x17\x20Uses\x20ZigZag\x20encoding.\n\n\x0f\n\x07\x05\x03\
+    \x04\0\x02\x11\x01\x12\x04\x97\x01\x04\x0f\n\x0f\n\x07\x05\x03\x04\0\x02\
+    \x11\x02\x12\x04\x97\x01\x1a\x1c\n\x0e\n\x04\x05\x03\x04\x01\x12\x06\x9a\
+    \x01\x02\xa0\x01\x03\n\r\n\x05\x05\x03\x04\x01\x01\x12\x04\x9a\x01\x07\
+    \x0c\n*\n\x06\x05\x03\x04\x01\x02\0\x12\x04\x9c\x01\x04\x1c\x1a\x1a\x200\
+    \x20is\x20reserved\x20for\x20errors\n\n\x0f\n\x07\x05\x03\x04\x01\x02\0\
+    \x01\x12\x04\x9c\x01\x04\x12\n\x0f\n\x07\x05\x03\x04\x01\x02\0\x02\x12\
+    \x04\x9c\x01\x1a\x1b\n\x0e\n\x06\x05\x03\x04\x01\x02\x01\x12\x04\x9d\x01\
+    \x04\x1c\n\x0f\n\x07\x05\x03\x04\x01\x02\x01\x01\x12\x04\x9d\x01\x04\x12\
+    \n\x0f\n\x07\x05\x03\x04\x01\x02\x01\x02\x12\x04\x9d\x01\x1a\x1b\n8\n\
+    \x06\x05\x03\x04\x01\x02\x02\x12\x04\x9e\x01\x04\x1c\"(\x20TODO(sanjay):\
+    \x20Should\x20we\x20add\x20LABEL_MAP?\n\n\x0f\n\x07\x05\x03\x04\x01\x02\
+    \x02\x01\x12\x04\x9e\x01\x04\x12\n\x0f\n\x07\x05\x03\x04\x01\x02\x02\x02\
+    \x12\x04\x9e\x01\x1a\x1b\n\x0c\n\x04\x05\x03\x02\0\x12\x04\xa2\x01\x02\
+    \x1b\n\r\n\x05\x05\x03\x02\0\x04\x12\x04\xa2\x01\x02\n\n\r\n\x05\x05\x03\
+    \x02\0\x05\x12\x04\xa2\x01\x0b\x11\n\r\n\x05\x05\x03\x02\0\x01\x12\x04\
+    \xa2\x01\x12\x16\n\r\n\x05\x05\x03\x02\0\x03\x12\x04\xa2\x01\x19\x1a\n\
+    \x0c\n\x04\x05\x03\x02\x01\x12\x04\xa3\x01\x02\x1c\n\r\n\x05\x05\x03\x02\
+    \x01\x04\x12\x04\xa3\x01\x02\n\n\r\n\x05\x05\x03\x02\x01\x05\x12\x04\xa3\
+    \x01\x0b\x10\n\r\n\x05\x05\x03\x02\x01\x01\x12\x04\xa3\x01\x11\x17\n\r\n\
+    \x05\x05\x03\x02\x01\x03\x12\x04\xa3\x01\x1a\x1b\n\x0c\n\x04\x05\x03\x02\
+    \x02\x12\x04\xa4\x01\x02\x1b\n\r\n\x05\x05\x03\x02\x02\x04\x12\x04\xa4\
+    \x01\x02\n\n\r\n\x05\x05\x03\x02\x02\x06\x12\x04\xa4\x01\x0b\x10\n\r\n\
+    \x05\x05\x03\x02\x02\x01\x12\x04\xa4\x01\x11\x16\n\r\n\x05\x05\x03\x02\
+    \x02\x03\x12\x04\xa4\x01\x19\x1a\n\x9c\x01\n\x04\x05\x03\x02\x03\x12\x04\
     \xa8\x01\x02\x19\x1a\x8d\x01\x20If\x20type_name\x20is\x20set,\x20this\
     \x20need\x20not\x20be\x20set.\x20\x20If\x20both\x20this\x20and\x20type_n\
     ame\n\x20are\x20set,\x20this\x20must\x20be\x20one\x20of\x20TYPE_ENUM,\
-    \x20TYPE_MESSAGE\x20or\x20TYPE_GROUP.\n\n\r\n\x05\x04\x03\x02\x03\x04\
-    \x12\x04\xa8\x01\x02\n\n\r\n\x05\x04\x03\x02\x03\x06\x12\x04\xa8\x01\x0b\
-    \x0f\n\r\n\x05\x04\x03\x02\x03\x01\x12\x04\xa8\x01\x10\x14\n\r\n\x05\x04\
-    \x03\x02\x03\x03\x12\x04\xa8\x01\x17\x18\n\xb7\x02\n\x04\x04\x03\x02\x04\
+    \x20TYPE_MESSAGE\x20or\x20TYPE_GROUP.\n\n\r\n\x05\x05\x03\x02\x03\x04\
+    \x12\x04\xa8\x01\x02\n\n\r\n\x05\x05\x03\x02\x03\x06\x12\x04\xa8\x01\x0b\
+    \x0f\n\r\n\x05\x05\x03\x02\x03\x01\x12\x04\xa8\x01\x10\x14\n\r\n\x05\x05\
+    \x03\x02\x03\x03\x12\x04\xa8\x01\x17\x18\n\xb7\x02\n\x04\x05\x03\x02\x04\
     \x12\x04\xaf\x01\x02\x20\x1a\xa8\x02\x20For\x20message\x20and\x20enum\
     \x20types,\x20this\x20is\x20the\x20name\x20of\x20the\x20type.\x20\x20If\
     \x20the\x20name\n\x20starts\x20with\x20a\x20'.',\x20it\x20is\x20fully-qu\
@@ -8901,875 +9089,888 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     sed\x20to\x20find\x20the\x20type\x20(i.e.\x20first\x20the\x20nested\x20t\
     ypes\x20within\x20this\n\x20message\x20are\x20searched,\x20then\x20withi\
     n\x20the\x20parent,\x20on\x20up\x20to\x20the\x20root\n\x20namespace).\n\
-    \n\r\n\x05\x04\x03\x02\x04\x04\x12\x04\xaf\x01\x02\n\n\r\n\x05\x04\x03\
-    \x02\x04\x05\x12\x04\xaf\x01\x0b\x11\n\r\n\x05\x04\x03\x02\x04\x01\x12\
-    \x04\xaf\x01\x12\x1b\n\r\n\x05\x04\x03\x02\x04\x03\x12\x04\xaf\x01\x1e\
-    \x1f\n~\n\x04\x04\x03\x02\x05\x12\x04\xb3\x01\x02\x1f\x1ap\x20For\x20ext\
+    \n\r\n\x05\x05\x03\x02\x04\x04\x12\x04\xaf\x01\x02\n\n\r\n\x05\x05\x03\
+    \x02\x04\x05\x12\x04\xaf\x01\x0b\x11\n\r\n\x05\x05\x03\x02\x04\x01\x12\
+    \x04\xaf\x01\x12\x1b\n\r\n\x05\x05\x03\x02\x04\x03\x12\x04\xaf\x01\x1e\
+    \x1f\n~\n\x04\x05\x03\x02\x05\x12\x04\xb3\x01\x02\x1f\x1ap\x20For\x20ext\
     ensions,\x20this\x20is\x20the\x20name\x20of\x20the\x20type\x20being\x20e\
     xtended.\x20\x20It\x20is\n\x20resolved\x20in\x20the\x20same\x20manner\
-    \x20as\x20type_name.\n\n\r\n\x05\x04\x03\x02\x05\x04\x12\x04\xb3\x01\x02\
-    \n\n\r\n\x05\x04\x03\x02\x05\x05\x12\x04\xb3\x01\x0b\x11\n\r\n\x05\x04\
-    \x03\x02\x05\x01\x12\x04\xb3\x01\x12\x1a\n\r\n\x05\x04\x03\x02\x05\x03\
-    \x12\x04\xb3\x01\x1d\x1e\n\xb1\x02\n\x04\x04\x03\x02\x06\x12\x04\xba\x01\
+    \x20as\x20type_name.\n\n\r\n\x05\x05\x03\x02\x05\x04\x12\x04\xb3\x01\x02\
+    \n\n\r\n\x05\x05\x03\x02\x05\x05\x12\x04\xb3\x01\x0b\x11\n\r\n\x05\x05\
+    \x03\x02\x05\x01\x12\x04\xb3\x01\x12\x1a\n\r\n\x05\x05\x03\x02\x05\x03\
+    \x12\x04\xb3\x01\x1d\x1e\n\xb1\x02\n\x04\x05\x03\x02\x06\x12\x04\xba\x01\
     \x02$\x1a\xa2\x02\x20For\x20numeric\x20types,\x20contains\x20the\x20orig\
     inal\x20text\x20representation\x20of\x20the\x20value.\n\x20For\x20boolea\
     ns,\x20\"true\"\x20or\x20\"false\".\n\x20For\x20strings,\x20contains\x20\
     the\x20default\x20text\x20contents\x20(not\x20escaped\x20in\x20any\x20wa\
     y).\n\x20For\x20bytes,\x20contains\x20the\x20C\x20escaped\x20value.\x20\
     \x20All\x20bytes\x20>=\x20128\x20are\x20escaped.\n\x20TODO(kenton):\x20\
-    \x20Base-64\x20encode?\n\n\r\n\x05\x04\x03\x02\x06\x04\x12\x04\xba\x01\
-    \x02\n\n\r\n\x05\x04\x03\x02\x06\x05\x12\x04\xba\x01\x0b\x11\n\r\n\x05\
-    \x04\x03\x02\x06\x01\x12\x04\xba\x01\x12\x1f\n\r\n\x05\x04\x03\x02\x06\
-    \x03\x12\x04\xba\x01\"#\n\x84\x01\n\x04\x04\x03\x02\x07\x12\x04\xbe\x01\
+    \x20Base-64\x20encode?\n\n\r\n\x05\x05\x03\x02\x06\x04\x12\x04\xba\x01\
+    \x02\n\n\r\n\x05\x05\x03\x02\x06\x05\x12\x04\xba\x01\x0b\x11\n\r\n\x05\
+    \x05\x03\x02\x06\x01\x12\x04\xba\x01\x12\x1f\n\r\n\x05\x05\x03\x02\x06\
+    \x03\x12\x04\xba\x01\"#\n\x84\x01\n\x04\x05\x03\x02\x07\x12\x04\xbe\x01\
     \x02!\x1av\x20If\x20set,\x20gives\x20the\x20index\x20of\x20a\x20oneof\
     \x20in\x20the\x20containing\x20type's\x20oneof_decl\n\x20list.\x20\x20Th\
-    is\x20field\x20is\x20a\x20member\x20of\x20that\x20oneof.\n\n\r\n\x05\x04\
-    \x03\x02\x07\x04\x12\x04\xbe\x01\x02\n\n\r\n\x05\x04\x03\x02\x07\x05\x12\
-    \x04\xbe\x01\x0b\x10\n\r\n\x05\x04\x03\x02\x07\x01\x12\x04\xbe\x01\x11\
-    \x1c\n\r\n\x05\x04\x03\x02\x07\x03\x12\x04\xbe\x01\x1f\x20\n\xfa\x01\n\
-    \x04\x04\x03\x02\x08\x12\x04\xc4\x01\x02!\x1a\xeb\x01\x20JSON\x20name\
+    is\x20field\x20is\x20a\x20member\x20of\x20that\x20oneof.\n\n\r\n\x05\x05\
+    \x03\x02\x07\x04\x12\x04\xbe\x01\x02\n\n\r\n\x05\x05\x03\x02\x07\x05\x12\
+    \x04\xbe\x01\x0b\x10\n\r\n\x05\x05\x03\x02\x07\x01\x12\x04\xbe\x01\x11\
+    \x1c\n\r\n\x05\x05\x03\x02\x07\x03\x12\x04\xbe\x01\x1f\x20\n\xfa\x01\n\
+    \x04\x05\x03\x02\x08\x12\x04\xc4\x01\x02!\x1a\xeb\x01\x20JSON\x20name\
     \x20of\x20this\x20field.\x20The\x20value\x20is\x20set\x20by\x20protocol\
     \x20compiler.\x20If\x20the\n\x20user\x20has\x20set\x20a\x20\"json_name\"\
     \x20option\x20on\x20this\x20field,\x20that\x20option's\x20value\n\x20wil\
     l\x20be\x20used.\x20Otherwise,\x20it's\x20deduced\x20from\x20the\x20fiel\
     d's\x20name\x20by\x20converting\n\x20it\x20to\x20camelCase.\n\n\r\n\x05\
-    \x04\x03\x02\x08\x04\x12\x04\xc4\x01\x02\n\n\r\n\x05\x04\x03\x02\x08\x05\
-    \x12\x04\xc4\x01\x0b\x11\n\r\n\x05\x04\x03\x02\x08\x01\x12\x04\xc4\x01\
-    \x12\x1b\n\r\n\x05\x04\x03\x02\x08\x03\x12\x04\xc4\x01\x1e\x20\n\x0c\n\
-    \x04\x04\x03\x02\t\x12\x04\xc6\x01\x02$\n\r\n\x05\x04\x03\x02\t\x04\x12\
-    \x04\xc6\x01\x02\n\n\r\n\x05\x04\x03\x02\t\x06\x12\x04\xc6\x01\x0b\x17\n\
-    \r\n\x05\x04\x03\x02\t\x01\x12\x04\xc6\x01\x18\x1f\n\r\n\x05\x04\x03\x02\
-    \t\x03\x12\x04\xc6\x01\"#\n\"\n\x02\x04\x04\x12\x06\xca\x01\0\xcd\x01\
-    \x01\x1a\x14\x20Describes\x20a\x20oneof.\n\n\x0b\n\x03\x04\x04\x01\x12\
-    \x04\xca\x01\x08\x1c\n\x0c\n\x04\x04\x04\x02\0\x12\x04\xcb\x01\x02\x1b\n\
-    \r\n\x05\x04\x04\x02\0\x04\x12\x04\xcb\x01\x02\n\n\r\n\x05\x04\x04\x02\0\
-    \x05\x12\x04\xcb\x01\x0b\x11\n\r\n\x05\x04\x04\x02\0\x01\x12\x04\xcb\x01\
-    \x12\x16\n\r\n\x05\x04\x04\x02\0\x03\x12\x04\xcb\x01\x19\x1a\n\x0c\n\x04\
-    \x04\x04\x02\x01\x12\x04\xcc\x01\x02$\n\r\n\x05\x04\x04\x02\x01\x04\x12\
-    \x04\xcc\x01\x02\n\n\r\n\x05\x04\x04\x02\x01\x06\x12\x04\xcc\x01\x0b\x17\
-    \n\r\n\x05\x04\x04\x02\x01\x01\x12\x04\xcc\x01\x18\x1f\n\r\n\x05\x04\x04\
-    \x02\x01\x03\x12\x04\xcc\x01\"#\n'\n\x02\x04\x05\x12\x06\xd0\x01\0\xd6\
-    \x01\x01\x1a\x19\x20Describes\x20an\x20enum\x20type.\n\n\x0b\n\x03\x04\
-    \x05\x01\x12\x04\xd0\x01\x08\x1b\n\x0c\n\x04\x04\x05\x02\0\x12\x04\xd1\
-    \x01\x02\x1b\n\r\n\x05\x04\x05\x02\0\x04\x12\x04\xd1\x01\x02\n\n\r\n\x05\
-    \x04\x05\x02\0\x05\x12\x04\xd1\x01\x0b\x11\n\r\n\x05\x04\x05\x02\0\x01\
-    \x12\x04\xd1\x01\x12\x16\n\r\n\x05\x04\x05\x02\0\x03\x12\x04\xd1\x01\x19\
-    \x1a\n\x0c\n\x04\x04\x05\x02\x01\x12\x04\xd3\x01\x02.\n\r\n\x05\x04\x05\
-    \x02\x01\x04\x12\x04\xd3\x01\x02\n\n\r\n\x05\x04\x05\x02\x01\x06\x12\x04\
-    \xd3\x01\x0b#\n\r\n\x05\x04\x05\x02\x01\x01\x12\x04\xd3\x01$)\n\r\n\x05\
-    \x04\x05\x02\x01\x03\x12\x04\xd3\x01,-\n\x0c\n\x04\x04\x05\x02\x02\x12\
-    \x04\xd5\x01\x02#\n\r\n\x05\x04\x05\x02\x02\x04\x12\x04\xd5\x01\x02\n\n\
-    \r\n\x05\x04\x05\x02\x02\x06\x12\x04\xd5\x01\x0b\x16\n\r\n\x05\x04\x05\
-    \x02\x02\x01\x12\x04\xd5\x01\x17\x1e\n\r\n\x05\x04\x05\x02\x02\x03\x12\
-    \x04\xd5\x01!\"\n1\n\x02\x04\x06\x12\x06\xd9\x01\0\xde\x01\x01\x1a#\x20D\
-    escribes\x20a\x20value\x20within\x20an\x20enum.\n\n\x0b\n\x03\x04\x06\
-    \x01\x12\x04\xd9\x01\x08\x20\n\x0c\n\x04\x04\x06\x02\0\x12\x04\xda\x01\
-    \x02\x1b\n\r\n\x05\x04\x06\x02\0\x04\x12\x04\xda\x01\x02\n\n\r\n\x05\x04\
-    \x06\x02\0\x05\x12\x04\xda\x01\x0b\x11\n\r\n\x05\x04\x06\x02\0\x01\x12\
-    \x04\xda\x01\x12\x16\n\r\n\x05\x04\x06\x02\0\x03\x12\x04\xda\x01\x19\x1a\
-    \n\x0c\n\x04\x04\x06\x02\x01\x12\x04\xdb\x01\x02\x1c\n\r\n\x05\x04\x06\
-    \x02\x01\x04\x12\x04\xdb\x01\x02\n\n\r\n\x05\x04\x06\x02\x01\x05\x12\x04\
-    \xdb\x01\x0b\x10\n\r\n\x05\x04\x06\x02\x01\x01\x12\x04\xdb\x01\x11\x17\n\
-    \r\n\x05\x04\x06\x02\x01\x03\x12\x04\xdb\x01\x1a\x1b\n\x0c\n\x04\x04\x06\
-    \x02\x02\x12\x04\xdd\x01\x02(\n\r\n\x05\x04\x06\x02\x02\x04\x12\x04\xdd\
-    \x01\x02\n\n\r\n\x05\x04\x06\x02\x02\x06\x12\x04\xdd\x01\x0b\x1b\n\r\n\
-    \x05\x04\x06\x02\x02\x01\x12\x04\xdd\x01\x1c#\n\r\n\x05\x04\x06\x02\x02\
-    \x03\x12\x04\xdd\x01&'\n$\n\x02\x04\x07\x12\x06\xe1\x01\0\xe6\x01\x01\
-    \x1a\x16\x20Describes\x20a\x20service.\n\n\x0b\n\x03\x04\x07\x01\x12\x04\
-    \xe1\x01\x08\x1e\n\x0c\n\x04\x04\x07\x02\0\x12\x04\xe2\x01\x02\x1b\n\r\n\
-    \x05\x04\x07\x02\0\x04\x12\x04\xe2\x01\x02\n\n\r\n\x05\x04\x07\x02\0\x05\
-    \x12\x04\xe2\x01\x0b\x11\n\r\n\x05\x04\x07\x02\0\x01\x12\x04\xe2\x01\x12\
-    \x16\n\r\n\x05\x04\x07\x02\0\x03\x12\x04\xe2\x01\x19\x1a\n\x0c\n\x04\x04\
-    \x07\x02\x01\x12\x04\xe3\x01\x02,\n\r\n\x05\x04\x07\x02\x01\x04\x12\x04\
-    \xe3\x01\x02\n\n\r\n\x05\x04\x07\x02\x01\x06\x12\x04\xe3\x01\x0b\x20\n\r\
-    \n\x05\x04\x07\x02\x01\x01\x12\x04\xe3\x01!'\n\r\n\x05\x04\x07\x02\x01\
-    \x03\x12\x04\xe3\x01*+\n\x0c\n\x04\x04\x07\x02\x02\x12\x04\xe5\x01\x02&\
-    \n\r\n\x05\x04\x07\x02\x02\x04\x12\x04\xe5\x01\x02\n\n\r\n\x05\x04\x07\
-    \x02\x02\x06\x12\x04\xe5\x01\x0b\x19\n\r\n\x05\x04\x07\x02\x02\x01\x12\
-    \x04\xe5\x01\x1a!\n\r\n\x05\x04\x07\x02\x02\x03\x12\x04\xe5\x01$%\n0\n\
-    \x02\x04\x08\x12\x06\xe9\x01\0\xf7\x01\x01\x1a\"\x20Describes\x20a\x20me\
-    thod\x20of\x20a\x20service.\n\n\x0b\n\x03\x04\x08\x01\x12\x04\xe9\x01\
-    \x08\x1d\n\x0c\n\x04\x04\x08\x02\0\x12\x04\xea\x01\x02\x1b\n\r\n\x05\x04\
-    \x08\x02\0\x04\x12\x04\xea\x01\x02\n\n\r\n\x05\x04\x08\x02\0\x05\x12\x04\
-    \xea\x01\x0b\x11\n\r\n\x05\x04\x08\x02\0\x01\x12\x04\xea\x01\x12\x16\n\r\
-    \n\x05\x04\x08\x02\0\x03\x12\x04\xea\x01\x19\x1a\n\x97\x01\n\x04\x04\x08\
-    \x02\x01\x12\x04\xee\x01\x02!\x1a\x88\x01\x20Input\x20and\x20output\x20t\
-    ype\x20names.\x20\x20These\x20are\x20resolved\x20in\x20the\x20same\x20wa\
-    y\x20as\n\x20FieldDescriptorProto.type_name,\x20but\x20must\x20refer\x20\
-    to\x20a\x20message\x20type.\n\n\r\n\x05\x04\x08\x02\x01\x04\x12\x04\xee\
-    \x01\x02\n\n\r\n\x05\x04\x08\x02\x01\x05\x12\x04\xee\x01\x0b\x11\n\r\n\
-    \x05\x04\x08\x02\x01\x01\x12\x04\xee\x01\x12\x1c\n\r\n\x05\x04\x08\x02\
-    \x01\x03\x12\x04\xee\x01\x1f\x20\n\x0c\n\x04\x04\x08\x02\x02\x12\x04\xef\
-    \x01\x02\"\n\r\n\x05\x04\x08\x02\x02\x04\x12\x04\xef\x01\x02\n\n\r\n\x05\
-    \x04\x08\x02\x02\x05\x12\x04\xef\x01\x0b\x11\n\r\n\x05\x04\x08\x02\x02\
-    \x01\x12\x04\xef\x01\x12\x1d\n\r\n\x05\x04\x08\x02\x02\x03\x12\x04\xef\
-    \x01\x20!\n\x0c\n\x04\x04\x08\x02\x03\x12\x04\xf1\x01\x02%\n\r\n\x05\x04\
-    \x08\x02\x03\x04\x12\x04\xf1\x01\x02\n\n\r\n\x05\x04\x08\x02\x03\x06\x12\
-    \x04\xf1\x01\x0b\x18\n\r\n\x05\x04\x08\x02\x03\x01\x12\x04\xf1\x01\x19\
-    \x20\n\r\n\x05\x04\x08\x02\x03\x03\x12\x04\xf1\x01#$\nE\n\x04\x04\x08\
-    \x02\x04\x12\x04\xf4\x01\x025\x1a7\x20Identifies\x20if\x20client\x20stre\
-    ams\x20multiple\x20client\x20messages\n\n\r\n\x05\x04\x08\x02\x04\x04\
-    \x12\x04\xf4\x01\x02\n\n\r\n\x05\x04\x08\x02\x04\x05\x12\x04\xf4\x01\x0b\
-    \x0f\n\r\n\x05\x04\x08\x02\x04\x01\x12\x04\xf4\x01\x10\x20\n\r\n\x05\x04\
-    \x08\x02\x04\x03\x12\x04\xf4\x01#$\n\r\n\x05\x04\x08\x02\x04\x08\x12\x04\
-    \xf4\x01%4\n\r\n\x05\x04\x08\x02\x04\x07\x12\x04\xf4\x01.3\nE\n\x04\x04\
-    \x08\x02\x05\x12\x04\xf6\x01\x025\x1a7\x20Identifies\x20if\x20server\x20\
-    streams\x20multiple\x20server\x20messages\n\n\r\n\x05\x04\x08\x02\x05\
-    \x04\x12\x04\xf6\x01\x02\n\n\r\n\x05\x04\x08\x02\x05\x05\x12\x04\xf6\x01\
-    \x0b\x0f\n\r\n\x05\x04\x08\x02\x05\x01\x12\x04\xf6\x01\x10\x20\n\r\n\x05\
-    \x04\x08\x02\x05\x03\x12\x04\xf6\x01#$\n\r\n\x05\x04\x08\x02\x05\x08\x12\
-    \x04\xf6\x01%4\n\r\n\x05\x04\x08\x02\x05\x07\x12\x04\xf6\x01.3\n\xaf\x0e\
-    \n\x02\x04\t\x12\x06\x9b\x02\0\xf8\x02\x012N\x20========================\
-    ===========================================\n\x20Options\n2\xd0\r\x20Eac\
-    h\x20of\x20the\x20definitions\x20above\x20may\x20have\x20\"options\"\x20\
-    attached.\x20\x20These\x20are\n\x20just\x20annotations\x20which\x20may\
-    \x20cause\x20code\x20to\x20be\x20generated\x20slightly\x20differently\n\
-    \x20or\x20may\x20contain\x20hints\x20for\x20code\x20that\x20manipulates\
-    \x20protocol\x20messages.\n\n\x20Clients\x20may\x20define\x20custom\x20o\
-    ptions\x20as\x20extensions\x20of\x20the\x20*Options\x20messages.\n\x20Th\
-    ese\x20extensions\x20may\x20not\x20yet\x20be\x20known\x20at\x20parsing\
-    \x20time,\x20so\x20the\x20parser\x20cannot\n\x20store\x20the\x20values\
-    \x20in\x20them.\x20\x20Instead\x20it\x20stores\x20them\x20in\x20a\x20fie\
-    ld\x20in\x20the\x20*Options\n\x20message\x20called\x20uninterpreted_opti\
-    on.\x20This\x20field\x20must\x20have\x20the\x20same\x20name\n\x20across\
-    \x20all\x20*Options\x20messages.\x20We\x20then\x20use\x20this\x20field\
-    \x20to\x20populate\x20the\n\x20extensions\x20when\x20we\x20build\x20a\
-    \x20descriptor,\x20at\x20which\x20point\x20all\x20protos\x20have\x20been\
-    \n\x20parsed\x20and\x20so\x20all\x20extensions\x20are\x20known.\n\n\x20E\
-    xtension\x20numbers\x20for\x20custom\x20options\x20may\x20be\x20chosen\
-    \x20as\x20follows:\n\x20*\x20For\x20options\x20which\x20will\x20only\x20\
-    be\x20used\x20within\x20a\x20single\x20application\x20or\n\x20\x20\x20or\
-    ganization,\x20or\x20for\x20experimental\x20options,\x20use\x20field\x20\
-    numbers\x2050000\n\x20\x20\x20through\x2099999.\x20\x20It\x20is\x20up\
-    \x20to\x20you\x20to\x20ensure\x20that\x20you\x20do\x20not\x20use\x20the\
-    \n\x20\x20\x20same\x20number\x20for\x20multiple\x20options.\n\x20*\x20Fo\
-    r\x20options\x20which\x20will\x20be\x20published\x20and\x20used\x20publi\
-    cly\x20by\x20multiple\n\x20\x20\x20independent\x20entities,\x20e-mail\
-    \x20protobuf-global-extension-registry@google.com\n\x20\x20\x20to\x20res\
-    erve\x20extension\x20numbers.\x20Simply\x20provide\x20your\x20project\
-    \x20name\x20(e.g.\n\x20\x20\x20Objective-C\x20plugin)\x20and\x20your\x20\
-    project\x20website\x20(if\x20available)\x20--\x20there's\x20no\n\x20\x20\
-    \x20need\x20to\x20explain\x20how\x20you\x20intend\x20to\x20use\x20them.\
-    \x20Usually\x20you\x20only\x20need\x20one\n\x20\x20\x20extension\x20numb\
-    er.\x20You\x20can\x20declare\x20multiple\x20options\x20with\x20only\x20o\
-    ne\x20extension\n\x20\x20\x20number\x20by\x20putting\x20them\x20in\x20a\
-    \x20sub-message.\x20See\x20the\x20Custom\x20Options\x20section\x20of\n\
-    \x20\x20\x20the\x20docs\x20for\x20examples:\n\x20\x20\x20https://develop\
-    ers.google.com/protocol-buffers/docs/proto#options\n\x20\x20\x20If\x20th\
-    is\x20turns\x20out\x20to\x20be\x20popular,\x20a\x20web\x20service\x20wil\
-    l\x20be\x20set\x20up\n\x20\x20\x20to\x20automatically\x20assign\x20optio\
-    n\x20numbers.\n\n\x0b\n\x03\x04\t\x01\x12\x04\x9b\x02\x08\x13\n\xf4\x01\
-    \n\x04\x04\t\x02\0\x12\x04\xa1\x02\x02#\x1a\xe5\x01\x20Sets\x20the\x20Ja\
-    va\x20package\x20where\x20classes\x20generated\x20from\x20this\x20.proto\
-    \x20will\x20be\n\x20placed.\x20\x20By\x20default,\x20the\x20proto\x20pac\
-    kage\x20is\x20used,\x20but\x20this\x20is\x20often\n\x20inappropriate\x20\
-    because\x20proto\x20packages\x20do\x20not\x20normally\x20start\x20with\
-    \x20backwards\n\x20domain\x20names.\n\n\r\n\x05\x04\t\x02\0\x04\x12\x04\
-    \xa1\x02\x02\n\n\r\n\x05\x04\t\x02\0\x05\x12\x04\xa1\x02\x0b\x11\n\r\n\
-    \x05\x04\t\x02\0\x01\x12\x04\xa1\x02\x12\x1e\n\r\n\x05\x04\t\x02\0\x03\
-    \x12\x04\xa1\x02!\"\n\xbf\x02\n\x04\x04\t\x02\x01\x12\x04\xa9\x02\x02+\
-    \x1a\xb0\x02\x20If\x20set,\x20all\x20the\x20classes\x20from\x20the\x20.p\
-    roto\x20file\x20are\x20wrapped\x20in\x20a\x20single\n\x20outer\x20class\
-    \x20with\x20the\x20given\x20name.\x20\x20This\x20applies\x20to\x20both\
-    \x20Proto1\n\x20(equivalent\x20to\x20the\x20old\x20\"--one_java_file\"\
-    \x20option)\x20and\x20Proto2\x20(where\n\x20a\x20.proto\x20always\x20tra\
-    nslates\x20to\x20a\x20single\x20class,\x20but\x20you\x20may\x20want\x20t\
-    o\n\x20explicitly\x20choose\x20the\x20class\x20name).\n\n\r\n\x05\x04\t\
-    \x02\x01\x04\x12\x04\xa9\x02\x02\n\n\r\n\x05\x04\t\x02\x01\x05\x12\x04\
-    \xa9\x02\x0b\x11\n\r\n\x05\x04\t\x02\x01\x01\x12\x04\xa9\x02\x12&\n\r\n\
-    \x05\x04\t\x02\x01\x03\x12\x04\xa9\x02)*\n\xa3\x03\n\x04\x04\t\x02\x02\
-    \x12\x04\xb1\x02\x029\x1a\x94\x03\x20If\x20set\x20true,\x20then\x20the\
-    \x20Java\x20code\x20generator\x20will\x20generate\x20a\x20separate\x20.j\
-    ava\n\x20file\x20for\x20each\x20top-level\x20message,\x20enum,\x20and\
-    \x20service\x20defined\x20in\x20the\x20.proto\n\x20file.\x20\x20Thus,\
-    \x20these\x20types\x20will\x20*not*\x20be\x20nested\x20inside\x20the\x20\
-    outer\x20class\n\x20named\x20by\x20java_outer_classname.\x20\x20However,\
-    \x20the\x20outer\x20class\x20will\x20still\x20be\n\x20generated\x20to\
-    \x20contain\x20the\x20file's\x20getDescriptor()\x20method\x20as\x20well\
-    \x20as\x20any\n\x20top-level\x20extensions\x20defined\x20in\x20the\x20fi\
-    le.\n\n\r\n\x05\x04\t\x02\x02\x04\x12\x04\xb1\x02\x02\n\n\r\n\x05\x04\t\
-    \x02\x02\x05\x12\x04\xb1\x02\x0b\x0f\n\r\n\x05\x04\t\x02\x02\x01\x12\x04\
-    \xb1\x02\x10#\n\r\n\x05\x04\t\x02\x02\x03\x12\x04\xb1\x02&(\n\r\n\x05\
-    \x04\t\x02\x02\x08\x12\x04\xb1\x02)8\n\r\n\x05\x04\t\x02\x02\x07\x12\x04\
-    \xb1\x0227\n)\n\x04\x04\t\x02\x03\x12\x04\xb4\x02\x02E\x1a\x1b\x20This\
-    \x20option\x20does\x20nothing.\n\n\r\n\x05\x04\t\x02\x03\x04\x12\x04\xb4\
-    \x02\x02\n\n\r\n\x05\x04\t\x02\x03\x05\x12\x04\xb4\x02\x0b\x0f\n\r\n\x05\
-    \x04\t\x02\x03\x01\x12\x04\xb4\x02\x10-\n\r\n\x05\x04\t\x02\x03\x03\x12\
-    \x04\xb4\x0202\n\r\n\x05\x04\t\x02\x03\x08\x12\x04\xb4\x023D\n\x10\n\x08\
-    \x04\t\x02\x03\x08\xe7\x07\0\x12\x04\xb4\x024C\n\x11\n\t\x04\t\x02\x03\
-    \x08\xe7\x07\0\x02\x12\x04\xb4\x024>\n\x12\n\n\x04\t\x02\x03\x08\xe7\x07\
-    \0\x02\0\x12\x04\xb4\x024>\n\x13\n\x0b\x04\t\x02\x03\x08\xe7\x07\0\x02\0\
-    \x01\x12\x04\xb4\x024>\n\x11\n\t\x04\t\x02\x03\x08\xe7\x07\0\x03\x12\x04\
-    \xb4\x02?C\n\xe6\x02\n\x04\x04\t\x02\x04\x12\x04\xbc\x02\x02<\x1a\xd7\
-    \x02\x20If\x20set\x20true,\x20then\x20the\x20Java2\x20code\x20generator\
-    \x20will\x20generate\x20code\x20that\n\x20throws\x20an\x20exception\x20w\
-    henever\x20an\x20attempt\x20is\x20made\x20to\x20assign\x20a\x20non-UTF-8\
-    \n\x20byte\x20sequence\x20to\x20a\x20string\x20field.\n\x20Message\x20re\
-    flection\x20will\x20do\x20the\x20same.\n\x20However,\x20an\x20extension\
-    \x20field\x20still\x20accepts\x20non-UTF-8\x20byte\x20sequences.\n\x20Th\
-    is\x20option\x20has\x20no\x20effect\x20on\x20when\x20used\x20with\x20the\
-    \x20lite\x20runtime.\n\n\r\n\x05\x04\t\x02\x04\x04\x12\x04\xbc\x02\x02\n\
-    \n\r\n\x05\x04\t\x02\x04\x05\x12\x04\xbc\x02\x0b\x0f\n\r\n\x05\x04\t\x02\
-    \x04\x01\x12\x04\xbc\x02\x10&\n\r\n\x05\x04\t\x02\x04\x03\x12\x04\xbc\
-    \x02)+\n\r\n\x05\x04\t\x02\x04\x08\x12\x04\xbc\x02,;\n\r\n\x05\x04\t\x02\
-    \x04\x07\x12\x04\xbc\x025:\nL\n\x04\x04\t\x04\0\x12\x06\xc0\x02\x02\xc5\
-    \x02\x03\x1a<\x20Generated\x20classes\x20can\x20be\x20optimized\x20for\
-    \x20speed\x20or\x20code\x20size.\n\n\r\n\x05\x04\t\x04\0\x01\x12\x04\xc0\
-    \x02\x07\x13\nD\n\x06\x04\t\x04\0\x02\0\x12\x04\xc1\x02\x04\x0e\"4\x20Ge\
-    nerate\x20complete\x20code\x20for\x20parsing,\x20serialization,\n\n\x0f\
-    \n\x07\x04\t\x04\0\x02\0\x01\x12\x04\xc1\x02\x04\t\n\x0f\n\x07\x04\t\x04\
-    \0\x02\0\x02\x12\x04\xc1\x02\x0c\r\nG\n\x06\x04\t\x04\0\x02\x01\x12\x04\
-    \xc3\x02\x04\x12\x1a\x06\x20etc.\n\"/\x20Use\x20ReflectionOps\x20to\x20i\
-    mplement\x20these\x20methods.\n\n\x0f\n\x07\x04\t\x04\0\x02\x01\x01\x12\
-    \x04\xc3\x02\x04\r\n\x0f\n\x07\x04\t\x04\0\x02\x01\x02\x12\x04\xc3\x02\
-    \x10\x11\nG\n\x06\x04\t\x04\0\x02\x02\x12\x04\xc4\x02\x04\x15\"7\x20Gene\
-    rate\x20code\x20using\x20MessageLite\x20and\x20the\x20lite\x20runtime.\n\
-    \n\x0f\n\x07\x04\t\x04\0\x02\x02\x01\x12\x04\xc4\x02\x04\x10\n\x0f\n\x07\
-    \x04\t\x04\0\x02\x02\x02\x12\x04\xc4\x02\x13\x14\n\x0c\n\x04\x04\t\x02\
-    \x05\x12\x04\xc6\x02\x029\n\r\n\x05\x04\t\x02\x05\x04\x12\x04\xc6\x02\
-    \x02\n\n\r\n\x05\x04\t\x02\x05\x06\x12\x04\xc6\x02\x0b\x17\n\r\n\x05\x04\
-    \t\x02\x05\x01\x12\x04\xc6\x02\x18$\n\r\n\x05\x04\t\x02\x05\x03\x12\x04\
-    \xc6\x02'(\n\r\n\x05\x04\t\x02\x05\x08\x12\x04\xc6\x02)8\n\r\n\x05\x04\t\
-    \x02\x05\x07\x12\x04\xc6\x0227\n\xe2\x02\n\x04\x04\t\x02\x06\x12\x04\xcd\
-    \x02\x02\"\x1a\xd3\x02\x20Sets\x20the\x20Go\x20package\x20where\x20struc\
-    ts\x20generated\x20from\x20this\x20.proto\x20will\x20be\n\x20placed.\x20\
-    If\x20omitted,\x20the\x20Go\x20package\x20will\x20be\x20derived\x20from\
-    \x20the\x20following:\n\x20\x20\x20-\x20The\x20basename\x20of\x20the\x20\
-    package\x20import\x20path,\x20if\x20provided.\n\x20\x20\x20-\x20Otherwis\
-    e,\x20the\x20package\x20statement\x20in\x20the\x20.proto\x20file,\x20if\
-    \x20present.\n\x20\x20\x20-\x20Otherwise,\x20the\x20basename\x20of\x20th\
-    e\x20.proto\x20file,\x20without\x20extension.\n\n\r\n\x05\x04\t\x02\x06\
-    \x04\x12\x04\xcd\x02\x02\n\n\r\n\x05\x04\t\x02\x06\x05\x12\x04\xcd\x02\
-    \x0b\x11\n\r\n\x05\x04\t\x02\x06\x01\x12\x04\xcd\x02\x12\x1c\n\r\n\x05\
-    \x04\t\x02\x06\x03\x12\x04\xcd\x02\x1f!\n\xd4\x04\n\x04\x04\t\x02\x07\
-    \x12\x04\xdb\x02\x029\x1a\xc5\x04\x20Should\x20generic\x20services\x20be\
-    \x20generated\x20in\x20each\x20language?\x20\x20\"Generic\"\x20services\
-    \n\x20are\x20not\x20specific\x20to\x20any\x20particular\x20RPC\x20system\
-    .\x20\x20They\x20are\x20generated\x20by\x20the\n\x20main\x20code\x20gene\
-    rators\x20in\x20each\x20language\x20(without\x20additional\x20plugins).\
-    \n\x20Generic\x20services\x20were\x20the\x20only\x20kind\x20of\x20servic\
-    e\x20generation\x20supported\x20by\n\x20early\x20versions\x20of\x20googl\
-    e.protobuf.\n\n\x20Generic\x20services\x20are\x20now\x20considered\x20de\
-    precated\x20in\x20favor\x20of\x20using\x20plugins\n\x20that\x20generate\
-    \x20code\x20specific\x20to\x20your\x20particular\x20RPC\x20system.\x20\
-    \x20Therefore,\n\x20these\x20default\x20to\x20false.\x20\x20Old\x20code\
-    \x20which\x20depends\x20on\x20generic\x20services\x20should\n\x20explici\
-    tly\x20set\x20them\x20to\x20true.\n\n\r\n\x05\x04\t\x02\x07\x04\x12\x04\
-    \xdb\x02\x02\n\n\r\n\x05\x04\t\x02\x07\x05\x12\x04\xdb\x02\x0b\x0f\n\r\n\
-    \x05\x04\t\x02\x07\x01\x12\x04\xdb\x02\x10#\n\r\n\x05\x04\t\x02\x07\x03\
-    \x12\x04\xdb\x02&(\n\r\n\x05\x04\t\x02\x07\x08\x12\x04\xdb\x02)8\n\r\n\
-    \x05\x04\t\x02\x07\x07\x12\x04\xdb\x0227\n\x0c\n\x04\x04\t\x02\x08\x12\
-    \x04\xdc\x02\x02;\n\r\n\x05\x04\t\x02\x08\x04\x12\x04\xdc\x02\x02\n\n\r\
-    \n\x05\x04\t\x02\x08\x05\x12\x04\xdc\x02\x0b\x0f\n\r\n\x05\x04\t\x02\x08\
-    \x01\x12\x04\xdc\x02\x10%\n\r\n\x05\x04\t\x02\x08\x03\x12\x04\xdc\x02(*\
-    \n\r\n\x05\x04\t\x02\x08\x08\x12\x04\xdc\x02+:\n\r\n\x05\x04\t\x02\x08\
-    \x07\x12\x04\xdc\x0249\n\x0c\n\x04\x04\t\x02\t\x12\x04\xdd\x02\x029\n\r\
-    \n\x05\x04\t\x02\t\x04\x12\x04\xdd\x02\x02\n\n\r\n\x05\x04\t\x02\t\x05\
-    \x12\x04\xdd\x02\x0b\x0f\n\r\n\x05\x04\t\x02\t\x01\x12\x04\xdd\x02\x10#\
-    \n\r\n\x05\x04\t\x02\t\x03\x12\x04\xdd\x02&(\n\r\n\x05\x04\t\x02\t\x08\
-    \x12\x04\xdd\x02)8\n\r\n\x05\x04\t\x02\t\x07\x12\x04\xdd\x0227\n\xf3\x01\
-    \n\x04\x04\t\x02\n\x12\x04\xe3\x02\x020\x1a\xe4\x01\x20Is\x20this\x20fil\
-    e\x20deprecated?\n\x20Depending\x20on\x20the\x20target\x20platform,\x20t\
-    his\x20can\x20emit\x20Deprecated\x20annotations\n\x20for\x20everything\
-    \x20in\x20the\x20file,\x20or\x20it\x20will\x20be\x20completely\x20ignore\
-    d;\x20in\x20the\x20very\n\x20least,\x20this\x20is\x20a\x20formalization\
-    \x20for\x20deprecating\x20files.\n\n\r\n\x05\x04\t\x02\n\x04\x12\x04\xe3\
-    \x02\x02\n\n\r\n\x05\x04\t\x02\n\x05\x12\x04\xe3\x02\x0b\x0f\n\r\n\x05\
-    \x04\t\x02\n\x01\x12\x04\xe3\x02\x10\x1a\n\r\n\x05\x04\t\x02\n\x03\x12\
-    \x04\xe3\x02\x1d\x1f\n\r\n\x05\x04\t\x02\n\x08\x12\x04\xe3\x02\x20/\n\r\
-    \n\x05\x04\t\x02\n\x07\x12\x04\xe3\x02).\n\x7f\n\x04\x04\t\x02\x0b\x12\
-    \x04\xe7\x02\x026\x1aq\x20Enables\x20the\x20use\x20of\x20arenas\x20for\
-    \x20the\x20proto\x20messages\x20in\x20this\x20file.\x20This\x20applies\n\
-    \x20only\x20to\x20generated\x20classes\x20for\x20C++.\n\n\r\n\x05\x04\t\
-    \x02\x0b\x04\x12\x04\xe7\x02\x02\n\n\r\n\x05\x04\t\x02\x0b\x05\x12\x04\
-    \xe7\x02\x0b\x0f\n\r\n\x05\x04\t\x02\x0b\x01\x12\x04\xe7\x02\x10\x20\n\r\
-    \n\x05\x04\t\x02\x0b\x03\x12\x04\xe7\x02#%\n\r\n\x05\x04\t\x02\x0b\x08\
-    \x12\x04\xe7\x02&5\n\r\n\x05\x04\t\x02\x0b\x07\x12\x04\xe7\x02/4\n\x92\
-    \x01\n\x04\x04\t\x02\x0c\x12\x04\xec\x02\x02)\x1a\x83\x01\x20Sets\x20the\
-    \x20objective\x20c\x20class\x20prefix\x20which\x20is\x20prepended\x20to\
-    \x20all\x20objective\x20c\n\x20generated\x20classes\x20from\x20this\x20.\
-    proto.\x20There\x20is\x20no\x20default.\n\n\r\n\x05\x04\t\x02\x0c\x04\
-    \x12\x04\xec\x02\x02\n\n\r\n\x05\x04\t\x02\x0c\x05\x12\x04\xec\x02\x0b\
-    \x11\n\r\n\x05\x04\t\x02\x0c\x01\x12\x04\xec\x02\x12#\n\r\n\x05\x04\t\
-    \x02\x0c\x03\x12\x04\xec\x02&(\nI\n\x04\x04\t\x02\r\x12\x04\xef\x02\x02(\
-    \x1a;\x20Namespace\x20for\x20generated\x20classes;\x20defaults\x20to\x20\
-    the\x20package.\n\n\r\n\x05\x04\t\x02\r\x04\x12\x04\xef\x02\x02\n\n\r\n\
-    \x05\x04\t\x02\r\x05\x12\x04\xef\x02\x0b\x11\n\r\n\x05\x04\t\x02\r\x01\
-    \x12\x04\xef\x02\x12\"\n\r\n\x05\x04\t\x02\r\x03\x12\x04\xef\x02%'\nO\n\
-    \x04\x04\t\x02\x0e\x12\x04\xf2\x02\x02:\x1aA\x20The\x20parser\x20stores\
-    \x20options\x20it\x20doesn't\x20recognize\x20here.\x20See\x20above.\n\n\
-    \r\n\x05\x04\t\x02\x0e\x04\x12\x04\xf2\x02\x02\n\n\r\n\x05\x04\t\x02\x0e\
-    \x06\x12\x04\xf2\x02\x0b\x1e\n\r\n\x05\x04\t\x02\x0e\x01\x12\x04\xf2\x02\
-    \x1f3\n\r\n\x05\x04\t\x02\x0e\x03\x12\x04\xf2\x0269\nZ\n\x03\x04\t\x05\
-    \x12\x04\xf5\x02\x02\x19\x1aM\x20Clients\x20can\x20define\x20custom\x20o\
-    ptions\x20in\x20extensions\x20of\x20this\x20message.\x20See\x20above.\n\
-    \n\x0c\n\x04\x04\t\x05\0\x12\x04\xf5\x02\r\x18\n\r\n\x05\x04\t\x05\0\x01\
-    \x12\x04\xf5\x02\r\x11\n\r\n\x05\x04\t\x05\0\x02\x12\x04\xf5\x02\x15\x18\
-    \n\x0c\n\x02\x04\n\x12\x06\xfa\x02\0\xb8\x03\x01\n\x0b\n\x03\x04\n\x01\
-    \x12\x04\xfa\x02\x08\x16\n\xd8\x05\n\x04\x04\n\x02\0\x12\x04\x8d\x03\x02\
-    <\x1a\xc9\x05\x20Set\x20true\x20to\x20use\x20the\x20old\x20proto1\x20Mes\
-    sageSet\x20wire\x20format\x20for\x20extensions.\n\x20This\x20is\x20provi\
-    ded\x20for\x20backwards-compatibility\x20with\x20the\x20MessageSet\x20wi\
-    re\n\x20format.\x20\x20You\x20should\x20not\x20use\x20this\x20for\x20any\
-    \x20other\x20reason:\x20\x20It's\x20less\n\x20efficient,\x20has\x20fewer\
-    \x20features,\x20and\x20is\x20more\x20complicated.\n\n\x20The\x20message\
-    \x20must\x20be\x20defined\x20exactly\x20as\x20follows:\n\x20\x20\x20mess\
-    age\x20Foo\x20{\n\x20\x20\x20\x20\x20option\x20message_set_wire_format\
-    \x20=\x20true;\n\x20\x20\x20\x20\x20extensions\x204\x20to\x20max;\n\x20\
-    \x20\x20}\n\x20Note\x20that\x20the\x20message\x20cannot\x20have\x20any\
-    \x20defined\x20fields;\x20MessageSets\x20only\n\x20have\x20extensions.\n\
-    \n\x20All\x20extensions\x20of\x20your\x20type\x20must\x20be\x20singular\
-    \x20messages;\x20e.g.\x20they\x20cannot\n\x20be\x20int32s,\x20enums,\x20\
-    or\x20repeated\x20messages.\n\n\x20Because\x20this\x20is\x20an\x20option\
-    ,\x20the\x20above\x20two\x20restrictions\x20are\x20not\x20enforced\x20by\
-    \n\x20the\x20protocol\x20compiler.\n\n\r\n\x05\x04\n\x02\0\x04\x12\x04\
-    \x8d\x03\x02\n\n\r\n\x05\x04\n\x02\0\x05\x12\x04\x8d\x03\x0b\x0f\n\r\n\
-    \x05\x04\n\x02\0\x01\x12\x04\x8d\x03\x10'\n\r\n\x05\x04\n\x02\0\x03\x12\
-    \x04\x8d\x03*+\n\r\n\x05\x04\n\x02\0\x08\x12\x04\x8d\x03,;\n\r\n\x05\x04\
-    \n\x02\0\x07\x12\x04\x8d\x035:\n\xeb\x01\n\x04\x04\n\x02\x01\x12\x04\x92\
-    \x03\x02D\x1a\xdc\x01\x20Disables\x20the\x20generation\x20of\x20the\x20s\
-    tandard\x20\"descriptor()\"\x20accessor,\x20which\x20can\n\x20conflict\
-    \x20with\x20a\x20field\x20of\x20the\x20same\x20name.\x20\x20This\x20is\
-    \x20meant\x20to\x20make\x20migration\n\x20from\x20proto1\x20easier;\x20n\
-    ew\x20code\x20should\x20avoid\x20fields\x20named\x20\"descriptor\".\n\n\
-    \r\n\x05\x04\n\x02\x01\x04\x12\x04\x92\x03\x02\n\n\r\n\x05\x04\n\x02\x01\
-    \x05\x12\x04\x92\x03\x0b\x0f\n\r\n\x05\x04\n\x02\x01\x01\x12\x04\x92\x03\
-    \x10/\n\r\n\x05\x04\n\x02\x01\x03\x12\x04\x92\x0323\n\r\n\x05\x04\n\x02\
-    \x01\x08\x12\x04\x92\x034C\n\r\n\x05\x04\n\x02\x01\x07\x12\x04\x92\x03=B\
-    \n\xee\x01\n\x04\x04\n\x02\x02\x12\x04\x98\x03\x02/\x1a\xdf\x01\x20Is\
-    \x20this\x20message\x20deprecated?\n\x20Depending\x20on\x20the\x20target\
-    \x20platform,\x20this\x20can\x20emit\x20Deprecated\x20annotations\n\x20f\
-    or\x20the\x20message,\x20or\x20it\x20will\x20be\x20completely\x20ignored\
-    ;\x20in\x20the\x20very\x20least,\n\x20this\x20is\x20a\x20formalization\
-    \x20for\x20deprecating\x20messages.\n\n\r\n\x05\x04\n\x02\x02\x04\x12\
-    \x04\x98\x03\x02\n\n\r\n\x05\x04\n\x02\x02\x05\x12\x04\x98\x03\x0b\x0f\n\
-    \r\n\x05\x04\n\x02\x02\x01\x12\x04\x98\x03\x10\x1a\n\r\n\x05\x04\n\x02\
-    \x02\x03\x12\x04\x98\x03\x1d\x1e\n\r\n\x05\x04\n\x02\x02\x08\x12\x04\x98\
-    \x03\x1f.\n\r\n\x05\x04\n\x02\x02\x07\x12\x04\x98\x03(-\n\x9e\x06\n\x04\
-    \x04\n\x02\x03\x12\x04\xaf\x03\x02\x1e\x1a\x8f\x06\x20Whether\x20the\x20\
-    message\x20is\x20an\x20automatically\x20generated\x20map\x20entry\x20typ\
-    e\x20for\x20the\n\x20maps\x20field.\n\n\x20For\x20maps\x20fields:\n\x20\
-    \x20\x20\x20\x20map<KeyType,\x20ValueType>\x20map_field\x20=\x201;\n\x20\
-    The\x20parsed\x20descriptor\x20looks\x20like:\n\x20\x20\x20\x20\x20messa\
-    ge\x20MapFieldEntry\x20{\n\x20\x20\x20\x20\x20\x20\x20\x20\x20option\x20\
-    map_entry\x20=\x20true;\n\x20\x20\x20\x20\x20\x20\x20\x20\x20optional\
-    \x20KeyType\x20key\x20=\x201;\n\x20\x20\x20\x20\x20\x20\x20\x20\x20optio\
-    nal\x20ValueType\x20value\x20=\x202;\n\x20\x20\x20\x20\x20}\n\x20\x20\
-    \x20\x20\x20repeated\x20MapFieldEntry\x20map_field\x20=\x201;\n\n\x20Imp\
-    lementations\x20may\x20choose\x20not\x20to\x20generate\x20the\x20map_ent\
-    ry=true\x20message,\x20but\n\x20use\x20a\x20native\x20map\x20in\x20the\
-    \x20target\x20language\x20to\x20hold\x20the\x20keys\x20and\x20values.\n\
-    \x20The\x20reflection\x20APIs\x20in\x20such\x20implementions\x20still\
-    \x20need\x20to\x20work\x20as\n\x20if\x20the\x20field\x20is\x20a\x20repea\
-    ted\x20message\x20field.\n\n\x20NOTE:\x20Do\x20not\x20set\x20the\x20opti\
-    on\x20in\x20.proto\x20files.\x20Always\x20use\x20the\x20maps\x20syntax\n\
-    \x20instead.\x20The\x20option\x20should\x20only\x20be\x20implicitly\x20s\
-    et\x20by\x20the\x20proto\x20compiler\n\x20parser.\n\n\r\n\x05\x04\n\x02\
-    \x03\x04\x12\x04\xaf\x03\x02\n\n\r\n\x05\x04\n\x02\x03\x05\x12\x04\xaf\
-    \x03\x0b\x0f\n\r\n\x05\x04\n\x02\x03\x01\x12\x04\xaf\x03\x10\x19\n\r\n\
-    \x05\x04\n\x02\x03\x03\x12\x04\xaf\x03\x1c\x1d\nO\n\x04\x04\n\x02\x04\
-    \x12\x04\xb2\x03\x02:\x1aA\x20The\x20parser\x20stores\x20options\x20it\
-    \x20doesn't\x20recognize\x20here.\x20See\x20above.\n\n\r\n\x05\x04\n\x02\
-    \x04\x04\x12\x04\xb2\x03\x02\n\n\r\n\x05\x04\n\x02\x04\x06\x12\x04\xb2\
-    \x03\x0b\x1e\n\r\n\x05\x04\n\x02\x04\x01\x12\x04\xb2\x03\x1f3\n\r\n\x05\
-    \x04\n\x02\x04\x03\x12\x04\xb2\x0369\nZ\n\x03\x04\n\x05\x12\x04\xb5\x03\
-    \x02\x19\x1aM\x20Clients\x20can\x20define\x20custom\x20options\x20in\x20\
-    extensions\x20of\x20this\x20message.\x20See\x20above.\n\n\x0c\n\x04\x04\
-    \n\x05\0\x12\x04\xb5\x03\r\x18\n\r\n\x05\x04\n\x05\0\x01\x12\x04\xb5\x03\
-    \r\x11\n\r\n\x05\x04\n\x05\0\x02\x12\x04\xb5\x03\x15\x18\n\x0c\n\x02\x04\
-    \x0b\x12\x06\xba\x03\0\x93\x04\x01\n\x0b\n\x03\x04\x0b\x01\x12\x04\xba\
-    \x03\x08\x14\n\xa3\x02\n\x04\x04\x0b\x02\0\x12\x04\xbf\x03\x02.\x1a\x94\
-    \x02\x20The\x20ctype\x20option\x20instructs\x20the\x20C++\x20code\x20gen\
-    erator\x20to\x20use\x20a\x20different\n\x20representation\x20of\x20the\
-    \x20field\x20than\x20it\x20normally\x20would.\x20\x20See\x20the\x20speci\
-    fic\n\x20options\x20below.\x20\x20This\x20option\x20is\x20not\x20yet\x20\
-    implemented\x20in\x20the\x20open\x20source\n\x20release\x20--\x20sorry,\
-    \x20we'll\x20try\x20to\x20include\x20it\x20in\x20a\x20future\x20version!\
-    \n\n\r\n\x05\x04\x0b\x02\0\x04\x12\x04\xbf\x03\x02\n\n\r\n\x05\x04\x0b\
-    \x02\0\x06\x12\x04\xbf\x03\x0b\x10\n\r\n\x05\x04\x0b\x02\0\x01\x12\x04\
-    \xbf\x03\x11\x16\n\r\n\x05\x04\x0b\x02\0\x03\x12\x04\xbf\x03\x19\x1a\n\r\
-    \n\x05\x04\x0b\x02\0\x08\x12\x04\xbf\x03\x1b-\n\r\n\x05\x04\x0b\x02\0\
-    \x07\x12\x04\xbf\x03&,\n\x0e\n\x04\x04\x0b\x04\0\x12\x06\xc0\x03\x02\xc7\
-    \x03\x03\n\r\n\x05\x04\x0b\x04\0\x01\x12\x04\xc0\x03\x07\x0c\n\x1f\n\x06\
-    \x04\x0b\x04\0\x02\0\x12\x04\xc2\x03\x04\x0f\x1a\x0f\x20Default\x20mode.\
-    \n\n\x0f\n\x07\x04\x0b\x04\0\x02\0\x01\x12\x04\xc2\x03\x04\n\n\x0f\n\x07\
-    \x04\x0b\x04\0\x02\0\x02\x12\x04\xc2\x03\r\x0e\n\x0e\n\x06\x04\x0b\x04\0\
-    \x02\x01\x12\x04\xc4\x03\x04\r\n\x0f\n\x07\x04\x0b\x04\0\x02\x01\x01\x12\
-    \x04\xc4\x03\x04\x08\n\x0f\n\x07\x04\x0b\x04\0\x02\x01\x02\x12\x04\xc4\
-    \x03\x0b\x0c\n\x0e\n\x06\x04\x0b\x04\0\x02\x02\x12\x04\xc6\x03\x04\x15\n\
-    \x0f\n\x07\x04\x0b\x04\0\x02\x02\x01\x12\x04\xc6\x03\x04\x10\n\x0f\n\x07\
-    \x04\x0b\x04\0\x02\x02\x02\x12\x04\xc6\x03\x13\x14\n\xda\x02\n\x04\x04\
-    \x0b\x02\x01\x12\x04\xcd\x03\x02\x1b\x1a\xcb\x02\x20The\x20packed\x20opt\
-    ion\x20can\x20be\x20enabled\x20for\x20repeated\x20primitive\x20fields\
-    \x20to\x20enable\n\x20a\x20more\x20efficient\x20representation\x20on\x20\
-    the\x20wire.\x20Rather\x20than\x20repeatedly\n\x20writing\x20the\x20tag\
-    \x20and\x20type\x20for\x20each\x20element,\x20the\x20entire\x20array\x20\
-    is\x20encoded\x20as\n\x20a\x20single\x20length-delimited\x20blob.\x20In\
-    \x20proto3,\x20only\x20explicit\x20setting\x20it\x20to\n\x20false\x20wil\
-    l\x20avoid\x20using\x20packed\x20encoding.\n\n\r\n\x05\x04\x0b\x02\x01\
-    \x04\x12\x04\xcd\x03\x02\n\n\r\n\x05\x04\x0b\x02\x01\x05\x12\x04\xcd\x03\
-    \x0b\x0f\n\r\n\x05\x04\x0b\x02\x01\x01\x12\x04\xcd\x03\x10\x16\n\r\n\x05\
-    \x04\x0b\x02\x01\x03\x12\x04\xcd\x03\x19\x1a\n\xe4\x04\n\x04\x04\x0b\x02\
-    \x02\x12\x04\xd8\x03\x023\x1a\xd5\x04\x20The\x20jstype\x20option\x20dete\
-    rmines\x20the\x20JavaScript\x20type\x20used\x20for\x20values\x20of\x20th\
-    e\n\x20field.\x20\x20The\x20option\x20is\x20permitted\x20only\x20for\x20\
-    64\x20bit\x20integral\x20and\x20fixed\x20types\n\x20(int64,\x20uint64,\
-    \x20sint64,\x20fixed64,\x20sfixed64).\x20\x20By\x20default\x20these\x20t\
-    ypes\x20are\n\x20represented\x20as\x20JavaScript\x20strings.\x20\x20This\
-    \x20avoids\x20loss\x20of\x20precision\x20that\x20can\n\x20happen\x20when\
-    \x20a\x20large\x20value\x20is\x20converted\x20to\x20a\x20floating\x20poi\
-    nt\x20JavaScript\n\x20numbers.\x20\x20Specifying\x20JS_NUMBER\x20for\x20\
-    the\x20jstype\x20causes\x20the\x20generated\n\x20JavaScript\x20code\x20t\
-    o\x20use\x20the\x20JavaScript\x20\"number\"\x20type\x20instead\x20of\x20\
-    strings.\n\x20This\x20option\x20is\x20an\x20enum\x20to\x20permit\x20addi\
-    tional\x20types\x20to\x20be\x20added,\n\x20e.g.\x20goog.math.Integer.\n\
-    \n\r\n\x05\x04\x0b\x02\x02\x04\x12\x04\xd8\x03\x02\n\n\r\n\x05\x04\x0b\
-    \x02\x02\x06\x12\x04\xd8\x03\x0b\x11\n\r\n\x05\x04\x0b\x02\x02\x01\x12\
-    \x04\xd8\x03\x12\x18\n\r\n\x05\x04\x0b\x02\x02\x03\x12\x04\xd8\x03\x1b\
-    \x1c\n\r\n\x05\x04\x0b\x02\x02\x08\x12\x04\xd8\x03\x1d2\n\r\n\x05\x04\
-    \x0b\x02\x02\x07\x12\x04\xd8\x03(1\n\x0e\n\x04\x04\x0b\x04\x01\x12\x06\
-    \xd9\x03\x02\xe2\x03\x03\n\r\n\x05\x04\x0b\x04\x01\x01\x12\x04\xd9\x03\
-    \x07\r\n'\n\x06\x04\x0b\x04\x01\x02\0\x12\x04\xdb\x03\x04\x12\x1a\x17\
-    \x20Use\x20the\x20default\x20type.\n\n\x0f\n\x07\x04\x0b\x04\x01\x02\0\
-    \x01\x12\x04\xdb\x03\x04\r\n\x0f\n\x07\x04\x0b\x04\x01\x02\0\x02\x12\x04\
-    \xdb\x03\x10\x11\n)\n\x06\x04\x0b\x04\x01\x02\x01\x12\x04\xde\x03\x04\
-    \x12\x1a\x19\x20Use\x20JavaScript\x20strings.\n\n\x0f\n\x07\x04\x0b\x04\
-    \x01\x02\x01\x01\x12\x04\xde\x03\x04\r\n\x0f\n\x07\x04\x0b\x04\x01\x02\
-    \x01\x02\x12\x04\xde\x03\x10\x11\n)\n\x06\x04\x0b\x04\x01\x02\x02\x12\
-    \x04\xe1\x03\x04\x12\x1a\x19\x20Use\x20JavaScript\x20numbers.\n\n\x0f\n\
-    \x07\x04\x0b\x04\x01\x02\x02\x01\x12\x04\xe1\x03\x04\r\n\x0f\n\x07\x04\
-    \x0b\x04\x01\x02\x02\x02\x12\x04\xe1\x03\x10\x11\n\xef\x0c\n\x04\x04\x0b\
-    \x02\x03\x12\x04\x80\x04\x02)\x1a\xe0\x0c\x20Should\x20this\x20field\x20\
-    be\x20parsed\x20lazily?\x20\x20Lazy\x20applies\x20only\x20to\x20message-\
-    type\n\x20fields.\x20\x20It\x20means\x20that\x20when\x20the\x20outer\x20\
-    message\x20is\x20initially\x20parsed,\x20the\n\x20inner\x20message's\x20\
-    contents\x20will\x20not\x20be\x20parsed\x20but\x20instead\x20stored\x20i\
-    n\x20encoded\n\x20form.\x20\x20The\x20inner\x20message\x20will\x20actual\
-    ly\x20be\x20parsed\x20when\x20it\x20is\x20first\x20accessed.\n\n\x20This\
-    \x20is\x20only\x20a\x20hint.\x20\x20Implementations\x20are\x20free\x20to\
-    \x20choose\x20whether\x20to\x20use\n\x20eager\x20or\x20lazy\x20parsing\
-    \x20regardless\x20of\x20the\x20value\x20of\x20this\x20option.\x20\x20How\
-    ever,\n\x20setting\x20this\x20option\x20true\x20suggests\x20that\x20the\
-    \x20protocol\x20author\x20believes\x20that\n\x20using\x20lazy\x20parsing\
-    \x20on\x20this\x20field\x20is\x20worth\x20the\x20additional\x20bookkeepi\
-    ng\n\x20overhead\x20typically\x20needed\x20to\x20implement\x20it.\n\n\
-    \x20This\x20option\x20does\x20not\x20affect\x20the\x20public\x20interfac\
-    e\x20of\x20any\x20generated\x20code;\n\x20all\x20method\x20signatures\
-    \x20remain\x20the\x20same.\x20\x20Furthermore,\x20thread-safety\x20of\
-    \x20the\n\x20interface\x20is\x20not\x20affected\x20by\x20this\x20option;\
-    \x20const\x20methods\x20remain\x20safe\x20to\n\x20call\x20from\x20multip\
-    le\x20threads\x20concurrently,\x20while\x20non-const\x20methods\x20conti\
-    nue\n\x20to\x20require\x20exclusive\x20access.\n\n\n\x20Note\x20that\x20\
-    implementations\x20may\x20choose\x20not\x20to\x20check\x20required\x20fi\
-    elds\x20within\n\x20a\x20lazy\x20sub-message.\x20\x20That\x20is,\x20call\
-    ing\x20IsInitialized()\x20on\x20the\x20outer\x20message\n\x20may\x20retu\
-    rn\x20true\x20even\x20if\x20the\x20inner\x20message\x20has\x20missing\
-    \x20required\x20fields.\n\x20This\x20is\x20necessary\x20because\x20other\
-    wise\x20the\x20inner\x20message\x20would\x20have\x20to\x20be\n\x20parsed\
-    \x20in\x20order\x20to\x20perform\x20the\x20check,\x20defeating\x20the\
-    \x20purpose\x20of\x20lazy\n\x20parsing.\x20\x20An\x20implementation\x20w\
-    hich\x20chooses\x20not\x20to\x20check\x20required\x20fields\n\x20must\
-    \x20be\x20consistent\x20about\x20it.\x20\x20That\x20is,\x20for\x20any\
-    \x20particular\x20sub-message,\x20the\n\x20implementation\x20must\x20eit\
-    her\x20*always*\x20check\x20its\x20required\x20fields,\x20or\x20*never*\
-    \n\x20check\x20its\x20required\x20fields,\x20regardless\x20of\x20whether\
-    \x20or\x20not\x20the\x20message\x20has\n\x20been\x20parsed.\n\n\r\n\x05\
-    \x04\x0b\x02\x03\x04\x12\x04\x80\x04\x02\n\n\r\n\x05\x04\x0b\x02\x03\x05\
-    \x12\x04\x80\x04\x0b\x0f\n\r\n\x05\x04\x0b\x02\x03\x01\x12\x04\x80\x04\
-    \x10\x14\n\r\n\x05\x04\x0b\x02\x03\x03\x12\x04\x80\x04\x17\x18\n\r\n\x05\
-    \x04\x0b\x02\x03\x08\x12\x04\x80\x04\x19(\n\r\n\x05\x04\x0b\x02\x03\x07\
-    \x12\x04\x80\x04\"'\n\xe8\x01\n\x04\x04\x0b\x02\x04\x12\x04\x86\x04\x02/\
-    \x1a\xd9\x01\x20Is\x20this\x20field\x20deprecated?\n\x20Depending\x20on\
+    \x05\x03\x02\x08\x04\x12\x04\xc4\x01\x02\n\n\r\n\x05\x05\x03\x02\x08\x05\
+    \x12\x04\xc4\x01\x0b\x11\n\r\n\x05\x05\x03\x02\x08\x01\x12\x04\xc4\x01\
+    \x12\x1b\n\r\n\x05\x05\x03\x02\x08\x03\x12\x04\xc4\x01\x1e\x20\n\x0c\n\
+    \x04\x05\x03\x02\t\x12\x04\xc6\x01\x02$\n\r\n\x05\x05\x03\x02\t\x04\x12\
+    \x04\xc6\x01\x02\n\n\r\n\x05\x05\x03\x02\t\x06\x12\x04\xc6\x01\x0b\x17\n\
+    \r\n\x05\x05\x03\x02\t\x01\x12\x04\xc6\x01\x18\x1f\n\r\n\x05\x05\x03\x02\
+    \t\x03\x12\x04\xc6\x01\"#\n\xd8\x03\n\x04\x04\x03\x02\n\x12\x04\xd2\x01\
+    \x02%\x1a\xc9\x03\x20If\x20true,\x20this\x20is\x20a\x20proto3\x20\"optio\
+    nal\".\x20When\x20a\x20proto3\x20field\x20is\x20optional,\x20it\n\x20tra\
+    cks\x20presence\x20regardless\x20of\x20field\x20type.\n\n\x20When\x20pro\
+    to3_optional\x20is\x20true,\x20this\x20field\x20must\x20be\x20belong\x20\
+    to\x20a\x20oneof\x20to\n\x20signal\x20to\x20old\x20proto3\x20clients\x20\
+    that\x20presence\x20is\x20tracked\x20for\x20this\x20field.\x20This\n\x20\
+    oneof\x20is\x20known\x20as\x20a\x20\"synthetic\"\x20oneof,\x20and\x20thi\
+    s\x20field\x20must\x20be\x20its\x20sole\n\x20member.\n\n\x20Proto2\x20op\
+    tional\x20fields\x20do\x20not\x20set\x20this\x20flag,\x20because\x20they\
+    \x20already\x20indicate\n\x20optional\x20with\x20`LABEL_OPTIONAL`.\n\n\r\
+    \n\x05\x04\x03\x02\n\x04\x12\x04\xd2\x01\x02\n\n\r\n\x05\x04\x03\x02\n\
+    \x05\x12\x04\xd2\x01\x0b\x0f\n\r\n\x05\x04\x03\x02\n\x01\x12\x04\xd2\x01\
+    \x10\x1f\n\r\n\x05\x04\x03\x02\n\x03\x12\x04\xd2\x01\"$\n\"\n\x02\x05\
+    \x04\x12\x06\xd6\x01\0\xd9\x01\x01\x1a\x14\x20Describes\x20a\x20oneof.\n\
+    \n\x0b\n\x03\x05\x04\x01\x12\x04\xd6\x01\x08\x1c\n\x0c\n\x04\x05\x04\x02\
+    \0\x12\x04\xd7\x01\x02\x1b\n\r\n\x05\x05\x04\x02\0\x04\x12\x04\xd7\x01\
+    \x02\n\n\r\n\x05\x05\x04\x02\0\x05\x12\x04\xd7\x01\x0b\x11\n\r\n\x05\x05\
+    \x04\x02\0\x01\x12\x04\xd7\x01\x12\x16\n\r\n\x05\x05\x04\x02\0\x03\x12\
+    \x04\xd7\x01\x19\x1a\n\x0c\n\x04\x05\x04\x02\x01\x12\x04\xd8\x01\x02$\n\
+    \r\n\x05\x05\x04\x02\x01\x04\x12\x04\xd8\x01\x02\n\n\r\n\x05\x05\x04\x02\
+    \x01\x06\x12\x04\xd8\x01\x0b\x17\n\r\n\x05\x05\x04\x02\x01\x01\x12\x04\
+    \xd8\x01\x18\x1f\n\r\n\x05\x05\x04\x02\x01\x03\x12\x04\xd8\x01\"#\n'\n\
+    \x02\x05\x05\x12\x06\xdc\x01\0\xe2\x01\x01\x1a\x19\x20Describes\x20an\
+    \x20enum\x20type.\n\n\x0b\n\x03\x05\x05\x01\x12\x04\xdc\x01\x08\x1b\n\
+    \x0c\n\x04\x05\x05\x02\0\x12\x04\xdd\x01\x02\x1b\n\r\n\x05\x05\x05\x02\0\
+    \x04\x12\x04\xdd\x01\x02\n\n\r\n\x05\x05\x05\x02\0\x05\x12\x04\xdd\x01\
+    \x0b\x11\n\r\n\x05\x05\x05\x02\0\x01\x12\x04\xdd\x01\x12\x16\n\r\n\x05\
+    \x05\x05\x02\0\x03\x12\x04\xdd\x01\x19\x1a\n\x0c\n\x04\x05\x05\x02\x01\
+    \x12\x04\xdf\x01\x02.\n\r\n\x05\x05\x05\x02\x01\x04\x12\x04\xdf\x01\x02\
+    \n\n\r\n\x05\x05\x05\x02\x01\x06\x12\x04\xdf\x01\x0b#\n\r\n\x05\x05\x05\
+    \x02\x01\x01\x12\x04\xdf\x01$)\n\r\n\x05\x05\x05\x02\x01\x03\x12\x04\xdf\
+    \x01,-\n\x0c\n\x04\x05\x05\x02\x02\x12\x04\xe1\x01\x02#\n\r\n\x05\x05\
+    \x05\x02\x02\x04\x12\x04\xe1\x01\x02\n\n\r\n\x05\x05\x05\x02\x02\x06\x12\
+    \x04\xe1\x01\x0b\x16\n\r\n\x05\x05\x05\x02\x02\x01\x12\x04\xe1\x01\x17\
+    \x1e\n\r\n\x05\x05\x05\x02\x02\x03\x12\x04\xe1\x01!\"\n1\n\x02\x05\x06\
+    \x12\x06\xe5\x01\0\xea\x01\x01\x1a#\x20Describes\x20a\x20value\x20within\
+    \x20an\x20enum.\n\n\x0b\n\x03\x05\x06\x01\x12\x04\xe5\x01\x08\x20\n\x0c\
+    \n\x04\x05\x06\x02\0\x12\x04\xe6\x01\x02\x1b\n\r\n\x05\x05\x06\x02\0\x04\
+    \x12\x04\xe6\x01\x02\n\n\r\n\x05\x05\x06\x02\0\x05\x12\x04\xe6\x01\x0b\
+    \x11\n\r\n\x05\x05\x06\x02\0\x01\x12\x04\xe6\x01\x12\x16\n\r\n\x05\x05\
+    \x06\x02\0\x03\x12\x04\xe6\x01\x19\x1a\n\x0c\n\x04\x05\x06\x02\x01\x12\
+    \x04\xe7\x01\x02\x1c\n\r\n\x05\x05\x06\x02\x01\x04\x12\x04\xe7\x01\x02\n\
+    \n\r\n\x05\x05\x06\x02\x01\x05\x12\x04\xe7\x01\x0b\x10\n\r\n\x05\x05\x06\
+    \x02\x01\x01\x12\x04\xe7\x01\x11\x17\n\r\n\x05\x05\x06\x02\x01\x03\x12\
+    \x04\xe7\x01\x1a\x1b\n\x0c\n\x04\x05\x06\x02\x02\x12\x04\xe9\x01\x02(\n\
+    \r\n\x05\x05\x06\x02\x02\x04\x12\x04\xe9\x01\x02\n\n\r\n\x05\x05\x06\x02\
+    \x02\x06\x12\x04\xe9\x01\x0b\x1b\n\r\n\x05\x05\x06\x02\x02\x01\x12\x04\
+    \xe9\x01\x1c#\n\r\n\x05\x05\x06\x02\x02\x03\x12\x04\xe9\x01&'\n$\n\x02\
+    \x05\x07\x12\x06\xed\x01\0\xf2\x01\x01\x1a\x16\x20Describes\x20a\x20serv\
+    ice.\n\n\x0b\n\x03\x05\x07\x01\x12\x04\xed\x01\x08\x1e\n\x0c\n\x04\x05\
+    \x07\x02\0\x12\x04\xee\x01\x02\x1b\n\r\n\x05\x05\x07\x02\0\x04\x12\x04\
+    \xee\x01\x02\n\n\r\n\x05\x05\x07\x02\0\x05\x12\x04\xee\x01\x0b\x11\n\r\n\
+    \x05\x05\x07\x02\0\x01\x12\x04\xee\x01\x12\x16\n\r\n\x05\x05\x07\x02\0\
+    \x03\x12\x04\xee\x01\x19\x1a\n\x0c\n\x04\x05\x07\x02\x01\x12\x04\xef\x01\
+    \x02,\n\r\n\x05\x05\x07\x02\x01\x04\x12\x04\xef\x01\x02\n\n\r\n\x05\x05\
+    \x07\x02\x01\x06\x12\x04\xef\x01\x0b\x20\n\r\n\x05\x05\x07\x02\x01\x01\
+    \x12\x04\xef\x01!'\n\r\n\x05\x05\x07\x02\x01\x03\x12\x04\xef\x01*+\n\x0c\
+    \n\x04\x05\x07\x02\x02\x12\x04\xf1\x01\x02&\n\r\n\x05\x05\x07\x02\x02\
+    \x04\x12\x04\xf1\x01\x02\n\n\r\n\x05\x05\x07\x02\x02\x06\x12\x04\xf1\x01\
+    \x0b\x19\n\r\n\x05\x05\x07\x02\x02\x01\x12\x04\xf1\x01\x1a!\n\r\n\x05\
+    \x05\x07\x02\x02\x03\x12\x04\xf1\x01$%\n0\n\x02\x05\x08\x12\x06\xf5\x01\
+    \0\x83\x02\x01\x1a\"\x20Describes\x20a\x20method\x20of\x20a\x20service.\
+    \n\n\x0b\n\x03\x05\x08\x01\x12\x04\xf5\x01\x08\x1d\n\x0c\n\x04\x05\x08\
+    \x02\0\x12\x04\xf6\x01\x02\x1b\n\r\n\x05\x05\x08\x02\0\x04\x12\x04\xf6\
+    \x01\x02\n\n\r\n\x05\x05\x08\x02\0\x05\x12\x04\xf6\x01\x0b\x11\n\r\n\x05\
+    \x05\x08\x02\0\x01\x12\x04\xf6\x01\x12\x16\n\r\n\x05\x05\x08\x02\0\x03\
+    \x12\x04\xf6\x01\x19\x1a\n\x97\x01\n\x04\x05\x08\x02\x01\x12\x04\xfa\x01\
+    \x02!\x1a\x88\x01\x20Input\x20and\x20output\x20type\x20names.\x20\x20The\
+    se\x20are\x20resolved\x20in\x20the\x20same\x20way\x20as\n\x20FieldDescri\
+    ptorProto.type_name,\x20but\x20must\x20refer\x20to\x20a\x20message\x20ty\
+    pe.\n\n\r\n\x05\x05\x08\x02\x01\x04\x12\x04\xfa\x01\x02\n\n\r\n\x05\x05\
+    \x08\x02\x01\x05\x12\x04\xfa\x01\x0b\x11\n\r\n\x05\x05\x08\x02\x01\x01\
+    \x12\x04\xfa\x01\x12\x1c\n\r\n\x05\x05\x08\x02\x01\x03\x12\x04\xfa\x01\
+    \x1f\x20\n\x0c\n\x04\x05\x08\x02\x02\x12\x04\xfb\x01\x02\"\n\r\n\x05\x05\
+    \x08\x02\x02\x04\x12\x04\xfb\x01\x02\n\n\r\n\x05\x05\x08\x02\x02\x05\x12\
+    \x04\xfb\x01\x0b\x11\n\r\n\x05\x05\x08\x02\x02\x01\x12\x04\xfb\x01\x12\
+    \x1d\n\r\n\x05\x05\x08\x02\x02\x03\x12\x04\xfb\x01\x20!\n\x0c\n\x04\x05\
+    \x08\x02\x03\x12\x04\xfd\x01\x02%\n\r\n\x05\x05\x08\x02\x03\x04\x12\x04\
+    \xfd\x01\x02\n\n\r\n\x05\x05\x08\x02\x03\x06\x12\x04\xfd\x01\x0b\x18\n\r\
+    \n\x05\x05\x08\x02\x03\x01\x12\x04\xfd\x01\x19\x20\n\r\n\x05\x05\x08\x02\
+    \x03\x03\x12\x04\xfd\x01#$\nE\n\x04\x05\x08\x02\x04\x12\x04\x80\x02\x025\
+    \x1a7\x20Identifies\x20if\x20client\x20streams\x20multiple\x20client\x20\
+    messages\n\n\r\n\x05\x05\x08\x02\x04\x04\x12\x04\x80\x02\x02\n\n\r\n\x05\
+    \x05\x08\x02\x04\x05\x12\x04\x80\x02\x0b\x0f\n\r\n\x05\x05\x08\x02\x04\
+    \x01\x12\x04\x80\x02\x10\x20\n\r\n\x05\x05\x08\x02\x04\x03\x12\x04\x80\
+    \x02#$\n\r\n\x05\x05\x08\x02\x04\x08\x12\x04\x80\x02%4\n\r\n\x05\x05\x08\
+    \x02\x04\x07\x12\x04\x80\x02.3\nE\n\x04\x05\x08\x02\x05\x12\x04\x82\x02\
+    \x025\x1a7\x20Identifies\x20if\x20server\x20streams\x20multiple\x20serve\
+    r\x20messages\n\n\r\n\x05\x05\x08\x02\x05\x04\x12\x04\x82\x02\x02\n\n\r\
+    \n\x05\x05\x08\x02\x05\x05\x12\x04\x82\x02\x0b\x0f\n\r\n\x05\x05\x08\x02\
+    \x05\x01\x12\x04\x82\x02\x10\x20\n\r\n\x05\x05\x08\x02\x05\x03\x12\x04\
+    \x82\x02#$\n\r\n\x05\x05\x08\x02\x05\x08\x12\x04\x82\x02%4\n\r\n\x05\x05\
+    \x08\x02\x05\x07\x12\x04\x82\x02.3\n\xaf\x0e\n\x02\x05\t\x12\x06\xa7\x02\
+    \0\x84\x03\x012N\x20====================================================\
+    ===============\n\x20Options\n2\xd0\r\x20Each\x20of\x20the\x20definition\
+    s\x20above\x20may\x20have\x20\"options\"\x20attached.\x20\x20These\x20ar\
+    e\n\x20just\x20annotations\x20which\x20may\x20cause\x20code\x20to\x20be\
+    \x20generated\x20slightly\x20differently\n\x20or\x20may\x20contain\x20hi\
+    nts\x20for\x20code\x20that\x20manipulates\x20protocol\x20messages.\n\n\
+    \x20Clients\x20may\x20define\x20custom\x20options\x20as\x20extensions\
+    \x20of\x20the\x20*Options\x20messages.\n\x20These\x20extensions\x20may\
+    \x20not\x20yet\x20be\x20known\x20at\x20parsing\x20time,\x20so\x20the\x20\
+    parser\x20cannot\n\x20store\x20the\x20values\x20in\x20them.\x20\x20Inste\
+    ad\x20it\x20stores\x20them\x20in\x20a\x20field\x20in\x20the\x20*Options\
+    \n\x20message\x20called\x20uninterpreted_option.\x20This\x20field\x20mus\
+    t\x20have\x20the\x20same\x20name\n\x20across\x20all\x20*Options\x20messa\
+    ges.\x20We\x20then\x20use\x20this\x20field\x20to\x20populate\x20the\n\
+    \x20extensions\x20when\x20we\x20build\x20a\x20descriptor,\x20at\x20which\
+    \x20point\x20all\x20protos\x20have\x20been\n\x20parsed\x20and\x20so\x20a\
+    ll\x20extensions\x20are\x20known.\n\n\x20Extension\x20numbers\x20for\x20\
+    custom\x20options\x20may\x20be\x20chosen\x20as\x20follows:\n\x20*\x20For\
+    \x20options\x20which\x20will\x20only\x20be\x20used\x20within\x20a\x20sin\
+    gle\x20application\x20or\n\x20\x20\x20organization,\x20or\x20for\x20expe\
+    rimental\x20options,\x20use\x20field\x20numbers\x2050000\n\x20\x20\x20th\
+    rough\x2099999.\x20\x20It\x20is\x20up\x20to\x20you\x20to\x20ensure\x20th\
+    at\x20you\x20do\x20not\x20use\x20the\n\x20\x20\x20same\x20number\x20for\
+    \x20multiple\x20options.\n\x20*\x20For\x20options\x20which\x20will\x20be\
+    \x20published\x20and\x20used\x20publicly\x20by\x20multiple\n\x20\x20\x20\
+    independent\x20entities,\x20e-mail\x20protobuf-global-extension-registry\
+    @google.com\n\x20\x20\x20to\x20reserve\x20extension\x20numbers.\x20Simpl\
+    y\x20provide\x20your\x20project\x20name\x20(e.g.\n\x20\x20\x20Objective-\
+    C\x20plugin)\x20and\x20your\x20project\x20website\x20(if\x20available)\
+    \x20--\x20there's\x20no\n\x20\x20\x20need\x20to\x20explain\x20how\x20you\
+    \x20intend\x20to\x20use\x20them.\x20Usually\x20you\x20only\x20need\x20on\
+    e\n\x20\x20\x20extension\x20number.\x20You\x20can\x20declare\x20multiple\
+    \x20options\x20with\x20only\x20one\x20extension\n\x20\x20\x20number\x20b\
+    y\x20putting\x20them\x20in\x20a\x20sub-message.\x20See\x20the\x20Custom\
+    \x20Options\x20section\x20of\n\x20\x20\x20the\x20docs\x20for\x20examples\
+    :\n\x20\x20\x20https://developers.google.com/protocol-buffers/docs/proto\
+    #options\n\x20\x20\x20If\x20this\x20turns\x20out\x20to\x20be\x20popular,\
+    \x20a\x20web\x20service\x20will\x20be\x20set\x20up\n\x20\x20\x20to\x20au\
+    tomatically\x20assign\x20option\x20numbers.\n\n\x0b\n\x03\x05\t\x01\x12\
+    \x04\xa7\x02\x08\x13\n\xf4\x01\n\x04\x05\t\x02\0\x12\x04\xad\x02\x02#\
+    \x1a\xe5\x01\x20Sets\x20the\x20Java\x20package\x20where\x20classes\x20ge\
+    nerated\x20from\x20this\x20.proto\x20will\x20be\n\x20placed.\x20\x20By\
+    \x20default,\x20the\x20proto\x20package\x20is\x20used,\x20but\x20this\
+    \x20is\x20often\n\x20inappropriate\x20because\x20proto\x20packages\x20do\
+    \x20not\x20normally\x20start\x20with\x20backwards\n\x20domain\x20names.\
+    \n\n\r\n\x05\x05\t\x02\0\x04\x12\x04\xad\x02\x02\n\n\r\n\x05\x05\t\x02\0\
+    \x05\x12\x04\xad\x02\x0b\x11\n\r\n\x05\x05\t\x02\0\x01\x12\x04\xad\x02\
+    \x12\x1e\n\r\n\x05\x05\t\x02\0\x03\x12\x04\xad\x02!\"\n\xbf\x02\n\x04\
+    \x05\t\x02\x01\x12\x04\xb5\x02\x02+\x1a\xb0\x02\x20If\x20set,\x20all\x20\
+    the\x20classes\x20from\x20the\x20.proto\x20file\x20are\x20wrapped\x20in\
+    \x20a\x20single\n\x20outer\x20class\x20with\x20the\x20given\x20name.\x20\
+    \x20This\x20applies\x20to\x20both\x20Proto1\n\x20(equivalent\x20to\x20th\
+    e\x20old\x20\"--one_java_file\"\x20option)\x20and\x20Proto2\x20(where\n\
+    \x20a\x20.proto\x20always\x20translates\x20to\x20a\x20single\x20class,\
+    \x20but\x20you\x20may\x20want\x20to\n\x20explicitly\x20choose\x20the\x20\
+    class\x20name).\n\n\r\n\x05\x05\t\x02\x01\x04\x12\x04\xb5\x02\x02\n\n\r\
+    \n\x05\x05\t\x02\x01\x05\x12\x04\xb5\x02\x0b\x11\n\r\n\x05\x05\t\x02\x01\
+    \x01\x12\x04\xb5\x02\x12&\n\r\n\x05\x05\t\x02\x01\x03\x12\x04\xb5\x02)*\
+    \n\xa3\x03\n\x04\x05\t\x02\x02\x12\x04\xbd\x02\x029\x1a\x94\x03\x20If\
+    \x20set\x20true,\x20then\x20the\x20Java\x20code\x20generator\x20will\x20\
+    generate\x20a\x20separate\x20.java\n\x20file\x20for\x20each\x20top-level\
+    \x20message,\x20enum,\x20and\x20service\x20defined\x20in\x20the\x20.prot\
+    o\n\x20file.\x20\x20Thus,\x20these\x20types\x20will\x20*not*\x20be\x20ne\
+    sted\x20inside\x20the\x20outer\x20class\n\x20named\x20by\x20java_outer_c\
+    lassname.\x20\x20However,\x20the\x20outer\x20class\x20will\x20still\x20b\
+    e\n\x20generated\x20to\x20contain\x20the\x20file's\x20getDescriptor()\
+    \x20method\x20as\x20well\x20as\x20any\n\x20top-level\x20extensions\x20de\
+    fined\x20in\x20the\x20file.\n\n\r\n\x05\x05\t\x02\x02\x04\x12\x04\xbd\
+    \x02\x02\n\n\r\n\x05\x05\t\x02\x02\x05\x12\x04\xbd\x02\x0b\x0f\n\r\n\x05\
+    \x05\t\x02\x02\x01\x12\x04\xbd\x02\x10#\n\r\n\x05\x05\t\x02\x02\x03\x12\
+    \x04\xbd\x02&(\n\r\n\x05\x05\t\x02\x02\x08\x12\x04\xbd\x02)8\n\r\n\x05\
+    \x05\t\x02\x02\x07\x12\x04\xbd\x0227\n)\n\x04\x05\t\x02\x03\x12\x04\xc0\
+    \x02\x02E\x1a\x1b\x20This\x20option\x20does\x20nothing.\n\n\r\n\x05\x05\
+    \t\x02\x03\x04\x12\x04\xc0\x02\x02\n\n\r\n\x05\x05\t\x02\x03\x05\x12\x04\
+    \xc0\x02\x0b\x0f\n\r\n\x05\x05\t\x02\x03\x01\x12\x04\xc0\x02\x10-\n\r\n\
+    \x05\x05\t\x02\x03\x03\x12\x04\xc0\x0202\n\r\n\x05\x05\t\x02\x03\x08\x12\
+    \x04\xc0\x023D\n\x10\n\x08\x05\t\x02\x03\x08\xe7\x07\0\x12\x04\xc0\x024C\
+    \n\x11\n\t\x05\t\x02\x03\x08\xe7\x07\0\x02\x12\x04\xc0\x024>\n\x12\n\n\
+    \x05\t\x02\x03\x08\xe7\x07\0\x02\0\x12\x04\xc0\x024>\n\x13\n\x0b\x05\t\
+    \x02\x03\x08\xe7\x07\0\x02\0\x01\x12\x04\xc0\x024>\n\x11\n\t\x05\t\x02\
+    \x03\x08\xe7\x07\0\x03\x12\x04\xc0\x02?C\n\xe6\x02\n\x04\x05\t\x02\x04\
+    \x12\x04\xc8\x02\x02<\x1a\xd7\x02\x20If\x20set\x20true,\x20then\x20the\
+    \x20Java2\x20code\x20generator\x20will\x20generate\x20code\x20that\n\x20\
+    throws\x20an\x20exception\x20whenever\x20an\x20attempt\x20is\x20made\x20\
+    to\x20assign\x20a\x20non-UTF-8\n\x20byte\x20sequence\x20to\x20a\x20strin\
+    g\x20field.\n\x20Message\x20reflection\x20will\x20do\x20the\x20same.\n\
+    \x20However,\x20an\x20extension\x20field\x20still\x20accepts\x20non-UTF-\
+    8\x20byte\x20sequences.\n\x20This\x20option\x20has\x20no\x20effect\x20on\
+    \x20when\x20used\x20with\x20the\x20lite\x20runtime.\n\n\r\n\x05\x05\t\
+    \x02\x04\x04\x12\x04\xc8\x02\x02\n\n\r\n\x05\x05\t\x02\x04\x05\x12\x04\
+    \xc8\x02\x0b\x0f\n\r\n\x05\x05\t\x02\x04\x01\x12\x04\xc8\x02\x10&\n\r\n\
+    \x05\x05\t\x02\x04\x03\x12\x04\xc8\x02)+\n\r\n\x05\x05\t\x02\x04\x08\x12\
+    \x04\xc8\x02,;\n\r\n\x05\x05\t\x02\x04\x07\x12\x04\xc8\x025:\nL\n\x04\
+    \x05\t\x04\0\x12\x06\xcc\x02\x02\xd1\x02\x03\x1a<\x20Generated\x20classe\
+    s\x20can\x20be\x20optimized\x20for\x20speed\x20or\x20code\x20size.\n\n\r\
+    \n\x05\x05\t\x04\0\x01\x12\x04\xcc\x02\x07\x13\nD\n\x06\x05\t\x04\0\x02\
+    \0\x12\x04\xcd\x02\x04\x0e\"4\x20Generate\x20complete\x20code\x20for\x20\
+    parsing,\x20serialization,\n\n\x0f\n\x07\x05\t\x04\0\x02\0\x01\x12\x04\
+    \xcd\x02\x04\t\n\x0f\n\x07\x05\t\x04\0\x02\0\x02\x12\x04\xcd\x02\x0c\r\n\
+    G\n\x06\x05\t\x04\0\x02\x01\x12\x04\xcf\x02\x04\x12\x1a\x06\x20etc.\n\"/\
+    \x20Use\x20ReflectionOps\x20to\x20implement\x20these\x20methods.\n\n\x0f\
+    \n\x07\x05\t\x04\0\x02\x01\x01\x12\x04\xcf\x02\x04\r\n\x0f\n\x07\x05\t\
+    \x04\0\x02\x01\x02\x12\x04\xcf\x02\x10\x11\nG\n\x06\x05\t\x04\0\x02\x02\
+    \x12\x04\xd0\x02\x04\x15\"7\x20Generate\x20code\x20using\x20MessageLite\
+    \x20and\x20the\x20lite\x20runtime.\n\n\x0f\n\x07\x05\t\x04\0\x02\x02\x01\
+    \x12\x04\xd0\x02\x04\x10\n\x0f\n\x07\x05\t\x04\0\x02\x02\x02\x12\x04\xd0\
+    \x02\x13\x14\n\x0c\n\x04\x05\t\x02\x05\x12\x04\xd2\x02\x029\n\r\n\x05\
+    \x05\t\x02\x05\x04\x12\x04\xd2\x02\x02\n\n\r\n\x05\x05\t\x02\x05\x06\x12\
+    \x04\xd2\x02\x0b\x17\n\r\n\x05\x05\t\x02\x05\x01\x12\x04\xd2\x02\x18$\n\
+    \r\n\x05\x05\t\x02\x05\x03\x12\x04\xd2\x02'(\n\r\n\x05\x05\t\x02\x05\x08\
+    \x12\x04\xd2\x02)8\n\r\n\x05\x05\t\x02\x05\x07\x12\x04\xd2\x0227\n\xe2\
+    \x02\n\x04\x05\t\x02\x06\x12\x04\xd9\x02\x02\"\x1a\xd3\x02\x20Sets\x20th\
+    e\x20Go\x20package\x20where\x20structs\x20generated\x20from\x20this\x20.\
+    proto\x20will\x20be\n\x20placed.\x20If\x20omitted,\x20the\x20Go\x20packa\
+    ge\x20will\x20be\x20derived\x20from\x20the\x20following:\n\x20\x20\x20-\
+    \x20The\x20basename\x20of\x20the\x20package\x20import\x20path,\x20if\x20\
+    provided.\n\x20\x20\x20-\x20Otherwise,\x20the\x20package\x20statement\
+    \x20in\x20the\x20.proto\x20file,\x20if\x20present.\n\x20\x20\x20-\x20Oth\
+    erwise,\x20the\x20basename\x20of\x20the\x20.proto\x20file,\x20without\
+    \x20extension.\n\n\r\n\x05\x05\t\x02\x06\x04\x12\x04\xd9\x02\x02\n\n\r\n\
+    \x05\x05\t\x02\x06\x05\x12\x04\xd9\x02\x0b\x11\n\r\n\x05\x05\t\x02\x06\
+    \x01\x12\x04\xd9\x02\x12\x1c\n\r\n\x05\x05\t\x02\x06\x03\x12\x04\xd9\x02\
+    \x1f!\n\xd4\x04\n\x04\x05\t\x02\x07\x12\x04\xe7\x02\x029\x1a\xc5\x04\x20\
+    Should\x20generic\x20services\x20be\x20generated\x20in\x20each\x20langua\
+    ge?\x20\x20\"Generic\"\x20services\n\x20are\x20not\x20specific\x20to\x20\
+    any\x20particular\x20RPC\x20system.\x20\x20They\x20are\x20generated\x20b\
+    y\x20the\n\x20main\x20code\x20generators\x20in\x20each\x20language\x20(w\
+    ithout\x20additional\x20plugins).\n\x20Generic\x20services\x20were\x20th\
+    e\x20only\x20kind\x20of\x20service\x20generation\x20supported\x20by\n\
+    \x20early\x20versions\x20of\x20google.protobuf.\n\n\x20Generic\x20servic\
+    es\x20are\x20now\x20considered\x20deprecated\x20in\x20favor\x20of\x20usi\
+    ng\x20plugins\n\x20that\x20generate\x20code\x20specific\x20to\x20your\
+    \x20particular\x20RPC\x20system.\x20\x20Therefore,\n\x20these\x20default\
+    \x20to\x20false.\x20\x20Old\x20code\x20which\x20depends\x20on\x20generic\
+    \x20services\x20should\n\x20explicitly\x20set\x20them\x20to\x20true.\n\n\
+    \r\n\x05\x05\t\x02\x07\x04\x12\x04\xe7\x02\x02\n\n\r\n\x05\x05\t\x02\x07\
+    \x05\x12\x04\xe7\x02\x0b\x0f\n\r\n\x05\x05\t\x02\x07\x01\x12\x04\xe7\x02\
+    \x10#\n\r\n\x05\x05\t\x02\x07\x03\x12\x04\xe7\x02&(\n\r\n\x05\x05\t\x02\
+    \x07\x08\x12\x04\xe7\x02)8\n\r\n\x05\x05\t\x02\x07\x07\x12\x04\xe7\x0227\
+    \n\x0c\n\x04\x05\t\x02\x08\x12\x04\xe8\x02\x02;\n\r\n\x05\x05\t\x02\x08\
+    \x04\x12\x04\xe8\x02\x02\n\n\r\n\x05\x05\t\x02\x08\x05\x12\x04\xe8\x02\
+    \x0b\x0f\n\r\n\x05\x05\t\x02\x08\x01\x12\x04\xe8\x02\x10%\n\r\n\x05\x05\
+    \t\x02\x08\x03\x12\x04\xe8\x02(*\n\r\n\x05\x05\t\x02\x08\x08\x12\x04\xe8\
+    \x02+:\n\r\n\x05\x05\t\x02\x08\x07\x12\x04\xe8\x0249\n\x0c\n\x04\x05\t\
+    \x02\t\x12\x04\xe9\x02\x029\n\r\n\x05\x05\t\x02\t\x04\x12\x04\xe9\x02\
+    \x02\n\n\r\n\x05\x05\t\x02\t\x05\x12\x04\xe9\x02\x0b\x0f\n\r\n\x05\x05\t\
+    \x02\t\x01\x12\x04\xe9\x02\x10#\n\r\n\x05\x05\t\x02\t\x03\x12\x04\xe9\
+    \x02&(\n\r\n\x05\x05\t\x02\t\x08\x12\x04\xe9\x02)8\n\r\n\x05\x05\t\x02\t\
+    \x07\x12\x04\xe9\x0227\n\xf3\x01\n\x04\x05\t\x02\n\x12\x04\xef\x02\x020\
+    \x1a\xe4\x01\x20Is\x20this\x20file\x20deprecated?\n\x20Depending\x20on\
     \x20the\x20target\x20platform,\x20this\x20can\x20emit\x20Deprecated\x20a\
-    nnotations\n\x20for\x20accessors,\x20or\x20it\x20will\x20be\x20completel\
-    y\x20ignored;\x20in\x20the\x20very\x20least,\x20this\n\x20is\x20a\x20for\
-    malization\x20for\x20deprecating\x20fields.\n\n\r\n\x05\x04\x0b\x02\x04\
-    \x04\x12\x04\x86\x04\x02\n\n\r\n\x05\x04\x0b\x02\x04\x05\x12\x04\x86\x04\
-    \x0b\x0f\n\r\n\x05\x04\x0b\x02\x04\x01\x12\x04\x86\x04\x10\x1a\n\r\n\x05\
-    \x04\x0b\x02\x04\x03\x12\x04\x86\x04\x1d\x1e\n\r\n\x05\x04\x0b\x02\x04\
-    \x08\x12\x04\x86\x04\x1f.\n\r\n\x05\x04\x0b\x02\x04\x07\x12\x04\x86\x04(\
-    -\n?\n\x04\x04\x0b\x02\x05\x12\x04\x89\x04\x02*\x1a1\x20For\x20Google-in\
-    ternal\x20migration\x20only.\x20Do\x20not\x20use.\n\n\r\n\x05\x04\x0b\
-    \x02\x05\x04\x12\x04\x89\x04\x02\n\n\r\n\x05\x04\x0b\x02\x05\x05\x12\x04\
-    \x89\x04\x0b\x0f\n\r\n\x05\x04\x0b\x02\x05\x01\x12\x04\x89\x04\x10\x14\n\
-    \r\n\x05\x04\x0b\x02\x05\x03\x12\x04\x89\x04\x17\x19\n\r\n\x05\x04\x0b\
-    \x02\x05\x08\x12\x04\x89\x04\x1a)\n\r\n\x05\x04\x0b\x02\x05\x07\x12\x04\
-    \x89\x04#(\nO\n\x04\x04\x0b\x02\x06\x12\x04\x8d\x04\x02:\x1aA\x20The\x20\
-    parser\x20stores\x20options\x20it\x20doesn't\x20recognize\x20here.\x20Se\
-    e\x20above.\n\n\r\n\x05\x04\x0b\x02\x06\x04\x12\x04\x8d\x04\x02\n\n\r\n\
-    \x05\x04\x0b\x02\x06\x06\x12\x04\x8d\x04\x0b\x1e\n\r\n\x05\x04\x0b\x02\
-    \x06\x01\x12\x04\x8d\x04\x1f3\n\r\n\x05\x04\x0b\x02\x06\x03\x12\x04\x8d\
-    \x0469\nZ\n\x03\x04\x0b\x05\x12\x04\x90\x04\x02\x19\x1aM\x20Clients\x20c\
-    an\x20define\x20custom\x20options\x20in\x20extensions\x20of\x20this\x20m\
-    essage.\x20See\x20above.\n\n\x0c\n\x04\x04\x0b\x05\0\x12\x04\x90\x04\r\
-    \x18\n\r\n\x05\x04\x0b\x05\0\x01\x12\x04\x90\x04\r\x11\n\r\n\x05\x04\x0b\
-    \x05\0\x02\x12\x04\x90\x04\x15\x18\n\x0c\n\x02\x04\x0c\x12\x06\x95\x04\0\
-    \x9b\x04\x01\n\x0b\n\x03\x04\x0c\x01\x12\x04\x95\x04\x08\x14\nO\n\x04\
-    \x04\x0c\x02\0\x12\x04\x97\x04\x02:\x1aA\x20The\x20parser\x20stores\x20o\
-    ptions\x20it\x20doesn't\x20recognize\x20here.\x20See\x20above.\n\n\r\n\
-    \x05\x04\x0c\x02\0\x04\x12\x04\x97\x04\x02\n\n\r\n\x05\x04\x0c\x02\0\x06\
-    \x12\x04\x97\x04\x0b\x1e\n\r\n\x05\x04\x0c\x02\0\x01\x12\x04\x97\x04\x1f\
-    3\n\r\n\x05\x04\x0c\x02\0\x03\x12\x04\x97\x0469\nZ\n\x03\x04\x0c\x05\x12\
-    \x04\x9a\x04\x02\x19\x1aM\x20Clients\x20can\x20define\x20custom\x20optio\
-    ns\x20in\x20extensions\x20of\x20this\x20message.\x20See\x20above.\n\n\
-    \x0c\n\x04\x04\x0c\x05\0\x12\x04\x9a\x04\r\x18\n\r\n\x05\x04\x0c\x05\0\
-    \x01\x12\x04\x9a\x04\r\x11\n\r\n\x05\x04\x0c\x05\0\x02\x12\x04\x9a\x04\
-    \x15\x18\n\x0c\n\x02\x04\r\x12\x06\x9d\x04\0\xae\x04\x01\n\x0b\n\x03\x04\
-    \r\x01\x12\x04\x9d\x04\x08\x13\n`\n\x04\x04\r\x02\0\x12\x04\xa1\x04\x02\
-    \x20\x1aR\x20Set\x20this\x20option\x20to\x20true\x20to\x20allow\x20mappi\
-    ng\x20different\x20tag\x20names\x20to\x20the\x20same\n\x20value.\n\n\r\n\
-    \x05\x04\r\x02\0\x04\x12\x04\xa1\x04\x02\n\n\r\n\x05\x04\r\x02\0\x05\x12\
-    \x04\xa1\x04\x0b\x0f\n\r\n\x05\x04\r\x02\0\x01\x12\x04\xa1\x04\x10\x1b\n\
-    \r\n\x05\x04\r\x02\0\x03\x12\x04\xa1\x04\x1e\x1f\n\xe5\x01\n\x04\x04\r\
-    \x02\x01\x12\x04\xa7\x04\x02/\x1a\xd6\x01\x20Is\x20this\x20enum\x20depre\
-    cated?\n\x20Depending\x20on\x20the\x20target\x20platform,\x20this\x20can\
-    \x20emit\x20Deprecated\x20annotations\n\x20for\x20the\x20enum,\x20or\x20\
-    it\x20will\x20be\x20completely\x20ignored;\x20in\x20the\x20very\x20least\
-    ,\x20this\n\x20is\x20a\x20formalization\x20for\x20deprecating\x20enums.\
-    \n\n\r\n\x05\x04\r\x02\x01\x04\x12\x04\xa7\x04\x02\n\n\r\n\x05\x04\r\x02\
-    \x01\x05\x12\x04\xa7\x04\x0b\x0f\n\r\n\x05\x04\r\x02\x01\x01\x12\x04\xa7\
-    \x04\x10\x1a\n\r\n\x05\x04\r\x02\x01\x03\x12\x04\xa7\x04\x1d\x1e\n\r\n\
-    \x05\x04\r\x02\x01\x08\x12\x04\xa7\x04\x1f.\n\r\n\x05\x04\r\x02\x01\x07\
-    \x12\x04\xa7\x04(-\nO\n\x04\x04\r\x02\x02\x12\x04\xaa\x04\x02:\x1aA\x20T\
+    nnotations\n\x20for\x20everything\x20in\x20the\x20file,\x20or\x20it\x20w\
+    ill\x20be\x20completely\x20ignored;\x20in\x20the\x20very\n\x20least,\x20\
+    this\x20is\x20a\x20formalization\x20for\x20deprecating\x20files.\n\n\r\n\
+    \x05\x05\t\x02\n\x04\x12\x04\xef\x02\x02\n\n\r\n\x05\x05\t\x02\n\x05\x12\
+    \x04\xef\x02\x0b\x0f\n\r\n\x05\x05\t\x02\n\x01\x12\x04\xef\x02\x10\x1a\n\
+    \r\n\x05\x05\t\x02\n\x03\x12\x04\xef\x02\x1d\x1f\n\r\n\x05\x05\t\x02\n\
+    \x08\x12\x04\xef\x02\x20/\n\r\n\x05\x05\t\x02\n\x07\x12\x04\xef\x02).\n\
+    \x7f\n\x04\x05\t\x02\x0b\x12\x04\xf3\x02\x026\x1aq\x20Enables\x20the\x20\
+    use\x20of\x20arenas\x20for\x20the\x20proto\x20messages\x20in\x20this\x20\
+    file.\x20This\x20applies\n\x20only\x20to\x20generated\x20classes\x20for\
+    \x20C++.\n\n\r\n\x05\x05\t\x02\x0b\x04\x12\x04\xf3\x02\x02\n\n\r\n\x05\
+    \x05\t\x02\x0b\x05\x12\x04\xf3\x02\x0b\x0f\n\r\n\x05\x05\t\x02\x0b\x01\
+    \x12\x04\xf3\x02\x10\x20\n\r\n\x05\x05\t\x02\x0b\x03\x12\x04\xf3\x02#%\n\
+    \r\n\x05\x05\t\x02\x0b\x08\x12\x04\xf3\x02&5\n\r\n\x05\x05\t\x02\x0b\x07\
+    \x12\x04\xf3\x02/4\n\x92\x01\n\x04\x05\t\x02\x0c\x12\x04\xf8\x02\x02)\
+    \x1a\x83\x01\x20Sets\x20the\x20objective\x20c\x20class\x20prefix\x20whic\
+    h\x20is\x20prepended\x20to\x20all\x20objective\x20c\n\x20generated\x20cl\
+    asses\x20from\x20this\x20.proto.\x20There\x20is\x20no\x20default.\n\n\r\
+    \n\x05\x05\t\x02\x0c\x04\x12\x04\xf8\x02\x02\n\n\r\n\x05\x05\t\x02\x0c\
+    \x05\x12\x04\xf8\x02\x0b\x11\n\r\n\x05\x05\t\x02\x0c\x01\x12\x04\xf8\x02\
+    \x12#\n\r\n\x05\x05\t\x02\x0c\x03\x12\x04\xf8\x02&(\nI\n\x04\x05\t\x02\r\
+    \x12\x04\xfb\x02\x02(\x1a;\x20Namespace\x20for\x20generated\x20classes;\
+    \x20defaults\x20to\x20the\x20package.\n\n\r\n\x05\x05\t\x02\r\x04\x12\
+    \x04\xfb\x02\x02\n\n\r\n\x05\x05\t\x02\r\x05\x12\x04\xfb\x02\x0b\x11\n\r\
+    \n\x05\x05\t\x02\r\x01\x12\x04\xfb\x02\x12\"\n\r\n\x05\x05\t\x02\r\x03\
+    \x12\x04\xfb\x02%'\nO\n\x04\x05\t\x02\x0e\x12\x04\xfe\x02\x02:\x1aA\x20T\
     he\x20parser\x20stores\x20options\x20it\x20doesn't\x20recognize\x20here.\
-    \x20See\x20above.\n\n\r\n\x05\x04\r\x02\x02\x04\x12\x04\xaa\x04\x02\n\n\
-    \r\n\x05\x04\r\x02\x02\x06\x12\x04\xaa\x04\x0b\x1e\n\r\n\x05\x04\r\x02\
-    \x02\x01\x12\x04\xaa\x04\x1f3\n\r\n\x05\x04\r\x02\x02\x03\x12\x04\xaa\
-    \x0469\nZ\n\x03\x04\r\x05\x12\x04\xad\x04\x02\x19\x1aM\x20Clients\x20can\
+    \x20See\x20above.\n\n\r\n\x05\x05\t\x02\x0e\x04\x12\x04\xfe\x02\x02\n\n\
+    \r\n\x05\x05\t\x02\x0e\x06\x12\x04\xfe\x02\x0b\x1e\n\r\n\x05\x05\t\x02\
+    \x0e\x01\x12\x04\xfe\x02\x1f3\n\r\n\x05\x05\t\x02\x0e\x03\x12\x04\xfe\
+    \x0269\nZ\n\x03\x05\t\x05\x12\x04\x81\x03\x02\x19\x1aM\x20Clients\x20can\
     \x20define\x20custom\x20options\x20in\x20extensions\x20of\x20this\x20mes\
-    sage.\x20See\x20above.\n\n\x0c\n\x04\x04\r\x05\0\x12\x04\xad\x04\r\x18\n\
-    \r\n\x05\x04\r\x05\0\x01\x12\x04\xad\x04\r\x11\n\r\n\x05\x04\r\x05\0\x02\
-    \x12\x04\xad\x04\x15\x18\n\x0c\n\x02\x04\x0e\x12\x06\xb0\x04\0\xbc\x04\
-    \x01\n\x0b\n\x03\x04\x0e\x01\x12\x04\xb0\x04\x08\x18\n\xf7\x01\n\x04\x04\
-    \x0e\x02\0\x12\x04\xb5\x04\x02/\x1a\xe8\x01\x20Is\x20this\x20enum\x20val\
-    ue\x20deprecated?\n\x20Depending\x20on\x20the\x20target\x20platform,\x20\
-    this\x20can\x20emit\x20Deprecated\x20annotations\n\x20for\x20the\x20enum\
-    \x20value,\x20or\x20it\x20will\x20be\x20completely\x20ignored;\x20in\x20\
-    the\x20very\x20least,\n\x20this\x20is\x20a\x20formalization\x20for\x20de\
-    precating\x20enum\x20values.\n\n\r\n\x05\x04\x0e\x02\0\x04\x12\x04\xb5\
-    \x04\x02\n\n\r\n\x05\x04\x0e\x02\0\x05\x12\x04\xb5\x04\x0b\x0f\n\r\n\x05\
-    \x04\x0e\x02\0\x01\x12\x04\xb5\x04\x10\x1a\n\r\n\x05\x04\x0e\x02\0\x03\
-    \x12\x04\xb5\x04\x1d\x1e\n\r\n\x05\x04\x0e\x02\0\x08\x12\x04\xb5\x04\x1f\
-    .\n\r\n\x05\x04\x0e\x02\0\x07\x12\x04\xb5\x04(-\nO\n\x04\x04\x0e\x02\x01\
-    \x12\x04\xb8\x04\x02:\x1aA\x20The\x20parser\x20stores\x20options\x20it\
-    \x20doesn't\x20recognize\x20here.\x20See\x20above.\n\n\r\n\x05\x04\x0e\
-    \x02\x01\x04\x12\x04\xb8\x04\x02\n\n\r\n\x05\x04\x0e\x02\x01\x06\x12\x04\
-    \xb8\x04\x0b\x1e\n\r\n\x05\x04\x0e\x02\x01\x01\x12\x04\xb8\x04\x1f3\n\r\
-    \n\x05\x04\x0e\x02\x01\x03\x12\x04\xb8\x0469\nZ\n\x03\x04\x0e\x05\x12\
-    \x04\xbb\x04\x02\x19\x1aM\x20Clients\x20can\x20define\x20custom\x20optio\
-    ns\x20in\x20extensions\x20of\x20this\x20message.\x20See\x20above.\n\n\
-    \x0c\n\x04\x04\x0e\x05\0\x12\x04\xbb\x04\r\x18\n\r\n\x05\x04\x0e\x05\0\
-    \x01\x12\x04\xbb\x04\r\x11\n\r\n\x05\x04\x0e\x05\0\x02\x12\x04\xbb\x04\
-    \x15\x18\n\x0c\n\x02\x04\x0f\x12\x06\xbe\x04\0\xd0\x04\x01\n\x0b\n\x03\
-    \x04\x0f\x01\x12\x04\xbe\x04\x08\x16\n\xd9\x03\n\x04\x04\x0f\x02\0\x12\
-    \x04\xc9\x04\x020\x1a\xdf\x01\x20Is\x20this\x20service\x20deprecated?\n\
-    \x20Depending\x20on\x20the\x20target\x20platform,\x20this\x20can\x20emit\
-    \x20Deprecated\x20annotations\n\x20for\x20the\x20service,\x20or\x20it\
-    \x20will\x20be\x20completely\x20ignored;\x20in\x20the\x20very\x20least,\
-    \n\x20this\x20is\x20a\x20formalization\x20for\x20deprecating\x20services\
-    .\n2\xe8\x01\x20Note:\x20\x20Field\x20numbers\x201\x20through\x2032\x20a\
-    re\x20reserved\x20for\x20Google's\x20internal\x20RPC\n\x20\x20\x20framew\
-    ork.\x20\x20We\x20apologize\x20for\x20hoarding\x20these\x20numbers\x20to\
-    \x20ourselves,\x20but\n\x20\x20\x20we\x20were\x20already\x20using\x20the\
-    m\x20long\x20before\x20we\x20decided\x20to\x20release\x20Protocol\n\x20\
-    \x20\x20Buffers.\n\n\r\n\x05\x04\x0f\x02\0\x04\x12\x04\xc9\x04\x02\n\n\r\
-    \n\x05\x04\x0f\x02\0\x05\x12\x04\xc9\x04\x0b\x0f\n\r\n\x05\x04\x0f\x02\0\
-    \x01\x12\x04\xc9\x04\x10\x1a\n\r\n\x05\x04\x0f\x02\0\x03\x12\x04\xc9\x04\
-    \x1d\x1f\n\r\n\x05\x04\x0f\x02\0\x08\x12\x04\xc9\x04\x20/\n\r\n\x05\x04\
-    \x0f\x02\0\x07\x12\x04\xc9\x04).\nO\n\x04\x04\x0f\x02\x01\x12\x04\xcc\
+    sage.\x20See\x20above.\n\n\x0c\n\x04\x05\t\x05\0\x12\x04\x81\x03\r\x18\n\
+    \r\n\x05\x05\t\x05\0\x01\x12\x04\x81\x03\r\x11\n\r\n\x05\x05\t\x05\0\x02\
+    \x12\x04\x81\x03\x15\x18\n\x0c\n\x02\x05\n\x12\x06\x86\x03\0\xc4\x03\x01\
+    \n\x0b\n\x03\x05\n\x01\x12\x04\x86\x03\x08\x16\n\xd8\x05\n\x04\x05\n\x02\
+    \0\x12\x04\x99\x03\x02<\x1a\xc9\x05\x20Set\x20true\x20to\x20use\x20the\
+    \x20old\x20proto1\x20MessageSet\x20wire\x20format\x20for\x20extensions.\
+    \n\x20This\x20is\x20provided\x20for\x20backwards-compatibility\x20with\
+    \x20the\x20MessageSet\x20wire\n\x20format.\x20\x20You\x20should\x20not\
+    \x20use\x20this\x20for\x20any\x20other\x20reason:\x20\x20It's\x20less\n\
+    \x20efficient,\x20has\x20fewer\x20features,\x20and\x20is\x20more\x20comp\
+    licated.\n\n\x20The\x20message\x20must\x20be\x20defined\x20exactly\x20as\
+    \x20follows:\n\x20\x20\x20message\x20Foo\x20{\n\x20\x20\x20\x20\x20optio\
+    n\x20message_set_wire_format\x20=\x20true;\n\x20\x20\x20\x20\x20extensio\
+    ns\x204\x20to\x20max;\n\x20\x20\x20}\n\x20Note\x20that\x20the\x20message\
+    \x20cannot\x20have\x20any\x20defined\x20fields;\x20MessageSets\x20only\n\
+    \x20have\x20extensions.\n\n\x20All\x20extensions\x20of\x20your\x20type\
+    \x20must\x20be\x20singular\x20messages;\x20e.g.\x20they\x20cannot\n\x20b\
+    e\x20int32s,\x20enums,\x20or\x20repeated\x20messages.\n\n\x20Because\x20\
+    this\x20is\x20an\x20option,\x20the\x20above\x20two\x20restrictions\x20ar\
+    e\x20not\x20enforced\x20by\n\x20the\x20protocol\x20compiler.\n\n\r\n\x05\
+    \x05\n\x02\0\x04\x12\x04\x99\x03\x02\n\n\r\n\x05\x05\n\x02\0\x05\x12\x04\
+    \x99\x03\x0b\x0f\n\r\n\x05\x05\n\x02\0\x01\x12\x04\x99\x03\x10'\n\r\n\
+    \x05\x05\n\x02\0\x03\x12\x04\x99\x03*+\n\r\n\x05\x05\n\x02\0\x08\x12\x04\
+    \x99\x03,;\n\r\n\x05\x05\n\x02\0\x07\x12\x04\x99\x035:\n\xeb\x01\n\x04\
+    \x05\n\x02\x01\x12\x04\x9e\x03\x02D\x1a\xdc\x01\x20Disables\x20the\x20ge\
+    neration\x20of\x20the\x20standard\x20\"descriptor()\"\x20accessor,\x20wh\
+    ich\x20can\n\x20conflict\x20with\x20a\x20field\x20of\x20the\x20same\x20n\
+    ame.\x20\x20This\x20is\x20meant\x20to\x20make\x20migration\n\x20from\x20\
+    proto1\x20easier;\x20new\x20code\x20should\x20avoid\x20fields\x20named\
+    \x20\"descriptor\".\n\n\r\n\x05\x05\n\x02\x01\x04\x12\x04\x9e\x03\x02\n\
+    \n\r\n\x05\x05\n\x02\x01\x05\x12\x04\x9e\x03\x0b\x0f\n\r\n\x05\x05\n\x02\
+    \x01\x01\x12\x04\x9e\x03\x10/\n\r\n\x05\x05\n\x02\x01\x03\x12\x04\x9e\
+    \x0323\n\r\n\x05\x05\n\x02\x01\x08\x12\x04\x9e\x034C\n\r\n\x05\x05\n\x02\
+    \x01\x07\x12\x04\x9e\x03=B\n\xee\x01\n\x04\x05\n\x02\x02\x12\x04\xa4\x03\
+    \x02/\x1a\xdf\x01\x20Is\x20this\x20message\x20deprecated?\n\x20Depending\
+    \x20on\x20the\x20target\x20platform,\x20this\x20can\x20emit\x20Deprecate\
+    d\x20annotations\n\x20for\x20the\x20message,\x20or\x20it\x20will\x20be\
+    \x20completely\x20ignored;\x20in\x20the\x20very\x20least,\n\x20this\x20i\
+    s\x20a\x20formalization\x20for\x20deprecating\x20messages.\n\n\r\n\x05\
+    \x05\n\x02\x02\x04\x12\x04\xa4\x03\x02\n\n\r\n\x05\x05\n\x02\x02\x05\x12\
+    \x04\xa4\x03\x0b\x0f\n\r\n\x05\x05\n\x02\x02\x01\x12\x04\xa4\x03\x10\x1a\
+    \n\r\n\x05\x05\n\x02\x02\x03\x12\x04\xa4\x03\x1d\x1e\n\r\n\x05\x05\n\x02\
+    \x02\x08\x12\x04\xa4\x03\x1f.\n\r\n\x05\x05\n\x02\x02\x07\x12\x04\xa4\
+    \x03(-\n\x9e\x06\n\x04\x05\n\x02\x03\x12\x04\xbb\x03\x02\x1e\x1a\x8f\x06\
+    \x20Whether\x20the\x20message\x20is\x20an\x20automatically\x20generated\
+    \x20map\x20entry\x20type\x20for\x20the\n\x20maps\x20field.\n\n\x20For\
+    \x20maps\x20fields:\n\x20\x20\x20\x20\x20map<KeyType,\x20ValueType>\x20m\
+    ap_field\x20=\x201;\n\x20The\x20parsed\x20descriptor\x20looks\x20like:\n\
+    \x20\x20\x20\x20\x20message\x20MapFieldEntry\x20{\n\x20\x20\x20\x20\x20\
+    \x20\x20\x20\x20option\x20map_entry\x20=\x20true;\n\x20\x20\x20\x20\x20\
+    \x20\x20\x20\x20optional\x20KeyType\x20key\x20=\x201;\n\x20\x20\x20\x20\
+    \x20\x20\x20\x20\x20optional\x20ValueType\x20value\x20=\x202;\n\x20\x20\
+    \x20\x20\x20}\n\x20\x20\x20\x20\x20repeated\x20MapFieldEntry\x20map_fiel\
+    d\x20=\x201;\n\n\x20Implementations\x20may\x20choose\x20not\x20to\x20gen\
+    erate\x20the\x20map_entry=true\x20message,\x20but\n\x20use\x20a\x20nativ\
+    e\x20map\x20in\x20the\x20target\x20language\x20to\x20hold\x20the\x20keys\
+    \x20and\x20values.\n\x20The\x20reflection\x20APIs\x20in\x20such\x20imple\
+    mentions\x20still\x20need\x20to\x20work\x20as\n\x20if\x20the\x20field\
+    \x20is\x20a\x20repeated\x20message\x20field.\n\n\x20NOTE:\x20Do\x20not\
+    \x20set\x20the\x20option\x20in\x20.proto\x20files.\x20Always\x20use\x20t\
+    he\x20maps\x20syntax\n\x20instead.\x20The\x20option\x20should\x20only\
+    \x20be\x20implicitly\x20set\x20by\x20the\x20proto\x20compiler\n\x20parse\
+    r.\n\n\r\n\x05\x05\n\x02\x03\x04\x12\x04\xbb\x03\x02\n\n\r\n\x05\x05\n\
+    \x02\x03\x05\x12\x04\xbb\x03\x0b\x0f\n\r\n\x05\x05\n\x02\x03\x01\x12\x04\
+    \xbb\x03\x10\x19\n\r\n\x05\x05\n\x02\x03\x03\x12\x04\xbb\x03\x1c\x1d\nO\
+    \n\x04\x05\n\x02\x04\x12\x04\xbe\x03\x02:\x1aA\x20The\x20parser\x20store\
+    s\x20options\x20it\x20doesn't\x20recognize\x20here.\x20See\x20above.\n\n\
+    \r\n\x05\x05\n\x02\x04\x04\x12\x04\xbe\x03\x02\n\n\r\n\x05\x05\n\x02\x04\
+    \x06\x12\x04\xbe\x03\x0b\x1e\n\r\n\x05\x05\n\x02\x04\x01\x12\x04\xbe\x03\
+    \x1f3\n\r\n\x05\x05\n\x02\x04\x03\x12\x04\xbe\x0369\nZ\n\x03\x05\n\x05\
+    \x12\x04\xc1\x03\x02\x19\x1aM\x20Clients\x20can\x20define\x20custom\x20o\
+    ptions\x20in\x20extensions\x20of\x20this\x20message.\x20See\x20above.\n\
+    \n\x0c\n\x04\x05\n\x05\0\x12\x04\xc1\x03\r\x18\n\r\n\x05\x05\n\x05\0\x01\
+    \x12\x04\xc1\x03\r\x11\n\r\n\x05\x05\n\x05\0\x02\x12\x04\xc1\x03\x15\x18\
+    \n\x0c\n\x02\x05\x0b\x12\x06\xc6\x03\0\x9f\x04\x01\n\x0b\n\x03\x05\x0b\
+    \x01\x12\x04\xc6\x03\x08\x14\n\xa3\x02\n\x04\x05\x0b\x02\0\x12\x04\xcb\
+    \x03\x02.\x1a\x94\x02\x20The\x20ctype\x20option\x20instructs\x20the\x20C\
+    ++\x20code\x20generator\x20to\x20use\x20a\x20different\n\x20representati\
+    on\x20of\x20the\x20field\x20than\x20it\x20normally\x20would.\x20\x20See\
+    \x20the\x20specific\n\x20options\x20below.\x20\x20This\x20option\x20is\
+    \x20not\x20yet\x20implemented\x20in\x20the\x20open\x20source\n\x20releas\
+    e\x20--\x20sorry,\x20we'll\x20try\x20to\x20include\x20it\x20in\x20a\x20f\
+    uture\x20version!\n\n\r\n\x05\x05\x0b\x02\0\x04\x12\x04\xcb\x03\x02\n\n\
+    \r\n\x05\x05\x0b\x02\0\x06\x12\x04\xcb\x03\x0b\x10\n\r\n\x05\x05\x0b\x02\
+    \0\x01\x12\x04\xcb\x03\x11\x16\n\r\n\x05\x05\x0b\x02\0\x03\x12\x04\xcb\
+    \x03\x19\x1a\n\r\n\x05\x05\x0b\x02\0\x08\x12\x04\xcb\x03\x1b-\n\r\n\x05\
+    \x05\x0b\x02\0\x07\x12\x04\xcb\x03&,\n\x0e\n\x04\x05\x0b\x04\0\x12\x06\
+    \xcc\x03\x02\xd3\x03\x03\n\r\n\x05\x05\x0b\x04\0\x01\x12\x04\xcc\x03\x07\
+    \x0c\n\x1f\n\x06\x05\x0b\x04\0\x02\0\x12\x04\xce\x03\x04\x0f\x1a\x0f\x20\
+    Default\x20mode.\n\n\x0f\n\x07\x05\x0b\x04\0\x02\0\x01\x12\x04\xce\x03\
+    \x04\n\n\x0f\n\x07\x05\x0b\x04\0\x02\0\x02\x12\x04\xce\x03\r\x0e\n\x0e\n\
+    \x06\x05\x0b\x04\0\x02\x01\x12\x04\xd0\x03\x04\r\n\x0f\n\x07\x05\x0b\x04\
+    \0\x02\x01\x01\x12\x04\xd0\x03\x04\x08\n\x0f\n\x07\x05\x0b\x04\0\x02\x01\
+    \x02\x12\x04\xd0\x03\x0b\x0c\n\x0e\n\x06\x05\x0b\x04\0\x02\x02\x12\x04\
+    \xd2\x03\x04\x15\n\x0f\n\x07\x05\x0b\x04\0\x02\x02\x01\x12\x04\xd2\x03\
+    \x04\x10\n\x0f\n\x07\x05\x0b\x04\0\x02\x02\x02\x12\x04\xd2\x03\x13\x14\n\
+    \xda\x02\n\x04\x05\x0b\x02\x01\x12\x04\xd9\x03\x02\x1b\x1a\xcb\x02\x20Th\
+    e\x20packed\x20option\x20can\x20be\x20enabled\x20for\x20repeated\x20prim\
+    itive\x20fields\x20to\x20enable\n\x20a\x20more\x20efficient\x20represent\
+    ation\x20on\x20the\x20wire.\x20Rather\x20than\x20repeatedly\n\x20writing\
+    \x20the\x20tag\x20and\x20type\x20for\x20each\x20element,\x20the\x20entir\
+    e\x20array\x20is\x20encoded\x20as\n\x20a\x20single\x20length-delimited\
+    \x20blob.\x20In\x20proto3,\x20only\x20explicit\x20setting\x20it\x20to\n\
+    \x20false\x20will\x20avoid\x20using\x20packed\x20encoding.\n\n\r\n\x05\
+    \x05\x0b\x02\x01\x04\x12\x04\xd9\x03\x02\n\n\r\n\x05\x05\x0b\x02\x01\x05\
+    \x12\x04\xd9\x03\x0b\x0f\n\r\n\x05\x05\x0b\x02\x01\x01\x12\x04\xd9\x03\
+    \x10\x16\n\r\n\x05\x05\x0b\x02\x01\x03\x12\x04\xd9\x03\x19\x1a\n\xe4\x04\
+    \n\x04\x05\x0b\x02\x02\x12\x04\xe4\x03\x023\x1a\xd5\x04\x20The\x20jstype\
+    \x20option\x20determines\x20the\x20JavaScript\x20type\x20used\x20for\x20\
+    values\x20of\x20the\n\x20field.\x20\x20The\x20option\x20is\x20permitted\
+    \x20only\x20for\x2064\x20bit\x20integral\x20and\x20fixed\x20types\n\x20(\
+    int64,\x20uint64,\x20sint64,\x20fixed64,\x20sfixed64).\x20\x20By\x20defa\
+    ult\x20these\x20types\x20are\n\x20represented\x20as\x20JavaScript\x20str\
+    ings.\x20\x20This\x20avoids\x20loss\x20of\x20precision\x20that\x20can\n\
+    \x20happen\x20when\x20a\x20large\x20value\x20is\x20converted\x20to\x20a\
+    \x20floating\x20point\x20JavaScript\n\x20numbers.\x20\x20Specifying\x20J\
+    S_NUMBER\x20for\x20the\x20jstype\x20causes\x20the\x20generated\n\x20Java\
+    Script\x20code\x20to\x20use\x20the\x20JavaScript\x20\"number\"\x20type\
+    \x20instead\x20of\x20strings.\n\x20This\x20option\x20is\x20an\x20enum\
+    \x20to\x20permit\x20additional\x20types\x20to\x20be\x20added,\n\x20e.g.\
+    \x20goog.math.Integer.\n\n\r\n\x05\x05\x0b\x02\x02\x04\x12\x04\xe4\x03\
+    \x02\n\n\r\n\x05\x05\x0b\x02\x02\x06\x12\x04\xe4\x03\x0b\x11\n\r\n\x05\
+    \x05\x0b\x02\x02\x01\x12\x04\xe4\x03\x12\x18\n\r\n\x05\x05\x0b\x02\x02\
+    \x03\x12\x04\xe4\x03\x1b\x1c\n\r\n\x05\x05\x0b\x02\x02\x08\x12\x04\xe4\
+    \x03\x1d2\n\r\n\x05\x05\x0b\x02\x02\x07\x12\x04\xe4\x03(1\n\x0e\n\x04\
+    \x05\x0b\x04\x01\x12\x06\xe5\x03\x02\xee\x03\x03\n\r\n\x05\x05\x0b\x04\
+    \x01\x01\x12\x04\xe5\x03\x07\r\n'\n\x06\x05\x0b\x04\x01\x02\0\x12\x04\
+    \xe7\x03\x04\x12\x1a\x17\x20Use\x20the\x20default\x20type.\n\n\x0f\n\x07\
+    \x05\x0b\x04\x01\x02\0\x01\x12\x04\xe7\x03\x04\r\n\x0f\n\x07\x05\x0b\x04\
+    \x01\x02\0\x02\x12\x04\xe7\x03\x10\x11\n)\n\x06\x05\x0b\x04\x01\x02\x01\
+    \x12\x04\xea\x03\x04\x12\x1a\x19\x20Use\x20JavaScript\x20strings.\n\n\
+    \x0f\n\x07\x05\x0b\x04\x01\x02\x01\x01\x12\x04\xea\x03\x04\r\n\x0f\n\x07\
+    \x05\x0b\x04\x01\x02\x01\x02\x12\x04\xea\x03\x10\x11\n)\n\x06\x05\x0b\
+    \x04\x01\x02\x02\x12\x04\xed\x03\x04\x12\x1a\x19\x20Use\x20JavaScript\
+    \x20numbers.\n\n\x0f\n\x07\x05\x0b\x04\x01\x02\x02\x01\x12\x04\xed\x03\
+    \x04\r\n\x0f\n\x07\x05\x0b\x04\x01\x02\x02\x02\x12\x04\xed\x03\x10\x11\n\
+    \xef\x0c\n\x04\x05\x0b\x02\x03\x12\x04\x8c\x04\x02)\x1a\xe0\x0c\x20Shoul\
+    d\x20this\x20field\x20be\x20parsed\x20lazily?\x20\x20Lazy\x20applies\x20\
+    only\x20to\x20message-type\n\x20fields.\x20\x20It\x20means\x20that\x20wh\
+    en\x20the\x20outer\x20message\x20is\x20initially\x20parsed,\x20the\n\x20\
+    inner\x20message's\x20contents\x20will\x20not\x20be\x20parsed\x20but\x20\
+    instead\x20stored\x20in\x20encoded\n\x20form.\x20\x20The\x20inner\x20mes\
+    sage\x20will\x20actually\x20be\x20parsed\x20when\x20it\x20is\x20first\
+    \x20accessed.\n\n\x20This\x20is\x20only\x20a\x20hint.\x20\x20Implementat\
+    ions\x20are\x20free\x20to\x20choose\x20whether\x20to\x20use\n\x20eager\
+    \x20or\x20lazy\x20parsing\x20regardless\x20of\x20the\x20value\x20of\x20t\
+    his\x20option.\x20\x20However,\n\x20setting\x20this\x20option\x20true\
+    \x20suggests\x20that\x20the\x20protocol\x20author\x20believes\x20that\n\
+    \x20using\x20lazy\x20parsing\x20on\x20this\x20field\x20is\x20worth\x20th\
+    e\x20additional\x20bookkeeping\n\x20overhead\x20typically\x20needed\x20t\
+    o\x20implement\x20it.\n\n\x20This\x20option\x20does\x20not\x20affect\x20\
+    the\x20public\x20interface\x20of\x20any\x20generated\x20code;\n\x20all\
+    \x20method\x20signatures\x20remain\x20the\x20same.\x20\x20Furthermore,\
+    \x20thread-safety\x20of\x20the\n\x20interface\x20is\x20not\x20affected\
+    \x20by\x20this\x20option;\x20const\x20methods\x20remain\x20safe\x20to\n\
+    \x20call\x20from\x20multiple\x20threads\x20concurrently,\x20while\x20non\
+    -const\x20methods\x20continue\n\x20to\x20require\x20exclusive\x20access.\
+    \n\n\n\x20Note\x20that\x20implementations\x20may\x20choose\x20not\x20to\
+    \x20check\x20required\x20fields\x20within\n\x20a\x20lazy\x20sub-message.\
+    \x20\x20That\x20is,\x20calling\x20IsInitialized()\x20on\x20the\x20outer\
+    \x20message\n\x20may\x20return\x20true\x20even\x20if\x20the\x20inner\x20\
+    message\x20has\x20missing\x20required\x20fields.\n\x20This\x20is\x20nece\
+    ssary\x20because\x20otherwise\x20the\x20inner\x20message\x20would\x20hav\
+    e\x20to\x20be\n\x20parsed\x20in\x20order\x20to\x20perform\x20the\x20chec\
+    k,\x20defeating\x20the\x20purpose\x20of\x20lazy\n\x20parsing.\x20\x20An\
+    \x20implementation\x20which\x20chooses\x20not\x20to\x20check\x20required\
+    \x20fields\n\x20must\x20be\x20consistent\x20about\x20it.\x20\x20That\x20\
+    is,\x20for\x20any\x20particular\x20sub-message,\x20the\n\x20implementati\
+    on\x20must\x20either\x20*always*\x20check\x20its\x20required\x20fields,\
+    \x20or\x20*never*\n\x20check\x20its\x20required\x20fields,\x20regardless\
+    \x20of\x20whether\x20or\x20not\x20the\x20message\x20has\n\x20been\x20par\
+    sed.\n\n\r\n\x05\x05\x0b\x02\x03\x04\x12\x04\x8c\x04\x02\n\n\r\n\x05\x05\
+    \x0b\x02\x03\x05\x12\x04\x8c\x04\x0b\x0f\n\r\n\x05\x05\x0b\x02\x03\x01\
+    \x12\x04\x8c\x04\x10\x14\n\r\n\x05\x05\x0b\x02\x03\x03\x12\x04\x8c\x04\
+    \x17\x18\n\r\n\x05\x05\x0b\x02\x03\x08\x12\x04\x8c\x04\x19(\n\r\n\x05\
+    \x05\x0b\x02\x03\x07\x12\x04\x8c\x04\"'\n\xe8\x01\n\x04\x05\x0b\x02\x04\
+    \x12\x04\x92\x04\x02/\x1a\xd9\x01\x20Is\x20this\x20field\x20deprecated?\
+    \n\x20Depending\x20on\x20the\x20target\x20platform,\x20this\x20can\x20em\
+    it\x20Deprecated\x20annotations\n\x20for\x20accessors,\x20or\x20it\x20wi\
+    ll\x20be\x20completely\x20ignored;\x20in\x20the\x20very\x20least,\x20thi\
+    s\n\x20is\x20a\x20formalization\x20for\x20deprecating\x20fields.\n\n\r\n\
+    \x05\x05\x0b\x02\x04\x04\x12\x04\x92\x04\x02\n\n\r\n\x05\x05\x0b\x02\x04\
+    \x05\x12\x04\x92\x04\x0b\x0f\n\r\n\x05\x05\x0b\x02\x04\x01\x12\x04\x92\
+    \x04\x10\x1a\n\r\n\x05\x05\x0b\x02\x04\x03\x12\x04\x92\x04\x1d\x1e\n\r\n\
+    \x05\x05\x0b\x02\x04\x08\x12\x04\x92\x04\x1f.\n\r\n\x05\x05\x0b\x02\x04\
+    \x07\x12\x04\x92\x04(-\n?\n\x04\x05\x0b\x02\x05\x12\x04\x95\x04\x02*\x1a\
+    1\x20For\x20Google-internal\x20migration\x20only.\x20Do\x20not\x20use.\n\
+    \n\r\n\x05\x05\x0b\x02\x05\x04\x12\x04\x95\x04\x02\n\n\r\n\x05\x05\x0b\
+    \x02\x05\x05\x12\x04\x95\x04\x0b\x0f\n\r\n\x05\x05\x0b\x02\x05\x01\x12\
+    \x04\x95\x04\x10\x14\n\r\n\x05\x05\x0b\x02\x05\x03\x12\x04\x95\x04\x17\
+    \x19\n\r\n\x05\x05\x0b\x02\x05\x08\x12\x04\x95\x04\x1a)\n\r\n\x05\x05\
+    \x0b\x02\x05\x07\x12\x04\x95\x04#(\nO\n\x04\x05\x0b\x02\x06\x12\x04\x99\
     \x04\x02:\x1aA\x20The\x20parser\x20stores\x20options\x20it\x20doesn't\
-    \x20recognize\x20here.\x20See\x20above.\n\n\r\n\x05\x04\x0f\x02\x01\x04\
-    \x12\x04\xcc\x04\x02\n\n\r\n\x05\x04\x0f\x02\x01\x06\x12\x04\xcc\x04\x0b\
-    \x1e\n\r\n\x05\x04\x0f\x02\x01\x01\x12\x04\xcc\x04\x1f3\n\r\n\x05\x04\
-    \x0f\x02\x01\x03\x12\x04\xcc\x0469\nZ\n\x03\x04\x0f\x05\x12\x04\xcf\x04\
+    \x20recognize\x20here.\x20See\x20above.\n\n\r\n\x05\x05\x0b\x02\x06\x04\
+    \x12\x04\x99\x04\x02\n\n\r\n\x05\x05\x0b\x02\x06\x06\x12\x04\x99\x04\x0b\
+    \x1e\n\r\n\x05\x05\x0b\x02\x06\x01\x12\x04\x99\x04\x1f3\n\r\n\x05\x05\
+    \x0b\x02\x06\x03\x12\x04\x99\x0469\nZ\n\x03\x05\x0b\x05\x12\x04\x9c\x04\
     \x02\x19\x1aM\x20Clients\x20can\x20define\x20custom\x20options\x20in\x20\
-    extensions\x20of\x20this\x20message.\x20See\x20above.\n\n\x0c\n\x04\x04\
-    \x0f\x05\0\x12\x04\xcf\x04\r\x18\n\r\n\x05\x04\x0f\x05\0\x01\x12\x04\xcf\
-    \x04\r\x11\n\r\n\x05\x04\x0f\x05\0\x02\x12\x04\xcf\x04\x15\x18\n\x0c\n\
-    \x02\x04\x10\x12\x06\xd2\x04\0\xe4\x04\x01\n\x0b\n\x03\x04\x10\x01\x12\
-    \x04\xd2\x04\x08\x15\n\xd6\x03\n\x04\x04\x10\x02\0\x12\x04\xdd\x04\x020\
-    \x1a\xdc\x01\x20Is\x20this\x20method\x20deprecated?\n\x20Depending\x20on\
-    \x20the\x20target\x20platform,\x20this\x20can\x20emit\x20Deprecated\x20a\
-    nnotations\n\x20for\x20the\x20method,\x20or\x20it\x20will\x20be\x20compl\
-    etely\x20ignored;\x20in\x20the\x20very\x20least,\n\x20this\x20is\x20a\
-    \x20formalization\x20for\x20deprecating\x20methods.\n2\xe8\x01\x20Note:\
-    \x20\x20Field\x20numbers\x201\x20through\x2032\x20are\x20reserved\x20for\
-    \x20Google's\x20internal\x20RPC\n\x20\x20\x20framework.\x20\x20We\x20apo\
-    logize\x20for\x20hoarding\x20these\x20numbers\x20to\x20ourselves,\x20but\
-    \n\x20\x20\x20we\x20were\x20already\x20using\x20them\x20long\x20before\
-    \x20we\x20decided\x20to\x20release\x20Protocol\n\x20\x20\x20Buffers.\n\n\
-    \r\n\x05\x04\x10\x02\0\x04\x12\x04\xdd\x04\x02\n\n\r\n\x05\x04\x10\x02\0\
-    \x05\x12\x04\xdd\x04\x0b\x0f\n\r\n\x05\x04\x10\x02\0\x01\x12\x04\xdd\x04\
-    \x10\x1a\n\r\n\x05\x04\x10\x02\0\x03\x12\x04\xdd\x04\x1d\x1f\n\r\n\x05\
-    \x04\x10\x02\0\x08\x12\x04\xdd\x04\x20/\n\r\n\x05\x04\x10\x02\0\x07\x12\
-    \x04\xdd\x04).\nO\n\x04\x04\x10\x02\x01\x12\x04\xe0\x04\x02:\x1aA\x20The\
-    \x20parser\x20stores\x20options\x20it\x20doesn't\x20recognize\x20here.\
-    \x20See\x20above.\n\n\r\n\x05\x04\x10\x02\x01\x04\x12\x04\xe0\x04\x02\n\
-    \n\r\n\x05\x04\x10\x02\x01\x06\x12\x04\xe0\x04\x0b\x1e\n\r\n\x05\x04\x10\
-    \x02\x01\x01\x12\x04\xe0\x04\x1f3\n\r\n\x05\x04\x10\x02\x01\x03\x12\x04\
-    \xe0\x0469\nZ\n\x03\x04\x10\x05\x12\x04\xe3\x04\x02\x19\x1aM\x20Clients\
-    \x20can\x20define\x20custom\x20options\x20in\x20extensions\x20of\x20this\
-    \x20message.\x20See\x20above.\n\n\x0c\n\x04\x04\x10\x05\0\x12\x04\xe3\
-    \x04\r\x18\n\r\n\x05\x04\x10\x05\0\x01\x12\x04\xe3\x04\r\x11\n\r\n\x05\
-    \x04\x10\x05\0\x02\x12\x04\xe3\x04\x15\x18\n\x8b\x03\n\x02\x04\x11\x12\
-    \x06\xed\x04\0\x81\x05\x01\x1a\xfc\x02\x20A\x20message\x20representing\
-    \x20a\x20option\x20the\x20parser\x20does\x20not\x20recognize.\x20This\
-    \x20only\n\x20appears\x20in\x20options\x20protos\x20created\x20by\x20the\
-    \x20compiler::Parser\x20class.\n\x20DescriptorPool\x20resolves\x20these\
-    \x20when\x20building\x20Descriptor\x20objects.\x20Therefore,\n\x20option\
-    s\x20protos\x20in\x20descriptor\x20objects\x20(e.g.\x20returned\x20by\
-    \x20Descriptor::options(),\n\x20or\x20produced\x20by\x20Descriptor::Copy\
-    To())\x20will\x20never\x20have\x20UninterpretedOptions\n\x20in\x20them.\
-    \n\n\x0b\n\x03\x04\x11\x01\x12\x04\xed\x04\x08\x1b\n\xcb\x02\n\x04\x04\
-    \x11\x03\0\x12\x06\xf3\x04\x02\xf6\x04\x03\x1a\xba\x02\x20The\x20name\
-    \x20of\x20the\x20uninterpreted\x20option.\x20\x20Each\x20string\x20repre\
-    sents\x20a\x20segment\x20in\n\x20a\x20dot-separated\x20name.\x20\x20is_e\
-    xtension\x20is\x20true\x20iff\x20a\x20segment\x20represents\x20an\n\x20e\
-    xtension\x20(denoted\x20with\x20parentheses\x20in\x20options\x20specs\
-    \x20in\x20.proto\x20files).\n\x20E.g.,{\x20[\"foo\",\x20false],\x20[\"ba\
-    r.baz\",\x20true],\x20[\"qux\",\x20false]\x20}\x20represents\n\x20\"foo.\
-    (bar.baz).qux\".\n\n\r\n\x05\x04\x11\x03\0\x01\x12\x04\xf3\x04\n\x12\n\
-    \x0e\n\x06\x04\x11\x03\0\x02\0\x12\x04\xf4\x04\x04\"\n\x0f\n\x07\x04\x11\
-    \x03\0\x02\0\x04\x12\x04\xf4\x04\x04\x0c\n\x0f\n\x07\x04\x11\x03\0\x02\0\
-    \x05\x12\x04\xf4\x04\r\x13\n\x0f\n\x07\x04\x11\x03\0\x02\0\x01\x12\x04\
-    \xf4\x04\x14\x1d\n\x0f\n\x07\x04\x11\x03\0\x02\0\x03\x12\x04\xf4\x04\x20\
-    !\n\x0e\n\x06\x04\x11\x03\0\x02\x01\x12\x04\xf5\x04\x04#\n\x0f\n\x07\x04\
-    \x11\x03\0\x02\x01\x04\x12\x04\xf5\x04\x04\x0c\n\x0f\n\x07\x04\x11\x03\0\
-    \x02\x01\x05\x12\x04\xf5\x04\r\x11\n\x0f\n\x07\x04\x11\x03\0\x02\x01\x01\
-    \x12\x04\xf5\x04\x12\x1e\n\x0f\n\x07\x04\x11\x03\0\x02\x01\x03\x12\x04\
-    \xf5\x04!\"\n\x0c\n\x04\x04\x11\x02\0\x12\x04\xf7\x04\x02\x1d\n\r\n\x05\
-    \x04\x11\x02\0\x04\x12\x04\xf7\x04\x02\n\n\r\n\x05\x04\x11\x02\0\x06\x12\
-    \x04\xf7\x04\x0b\x13\n\r\n\x05\x04\x11\x02\0\x01\x12\x04\xf7\x04\x14\x18\
-    \n\r\n\x05\x04\x11\x02\0\x03\x12\x04\xf7\x04\x1b\x1c\n\x9c\x01\n\x04\x04\
-    \x11\x02\x01\x12\x04\xfb\x04\x02'\x1a\x8d\x01\x20The\x20value\x20of\x20t\
-    he\x20uninterpreted\x20option,\x20in\x20whatever\x20type\x20the\x20token\
-    izer\n\x20identified\x20it\x20as\x20during\x20parsing.\x20Exactly\x20one\
-    \x20of\x20these\x20should\x20be\x20set.\n\n\r\n\x05\x04\x11\x02\x01\x04\
-    \x12\x04\xfb\x04\x02\n\n\r\n\x05\x04\x11\x02\x01\x05\x12\x04\xfb\x04\x0b\
-    \x11\n\r\n\x05\x04\x11\x02\x01\x01\x12\x04\xfb\x04\x12\"\n\r\n\x05\x04\
-    \x11\x02\x01\x03\x12\x04\xfb\x04%&\n\x0c\n\x04\x04\x11\x02\x02\x12\x04\
-    \xfc\x04\x02)\n\r\n\x05\x04\x11\x02\x02\x04\x12\x04\xfc\x04\x02\n\n\r\n\
-    \x05\x04\x11\x02\x02\x05\x12\x04\xfc\x04\x0b\x11\n\r\n\x05\x04\x11\x02\
-    \x02\x01\x12\x04\xfc\x04\x12$\n\r\n\x05\x04\x11\x02\x02\x03\x12\x04\xfc\
-    \x04'(\n\x0c\n\x04\x04\x11\x02\x03\x12\x04\xfd\x04\x02(\n\r\n\x05\x04\
-    \x11\x02\x03\x04\x12\x04\xfd\x04\x02\n\n\r\n\x05\x04\x11\x02\x03\x05\x12\
-    \x04\xfd\x04\x0b\x10\n\r\n\x05\x04\x11\x02\x03\x01\x12\x04\xfd\x04\x11#\
-    \n\r\n\x05\x04\x11\x02\x03\x03\x12\x04\xfd\x04&'\n\x0c\n\x04\x04\x11\x02\
-    \x04\x12\x04\xfe\x04\x02#\n\r\n\x05\x04\x11\x02\x04\x04\x12\x04\xfe\x04\
-    \x02\n\n\r\n\x05\x04\x11\x02\x04\x05\x12\x04\xfe\x04\x0b\x11\n\r\n\x05\
-    \x04\x11\x02\x04\x01\x12\x04\xfe\x04\x12\x1e\n\r\n\x05\x04\x11\x02\x04\
-    \x03\x12\x04\xfe\x04!\"\n\x0c\n\x04\x04\x11\x02\x05\x12\x04\xff\x04\x02\
-    \"\n\r\n\x05\x04\x11\x02\x05\x04\x12\x04\xff\x04\x02\n\n\r\n\x05\x04\x11\
-    \x02\x05\x05\x12\x04\xff\x04\x0b\x10\n\r\n\x05\x04\x11\x02\x05\x01\x12\
-    \x04\xff\x04\x11\x1d\n\r\n\x05\x04\x11\x02\x05\x03\x12\x04\xff\x04\x20!\
-    \n\x0c\n\x04\x04\x11\x02\x06\x12\x04\x80\x05\x02&\n\r\n\x05\x04\x11\x02\
-    \x06\x04\x12\x04\x80\x05\x02\n\n\r\n\x05\x04\x11\x02\x06\x05\x12\x04\x80\
-    \x05\x0b\x11\n\r\n\x05\x04\x11\x02\x06\x01\x12\x04\x80\x05\x12!\n\r\n\
-    \x05\x04\x11\x02\x06\x03\x12\x04\x80\x05$%\n\xda\x01\n\x02\x04\x12\x12\
-    \x06\x88\x05\0\x89\x06\x01\x1aj\x20Encapsulates\x20information\x20about\
-    \x20the\x20original\x20source\x20file\x20from\x20which\x20a\n\x20FileDes\
-    criptorProto\x20was\x20generated.\n2`\x20===============================\
-    ====================================\n\x20Optional\x20source\x20code\x20\
-    info\n\n\x0b\n\x03\x04\x12\x01\x12\x04\x88\x05\x08\x16\n\x82\x11\n\x04\
-    \x04\x12\x02\0\x12\x04\xb4\x05\x02!\x1a\xf3\x10\x20A\x20Location\x20iden\
-    tifies\x20a\x20piece\x20of\x20source\x20code\x20in\x20a\x20.proto\x20fil\
-    e\x20which\n\x20corresponds\x20to\x20a\x20particular\x20definition.\x20\
-    \x20This\x20information\x20is\x20intended\n\x20to\x20be\x20useful\x20to\
-    \x20IDEs,\x20code\x20indexers,\x20documentation\x20generators,\x20and\
-    \x20similar\n\x20tools.\n\n\x20For\x20example,\x20say\x20we\x20have\x20a\
-    \x20file\x20like:\n\x20\x20\x20message\x20Foo\x20{\n\x20\x20\x20\x20\x20\
-    optional\x20string\x20foo\x20=\x201;\n\x20\x20\x20}\n\x20Let's\x20look\
-    \x20at\x20just\x20the\x20field\x20definition:\n\x20\x20\x20optional\x20s\
-    tring\x20foo\x20=\x201;\n\x20\x20\x20^\x20\x20\x20\x20\x20\x20\x20^^\x20\
-    \x20\x20\x20\x20^^\x20\x20^\x20\x20^^^\n\x20\x20\x20a\x20\x20\x20\x20\
-    \x20\x20\x20bc\x20\x20\x20\x20\x20de\x20\x20f\x20\x20ghi\n\x20We\x20have\
-    \x20the\x20following\x20locations:\n\x20\x20\x20span\x20\x20\x20path\x20\
-    \x20\x20\x20\x20\x20\x20\x20\x20\x20\x20\x20\x20\x20\x20represents\n\x20\
-    \x20\x20[a,i)\x20\x20[\x204,\x200,\x202,\x200\x20]\x20\x20\x20\x20\x20Th\
-    e\x20whole\x20field\x20definition.\n\x20\x20\x20[a,b)\x20\x20[\x204,\x20\
-    0,\x202,\x200,\x204\x20]\x20\x20The\x20label\x20(optional).\n\x20\x20\
-    \x20[c,d)\x20\x20[\x204,\x200,\x202,\x200,\x205\x20]\x20\x20The\x20type\
-    \x20(string).\n\x20\x20\x20[e,f)\x20\x20[\x204,\x200,\x202,\x200,\x201\
-    \x20]\x20\x20The\x20name\x20(foo).\n\x20\x20\x20[g,h)\x20\x20[\x204,\x20\
-    0,\x202,\x200,\x203\x20]\x20\x20The\x20number\x20(1).\n\n\x20Notes:\n\
-    \x20-\x20A\x20location\x20may\x20refer\x20to\x20a\x20repeated\x20field\
-    \x20itself\x20(i.e.\x20not\x20to\x20any\n\x20\x20\x20particular\x20index\
-    \x20within\x20it).\x20\x20This\x20is\x20used\x20whenever\x20a\x20set\x20\
-    of\x20elements\x20are\n\x20\x20\x20logically\x20enclosed\x20in\x20a\x20s\
-    ingle\x20code\x20segment.\x20\x20For\x20example,\x20an\x20entire\n\x20\
-    \x20\x20extend\x20block\x20(possibly\x20containing\x20multiple\x20extens\
-    ion\x20definitions)\x20will\n\x20\x20\x20have\x20an\x20outer\x20location\
-    \x20whose\x20path\x20refers\x20to\x20the\x20\"extensions\"\x20repeated\n\
-    \x20\x20\x20field\x20without\x20an\x20index.\n\x20-\x20Multiple\x20locat\
-    ions\x20may\x20have\x20the\x20same\x20path.\x20\x20This\x20happens\x20wh\
-    en\x20a\x20single\n\x20\x20\x20logical\x20declaration\x20is\x20spread\
-    \x20out\x20across\x20multiple\x20places.\x20\x20The\x20most\n\x20\x20\
-    \x20obvious\x20example\x20is\x20the\x20\"extend\"\x20block\x20again\x20-\
-    -\x20there\x20may\x20be\x20multiple\n\x20\x20\x20extend\x20blocks\x20in\
-    \x20the\x20same\x20scope,\x20each\x20of\x20which\x20will\x20have\x20the\
-    \x20same\x20path.\n\x20-\x20A\x20location's\x20span\x20is\x20not\x20alwa\
-    ys\x20a\x20subset\x20of\x20its\x20parent's\x20span.\x20\x20For\n\x20\x20\
-    \x20example,\x20the\x20\"extendee\"\x20of\x20an\x20extension\x20declarat\
-    ion\x20appears\x20at\x20the\n\x20\x20\x20beginning\x20of\x20the\x20\"ext\
-    end\"\x20block\x20and\x20is\x20shared\x20by\x20all\x20extensions\x20with\
-    in\n\x20\x20\x20the\x20block.\n\x20-\x20Just\x20because\x20a\x20location\
-    's\x20span\x20is\x20a\x20subset\x20of\x20some\x20other\x20location's\x20\
-    span\n\x20\x20\x20does\x20not\x20mean\x20that\x20it\x20is\x20a\x20descen\
-    dent.\x20\x20For\x20example,\x20a\x20\"group\"\x20defines\n\x20\x20\x20b\
-    oth\x20a\x20type\x20and\x20a\x20field\x20in\x20a\x20single\x20declaratio\
-    n.\x20\x20Thus,\x20the\x20locations\n\x20\x20\x20corresponding\x20to\x20\
-    the\x20type\x20and\x20field\x20and\x20their\x20components\x20will\x20ove\
-    rlap.\n\x20-\x20Code\x20which\x20tries\x20to\x20interpret\x20locations\
-    \x20should\x20probably\x20be\x20designed\x20to\n\x20\x20\x20ignore\x20th\
-    ose\x20that\x20it\x20doesn't\x20understand,\x20as\x20more\x20types\x20of\
-    \x20locations\x20could\n\x20\x20\x20be\x20recorded\x20in\x20the\x20futur\
-    e.\n\n\r\n\x05\x04\x12\x02\0\x04\x12\x04\xb4\x05\x02\n\n\r\n\x05\x04\x12\
-    \x02\0\x06\x12\x04\xb4\x05\x0b\x13\n\r\n\x05\x04\x12\x02\0\x01\x12\x04\
-    \xb4\x05\x14\x1c\n\r\n\x05\x04\x12\x02\0\x03\x12\x04\xb4\x05\x1f\x20\n\
-    \x0e\n\x04\x04\x12\x03\0\x12\x06\xb5\x05\x02\x88\x06\x03\n\r\n\x05\x04\
-    \x12\x03\0\x01\x12\x04\xb5\x05\n\x12\n\x83\x07\n\x06\x04\x12\x03\0\x02\0\
-    \x12\x04\xcd\x05\x04*\x1a\xf2\x06\x20Identifies\x20which\x20part\x20of\
-    \x20the\x20FileDescriptorProto\x20was\x20defined\x20at\x20this\n\x20loca\
-    tion.\n\n\x20Each\x20element\x20is\x20a\x20field\x20number\x20or\x20an\
-    \x20index.\x20\x20They\x20form\x20a\x20path\x20from\n\x20the\x20root\x20\
-    FileDescriptorProto\x20to\x20the\x20place\x20where\x20the\x20definition.\
-    \x20\x20For\n\x20example,\x20this\x20path:\n\x20\x20\x20[\x204,\x203,\
-    \x202,\x207,\x201\x20]\n\x20refers\x20to:\n\x20\x20\x20file.message_type\
-    (3)\x20\x20//\x204,\x203\n\x20\x20\x20\x20\x20\x20\x20.field(7)\x20\x20\
-    \x20\x20\x20\x20\x20\x20\x20//\x202,\x207\n\x20\x20\x20\x20\x20\x20\x20.\
-    name()\x20\x20\x20\x20\x20\x20\x20\x20\x20\x20\x20//\x201\n\x20This\x20i\
-    s\x20because\x20FileDescriptorProto.message_type\x20has\x20field\x20numb\
-    er\x204:\n\x20\x20\x20repeated\x20DescriptorProto\x20message_type\x20=\
-    \x204;\n\x20and\x20DescriptorProto.field\x20has\x20field\x20number\x202:\
-    \n\x20\x20\x20repeated\x20FieldDescriptorProto\x20field\x20=\x202;\n\x20\
-    and\x20FieldDescriptorProto.name\x20has\x20field\x20number\x201:\n\x20\
-    \x20\x20optional\x20string\x20name\x20=\x201;\n\n\x20Thus,\x20the\x20abo\
-    ve\x20path\x20gives\x20the\x20location\x20of\x20a\x20field\x20name.\x20\
-    \x20If\x20we\x20removed\n\x20the\x20last\x20element:\n\x20\x20\x20[\x204\
-    ,\x203,\x202,\x207\x20]\n\x20this\x20path\x20refers\x20to\x20the\x20whol\
-    e\x20field\x20declaration\x20(from\x20the\x20beginning\n\x20of\x20the\
-    \x20label\x20to\x20the\x20terminating\x20semicolon).\n\n\x0f\n\x07\x04\
-    \x12\x03\0\x02\0\x04\x12\x04\xcd\x05\x04\x0c\n\x0f\n\x07\x04\x12\x03\0\
-    \x02\0\x05\x12\x04\xcd\x05\r\x12\n\x0f\n\x07\x04\x12\x03\0\x02\0\x01\x12\
-    \x04\xcd\x05\x13\x17\n\x0f\n\x07\x04\x12\x03\0\x02\0\x03\x12\x04\xcd\x05\
-    \x1a\x1b\n\x0f\n\x07\x04\x12\x03\0\x02\0\x08\x12\x04\xcd\x05\x1c)\n\x12\
-    \n\n\x04\x12\x03\0\x02\0\x08\xe7\x07\0\x12\x04\xcd\x05\x1d(\n\x13\n\x0b\
-    \x04\x12\x03\0\x02\0\x08\xe7\x07\0\x02\x12\x04\xcd\x05\x1d#\n\x14\n\x0c\
-    \x04\x12\x03\0\x02\0\x08\xe7\x07\0\x02\0\x12\x04\xcd\x05\x1d#\n\x15\n\r\
-    \x04\x12\x03\0\x02\0\x08\xe7\x07\0\x02\0\x01\x12\x04\xcd\x05\x1d#\n\x13\
-    \n\x0b\x04\x12\x03\0\x02\0\x08\xe7\x07\0\x03\x12\x04\xcd\x05$(\n\xd2\x02\
-    \n\x06\x04\x12\x03\0\x02\x01\x12\x04\xd4\x05\x04*\x1a\xc1\x02\x20Always\
-    \x20has\x20exactly\x20three\x20or\x20four\x20elements:\x20start\x20line,\
-    \x20start\x20column,\n\x20end\x20line\x20(optional,\x20otherwise\x20assu\
-    med\x20same\x20as\x20start\x20line),\x20end\x20column.\n\x20These\x20are\
-    \x20packed\x20into\x20a\x20single\x20field\x20for\x20efficiency.\x20\x20\
-    Note\x20that\x20line\n\x20and\x20column\x20numbers\x20are\x20zero-based\
-    \x20--\x20typically\x20you\x20will\x20want\x20to\x20add\n\x201\x20to\x20\
-    each\x20before\x20displaying\x20to\x20a\x20user.\n\n\x0f\n\x07\x04\x12\
-    \x03\0\x02\x01\x04\x12\x04\xd4\x05\x04\x0c\n\x0f\n\x07\x04\x12\x03\0\x02\
-    \x01\x05\x12\x04\xd4\x05\r\x12\n\x0f\n\x07\x04\x12\x03\0\x02\x01\x01\x12\
-    \x04\xd4\x05\x13\x17\n\x0f\n\x07\x04\x12\x03\0\x02\x01\x03\x12\x04\xd4\
-    \x05\x1a\x1b\n\x0f\n\x07\x04\x12\x03\0\x02\x01\x08\x12\x04\xd4\x05\x1c)\
-    \n\x12\n\n\x04\x12\x03\0\x02\x01\x08\xe7\x07\0\x12\x04\xd4\x05\x1d(\n\
-    \x13\n\x0b\x04\x12\x03\0\x02\x01\x08\xe7\x07\0\x02\x12\x04\xd4\x05\x1d#\
-    \n\x14\n\x0c\x04\x12\x03\0\x02\x01\x08\xe7\x07\0\x02\0\x12\x04\xd4\x05\
-    \x1d#\n\x15\n\r\x04\x12\x03\0\x02\x01\x08\xe7\x07\0\x02\0\x01\x12\x04\
-    \xd4\x05\x1d#\n\x13\n\x0b\x04\x12\x03\0\x02\x01\x08\xe7\x07\0\x03\x12\
-    \x04\xd4\x05$(\n\xa5\x0c\n\x06\x04\x12\x03\0\x02\x02\x12\x04\x85\x06\x04\
-    )\x1a\x94\x0c\x20If\x20this\x20SourceCodeInfo\x20represents\x20a\x20comp\
-    lete\x20declaration,\x20these\x20are\x20any\n\x20comments\x20appearing\
-    \x20before\x20and\x20after\x20the\x20declaration\x20which\x20appear\x20t\
-    o\x20be\n\x20attached\x20to\x20the\x20declaration.\n\n\x20A\x20series\
-    \x20of\x20line\x20comments\x20appearing\x20on\x20consecutive\x20lines,\
-    \x20with\x20no\x20other\n\x20tokens\x20appearing\x20on\x20those\x20lines\
-    ,\x20will\x20be\x20treated\x20as\x20a\x20single\x20comment.\n\n\x20leadi\
-    ng_detached_comments\x20will\x20keep\x20paragraphs\x20of\x20comments\x20\
-    that\x20appear\n\x20before\x20(but\x20not\x20connected\x20to)\x20the\x20\
-    current\x20element.\x20Each\x20paragraph,\n\x20separated\x20by\x20empty\
-    \x20lines,\x20will\x20be\x20one\x20comment\x20element\x20in\x20the\x20re\
-    peated\n\x20field.\n\n\x20Only\x20the\x20comment\x20content\x20is\x20pro\
-    vided;\x20comment\x20markers\x20(e.g.\x20//)\x20are\n\x20stripped\x20out\
-    .\x20\x20For\x20block\x20comments,\x20leading\x20whitespace\x20and\x20an\
-    \x20asterisk\n\x20will\x20be\x20stripped\x20from\x20the\x20beginning\x20\
-    of\x20each\x20line\x20other\x20than\x20the\x20first.\n\x20Newlines\x20ar\
-    e\x20included\x20in\x20the\x20output.\n\n\x20Examples:\n\n\x20\x20\x20op\
-    tional\x20int32\x20foo\x20=\x201;\x20\x20//\x20Comment\x20attached\x20to\
-    \x20foo.\n\x20\x20\x20//\x20Comment\x20attached\x20to\x20bar.\n\x20\x20\
-    \x20optional\x20int32\x20bar\x20=\x202;\n\n\x20\x20\x20optional\x20strin\
-    g\x20baz\x20=\x203;\n\x20\x20\x20//\x20Comment\x20attached\x20to\x20baz.\
-    \n\x20\x20\x20//\x20Another\x20line\x20attached\x20to\x20baz.\n\n\x20\
-    \x20\x20//\x20Comment\x20attached\x20to\x20qux.\n\x20\x20\x20//\n\x20\
-    \x20\x20//\x20Another\x20line\x20attached\x20to\x20qux.\n\x20\x20\x20opt\
-    ional\x20double\x20qux\x20=\x204;\n\n\x20\x20\x20//\x20Detached\x20comme\
-    nt\x20for\x20corge.\x20This\x20is\x20not\x20leading\x20or\x20trailing\
-    \x20comments\n\x20\x20\x20//\x20to\x20qux\x20or\x20corge\x20because\x20t\
-    here\x20are\x20blank\x20lines\x20separating\x20it\x20from\n\x20\x20\x20/\
-    /\x20both.\n\n\x20\x20\x20//\x20Detached\x20comment\x20for\x20corge\x20p\
-    aragraph\x202.\n\n\x20\x20\x20optional\x20string\x20corge\x20=\x205;\n\
-    \x20\x20\x20/*\x20Block\x20comment\x20attached\n\x20\x20\x20\x20*\x20to\
-    \x20corge.\x20\x20Leading\x20asterisks\n\x20\x20\x20\x20*\x20will\x20be\
-    \x20removed.\x20*/\n\x20\x20\x20/*\x20Block\x20comment\x20attached\x20to\
-    \n\x20\x20\x20\x20*\x20grault.\x20*/\n\x20\x20\x20optional\x20int32\x20g\
-    rault\x20=\x206;\n\n\x20\x20\x20//\x20ignored\x20detached\x20comments.\n\
-    \n\x0f\n\x07\x04\x12\x03\0\x02\x02\x04\x12\x04\x85\x06\x04\x0c\n\x0f\n\
-    \x07\x04\x12\x03\0\x02\x02\x05\x12\x04\x85\x06\r\x13\n\x0f\n\x07\x04\x12\
-    \x03\0\x02\x02\x01\x12\x04\x85\x06\x14$\n\x0f\n\x07\x04\x12\x03\0\x02\
-    \x02\x03\x12\x04\x85\x06'(\n\x0e\n\x06\x04\x12\x03\0\x02\x03\x12\x04\x86\
-    \x06\x04*\n\x0f\n\x07\x04\x12\x03\0\x02\x03\x04\x12\x04\x86\x06\x04\x0c\
-    \n\x0f\n\x07\x04\x12\x03\0\x02\x03\x05\x12\x04\x86\x06\r\x13\n\x0f\n\x07\
-    \x04\x12\x03\0\x02\x03\x01\x12\x04\x86\x06\x14%\n\x0f\n\x07\x04\x12\x03\
-    \0\x02\x03\x03\x12\x04\x86\x06()\n\x0e\n\x06\x04\x12\x03\0\x02\x04\x12\
-    \x04\x87\x06\x042\n\x0f\n\x07\x04\x12\x03\0\x02\x04\x04\x12\x04\x87\x06\
-    \x04\x0c\n\x0f\n\x07\x04\x12\x03\0\x02\x04\x05\x12\x04\x87\x06\r\x13\n\
-    \x0f\n\x07\x04\x12\x03\0\x02\x04\x01\x12\x04\x87\x06\x14-\n\x0f\n\x07\
-    \x04\x12\x03\0\x02\x04\x03\x12\x04\x87\x0601\n\xee\x01\n\x02\x04\x13\x12\
-    \x06\x8e\x06\0\xa3\x06\x01\x1a\xdf\x01\x20Describes\x20the\x20relationsh\
-    ip\x20between\x20generated\x20code\x20and\x20its\x20original\x20source\n\
-    \x20file.\x20A\x20GeneratedCodeInfo\x20message\x20is\x20associated\x20wi\
-    th\x20only\x20one\x20generated\n\x20source\x20file,\x20but\x20may\x20con\
-    tain\x20references\x20to\x20different\x20source\x20.proto\x20files.\n\n\
-    \x0b\n\x03\x04\x13\x01\x12\x04\x8e\x06\x08\x19\nx\n\x04\x04\x13\x02\0\
-    \x12\x04\x91\x06\x02%\x1aj\x20An\x20Annotation\x20connects\x20some\x20sp\
-    an\x20of\x20text\x20in\x20generated\x20code\x20to\x20an\x20element\n\x20\
-    of\x20its\x20generating\x20.proto\x20file.\n\n\r\n\x05\x04\x13\x02\0\x04\
-    \x12\x04\x91\x06\x02\n\n\r\n\x05\x04\x13\x02\0\x06\x12\x04\x91\x06\x0b\
-    \x15\n\r\n\x05\x04\x13\x02\0\x01\x12\x04\x91\x06\x16\x20\n\r\n\x05\x04\
-    \x13\x02\0\x03\x12\x04\x91\x06#$\n\x0e\n\x04\x04\x13\x03\0\x12\x06\x92\
-    \x06\x02\xa2\x06\x03\n\r\n\x05\x04\x13\x03\0\x01\x12\x04\x92\x06\n\x14\n\
-    \x8f\x01\n\x06\x04\x13\x03\0\x02\0\x12\x04\x95\x06\x04*\x1a\x7f\x20Ident\
-    ifies\x20the\x20element\x20in\x20the\x20original\x20source\x20.proto\x20\
-    file.\x20This\x20field\n\x20is\x20formatted\x20the\x20same\x20as\x20Sour\
-    ceCodeInfo.Location.path.\n\n\x0f\n\x07\x04\x13\x03\0\x02\0\x04\x12\x04\
-    \x95\x06\x04\x0c\n\x0f\n\x07\x04\x13\x03\0\x02\0\x05\x12\x04\x95\x06\r\
-    \x12\n\x0f\n\x07\x04\x13\x03\0\x02\0\x01\x12\x04\x95\x06\x13\x17\n\x0f\n\
-    \x07\x04\x13\x03\0\x02\0\x03\x12\x04\x95\x06\x1a\x1b\n\x0f\n\x07\x04\x13\
-    \x03\0\x02\0\x08\x12\x04\x95\x06\x1c)\n\x12\n\n\x04\x13\x03\0\x02\0\x08\
-    \xe7\x07\0\x12\x04\x95\x06\x1d(\n\x13\n\x0b\x04\x13\x03\0\x02\0\x08\xe7\
-    \x07\0\x02\x12\x04\x95\x06\x1d#\n\x14\n\x0c\x04\x13\x03\0\x02\0\x08\xe7\
-    \x07\0\x02\0\x12\x04\x95\x06\x1d#\n\x15\n\r\x04\x13\x03\0\x02\0\x08\xe7\
-    \x07\0\x02\0\x01\x12\x04\x95\x06\x1d#\n\x13\n\x0b\x04\x13\x03\0\x02\0\
-    \x08\xe7\x07\0\x03\x12\x04\x95\x06$(\nO\n\x06\x04\x13\x03\0\x02\x01\x12\
-    \x04\x98\x06\x04$\x1a?\x20Identifies\x20the\x20filesystem\x20path\x20to\
-    \x20the\x20original\x20source\x20.proto.\n\n\x0f\n\x07\x04\x13\x03\0\x02\
-    \x01\x04\x12\x04\x98\x06\x04\x0c\n\x0f\n\x07\x04\x13\x03\0\x02\x01\x05\
-    \x12\x04\x98\x06\r\x13\n\x0f\n\x07\x04\x13\x03\0\x02\x01\x01\x12\x04\x98\
-    \x06\x14\x1f\n\x0f\n\x07\x04\x13\x03\0\x02\x01\x03\x12\x04\x98\x06\"#\nw\
-    \n\x06\x04\x13\x03\0\x02\x02\x12\x04\x9c\x06\x04\x1d\x1ag\x20Identifies\
-    \x20the\x20starting\x20offset\x20in\x20bytes\x20in\x20the\x20generated\
-    \x20code\n\x20that\x20relates\x20to\x20the\x20identified\x20object.\n\n\
-    \x0f\n\x07\x04\x13\x03\0\x02\x02\x04\x12\x04\x9c\x06\x04\x0c\n\x0f\n\x07\
-    \x04\x13\x03\0\x02\x02\x05\x12\x04\x9c\x06\r\x12\n\x0f\n\x07\x04\x13\x03\
-    \0\x02\x02\x01\x12\x04\x9c\x06\x13\x18\n\x0f\n\x07\x04\x13\x03\0\x02\x02\
-    \x03\x12\x04\x9c\x06\x1b\x1c\n\xdb\x01\n\x06\x04\x13\x03\0\x02\x03\x12\
-    \x04\xa1\x06\x04\x1b\x1a\xca\x01\x20Identifies\x20the\x20ending\x20offse\
-    t\x20in\x20bytes\x20in\x20the\x20generated\x20code\x20that\n\x20relates\
-    \x20to\x20the\x20identified\x20offset.\x20The\x20end\x20offset\x20should\
-    \x20be\x20one\x20past\n\x20the\x20last\x20relevant\x20byte\x20(so\x20the\
-    \x20length\x20of\x20the\x20text\x20=\x20end\x20-\x20begin).\n\n\x0f\n\
-    \x07\x04\x13\x03\0\x02\x03\x04\x12\x04\xa1\x06\x04\x0c\n\x0f\n\x07\x04\
-    \x13\x03\0\x02\x03\x05\x12\x04\xa1\x06\r\x12\n\x0f\n\x07\x04\x13\x03\0\
-    \x02\x03\x01\x12\x04\xa1\x06\x13\x16\n\x0f\n\x07\x04\x13\x03\0\x02\x03\
-    \x03\x12\x04\xa1\x06\x19\x1a\
+    extensions\x20of\x20this\x20message.\x20See\x20above.\n\n\x0c\n\x04\x05\
+    \x0b\x05\0\x12\x04\x9c\x04\r\x18\n\r\n\x05\x05\x0b\x05\0\x01\x12\x04\x9c\
+    \x04\r\x11\n\r\n\x05\x05\x0b\x05\0\x02\x12\x04\x9c\x04\x15\x18\n\x0c\n\
+    \x02\x05\x0c\x12\x06\xa1\x04\0\xa7\x04\x01\n\x0b\n\x03\x05\x0c\x01\x12\
+    \x04\xa1\x04\x08\x14\nO\n\x04\x05\x0c\x02\0\x12\x04\xa3\x04\x02:\x1aA\
+    \x20The\x20parser\x20stores\x20options\x20it\x20doesn't\x20recognize\x20\
+    here.\x20See\x20above.\n\n\r\n\x05\x05\x0c\x02\0\x04\x12\x04\xa3\x04\x02\
+    \n\n\r\n\x05\x05\x0c\x02\0\x06\x12\x04\xa3\x04\x0b\x1e\n\r\n\x05\x05\x0c\
+    \x02\0\x01\x12\x04\xa3\x04\x1f3\n\r\n\x05\x05\x0c\x02\0\x03\x12\x04\xa3\
+    \x0469\nZ\n\x03\x05\x0c\x05\x12\x04\xa6\x04\x02\x19\x1aM\x20Clients\x20c\
+    an\x20define\x20custom\x20options\x20in\x20extensions\x20of\x20this\x20m\
+    essage.\x20See\x20above.\n\n\x0c\n\x04\x05\x0c\x05\0\x12\x04\xa6\x04\r\
+    \x18\n\r\n\x05\x05\x0c\x05\0\x01\x12\x04\xa6\x04\r\x11\n\r\n\x05\x05\x0c\
+    \x05\0\x02\x12\x04\xa6\x04\x15\x18\n\x0c\n\x02\x05\r\x12\x06\xa9\x04\0\
+    \xba\x04\x01\n\x0b\n\x03\x05\r\x01\x12\x04\xa9\x04\x08\x13\n`\n\x04\x05\
+    \r\x02\0\x12\x04\xad\x04\x02\x20\x1aR\x20Set\x20this\x20option\x20to\x20\
+    true\x20to\x20allow\x20mapping\x20different\x20tag\x20names\x20to\x20the\
+    \x20same\n\x20value.\n\n\r\n\x05\x05\r\x02\0\x04\x12\x04\xad\x04\x02\n\n\
+    \r\n\x05\x05\r\x02\0\x05\x12\x04\xad\x04\x0b\x0f\n\r\n\x05\x05\r\x02\0\
+    \x01\x12\x04\xad\x04\x10\x1b\n\r\n\x05\x05\r\x02\0\x03\x12\x04\xad\x04\
+    \x1e\x1f\n\xe5\x01\n\x04\x05\r\x02\x01\x12\x04\xb3\x04\x02/\x1a\xd6\x01\
+    \x20Is\x20this\x20enum\x20deprecated?\n\x20Depending\x20on\x20the\x20tar\
+    get\x20platform,\x20this\x20can\x20emit\x20Deprecated\x20annotations\n\
+    \x20for\x20the\x20enum,\x20or\x20it\x20will\x20be\x20completely\x20ignor\
+    ed;\x20in\x20the\x20very\x20least,\x20this\n\x20is\x20a\x20formalization\
+    \x20for\x20deprecating\x20enums.\n\n\r\n\x05\x05\r\x02\x01\x04\x12\x04\
+    \xb3\x04\x02\n\n\r\n\x05\x05\r\x02\x01\x05\x12\x04\xb3\x04\x0b\x0f\n\r\n\
+    \x05\x05\r\x02\x01\x01\x12\x04\xb3\x04\x10\x1a\n\r\n\x05\x05\r\x02\x01\
+    \x03\x12\x04\xb3\x04\x1d\x1e\n\r\n\x05\x05\r\x02\x01\x08\x12\x04\xb3\x04\
+    \x1f.\n\r\n\x05\x05\r\x02\x01\x07\x12\x04\xb3\x04(-\nO\n\x04\x05\r\x02\
+    \x02\x12\x04\xb6\x04\x02:\x1aA\x20The\x20parser\x20stores\x20options\x20\
+    it\x20doesn't\x20recognize\x20here.\x20See\x20above.\n\n\r\n\x05\x05\r\
+    \x02\x02\x04\x12\x04\xb6\x04\x02\n\n\r\n\x05\x05\r\x02\x02\x06\x12\x04\
+    \xb6\x04\x0b\x1e\n\r\n\x05\x05\r\x02\x02\x01\x12\x04\xb6\x04\x1f3\n\r\n\
+    \x05\x05\r\x02\x02\x03\x12\x04\xb6\x0469\nZ\n\x03\x05\r\x05\x12\x04\xb9\
+    \x04\x02\x19\x1aM\x20Clients\x20can\x20define\x20custom\x20options\x20in\
+    \x20extensions\x20of\x20this\x20message.\x20See\x20above.\n\n\x0c\n\x04\
+    \x05\r\x05\0\x12\x04\xb9\x04\r\x18\n\r\n\x05\x05\r\x05\0\x01\x12\x04\xb9\
+    \x04\r\x11\n\r\n\x05\x05\r\x05\0\x02\x12\x04\xb9\x04\x15\x18\n\x0c\n\x02\
+    \x05\x0e\x12\x06\xbc\x04\0\xc8\x04\x01\n\x0b\n\x03\x05\x0e\x01\x12\x04\
+    \xbc\x04\x08\x18\n\xf7\x01\n\x04\x05\x0e\x02\0\x12\x04\xc1\x04\x02/\x1a\
+    \xe8\x01\x20Is\x20this\x20enum\x20value\x20deprecated?\n\x20Depending\
+    \x20on\x20the\x20target\x20platform,\x20this\x20can\x20emit\x20Deprecate\
+    d\x20annotations\n\x20for\x20the\x20enum\x20value,\x20or\x20it\x20will\
+    \x20be\x20completely\x20ignored;\x20in\x20the\x20very\x20least,\n\x20thi\
+    s\x20is\x20a\x20formalization\x20for\x20deprecating\x20enum\x20values.\n\
+    \n\r\n\x05\x05\x0e\x02\0\x04\x12\x04\xc1\x04\x02\n\n\r\n\x05\x05\x0e\x02\
+    \0\x05\x12\x04\xc1\x04\x0b\x0f\n\r\n\x05\x05\x0e\x02\0\x01\x12\x04\xc1\
+    \x04\x10\x1a\n\r\n\x05\x05\x0e\x02\0\x03\x12\x04\xc1\x04\x1d\x1e\n\r\n\
+    \x05\x05\x0e\x02\0\x08\x12\x04\xc1\x04\x1f.\n\r\n\x05\x05\x0e\x02\0\x07\
+    \x12\x04\xc1\x04(-\nO\n\x04\x05\x0e\x02\x01\x12\x04\xc4\x04\x02:\x1aA\
+    \x20The\x20parser\x20stores\x20options\x20it\x20doesn't\x20recognize\x20\
+    here.\x20See\x20above.\n\n\r\n\x05\x05\x0e\x02\x01\x04\x12\x04\xc4\x04\
+    \x02\n\n\r\n\x05\x05\x0e\x02\x01\x06\x12\x04\xc4\x04\x0b\x1e\n\r\n\x05\
+    \x05\x0e\x02\x01\x01\x12\x04\xc4\x04\x1f3\n\r\n\x05\x05\x0e\x02\x01\x03\
+    \x12\x04\xc4\x0469\nZ\n\x03\x05\x0e\x05\x12\x04\xc7\x04\x02\x19\x1aM\x20\
+    Clients\x20can\x20define\x20custom\x20options\x20in\x20extensions\x20of\
+    \x20this\x20message.\x20See\x20above.\n\n\x0c\n\x04\x05\x0e\x05\0\x12\
+    \x04\xc7\x04\r\x18\n\r\n\x05\x05\x0e\x05\0\x01\x12\x04\xc7\x04\r\x11\n\r\
+    \n\x05\x05\x0e\x05\0\x02\x12\x04\xc7\x04\x15\x18\n\x0c\n\x02\x05\x0f\x12\
+    \x06\xca\x04\0\xdc\x04\x01\n\x0b\n\x03\x05\x0f\x01\x12\x04\xca\x04\x08\
+    \x16\n\xd9\x03\n\x04\x05\x0f\x02\0\x12\x04\xd5\x04\x020\x1a\xdf\x01\x20I\
+    s\x20this\x20service\x20deprecated?\n\x20Depending\x20on\x20the\x20targe\
+    t\x20platform,\x20this\x20can\x20emit\x20Deprecated\x20annotations\n\x20\
+    for\x20the\x20service,\x20or\x20it\x20will\x20be\x20completely\x20ignore\
+    d;\x20in\x20the\x20very\x20least,\n\x20this\x20is\x20a\x20formalization\
+    \x20for\x20deprecating\x20services.\n2\xe8\x01\x20Note:\x20\x20Field\x20\
+    numbers\x201\x20through\x2032\x20are\x20reserved\x20for\x20Google's\x20i\
+    nternal\x20RPC\n\x20\x20\x20framework.\x20\x20We\x20apologize\x20for\x20\
+    hoarding\x20these\x20numbers\x20to\x20ourselves,\x20but\n\x20\x20\x20we\
+    \x20were\x20already\x20using\x20them\x20long\x20before\x20we\x20decided\
+    \x20to\x20release\x20Protocol\n\x20\x20\x20Buffers.\n\n\r\n\x05\x05\x0f\
+    \x02\0\x04\x12\x04\xd5\x04\x02\n\n\r\n\x05\x05\x0f\x02\0\x05\x12\x04\xd5\
+    \x04\x0b\x0f\n\r\n\x05\x05\x0f\x02\0\x01\x12\x04\xd5\x04\x10\x1a\n\r\n\
+    \x05\x05\x0f\x02\0\x03\x12\x04\xd5\x04\x1d\x1f\n\r\n\x05\x05\x0f\x02\0\
+    \x08\x12\x04\xd5\x04\x20/\n\r\n\x05\x05\x0f\x02\0\x07\x12\x04\xd5\x04).\
+    \nO\n\x04\x05\x0f\x02\x01\x12\x04\xd8\x04\x02:\x1aA\x20The\x20parser\x20\
+    stores\x20options\x20it\x20doesn't\x20recognize\x20here.\x20See\x20above\
+    .\n\n\r\n\x05\x05\x0f\x02\x01\x04\x12\x04\xd8\x04\x02\n\n\r\n\x05\x05\
+    \x0f\x02\x01\x06\x12\x04\xd8\x04\x0b\x1e\n\r\n\x05\x05\x0f\x02\x01\x01\
+    \x12\x04\xd8\x04\x1f3\n\r\n\x05\x05\x0f\x02\x01\x03\x12\x04\xd8\x0469\nZ\
+    \n\x03\x05\x0f\x05\x12\x04\xdb\x04\x02\x19\x1aM\x20Clients\x20can\x20def\
+    ine\x20custom\x20options\x20in\x20extensions\x20of\x20this\x20message.\
+    \x20See\x20above.\n\n\x0c\n\x04\x05\x0f\x05\0\x12\x04\xdb\x04\r\x18\n\r\
+    \n\x05\x05\x0f\x05\0\x01\x12\x04\xdb\x04\r\x11\n\r\n\x05\x05\x0f\x05\0\
+    \x02\x12\x04\xdb\x04\x15\x18\n\x0c\n\x02\x05\x10\x12\x06\xde\x04\0\xf0\
+    \x04\x01\n\x0b\n\x03\x05\x10\x01\x12\x04\xde\x04\x08\x15\n\xd6\x03\n\x04\
+    \x05\x10\x02\0\x12\x04\xe9\x04\x020\x1a\xdc\x01\x20Is\x20this\x20method\
+    \x20deprecated?\n\x20Depending\x20on\x20the\x20target\x20platform,\x20th\
+    is\x20can\x20emit\x20Deprecated\x20annotations\n\x20for\x20the\x20method\
+    ,\x20or\x20it\x20will\x20be\x20completely\x20ignored;\x20in\x20the\x20ve\
+    ry\x20least,\n\x20this\x20is\x20a\x20formalization\x20for\x20deprecating\
+    \x20methods.\n2\xe8\x01\x20Note:\x20\x20Field\x20numbers\x201\x20through\
+    \x2032\x20are\x20reserved\x20for\x20Google's\x20internal\x20RPC\n\x20\
+    \x20\x20framework.\x20\x20We\x20apologize\x20for\x20hoarding\x20these\
+    \x20numbers\x20to\x20ourselves,\x20but\n\x20\x20\x20we\x20were\x20alread\
+    y\x20using\x20them\x20long\x20before\x20we\x20decided\x20to\x20release\
+    \x20Protocol\n\x20\x20\x20Buffers.\n\n\r\n\x05\x05\x10\x02\0\x04\x12\x04\
+    \xe9\x04\x02\n\n\r\n\x05\x05\x10\x02\0\x05\x12\x04\xe9\x04\x0b\x0f\n\r\n\
+    \x05\x05\x10\x02\0\x01\x12\x04\xe9\x04\x10\x1a\n\r\n\x05\x05\x10\x02\0\
+    \x03\x12\x04\xe9\x04\x1d\x1f\n\r\n\x05\x05\x10\x02\0\x08\x12\x04\xe9\x04\
+    \x20/\n\r\n\x05\x05\x10\x02\0\x07\x12\x04\xe9\x04).\nO\n\x04\x05\x10\x02\
+    \x01\x12\x04\xec\x04\x02:\x1aA\x20The\x20parser\x20stores\x20options\x20\
+    it\x20doesn't\x20recognize\x20here.\x20See\x20above.\n\n\r\n\x05\x05\x10\
+    \x02\x01\x04\x12\x04\xec\x04\x02\n\n\r\n\x05\x05\x10\x02\x01\x06\x12\x04\
+    \xec\x04\x0b\x1e\n\r\n\x05\x05\x10\x02\x01\x01\x12\x04\xec\x04\x1f3\n\r\
+    \n\x05\x05\x10\x02\x01\x03\x12\x04\xec\x0469\nZ\n\x03\x05\x10\x05\x12\
+    \x04\xef\x04\x02\x19\x1aM\x20Clients\x20can\x20define\x20custom\x20optio\
+    ns\x20in\x20extensions\x20of\x20this\x20message.\x20See\x20above.\n\n\
+    \x0c\n\x04\x05\x10\x05\0\x12\x04\xef\x04\r\x18\n\r\n\x05\x05\x10\x05\0\
+    \x01\x12\x04\xef\x04\r\x11\n\r\n\x05\x05\x10\x05\0\x02\x12\x04\xef\x04\
+    \x15\x18\n\x8b\x03\n\x02\x05\x11\x12\x06\xf9\x04\0\x8d\x05\x01\x1a\xfc\
+    \x02\x20A\x20message\x20representing\x20a\x20option\x20the\x20parser\x20\
+    does\x20not\x20recognize.\x20This\x20only\n\x20appears\x20in\x20options\
+    \x20protos\x20created\x20by\x20the\x20compiler::Parser\x20class.\n\x20De\
+    scriptorPool\x20resolves\x20these\x20when\x20building\x20Descriptor\x20o\
+    bjects.\x20Therefore,\n\x20options\x20protos\x20in\x20descriptor\x20obje\
+    cts\x20(e.g.\x20returned\x20by\x20Descriptor::options(),\n\x20or\x20prod\
+    uced\x20by\x20Descriptor::CopyTo())\x20will\x20never\x20have\x20Uninterp\
+    retedOptions\n\x20in\x20them.\n\n\x0b\n\x03\x05\x11\x01\x12\x04\xf9\x04\
+    \x08\x1b\n\xcb\x02\n\x04\x05\x11\x03\0\x12\x06\xff\x04\x02\x82\x05\x03\
+    \x1a\xba\x02\x20The\x20name\x20of\x20the\x20uninterpreted\x20option.\x20\
+    \x20Each\x20string\x20represents\x20a\x20segment\x20in\n\x20a\x20dot-sep\
+    arated\x20name.\x20\x20is_extension\x20is\x20true\x20iff\x20a\x20segment\
+    \x20represents\x20an\n\x20extension\x20(denoted\x20with\x20parentheses\
+    \x20in\x20options\x20specs\x20in\x20.proto\x20files).\n\x20E.g.,{\x20[\"\
+    foo\",\x20false],\x20[\"bar.baz\",\x20true],\x20[\"qux\",\x20false]\x20}\
+    \x20represents\n\x20\"foo.(bar.baz).qux\".\n\n\r\n\x05\x05\x11\x03\0\x01\
+    \x12\x04\xff\x04\n\x12\n\x0e\n\x06\x05\x11\x03\0\x02\0\x12\x04\x80\x05\
+    \x04\"\n\x0f\n\x07\x05\x11\x03\0\x02\0\x04\x12\x04\x80\x05\x04\x0c\n\x0f\
+    \n\x07\x05\x11\x03\0\x02\0\x05\x12\x04\x80\x05\r\x13\n\x0f\n\x07\x05\x11\
+    \x03\0\x02\0\x01\x12\x04\x80\x05\x14\x1d\n\x0f\n\x07\x05\x11\x03\0\x02\0\
+    \x03\x12\x04\x80\x05\x20!\n\x0e\n\x06\x05\x11\x03\0\x02\x01\x12\x04\x81\
+    \x05\x04#\n\x0f\n\x07\x05\x11\x03\0\x02\x01\x04\x12\x04\x81\x05\x04\x0c\
+    \n\x0f\n\x07\x05\x11\x03\0\x02\x01\x05\x12\x04\x81\x05\r\x11\n\x0f\n\x07\
+    \x05\x11\x03\0\x02\x01\x01\x12\x04\x81\x05\x12\x1e\n\x0f\n\x07\x05\x11\
+    \x03\0\x02\x01\x03\x12\x04\x81\x05!\"\n\x0c\n\x04\x05\x11\x02\0\x12\x04\
+    \x83\x05\x02\x1d\n\r\n\x05\x05\x11\x02\0\x04\x12\x04\x83\x05\x02\n\n\r\n\
+    \x05\x05\x11\x02\0\x06\x12\x04\x83\x05\x0b\x13\n\r\n\x05\x05\x11\x02\0\
+    \x01\x12\x04\x83\x05\x14\x18\n\r\n\x05\x05\x11\x02\0\x03\x12\x04\x83\x05\
+    \x1b\x1c\n\x9c\x01\n\x04\x05\x11\x02\x01\x12\x04\x87\x05\x02'\x1a\x8d\
+    \x01\x20The\x20value\x20of\x20the\x20uninterpreted\x20option,\x20in\x20w\
+    hatever\x20type\x20the\x20tokenizer\n\x20identified\x20it\x20as\x20durin\
+    g\x20parsing.\x20Exactly\x20one\x20of\x20these\x20should\x20be\x20set.\n\
+    \n\r\n\x05\x05\x11\x02\x01\x04\x12\x04\x87\x05\x02\n\n\r\n\x05\x05\x11\
+    \x02\x01\x05\x12\x04\x87\x05\x0b\x11\n\r\n\x05\x05\x11\x02\x01\x01\x12\
+    \x04\x87\x05\x12\"\n\r\n\x05\x05\x11\x02\x01\x03\x12\x04\x87\x05%&\n\x0c\
+    \n\x04\x05\x11\x02\x02\x12\x04\x88\x05\x02)\n\r\n\x05\x05\x11\x02\x02\
+    \x04\x12\x04\x88\x05\x02\n\n\r\n\x05\x05\x11\x02\x02\x05\x12\x04\x88\x05\
+    \x0b\x11\n\r\n\x05\x05\x11\x02\x02\x01\x12\x04\x88\x05\x12$\n\r\n\x05\
+    \x05\x11\x02\x02\x03\x12\x04\x88\x05'(\n\x0c\n\x04\x05\x11\x02\x03\x12\
+    \x04\x89\x05\x02(\n\r\n\x05\x05\x11\x02\x03\x04\x12\x04\x89\x05\x02\n\n\
+    \r\n\x05\x05\x11\x02\x03\x05\x12\x04\x89\x05\x0b\x10\n\r\n\x05\x05\x11\
+    \x02\x03\x01\x12\x04\x89\x05\x11#\n\r\n\x05\x05\x11\x02\x03\x03\x12\x04\
+    \x89\x05&'\n\x0c\n\x04\x05\x11\x02\x04\x12\x04\x8a\x05\x02#\n\r\n\x05\
+    \x05\x11\x02\x04\x04\x12\x04\x8a\x05\x02\n\n\r\n\x05\x05\x11\x02\x04\x05\
+    \x12\x04\x8a\x05\x0b\x11\n\r\n\x05\x05\x11\x02\x04\x01\x12\x04\x8a\x05\
+    \x12\x1e\n\r\n\x05\x05\x11\x02\x04\x03\x12\x04\x8a\x05!\"\n\x0c\n\x04\
+    \x05\x11\x02\x05\x12\x04\x8b\x05\x02\"\n\r\n\x05\x05\x11\x02\x05\x04\x12\
+    \x04\x8b\x05\x02\n\n\r\n\x05\x05\x11\x02\x05\x05\x12\x04\x8b\x05\x0b\x10\
+    \n\r\n\x05\x05\x11\x02\x05\x01\x12\x04\x8b\x05\x11\x1d\n\r\n\x05\x05\x11\
+    \x02\x05\x03\x12\x04\x8b\x05\x20!\n\x0c\n\x04\x05\x11\x02\x06\x12\x04\
+    \x8c\x05\x02&\n\r\n\x05\x05\x11\x02\x06\x04\x12\x04\x8c\x05\x02\n\n\r\n\
+    \x05\x05\x11\x02\x06\x05\x12\x04\x8c\x05\x0b\x11\n\r\n\x05\x05\x11\x02\
+    \x06\x01\x12\x04\x8c\x05\x12!\n\r\n\x05\x05\x11\x02\x06\x03\x12\x04\x8c\
+    \x05$%\n\xda\x01\n\x02\x05\x12\x12\x06\x94\x05\0\x95\x06\x01\x1aj\x20Enc\
+    apsulates\x20information\x20about\x20the\x20original\x20source\x20file\
+    \x20from\x20which\x20a\n\x20FileDescriptorProto\x20was\x20generated.\n2`\
+    \x20===================================================================\
+    \n\x20Optional\x20source\x20code\x20info\n\n\x0b\n\x03\x05\x12\x01\x12\
+    \x04\x94\x05\x08\x16\n\x82\x11\n\x04\x05\x12\x02\0\x12\x04\xc0\x05\x02!\
+    \x1a\xf3\x10\x20A\x20Location\x20identifies\x20a\x20piece\x20of\x20sourc\
+    e\x20code\x20in\x20a\x20.proto\x20file\x20which\n\x20corresponds\x20to\
+    \x20a\x20particular\x20definition.\x20\x20This\x20information\x20is\x20i\
+    ntended\n\x20to\x20be\x20useful\x20to\x20IDEs,\x20code\x20indexers,\x20d\
+    ocumentation\x20generators,\x20and\x20similar\n\x20tools.\n\n\x20For\x20\
+    example,\x20say\x20we\x20have\x20a\x20file\x20like:\n\x20\x20\x20message\
+    \x20Foo\x20{\n\x20\x20\x20\x20\x20optional\x20string\x20foo\x20=\x201;\n\
+    \x20\x20\x20}\n\x20Let's\x20look\x20at\x20just\x20the\x20field\x20defini\
+    tion:\n\x20\x20\x20optional\x20string\x20foo\x20=\x201;\n\x20\x20\x20^\
+    \x20\x20\x20\x20\x20\x20\x20^^\x20\x20\x20\x20\x20^^\x20\x20^\x20\x20^^^\
+    \n\x20\x20\x20a\x20\x20\x20\x20\x20\x20\x20bc\x20\x20\x20\x20\x20de\x20\
+    \x20f\x20\x20ghi\n\x20We\x20have\x20the\x20following\x20locations:\n\x20\
+    \x20\x20span\x20\x20\x20path\x20\x20\x20\x20\x20\x20\x20\x20\x20\x20\x20\
+    \x20\x20\x20\x20represents\n\x20\x20\x20[a,i)\x20\x20[\x204,\x200,\x202,\
+    \x200\x20]\x20\x20\x20\x20\x20The\x20whole\x20field\x20definition.\n\x20\
+    \x20\x20[a,b)\x20\x20[\x204,\x200,\x202,\x200,\x204\x20]\x20\x20The\x20l\
+    abel\x20(optional).\n\x20\x20\x20[c,d)\x20\x20[\x204,\x200,\x202,\x200,\
+    \x205\x20]\x20\x20The\x20type\x20(string).\n\x20\x20\x20[e,f)\x20\x20[\
+    \x204,\x200,\x202,\x200,\x201\x20]\x20\x20The\x20name\x20(foo).\n\x20\
+    \x20\x20[g,h)\x20\x20[\x204,\x200,\x202,\x200,\x203\x20]\x20\x20The\x20n\
+    umber\x20(1).\n\n\x20Notes:\n\x20-\x20A\x20location\x20may\x20refer\x20t\
+    o\x20a\x20repeated\x20field\x20itself\x20(i.e.\x20not\x20to\x20any\n\x20\
+    \x20\x20particular\x20index\x20within\x20it).\x20\x20This\x20is\x20used\
+    \x20whenever\x20a\x20set\x20of\x20elements\x20are\n\x20\x20\x20logically\
+    \x20enclosed\x20in\x20a\x20single\x20code\x20segment.\x20\x20For\x20exam\
+    ple,\x20an\x20entire\n\x20\x20\x20extend\x20block\x20(possibly\x20contai\
+    ning\x20multiple\x20extension\x20definitions)\x20will\n\x20\x20\x20have\
+    \x20an\x20outer\x20location\x20whose\x20path\x20refers\x20to\x20the\x20\
+    \"extensions\"\x20repeated\n\x20\x20\x20field\x20without\x20an\x20index.\
+    \n\x20-\x20Multiple\x20locations\x20may\x20have\x20the\x20same\x20path.\
+    \x20\x20This\x20happens\x20when\x20a\x20single\n\x20\x20\x20logical\x20d\
+    eclaration\x20is\x20spread\x20out\x20across\x20multiple\x20places.\x20\
+    \x20The\x20most\n\x20\x20\x20obvious\x20example\x20is\x20the\x20\"extend\
+    \"\x20block\x20again\x20--\x20there\x20may\x20be\x20multiple\n\x20\x20\
+    \x20extend\x20blocks\x20in\x20the\x20same\x20scope,\x20each\x20of\x20whi\
+    ch\x20will\x20have\x20the\x20same\x20path.\n\x20-\x20A\x20location's\x20\
+    span\x20is\x20not\x20always\x20a\x20subset\x20of\x20its\x20parent's\x20s\
+    pan.\x20\x20For\n\x20\x20\x20example,\x20the\x20\"extendee\"\x20of\x20an\
+    \x20extension\x20declaration\x20appears\x20at\x20the\n\x20\x20\x20beginn\
+    ing\x20of\x20the\x20\"extend\"\x20block\x20and\x20is\x20shared\x20by\x20\
+    all\x20extensions\x20within\n\x20\x20\x20the\x20block.\n\x20-\x20Just\
+    \x20because\x20a\x20location's\x20span\x20is\x20a\x20subset\x20of\x20som\
+    e\x20other\x20location's\x20span\n\x20\x20\x20does\x20not\x20mean\x20tha\
+    t\x20it\x20is\x20a\x20descendent.\x20\x20For\x20example,\x20a\x20\"group\
+    \"\x20defines\n\x20\x20\x20both\x20a\x20type\x20and\x20a\x20field\x20in\
+    \x20a\x20single\x20declaration.\x20\x20Thus,\x20the\x20locations\n\x20\
+    \x20\x20corresponding\x20to\x20the\x20type\x20and\x20field\x20and\x20the\
+    ir\x20components\x20will\x20overlap.\n\x20-\x20Code\x20which\x20tries\
+    \x20to\x20interpret\x20locations\x20should\x20probably\x20be\x20designed\
+    \x20to\n\x20\x20\x20ignore\x20those\x20that\x20it\x20doesn't\x20understa\
+    nd,\x20as\x20more\x20types\x20of\x20locations\x20could\n\x20\x20\x20be\
+    \x20recorded\x20in\x20the\x20future.\n\n\r\n\x05\x05\x12\x02\0\x04\x12\
+    \x04\xc0\x05\x02\n\n\r\n\x05\x05\x12\x02\0\x06\x12\x04\xc0\x05\x0b\x13\n\
+    \r\n\x05\x05\x12\x02\0\x01\x12\x04\xc0\x05\x14\x1c\n\r\n\x05\x05\x12\x02\
+    \0\x03\x12\x04\xc0\x05\x1f\x20\n\x0e\n\x04\x05\x12\x03\0\x12\x06\xc1\x05\
+    \x02\x94\x06\x03\n\r\n\x05\x05\x12\x03\0\x01\x12\x04\xc1\x05\n\x12\n\x83\
+    \x07\n\x06\x05\x12\x03\0\x02\0\x12\x04\xd9\x05\x04*\x1a\xf2\x06\x20Ident\
+    ifies\x20which\x20part\x20of\x20the\x20FileDescriptorProto\x20was\x20def\
+    ined\x20at\x20this\n\x20location.\n\n\x20Each\x20element\x20is\x20a\x20f\
+    ield\x20number\x20or\x20an\x20index.\x20\x20They\x20form\x20a\x20path\
+    \x20from\n\x20the\x20root\x20FileDescriptorProto\x20to\x20the\x20place\
+    \x20where\x20the\x20definition.\x20\x20For\n\x20example,\x20this\x20path\
+    :\n\x20\x20\x20[\x204,\x203,\x202,\x207,\x201\x20]\n\x20refers\x20to:\n\
+    \x20\x20\x20file.message_type(3)\x20\x20//\x204,\x203\n\x20\x20\x20\x20\
+    \x20\x20\x20.field(7)\x20\x20\x20\x20\x20\x20\x20\x20\x20//\x202,\x207\n\
+    \x20\x20\x20\x20\x20\x20\x20.name()\x20\x20\x20\x20\x20\x20\x20\x20\x20\
+    \x20\x20//\x201\n\x20This\x20is\x20because\x20FileDescriptorProto.messag\
+    e_type\x20has\x20field\x20number\x204:\n\x20\x20\x20repeated\x20Descript\
+    orProto\x20message_type\x20=\x204;\n\x20and\x20DescriptorProto.field\x20\
+    has\x20field\x20number\x202:\n\x20\x20\x20repeated\x20FieldDescriptorPro\
+    to\x20field\x20=\x202;\n\x20and\x20FieldDescriptorProto.name\x20has\x20f\
+    ield\x20number\x201:\n\x20\x20\x20optional\x20string\x20name\x20=\x201;\
+    \n\n\x20Thus,\x20the\x20above\x20path\x20gives\x20the\x20location\x20of\
+    \x20a\x20field\x20name.\x20\x20If\x20we\x20removed\n\x20the\x20last\x20e\
+    lement:\n\x20\x20\x20[\x204,\x203,\x202,\x207\x20]\n\x20this\x20path\x20\
+    refers\x20to\x20the\x20whole\x20field\x20declaration\x20(from\x20the\x20\
+    beginning\n\x20of\x20the\x20label\x20to\x20the\x20terminating\x20semicol\
+    on).\n\n\x0f\n\x07\x05\x12\x03\0\x02\0\x04\x12\x04\xd9\x05\x04\x0c\n\x0f\
+    \n\x07\x05\x12\x03\0\x02\0\x05\x12\x04\xd9\x05\r\x12\n\x0f\n\x07\x05\x12\
+    \x03\0\x02\0\x01\x12\x04\xd9\x05\x13\x17\n\x0f\n\x07\x05\x12\x03\0\x02\0\
+    \x03\x12\x04\xd9\x05\x1a\x1b\n\x0f\n\x07\x05\x12\x03\0\x02\0\x08\x12\x04\
+    \xd9\x05\x1c)\n\x12\n\n\x05\x12\x03\0\x02\0\x08\xe7\x07\0\x12\x04\xd9\
+    \x05\x1d(\n\x13\n\x0b\x05\x12\x03\0\x02\0\x08\xe7\x07\0\x02\x12\x04\xd9\
+    \x05\x1d#\n\x14\n\x0c\x05\x12\x03\0\x02\0\x08\xe7\x07\0\x02\0\x12\x04\
+    \xd9\x05\x1d#\n\x15\n\r\x05\x12\x03\0\x02\0\x08\xe7\x07\0\x02\0\x01\x12\
+    \x04\xd9\x05\x1d#\n\x13\n\x0b\x05\x12\x03\0\x02\0\x08\xe7\x07\0\x03\x12\
+    \x04\xd9\x05$(\n\xd2\x02\n\x06\x05\x12\x03\0\x02\x01\x12\x04\xe0\x05\x04\
+    *\x1a\xc1\x02\x20Always\x20has\x20exactly\x20three\x20or\x20four\x20elem\
+    ents:\x20start\x20line,\x20start\x20column,\n\x20end\x20line\x20(optiona\
+    l,\x20otherwise\x20assumed\x20same\x20as\x20start\x20line),\x20end\x20co\
+    lumn.\n\x20These\x20are\x20packed\x20into\x20a\x20single\x20field\x20for\
+    \x20efficiency.\x20\x20Note\x20that\x20line\n\x20and\x20column\x20number\
+    s\x20are\x20zero-based\x20--\x20typically\x20you\x20will\x20want\x20to\
+    \x20add\n\x201\x20to\x20each\x20before\x20displaying\x20to\x20a\x20user.\
+    \n\n\x0f\n\x07\x05\x12\x03\0\x02\x01\x04\x12\x04\xe0\x05\x04\x0c\n\x0f\n\
+    \x07\x05\x12\x03\0\x02\x01\x05\x12\x04\xe0\x05\r\x12\n\x0f\n\x07\x05\x12\
+    \x03\0\x02\x01\x01\x12\x04\xe0\x05\x13\x17\n\x0f\n\x07\x05\x12\x03\0\x02\
+    \x01\x03\x12\x04\xe0\x05\x1a\x1b\n\x0f\n\x07\x05\x12\x03\0\x02\x01\x08\
+    \x12\x04\xe0\x05\x1c)\n\x12\n\n\x05\x12\x03\0\x02\x01\x08\xe7\x07\0\x12\
+    \x04\xe0\x05\x1d(\n\x13\n\x0b\x05\x12\x03\0\x02\x01\x08\xe7\x07\0\x02\
+    \x12\x04\xe0\x05\x1d#\n\x14\n\x0c\x05\x12\x03\0\x02\x01\x08\xe7\x07\0\
+    \x02\0\x12\x04\xe0\x05\x1d#\n\x15\n\r\x05\x12\x03\0\x02\x01\x08\xe7\x07\
+    \0\x02\0\x01\x12\x04\xe0\x05\x1d#\n\x13\n\x0b\x05\x12\x03\0\x02\x01\x08\
+    \xe7\x07\0\x03\x12\x04\xe0\x05$(\n\xa5\x0c\n\x06\x05\x12\x03\0\x02\x02\
+    \x12\x04\x91\x06\x04)\x1a\x94\x0c\x20If\x20this\x20SourceCodeInfo\x20rep\
+    resents\x20a\x20complete\x20declaration,\x20these\x20are\x20any\n\x20com\
+    ments\x20appearing\x20before\x20and\x20after\x20the\x20declaration\x20wh\
+    ich\x20appear\x20to\x20be\n\x20attached\x20to\x20the\x20declaration.\n\n\
+    \x20A\x20series\x20of\x20line\x20comments\x20appearing\x20on\x20consecut\
+    ive\x20lines,\x20with\x20no\x20other\n\x20tokens\x20appearing\x20on\x20t\
+    hose\x20lines,\x20will\x20be\x20treated\x20as\x20a\x20single\x20comment.\
+    \n\n\x20leading_detached_comments\x20will\x20keep\x20paragraphs\x20of\
+    \x20comments\x20that\x20appear\n\x20before\x20(but\x20not\x20connected\
+    \x20to)\x20the\x20current\x20element.\x20Each\x20paragraph,\n\x20separat\
+    ed\x20by\x20empty\x20lines,\x20will\x20be\x20one\x20comment\x20element\
+    \x20in\x20the\x20repeated\n\x20field.\n\n\x20Only\x20the\x20comment\x20c\
+    ontent\x20is\x20provided;\x20comment\x20markers\x20(e.g.\x20//)\x20are\n\
+    \x20stripped\x20out.\x20\x20For\x20block\x20comments,\x20leading\x20whit\
+    espace\x20and\x20an\x20asterisk\n\x20will\x20be\x20stripped\x20from\x20t\
+    he\x20beginning\x20of\x20each\x20line\x20other\x20than\x20the\x20first.\
+    \n\x20Newlines\x20are\x20included\x20in\x20the\x20output.\n\n\x20Example\
+    s:\n\n\x20\x20\x20optional\x20int32\x20foo\x20=\x201;\x20\x20//\x20Comme\
+    nt\x20attached\x20to\x20foo.\n\x20\x20\x20//\x20Comment\x20attached\x20t\
+    o\x20bar.\n\x20\x20\x20optional\x20int32\x20bar\x20=\x202;\n\n\x20\x20\
+    \x20optional\x20string\x20baz\x20=\x203;\n\x20\x20\x20//\x20Comment\x20a\
+    ttached\x20to\x20baz.\n\x20\x20\x20//\x20Another\x20line\x20attached\x20\
+    to\x20baz.\n\n\x20\x20\x20//\x20Comment\x20attached\x20to\x20qux.\n\x20\
+    \x20\x20//\n\x20\x20\x20//\x20Another\x20line\x20attached\x20to\x20qux.\
+    \n\x20\x20\x20optional\x20double\x20qux\x20=\x204;\n\n\x20\x20\x20//\x20\
+    Detached\x20comment\x20for\x20corge.\x20This\x20is\x20not\x20leading\x20\
+    or\x20trailing\x20comments\n\x20\x20\x20//\x20to\x20qux\x20or\x20corge\
+    \x20because\x20there\x20are\x20blank\x20lines\x20separating\x20it\x20fro\
+    m\n\x20\x20\x20//\x20both.\n\n\x20\x20\x20//\x20Detached\x20comment\x20f\
+    or\x20corge\x20paragraph\x202.\n\n\x20\x20\x20optional\x20string\x20corg\
+    e\x20=\x205;\n\x20\x20\x20/*\x20Block\x20comment\x20attached\n\x20\x20\
+    \x20\x20*\x20to\x20corge.\x20\x20Leading\x20asterisks\n\x20\x20\x20\x20*\
+    \x20will\x20be\x20removed.\x20*/\n\x20\x20\x20/*\x20Block\x20comment\x20\
+    attached\x20to\n\x20\x20\x20\x20*\x20grault.\x20*/\n\x20\x20\x20optional\
+    \x20int32\x20grault\x20=\x206;\n\n\x20\x20\x20//\x20ignored\x20detached\
+    \x20comments.\n\n\x0f\n\x07\x05\x12\x03\0\x02\x02\x04\x12\x04\x91\x06\
+    \x04\x0c\n\x0f\n\x07\x05\x12\x03\0\x02\x02\x05\x12\x04\x91\x06\r\x13\n\
+    \x0f\n\x07\x05\x12\x03\0\x02\x02\x01\x12\x04\x91\x06\x14$\n\x0f\n\x07\
+    \x05\x12\x03\0\x02\x02\x03\x12\x04\x91\x06'(\n\x0e\n\x06\x05\x12\x03\0\
+    \x02\x03\x12\x04\x92\x06\x04*\n\x0f\n\x07\x05\x12\x03\0\x02\x03\x04\x12\
+    \x04\x92\x06\x04\x0c\n\x0f\n\x07\x05\x12\x03\0\x02\x03\x05\x12\x04\x92\
+    \x06\r\x13\n\x0f\n\x07\x05\x12\x03\0\x02\x03\x01\x12\x04\x92\x06\x14%\n\
+    \x0f\n\x07\x05\x12\x03\0\x02\x03\x03\x12\x04\x92\x06()\n\x0e\n\x06\x05\
+    \x12\x03\0\x02\x04\x12\x04\x93\x06\x042\n\x0f\n\x07\x05\x12\x03\0\x02\
+    \x04\x04\x12\x04\x93\x06\x04\x0c\n\x0f\n\x07\x05\x12\x03\0\x02\x04\x05\
+    \x12\x04\x93\x06\r\x13\n\x0f\n\x07\x05\x12\x03\0\x02\x04\x01\x12\x04\x93\
+    \x06\x14-\n\x0f\n\x07\x05\x12\x03\0\x02\x04\x03\x12\x04\x93\x0601\n\xee\
+    \x01\n\x02\x05\x13\x12\x06\x9a\x06\0\xaf\x06\x01\x1a\xdf\x01\x20Describe\
+    s\x20the\x20relationship\x20between\x20generated\x20code\x20and\x20its\
+    \x20original\x20source\n\x20file.\x20A\x20GeneratedCodeInfo\x20message\
+    \x20is\x20associated\x20with\x20only\x20one\x20generated\n\x20source\x20\
+    file,\x20but\x20may\x20contain\x20references\x20to\x20different\x20sourc\
+    e\x20.proto\x20files.\n\n\x0b\n\x03\x05\x13\x01\x12\x04\x9a\x06\x08\x19\
+    \nx\n\x04\x05\x13\x02\0\x12\x04\x9d\x06\x02%\x1aj\x20An\x20Annotation\
+    \x20connects\x20some\x20span\x20of\x20text\x20in\x20generated\x20code\
+    \x20to\x20an\x20element\n\x20of\x20its\x20generating\x20.proto\x20file.\
+    \n\n\r\n\x05\x05\x13\x02\0\x04\x12\x04\x9d\x06\x02\n\n\r\n\x05\x05\x13\
+    \x02\0\x06\x12\x04\x9d\x06\x0b\x15\n\r\n\x05\x05\x13\x02\0\x01\x12\x04\
+    \x9d\x06\x16\x20\n\r\n\x05\x05\x13\x02\0\x03\x12\x04\x9d\x06#$\n\x0e\n\
+    \x04\x05\x13\x03\0\x12\x06\x9e\x06\x02\xae\x06\x03\n\r\n\x05\x05\x13\x03\
+    \0\x01\x12\x04\x9e\x06\n\x14\n\x8f\x01\n\x06\x05\x13\x03\0\x02\0\x12\x04\
+    \xa1\x06\x04*\x1a\x7f\x20Identifies\x20the\x20element\x20in\x20the\x20or\
+    iginal\x20source\x20.proto\x20file.\x20This\x20field\n\x20is\x20formatte\
+    d\x20the\x20same\x20as\x20SourceCodeInfo.Location.path.\n\n\x0f\n\x07\
+    \x05\x13\x03\0\x02\0\x04\x12\x04\xa1\x06\x04\x0c\n\x0f\n\x07\x05\x13\x03\
+    \0\x02\0\x05\x12\x04\xa1\x06\r\x12\n\x0f\n\x07\x05\x13\x03\0\x02\0\x01\
+    \x12\x04\xa1\x06\x13\x17\n\x0f\n\x07\x05\x13\x03\0\x02\0\x03\x12\x04\xa1\
+    \x06\x1a\x1b\n\x0f\n\x07\x05\x13\x03\0\x02\0\x08\x12\x04\xa1\x06\x1c)\n\
+    \x12\n\n\x05\x13\x03\0\x02\0\x08\xe7\x07\0\x12\x04\xa1\x06\x1d(\n\x13\n\
+    \x0b\x05\x13\x03\0\x02\0\x08\xe7\x07\0\x02\x12\x04\xa1\x06\x1d#\n\x14\n\
+    \x0c\x05\x13\x03\0\x02\0\x08\xe7\x07\0\x02\0\x12\x04\xa1\x06\x1d#\n\x15\
+    \n\r\x05\x13\x03\0\x02\0\x08\xe7\x07\0\x02\0\x01\x12\x04\xa1\x06\x1d#\n\
+    \x13\n\x0b\x05\x13\x03\0\x02\0\x08\xe7\x07\0\x03\x12\x04\xa1\x06$(\nO\n\
+    \x06\x05\x13\x03\0\x02\x01\x12\x04\xa4\x06\x04$\x1a?\x20Identifies\x20th\
+    e\x20filesystem\x20path\x20to\x20the\x20original\x20source\x20.proto.\n\
+    \n\x0f\n\x07\x05\x13\x03\0\x02\x01\x04\x12\x04\xa4\x06\x04\x0c\n\x0f\n\
+    \x07\x05\x13\x03\0\x02\x01\x05\x12\x04\xa4\x06\r\x13\n\x0f\n\x07\x05\x13\
+    \x03\0\x02\x01\x01\x12\x04\xa4\x06\x14\x1f\n\x0f\n\x07\x05\x13\x03\0\x02\
+    \x01\x03\x12\x04\xa4\x06\"#\nw\n\x06\x05\x13\x03\0\x02\x02\x12\x04\xa8\
+    \x06\x04\x1d\x1ag\x20Identifies\x20the\x20starting\x20offset\x20in\x20by\
+    tes\x20in\x20the\x20generated\x20code\n\x20that\x20relates\x20to\x20the\
+    \x20identified\x20object.\n\n\x0f\n\x07\x05\x13\x03\0\x02\x02\x04\x12\
+    \x04\xa8\x06\x04\x0c\n\x0f\n\x07\x05\x13\x03\0\x02\x02\x05\x12\x04\xa8\
+    \x06\r\x12\n\x0f\n\x07\x05\x13\x03\0\x02\x02\x01\x12\x04\xa8\x06\x13\x18\
+    \n\x0f\n\x07\x05\x13\x03\0\x02\x02\x03\x12\x04\xa8\x06\x1b\x1c\n\xdb\x01\
+    \n\x06\x05\x13\x03\0\x02\x03\x12\x04\xad\x06\x04\x1b\x1a\xca\x01\x20Iden\
+    tifies\x20the\x20ending\x20offset\x20in\x20bytes\x20in\x20the\x20generat\
+    ed\x20code\x20that\n\x20relates\x20to\x20the\x20identified\x20offset.\
+    \x20The\x20end\x20offset\x20should\x20be\x20one\x20past\n\x20the\x20last\
+    \x20relevant\x20byte\x20(so\x20the\x20length\x20of\x20the\x20text\x20=\
+    \x20end\x20-\x20begin).\n\n\x0f\n\x07\x05\x13\x03\0\x02\x03\x04\x12\x04\
+    \xad\x06\x04\x0c\n\x0f\n\x07\x05\x13\x03\0\x02\x03\x05\x12\x04\xad\x06\r\
+    \x12\n\x0f\n\x07\x05\x13\x03\0\x02\x03\x01\x12\x04\xad\x06\x13\x16\n\x0f\
+    \n\x07\x05\x13\x03\0\x02\x03\x03\x12\x04\xad\x06\x19\x1a\
 ";
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
@@ -384,11 +384,15 @@ impl<'a> MessageWithScope<'a> {
                     index: index as u32,
                 }
             })
+            .filter(|oneof| !oneof.is_synthetic())
             .collect()
     }
 
     pub fn oneof_by_index(&'a self, index: u32) -> OneofWithContext<'a> {
-        self.oneofs().swap_remove(index as usize)
+        self.oneofs()
+            .into_iter()
+            .find(|oneof| oneof.index == index)
+            .expect("oneof not found")
     }
 
     /// Pair of (key, value) if this message is map entry
@@ -499,8 +503,9 @@ pub struct FieldWithContext<'a> {
 }
 
 impl<'a> FieldWithContext<'a> {
+    // proto3 `optional` field belongs to synthetic oneof, but is not generated as oneof
     fn is_oneof(&self) -> bool {
-        self.field.has_oneof_index()
+        self.field.has_oneof_index() && !self.field.get_proto3_optional()
     }
 
     pub fn oneof(&'a self) -> Option<OneofWithContext<'a>> {
@@ -557,6 +562,13 @@ pub struct OneofWithContext<'a> {
 }
 
 impl<'a> OneofWithContext<'a> {
+    /// Oneof generated by `protoc` for proto3 `optional` field
+    pub fn is_synthetic(&self) -> bool {
+        self.message.message.get_field().iter().any(|f| {
+            f.get_proto3_optional() && f.get_oneof_index() == self.index as i32
+        })
+    }
+
     pub fn name(&'a self) -> &'a str {
         match self.oneof.get_name() {
             "type" => "field_type",
@@ -0,0 +1,509 @@
+//! Field-level comparison of messages with reflection.
+//!
+//! ```ignore
+//! let diff = protobuf::diff::diff_messages(&expected, &actual);
+//! assert!(diff.is_empty(), "messages differ:\n{}", diff);
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use core::Message;
+use reflect::ProtobufValue;
+use reflect::ProtobufValueRef;
+use reflect::ReflectFieldRef;
+use text_format;
+use unknown::UnknownValueRef;
+
+
+/// Options for message comparison
+#[derive(Default, Debug, Clone)]
+pub struct DiffOptions {
+    /// `float` and `double` values are equal if they differ by no more than this value
+    pub float_tolerance: f64,
+    /// Paths of fields which are not compared, e. g. `message_type.field.options`.
+    ///
+    /// Paths contain field names only, without indices of repeated elements and map keys.
+    pub ignore_fields: Vec<String>,
+    /// Paths of repeated fields compared as unordered multisets
+    pub unordered_fields: Vec<String>,
+}
+
+/// How field value differs
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DiffKind {
+    /// Value is only present in right message
+    Added,
+    /// Value is only present in left message
+    Removed,
+    /// Values are present in both messages but not equal
+    Changed,
+}
+
+/// Single difference between two messages
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldDiff {
+    /// Path to value like `message_type[1].field[0].name`, `fields["key"]`;
+    /// unknown fields are identified by field number
+    pub path: String,
+    pub kind: DiffKind,
+    /// Value in left message, in text format
+    pub left: Option<String>,
+    /// Value in right message, in text format
+    pub right: Option<String>,
+}
+
+impl fmt::Display for FieldDiff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (&self.left, &self.right) {
+            (&Some(ref left), &Some(ref right)) => {
+                write!(f, "{}: {} -> {}", self.path, left, right)
+            }
+            (&Some(ref left), &None) => write!(f, "{}: removed {}", self.path, left),
+            (&None, &Some(ref right)) => write!(f, "{}: added {}", self.path, right),
+            (&None, &None) => write!(f, "{}", self.path),
+        }
+    }
+}
+
+/// Differences between two messages, in field order
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct MessageDiff {
+    pub diffs: Vec<FieldDiff>,
+}
+
+impl MessageDiff {
+    /// Messages are equal
+    pub fn is_empty(&self) -> bool {
+        self.diffs.is_empty()
+    }
+}
+
+/// One difference per line
+impl fmt::Display for MessageDiff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for d in &self.diffs {
+            writeln!(f, "{}", d)?;
+        }
+        Ok(())
+    }
+}
+
+
+fn value_to_string(value: &ProtobufValueRef) -> String {
+    match *value {
+        ProtobufValueRef::Message(..) | ProtobufValueRef::OwnedMessage(..) => {
+            format!("{{ {} }}", text_format::print_to_string(value.as_message().unwrap()))
+        }
+        ProtobufValueRef::Enum(e) => e.name().to_owned(),
+        ProtobufValueRef::UnknownEnum(_, v) => v.to_string(),
+        ProtobufValueRef::String(s) => text_format::quote_escape_bytes(s.as_bytes()),
+        ProtobufValueRef::Bytes(b) => text_format::quote_escape_bytes(b),
+        ProtobufValueRef::I32(v) => v.to_string(),
+        ProtobufValueRef::I64(v) => v.to_string(),
+        ProtobufValueRef::U32(v) => v.to_string(),
+        ProtobufValueRef::U64(v) => v.to_string(),
+        ProtobufValueRef::Bool(v) => v.to_string(),
+        ProtobufValueRef::F32(v) => v.to_string(),
+        ProtobufValueRef::F64(v) => v.to_string(),
+    }
+}
+
+fn unknown_value_to_string(value: UnknownValueRef) -> String {
+    match value {
+        UnknownValueRef::Fixed32(v) => format!("0x{:08x}", v),
+        UnknownValueRef::Fixed64(v) => format!("0x{:016x}", v),
+        UnknownValueRef::Varint(v) => v.to_string(),
+        UnknownValueRef::LengthDelimited(b) => text_format::quote_escape_bytes(b),
+    }
+}
+
+/// Path without indices and map keys, as specified in options
+fn strip_indices(path: &str) -> String {
+    let mut r = String::new();
+    let mut depth = 0;
+    let mut in_string = false;
+    let mut escape = false;
+    for c in path.chars() {
+        if in_string {
+            if escape {
+                escape = false;
+            } else if c == '\\' {
+                escape = true;
+            } else if c == '"' {
+                in_string = false;
+            }
+            continue;
+        }
+        match c {
+            '[' => depth += 1,
+            ']' => depth -= 1,
+            '"' if depth > 0 => in_string = true,
+            c if depth == 0 => r.push(c),
+            _ => {}
+        }
+    }
+    r
+}
+
+struct Differ<'o> {
+    options: &'o DiffOptions,
+    diffs: Vec<FieldDiff>,
+}
+
+impl<'o> Differ<'o> {
+    fn add(&mut self, path: String, kind: DiffKind, left: Option<String>, right: Option<String>) {
+        self.diffs.push(FieldDiff {
+            path: path,
+            kind: kind,
+            left: left,
+            right: right,
+        });
+    }
+
+    fn added(&mut self, path: String, right: &ProtobufValueRef) {
+        self.add(path, DiffKind::Added, None, Some(value_to_string(right)));
+    }
+
+    fn removed(&mut self, path: String, left: &ProtobufValueRef) {
+        self.add(path, DiffKind::Removed, Some(value_to_string(left)), None);
+    }
+
+    fn floats_equal(&self, a: f64, b: f64) -> bool {
+        a == b || (a.is_nan() && b.is_nan()) || (a - b).abs() <= self.options.float_tolerance
+    }
+
+    /// Values are equal without reporting differences
+    fn values_equal(&self, a: &ProtobufValueRef, b: &ProtobufValueRef, path: &str) -> bool {
+        let mut nested = Differ {
+            options: self.options,
+            diffs: Vec::new(),
+        };
+        nested.diff_values(a, b, path);
+        nested.diffs.is_empty()
+    }
+
+    fn diff_values(&mut self, a: &ProtobufValueRef, b: &ProtobufValueRef, path: &str) {
+        let equal = match (a, b) {
+            (a, b) if a.as_message().is_some() && b.as_message().is_some() => {
+                return self.diff_messages(a.as_message().unwrap(), b.as_message().unwrap(), path);
+            }
+            (&ProtobufValueRef::F32(a), &ProtobufValueRef::F32(b)) => {
+                self.floats_equal(a as f64, b as f64)
+            }
+            (&ProtobufValueRef::F64(a), &ProtobufValueRef::F64(b)) => self.floats_equal(a, b),
+            (&ProtobufValueRef::Enum(a), &ProtobufValueRef::Enum(b)) => a.value() == b.value(),
+            (a, b) => value_to_string(a) == value_to_string(b),
+        };
+        if !equal {
+            self.add(
+                path.to_owned(),
+                DiffKind::Changed,
+                Some(value_to_string(a)),
+                Some(value_to_string(b)),
+            );
+        }
+    }
+
+    fn diff_repeated_ordered(
+        &mut self,
+        a: &[ProtobufValueRef],
+        b: &[ProtobufValueRef],
+        path: &str,
+    ) {
+        for i in 0..a.len().max(b.len()) {
+            let elem_path = format!("{}[{}]", path, i);
+            match (a.get(i), b.get(i)) {
+                (Some(a), Some(b)) => self.diff_values(a, b, &elem_path),
+                (Some(a), None) => self.removed(elem_path, a),
+                (None, Some(b)) => self.added(elem_path, b),
+                (None, None) => unreachable!(),
+            }
+        }
+    }
+
+    fn diff_repeated_unordered(
+        &mut self,
+        a: &[ProtobufValueRef],
+        b: &[ProtobufValueRef],
+        path: &str,
+    ) {
+        let mut matched = vec![false; b.len()];
+        let mut removed = Vec::new();
+        for (i, a) in a.iter().enumerate() {
+            let found = (0..b.len()).find(|&j| !matched[j] && self.values_equal(a, &b[j], path));
+            match found {
+                Some(j) => matched[j] = true,
+                None => removed.push(i),
+            }
+        }
+        for i in removed {
+            self.removed(format!("{}[{}]", path, i), &a[i]);
+        }
+        for (j, b) in b.iter().enumerate() {
+            if !matched[j] {
+                self.added(format!("{}[{}]", path, j), b);
+            }
+        }
+    }
+
+    fn diff_maps<'a>(
+        &mut self,
+        a: Vec<(&'a ProtobufValue, &'a ProtobufValue)>,
+        b: Vec<(&'a ProtobufValue, &'a ProtobufValue)>,
+        path: &str,
+    ) {
+        // sort by key for stable output
+        let mut entries: BTreeMap<String, (Option<&ProtobufValue>, Option<&ProtobufValue>)> =
+            BTreeMap::new();
+        for (k, v) in a {
+            entries.entry(value_to_string(&k.as_ref())).or_insert((None, None)).0 = Some(v);
+        }
+        for (k, v) in b {
+            entries.entry(value_to_string(&k.as_ref())).or_insert((None, None)).1 = Some(v);
+        }
+        for (key, (a, b)) in entries {
+            let entry_path = format!("{}[{}]", path, key);
+            match (a, b) {
+                (Some(a), Some(b)) => self.diff_values(&a.as_ref(), &b.as_ref(), &entry_path),
+                (Some(a), None) => self.removed(entry_path, &a.as_ref()),
+                (None, Some(b)) => self.added(entry_path, &b.as_ref()),
+                (None, None) => unreachable!(),
+            }
+        }
+    }
+
+    fn diff_unknown_fields(&mut self, a: &Message, b: &Message, prefix: &str) {
+        let mut numbers: Vec<u32> = a.get_unknown_fields()
+            .iter()
+            .chain(b.get_unknown_fields().iter())
+            .map(|(number, _)| number)
+            .collect();
+        numbers.sort();
+        numbers.dedup();
+
+        for number in numbers {
+            let values = |m: &Message| -> Vec<String> {
+                match m.get_unknown_fields().get(number) {
+                    Some(values) => values.iter().map(unknown_value_to_string).collect(),
+                    None => Vec::new(),
+                }
+            };
+            let (a, b) = (values(a), values(b));
+            let path = format!("{}{}", prefix, number);
+            for i in 0..a.len().max(b.len()) {
+                let elem_path = format!("{}[{}]", path, i);
+                match (a.get(i), b.get(i)) {
+                    (Some(a), Some(b)) if a == b => {}
+                    (Some(a), Some(b)) => {
+                        self.add(elem_path, DiffKind::Changed, Some(a.clone()), Some(b.clone()))
+                    }
+                    (Some(a), None) => {
+                        self.add(elem_path, DiffKind::Removed, Some(a.clone()), None)
+                    }
+                    (None, Some(b)) => self.add(elem_path, DiffKind::Added, None, Some(b.clone())),
+                    (None, None) => unreachable!(),
+                }
+            }
+        }
+    }
+
+    /// Messages must be of the same type, nested messages are of the field type
+    fn diff_messages(&mut self, a: &Message, b: &Message, path: &str) {
+        let d = a.descriptor();
+        let prefix = if path.is_empty() { String::new() } else { format!("{}.", path) };
+
+        for f in d.fields() {
+            let field_path = format!("{}{}", prefix, f.name());
+            let stripped = strip_indices(&field_path);
+            if self.options.ignore_fields.contains(&stripped) {
+                continue;
+            }
+            match (f.get_reflect(a), f.get_reflect(b)) {
+                (ReflectFieldRef::Optional(a), ReflectFieldRef::Optional(b)) => match (a, b) {
+                    (Some(a), Some(b)) => self.diff_values(&a, &b, &field_path),
+                    (Some(a), None) => self.removed(field_path, &a),
+                    (None, Some(b)) => self.added(field_path, &b),
+                    (None, None) => {}
+                },
+                (ReflectFieldRef::Repeated(a), ReflectFieldRef::Repeated(b)) => {
+                    let a: Vec<ProtobufValueRef> = a.into_iter().map(|v| v.as_ref()).collect();
+                    let b: Vec<ProtobufValueRef> = b.into_iter().map(|v| v.as_ref()).collect();
+                    if self.options.unordered_fields.contains(&stripped) {
+                        self.diff_repeated_unordered(&a, &b, &field_path);
+                    } else {
+                        self.diff_repeated_ordered(&a, &b, &field_path);
+                    }
+                }
+                (ReflectFieldRef::Map(a), ReflectFieldRef::Map(b)) => {
+                    self.diff_maps(a.into_iter().collect(), b.into_iter().collect(), &field_path);
+                }
+                _ => unreachable!(),
+            }
+        }
+
+        self.diff_unknown_fields(a, b, &prefix);
+    }
+}
+
+
+/// Compare two messages of the same type field by field
+pub fn diff_messages<M : Message>(a: &M, b: &M) -> MessageDiff {
+    diff_messages_with_options(a, b, &DiffOptions::default())
+}
+
+/// Compare two messages of the same type field by field.
+///
+/// Panics if `M` is `DynamicMessage` and messages have different descriptors,
+/// use `try_diff_messages` to compare messages of types not known at compile time.
+pub fn diff_messages_with_options<M : Message>(
+    a: &M,
+    b: &M,
+    options: &DiffOptions,
+) -> MessageDiff {
+    match try_diff_messages(a, b, options) {
+        Some(diff) => diff,
+        None => panic!(
+            "cannot compare messages of different types: {} and {}",
+            a.descriptor().full_name(),
+            b.descriptor().full_name()
+        ),
+    }
+}
+
+/// Compare two messages field by field, `None` if messages have different types
+pub fn try_diff_messages(a: &Message, b: &Message, options: &DiffOptions) -> Option<MessageDiff> {
+    if a.descriptor().full_name() != b.descriptor().full_name() {
+        return None;
+    }
+    let mut differ = Differ {
+        options: options,
+        diffs: Vec::new(),
+    };
+    differ.diff_messages(a, b, "");
+    Some(MessageDiff { diffs: differ.diffs })
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use descriptor::DescriptorProto;
+    use descriptor::FieldDescriptorProto;
+    use descriptor::FieldDescriptorProto_Type;
+    use descriptor::FieldDescriptorProto_Type::TYPE_INT32;
+    use descriptor::FieldDescriptorProto_Type::TYPE_INT64;
+    use descriptor::FieldDescriptorProto_Type::TYPE_STRING;
+    use descriptor::FileDescriptorProto;
+    use well_known_types::Struct;
+    use well_known_types::Value;
+
+    fn file(fields: &[(&str, FieldDescriptorProto_Type)]) -> FileDescriptorProto {
+        let mut message = DescriptorProto::new();
+        message.set_name("M".to_owned());
+        for &(name, t) in fields {
+            let mut field = FieldDescriptorProto::new();
+            field.set_name(name.to_owned());
+            field.set_field_type(t);
+            message.mut_field().push(field);
+        }
+        let mut file = FileDescriptorProto::new();
+        file.set_name("a.proto".to_owned());
+        file.mut_message_type().push(message);
+        file
+    }
+
+    fn paths(diff: &MessageDiff) -> Vec<(&str, DiffKind)> {
+        diff.diffs.iter().map(|d| (&d.path[..], d.kind)).collect()
+    }
+
+    #[test]
+    fn test_diff() {
+        let a = file(&[("x", TYPE_INT32), ("y", TYPE_STRING)]);
+        assert!(diff_messages(&a, &a.clone()).is_empty());
+
+        let mut b = file(&[("x", TYPE_INT64)]);
+        b.set_name("b.proto".to_owned());
+        b.mut_message_type()[0].mut_field()[0].set_number(3);
+        b.mut_unknown_fields().add_varint(100, 1);
+
+        let diff = diff_messages(&a, &b);
+        assert_eq!(
+            vec![
+                ("name", DiffKind::Changed),
+                ("message_type[0].field[0].number", DiffKind::Added),
+                ("message_type[0].field[0].type", DiffKind::Changed),
+                ("message_type[0].field[1]", DiffKind::Removed),
+                ("100[0]", DiffKind::Added),
+            ],
+            paths(&diff)
+        );
+        assert_eq!("name: \"a.proto\" -> \"b.proto\"", diff.diffs[0].to_string());
+        assert_eq!(
+            "message_type[0].field[0].type: TYPE_INT32 -> TYPE_INT64",
+            diff.diffs[2].to_string()
+        );
+        assert_eq!("100[0]: added 1", diff.diffs[4].to_string());
+    }
+
+    #[test]
+    fn test_diff_options() {
+        let a = file(&[("x", TYPE_INT32), ("y", TYPE_STRING)]);
+        let mut b = file(&[("y", TYPE_STRING), ("x", TYPE_INT32)]);
+        b.mut_message_type()[0].mut_field()[1].set_json_name("xx".to_owned());
+
+        let mut options = DiffOptions::default();
+        assert_eq!(5, diff_messages_with_options(&a, &b, &options).diffs.len());
+
+        options.unordered_fields.push("message_type.field".to_owned());
+        assert_eq!(
+            vec![
+                ("message_type[0].field[0]", DiffKind::Removed),
+                ("message_type[0].field[1]", DiffKind::Added),
+            ],
+            paths(&diff_messages_with_options(&a, &b, &options))
+        );
+
+        options.ignore_fields.push("message_type.field.json_name".to_owned());
+        assert!(diff_messages_with_options(&a, &b, &options).is_empty());
+    }
+
+    #[test]
+    fn test_diff_map_and_float() {
+        let mut a = Struct::new();
+        let mut b = Struct::new();
+        let mut v = Value::new();
+        v.set_number_value(1.0);
+        a.mut_fields().insert("k".to_owned(), v.clone());
+        a.mut_fields().insert("removed".to_owned(), Value::new());
+        v.set_number_value(1.001);
+        b.mut_fields().insert("k".to_owned(), v);
+
+        let diff = diff_messages(&a, &b);
+        assert_eq!(
+            vec![
+                ("fields[\"k\"].number_value", DiffKind::Changed),
+                ("fields[\"removed\"]", DiffKind::Removed),
+            ],
+            paths(&diff)
+        );
+
+        let mut options = DiffOptions::default();
+        options.float_tolerance = 0.01;
+        assert_eq!(1, diff_messages_with_options(&a, &b, &options).diffs.len());
+    }
+
+    #[test]
+    fn test_try_diff_different_types() {
+        let a = file(&[]);
+        let b = Struct::new();
+        assert!(try_diff_messages(&a, &b, &DiffOptions::default()).is_none());
+        assert!(try_diff_messages(&a, &a, &DiffOptions::default()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_strip_indices() {
+        assert_eq!("a.b.c", strip_indices("a[1].b[\"x]\\\"\"].c"));
+    }
+}
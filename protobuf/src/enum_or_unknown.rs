@@ -0,0 +1,160 @@
+use std::fmt;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::marker;
+
+#[cfg(feature = "with-serde")]
+use serde;
+
+use core::ProtobufEnum;
+use reflect::ProtobufValue;
+use reflect::ProtobufValueRef;
+use reflect::EnumDescriptor;
+
+
+/// Enum value which may be unknown to this version of generated code.
+///
+/// Proto3 enums are open: a field may hold any `i32` number.
+/// This type keeps the number in the field itself,
+/// so unknown values survive get, set and serialization.
+pub struct EnumOrUnknown<E : ProtobufEnum> {
+    value: i32,
+    _marker: marker::PhantomData<E>,
+}
+
+impl<E : ProtobufEnum> EnumOrUnknown<E> {
+    /// Construct from known enum value.
+    pub fn new(e: E) -> EnumOrUnknown<E> {
+        EnumOrUnknown::from_i32(e.value())
+    }
+
+    /// Construct from any `i32` number, known or not.
+    pub fn from_i32(value: i32) -> EnumOrUnknown<E> {
+        EnumOrUnknown {
+            value: value,
+            _marker: marker::PhantomData,
+        }
+    }
+
+    /// Raw `i32` number.
+    pub fn value(&self) -> i32 {
+        self.value
+    }
+
+    /// Known enum value, or the number if it is unknown.
+    pub fn enum_value(&self) -> Result<E, i32> {
+        E::from_i32(self.value).ok_or(self.value)
+    }
+
+    /// Known enum value, or `default` if the number is unknown.
+    pub fn enum_value_or(&self, default: E) -> E {
+        self.enum_value().unwrap_or(default)
+    }
+
+    /// Descriptor of enum type.
+    pub fn enum_descriptor(&self) -> &'static EnumDescriptor {
+        E::enum_descriptor_static(None::<E>)
+    }
+}
+
+impl<E : ProtobufEnum + Default> EnumOrUnknown<E> {
+    /// Known enum value, or enum default if the number is unknown.
+    pub fn enum_value_or_default(&self) -> E {
+        self.enum_value().unwrap_or_default()
+    }
+}
+
+impl<E : ProtobufEnum> From<E> for EnumOrUnknown<E> {
+    fn from(e: E) -> EnumOrUnknown<E> {
+        EnumOrUnknown::new(e)
+    }
+}
+
+impl<E : ProtobufEnum + Default> Default for EnumOrUnknown<E> {
+    fn default() -> EnumOrUnknown<E> {
+        EnumOrUnknown::new(E::default())
+    }
+}
+
+// derives would require bounds on `E`
+
+impl<E : ProtobufEnum> Clone for EnumOrUnknown<E> {
+    fn clone(&self) -> EnumOrUnknown<E> {
+        *self
+    }
+}
+
+impl<E : ProtobufEnum> Copy for EnumOrUnknown<E> {}
+
+impl<E : ProtobufEnum> PartialEq for EnumOrUnknown<E> {
+    fn eq(&self, other: &EnumOrUnknown<E>) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<E : ProtobufEnum> Eq for EnumOrUnknown<E> {}
+
+impl<E : ProtobufEnum> Hash for EnumOrUnknown<E> {
+    fn hash<H : Hasher>(&self, state: &mut H) {
+        self.value.hash(state)
+    }
+}
+
+impl<E : ProtobufEnum + fmt::Debug> fmt::Debug for EnumOrUnknown<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.enum_value() {
+            Ok(e) => fmt::Debug::fmt(&e, f),
+            Err(v) => fmt::Debug::fmt(&v, f),
+        }
+    }
+}
+
+impl<E : ProtobufEnum> ProtobufValue for EnumOrUnknown<E> {
+    fn as_ref(&self) -> ProtobufValueRef {
+        match self.enum_value() {
+            Ok(e) => ProtobufValueRef::Enum(e.descriptor()),
+            Err(v) => ProtobufValueRef::UnknownEnum(self.enum_descriptor(), v),
+        }
+    }
+}
+
+#[cfg(feature = "with-serde")]
+impl<E : ProtobufEnum> serde::Serialize for EnumOrUnknown<E> {
+    fn serialize<S : serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.value.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "with-serde")]
+impl<'de, E : ProtobufEnum> serde::Deserialize<'de> for EnumOrUnknown<E> {
+    fn deserialize<D : serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        i32::deserialize(deserializer).map(EnumOrUnknown::from_i32)
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use descriptor::FieldDescriptorProto_Type;
+
+    #[test]
+    fn known_and_unknown() {
+        let known = EnumOrUnknown::new(FieldDescriptorProto_Type::TYPE_BOOL);
+        assert_eq!(8, known.value());
+        assert_eq!(Ok(FieldDescriptorProto_Type::TYPE_BOOL), known.enum_value());
+        assert_eq!("TYPE_BOOL", format!("{:?}", known));
+
+        let unknown = EnumOrUnknown::<FieldDescriptorProto_Type>::from_i32(100);
+        assert_eq!(Err(100), unknown.enum_value());
+        assert_eq!(
+            FieldDescriptorProto_Type::TYPE_INT32,
+            unknown.enum_value_or(FieldDescriptorProto_Type::TYPE_INT32));
+        assert_eq!("100", format!("{:?}", unknown));
+        match unknown.as_ref() {
+            ProtobufValueRef::UnknownEnum(d, 100) => assert_eq!("Type", d.name()),
+            _ => panic!("expecting unknown enum"),
+        }
+    }
+}
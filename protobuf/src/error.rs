@@ -19,6 +19,12 @@ pub enum WireError {
     Utf8Error,
     InvalidEnumValue(i32),
     OverRecursionLimit,
+    /// Length of length-delimited message exceeds the limit
+    MessageTooLarge(u64),
+    /// Length of `string`, `bytes` or packed field exceeds `ParseOptions::max_field_length`
+    FieldTooLarge(u64),
+    /// Number of elements of repeated or map field exceeds `ParseOptions::max_repeated_len`
+    TooManyElements(u64),
     Other,
 }
 
@@ -28,17 +34,144 @@ pub enum ProtobufError {
     WireError(WireError),
     Utf8(str::Utf8Error),
     MessageNotInitialized { message: &'static str },
+    /// Error while decoding binary input with location where decoding failed,
+    /// only returned if `ParseOptions::locate_errors` is set
+    Located { error: Box<ProtobufError>, location: ErrorLocation },
+    /// Required fields are not set; `missing_fields` are paths like `Outer.items[3].id`.
+    ///
+    /// Returned instead of `MessageNotInitialized` only if `ParseOptions::locate_errors` is set.
+    MissingFields { message: &'static str, missing_fields: Vec<String> },
 }
 
 impl ProtobufError {
     pub fn message_not_initialized(message: &'static str) -> ProtobufError {
         ProtobufError::MessageNotInitialized { message: message }
     }
+
+    /// Location in input if error occurred while decoding
+    pub fn location(&self) -> Option<&ErrorLocation> {
+        match *self {
+            ProtobufError::Located { ref location, .. } => Some(location),
+            _ => None,
+        }
+    }
+
+    /// Error without location
+    pub fn without_location(&self) -> &ProtobufError {
+        match *self {
+            ProtobufError::Located { ref error, .. } => error,
+            ref e => e,
+        }
+    }
+
+    /// Wire error, possibly with location stripped
+    pub fn wire_error(&self) -> Option<&WireError> {
+        match *self.without_location() {
+            ProtobufError::WireError(ref e) => Some(e),
+            _ => None,
+        }
+    }
+
+    /// Paths of required fields which are not set
+    pub fn missing_fields(&self) -> Option<&[String]> {
+        match *self.without_location() {
+            ProtobufError::MissingFields { ref missing_fields, .. } => Some(missing_fields),
+            _ => None,
+        }
+    }
+}
+
+/// Position in binary input where decoding failed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorLocation {
+    offset: u64,
+    field_number: Option<u32>,
+    // name of outermost message decoded so far
+    message: &'static str,
+    // field path segments, innermost first
+    fields: Vec<String>,
+    // index of repeated element to be added to the next outer segment
+    index: Option<usize>,
+}
+
+impl ErrorLocation {
+    pub(crate) fn new(offset: u64, field_number: Option<u32>) -> ErrorLocation {
+        ErrorLocation {
+            offset: offset,
+            field_number: field_number,
+            message: "",
+            fields: Vec::new(),
+            index: None,
+        }
+    }
+
+    /// Add field of `message` enclosing previously added fields
+    pub(crate) fn push_field(&mut self, message: &'static str, field_name: String) {
+        let segment = match self.index.take() {
+            Some(index) => format!("{}[{}]", field_name, index),
+            None => field_name,
+        };
+        self.fields.push(segment);
+        self.message = message;
+    }
+
+    pub(crate) fn set_message(&mut self, message: &'static str) {
+        self.message = message;
+    }
+
+    pub(crate) fn set_index(&mut self, index: usize) {
+        self.index = Some(index);
+    }
+
+    /// Byte offset from the start of the stream
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Number of the innermost field being decoded, `None` if error occurred reading a tag
+    pub fn field_number(&self) -> Option<u32> {
+        self.field_number
+    }
+
+    /// Path of the innermost field being decoded like `Outer.items[3].name`
+    pub fn path(&self) -> String {
+        let mut r = self.message.to_owned();
+        for segment in self.fields.iter().rev() {
+            if !r.is_empty() {
+                r.push('.');
+            }
+            r.push_str(segment);
+        }
+        r
+    }
+}
+
+impl fmt::Display for ErrorLocation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "offset {}", self.offset)?;
+        if let Some(field_number) = self.field_number {
+            write!(f, ", field {}", field_number)?;
+        }
+        let path = self.path();
+        if !path.is_empty() {
+            write!(f, ", `{}`", path)?;
+        }
+        Ok(())
+    }
 }
 
 impl fmt::Display for ProtobufError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        fmt::Debug::fmt(self, f)
+        match *self {
+            ProtobufError::Located { ref error, ref location } => {
+                write!(f, "{} at {}", error, location)
+            }
+            ProtobufError::MissingFields { ref message, ref missing_fields } => {
+                write!(f, "message `{}` is missing required fields: ", message)?;
+                f.write_str(&missing_fields.join(", "))
+            }
+            _ => fmt::Debug::fmt(self, f),
+        }
     }
 }
 
@@ -57,11 +190,16 @@ impl Error for ProtobufError {
                     WireError::IncompleteMap => "incomplete map",
                     WireError::UnexpectedEof => "unexpected EOF",
                     WireError::OverRecursionLimit => "over recursion limit",
+                    WireError::MessageTooLarge(..) => "message is too large",
+                    WireError::FieldTooLarge(..) => "field is too large",
+                    WireError::TooManyElements(..) => "too many elements in repeated field",
                     WireError::Other => "other error",
                 }
             }
             &ProtobufError::Utf8(ref e) => &e.description(),
             &ProtobufError::MessageNotInitialized { .. } => "not all message fields set",
+            &ProtobufError::MissingFields { .. } => "not all message fields set",
+            &ProtobufError::Located { ref error, .. } => error.description(),
         }
     }
 
@@ -71,6 +209,8 @@ impl Error for ProtobufError {
             &ProtobufError::Utf8(ref e) => Some(e),
             &ProtobufError::WireError(..) => None,
             &ProtobufError::MessageNotInitialized { .. } => None,
+            &ProtobufError::MissingFields { .. } => None,
+            &ProtobufError::Located { ref error, .. } => Some(&**error),
         }
     }
 }
@@ -89,18 +229,105 @@ impl From<str::Utf8Error> for ProtobufError {
 
 impl From<ProtobufError> for io::Error {
     fn from(err: ProtobufError) -> Self {
+        let kind = match *err.without_location() {
+            ProtobufError::IoError(ref e) => e.kind(),
+            ProtobufError::WireError(..) => io::ErrorKind::InvalidData,
+            ProtobufError::MessageNotInitialized { .. } |
+            ProtobufError::MissingFields { .. } => io::ErrorKind::InvalidInput,
+            _ => io::ErrorKind::Other,
+        };
         match err {
             ProtobufError::IoError(e) => e,
-            ProtobufError::WireError(e) => {
-                io::Error::new(io::ErrorKind::InvalidData, ProtobufError::WireError(e))
+            e => io::Error::new(kind, e),
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use core::Message;
+    use core::check_initialized_with_paths;
+    use core::parse_from_bytes;
+    use core::parse_from_bytes_with_options;
+    use descriptor::DescriptorProto;
+    use descriptor::FieldDescriptorProto;
+    use descriptor::FileDescriptorProto;
+    use descriptor::UninterpretedOption;
+    use descriptor::UninterpretedOption_NamePart;
+    use stream::ParseOptions;
+
+    fn locate_errors() -> ParseOptions {
+        ParseOptions {
+            locate_errors: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_location() {
+        let mut field = FieldDescriptorProto::new();
+        field.set_name("XY".to_owned());
+        let mut message = DescriptorProto::new();
+        message.mut_field().push(field);
+        let mut file = FileDescriptorProto::new();
+        file.mut_message_type().push(DescriptorProto::new());
+        file.mut_message_type().push(message);
+        let mut bytes = file.write_to_bytes().unwrap();
+        let pos = bytes.windows(2).position(|w| w == b"XY").unwrap();
+        bytes[pos] = 0xff;
+
+        match parse_from_bytes::<FileDescriptorProto>(&bytes) {
+            Err(ProtobufError::WireError(WireError::Utf8Error)) => {}
+            r => panic!("{:?}", r),
+        }
+
+        let e = parse_from_bytes_with_options::<FileDescriptorProto>(&bytes, &locate_errors())
+            .unwrap_err();
+        match e.wire_error() {
+            Some(&WireError::Utf8Error) => {}
+            _ => panic!("{:?}", e),
+        }
+        let location = e.location().unwrap();
+        assert_eq!(pos as u64 + 2, location.offset());
+        assert_eq!(Some(1), location.field_number());
+        assert_eq!("FileDescriptorProto.message_type[1].field[0].name", location.path());
+        assert!(e.to_string().ends_with(
+            "at offset 10, field 1, `FileDescriptorProto.message_type[1].field[0].name`"
+        ), "{}", e);
+
+        let e = parse_from_bytes_with_options::<FileDescriptorProto>(&[0x80], &locate_errors())
+            .unwrap_err();
+        let location = e.location().unwrap();
+        assert_eq!((1, None), (location.offset(), location.field_number()));
+        assert_eq!("FileDescriptorProto", location.path());
+    }
+
+    #[test]
+    fn test_missing_fields() {
+        let mut part = UninterpretedOption_NamePart::new();
+        part.set_name_part("a".to_owned());
+        let mut option = UninterpretedOption::new();
+        option.mut_name().push(part);
+        let mut file = FileDescriptorProto::new();
+        file.mut_options().mut_uninterpreted_option().push(option);
+
+        match file.check_initialized() {
+            Err(ProtobufError::MessageNotInitialized { message }) => {
+                assert_eq!("FileDescriptorProto", message);
             }
-            ProtobufError::MessageNotInitialized { message: msg } => {
-                io::Error::new(
-                    io::ErrorKind::InvalidInput,
-                    ProtobufError::MessageNotInitialized { message: msg },
-                )
+            r => panic!("{:?}", r),
+        }
+
+        match check_initialized_with_paths(&file) {
+            Err(ProtobufError::MissingFields { message, missing_fields }) => {
+                assert_eq!("FileDescriptorProto", message);
+                let path = "options.uninterpreted_option[0].name[0].is_extension";
+                assert_eq!(vec![format!("FileDescriptorProto.{}", path)], missing_fields);
             }
-            e => io::Error::new(io::ErrorKind::Other, Box::new(e)),
+            r => panic!("{:?}", r),
         }
     }
 }
@@ -1,7 +1,22 @@
+//! Extension fields.
+//!
+//! Extension values are stored in unknown fields of the extended message,
+//! they are decoded when read and encoded when set.
+
 use std::marker::PhantomData;
 
 use core::Message;
+use descriptor::FileDescriptorProto;
+use error::ProtobufResult;
+use reflect::registry;
+use reflect::RuntimeType;
+use rt;
+use stream::CodedInputStream;
+use stream::CodedOutputStream;
 use types::ProtobufType;
+use unknown::UnknownFields;
+use unknown::UnknownValues;
+use wire_format::WireType;
 
 /// Optional ext field
 pub struct ExtFieldOptional<M : Message, T : ProtobufType> {
@@ -15,16 +30,266 @@ pub struct ExtFieldRepeated<M : Message, T : ProtobufType> {
     pub phantom: PhantomData<(M, T)>,
 }
 
+/// Decode all values of type `T` stored in unknown fields.
+///
+/// Values of scalar types may be written packed or unpacked,
+/// packed values are returned after unpacked values.
+fn read_values<T : ProtobufType>(unknown: &UnknownValues) -> ProtobufResult<Vec<T::Value>> {
+    // values are written without tags and then read one after another
+    let mut bytes = Vec::new();
+    {
+        let mut os = CodedOutputStream::vec(&mut bytes);
+        match T::wire_type() {
+            WireType::WireTypeVarint => for &v in &unknown.varint {
+                os.write_raw_varint64(v)?;
+            },
+            WireType::WireTypeFixed32 => for &v in &unknown.fixed32 {
+                os.write_raw_little_endian32(v)?;
+            },
+            WireType::WireTypeFixed64 => for &v in &unknown.fixed64 {
+                os.write_raw_little_endian64(v)?;
+            },
+            WireType::WireTypeLengthDelimited => for v in &unknown.length_delimited {
+                os.write_raw_varint32(v.len() as u32)?;
+                os.write_raw_bytes(v)?;
+            },
+            // groups are not kept in unknown fields
+            wire_type => return Err(rt::unexpected_wire_type(wire_type)),
+        }
+        if T::wire_type() != WireType::WireTypeLengthDelimited {
+            for packed in &unknown.length_delimited {
+                os.write_raw_bytes(packed)?;
+            }
+        }
+        os.flush()?;
+    }
+
+    let mut is = CodedInputStream::from_bytes(&bytes);
+    let mut values = Vec::new();
+    while !is.eof()? {
+        values.push(T::read(&mut is)?);
+    }
+    Ok(values)
+}
+
+/// Decode value of singular field: last value wins, occurrences of a message are merged
+fn read_singular<T : ProtobufType>(unknown: &UnknownValues) -> ProtobufResult<Option<T::Value>> {
+    if let RuntimeType::Message(..) = T::runtime_type() {
+        if unknown.length_delimited.is_empty() {
+            return Ok(None);
+        }
+        let merged = UnknownValues {
+            length_delimited: vec![unknown.length_delimited.concat()],
+            ..Default::default()
+        };
+        return Ok(read_values::<T>(&merged)?.pop());
+    }
+    Ok(read_values::<T>(unknown)?.pop())
+}
+
+/// Encode values and append them to unknown fields.
+///
+/// Repeated values are written unpacked.
+fn write_values<T : ProtobufType>(
+    field_number: u32,
+    values: &[T::Value],
+    unknown_fields: &mut UnknownFields,
+) -> ProtobufResult<()> {
+    let mut bytes = Vec::new();
+    {
+        let mut os = CodedOutputStream::vec(&mut bytes);
+        for value in values {
+            // caches sizes of nested messages
+            T::compute_size(value);
+            T::write_with_cached_size(field_number, value, &mut os)?;
+        }
+        os.flush()?;
+    }
+
+    let mut is = CodedInputStream::from_bytes(&bytes);
+    while !is.eof()? {
+        let (number, wire_type) = is.read_tag_unpack()?;
+        rt::read_unknown_or_skip_group(number, wire_type, &mut is, unknown_fields)?;
+    }
+    Ok(())
+}
+
 impl<M : Message, T : ProtobufType> ExtFieldOptional<M, T> {
+    /// Get extension value, `None` if it is not set or cannot be decoded
     pub fn get(&self, m: &M) -> Option<T::Value> {
-        m.get_unknown_fields()
-            .get(self.field_number)
-            .and_then(T::get_from_unknown)
+        let unknown = m.get_unknown_fields().get(self.field_number)?;
+        read_singular::<T>(unknown).ok()?
+    }
+
+    pub fn has(&self, m: &M) -> bool {
+        self.get(m).is_some()
+    }
+
+    /// Replace extension value
+    pub fn set(&self, m: &mut M, value: T::Value) {
+        self.clear(m);
+        write_values::<T>(self.field_number, &[value], m.mut_unknown_fields())
+            .expect("write extension");
+    }
+
+    pub fn clear(&self, m: &mut M) {
+        m.mut_unknown_fields().remove(self.field_number);
+    }
+
+    /// Register extension in `reflect::registry`, `file` is the file where it is declared
+    pub fn register(&self, file: &'static FileDescriptorProto) {
+        registry::register_extension::<M, T>(file, self.field_number);
     }
 }
 
 impl<M : Message, T : ProtobufType> ExtFieldRepeated<M, T> {
-    pub fn get(&self, _m: &M) -> Vec<T::Value> {
-        unimplemented!()
+    /// Get extension values, error if stored values cannot be decoded
+    pub fn get(&self, m: &M) -> ProtobufResult<Vec<T::Value>> {
+        match m.get_unknown_fields().get(self.field_number) {
+            Some(unknown) => read_values::<T>(unknown),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Values are stored, even if they cannot be decoded
+    pub fn has(&self, m: &M) -> bool {
+        match self.get(m) {
+            Ok(values) => !values.is_empty(),
+            Err(..) => true,
+        }
+    }
+
+    /// Replace extension values
+    pub fn set(&self, m: &mut M, values: Vec<T::Value>) {
+        self.clear(m);
+        write_values::<T>(self.field_number, &values, m.mut_unknown_fields())
+            .expect("write extension");
+    }
+
+    /// Append a value
+    pub fn push(&self, m: &mut M, value: T::Value) {
+        write_values::<T>(self.field_number, &[value], m.mut_unknown_fields())
+            .expect("write extension");
+    }
+
+    pub fn clear(&self, m: &mut M) {
+        m.mut_unknown_fields().remove(self.field_number);
+    }
+
+    /// Register extension in `reflect::registry`, `file` is the file where it is declared
+    pub fn register(&self, file: &'static FileDescriptorProto) {
+        registry::register_extension::<M, T>(file, self.field_number);
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use descriptor::DescriptorProto;
+    use descriptor::FieldOptions;
+    use types::ProtobufTypeInt32;
+    use types::ProtobufTypeMessage;
+    use types::ProtobufTypeSint64;
+    use types::ProtobufTypeString;
+
+    const INT_EXT: ExtFieldOptional<FieldOptions, ProtobufTypeInt32> =
+        ExtFieldOptional { field_number: 50001, phantom: PhantomData };
+    const STRING_EXT: ExtFieldOptional<FieldOptions, ProtobufTypeString> =
+        ExtFieldOptional { field_number: 50002, phantom: PhantomData };
+    const MESSAGE_EXT: ExtFieldOptional<FieldOptions, ProtobufTypeMessage<DescriptorProto>> =
+        ExtFieldOptional { field_number: 50003, phantom: PhantomData };
+    const REPEATED_EXT: ExtFieldRepeated<FieldOptions, ProtobufTypeSint64> =
+        ExtFieldRepeated { field_number: 50004, phantom: PhantomData };
+
+    #[test]
+    fn test_optional() {
+        let mut m = FieldOptions::new();
+        assert!(!INT_EXT.has(&m));
+        INT_EXT.set(&mut m, -3);
+        STRING_EXT.set(&mut m, "a".to_owned());
+        STRING_EXT.set(&mut m, "b".to_owned());
+        assert_eq!(Some(-3), INT_EXT.get(&m));
+        assert_eq!(Some("b".to_owned()), STRING_EXT.get(&m));
+
+        INT_EXT.clear(&mut m);
+        assert_eq!(None, INT_EXT.get(&m));
+        assert!(STRING_EXT.has(&m));
+    }
+
+    #[test]
+    fn test_message_occurrences_merged() {
+        let mut m = FieldOptions::new();
+        m.mut_unknown_fields().add_length_delimited(50003, b"\x0a\x01a".to_vec());
+        m.mut_unknown_fields().add_length_delimited(50003, b"\x52\x01b".to_vec());
+        let value = MESSAGE_EXT.get(&m).unwrap();
+        assert_eq!("a", value.get_name());
+        assert_eq!(vec!["b".to_owned()], value.get_reserved_name());
+    }
+
+    #[test]
+    fn test_repeated_packed_and_unpacked() {
+        let mut m = FieldOptions::new();
+        REPEATED_EXT.set(&mut m, vec![1, -1]);
+        REPEATED_EXT.push(&mut m, 5);
+        assert_eq!(vec![1, -1, 5], REPEATED_EXT.get(&m).unwrap());
+
+        // packed: zigzag encoded 7 and -2
+        m.mut_unknown_fields().add_length_delimited(50004, vec![14, 3]);
+        assert_eq!(vec![1, -1, 5, 7, -2], REPEATED_EXT.get(&m).unwrap());
+
+        REPEATED_EXT.clear(&mut m);
+        assert!(!REPEATED_EXT.has(&m));
+    }
+
+    /// Message encoded as group, like a group extension
+    struct ProtobufTypeGroup;
+
+    impl ProtobufType for ProtobufTypeGroup {
+        type Value = DescriptorProto;
+
+        fn wire_type() -> WireType {
+            WireType::WireTypeStartGroup
+        }
+
+        fn runtime_type() -> RuntimeType {
+            ProtobufTypeMessage::<DescriptorProto>::runtime_type()
+        }
+
+        fn read(_is: &mut CodedInputStream) -> ProtobufResult<DescriptorProto> {
+            unimplemented!()
+        }
+
+        fn compute_size(_value: &DescriptorProto) -> u32 {
+            unimplemented!()
+        }
+
+        fn write_with_cached_size(
+            _field_number: u32,
+            _value: &DescriptorProto,
+            _os: &mut CodedOutputStream,
+        ) -> ProtobufResult<()> {
+            unimplemented!()
+        }
+    }
+
+    #[test]
+    fn test_group_is_error() {
+        let group_ext: ExtFieldRepeated<FieldOptions, ProtobufTypeGroup> =
+            ExtFieldRepeated { field_number: 50005, phantom: PhantomData };
+        let mut m = FieldOptions::new();
+        m.mut_unknown_fields().add_length_delimited(50005, b"\x0a\x01a".to_vec());
+        assert!(group_ext.get(&m).is_err());
+    }
+
+    #[test]
+    fn test_repeated_decode_error() {
+        let mut m = FieldOptions::new();
+        REPEATED_EXT.set(&mut m, vec![1]);
+        // truncated packed varint
+        m.mut_unknown_fields().add_length_delimited(50004, vec![0x80]);
+        assert!(REPEATED_EXT.get(&m).is_err());
+        assert!(REPEATED_EXT.has(&m));
     }
 }
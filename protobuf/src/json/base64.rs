@@ -0,0 +1,91 @@
+//! Base64 encoding used for `bytes` fields in JSON.
+
+const STANDARD: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Encode bytes as standard base64 with padding
+pub fn encode(input: &[u8]) -> String {
+    let mut r = String::with_capacity((input.len() + 2) / 3 * 4);
+    for chunk in input.chunks(3) {
+        let b0 = chunk[0] as u32;
+        let b1 = chunk.get(1).cloned().unwrap_or(0) as u32;
+        let b2 = chunk.get(2).cloned().unwrap_or(0) as u32;
+        let n = (b0 << 16) | (b1 << 8) | b2;
+        r.push(STANDARD[(n >> 18) as usize & 63] as char);
+        r.push(STANDARD[(n >> 12) as usize & 63] as char);
+        if chunk.len() > 1 {
+            r.push(STANDARD[(n >> 6) as usize & 63] as char);
+        } else {
+            r.push('=');
+        }
+        if chunk.len() > 2 {
+            r.push(STANDARD[n as usize & 63] as char);
+        } else {
+            r.push('=');
+        }
+    }
+    r
+}
+
+fn decode_char(c: u8) -> Option<u32> {
+    match c {
+        b'A'...b'Z' => Some((c - b'A') as u32),
+        b'a'...b'z' => Some((c - b'a' + 26) as u32),
+        b'0'...b'9' => Some((c - b'0' + 52) as u32),
+        b'+' | b'-' => Some(62),
+        b'/' | b'_' => Some(63),
+        _ => None,
+    }
+}
+
+/// Decode base64, both standard and URL-safe alphabets are accepted,
+/// padding is optional
+pub fn decode(input: &str) -> Result<Vec<u8>, ()> {
+    let input = input.trim_right_matches('=').as_bytes();
+    if input.len() % 4 == 1 {
+        return Err(());
+    }
+
+    let mut r = Vec::with_capacity(input.len() * 3 / 4);
+    for chunk in input.chunks(4) {
+        let mut n = 0;
+        for (i, &c) in chunk.iter().enumerate() {
+            n |= decode_char(c).ok_or(())? << (18 - 6 * i);
+        }
+        r.push((n >> 16) as u8);
+        if chunk.len() > 2 {
+            r.push((n >> 8) as u8);
+        }
+        if chunk.len() > 3 {
+            r.push(n as u8);
+        }
+    }
+    Ok(r)
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_encode_decode() {
+        for &(plain, encoded) in &[
+            (&b""[..], ""),
+            (b"f", "Zg=="),
+            (b"fo", "Zm8="),
+            (b"foo", "Zm9v"),
+            (b"foob", "Zm9vYg=="),
+            (b"\xff\xfe\xfd", "//79"),
+        ] {
+            assert_eq!(encoded, encode(plain));
+            assert_eq!(plain, &decode(encoded).unwrap()[..]);
+        }
+    }
+
+    #[test]
+    fn test_decode_url_safe_unpadded() {
+        assert_eq!(b"\xff\xfe\xfdf", &decode("__79Zg").unwrap()[..]);
+        assert!(decode("Z").is_err());
+        assert!(decode("Zm!v").is_err());
+    }
+}
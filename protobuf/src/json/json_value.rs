@@ -0,0 +1,321 @@
+//! Minimal JSON document model: parser and printer.
+
+use std::fmt::Write;
+
+use stream::DEFAULT_RECURSION_LIMIT;
+
+/// Parsed JSON value.
+///
+/// Numbers are stored as written in input to avoid precision loss
+/// of 64-bit integers.
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonValue {
+    Null,
+    Bool(bool),
+    Number(String),
+    String(String),
+    Array(Vec<JsonValue>),
+    /// Object members in input order
+    Object(Vec<(String, JsonValue)>),
+}
+
+/// Write string as quoted JSON string literal
+pub fn write_json_string(s: &str, buf: &mut String) {
+    buf.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => buf.push_str("\\\""),
+            '\\' => buf.push_str("\\\\"),
+            '\n' => buf.push_str("\\n"),
+            '\r' => buf.push_str("\\r"),
+            '\t' => buf.push_str("\\t"),
+            '\x08' => buf.push_str("\\b"),
+            '\x0c' => buf.push_str("\\f"),
+            c if (c as u32) < 0x20 => write!(buf, "\\u{:04x}", c as u32).unwrap(),
+            c => buf.push(c),
+        }
+    }
+    buf.push('"');
+}
+
+impl JsonValue {
+    /// Human-readable name of the value kind for error messages
+    pub fn kind_name(&self) -> &'static str {
+        match *self {
+            JsonValue::Null => "null",
+            JsonValue::Bool(..) => "bool",
+            JsonValue::Number(..) => "number",
+            JsonValue::String(..) => "string",
+            JsonValue::Array(..) => "array",
+            JsonValue::Object(..) => "object",
+        }
+    }
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+    // nesting level of arrays and objects
+    depth: u32,
+}
+
+impl<'a> Parser<'a> {
+    fn error<T>(&self, message: &str) -> Result<T, String> {
+        let consumed = &self.input[..self.pos];
+        let line = consumed.matches('\n').count() + 1;
+        let col = consumed.rsplit('\n').next().unwrap().chars().count() + 1;
+        Err(format!("{}:{}: {}", line, col, message))
+    }
+
+    fn rem(&self) -> &'a str {
+        &self.input[self.pos..]
+    }
+
+    fn peek_char(&self) -> Option<char> {
+        self.rem().chars().next()
+    }
+
+    fn next_char(&mut self) -> Option<char> {
+        let c = self.peek_char()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn skip_ws(&mut self) {
+        while let Some(c) = self.peek_char() {
+            match c {
+                ' ' | '\t' | '\n' | '\r' => self.pos += 1,
+                _ => break,
+            }
+        }
+    }
+
+    fn next_char_if_eq(&mut self, c: char) -> bool {
+        self.skip_ws();
+        if self.peek_char() == Some(c) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect_char(&mut self, c: char) -> Result<(), String> {
+        if !self.next_char_if_eq(c) {
+            return self.error(&format!("expecting `{}`", c));
+        }
+        Ok(())
+    }
+
+    fn next_hex4(&mut self) -> Result<u32, String> {
+        let mut r = 0;
+        for _ in 0..4 {
+            let d = match self.next_char().and_then(|c| c.to_digit(16)) {
+                Some(d) => d,
+                None => return self.error("incorrect \\u escape"),
+            };
+            r = r * 16 + d;
+        }
+        Ok(r)
+    }
+
+    fn next_string(&mut self) -> Result<String, String> {
+        self.expect_char('"')?;
+        let mut r = String::new();
+        loop {
+            match self.next_char() {
+                None => return self.error("unterminated string"),
+                Some('"') => return Ok(r),
+                Some('\\') => {
+                    let c = match self.next_char() {
+                        Some('"') => '"',
+                        Some('\\') => '\\',
+                        Some('/') => '/',
+                        Some('b') => '\x08',
+                        Some('f') => '\x0c',
+                        Some('n') => '\n',
+                        Some('r') => '\r',
+                        Some('t') => '\t',
+                        Some('u') => {
+                            let mut n = self.next_hex4()?;
+                            if n >= 0xd800 && n < 0xdc00 {
+                                if !self.rem().starts_with("\\u") {
+                                    return self.error("unpaired surrogate");
+                                }
+                                self.pos += 2;
+                                let low = self.next_hex4()?;
+                                if low < 0xdc00 || low >= 0xe000 {
+                                    return self.error("unpaired surrogate");
+                                }
+                                n = 0x10000 + ((n - 0xd800) << 10) + (low - 0xdc00);
+                            }
+                            match ::std::char::from_u32(n) {
+                                Some(c) => c,
+                                None => return self.error("unpaired surrogate"),
+                            }
+                        }
+                        _ => return self.error("incorrect escape"),
+                    };
+                    r.push(c);
+                }
+                Some(c) if (c as u32) < 0x20 => {
+                    return self.error("control character in string");
+                }
+                Some(c) => r.push(c),
+            }
+        }
+    }
+
+    fn next_number(&mut self) -> Result<String, String> {
+        let start = self.pos;
+        if self.peek_char() == Some('-') {
+            self.pos += 1;
+        }
+        let mut digits = 0;
+        while let Some(c) = self.peek_char() {
+            match c {
+                '0'...'9' => digits += 1,
+                '.' | 'e' | 'E' => {}
+                '+' | '-' if self.input[..self.pos].ends_with(|c| c == 'e' || c == 'E') => {}
+                _ => break,
+            }
+            self.pos += 1;
+        }
+        let number = &self.input[start..self.pos];
+        if digits == 0 || number.parse::<f64>().is_err() {
+            self.pos = start;
+            return self.error("incorrect number");
+        }
+        Ok(number.to_owned())
+    }
+
+    fn next_keyword(&mut self, keyword: &str, value: JsonValue) -> Result<JsonValue, String> {
+        if !self.rem().starts_with(keyword) {
+            return self.error("unexpected token");
+        }
+        self.pos += keyword.len();
+        Ok(value)
+    }
+
+    fn next_array_or_object(&mut self) -> Result<JsonValue, String> {
+        match self.next_char() {
+            Some('[') => {
+                let mut r = Vec::new();
+                if self.next_char_if_eq(']') {
+                    return Ok(JsonValue::Array(r));
+                }
+                loop {
+                    r.push(self.next_value()?);
+                    if self.next_char_if_eq(']') {
+                        return Ok(JsonValue::Array(r));
+                    }
+                    if !self.next_char_if_eq(',') {
+                        return self.error("expecting `,` or `]`");
+                    }
+                }
+            }
+            Some('{') => {
+                let mut r = Vec::new();
+                if self.next_char_if_eq('}') {
+                    return Ok(JsonValue::Object(r));
+                }
+                loop {
+                    self.skip_ws();
+                    let key = self.next_string()?;
+                    self.expect_char(':')?;
+                    let value = self.next_value()?;
+                    r.push((key, value));
+                    if self.next_char_if_eq('}') {
+                        return Ok(JsonValue::Object(r));
+                    }
+                    if !self.next_char_if_eq(',') {
+                        return self.error("expecting `,` or `}`");
+                    }
+                }
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn next_value(&mut self) -> Result<JsonValue, String> {
+        self.skip_ws();
+        match self.peek_char() {
+            Some('n') => self.next_keyword("null", JsonValue::Null),
+            Some('t') => self.next_keyword("true", JsonValue::Bool(true)),
+            Some('f') => self.next_keyword("false", JsonValue::Bool(false)),
+            Some('"') => Ok(JsonValue::String(self.next_string()?)),
+            Some('[') | Some('{') => {
+                if self.depth == DEFAULT_RECURSION_LIMIT {
+                    return self.error("over recursion limit");
+                }
+                self.depth += 1;
+                let r = self.next_array_or_object();
+                self.depth -= 1;
+                r
+            }
+            Some('-') | Some('0'...'9') => Ok(JsonValue::Number(self.next_number()?)),
+            Some(_) => self.error("unexpected token"),
+            None => self.error("unexpected end of input"),
+        }
+    }
+}
+
+/// Parse JSON document; error message includes line and column
+pub fn parse(input: &str) -> Result<JsonValue, String> {
+    let mut parser = Parser {
+        input: input,
+        pos: 0,
+        depth: 0,
+    };
+    let value = parser.next_value()?;
+    parser.skip_ws();
+    if parser.pos != input.len() {
+        return parser.error("trailing characters");
+    }
+    Ok(value)
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let text = r#"{"a":[1,-2.5e+3,true,false,null],"b\n":{"c":"\u00e9\ud83d\ude00"}}"#;
+        let value = parse(text).unwrap();
+        assert_eq!(
+            JsonValue::Object(vec![
+                ("a".to_owned(), JsonValue::Array(vec![
+                    JsonValue::Number("1".to_owned()),
+                    JsonValue::Number("-2.5e+3".to_owned()),
+                    JsonValue::Bool(true),
+                    JsonValue::Bool(false),
+                    JsonValue::Null,
+                ])),
+                ("b\n".to_owned(), JsonValue::Object(vec![
+                    ("c".to_owned(), JsonValue::String("é😀".to_owned())),
+                ])),
+            ]),
+            value
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(Err("2:3: expecting `,` or `}`".to_owned()), parse("{\"a\":1\n  ]"));
+        assert!(parse("[1,]").is_err());
+        assert!(parse("-").is_err());
+        assert!(parse("\"\\ud800\"").is_err());
+        assert!(parse("1 2").is_err());
+    }
+
+    #[test]
+    fn test_recursion_limit() {
+        let nested = |depth| format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+        assert!(parse(&nested(100)).is_ok());
+        assert_eq!(Err("1:101: over recursion limit".to_owned()), parse(&nested(101)));
+        assert!(parse(&"{\"a\":".repeat(200000)).is_err());
+        assert!(parse(&"[".repeat(200000)).is_err());
+    }
+}
@@ -0,0 +1,86 @@
+//! Proto3 JSON mapping.
+//!
+//! Messages are printed and parsed using reflection, following
+//! [canonical JSON encoding](https://developers.google.com/protocol-buffers/docs/proto3#json):
+//! field names are lowerCamelCase (or `json_name` if specified),
+//! 64-bit integers are strings, `bytes` are base64 strings,
+//! enums are value names, and well-known types have special representations.
+
+mod base64;
+mod json_value;
+mod parse;
+mod print;
+pub(crate) mod rfc_3339;
+
+pub use self::parse::ParseError;
+pub use self::parse::ParseOptions;
+pub use self::parse::merge_from_str;
+pub use self::parse::merge_from_str_with_options;
+pub use self::parse::parse_from_str;
+pub use self::parse::parse_from_str_with_options;
+pub use self::print::PrintError;
+pub use self::print::PrintOptions;
+pub use self::print::print_to_string;
+pub use self::print::print_to_string_with_options;
+
+
+/// Convert proto field name to lowerCamelCase JSON name the same way `protoc` does
+pub(crate) fn json_name(field_name: &str) -> String {
+    let mut r = String::with_capacity(field_name.len());
+    let mut capitalize_next = false;
+    for c in field_name.chars() {
+        if c == '_' {
+            capitalize_next = true;
+        } else if capitalize_next {
+            r.extend(c.to_uppercase());
+            capitalize_next = false;
+        } else {
+            r.push(c);
+        }
+    }
+    r
+}
+
+/// Well-known types which have special JSON representation
+/// (i. e. not an object with fields)
+fn is_special_well_known_type(full_name: &str) -> bool {
+    match full_name {
+        "google.protobuf.Any" |
+        "google.protobuf.Duration" |
+        "google.protobuf.FieldMask" |
+        "google.protobuf.ListValue" |
+        "google.protobuf.Struct" |
+        "google.protobuf.Timestamp" |
+        "google.protobuf.Value" => true,
+        _ => is_wrapper_type(full_name),
+    }
+}
+
+fn is_wrapper_type(full_name: &str) -> bool {
+    match full_name {
+        "google.protobuf.BoolValue" |
+        "google.protobuf.BytesValue" |
+        "google.protobuf.DoubleValue" |
+        "google.protobuf.FloatValue" |
+        "google.protobuf.Int32Value" |
+        "google.protobuf.Int64Value" |
+        "google.protobuf.StringValue" |
+        "google.protobuf.UInt32Value" |
+        "google.protobuf.UInt64Value" => true,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_json_name() {
+        assert_eq!("fooBar", json_name("foo_bar"));
+        assert_eq!("fooBarBaz", json_name("foo_bar_baz"));
+        assert_eq!("FooBar", json_name("_foo_bar"));
+        assert_eq!("foo1Bar", json_name("foo1_bar"));
+        assert_eq!("fooBar", json_name("fooBar"));
+    }
+}
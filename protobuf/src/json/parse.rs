@@ -0,0 +1,735 @@
+//! JSON parser.
+//!
+//! Like the text format parser, JSON is converted into protobuf binary
+//! format using reflection, which is then merged into the message.
+
+use std::error::Error;
+use std::f32;
+use std::f64;
+use std::fmt;
+use std::ptr;
+
+use core::Message;
+use descriptor::FieldDescriptorProto;
+use descriptor::FieldDescriptorProto_Type;
+use error::ProtobufResult;
+use reflect::EnumDescriptor;
+use reflect::FieldDescriptor;
+use reflect::MessageDescriptor;
+use reflect::RuntimeFieldType;
+use reflect::RuntimeType;
+use reflect::registry;
+use stream::CodedOutputStream;
+use stream::wire_format;
+use well_known_types::Any;
+use well_known_types::Duration;
+use well_known_types::FieldMask;
+use well_known_types::ListValue;
+use well_known_types::NullValue;
+use well_known_types::Struct;
+use well_known_types::Timestamp;
+use well_known_types::Value;
+
+use super::base64;
+use super::is_special_well_known_type;
+use super::is_wrapper_type;
+use super::json_value;
+use super::json_value::JsonValue;
+
+
+/// JSON parse error
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    message: String,
+}
+
+impl ParseError {
+    fn new(message: String) -> ParseError {
+        ParseError { message: message }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl Error for ParseError {
+    fn description(&self) -> &str {
+        &self.message
+    }
+}
+
+type ParseResult<T> = Result<T, ParseError>;
+
+fn error<T>(message: String) -> ParseResult<T> {
+    Err(ParseError::new(message))
+}
+
+fn expecting<T>(what: &str, value: &JsonValue) -> ParseResult<T> {
+    error(format!("expecting {}, got {}", what, value.kind_name()))
+}
+
+fn write_result(r: ProtobufResult<()>) -> ParseResult<()> {
+    r.map_err(|e| ParseError::new(format!("{}", e)))
+}
+
+/// Options for JSON parsing
+#[derive(Default, Debug, Clone)]
+pub struct ParseOptions {
+    /// Skip object members which do not correspond to any message field
+    pub ignore_unknown_fields: bool,
+}
+
+/// Integer from JSON number or string; integral floats like `1e3` are accepted
+fn parse_i64(value: &JsonValue, min: i64, max: i64) -> ParseResult<i64> {
+    let s = match *value {
+        JsonValue::Number(ref s) | JsonValue::String(ref s) => s,
+        _ => return expecting("integer", value),
+    };
+    let v = match s.parse::<i64>() {
+        Ok(v) => v,
+        Err(_) => match s.parse::<f64>() {
+            Ok(f) if f.fract() == 0.0 && f >= min as f64 && f < max as f64 => f as i64,
+            _ => return error(format!("incorrect integer: `{}`", s)),
+        },
+    };
+    if v < min || v > max {
+        return error(format!("integer is out of range: `{}`", s));
+    }
+    Ok(v)
+}
+
+fn parse_u64(value: &JsonValue, max: u64) -> ParseResult<u64> {
+    let s = match *value {
+        JsonValue::Number(ref s) | JsonValue::String(ref s) => s,
+        _ => return expecting("integer", value),
+    };
+    let v = match s.parse::<u64>() {
+        Ok(v) => v,
+        Err(_) => match s.parse::<f64>() {
+            Ok(f) if f.fract() == 0.0 && f >= 0.0 && f < max as f64 => f as u64,
+            _ => return error(format!("incorrect integer: `{}`", s)),
+        },
+    };
+    if v > max {
+        return error(format!("integer is out of range: `{}`", s));
+    }
+    Ok(v)
+}
+
+fn parse_f64(value: &JsonValue) -> ParseResult<f64> {
+    match *value {
+        JsonValue::Number(ref s) => Ok(s.parse().unwrap()),
+        JsonValue::String(ref s) => match &s[..] {
+            "NaN" => Ok(f64::NAN),
+            "Infinity" => Ok(f64::INFINITY),
+            "-Infinity" => Ok(f64::NEG_INFINITY),
+            s => match s.parse::<f64>() {
+                Ok(v) if v.is_finite() => Ok(v),
+                _ => error(format!("incorrect float: `{}`", s)),
+            },
+        },
+        _ => expecting("number", value),
+    }
+}
+
+fn parse_f32(value: &JsonValue) -> ParseResult<f32> {
+    let v = parse_f64(value)?;
+    if v.is_finite() && (v > f32::MAX as f64 || v < f32::MIN as f64) {
+        return error(format!("float is out of range: {}", v));
+    }
+    Ok(v as f32)
+}
+
+fn parse_bool(value: &JsonValue) -> ParseResult<bool> {
+    match *value {
+        JsonValue::Bool(b) => Ok(b),
+        _ => expecting("bool", value),
+    }
+}
+
+fn parse_string(value: &JsonValue) -> ParseResult<&str> {
+    match *value {
+        JsonValue::String(ref s) => Ok(s),
+        _ => expecting("string", value),
+    }
+}
+
+fn parse_enum(descriptor: &EnumDescriptor, value: &JsonValue) -> ParseResult<i32> {
+    match *value {
+        JsonValue::String(ref name) => {
+            match descriptor.values().iter().find(|v| v.name() == name) {
+                Some(v) => Ok(v.value()),
+                None => error(format!("unknown value of enum `{}`: `{}`", descriptor.name(), name)),
+            }
+        }
+        JsonValue::Number(..) => {
+            parse_i64(value, i32::min_value() as i64, i32::max_value() as i64).map(|v| v as i32)
+        }
+        _ => expecting("enum value", value),
+    }
+}
+
+fn parse_timestamp(value: &JsonValue) -> ParseResult<Timestamp> {
+    let s = parse_string(value)?;
+    match s.parse() {
+        Ok(t) => Ok(t),
+        Err(_) => error(format!("incorrect timestamp: `{}`", s)),
+    }
+}
+
+fn parse_duration(value: &JsonValue) -> ParseResult<Duration> {
+    let s = parse_string(value)?;
+    match s.parse() {
+        Ok(d) => Ok(d),
+        Err(_) => error(format!("incorrect duration: `{}`", s)),
+    }
+}
+
+/// Convert lowerCamelCase field mask path to snake_case
+fn field_mask_path_from_json(path: &str) -> ParseResult<String> {
+    let mut r = String::with_capacity(path.len() + 4);
+    for c in path.chars() {
+        if c == '_' {
+            return error(format!("incorrect field mask path: `{}`", path));
+        }
+        if c.is_ascii_uppercase() {
+            r.push('_');
+            r.push(c.to_ascii_lowercase());
+        } else {
+            r.push(c);
+        }
+    }
+    Ok(r)
+}
+
+fn parse_field_mask(value: &JsonValue) -> ParseResult<FieldMask> {
+    let s = parse_string(value)?;
+    let mut mask = FieldMask::new();
+    if !s.is_empty() {
+        for path in s.split(',') {
+            mask.mut_paths().push(field_mask_path_from_json(path)?);
+        }
+    }
+    Ok(mask)
+}
+
+fn parse_struct(value: &JsonValue) -> ParseResult<Struct> {
+    let members = match *value {
+        JsonValue::Object(ref members) => members,
+        _ => return expecting("object", value),
+    };
+    let mut s = Struct::new();
+    for &(ref k, ref v) in members {
+        s.mut_fields().insert(k.clone(), parse_json_value(v)?);
+    }
+    Ok(s)
+}
+
+fn parse_list_value(value: &JsonValue) -> ParseResult<ListValue> {
+    let items = match *value {
+        JsonValue::Array(ref items) => items,
+        _ => return expecting("array", value),
+    };
+    let mut l = ListValue::new();
+    for item in items {
+        l.mut_values().push(parse_json_value(item)?);
+    }
+    Ok(l)
+}
+
+fn parse_json_value(value: &JsonValue) -> ParseResult<Value> {
+    let mut r = Value::new();
+    match *value {
+        JsonValue::Null => r.set_null_value(NullValue::NULL_VALUE),
+        JsonValue::Bool(b) => r.set_bool_value(b),
+        JsonValue::Number(..) => r.set_number_value(parse_f64(value)?),
+        JsonValue::String(ref s) => r.set_string_value(s.clone()),
+        JsonValue::Array(..) => r.set_list_value(parse_list_value(value)?),
+        JsonValue::Object(..) => r.set_struct_value(parse_struct(value)?),
+    }
+    Ok(r)
+}
+
+fn write_to_bytes(m: &Message) -> ParseResult<Vec<u8>> {
+    m.write_to_bytes().map_err(|e| ParseError::new(format!("{}", e)))
+}
+
+/// Field of message or map entry
+struct FieldInfo<'a> {
+    proto: &'a FieldDescriptorProto,
+    runtime_field_type: RuntimeFieldType,
+}
+
+struct Parser<'a> {
+    options: &'a ParseOptions,
+}
+
+impl<'a> Parser<'a> {
+    fn merge_value(
+        &self,
+        number: u32,
+        field_type: FieldDescriptorProto_Type,
+        runtime_type: RuntimeType,
+        value: &JsonValue,
+        os: &mut CodedOutputStream,
+    ) -> ParseResult<()> {
+        use self::FieldDescriptorProto_Type::*;
+
+        let r = match (field_type, runtime_type) {
+            (TYPE_MESSAGE, RuntimeType::Message(descriptor)) => {
+                let bytes = self.message_bytes(descriptor, value)?;
+                os.write_bytes(number, &bytes)
+            }
+            (TYPE_ENUM, RuntimeType::Enum(descriptor)) => {
+                let v = if descriptor.name() == "NullValue" && *value == JsonValue::Null {
+                    0
+                } else {
+                    parse_enum(descriptor, value)?
+                };
+                os.write_enum(number, v)
+            }
+            (TYPE_STRING, _) => os.write_string(number, parse_string(value)?),
+            (TYPE_BYTES, _) => {
+                let s = parse_string(value)?;
+                match base64::decode(s) {
+                    Ok(bytes) => os.write_bytes(number, &bytes),
+                    Err(()) => return error(format!("incorrect base64: `{}`", s)),
+                }
+            }
+            (TYPE_BOOL, _) => os.write_bool(number, parse_bool(value)?),
+            (TYPE_INT32, _) | (TYPE_SINT32, _) | (TYPE_SFIXED32, _) => {
+                let v = parse_i64(value, i32::min_value() as i64, i32::max_value() as i64)? as i32;
+                match field_type {
+                    TYPE_INT32 => os.write_int32(number, v),
+                    TYPE_SINT32 => os.write_sint32(number, v),
+                    _ => os.write_sfixed32(number, v),
+                }
+            }
+            (TYPE_INT64, _) | (TYPE_SINT64, _) | (TYPE_SFIXED64, _) => {
+                let v = parse_i64(value, i64::min_value(), i64::max_value())?;
+                match field_type {
+                    TYPE_INT64 => os.write_int64(number, v),
+                    TYPE_SINT64 => os.write_sint64(number, v),
+                    _ => os.write_sfixed64(number, v),
+                }
+            }
+            (TYPE_UINT32, _) | (TYPE_FIXED32, _) => {
+                let v = parse_u64(value, u32::max_value() as u64)? as u32;
+                match field_type {
+                    TYPE_UINT32 => os.write_uint32(number, v),
+                    _ => os.write_fixed32(number, v),
+                }
+            }
+            (TYPE_UINT64, _) | (TYPE_FIXED64, _) => {
+                let v = parse_u64(value, u64::max_value())?;
+                match field_type {
+                    TYPE_UINT64 => os.write_uint64(number, v),
+                    _ => os.write_fixed64(number, v),
+                }
+            }
+            (TYPE_FLOAT, _) => os.write_float(number, parse_f32(value)?),
+            (TYPE_DOUBLE, _) => os.write_double(number, parse_f64(value)?),
+            (TYPE_GROUP, RuntimeType::Message(descriptor)) => {
+                let bytes = self.message_bytes(descriptor, value)?;
+                os.write_tag(number, wire_format::WireTypeStartGroup)
+                    .and_then(|_| os.write_raw_bytes(&bytes))
+                    .and_then(|_| os.write_tag(number, wire_format::WireTypeEndGroup))
+            }
+            (TYPE_MESSAGE, _) | (TYPE_ENUM, _) | (TYPE_GROUP, _) => unreachable!(),
+        };
+        write_result(r)
+    }
+
+    fn merge_field(
+        &self,
+        field: &FieldInfo,
+        map_entry: Option<(&FieldDescriptorProto, &FieldDescriptorProto)>,
+        value: &JsonValue,
+        os: &mut CodedOutputStream,
+    ) -> ParseResult<()> {
+        let number = field.proto.get_number() as u32;
+        let field_type = field.proto.get_field_type();
+
+        // `null` means default value except for `google.protobuf.Value`
+        if *value == JsonValue::Null {
+            match field.runtime_field_type {
+                RuntimeFieldType::Singular(RuntimeType::Message(d))
+                    if d.full_name() == "google.protobuf.Value" => {}
+                RuntimeFieldType::Singular(RuntimeType::Enum(d)) if d.name() == "NullValue" => {}
+                _ => return Ok(()),
+            }
+        }
+
+        match field.runtime_field_type {
+            RuntimeFieldType::Singular(t) => self.merge_value(number, field_type, t, value, os),
+            RuntimeFieldType::Repeated(t) => {
+                let items = match *value {
+                    JsonValue::Array(ref items) => items,
+                    _ => return expecting("array", value),
+                };
+                for item in items {
+                    if *item == JsonValue::Null {
+                        return error("`null` is not allowed in repeated field".to_owned());
+                    }
+                    self.merge_value(number, field_type, t, item, os)?;
+                }
+                Ok(())
+            }
+            RuntimeFieldType::Map(k, v) => {
+                let (key_proto, value_proto) = map_entry.unwrap();
+                let members = match *value {
+                    JsonValue::Object(ref members) => members,
+                    _ => return expecting("object", value),
+                };
+                for &(ref key, ref value) in members {
+                    let key = match (k, &key[..]) {
+                        (RuntimeType::Bool, "true") => JsonValue::Bool(true),
+                        (RuntimeType::Bool, "false") => JsonValue::Bool(false),
+                        (RuntimeType::Bool, _) => {
+                            return error(format!("incorrect bool map key: `{}`", key))
+                        }
+                        _ => JsonValue::String(key.clone()),
+                    };
+                    let mut bytes = Vec::new();
+                    {
+                        let mut entry_os = CodedOutputStream::vec(&mut bytes);
+                        self.merge_value(
+                            key_proto.get_number() as u32,
+                            key_proto.get_field_type(),
+                            k,
+                            &key,
+                            &mut entry_os,
+                        )?;
+                        self.merge_value(
+                            value_proto.get_number() as u32,
+                            value_proto.get_field_type(),
+                            v,
+                            value,
+                            &mut entry_os,
+                        )?;
+                        write_result(entry_os.flush())?;
+                    }
+                    write_result(os.write_bytes(number, &bytes))?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    fn merge_fields(
+        &self,
+        descriptor: &'static MessageDescriptor,
+        members: &[(String, JsonValue)],
+        os: &mut CodedOutputStream,
+    ) -> ParseResult<()> {
+        // oneof fields specified so far, `null` does not set a field
+        let mut oneof_fields: Vec<&FieldDescriptor> = Vec::new();
+        for &(ref name, ref value) in members {
+            let field = descriptor
+                .fields()
+                .iter()
+                .find(|f| f.name() == name || f.json_name() == *name);
+            let field = match field {
+                Some(field) => field,
+                None if self.options.ignore_unknown_fields => continue,
+                None => {
+                    return error(format!(
+                        "message `{}` has no field `{}`",
+                        descriptor.full_name(),
+                        name
+                    ));
+                }
+            };
+
+            match field.containing_oneof() {
+                Some(oneof) if !oneof.is_synthetic() && *value != JsonValue::Null => {
+                    let other = oneof_fields.iter().find(|f| {
+                        ptr::eq(f.containing_oneof().unwrap(), oneof) && !ptr::eq(**f, field)
+                    });
+                    if let Some(other) = other {
+                        return error(format!(
+                            "field `{}` is specified along with field `{}` of oneof `{}`",
+                            field.name(),
+                            other.name(),
+                            oneof.name()
+                        ));
+                    }
+                    oneof_fields.push(field);
+                }
+                _ => {}
+            }
+
+            let info = FieldInfo {
+                proto: field.proto(),
+                runtime_field_type: field.runtime_field_type(),
+            };
+            let map_entry = match info.runtime_field_type {
+                RuntimeFieldType::Map(..) => {
+                    let entry = descriptor.map_entry_proto(field);
+                    Some((&entry.get_field()[0], &entry.get_field()[1]))
+                }
+                _ => None,
+            };
+            self.merge_field(&info, map_entry, value, os)
+                .map_err(|e| ParseError::new(format!("field `{}`: {}", name, e.message)))?;
+        }
+        Ok(())
+    }
+
+    fn fields_bytes(
+        &self,
+        descriptor: &'static MessageDescriptor,
+        members: &[(String, JsonValue)],
+    ) -> ParseResult<Vec<u8>> {
+        let mut bytes = Vec::new();
+        {
+            let mut os = CodedOutputStream::vec(&mut bytes);
+            self.merge_fields(descriptor, members, &mut os)?;
+            write_result(os.flush())?;
+        }
+        Ok(bytes)
+    }
+
+    fn wrapper_bytes(
+        &self,
+        descriptor: &'static MessageDescriptor,
+        value: &JsonValue,
+    ) -> ParseResult<Vec<u8>> {
+        let field = &descriptor.fields()[0];
+        let runtime_type = match field.runtime_field_type() {
+            RuntimeFieldType::Singular(t) => t,
+            _ => unreachable!(),
+        };
+        let mut bytes = Vec::new();
+        {
+            let mut os = CodedOutputStream::vec(&mut bytes);
+            self.merge_value(
+                field.proto().get_number() as u32,
+                field.proto().get_field_type(),
+                runtime_type,
+                value,
+                &mut os,
+            )?;
+            write_result(os.flush())?;
+        }
+        Ok(bytes)
+    }
+
+    fn any_bytes(&self, value: &JsonValue) -> ParseResult<Vec<u8>> {
+        let members = match *value {
+            JsonValue::Object(ref members) => members,
+            _ => return expecting("object", value),
+        };
+        let type_url = match members.iter().find(|m| m.0 == "@type") {
+            Some(&(_, ref type_url)) => parse_string(type_url)?,
+            None => return error("`Any` must have `@type` member".to_owned()),
+        };
+        let descriptor = match registry::find_message_by_type_url(type_url) {
+            Some(d) => d,
+            None => return error(format!("cannot resolve `Any` type URL `{}`", type_url)),
+        };
+
+        let content = if is_special_well_known_type(descriptor.full_name()) {
+            match members.iter().find(|m| m.0 == "value") {
+                Some(&(_, ref value)) => self.message_bytes(descriptor, value)?,
+                None => return error("`Any` of well-known type must have `value` member".to_owned()),
+            }
+        } else {
+            let rest: Vec<_> = members.iter().filter(|m| m.0 != "@type").cloned().collect();
+            self.fields_bytes(descriptor, &rest)?
+        };
+
+        let mut any = Any::new();
+        any.set_type_url(type_url.to_owned());
+        any.set_value(content);
+        write_to_bytes(&any)
+    }
+
+    /// Binary representation of message parsed from JSON value
+    fn message_bytes(
+        &self,
+        descriptor: &'static MessageDescriptor,
+        value: &JsonValue,
+    ) -> ParseResult<Vec<u8>> {
+        match descriptor.full_name() {
+            "google.protobuf.Any" => self.any_bytes(value),
+            "google.protobuf.Duration" => write_to_bytes(&parse_duration(value)?),
+            "google.protobuf.FieldMask" => write_to_bytes(&parse_field_mask(value)?),
+            "google.protobuf.ListValue" => write_to_bytes(&parse_list_value(value)?),
+            "google.protobuf.Struct" => write_to_bytes(&parse_struct(value)?),
+            "google.protobuf.Timestamp" => write_to_bytes(&parse_timestamp(value)?),
+            "google.protobuf.Value" => write_to_bytes(&parse_json_value(value)?),
+            name if is_wrapper_type(name) => self.wrapper_bytes(descriptor, value),
+            _ => match *value {
+                JsonValue::Object(ref members) => self.fields_bytes(descriptor, members),
+                _ => expecting("object", value),
+            },
+        }
+    }
+}
+
+/// Parse JSON and merge parsed fields into given message using default options
+pub fn merge_from_str(message: &mut Message, json: &str) -> Result<(), ParseError> {
+    merge_from_str_with_options(message, json, &ParseOptions::default())
+}
+
+/// Parse JSON and merge parsed fields into given message.
+///
+/// Singular fields present in JSON overwrite fields of the message,
+/// repeated fields are appended.
+pub fn merge_from_str_with_options(
+    message: &mut Message,
+    json: &str,
+    options: &ParseOptions,
+) -> Result<(), ParseError> {
+    let value = json_value::parse(json).map_err(ParseError::new)?;
+    let parser = Parser { options: options };
+    let bytes = parser.message_bytes(message.descriptor(), &value)?;
+    message
+        .merge_from_bytes(&bytes)
+        .map_err(|e| ParseError::new(format!("{}", e)))
+}
+
+/// Parse message from JSON using default options
+pub fn parse_from_str<M : Message>(json: &str) -> Result<M, ParseError> {
+    parse_from_str_with_options(json, &ParseOptions::default())
+}
+
+/// Parse message from JSON.
+///
+/// Results in error if JSON is malformed or required fields are missing.
+pub fn parse_from_str_with_options<M : Message>(
+    json: &str,
+    options: &ParseOptions,
+) -> Result<M, ParseError> {
+    let mut message = M::new();
+    merge_from_str_with_options(&mut message, json, options)?;
+    if !message.is_initialized() {
+        return error(format!(
+            "message `{}` is missing required fields",
+            message.descriptor().full_name()
+        ));
+    }
+    Ok(message)
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use descriptor::FileDescriptorProto;
+    use descriptor::FileOptions_OptimizeMode;
+    use json::print_to_string;
+    use reflect::DescriptorPool;
+    use reflect::DynamicMessage;
+    use text_format;
+    use well_known_types::BoolValue;
+    use well_known_types::UInt64Value;
+    use well_known_types::Value;
+
+    #[test]
+    fn test_parse_fields() {
+        let file: FileDescriptorProto = parse_from_str(
+            r#"{
+                "name": "a.proto",
+                "public_dependency": [1, "2", 3e0],
+                "options": { "optimizeFor": "CODE_SIZE", "javaPackage": null },
+                "messageType": [{ "field": [{ "name": "x", "type": 9, "jsonName": "X" }] }]
+            }"#,
+        ).unwrap();
+        assert_eq!("a.proto", file.get_name());
+        assert_eq!(&[1, 2, 3], file.get_public_dependency());
+        assert_eq!(FileOptions_OptimizeMode::CODE_SIZE, file.get_options().get_optimize_for());
+        assert!(!file.get_options().has_java_package());
+        let field = &file.get_message_type()[0].get_field()[0];
+        assert_eq!("X", field.get_json_name());
+
+        assert_eq!(file, parse_from_str::<FileDescriptorProto>(&print_to_string(&file).unwrap()).unwrap());
+    }
+
+    #[test]
+    fn test_unknown_fields() {
+        let json = r#"{"name":"a.proto","foo":{"bar":[]}}"#;
+        assert!(parse_from_str::<FileDescriptorProto>(json).is_err());
+
+        let options = ParseOptions { ignore_unknown_fields: true };
+        let file: FileDescriptorProto = parse_from_str_with_options(json, &options).unwrap();
+        assert_eq!("a.proto", file.get_name());
+    }
+
+    #[test]
+    fn test_well_known_types() {
+        let t: Timestamp = parse_from_str("\"1972-01-01T10:00:20.021Z\"").unwrap();
+        assert_eq!((63108020, 21000000), (t.get_seconds(), t.get_nanos()));
+
+        let d: Duration = parse_from_str("\"-1.5s\"").unwrap();
+        assert_eq!((-1, -500000000), (d.get_seconds(), d.get_nanos()));
+        assert!(parse_from_str::<Duration>("\"1.s\"").is_err());
+        assert!(parse_from_str::<Duration>("\"1\"").is_err());
+
+        let f: FieldMask = parse_from_str("\"fooBar.baz,qux\"").unwrap();
+        assert_eq!(&["foo_bar.baz", "qux"], f.get_paths());
+
+        let s: Struct = parse_from_str(r#"{"a":[null,1.5,"x",{"b":true}]}"#).unwrap();
+        assert_eq!(r#"{"a":[null,1.5,"x",{"b":true}]}"#, print_to_string(&s).unwrap());
+
+        let v: UInt64Value = parse_from_str("\"18446744073709551615\"").unwrap();
+        assert_eq!(u64::max_value(), v.get_value());
+        let v: BoolValue = parse_from_str("true").unwrap();
+        assert_eq!(true, v.get_value());
+
+        let json = r#"{"@type":"type.googleapis.com/google.protobuf.Duration","value":"3s"}"#;
+        let any: Any = parse_from_str(json).unwrap();
+        assert_eq!(json, print_to_string(&any).unwrap());
+
+        let json = r#"{"@type":"type.googleapis.com/google.protobuf.SourceContext","fileName":"a"}"#;
+        let any: Any = parse_from_str(json).unwrap();
+        assert_eq!(json, print_to_string(&any).unwrap());
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(parse_from_str::<FileDescriptorProto>("{\"name\": 1}").is_err());
+        assert!(parse_from_str::<FileDescriptorProto>("{\"publicDependency\": [1.5]}").is_err());
+        assert!(parse_from_str::<FileDescriptorProto>("{\"publicDependency\": [2147483648]}").is_err());
+        assert!(parse_from_str::<FileDescriptorProto>("[]").is_err());
+        assert_eq!(
+            "field `options`: field `optimize_for`: unknown value of enum `OptimizeMode`: `FAST`",
+            format!(
+                "{}",
+                parse_from_str::<FileDescriptorProto>(
+                    r#"{"options":{"optimize_for":"FAST"}}"#
+                ).unwrap_err()
+            )
+        );
+        assert!(parse_from_str::<Value>(&"[".repeat(200000)).is_err());
+    }
+
+    #[test]
+    fn test_oneof() {
+        let file: FileDescriptorProto = text_format::parse_from_str(r#"
+            name: "oneof.proto"
+            message_type {
+                name: "M"
+                field { name: "a" number: 1 label: LABEL_OPTIONAL type: TYPE_INT32 oneof_index: 0 }
+                field { name: "b" number: 2 label: LABEL_OPTIONAL type: TYPE_STRING oneof_index: 0 }
+                oneof_decl { name: "o" }
+            }
+        "#).unwrap();
+        let mut pool = DescriptorPool::new();
+        pool.add_file(file).unwrap();
+        let d = pool.find_message_by_full_name("M").unwrap();
+
+        let mut m = DynamicMessage::with_descriptor(d);
+        merge_from_str(&mut m, r#"{"a":1,"b":null}"#).unwrap();
+        assert_eq!("a: 1", text_format::print_to_string(&m));
+        assert_eq!(
+            "field `b` is specified along with field `a` of oneof `o`",
+            format!("{}", merge_from_str(&mut m, r#"{"a":1,"b":"x"}"#).unwrap_err())
+        );
+    }
+}
@@ -0,0 +1,537 @@
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt;
+use std::fmt::Write;
+use std::ptr;
+
+use core::Message;
+use reflect::EnumDescriptor;
+use reflect::EnumValueDescriptor;
+use reflect::FieldDescriptor;
+use reflect::ProtobufValue;
+use reflect::ProtobufValueRef;
+use reflect::ReflectFieldRef;
+use reflect::RuntimeFieldType;
+use reflect::RuntimeType;
+use reflect::registry;
+use well_known_types::Any;
+use well_known_types::Duration;
+use well_known_types::FieldMask;
+use well_known_types::ListValue;
+use well_known_types::NullValue;
+use well_known_types::Struct;
+use well_known_types::Timestamp;
+use well_known_types::Value;
+
+use super::base64;
+use super::is_special_well_known_type;
+use super::is_wrapper_type;
+use super::json_value::write_json_string;
+
+
+/// Message cannot be represented as JSON
+#[derive(Debug)]
+pub struct PrintError {
+    message: String,
+}
+
+impl PrintError {
+    fn new(message: String) -> PrintError {
+        PrintError { message: message }
+    }
+}
+
+impl fmt::Display for PrintError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl Error for PrintError {
+    fn description(&self) -> &str {
+        &self.message
+    }
+}
+
+type PrintResult<T> = Result<T, PrintError>;
+
+/// Options for JSON printing
+#[derive(Default, Debug, Clone)]
+pub struct PrintOptions {
+    /// Print enum values as numbers instead of names
+    pub enum_values_int: bool,
+    /// Use original proto field names instead of lowerCamelCase names
+    pub proto_field_name: bool,
+    /// Print fields with default values: proto3 scalars, empty repeated fields and maps
+    pub always_output_default_values: bool,
+}
+
+fn write_f64(v: f64, buf: &mut String) {
+    if v.is_nan() {
+        buf.push_str("\"NaN\"");
+    } else if v == ::std::f64::INFINITY {
+        buf.push_str("\"Infinity\"");
+    } else if v == ::std::f64::NEG_INFINITY {
+        buf.push_str("\"-Infinity\"");
+    } else {
+        write!(buf, "{}", v).unwrap();
+    }
+}
+
+fn write_f32(v: f32, buf: &mut String) {
+    if v.is_finite() {
+        write!(buf, "{}", v).unwrap();
+    } else {
+        write_f64(v as f64, buf);
+    }
+}
+
+/// `google.protobuf.NullValue` is printed as JSON `null`
+fn is_null_value(v: &EnumValueDescriptor) -> bool {
+    let null_value = &EnumDescriptor::for_type::<NullValue>().values()[0];
+    ptr::eq(v, null_value)
+}
+
+/// Order of map keys in output, to make output deterministic
+fn cmp_map_keys(a: &ProtobufValueRef, b: &ProtobufValueRef) -> Ordering {
+    match (a, b) {
+        (&ProtobufValueRef::U32(a), &ProtobufValueRef::U32(b)) => a.cmp(&b),
+        (&ProtobufValueRef::U64(a), &ProtobufValueRef::U64(b)) => a.cmp(&b),
+        (&ProtobufValueRef::I32(a), &ProtobufValueRef::I32(b)) => a.cmp(&b),
+        (&ProtobufValueRef::I64(a), &ProtobufValueRef::I64(b)) => a.cmp(&b),
+        (&ProtobufValueRef::Bool(a), &ProtobufValueRef::Bool(b)) => a.cmp(&b),
+        (&ProtobufValueRef::String(a), &ProtobufValueRef::String(b)) => a.cmp(b),
+        _ => Ordering::Equal,
+    }
+}
+
+/// Convert snake_case field mask path to lowerCamelCase
+fn field_mask_path_to_json(path: &str) -> PrintResult<String> {
+    let mut r = String::with_capacity(path.len());
+    let mut capitalize_next = false;
+    for c in path.chars() {
+        if c.is_ascii_uppercase() || (capitalize_next && !c.is_ascii_lowercase()) {
+            return Err(PrintError::new(format!("cannot convert field mask path `{}` to JSON", path)));
+        }
+        if c == '_' {
+            capitalize_next = true;
+        } else if capitalize_next {
+            r.push(c.to_ascii_uppercase());
+            capitalize_next = false;
+        } else {
+            r.push(c);
+        }
+    }
+    if capitalize_next {
+        return Err(PrintError::new(format!("cannot convert field mask path `{}` to JSON", path)));
+    }
+    Ok(r)
+}
+
+struct Printer<'a> {
+    buf: String,
+    options: &'a PrintOptions,
+}
+
+impl<'a> Printer<'a> {
+    fn print_string(&mut self, s: &str) {
+        write_json_string(s, &mut self.buf);
+    }
+
+    fn print_value(&mut self, value: &ProtobufValueRef) -> PrintResult<()> {
+        match *value {
+            ProtobufValueRef::U32(v) => write!(self.buf, "{}", v).unwrap(),
+            ProtobufValueRef::I32(v) => write!(self.buf, "{}", v).unwrap(),
+            ProtobufValueRef::U64(v) => write!(self.buf, "\"{}\"", v).unwrap(),
+            ProtobufValueRef::I64(v) => write!(self.buf, "\"{}\"", v).unwrap(),
+            ProtobufValueRef::F32(v) => write_f32(v, &mut self.buf),
+            ProtobufValueRef::F64(v) => write_f64(v, &mut self.buf),
+            ProtobufValueRef::Bool(v) => write!(self.buf, "{}", v).unwrap(),
+            ProtobufValueRef::String(v) => self.print_string(v),
+            ProtobufValueRef::Bytes(v) => self.print_string(&base64::encode(v)),
+            ProtobufValueRef::Enum(v) => {
+                if is_null_value(v) {
+                    self.buf.push_str("null");
+                } else if self.options.enum_values_int {
+                    write!(self.buf, "{}", v.value()).unwrap();
+                } else {
+                    self.print_string(v.name());
+                }
+            }
+            ProtobufValueRef::UnknownEnum(_, v) => write!(self.buf, "{}", v).unwrap(),
+            ProtobufValueRef::Message(m) => self.print_message(m)?,
+            ProtobufValueRef::OwnedMessage(ref m) => self.print_message(&**m)?,
+        }
+        Ok(())
+    }
+
+    /// Print value as JSON object key
+    fn print_map_key(&mut self, key: &ProtobufValueRef) -> PrintResult<()> {
+        match *key {
+            ProtobufValueRef::String(s) => self.print_string(s),
+            ProtobufValueRef::U64(..) | ProtobufValueRef::I64(..) => self.print_value(key)?,
+            ref key => {
+                self.buf.push('"');
+                self.print_value(key)?;
+                self.buf.push('"');
+            }
+        }
+        Ok(())
+    }
+
+    /// Print default value of field which is not set
+    fn print_field_name(&mut self, first: &mut bool, field: &FieldDescriptor) {
+        if !*first {
+            self.buf.push(',');
+        }
+        *first = false;
+        if self.options.proto_field_name {
+            self.print_string(field.name());
+        } else {
+            self.print_string(&field.json_name());
+        }
+        self.buf.push(':');
+    }
+
+    fn print_fields(&mut self, m: &Message, first: &mut bool) -> PrintResult<()> {
+        for field in m.descriptor().fields() {
+            match field.get_reflect(m) {
+                ReflectFieldRef::Repeated(repeated) => {
+                    if repeated.len() == 0 && !self.options.always_output_default_values {
+                        continue;
+                    }
+                    self.print_field_name(first, field);
+                    self.buf.push('[');
+                    for (i, v) in repeated.reflect_iter().enumerate() {
+                        if i != 0 {
+                            self.buf.push(',');
+                        }
+                        self.print_value(&v.as_ref())?;
+                    }
+                    self.buf.push(']');
+                }
+                ReflectFieldRef::Map(map) => {
+                    if map.len() == 0 && !self.options.always_output_default_values {
+                        continue;
+                    }
+                    self.print_field_name(first, field);
+                    let mut entries: Vec<_> =
+                        map.reflect_iter().map(|(k, v)| (k.as_ref(), v.as_ref())).collect();
+                    entries.sort_by(|a, b| cmp_map_keys(&a.0, &b.0));
+                    self.buf.push('{');
+                    for (i, &(ref k, ref v)) in entries.iter().enumerate() {
+                        if i != 0 {
+                            self.buf.push(',');
+                        }
+                        self.print_map_key(k)?;
+                        self.buf.push(':');
+                        self.print_value(v)?;
+                    }
+                    self.buf.push('}');
+                }
+                ReflectFieldRef::Optional(Some(v)) => {
+                    self.print_field_name(first, field);
+                    self.print_value(&v)?;
+                }
+                ReflectFieldRef::Optional(None) => {
+                    if !self.options.always_output_default_values ||
+                        field.containing_oneof().is_some()
+                    {
+                        continue;
+                    }
+                    if let RuntimeFieldType::Singular(RuntimeType::Message(..)) =
+                        field.runtime_field_type()
+                    {
+                        continue;
+                    }
+                    if let Some(v) = field.default_value() {
+                        self.print_field_name(first, field);
+                        self.print_value(&v.as_ref())?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn print_timestamp(&mut self, t: &Timestamp) -> PrintResult<()> {
+        if !t.is_valid() {
+            return Err(PrintError::new(format!("timestamp is out of range: {:?}", t)));
+        }
+        self.print_string(&t.to_string());
+        Ok(())
+    }
+
+    fn print_duration(&mut self, d: &Duration) -> PrintResult<()> {
+        if !d.is_valid() {
+            return Err(PrintError::new(format!("duration is out of range: {:?}", d)));
+        }
+        write!(self.buf, "\"{}\"", d).unwrap();
+        Ok(())
+    }
+
+    fn print_field_mask(&mut self, mask: &FieldMask) -> PrintResult<()> {
+        let paths = mask
+            .get_paths()
+            .iter()
+            .map(|p| field_mask_path_to_json(p))
+            .collect::<PrintResult<Vec<_>>>()?;
+        self.print_string(&paths.join(","));
+        Ok(())
+    }
+
+    fn print_struct(&mut self, s: &Struct) -> PrintResult<()> {
+        let mut fields: Vec<_> = s.get_fields().iter().collect();
+        fields.sort_by(|a, b| a.0.cmp(b.0));
+        self.buf.push('{');
+        for (i, &(k, v)) in fields.iter().enumerate() {
+            if i != 0 {
+                self.buf.push(',');
+            }
+            self.print_string(k);
+            self.buf.push(':');
+            self.print_json_value(v)?;
+        }
+        self.buf.push('}');
+        Ok(())
+    }
+
+    fn print_list_value(&mut self, l: &ListValue) -> PrintResult<()> {
+        self.buf.push('[');
+        for (i, v) in l.get_values().iter().enumerate() {
+            if i != 0 {
+                self.buf.push(',');
+            }
+            self.print_json_value(v)?;
+        }
+        self.buf.push(']');
+        Ok(())
+    }
+
+    fn print_json_value(&mut self, v: &Value) -> PrintResult<()> {
+        if v.has_null_value() {
+            self.buf.push_str("null");
+        } else if v.has_number_value() {
+            let n = v.get_number_value();
+            if !n.is_finite() {
+                return Err(PrintError::new(format!("`Value` cannot be {}", n)));
+            }
+            write_f64(n, &mut self.buf);
+        } else if v.has_string_value() {
+            self.print_string(v.get_string_value());
+        } else if v.has_bool_value() {
+            write!(self.buf, "{}", v.get_bool_value()).unwrap();
+        } else if v.has_struct_value() {
+            self.print_struct(v.get_struct_value())?;
+        } else if v.has_list_value() {
+            self.print_list_value(v.get_list_value())?;
+        } else {
+            return Err(PrintError::new("`Value` has no kind set".to_owned()));
+        }
+        Ok(())
+    }
+
+    fn print_wrapper(&mut self, m: &Message) -> PrintResult<()> {
+        let field = &m.descriptor().fields()[0];
+        match field.get_reflect(m) {
+            ReflectFieldRef::Optional(Some(v)) => self.print_value(&v),
+            ReflectFieldRef::Optional(None) => {
+                self.print_value(&field.default_value().unwrap().as_ref())
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn print_any(&mut self, any: &Any) -> PrintResult<()> {
+        let descriptor = match registry::find_message_by_type_url(any.get_type_url()) {
+            Some(d) => d,
+            None => {
+                return Err(PrintError::new(
+                    format!("cannot resolve `Any` type URL `{}`", any.get_type_url()),
+                ));
+            }
+        };
+        let mut message = descriptor.new_instance();
+        message
+            .merge_from_bytes(any.get_value())
+            .map_err(|e| PrintError::new(format!("failed to decode `Any` content: {}", e)))?;
+
+        self.buf.push_str("{\"@type\":");
+        self.print_string(any.get_type_url());
+        if is_special_well_known_type(descriptor.full_name()) {
+            self.buf.push_str(",\"value\":");
+            self.print_message(&*message)?;
+        } else {
+            let mut first = false;
+            self.print_fields(&*message, &mut first)?;
+        }
+        self.buf.push('}');
+        Ok(())
+    }
+
+    fn print_message(&mut self, m: &Message) -> PrintResult<()> {
+        let full_name = m.descriptor().full_name();
+        if is_wrapper_type(full_name) {
+            return self.print_wrapper(m);
+        }
+        if let Some(t) = m.as_any().downcast_ref::<Timestamp>() {
+            return self.print_timestamp(t);
+        }
+        if let Some(d) = m.as_any().downcast_ref::<Duration>() {
+            return self.print_duration(d);
+        }
+        if let Some(f) = m.as_any().downcast_ref::<FieldMask>() {
+            return self.print_field_mask(f);
+        }
+        if let Some(s) = m.as_any().downcast_ref::<Struct>() {
+            return self.print_struct(s);
+        }
+        if let Some(l) = m.as_any().downcast_ref::<ListValue>() {
+            return self.print_list_value(l);
+        }
+        if let Some(v) = m.as_any().downcast_ref::<Value>() {
+            return self.print_json_value(v);
+        }
+        if let Some(a) = m.as_any().downcast_ref::<Any>() {
+            return self.print_any(a);
+        }
+
+        self.buf.push('{');
+        let mut first = true;
+        self.print_fields(m, &mut first)?;
+        self.buf.push('}');
+        Ok(())
+    }
+}
+
+/// Print message as JSON using default options
+pub fn print_to_string(message: &Message) -> Result<String, PrintError> {
+    print_to_string_with_options(message, &PrintOptions::default())
+}
+
+/// Print message as JSON
+pub fn print_to_string_with_options(
+    message: &Message,
+    options: &PrintOptions,
+) -> Result<String, PrintError> {
+    let mut printer = Printer {
+        buf: String::new(),
+        options: options,
+    };
+    printer.print_message(message)?;
+    Ok(printer.buf)
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::f64;
+
+    use descriptor::FieldDescriptorProto;
+    use descriptor::FieldDescriptorProto_Type;
+    use descriptor::FileDescriptorProto;
+    use descriptor::FileOptions;
+    use well_known_types::Int64Value;
+    use Message;
+
+    #[test]
+    fn test_print_proto2() {
+        let mut field = FieldDescriptorProto::new();
+        field.set_name("foo".to_owned());
+        field.set_json_name("fooJson".to_owned());
+        field.set_field_type(FieldDescriptorProto_Type::TYPE_BYTES);
+        field.set_default_value("a\"\n".to_owned());
+
+        let mut file = FileDescriptorProto::new();
+        file.set_name("a.proto".to_owned());
+        file.mut_dependency().push("b.proto".to_owned());
+        file.mut_public_dependency().push(3);
+        file.mut_message_type().push(Default::default());
+        file.mut_message_type()[0].mut_field().push(field);
+
+        assert_eq!(
+            r#"{"name":"a.proto","dependency":["b.proto"],"publicDependency":[3],"#.to_owned() +
+                r#""messageType":[{"field":[{"name":"foo","type":"TYPE_BYTES","#  +
+                r#""defaultValue":"a\"\n","jsonName":"fooJson"}]}]}"#,
+            print_to_string(&file).unwrap()
+        );
+
+        let options = PrintOptions {
+            enum_values_int: true,
+            proto_field_name: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            r#"{"name":"a.proto","dependency":["b.proto"],"public_dependency":[3],"#.to_owned() +
+                r#""message_type":[{"field":[{"name":"foo","type":12,"#  +
+                r#""default_value":"a\"\n","json_name":"fooJson"}]}]}"#,
+            print_to_string_with_options(&file, &options).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_print_default_values() {
+        let options = PrintOptions {
+            always_output_default_values: true,
+            ..Default::default()
+        };
+        let mut v = Int64Value::new();
+        assert_eq!("\"0\"", print_to_string_with_options(&v, &options).unwrap());
+        v.set_value(-10);
+        assert_eq!("\"-10\"", print_to_string(&v).unwrap());
+
+        let mut f = FieldMask::new();
+        assert_eq!("\"\"", print_to_string_with_options(&f, &options).unwrap());
+        f.mut_paths().push("foo_bar.baz".to_owned());
+        f.mut_paths().push("qux".to_owned());
+        assert_eq!("\"fooBar.baz,qux\"", print_to_string(&f).unwrap());
+
+        let json = print_to_string_with_options(&FileOptions::new(), &options).unwrap();
+        assert!(json.contains(r#""optimizeFor":"SPEED""#), "{}", json);
+        assert!(json.contains(r#""javaMultipleFiles":false"#), "{}", json);
+    }
+
+    #[test]
+    fn test_print_well_known_types() {
+        let mut t = Timestamp::new();
+        t.set_seconds(63108020);
+        t.set_nanos(21000000);
+        assert_eq!("\"1972-01-01T10:00:20.021Z\"", print_to_string(&t).unwrap());
+
+        let mut d = Duration::new();
+        d.set_seconds(-1);
+        d.set_nanos(-500000000);
+        assert_eq!("\"-1.500s\"", print_to_string(&d).unwrap());
+        d.set_seconds(3);
+        d.set_nanos(1);
+        assert_eq!("\"3.000000001s\"", print_to_string(&d).unwrap());
+        d.set_seconds(-3);
+        assert!(print_to_string(&d).is_err());
+
+        let mut list = ListValue::new();
+        list.mut_values().push(Value::new());
+        list.mut_values()[0].set_null_value(NullValue::NULL_VALUE);
+        list.mut_values().push(Value::new());
+        list.mut_values()[1].set_number_value(1.5);
+        let mut s = Struct::new();
+        s.mut_fields().insert("b".to_owned(), Value::new());
+        s.mut_fields().get_mut("b").unwrap().set_list_value(list);
+        s.mut_fields().insert("a".to_owned(), Value::new());
+        s.mut_fields().get_mut("a").unwrap().set_string_value("x".to_owned());
+        assert_eq!(r#"{"a":"x","b":[null,1.5]}"#, print_to_string(&s).unwrap());
+
+        let mut v = Value::new();
+        v.set_number_value(f64::NAN);
+        assert!(print_to_string(&v).is_err());
+
+        let mut any = Any::new();
+        any.set_type_url("type.googleapis.com/google.protobuf.Timestamp".to_owned());
+        any.set_value(t.write_to_bytes().unwrap());
+        assert_eq!(
+            r#"{"@type":"type.googleapis.com/google.protobuf.Timestamp","value":"1972-01-01T10:00:20.021Z"}"#,
+            print_to_string(&any).unwrap()
+        );
+    }
+}
@@ -0,0 +1,242 @@
+//! RFC 3339 timestamps used for `google.protobuf.Timestamp`.
+
+use std::fmt::Write;
+
+/// Seconds of `0001-01-01T00:00:00Z`
+pub const MIN_SECONDS: i64 = -62135596800;
+/// Seconds of `9999-12-31T23:59:59Z`
+pub const MAX_SECONDS: i64 = 253402300799;
+
+const SECONDS_IN_DAY: i64 = 86400;
+
+// Algorithms from http://howardhinnant.github.io/date_algorithms.html
+
+fn days_from_civil(y: i64, m: u32, d: u32) -> i64 {
+    let y = if m <= 2 { y - 1 } else { y };
+    let era = if y >= 0 { y } else { y - 399 } / 400;
+    let yoe = y - era * 400;
+    let mp = ((m + 9) % 12) as i64;
+    let doy = (153 * mp + 2) / 5 + d as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+fn civil_from_days(z: i64) -> (i64, u32, u32) {
+    let z = z + 719468;
+    let era = if z >= 0 { z } else { z - 146096 } / 146097;
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let y = yoe + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    (if m <= 2 { y + 1 } else { y }, m, d)
+}
+
+fn is_leap_year(y: i64) -> bool {
+    y % 4 == 0 && (y % 100 != 0 || y % 400 == 0)
+}
+
+fn days_in_month(y: i64, m: u32) -> u32 {
+    match m {
+        2 if is_leap_year(y) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Append fraction of second using 0, 3, 6 or 9 digits
+pub fn write_nanos(nanos: u32, buf: &mut String) {
+    if nanos == 0 {
+        // no fraction
+    } else if nanos % 1_000_000 == 0 {
+        write!(buf, ".{:03}", nanos / 1_000_000).unwrap();
+    } else if nanos % 1_000 == 0 {
+        write!(buf, ".{:06}", nanos / 1_000).unwrap();
+    } else {
+        write!(buf, ".{:09}", nanos).unwrap();
+    }
+}
+
+/// Format UTC time as RFC 3339 string like `1972-01-01T10:00:20.021Z`.
+///
+/// Seconds must be in range `MIN_SECONDS..=MAX_SECONDS`,
+/// nanos must be in range `0..1_000_000_000`.
+pub fn format(seconds: i64, nanos: u32) -> String {
+    assert!(seconds >= MIN_SECONDS && seconds <= MAX_SECONDS);
+    assert!(nanos < 1_000_000_000);
+
+    let days = if seconds >= 0 {
+        seconds / SECONDS_IN_DAY
+    } else {
+        (seconds + 1) / SECONDS_IN_DAY - 1
+    };
+    let secs_of_day = seconds - days * SECONDS_IN_DAY;
+    let (y, m, d) = civil_from_days(days);
+
+    let mut r = String::new();
+    write!(
+        r,
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+        y,
+        m,
+        d,
+        secs_of_day / 3600,
+        secs_of_day / 60 % 60,
+        secs_of_day % 60
+    ).unwrap();
+    write_nanos(nanos, &mut r);
+    r.push('Z');
+    r
+}
+
+/// Error returned when parsing malformed RFC 3339 string
+#[derive(Debug, PartialEq, Eq)]
+pub struct Rfc3339ParseError;
+
+struct Parser<'a> {
+    s: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn next_digits(&mut self, count: usize) -> Result<u32, Rfc3339ParseError> {
+        let mut r = 0;
+        for _ in 0..count {
+            match self.s.get(self.pos) {
+                Some(&c @ b'0'...b'9') => r = r * 10 + (c - b'0') as u32,
+                _ => return Err(Rfc3339ParseError),
+            }
+            self.pos += 1;
+        }
+        Ok(r)
+    }
+
+    fn next_char_if(&mut self, f: &Fn(u8) -> bool) -> Option<u8> {
+        match self.s.get(self.pos) {
+            Some(&c) if f(c) => {
+                self.pos += 1;
+                Some(c)
+            }
+            _ => None,
+        }
+    }
+
+    fn expect_char(&mut self, c: u8) -> Result<(), Rfc3339ParseError> {
+        match self.next_char_if(&|n| n == c) {
+            Some(_) => Ok(()),
+            None => Err(Rfc3339ParseError),
+        }
+    }
+}
+
+/// Parse RFC 3339 string, return seconds since epoch and nanos.
+///
+/// Time zone offsets are accepted, result is normalized to UTC.
+pub fn parse(s: &str) -> Result<(i64, u32), Rfc3339ParseError> {
+    let mut p = Parser { s: s.as_bytes(), pos: 0 };
+
+    let year = p.next_digits(4)? as i64;
+    p.expect_char(b'-')?;
+    let month = p.next_digits(2)?;
+    p.expect_char(b'-')?;
+    let day = p.next_digits(2)?;
+    if p.next_char_if(&|c| c == b'T' || c == b't' || c == b' ').is_none() {
+        return Err(Rfc3339ParseError);
+    }
+    let hour = p.next_digits(2)? as i64;
+    p.expect_char(b':')?;
+    let minute = p.next_digits(2)? as i64;
+    p.expect_char(b':')?;
+    let second = p.next_digits(2)? as i64;
+
+    if month < 1 || month > 12 || day < 1 || day > days_in_month(year, month) || hour > 23 ||
+        minute > 59 || second > 59
+    {
+        return Err(Rfc3339ParseError);
+    }
+
+    let mut nanos = 0;
+    if p.next_char_if(&|c| c == b'.').is_some() {
+        let mut digits = 0;
+        while let Some(c) = p.next_char_if(&|c| c >= b'0' && c <= b'9') {
+            if digits == 9 {
+                return Err(Rfc3339ParseError);
+            }
+            nanos = nanos * 10 + (c - b'0') as u32;
+            digits += 1;
+        }
+        if digits == 0 {
+            return Err(Rfc3339ParseError);
+        }
+        for _ in digits..9 {
+            nanos *= 10;
+        }
+    }
+
+    let offset = match p.next_char_if(&|_| true) {
+        Some(b'Z') | Some(b'z') => 0,
+        Some(sign @ b'+') | Some(sign @ b'-') => {
+            let h = p.next_digits(2)? as i64;
+            p.expect_char(b':')?;
+            let m = p.next_digits(2)? as i64;
+            if h > 23 || m > 59 {
+                return Err(Rfc3339ParseError);
+            }
+            let offset = h * 3600 + m * 60;
+            if sign == b'+' { offset } else { -offset }
+        }
+        _ => return Err(Rfc3339ParseError),
+    };
+
+    if p.pos != p.s.len() {
+        return Err(Rfc3339ParseError);
+    }
+
+    let seconds = days_from_civil(year, month, day) * SECONDS_IN_DAY + hour * 3600 +
+        minute * 60 + second - offset;
+    if seconds < MIN_SECONDS || seconds > MAX_SECONDS {
+        return Err(Rfc3339ParseError);
+    }
+
+    Ok((seconds, nanos))
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_format() {
+        assert_eq!("1970-01-01T00:00:00Z", format(0, 0));
+        assert_eq!("1972-01-01T10:00:20.021Z", format(63108020, 21000000));
+        assert_eq!("1969-12-31T23:59:59.000001Z", format(-1, 1000));
+        assert_eq!("2000-02-29T12:00:00.000000001Z", format(951825600, 1));
+        assert_eq!("0001-01-01T00:00:00Z", format(MIN_SECONDS, 0));
+        assert_eq!("9999-12-31T23:59:59.999999999Z", format(MAX_SECONDS, 999999999));
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Ok((0, 0)), parse("1970-01-01T00:00:00Z"));
+        assert_eq!(Ok((63108020, 21000000)), parse("1972-01-01T10:00:20.021Z"));
+        assert_eq!(Ok((63108020, 21000000)), parse("1972-01-01T12:30:20.021+02:30"));
+        assert_eq!(Ok((-1, 100)), parse("1969-12-31t23:59:59.0000001z"));
+        assert_eq!(Ok((MIN_SECONDS, 0)), parse("0001-01-01T00:00:00Z"));
+        assert_eq!(Ok((MAX_SECONDS, 0)), parse("9999-12-31T23:59:59Z"));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert!(parse("1970-01-01T00:00:00").is_err());
+        assert!(parse("1970-01-01T00:00:00.Z").is_err());
+        assert!(parse("1970-01-01T00:00:00.1234567891Z").is_err());
+        assert!(parse("1970-02-30T00:00:00Z").is_err());
+        assert!(parse("1970-01-01T24:00:00Z").is_err());
+        assert!(parse("0001-01-01T00:00:00+00:01").is_err());
+        assert!(parse("1970-01-01T00:00:00Zx").is_err());
+    }
+}
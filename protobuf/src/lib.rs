@@ -5,6 +5,20 @@
 
 #[cfg(feature = "bytes")]
 extern crate bytes;
+#[cfg(feature = "futures")]
+#[macro_use]
+extern crate futures;
+#[cfg(feature = "tokio-io")]
+extern crate tokio_io;
+#[cfg(feature = "tokio-codec")]
+extern crate tokio_codec;
+#[cfg(feature = "with-serde")]
+extern crate serde;
+#[cfg(feature = "with-serde")]
+#[macro_use]
+extern crate serde_derive;
+#[cfg(all(test, feature = "with-serde"))]
+extern crate serde_test;
 
 pub use unknown::UnknownFields;
 pub use unknown::UnknownFieldsIter;
@@ -18,8 +32,11 @@ pub use singular::SingularPtrField;
 pub use clear::Clear;
 pub use core::Message;
 pub use core::ProtobufEnum;
+pub use enum_or_unknown::EnumOrUnknown;
 pub use core::parse_from_bytes;
 pub use core::parse_from_reader;
+pub use core::parse_from_bytes_with_options;
+pub use core::parse_from_reader_with_options;
 #[cfg(feature = "bytes")]
 pub use core::parse_from_carllerche_bytes;
 pub use core::parse_length_delimited_from;
@@ -27,6 +44,7 @@ pub use core::parse_length_delimited_from_reader;
 pub use core::parse_length_delimited_from_bytes;
 pub use stream::CodedInputStream;
 pub use stream::CodedOutputStream;
+pub use stream::ParseOptions;
 pub use stream::wire_format;
 pub use error::ProtobufResult;
 pub use error::ProtobufError;
@@ -40,6 +58,7 @@ pub mod plugin;
 pub mod rustproto;
 
 mod core;
+mod enum_or_unknown;
 pub mod rt;
 pub mod lazy;
 pub mod compiler_plugin;
@@ -48,11 +67,18 @@ mod singular;
 mod clear;
 pub mod reflect;
 pub mod text_format;
+pub mod json;
+pub mod diff;
 pub mod stream;
 pub mod error;
 pub mod types;
 pub mod well_known_types;
+pub mod well_known_types_util;
 pub mod ext;
+pub mod rpc;
+pub mod view;
+#[cfg(feature = "tokio-codec")]
+pub mod codec;
 
 // used by test
 #[cfg(test)]
@@ -22,13 +22,16 @@ use protobuf::Message as Message_imported_for_functions;
 use protobuf::ProtobufEnum as ProtobufEnum_imported_for_functions;
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
 pub struct CodeGeneratorRequest {
     // message fields
     file_to_generate: ::protobuf::RepeatedField<::std::string::String>,
     parameter: ::protobuf::SingularField<::std::string::String>,
     proto_file: ::protobuf::RepeatedField<::protobuf::descriptor::FileDescriptorProto>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     cached_size: ::protobuf::CachedSize,
 }
 
@@ -285,12 +288,16 @@ impl ::protobuf::reflect::ProtobufValue for CodeGeneratorRequest {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
 pub struct CodeGeneratorResponse {
     // message fields
     error: ::protobuf::SingularField<::std::string::String>,
+    supported_features: ::std::option::Option<u64>,
     file: ::protobuf::RepeatedField<CodeGeneratorResponse_File>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     cached_size: ::protobuf::CachedSize,
 }
 
@@ -335,6 +342,25 @@ impl CodeGeneratorResponse {
         }
     }
 
+    // optional uint64 supported_features = 2;
+
+    pub fn clear_supported_features(&mut self) {
+        self.supported_features = ::std::option::Option::None;
+    }
+
+    pub fn has_supported_features(&self) -> bool {
+        self.supported_features.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_supported_features(&mut self, v: u64) {
+        self.supported_features = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_supported_features(&self) -> u64 {
+        self.supported_features.unwrap_or(0)
+    }
+
     // repeated .google.protobuf.compiler.CodeGeneratorResponse.File file = 15;
 
     pub fn clear_file(&mut self) {
@@ -378,6 +404,13 @@ impl ::protobuf::Message for CodeGeneratorResponse {
                 1 => {
                     ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.error)?;
                 },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.supported_features = ::std::option::Option::Some(tmp);
+                },
                 15 => {
                     ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.file)?;
                 },
@@ -396,6 +429,9 @@ impl ::protobuf::Message for CodeGeneratorResponse {
         if let Some(ref v) = self.error.as_ref() {
             my_size += ::protobuf::rt::string_size(1, &v);
         }
+        if let Some(v) = self.supported_features {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
         for value in &self.file {
             let len = value.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
@@ -409,6 +445,9 @@ impl ::protobuf::Message for CodeGeneratorResponse {
         if let Some(ref v) = self.error.as_ref() {
             os.write_string(1, &v)?;
         }
+        if let Some(v) = self.supported_features {
+            os.write_uint64(2, v)?;
+        }
         for v in &self.file {
             os.write_tag(15, ::protobuf::wire_format::WireTypeLengthDelimited)?;
             os.write_raw_varint32(v.get_cached_size())?;
@@ -461,6 +500,11 @@ impl ::protobuf::Message for CodeGeneratorResponse {
                     |m: &CodeGeneratorResponse| { &m.error },
                     |m: &mut CodeGeneratorResponse| { &mut m.error },
                 ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "supported_features",
+                    |m: &CodeGeneratorResponse| { &m.supported_features },
+                    |m: &mut CodeGeneratorResponse| { &mut m.supported_features },
+                ));
                 fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CodeGeneratorResponse_File>>(
                     "file",
                     |m: &CodeGeneratorResponse| { &m.file },
@@ -489,6 +533,7 @@ impl ::protobuf::Message for CodeGeneratorResponse {
 impl ::protobuf::Clear for CodeGeneratorResponse {
     fn clear(&mut self) {
         self.clear_error();
+        self.clear_supported_features();
         self.clear_file();
         self.unknown_fields.clear();
     }
@@ -507,13 +552,16 @@ impl ::protobuf::reflect::ProtobufValue for CodeGeneratorResponse {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
 pub struct CodeGeneratorResponse_File {
     // message fields
     name: ::protobuf::SingularField<::std::string::String>,
     insertion_point: ::protobuf::SingularField<::std::string::String>,
     content: ::protobuf::SingularField<::std::string::String>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     cached_size: ::protobuf::CachedSize,
 }
 
@@ -783,203 +831,283 @@ impl ::protobuf::reflect::ProtobufValue for CodeGeneratorResponse_File {
     }
 }
 
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub enum CodeGeneratorResponse_Feature {
+    FEATURE_NONE = 0,
+    FEATURE_PROTO3_OPTIONAL = 1,
+}
+
+impl ::protobuf::ProtobufEnum for CodeGeneratorResponse_Feature {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<CodeGeneratorResponse_Feature> {
+        match value {
+            0 => ::std::option::Option::Some(CodeGeneratorResponse_Feature::FEATURE_NONE),
+            1 => ::std::option::Option::Some(CodeGeneratorResponse_Feature::FEATURE_PROTO3_OPTIONAL),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [CodeGeneratorResponse_Feature] = &[
+            CodeGeneratorResponse_Feature::FEATURE_NONE,
+            CodeGeneratorResponse_Feature::FEATURE_PROTO3_OPTIONAL,
+        ];
+        values
+    }
+
+    fn get_values() -> ::std::option::Option<&'static [Self]> {
+        ::std::option::Option::Some(<Self as ::protobuf::ProtobufEnum>::values())
+    }
+
+    fn enum_descriptor_static(_: ::std::option::Option<CodeGeneratorResponse_Feature>) -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("CodeGeneratorResponse_Feature", file_descriptor_proto())
+            })
+        }
+    }
+
+    fn get_enum_descriptor_static(_: ::std::option::Option<CodeGeneratorResponse_Feature>) -> ::std::option::Option<&'static ::protobuf::reflect::EnumDescriptor> {
+        ::std::option::Option::Some(<CodeGeneratorResponse_Feature as ::protobuf::ProtobufEnum>::enum_descriptor_static(::std::option::Option::None))
+    }
+}
+
+impl ::std::marker::Copy for CodeGeneratorResponse_Feature {
+}
+
+impl ::protobuf::reflect::ProtobufValue for CodeGeneratorResponse_Feature {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
+    }
+}
+
+// Register message types and extensions of this file in `::protobuf::reflect::registry`
+pub fn register_message_types() {
+    ::protobuf::reflect::registry::register_message::<CodeGeneratorRequest>();
+    ::protobuf::reflect::registry::register_message::<CodeGeneratorResponse>();
+    ::protobuf::reflect::registry::register_message::<CodeGeneratorResponse_File>();
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n%google/protobuf/compiler/plugin.proto\x12\x18google.protobuf.compiler\
     \x1a\x20google/protobuf/descriptor.proto\"\xa3\x01\n\x14CodeGeneratorReq\
     uest\x12(\n\x10file_to_generate\x18\x01\x20\x03(\tR\x0efileToGenerate\
     \x12\x1c\n\tparameter\x18\x02\x20\x01(\tR\tparameter\x12C\n\nproto_file\
     \x18\x0f\x20\x03(\x0b2$.google.protobuf.FileDescriptorProtoR\tprotoFile\
-    \"\xd6\x01\n\x15CodeGeneratorResponse\x12\x14\n\x05error\x18\x01\x20\x01\
-    (\tR\x05error\x12H\n\x04file\x18\x0f\x20\x03(\x0b24.google.protobuf.comp\
+    \"\xbf\x02\n\x15CodeGeneratorResponse\x12\x14\n\x05error\x18\x01\x20\x01\
+    (\tR\x05error\x12-\n\x12supported_features\x18\x02\x20\x01(\x04R\x11supp\
+    ortedFeatures\x12H\n\x04file\x18\x0f\x20\x03(\x0b24.google.protobuf.comp\
     iler.CodeGeneratorResponse.FileR\x04file\x1a]\n\x04File\x12\x12\n\x04nam\
     e\x18\x01\x20\x01(\tR\x04name\x12'\n\x0finsertion_point\x18\x02\x20\x01(\
     \tR\x0einsertionPoint\x12\x18\n\x07content\x18\x0f\x20\x01(\tR\x07conten\
-    tB7\n\x1ccom.google.protobuf.compilerB\x0cPluginProtosZ\tplugin_goJ\x89;\
-    \n\x07\x12\x05.\0\x95\x01\x01\n\xca\x11\n\x01\x0c\x12\x03.\0\x122\xc1\
-    \x0c\x20Protocol\x20Buffers\x20-\x20Google's\x20data\x20interchange\x20f\
-    ormat\n\x20Copyright\x202008\x20Google\x20Inc.\x20\x20All\x20rights\x20r\
-    eserved.\n\x20https://developers.google.com/protocol-buffers/\n\n\x20Red\
-    istribution\x20and\x20use\x20in\x20source\x20and\x20binary\x20forms,\x20\
-    with\x20or\x20without\n\x20modification,\x20are\x20permitted\x20provided\
-    \x20that\x20the\x20following\x20conditions\x20are\n\x20met:\n\n\x20\x20\
-    \x20\x20\x20*\x20Redistributions\x20of\x20source\x20code\x20must\x20reta\
-    in\x20the\x20above\x20copyright\n\x20notice,\x20this\x20list\x20of\x20co\
-    nditions\x20and\x20the\x20following\x20disclaimer.\n\x20\x20\x20\x20\x20\
-    *\x20Redistributions\x20in\x20binary\x20form\x20must\x20reproduce\x20the\
-    \x20above\n\x20copyright\x20notice,\x20this\x20list\x20of\x20conditions\
-    \x20and\x20the\x20following\x20disclaimer\n\x20in\x20the\x20documentatio\
-    n\x20and/or\x20other\x20materials\x20provided\x20with\x20the\n\x20distri\
-    bution.\n\x20\x20\x20\x20\x20*\x20Neither\x20the\x20name\x20of\x20Google\
-    \x20Inc.\x20nor\x20the\x20names\x20of\x20its\n\x20contributors\x20may\
-    \x20be\x20used\x20to\x20endorse\x20or\x20promote\x20products\x20derived\
-    \x20from\n\x20this\x20software\x20without\x20specific\x20prior\x20writte\
-    n\x20permission.\n\n\x20THIS\x20SOFTWARE\x20IS\x20PROVIDED\x20BY\x20THE\
-    \x20COPYRIGHT\x20HOLDERS\x20AND\x20CONTRIBUTORS\n\x20\"AS\x20IS\"\x20AND\
-    \x20ANY\x20EXPRESS\x20OR\x20IMPLIED\x20WARRANTIES,\x20INCLUDING,\x20BUT\
-    \x20NOT\n\x20LIMITED\x20TO,\x20THE\x20IMPLIED\x20WARRANTIES\x20OF\x20MER\
-    CHANTABILITY\x20AND\x20FITNESS\x20FOR\n\x20A\x20PARTICULAR\x20PURPOSE\
-    \x20ARE\x20DISCLAIMED.\x20IN\x20NO\x20EVENT\x20SHALL\x20THE\x20COPYRIGHT\
-    \n\x20OWNER\x20OR\x20CONTRIBUTORS\x20BE\x20LIABLE\x20FOR\x20ANY\x20DIREC\
-    T,\x20INDIRECT,\x20INCIDENTAL,\n\x20SPECIAL,\x20EXEMPLARY,\x20OR\x20CONS\
-    EQUENTIAL\x20DAMAGES\x20(INCLUDING,\x20BUT\x20NOT\n\x20LIMITED\x20TO,\
-    \x20PROCUREMENT\x20OF\x20SUBSTITUTE\x20GOODS\x20OR\x20SERVICES;\x20LOSS\
-    \x20OF\x20USE,\n\x20DATA,\x20OR\x20PROFITS;\x20OR\x20BUSINESS\x20INTERRU\
-    PTION)\x20HOWEVER\x20CAUSED\x20AND\x20ON\x20ANY\n\x20THEORY\x20OF\x20LIA\
-    BILITY,\x20WHETHER\x20IN\x20CONTRACT,\x20STRICT\x20LIABILITY,\x20OR\x20T\
-    ORT\n\x20(INCLUDING\x20NEGLIGENCE\x20OR\x20OTHERWISE)\x20ARISING\x20IN\
-    \x20ANY\x20WAY\x20OUT\x20OF\x20THE\x20USE\n\x20OF\x20THIS\x20SOFTWARE,\
-    \x20EVEN\x20IF\x20ADVISED\x20OF\x20THE\x20POSSIBILITY\x20OF\x20SUCH\x20D\
-    AMAGE.\n2\xfb\x04\x20Author:\x20kenton@google.com\x20(Kenton\x20Varda)\n\
-    \n\x20WARNING:\x20\x20The\x20plugin\x20interface\x20is\x20currently\x20E\
-    XPERIMENTAL\x20and\x20is\x20subject\x20to\n\x20\x20\x20change.\n\n\x20pr\
-    otoc\x20(aka\x20the\x20Protocol\x20Compiler)\x20can\x20be\x20extended\
-    \x20via\x20plugins.\x20\x20A\x20plugin\x20is\n\x20just\x20a\x20program\
-    \x20that\x20reads\x20a\x20CodeGeneratorRequest\x20from\x20stdin\x20and\
-    \x20writes\x20a\n\x20CodeGeneratorResponse\x20to\x20stdout.\n\n\x20Plugi\
-    ns\x20written\x20using\x20C++\x20can\x20use\x20google/protobuf/compiler/\
-    plugin.h\x20instead\n\x20of\x20dealing\x20with\x20the\x20raw\x20protocol\
-    \x20defined\x20here.\n\n\x20A\x20plugin\x20executable\x20needs\x20only\
-    \x20to\x20be\x20placed\x20somewhere\x20in\x20the\x20path.\x20\x20The\n\
-    \x20plugin\x20should\x20be\x20named\x20\"protoc-gen-$NAME\",\x20and\x20w\
-    ill\x20then\x20be\x20used\x20when\x20the\n\x20flag\x20\"--${NAME}_out\"\
-    \x20is\x20passed\x20to\x20protoc.\n\n\x08\n\x01\x02\x12\x03/\x08\x20\n\
-    \x08\n\x01\x08\x12\x030\05\n\x0b\n\x04\x08\xe7\x07\0\x12\x030\05\n\x0c\n\
-    \x05\x08\xe7\x07\0\x02\x12\x030\x07\x13\n\r\n\x06\x08\xe7\x07\0\x02\0\
-    \x12\x030\x07\x13\n\x0e\n\x07\x08\xe7\x07\0\x02\0\x01\x12\x030\x07\x13\n\
-    \x0c\n\x05\x08\xe7\x07\0\x07\x12\x030\x164\n\x08\n\x01\x08\x12\x031\0-\n\
-    \x0b\n\x04\x08\xe7\x07\x01\x12\x031\0-\n\x0c\n\x05\x08\xe7\x07\x01\x02\
-    \x12\x031\x07\x1b\n\r\n\x06\x08\xe7\x07\x01\x02\0\x12\x031\x07\x1b\n\x0e\
-    \n\x07\x08\xe7\x07\x01\x02\0\x01\x12\x031\x07\x1b\n\x0c\n\x05\x08\xe7\
-    \x07\x01\x07\x12\x031\x1e,\n\x08\n\x01\x08\x12\x033\0\x20\n\x0b\n\x04\
-    \x08\xe7\x07\x02\x12\x033\0\x20\n\x0c\n\x05\x08\xe7\x07\x02\x02\x12\x033\
-    \x07\x11\n\r\n\x06\x08\xe7\x07\x02\x02\0\x12\x033\x07\x11\n\x0e\n\x07\
-    \x08\xe7\x07\x02\x02\0\x01\x12\x033\x07\x11\n\x0c\n\x05\x08\xe7\x07\x02\
-    \x07\x12\x033\x14\x1f\n\t\n\x02\x03\0\x12\x035\x07)\nO\n\x02\x04\0\x12\
-    \x048\0M\x01\x1aC\x20An\x20encoded\x20CodeGeneratorRequest\x20is\x20writ\
-    ten\x20to\x20the\x20plugin's\x20stdin.\n\n\n\n\x03\x04\0\x01\x12\x038\
-    \x08\x1c\n\xd1\x01\n\x04\x04\0\x02\0\x12\x03<\x02'\x1a\xc3\x01\x20The\
-    \x20.proto\x20files\x20that\x20were\x20explicitly\x20listed\x20on\x20the\
-    \x20command-line.\x20\x20The\n\x20code\x20generator\x20should\x20generat\
-    e\x20code\x20only\x20for\x20these\x20files.\x20\x20Each\x20file's\n\x20d\
-    escriptor\x20will\x20be\x20included\x20in\x20proto_file,\x20below.\n\n\
-    \x0c\n\x05\x04\0\x02\0\x04\x12\x03<\x02\n\n\x0c\n\x05\x04\0\x02\0\x05\
-    \x12\x03<\x0b\x11\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03<\x12\"\n\x0c\n\x05\
-    \x04\0\x02\0\x03\x12\x03<%&\nB\n\x04\x04\0\x02\x01\x12\x03?\x02\x20\x1a5\
-    \x20The\x20generator\x20parameter\x20passed\x20on\x20the\x20command-line\
-    .\n\n\x0c\n\x05\x04\0\x02\x01\x04\x12\x03?\x02\n\n\x0c\n\x05\x04\0\x02\
-    \x01\x05\x12\x03?\x0b\x11\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03?\x12\x1b\
-    \n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03?\x1e\x1f\n\xa9\x05\n\x04\x04\0\
-    \x02\x02\x12\x03L\x02/\x1a\x9b\x05\x20FileDescriptorProtos\x20for\x20all\
-    \x20files\x20in\x20files_to_generate\x20and\x20everything\n\x20they\x20i\
-    mport.\x20\x20The\x20files\x20will\x20appear\x20in\x20topological\x20ord\
-    er,\x20so\x20each\x20file\n\x20appears\x20before\x20any\x20file\x20that\
-    \x20imports\x20it.\n\n\x20protoc\x20guarantees\x20that\x20all\x20proto_f\
-    iles\x20will\x20be\x20written\x20after\n\x20the\x20fields\x20above,\x20e\
-    ven\x20though\x20this\x20is\x20not\x20technically\x20guaranteed\x20by\
-    \x20the\n\x20protobuf\x20wire\x20format.\x20\x20This\x20theoretically\
-    \x20could\x20allow\x20a\x20plugin\x20to\x20stream\n\x20in\x20the\x20File\
-    DescriptorProtos\x20and\x20handle\x20them\x20one\x20by\x20one\x20rather\
-    \x20than\x20read\n\x20the\x20entire\x20set\x20into\x20memory\x20at\x20on\
-    ce.\x20\x20However,\x20as\x20of\x20this\x20writing,\x20this\n\x20is\x20n\
-    ot\x20similarly\x20optimized\x20on\x20protoc's\x20end\x20--\x20it\x20wil\
-    l\x20store\x20all\x20fields\x20in\n\x20memory\x20at\x20once\x20before\
-    \x20sending\x20them\x20to\x20the\x20plugin.\n\n\x0c\n\x05\x04\0\x02\x02\
-    \x04\x12\x03L\x02\n\n\x0c\n\x05\x04\0\x02\x02\x06\x12\x03L\x0b\x1e\n\x0c\
-    \n\x05\x04\0\x02\x02\x01\x12\x03L\x1f)\n\x0c\n\x05\x04\0\x02\x02\x03\x12\
-    \x03L,.\nL\n\x02\x04\x01\x12\x05P\0\x95\x01\x01\x1a?\x20The\x20plugin\
-    \x20writes\x20an\x20encoded\x20CodeGeneratorResponse\x20to\x20stdout.\n\
-    \n\n\n\x03\x04\x01\x01\x12\x03P\x08\x1d\n\xed\x03\n\x04\x04\x01\x02\0\
-    \x12\x03Y\x02\x1c\x1a\xdf\x03\x20Error\x20message.\x20\x20If\x20non-empt\
-    y,\x20code\x20generation\x20failed.\x20\x20The\x20plugin\x20process\n\
-    \x20should\x20exit\x20with\x20status\x20code\x20zero\x20even\x20if\x20it\
-    \x20reports\x20an\x20error\x20in\x20this\x20way.\n\n\x20This\x20should\
-    \x20be\x20used\x20to\x20indicate\x20errors\x20in\x20.proto\x20files\x20w\
-    hich\x20prevent\x20the\n\x20code\x20generator\x20from\x20generating\x20c\
-    orrect\x20code.\x20\x20Errors\x20which\x20indicate\x20a\n\x20problem\x20\
-    in\x20protoc\x20itself\x20--\x20such\x20as\x20the\x20input\x20CodeGenera\
-    torRequest\x20being\n\x20unparseable\x20--\x20should\x20be\x20reported\
-    \x20by\x20writing\x20a\x20message\x20to\x20stderr\x20and\n\x20exiting\
-    \x20with\x20a\x20non-zero\x20status\x20code.\n\n\x0c\n\x05\x04\x01\x02\0\
-    \x04\x12\x03Y\x02\n\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03Y\x0b\x11\n\x0c\
-    \n\x05\x04\x01\x02\0\x01\x12\x03Y\x12\x17\n\x0c\n\x05\x04\x01\x02\0\x03\
-    \x12\x03Y\x1a\x1b\n4\n\x04\x04\x01\x03\0\x12\x05\\\x02\x93\x01\x03\x1a%\
-    \x20Represents\x20a\x20single\x20generated\x20file.\n\n\x0c\n\x05\x04\
-    \x01\x03\0\x01\x12\x03\\\n\x0e\n\xad\x05\n\x06\x04\x01\x03\0\x02\0\x12\
-    \x03h\x04\x1d\x1a\x9d\x05\x20The\x20file\x20name,\x20relative\x20to\x20t\
-    he\x20output\x20directory.\x20\x20The\x20name\x20must\x20not\n\x20contai\
-    n\x20\".\"\x20or\x20\"..\"\x20components\x20and\x20must\x20be\x20relativ\
-    e,\x20not\x20be\x20absolute\x20(so,\n\x20the\x20file\x20cannot\x20lie\
-    \x20outside\x20the\x20output\x20directory).\x20\x20\"/\"\x20must\x20be\
-    \x20used\x20as\n\x20the\x20path\x20separator,\x20not\x20\"\\\".\n\n\x20I\
-    f\x20the\x20name\x20is\x20omitted,\x20the\x20content\x20will\x20be\x20ap\
-    pended\x20to\x20the\x20previous\n\x20file.\x20\x20This\x20allows\x20the\
-    \x20generator\x20to\x20break\x20large\x20files\x20into\x20small\x20chunk\
-    s,\n\x20and\x20allows\x20the\x20generated\x20text\x20to\x20be\x20streame\
-    d\x20back\x20to\x20protoc\x20so\x20that\x20large\n\x20files\x20need\x20n\
-    ot\x20reside\x20completely\x20in\x20memory\x20at\x20one\x20time.\x20\x20\
-    Note\x20that\x20as\x20of\n\x20this\x20writing\x20protoc\x20does\x20not\
-    \x20optimize\x20for\x20this\x20--\x20it\x20will\x20read\x20the\x20entire\
-    \n\x20CodeGeneratorResponse\x20before\x20writing\x20files\x20to\x20disk.\
-    \n\n\x0e\n\x07\x04\x01\x03\0\x02\0\x04\x12\x03h\x04\x0c\n\x0e\n\x07\x04\
-    \x01\x03\0\x02\0\x05\x12\x03h\r\x13\n\x0e\n\x07\x04\x01\x03\0\x02\0\x01\
-    \x12\x03h\x14\x18\n\x0e\n\x07\x04\x01\x03\0\x02\0\x03\x12\x03h\x1b\x1c\n\
-    \xae\x10\n\x06\x04\x01\x03\0\x02\x01\x12\x04\x8f\x01\x04(\x1a\x9d\x10\
-    \x20If\x20non-empty,\x20indicates\x20that\x20the\x20named\x20file\x20sho\
-    uld\x20already\x20exist,\x20and\x20the\n\x20content\x20here\x20is\x20to\
-    \x20be\x20inserted\x20into\x20that\x20file\x20at\x20a\x20defined\x20inse\
-    rtion\n\x20point.\x20\x20This\x20feature\x20allows\x20a\x20code\x20gener\
-    ator\x20to\x20extend\x20the\x20output\n\x20produced\x20by\x20another\x20\
-    code\x20generator.\x20\x20The\x20original\x20generator\x20may\x20provide\
-    \n\x20insertion\x20points\x20by\x20placing\x20special\x20annotations\x20\
-    in\x20the\x20file\x20that\x20look\n\x20like:\n\x20\x20\x20@@protoc_inser\
-    tion_point(NAME)\n\x20The\x20annotation\x20can\x20have\x20arbitrary\x20t\
-    ext\x20before\x20and\x20after\x20it\x20on\x20the\x20line,\n\x20which\x20\
-    allows\x20it\x20to\x20be\x20placed\x20in\x20a\x20comment.\x20\x20NAME\
-    \x20should\x20be\x20replaced\x20with\n\x20an\x20identifier\x20naming\x20\
-    the\x20point\x20--\x20this\x20is\x20what\x20other\x20generators\x20will\
-    \x20use\n\x20as\x20the\x20insertion_point.\x20\x20Code\x20inserted\x20at\
-    \x20this\x20point\x20will\x20be\x20placed\n\x20immediately\x20above\x20t\
-    he\x20line\x20containing\x20the\x20insertion\x20point\x20(thus\x20multip\
-    le\n\x20insertions\x20to\x20the\x20same\x20point\x20will\x20come\x20out\
-    \x20in\x20the\x20order\x20they\x20were\x20added).\n\x20The\x20double-@\
-    \x20is\x20intended\x20to\x20make\x20it\x20unlikely\x20that\x20the\x20gen\
-    erated\x20code\n\x20could\x20contain\x20things\x20that\x20look\x20like\
-    \x20insertion\x20points\x20by\x20accident.\n\n\x20For\x20example,\x20the\
-    \x20C++\x20code\x20generator\x20places\x20the\x20following\x20line\x20in\
-    \x20the\n\x20.pb.h\x20files\x20that\x20it\x20generates:\n\x20\x20\x20//\
-    \x20@@protoc_insertion_point(namespace_scope)\n\x20This\x20line\x20appea\
-    rs\x20within\x20the\x20scope\x20of\x20the\x20file's\x20package\x20namesp\
-    ace,\x20but\n\x20outside\x20of\x20any\x20particular\x20class.\x20\x20Ano\
-    ther\x20plugin\x20can\x20then\x20specify\x20the\n\x20insertion_point\x20\
-    \"namespace_scope\"\x20to\x20generate\x20additional\x20classes\x20or\n\
-    \x20other\x20declarations\x20that\x20should\x20be\x20placed\x20in\x20thi\
-    s\x20scope.\n\n\x20Note\x20that\x20if\x20the\x20line\x20containing\x20th\
-    e\x20insertion\x20point\x20begins\x20with\n\x20whitespace,\x20the\x20sam\
-    e\x20whitespace\x20will\x20be\x20added\x20to\x20every\x20line\x20of\x20t\
-    he\n\x20inserted\x20text.\x20\x20This\x20is\x20useful\x20for\x20language\
-    s\x20like\x20Python,\x20where\n\x20indentation\x20matters.\x20\x20In\x20\
-    these\x20languages,\x20the\x20insertion\x20point\x20comment\n\x20should\
-    \x20be\x20indented\x20the\x20same\x20amount\x20as\x20any\x20inserted\x20\
-    code\x20will\x20need\x20to\x20be\n\x20in\x20order\x20to\x20work\x20corre\
-    ctly\x20in\x20that\x20context.\n\n\x20The\x20code\x20generator\x20that\
-    \x20generates\x20the\x20initial\x20file\x20and\x20the\x20one\x20which\n\
-    \x20inserts\x20into\x20it\x20must\x20both\x20run\x20as\x20part\x20of\x20\
-    a\x20single\x20invocation\x20of\x20protoc.\n\x20Code\x20generators\x20ar\
-    e\x20executed\x20in\x20the\x20order\x20in\x20which\x20they\x20appear\x20\
-    on\x20the\n\x20command\x20line.\n\n\x20If\x20|insertion_point|\x20is\x20\
-    present,\x20|name|\x20must\x20also\x20be\x20present.\n\n\x0f\n\x07\x04\
-    \x01\x03\0\x02\x01\x04\x12\x04\x8f\x01\x04\x0c\n\x0f\n\x07\x04\x01\x03\0\
-    \x02\x01\x05\x12\x04\x8f\x01\r\x13\n\x0f\n\x07\x04\x01\x03\0\x02\x01\x01\
-    \x12\x04\x8f\x01\x14#\n\x0f\n\x07\x04\x01\x03\0\x02\x01\x03\x12\x04\x8f\
-    \x01&'\n$\n\x06\x04\x01\x03\0\x02\x02\x12\x04\x92\x01\x04!\x1a\x14\x20Th\
-    e\x20file\x20contents.\n\n\x0f\n\x07\x04\x01\x03\0\x02\x02\x04\x12\x04\
-    \x92\x01\x04\x0c\n\x0f\n\x07\x04\x01\x03\0\x02\x02\x05\x12\x04\x92\x01\r\
-    \x13\n\x0f\n\x07\x04\x01\x03\0\x02\x02\x01\x12\x04\x92\x01\x14\x1b\n\x0f\
-    \n\x07\x04\x01\x03\0\x02\x02\x03\x12\x04\x92\x01\x1e\x20\n\x0c\n\x04\x04\
-    \x01\x02\x01\x12\x04\x94\x01\x02\x1a\n\r\n\x05\x04\x01\x02\x01\x04\x12\
-    \x04\x94\x01\x02\n\n\r\n\x05\x04\x01\x02\x01\x06\x12\x04\x94\x01\x0b\x0f\
-    \n\r\n\x05\x04\x01\x02\x01\x01\x12\x04\x94\x01\x10\x14\n\r\n\x05\x04\x01\
-    \x02\x01\x03\x12\x04\x94\x01\x17\x19\
+    t\"8\n\x07Feature\x12\x10\n\x0cFEATURE_NONE\x10\0\x12\x1b\n\x17FEATURE_P\
+    ROTO3_OPTIONAL\x10\x01B7\n\x1ccom.google.protobuf.compilerB\x0cPluginPro\
+    tosZ\tplugin_goJ\xe6=\n\x07\x12\x05.\0\x9f\x01\x01\n\xca\x11\n\x01\x0c\
+    \x12\x03.\0\x122\xc1\x0c\x20Protocol\x20Buffers\x20-\x20Google's\x20data\
+    \x20interchange\x20format\n\x20Copyright\x202008\x20Google\x20Inc.\x20\
+    \x20All\x20rights\x20reserved.\n\x20https://developers.google.com/protoc\
+    ol-buffers/\n\n\x20Redistribution\x20and\x20use\x20in\x20source\x20and\
+    \x20binary\x20forms,\x20with\x20or\x20without\n\x20modification,\x20are\
+    \x20permitted\x20provided\x20that\x20the\x20following\x20conditions\x20a\
+    re\n\x20met:\n\n\x20\x20\x20\x20\x20*\x20Redistributions\x20of\x20source\
+    \x20code\x20must\x20retain\x20the\x20above\x20copyright\n\x20notice,\x20\
+    this\x20list\x20of\x20conditions\x20and\x20the\x20following\x20disclaime\
+    r.\n\x20\x20\x20\x20\x20*\x20Redistributions\x20in\x20binary\x20form\x20\
+    must\x20reproduce\x20the\x20above\n\x20copyright\x20notice,\x20this\x20l\
+    ist\x20of\x20conditions\x20and\x20the\x20following\x20disclaimer\n\x20in\
+    \x20the\x20documentation\x20and/or\x20other\x20materials\x20provided\x20\
+    with\x20the\n\x20distribution.\n\x20\x20\x20\x20\x20*\x20Neither\x20the\
+    \x20name\x20of\x20Google\x20Inc.\x20nor\x20the\x20names\x20of\x20its\n\
+    \x20contributors\x20may\x20be\x20used\x20to\x20endorse\x20or\x20promote\
+    \x20products\x20derived\x20from\n\x20this\x20software\x20without\x20spec\
+    ific\x20prior\x20written\x20permission.\n\n\x20THIS\x20SOFTWARE\x20IS\
+    \x20PROVIDED\x20BY\x20THE\x20COPYRIGHT\x20HOLDERS\x20AND\x20CONTRIBUTORS\
+    \n\x20\"AS\x20IS\"\x20AND\x20ANY\x20EXPRESS\x20OR\x20IMPLIED\x20WARRANTI\
+    ES,\x20INCLUDING,\x20BUT\x20NOT\n\x20LIMITED\x20TO,\x20THE\x20IMPLIED\
+    \x20WARRANTIES\x20OF\x20MERCHANTABILITY\x20AND\x20FITNESS\x20FOR\n\x20A\
+    \x20PARTICULAR\x20PURPOSE\x20ARE\x20DISCLAIMED.\x20IN\x20NO\x20EVENT\x20\
+    SHALL\x20THE\x20COPYRIGHT\n\x20OWNER\x20OR\x20CONTRIBUTORS\x20BE\x20LIAB\
+    LE\x20FOR\x20ANY\x20DIRECT,\x20INDIRECT,\x20INCIDENTAL,\n\x20SPECIAL,\
+    \x20EXEMPLARY,\x20OR\x20CONSEQUENTIAL\x20DAMAGES\x20(INCLUDING,\x20BUT\
+    \x20NOT\n\x20LIMITED\x20TO,\x20PROCUREMENT\x20OF\x20SUBSTITUTE\x20GOODS\
+    \x20OR\x20SERVICES;\x20LOSS\x20OF\x20USE,\n\x20DATA,\x20OR\x20PROFITS;\
+    \x20OR\x20BUSINESS\x20INTERRUPTION)\x20HOWEVER\x20CAUSED\x20AND\x20ON\
+    \x20ANY\n\x20THEORY\x20OF\x20LIABILITY,\x20WHETHER\x20IN\x20CONTRACT,\
+    \x20STRICT\x20LIABILITY,\x20OR\x20TORT\n\x20(INCLUDING\x20NEGLIGENCE\x20\
+    OR\x20OTHERWISE)\x20ARISING\x20IN\x20ANY\x20WAY\x20OUT\x20OF\x20THE\x20U\
+    SE\n\x20OF\x20THIS\x20SOFTWARE,\x20EVEN\x20IF\x20ADVISED\x20OF\x20THE\
+    \x20POSSIBILITY\x20OF\x20SUCH\x20DAMAGE.\n2\xfb\x04\x20Author:\x20kenton\
+    @google.com\x20(Kenton\x20Varda)\n\n\x20WARNING:\x20\x20The\x20plugin\
+    \x20interface\x20is\x20currently\x20EXPERIMENTAL\x20and\x20is\x20subject\
+    \x20to\n\x20\x20\x20change.\n\n\x20protoc\x20(aka\x20the\x20Protocol\x20\
+    Compiler)\x20can\x20be\x20extended\x20via\x20plugins.\x20\x20A\x20plugin\
+    \x20is\n\x20just\x20a\x20program\x20that\x20reads\x20a\x20CodeGeneratorR\
+    equest\x20from\x20stdin\x20and\x20writes\x20a\n\x20CodeGeneratorResponse\
+    \x20to\x20stdout.\n\n\x20Plugins\x20written\x20using\x20C++\x20can\x20us\
+    e\x20google/protobuf/compiler/plugin.h\x20instead\n\x20of\x20dealing\x20\
+    with\x20the\x20raw\x20protocol\x20defined\x20here.\n\n\x20A\x20plugin\
+    \x20executable\x20needs\x20only\x20to\x20be\x20placed\x20somewhere\x20in\
+    \x20the\x20path.\x20\x20The\n\x20plugin\x20should\x20be\x20named\x20\"pr\
+    otoc-gen-$NAME\",\x20and\x20will\x20then\x20be\x20used\x20when\x20the\n\
+    \x20flag\x20\"--${NAME}_out\"\x20is\x20passed\x20to\x20protoc.\n\n\x08\n\
+    \x01\x02\x12\x03/\x08\x20\n\x08\n\x01\x08\x12\x030\05\n\x0b\n\x04\x08\
+    \xe7\x07\0\x12\x030\05\n\x0c\n\x05\x08\xe7\x07\0\x02\x12\x030\x07\x13\n\
+    \r\n\x06\x08\xe7\x07\0\x02\0\x12\x030\x07\x13\n\x0e\n\x07\x08\xe7\x07\0\
+    \x02\0\x01\x12\x030\x07\x13\n\x0c\n\x05\x08\xe7\x07\0\x07\x12\x030\x164\
+    \n\x08\n\x01\x08\x12\x031\0-\n\x0b\n\x04\x08\xe7\x07\x01\x12\x031\0-\n\
+    \x0c\n\x05\x08\xe7\x07\x01\x02\x12\x031\x07\x1b\n\r\n\x06\x08\xe7\x07\
+    \x01\x02\0\x12\x031\x07\x1b\n\x0e\n\x07\x08\xe7\x07\x01\x02\0\x01\x12\
+    \x031\x07\x1b\n\x0c\n\x05\x08\xe7\x07\x01\x07\x12\x031\x1e,\n\x08\n\x01\
+    \x08\x12\x033\0\x20\n\x0b\n\x04\x08\xe7\x07\x02\x12\x033\0\x20\n\x0c\n\
+    \x05\x08\xe7\x07\x02\x02\x12\x033\x07\x11\n\r\n\x06\x08\xe7\x07\x02\x02\
+    \0\x12\x033\x07\x11\n\x0e\n\x07\x08\xe7\x07\x02\x02\0\x01\x12\x033\x07\
+    \x11\n\x0c\n\x05\x08\xe7\x07\x02\x07\x12\x033\x14\x1f\n\t\n\x02\x03\0\
+    \x12\x035\x07)\nO\n\x02\x04\0\x12\x048\0M\x01\x1aC\x20An\x20encoded\x20C\
+    odeGeneratorRequest\x20is\x20written\x20to\x20the\x20plugin's\x20stdin.\
+    \n\n\n\n\x03\x04\0\x01\x12\x038\x08\x1c\n\xd1\x01\n\x04\x04\0\x02\0\x12\
+    \x03<\x02'\x1a\xc3\x01\x20The\x20.proto\x20files\x20that\x20were\x20expl\
+    icitly\x20listed\x20on\x20the\x20command-line.\x20\x20The\n\x20code\x20g\
+    enerator\x20should\x20generate\x20code\x20only\x20for\x20these\x20files.\
+    \x20\x20Each\x20file's\n\x20descriptor\x20will\x20be\x20included\x20in\
+    \x20proto_file,\x20below.\n\n\x0c\n\x05\x04\0\x02\0\x04\x12\x03<\x02\n\n\
+    \x0c\n\x05\x04\0\x02\0\x05\x12\x03<\x0b\x11\n\x0c\n\x05\x04\0\x02\0\x01\
+    \x12\x03<\x12\"\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03<%&\nB\n\x04\x04\0\
+    \x02\x01\x12\x03?\x02\x20\x1a5\x20The\x20generator\x20parameter\x20passe\
+    d\x20on\x20the\x20command-line.\n\n\x0c\n\x05\x04\0\x02\x01\x04\x12\x03?\
+    \x02\n\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03?\x0b\x11\n\x0c\n\x05\x04\0\
+    \x02\x01\x01\x12\x03?\x12\x1b\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03?\x1e\
+    \x1f\n\xa9\x05\n\x04\x04\0\x02\x02\x12\x03L\x02/\x1a\x9b\x05\x20FileDesc\
+    riptorProtos\x20for\x20all\x20files\x20in\x20files_to_generate\x20and\
+    \x20everything\n\x20they\x20import.\x20\x20The\x20files\x20will\x20appea\
+    r\x20in\x20topological\x20order,\x20so\x20each\x20file\n\x20appears\x20b\
+    efore\x20any\x20file\x20that\x20imports\x20it.\n\n\x20protoc\x20guarante\
+    es\x20that\x20all\x20proto_files\x20will\x20be\x20written\x20after\n\x20\
+    the\x20fields\x20above,\x20even\x20though\x20this\x20is\x20not\x20techni\
+    cally\x20guaranteed\x20by\x20the\n\x20protobuf\x20wire\x20format.\x20\
+    \x20This\x20theoretically\x20could\x20allow\x20a\x20plugin\x20to\x20stre\
+    am\n\x20in\x20the\x20FileDescriptorProtos\x20and\x20handle\x20them\x20on\
+    e\x20by\x20one\x20rather\x20than\x20read\n\x20the\x20entire\x20set\x20in\
+    to\x20memory\x20at\x20once.\x20\x20However,\x20as\x20of\x20this\x20writi\
+    ng,\x20this\n\x20is\x20not\x20similarly\x20optimized\x20on\x20protoc's\
+    \x20end\x20--\x20it\x20will\x20store\x20all\x20fields\x20in\n\x20memory\
+    \x20at\x20once\x20before\x20sending\x20them\x20to\x20the\x20plugin.\n\n\
+    \x0c\n\x05\x04\0\x02\x02\x04\x12\x03L\x02\n\n\x0c\n\x05\x04\0\x02\x02\
+    \x06\x12\x03L\x0b\x1e\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03L\x1f)\n\x0c\
+    \n\x05\x04\0\x02\x02\x03\x12\x03L,.\nL\n\x02\x04\x01\x12\x05P\0\x9f\x01\
+    \x01\x1a?\x20The\x20plugin\x20writes\x20an\x20encoded\x20CodeGeneratorRe\
+    sponse\x20to\x20stdout.\n\n\n\n\x03\x04\x01\x01\x12\x03P\x08\x1d\n\xed\
+    \x03\n\x04\x04\x01\x02\0\x12\x03Y\x02\x1c\x1a\xdf\x03\x20Error\x20messag\
+    e.\x20\x20If\x20non-empty,\x20code\x20generation\x20failed.\x20\x20The\
+    \x20plugin\x20process\n\x20should\x20exit\x20with\x20status\x20code\x20z\
+    ero\x20even\x20if\x20it\x20reports\x20an\x20error\x20in\x20this\x20way.\
+    \n\n\x20This\x20should\x20be\x20used\x20to\x20indicate\x20errors\x20in\
+    \x20.proto\x20files\x20which\x20prevent\x20the\n\x20code\x20generator\
+    \x20from\x20generating\x20correct\x20code.\x20\x20Errors\x20which\x20ind\
+    icate\x20a\n\x20problem\x20in\x20protoc\x20itself\x20--\x20such\x20as\
+    \x20the\x20input\x20CodeGeneratorRequest\x20being\n\x20unparseable\x20--\
+    \x20should\x20be\x20reported\x20by\x20writing\x20a\x20message\x20to\x20s\
+    tderr\x20and\n\x20exiting\x20with\x20a\x20non-zero\x20status\x20code.\n\
+    \n\x0c\n\x05\x04\x01\x02\0\x04\x12\x03Y\x02\n\n\x0c\n\x05\x04\x01\x02\0\
+    \x05\x12\x03Y\x0b\x11\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03Y\x12\x17\n\
+    \x0c\n\x05\x04\x01\x02\0\x03\x12\x03Y\x1a\x1b\n\x89\x01\n\x04\x04\x01\
+    \x02\x01\x12\x03]\x02)\x1a|\x20A\x20bitmask\x20of\x20supported\x20featur\
+    es\x20that\x20the\x20code\x20generator\x20supports.\n\x20This\x20is\x20a\
+    \x20bitwise\x20\"or\"\x20of\x20values\x20from\x20the\x20Feature\x20enum.\
+    \n\n\x0c\n\x05\x04\x01\x02\x01\x04\x12\x03]\x02\n\n\x0c\n\x05\x04\x01\
+    \x02\x01\x05\x12\x03]\x0b\x11\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03]\
+    \x12$\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03]'(\n+\n\x04\x04\x01\x04\0\
+    \x12\x04`\x02c\x03\x1a\x1d\x20Sync\x20with\x20code_generator.h.\n\n\x0c\
+    \n\x05\x04\x01\x04\0\x01\x12\x03`\x07\x0e\n\r\n\x06\x04\x01\x04\0\x02\0\
+    \x12\x03a\x04\x15\n\x0e\n\x07\x04\x01\x04\0\x02\0\x01\x12\x03a\x04\x10\n\
+    \x0e\n\x07\x04\x01\x04\0\x02\0\x02\x12\x03a\x13\x14\n\r\n\x06\x04\x01\
+    \x04\0\x02\x01\x12\x03b\x04\x20\n\x0e\n\x07\x04\x01\x04\0\x02\x01\x01\
+    \x12\x03b\x04\x1b\n\x0e\n\x07\x04\x01\x04\0\x02\x01\x02\x12\x03b\x1e\x1f\
+    \n4\n\x04\x04\x01\x03\0\x12\x05f\x02\x9d\x01\x03\x1a%\x20Represents\x20a\
+    \x20single\x20generated\x20file.\n\n\x0c\n\x05\x04\x01\x03\0\x01\x12\x03\
+    f\n\x0e\n\xad\x05\n\x06\x04\x01\x03\0\x02\0\x12\x03r\x04\x1d\x1a\x9d\x05\
+    \x20The\x20file\x20name,\x20relative\x20to\x20the\x20output\x20directory\
+    .\x20\x20The\x20name\x20must\x20not\n\x20contain\x20\".\"\x20or\x20\"..\
+    \"\x20components\x20and\x20must\x20be\x20relative,\x20not\x20be\x20absol\
+    ute\x20(so,\n\x20the\x20file\x20cannot\x20lie\x20outside\x20the\x20outpu\
+    t\x20directory).\x20\x20\"/\"\x20must\x20be\x20used\x20as\n\x20the\x20pa\
+    th\x20separator,\x20not\x20\"\\\".\n\n\x20If\x20the\x20name\x20is\x20omi\
+    tted,\x20the\x20content\x20will\x20be\x20appended\x20to\x20the\x20previo\
+    us\n\x20file.\x20\x20This\x20allows\x20the\x20generator\x20to\x20break\
+    \x20large\x20files\x20into\x20small\x20chunks,\n\x20and\x20allows\x20the\
+    \x20generated\x20text\x20to\x20be\x20streamed\x20back\x20to\x20protoc\
+    \x20so\x20that\x20large\n\x20files\x20need\x20not\x20reside\x20completel\
+    y\x20in\x20memory\x20at\x20one\x20time.\x20\x20Note\x20that\x20as\x20of\
+    \n\x20this\x20writing\x20protoc\x20does\x20not\x20optimize\x20for\x20thi\
+    s\x20--\x20it\x20will\x20read\x20the\x20entire\n\x20CodeGeneratorRespons\
+    e\x20before\x20writing\x20files\x20to\x20disk.\n\n\x0e\n\x07\x04\x01\x03\
+    \0\x02\0\x04\x12\x03r\x04\x0c\n\x0e\n\x07\x04\x01\x03\0\x02\0\x05\x12\
+    \x03r\r\x13\n\x0e\n\x07\x04\x01\x03\0\x02\0\x01\x12\x03r\x14\x18\n\x0e\n\
+    \x07\x04\x01\x03\0\x02\0\x03\x12\x03r\x1b\x1c\n\xae\x10\n\x06\x04\x01\
+    \x03\0\x02\x01\x12\x04\x99\x01\x04(\x1a\x9d\x10\x20If\x20non-empty,\x20i\
+    ndicates\x20that\x20the\x20named\x20file\x20should\x20already\x20exist,\
+    \x20and\x20the\n\x20content\x20here\x20is\x20to\x20be\x20inserted\x20int\
+    o\x20that\x20file\x20at\x20a\x20defined\x20insertion\n\x20point.\x20\x20\
+    This\x20feature\x20allows\x20a\x20code\x20generator\x20to\x20extend\x20t\
+    he\x20output\n\x20produced\x20by\x20another\x20code\x20generator.\x20\
+    \x20The\x20original\x20generator\x20may\x20provide\n\x20insertion\x20poi\
+    nts\x20by\x20placing\x20special\x20annotations\x20in\x20the\x20file\x20t\
+    hat\x20look\n\x20like:\n\x20\x20\x20@@protoc_insertion_point(NAME)\n\x20\
+    The\x20annotation\x20can\x20have\x20arbitrary\x20text\x20before\x20and\
+    \x20after\x20it\x20on\x20the\x20line,\n\x20which\x20allows\x20it\x20to\
+    \x20be\x20placed\x20in\x20a\x20comment.\x20\x20NAME\x20should\x20be\x20r\
+    eplaced\x20with\n\x20an\x20identifier\x20naming\x20the\x20point\x20--\
+    \x20this\x20is\x20what\x20other\x20generators\x20will\x20use\n\x20as\x20\
+    the\x20insertion_point.\x20\x20Code\x20inserted\x20at\x20this\x20point\
+    \x20will\x20be\x20placed\n\x20immediately\x20above\x20the\x20line\x20con\
+    taining\x20the\x20insertion\x20point\x20(thus\x20multiple\n\x20insertion\
+    s\x20to\x20the\x20same\x20point\x20will\x20come\x20out\x20in\x20the\x20o\
+    rder\x20they\x20were\x20added).\n\x20The\x20double-@\x20is\x20intended\
+    \x20to\x20make\x20it\x20unlikely\x20that\x20the\x20generated\x20code\n\
+    \x20could\x20contain\x20things\x20that\x20look\x20like\x20insertion\x20p\
+    oints\x20by\x20accident.\n\n\x20For\x20example,\x20the\x20C++\x20code\
+    \x20generator\x20places\x20the\x20following\x20line\x20in\x20the\n\x20.p\
+    b.h\x20files\x20that\x20it\x20generates:\n\x20\x20\x20//\x20@@protoc_ins\
+    ertion_point(namespace_scope)\n\x20This\x20line\x20appears\x20within\x20\
+    the\x20scope\x20of\x20the\x20file's\x20package\x20namespace,\x20but\n\
+    \x20outside\x20of\x20any\x20particular\x20class.\x20\x20Another\x20plugi\
+    n\x20can\x20then\x20specify\x20the\n\x20insertion_point\x20\"namespace_s\
+    cope\"\x20to\x20generate\x20additional\x20classes\x20or\n\x20other\x20de\
+    clarations\x20that\x20should\x20be\x20placed\x20in\x20this\x20scope.\n\n\
+    \x20Note\x20that\x20if\x20the\x20line\x20containing\x20the\x20insertion\
+    \x20point\x20begins\x20with\n\x20whitespace,\x20the\x20same\x20whitespac\
+    e\x20will\x20be\x20added\x20to\x20every\x20line\x20of\x20the\n\x20insert\
+    ed\x20text.\x20\x20This\x20is\x20useful\x20for\x20languages\x20like\x20P\
+    ython,\x20where\n\x20indentation\x20matters.\x20\x20In\x20these\x20langu\
+    ages,\x20the\x20insertion\x20point\x20comment\n\x20should\x20be\x20inden\
+    ted\x20the\x20same\x20amount\x20as\x20any\x20inserted\x20code\x20will\
+    \x20need\x20to\x20be\n\x20in\x20order\x20to\x20work\x20correctly\x20in\
+    \x20that\x20context.\n\n\x20The\x20code\x20generator\x20that\x20generate\
+    s\x20the\x20initial\x20file\x20and\x20the\x20one\x20which\n\x20inserts\
+    \x20into\x20it\x20must\x20both\x20run\x20as\x20part\x20of\x20a\x20single\
+    \x20invocation\x20of\x20protoc.\n\x20Code\x20generators\x20are\x20execut\
+    ed\x20in\x20the\x20order\x20in\x20which\x20they\x20appear\x20on\x20the\n\
+    \x20command\x20line.\n\n\x20If\x20|insertion_point|\x20is\x20present,\
+    \x20|name|\x20must\x20also\x20be\x20present.\n\n\x0f\n\x07\x04\x01\x03\0\
+    \x02\x01\x04\x12\x04\x99\x01\x04\x0c\n\x0f\n\x07\x04\x01\x03\0\x02\x01\
+    \x05\x12\x04\x99\x01\r\x13\n\x0f\n\x07\x04\x01\x03\0\x02\x01\x01\x12\x04\
+    \x99\x01\x14#\n\x0f\n\x07\x04\x01\x03\0\x02\x01\x03\x12\x04\x99\x01&'\n$\
+    \n\x06\x04\x01\x03\0\x02\x02\x12\x04\x9c\x01\x04!\x1a\x14\x20The\x20file\
+    \x20contents.\n\n\x0f\n\x07\x04\x01\x03\0\x02\x02\x04\x12\x04\x9c\x01\
+    \x04\x0c\n\x0f\n\x07\x04\x01\x03\0\x02\x02\x05\x12\x04\x9c\x01\r\x13\n\
+    \x0f\n\x07\x04\x01\x03\0\x02\x02\x01\x12\x04\x9c\x01\x14\x1b\n\x0f\n\x07\
+    \x04\x01\x03\0\x02\x02\x03\x12\x04\x9c\x01\x1e\x20\n\x0c\n\x04\x04\x01\
+    \x02\x02\x12\x04\x9e\x01\x02\x1a\n\r\n\x05\x04\x01\x02\x02\x04\x12\x04\
+    \x9e\x01\x02\n\n\r\n\x05\x04\x01\x02\x02\x06\x12\x04\x9e\x01\x0b\x0f\n\r\
+    \n\x05\x04\x01\x02\x02\x01\x12\x04\x9e\x01\x10\x14\n\r\n\x05\x04\x01\x02\
+    \x02\x03\x12\x04\x9e\x01\x17\x19\
 ";
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
@@ -1,10 +1,12 @@
 use std::hash::Hash;
 use std::collections::HashMap;
 use std::fmt;
+use std::marker;
 
 use core::Message;
 use core::ProtobufEnum;
 use core::message_down_cast;
+use core::message_down_cast_mut;
 use reflect::EnumValueDescriptor;
 use types::*;
 
@@ -19,17 +21,21 @@ use super::repeated::ReflectRepeatedMessage;
 use super::optional::ReflectOptional;
 use super::value::ProtobufValue;
 use super::value::ProtobufValueRef;
+use super::value::ReflectValueBox;
+use super::runtime_type::RuntimeFieldType;
+use super::runtime_type::RuntimeType;
 use super::ReflectFieldRef;
 
 
 /// this trait should not be used directly, use `FieldDescriptor` instead
-pub trait FieldAccessor {
+pub trait FieldAccessor : Send + Sync {
     fn name_generic(&self) -> &'static str;
     fn has_field_generic(&self, m: &Message) -> bool;
     fn len_field_generic(&self, m: &Message) -> usize;
     // TODO: should it return default value or panic on unset field?
     fn get_message_generic<'a>(&self, m: &'a Message) -> &'a Message;
-    fn get_enum_generic(&self, m: &Message) -> &'static EnumValueDescriptor;
+    /// Value of set enum field, number of the value if it is unknown to the enum
+    fn get_enum_generic(&self, m: &Message) -> Result<&'static EnumValueDescriptor, i32>;
     fn get_str_generic<'a>(&self, m: &'a Message) -> &'a str;
     fn get_bytes_generic<'a>(&self, m: &'a Message) -> &'a [u8];
     fn get_u32_generic(&self, m: &Message) -> u32;
@@ -41,36 +47,81 @@ pub trait FieldAccessor {
     fn get_f64_generic(&self, m: &Message) -> f64;
 
     fn get_reflect<'a>(&self, m: &'a Message) -> ReflectFieldRef<'a>;
+
+    fn runtime_field_type(&self) -> RuntimeFieldType;
+
+    /// Singular field remembers whether it is set
+    fn has_presence_generic(&self) -> bool {
+        match self.runtime_field_type() {
+            RuntimeFieldType::Singular(..) => true,
+            _ => false,
+        }
+    }
+
+    /// Message field value is not stored in the containing message
+    /// (wrapper field generated as `Option` of wrapped value), so it cannot be borrowed
+    fn is_wrapper_generic(&self) -> bool {
+        false
+    }
+
+    fn set_singular_field_generic(&self, m: &mut Message, value: ReflectValueBox);
+    fn clear_field_generic(&self, m: &mut Message);
+    fn mut_message_generic<'a>(&self, m: &'a mut Message) -> &'a mut Message;
+    fn push_generic(&self, m: &mut Message, value: ReflectValueBox);
+    fn insert_generic(&self, m: &mut Message, key: ReflectValueBox, value: ReflectValueBox);
+    fn remove_generic(&self, m: &mut Message, key: ReflectValueBox) -> bool;
+
+    /// Values of extension field, implemented only by accessors of extension fields
+    fn get_extension_generic(&self, _m: &Message) -> Vec<ReflectValueBox> {
+        panic!("not an extension field")
+    }
 }
 
 
-trait GetSingularMessage<M> {
+trait GetSingularMessage<M> : Send + Sync {
     fn get_message<'a>(&self, m: &'a M) -> &'a Message;
+    fn mut_message<'a>(&self, m: &'a mut M) -> &'a mut Message;
+    fn set_message(&self, m: &mut M, value: ReflectValueBox);
 }
 
 struct GetSingularMessageImpl<M, N> {
     get: for<'a> fn(&'a M) -> &'a N,
+    mut_field: for<'a> fn(&'a mut M) -> &'a mut N,
 }
 
 impl<M : Message, N : Message + 'static> GetSingularMessage<M> for GetSingularMessageImpl<M, N> {
     fn get_message<'a>(&self, m: &'a M) -> &'a Message {
         (self.get)(m)
     }
+
+    fn mut_message<'a>(&self, m: &'a mut M) -> &'a mut Message {
+        (self.mut_field)(m)
+    }
+
+    fn set_message(&self, m: &mut M, value: ReflectValueBox) {
+        *(self.mut_field)(m) = value.downcast::<N>().expect("wrong type");
+    }
 }
 
 
-trait GetSingularEnum<M> {
+trait GetSingularEnum<M> : Send + Sync {
     fn get_enum(&self, m: &M) -> &'static EnumValueDescriptor;
+    fn set_enum(&self, m: &mut M, value: ReflectValueBox);
 }
 
 struct GetSingularEnumImpl<M, E> {
     get: fn(&M) -> E,
+    set: fn(&mut M, E),
 }
 
 impl<M : Message, E : ProtobufEnum> GetSingularEnum<M> for GetSingularEnumImpl<M, E> {
     fn get_enum(&self, m: &M) -> &'static EnumValueDescriptor {
         (self.get)(m).descriptor()
     }
+
+    fn set_enum(&self, m: &mut M, value: ReflectValueBox) {
+        (self.set)(m, value.downcast_enum::<E>().expect("wrong type"));
+    }
 }
 
 
@@ -89,19 +140,24 @@ trait GetRepeatedEnum<M : Message + 'static> {
 
 
 
-trait GetSetCopyFns<M> {
+trait GetSetCopyFns<M> : Send + Sync {
     fn get_field<'a>(&self, m: &'a M) -> ProtobufValueRef<'a>;
+    fn set_field(&self, m: &mut M, value: ReflectValueBox);
 }
 
 struct GetSetCopyFnsImpl<M, V : ProtobufValue + Copy> {
     get: fn(&M) -> V,
-    _set: fn(&mut M, V),
+    set: fn(&mut M, V),
 }
 
 impl<M, V : ProtobufValue + Copy> GetSetCopyFns<M> for GetSetCopyFnsImpl<M, V> {
     fn get_field<'a>(&self, m: &'a M) -> ProtobufValueRef<'a> {
         (&(self.get)(m) as &ProtobufValue).as_ref_copy()
     }
+
+    fn set_field(&self, m: &mut M, value: ReflectValueBox) {
+        (self.set)(m, value.downcast::<V>().expect("wrong type"));
+    }
 }
 
 
@@ -123,9 +179,19 @@ impl<M : Message + 'static> SingularGetSet<M> {
             &SingularGetSet::Message(ref get) => ProtobufValueRef::Message(get.get_message(m)),
         }
     }
+
+    fn set_value(&self, m: &mut M, value: ReflectValueBox) {
+        match self {
+            &SingularGetSet::Copy(ref copy) => copy.set_field(m, value),
+            &SingularGetSet::String(_, set) => set(m, value.downcast().expect("wrong type")),
+            &SingularGetSet::Bytes(_, set) => set(m, value.downcast().expect("wrong type")),
+            &SingularGetSet::Enum(ref e) => e.set_enum(m, value),
+            &SingularGetSet::Message(ref get) => get.set_message(m, value),
+        }
+    }
 }
 
-trait FieldAccessor2<M, R : ?Sized>
+trait FieldAccessor2<M, R : ?Sized> : Send + Sync
 where
     M : Message + 'static,
 {
@@ -133,12 +199,90 @@ where
     fn mut_field<'a>(&self, &'a mut M) -> &'a mut R;
 }
 
-struct MessageGetMut<M, L>
+/// Write operations of singular field
+trait SingularFieldAccessor<M, R : ?Sized> : FieldAccessor2<M, R>
+where
+    M : Message + 'static,
+{
+    fn set_value(&self, m: &mut M, value: ReflectValueBox);
+    fn clear_field(&self, m: &mut M);
+    fn mut_message<'a>(&self, m: &'a mut M, runtime_type: RuntimeType) -> &'a mut Message;
+}
+
+/// Singular wrapper message field stored as `Option` of wrapped value,
+/// wrapper messages are created when the field is read
+trait WrapperFieldAccessor<M> : Send + Sync
+where
+    M : Message + 'static,
+{
+    fn get_message(&self, m: &M) -> Option<Box<Message>>;
+    fn set_message(&self, m: &mut M, value: ReflectValueBox);
+    fn clear_field(&self, m: &mut M);
+}
+
+/// Write operations of repeated field
+trait RepeatedFieldAccessor<M> : FieldAccessor2<M, ReflectRepeated>
+where
+    M : Message + 'static,
+{
+    fn push(&self, m: &mut M, value: ReflectValueBox);
+    fn clear_field(&self, m: &mut M);
+}
+
+/// Write operations of map field
+trait MapFieldAccessor<M> : FieldAccessor2<M, ReflectMap>
+where
+    M : Message + 'static,
+{
+    fn insert(&self, m: &mut M, key: ReflectValueBox, value: ReflectValueBox);
+    fn remove(&self, m: &mut M, key: ReflectValueBox) -> bool;
+    fn clear_field(&self, m: &mut M);
+}
+
+/// Field getters; `V` is `ProtobufType` of field elements,
+/// `(K, V)` for maps
+struct MessageGetMut<M, L, V>
 where
     M : Message + 'static,
 {
     get_field: for<'a> fn(&'a M) -> &'a L,
     mut_field: for<'a> fn(&'a mut M) -> &'a mut L,
+    // `fn() -> V` keeps getters `Send` and `Sync` whatever `V` is
+    _marker: marker::PhantomData<fn() -> V>,
+}
+
+/// Getters of wrapper message field; `V` is `ProtobufType` of wrapped value,
+/// `W` is wrapper message type
+struct WrapperGetMut<M, V, W>
+where
+    M : Message + 'static,
+    V : ProtobufType,
+{
+    get_field: for<'a> fn(&'a M) -> &'a Option<V::Value>,
+    mut_field: for<'a> fn(&'a mut M) -> &'a mut Option<V::Value>,
+    _marker: marker::PhantomData<fn() -> W>,
+}
+
+/// Getters of proto3 field stored without presence flag
+struct SimpleGetMut<M, V>
+where
+    M : Message + 'static,
+    V : ProtobufType,
+{
+    get_field: for<'a> fn(&'a M) -> &'a V::Value,
+    mut_field: for<'a> fn(&'a mut M) -> &'a mut V::Value,
+}
+
+fn value_from_box<V : ProtobufType>(value: ReflectValueBox) -> V::Value {
+    V::value_from_box(value).expect("wrong type")
+}
+
+/// Create message of given type as dynamic value
+fn new_message_box(runtime_type: RuntimeType) -> ReflectValueBox {
+    match runtime_type {
+        RuntimeType::Message(d) => ReflectValueBox::Message(d.new_instance()),
+        _ => panic!("not a message field"),
+    }
 }
 
 
@@ -147,15 +291,18 @@ enum FieldAccessorFunctions<M> {
     SingularHasGetSet {
         has: fn(&M) -> bool,
         get_set: SingularGetSet<M>,
+        clear: fn(&mut M),
     },
     // protobuf 3 simple field
-    Simple(Box<FieldAccessor2<M, ProtobufValue>>),
+    Simple(Box<SingularFieldAccessor<M, ProtobufValue>>),
     // optional, required or message
-    Optional(Box<FieldAccessor2<M, ReflectOptional>>),
+    Optional(Box<SingularFieldAccessor<M, ReflectOptional>>),
+    // wrapper message stored as `Option` of wrapped value
+    Wrapper(Box<WrapperFieldAccessor<M>>),
     // repeated
-    Repeated(Box<FieldAccessor2<M, ReflectRepeated>>),
+    Repeated(Box<RepeatedFieldAccessor<M>>),
     // protobuf 3 map
-    Map(Box<FieldAccessor2<M, ReflectMap>>),
+    Map(Box<MapFieldAccessor<M>>),
 }
 
 impl<M> fmt::Debug for FieldAccessorFunctions<M> {
@@ -167,6 +314,8 @@ impl<M> fmt::Debug for FieldAccessorFunctions<M> {
                 write!(f, "Simple(..)"),
             &FieldAccessorFunctions::Optional(..) =>
                 write!(f, "Optional(..)"),
+            &FieldAccessorFunctions::Wrapper(..) =>
+                write!(f, "Wrapper(..)"),
             &FieldAccessorFunctions::Repeated(..) =>
                 write!(f, "Repeated(..)"),
             &FieldAccessorFunctions::Map(..) =>
@@ -178,6 +327,7 @@ impl<M> fmt::Debug for FieldAccessorFunctions<M> {
 
 struct FieldAccessorImpl<M> {
     name: &'static str,
+    runtime_field_type: fn() -> RuntimeFieldType,
     fns: FieldAccessorFunctions<M>,
 }
 
@@ -186,6 +336,7 @@ impl<M : Message> FieldAccessorImpl<M> {
         match self.fns {
             FieldAccessorFunctions::Repeated(..) |
             FieldAccessorFunctions::Map(..) => panic!("repeated"),
+            FieldAccessorFunctions::Wrapper(..) => panic!("wrapper message"),
             FieldAccessorFunctions::Simple(ref a) => Some(a.get_field(m).as_ref()),
             FieldAccessorFunctions::Optional(ref a) => {
                 a.get_field(m).to_option().map(|v| v.as_ref())
@@ -193,6 +344,7 @@ impl<M : Message> FieldAccessorImpl<M> {
             FieldAccessorFunctions::SingularHasGetSet {
                 ref has,
                 ref get_set,
+                ..
             } => {
                 if !has(m) {
                     None
@@ -215,6 +367,9 @@ impl<M : Message + 'static> FieldAccessor for FieldAccessorImpl<M> {
             FieldAccessorFunctions::Optional(ref a) => {
                 a.get_field(message_down_cast(m)).to_option().is_some()
             }
+            FieldAccessorFunctions::Wrapper(ref a) => {
+                a.get_message(message_down_cast(m)).is_some()
+            }
             FieldAccessorFunctions::Simple(ref a) => {
                 a.get_field(message_down_cast(m)).is_non_zero()
             }
@@ -230,7 +385,8 @@ impl<M : Message + 'static> FieldAccessor for FieldAccessorImpl<M> {
             FieldAccessorFunctions::Map(ref a) => a.get_field(message_down_cast(m)).len(),
             FieldAccessorFunctions::Simple(..) |
             FieldAccessorFunctions::SingularHasGetSet { .. } |
-            FieldAccessorFunctions::Optional(..) => {
+            FieldAccessorFunctions::Optional(..) |
+            FieldAccessorFunctions::Wrapper(..) => {
                 panic!("not a repeated field");
             }
         }
@@ -251,12 +407,12 @@ impl<M : Message + 'static> FieldAccessor for FieldAccessorImpl<M> {
         }
     }
 
-    fn get_enum_generic(&self, m: &Message) -> &'static EnumValueDescriptor {
-        match self.fns {
-            FieldAccessorFunctions::SingularHasGetSet {
-                get_set: SingularGetSet::Enum(ref get), ..
-            } => get.get_enum(message_down_cast(m)),
-            _ => panic!(),
+    fn get_enum_generic(&self, m: &Message) -> Result<&'static EnumValueDescriptor, i32> {
+        match self.get_value_option(message_down_cast(m)) {
+            Some(ProtobufValueRef::Enum(v)) => Ok(v),
+            Some(ProtobufValueRef::UnknownEnum(_, v)) => Err(v),
+            Some(_) => panic!("wrong type"),
+            None => panic!("field unset"),
         }
     }
 
@@ -332,6 +488,99 @@ impl<M : Message + 'static> FieldAccessor for FieldAccessorImpl<M> {
         }
     }
 
+    fn runtime_field_type(&self) -> RuntimeFieldType {
+        (self.runtime_field_type)()
+    }
+
+    fn has_presence_generic(&self) -> bool {
+        match self.fns {
+            FieldAccessorFunctions::SingularHasGetSet { .. } |
+            FieldAccessorFunctions::Optional(..) |
+            FieldAccessorFunctions::Wrapper(..) => true,
+            FieldAccessorFunctions::Simple(..) |
+            FieldAccessorFunctions::Repeated(..) |
+            FieldAccessorFunctions::Map(..) => false,
+        }
+    }
+
+    fn is_wrapper_generic(&self) -> bool {
+        match self.fns {
+            FieldAccessorFunctions::Wrapper(..) => true,
+            _ => false,
+        }
+    }
+
+    fn set_singular_field_generic(&self, m: &mut Message, value: ReflectValueBox) {
+        let m = message_down_cast_mut(m);
+        match self.fns {
+            FieldAccessorFunctions::SingularHasGetSet { ref get_set, .. } => {
+                get_set.set_value(m, value)
+            }
+            FieldAccessorFunctions::Simple(ref a) => a.set_value(m, value),
+            FieldAccessorFunctions::Optional(ref a) => a.set_value(m, value),
+            FieldAccessorFunctions::Wrapper(ref a) => a.set_message(m, value),
+            FieldAccessorFunctions::Repeated(..) | FieldAccessorFunctions::Map(..) => {
+                panic!("not a singular field");
+            }
+        }
+    }
+
+    fn clear_field_generic(&self, m: &mut Message) {
+        let m = message_down_cast_mut(m);
+        match self.fns {
+            FieldAccessorFunctions::SingularHasGetSet { clear, .. } => clear(m),
+            FieldAccessorFunctions::Simple(ref a) => a.clear_field(m),
+            FieldAccessorFunctions::Optional(ref a) => a.clear_field(m),
+            FieldAccessorFunctions::Wrapper(ref a) => a.clear_field(m),
+            FieldAccessorFunctions::Repeated(ref a) => a.clear_field(m),
+            FieldAccessorFunctions::Map(ref a) => a.clear_field(m),
+        }
+    }
+
+    fn mut_message_generic<'a>(&self, m: &'a mut Message) -> &'a mut Message {
+        let runtime_type = match self.runtime_field_type() {
+            RuntimeFieldType::Singular(t) => t,
+            _ => panic!("not a singular field"),
+        };
+        let m = message_down_cast_mut(m);
+        match self.fns {
+            FieldAccessorFunctions::SingularHasGetSet {
+                has,
+                get_set: SingularGetSet::Message(ref get),
+                ..
+            } => {
+                if !has(m) {
+                    get.set_message(m, new_message_box(runtime_type));
+                }
+                get.mut_message(m)
+            }
+            FieldAccessorFunctions::Simple(ref a) => a.mut_message(m, runtime_type),
+            FieldAccessorFunctions::Optional(ref a) => a.mut_message(m, runtime_type),
+            ref fns => panic!("not a message field: {:?}", fns),
+        }
+    }
+
+    fn push_generic(&self, m: &mut Message, value: ReflectValueBox) {
+        match self.fns {
+            FieldAccessorFunctions::Repeated(ref a) => a.push(message_down_cast_mut(m), value),
+            ref fns => panic!("not a repeated field: {:?}", fns),
+        }
+    }
+
+    fn insert_generic(&self, m: &mut Message, key: ReflectValueBox, value: ReflectValueBox) {
+        match self.fns {
+            FieldAccessorFunctions::Map(ref a) => a.insert(message_down_cast_mut(m), key, value),
+            ref fns => panic!("not a map field: {:?}", fns),
+        }
+    }
+
+    fn remove_generic(&self, m: &mut Message, key: ReflectValueBox) -> bool {
+        match self.fns {
+            FieldAccessorFunctions::Map(ref a) => a.remove(message_down_cast_mut(m), key),
+            ref fns => panic!("not a map field: {:?}", fns),
+        }
+    }
+
     fn get_reflect<'a>(&self, m: &'a Message) -> ReflectFieldRef<'a> {
         match self.fns {
             FieldAccessorFunctions::Repeated(ref accessor2) => {
@@ -358,9 +607,17 @@ impl<M : Message + 'static> FieldAccessor for FieldAccessorImpl<M> {
                     }
                 })
             }
+            FieldAccessorFunctions::Wrapper(ref accessor2) => {
+                ReflectFieldRef::Optional(
+                    accessor2
+                        .get_message(message_down_cast(m))
+                        .map(ProtobufValueRef::OwnedMessage),
+                )
+            }
             FieldAccessorFunctions::SingularHasGetSet {
                 ref has,
                 ref get_set,
+                ..
             } => {
                 ReflectFieldRef::Optional(if has(message_down_cast(m)) {
                     Some(get_set.get_ref(message_down_cast(m)))
@@ -373,28 +630,95 @@ impl<M : Message + 'static> FieldAccessor for FieldAccessorImpl<M> {
 }
 
 
-// singular
+// runtime types
+
+fn runtime_singular<V : ProtobufType>() -> RuntimeFieldType {
+    RuntimeFieldType::Singular(V::runtime_type())
+}
 
-fn set_panic<A, B>(_: &mut A, _: B) {
-    panic!()
+fn runtime_singular_enum<E : ProtobufEnum>() -> RuntimeFieldType {
+    RuntimeFieldType::Singular(RuntimeType::for_enum::<E>())
 }
 
+fn runtime_singular_message<F : Message>() -> RuntimeFieldType {
+    RuntimeFieldType::Singular(RuntimeType::for_message::<F>())
+}
+
+fn runtime_repeated<V : ProtobufType>() -> RuntimeFieldType {
+    RuntimeFieldType::Repeated(V::runtime_type())
+}
+
+fn runtime_map<K : ProtobufType, V : ProtobufType>() -> RuntimeFieldType {
+    RuntimeFieldType::Map(K::runtime_type(), V::runtime_type())
+}
+
+
+// singular
+
 // TODO: make_singular_xxx_accessor are used only for oneof fields
 // oneof codegen should be changed
 
+// `make_singular_xxx_accessor` without `_mut` are kept for code generated
+// before mutable reflection, fields of such code cannot be modified with reflection
+
+fn set_panic<M, V>(_: &mut M, _: V) {
+    panic!("field is read-only: code was generated without mutable reflection");
+}
+
+fn mut_field_panic<M, F>(_: &mut M) -> &mut F {
+    panic!("field is read-only: code was generated without mutable reflection");
+}
+
+fn clear_panic<M>(_: &mut M) {
+    panic!("field is read-only: code was generated without mutable reflection");
+}
+
+pub fn make_singular_u32_accessor_mut<M : Message + 'static>(
+    name: &'static str,
+    has: fn(&M) -> bool,
+    get: fn(&M) -> u32,
+    set: fn(&mut M, u32),
+    clear: fn(&mut M),
+) -> Box<FieldAccessor + 'static> {
+    Box::new(FieldAccessorImpl {
+        name: name,
+        runtime_field_type: runtime_singular::<ProtobufTypeUint32>,
+        fns: FieldAccessorFunctions::SingularHasGetSet {
+            has: has,
+            get_set: SingularGetSet::Copy(Box::new(GetSetCopyFnsImpl {
+                get: get,
+                set: set,
+            })),
+            clear: clear,
+        },
+    })
+}
+
 pub fn make_singular_u32_accessor<M : Message + 'static>(
     name: &'static str,
     has: fn(&M) -> bool,
     get: fn(&M) -> u32,
+) -> Box<FieldAccessor + 'static> {
+    make_singular_u32_accessor_mut(name, has, get, set_panic, clear_panic)
+}
+
+pub fn make_singular_i32_accessor_mut<M : Message + 'static>(
+    name: &'static str,
+    has: fn(&M) -> bool,
+    get: fn(&M) -> i32,
+    set: fn(&mut M, i32),
+    clear: fn(&mut M),
 ) -> Box<FieldAccessor + 'static> {
     Box::new(FieldAccessorImpl {
         name: name,
+        runtime_field_type: runtime_singular::<ProtobufTypeInt32>,
         fns: FieldAccessorFunctions::SingularHasGetSet {
             has: has,
             get_set: SingularGetSet::Copy(Box::new(GetSetCopyFnsImpl {
                 get: get,
-                _set: set_panic,
+                set: set,
             })),
+            clear: clear,
         },
     })
 }
@@ -403,15 +727,27 @@ pub fn make_singular_i32_accessor<M : Message + 'static>(
     name: &'static str,
     has: fn(&M) -> bool,
     get: fn(&M) -> i32,
+) -> Box<FieldAccessor + 'static> {
+    make_singular_i32_accessor_mut(name, has, get, set_panic, clear_panic)
+}
+
+pub fn make_singular_u64_accessor_mut<M : Message + 'static>(
+    name: &'static str,
+    has: fn(&M) -> bool,
+    get: fn(&M) -> u64,
+    set: fn(&mut M, u64),
+    clear: fn(&mut M),
 ) -> Box<FieldAccessor + 'static> {
     Box::new(FieldAccessorImpl {
         name: name,
+        runtime_field_type: runtime_singular::<ProtobufTypeUint64>,
         fns: FieldAccessorFunctions::SingularHasGetSet {
             has: has,
             get_set: SingularGetSet::Copy(Box::new(GetSetCopyFnsImpl {
                 get: get,
-                _set: set_panic,
+                set: set,
             })),
+            clear: clear,
         },
     })
 }
@@ -420,15 +756,27 @@ pub fn make_singular_u64_accessor<M : Message + 'static>(
     name: &'static str,
     has: fn(&M) -> bool,
     get: fn(&M) -> u64,
+) -> Box<FieldAccessor + 'static> {
+    make_singular_u64_accessor_mut(name, has, get, set_panic, clear_panic)
+}
+
+pub fn make_singular_i64_accessor_mut<M : Message + 'static>(
+    name: &'static str,
+    has: fn(&M) -> bool,
+    get: fn(&M) -> i64,
+    set: fn(&mut M, i64),
+    clear: fn(&mut M),
 ) -> Box<FieldAccessor + 'static> {
     Box::new(FieldAccessorImpl {
         name: name,
+        runtime_field_type: runtime_singular::<ProtobufTypeInt64>,
         fns: FieldAccessorFunctions::SingularHasGetSet {
             has: has,
             get_set: SingularGetSet::Copy(Box::new(GetSetCopyFnsImpl {
                 get: get,
-                _set: set_panic,
+                set: set,
             })),
+            clear: clear,
         },
     })
 }
@@ -437,15 +785,27 @@ pub fn make_singular_i64_accessor<M : Message + 'static>(
     name: &'static str,
     has: fn(&M) -> bool,
     get: fn(&M) -> i64,
+) -> Box<FieldAccessor + 'static> {
+    make_singular_i64_accessor_mut(name, has, get, set_panic, clear_panic)
+}
+
+pub fn make_singular_f32_accessor_mut<M : Message + 'static>(
+    name: &'static str,
+    has: fn(&M) -> bool,
+    get: fn(&M) -> f32,
+    set: fn(&mut M, f32),
+    clear: fn(&mut M),
 ) -> Box<FieldAccessor + 'static> {
     Box::new(FieldAccessorImpl {
         name: name,
+        runtime_field_type: runtime_singular::<ProtobufTypeFloat>,
         fns: FieldAccessorFunctions::SingularHasGetSet {
             has: has,
             get_set: SingularGetSet::Copy(Box::new(GetSetCopyFnsImpl {
                 get: get,
-                _set: set_panic,
+                set: set,
             })),
+            clear: clear,
         },
     })
 }
@@ -454,15 +814,27 @@ pub fn make_singular_f32_accessor<M : Message + 'static>(
     name: &'static str,
     has: fn(&M) -> bool,
     get: fn(&M) -> f32,
+) -> Box<FieldAccessor + 'static> {
+    make_singular_f32_accessor_mut(name, has, get, set_panic, clear_panic)
+}
+
+pub fn make_singular_f64_accessor_mut<M : Message + 'static>(
+    name: &'static str,
+    has: fn(&M) -> bool,
+    get: fn(&M) -> f64,
+    set: fn(&mut M, f64),
+    clear: fn(&mut M),
 ) -> Box<FieldAccessor + 'static> {
     Box::new(FieldAccessorImpl {
         name: name,
+        runtime_field_type: runtime_singular::<ProtobufTypeDouble>,
         fns: FieldAccessorFunctions::SingularHasGetSet {
             has: has,
             get_set: SingularGetSet::Copy(Box::new(GetSetCopyFnsImpl {
                 get: get,
-                _set: set_panic,
+                set: set,
             })),
+            clear: clear,
         },
     })
 }
@@ -471,15 +843,27 @@ pub fn make_singular_f64_accessor<M : Message + 'static>(
     name: &'static str,
     has: fn(&M) -> bool,
     get: fn(&M) -> f64,
+) -> Box<FieldAccessor + 'static> {
+    make_singular_f64_accessor_mut(name, has, get, set_panic, clear_panic)
+}
+
+pub fn make_singular_bool_accessor_mut<M : Message + 'static>(
+    name: &'static str,
+    has: fn(&M) -> bool,
+    get: fn(&M) -> bool,
+    set: fn(&mut M, bool),
+    clear: fn(&mut M),
 ) -> Box<FieldAccessor + 'static> {
     Box::new(FieldAccessorImpl {
         name: name,
+        runtime_field_type: runtime_singular::<ProtobufTypeBool>,
         fns: FieldAccessorFunctions::SingularHasGetSet {
             has: has,
             get_set: SingularGetSet::Copy(Box::new(GetSetCopyFnsImpl {
                 get: get,
-                _set: set_panic,
+                set: set,
             })),
+            clear: clear,
         },
     })
 }
@@ -488,15 +872,24 @@ pub fn make_singular_bool_accessor<M : Message + 'static>(
     name: &'static str,
     has: fn(&M) -> bool,
     get: fn(&M) -> bool,
+) -> Box<FieldAccessor + 'static> {
+    make_singular_bool_accessor_mut(name, has, get, set_panic, clear_panic)
+}
+
+pub fn make_singular_enum_accessor_mut<M : Message + 'static, E : ProtobufEnum + 'static>(
+    name: &'static str,
+    has: fn(&M) -> bool,
+    get: fn(&M) -> E,
+    set: fn(&mut M, E),
+    clear: fn(&mut M),
 ) -> Box<FieldAccessor + 'static> {
     Box::new(FieldAccessorImpl {
         name: name,
+        runtime_field_type: runtime_singular_enum::<E>,
         fns: FieldAccessorFunctions::SingularHasGetSet {
             has: has,
-            get_set: SingularGetSet::Copy(Box::new(GetSetCopyFnsImpl {
-                get: get,
-                _set: set_panic,
-            })),
+            get_set: SingularGetSet::Enum(Box::new(GetSingularEnumImpl { get: get, set: set })),
+            clear: clear,
         },
     })
 }
@@ -505,12 +898,24 @@ pub fn make_singular_enum_accessor<M : Message + 'static, E : ProtobufEnum + 'st
     name: &'static str,
     has: fn(&M) -> bool,
     get: fn(&M) -> E,
+) -> Box<FieldAccessor + 'static> {
+    make_singular_enum_accessor_mut(name, has, get, set_panic, clear_panic)
+}
+
+pub fn make_singular_string_accessor_mut<M : Message + 'static>(
+    name: &'static str,
+    has: fn(&M) -> bool,
+    get: for<'a> fn(&'a M) -> &'a str,
+    set: fn(&mut M, String),
+    clear: fn(&mut M),
 ) -> Box<FieldAccessor + 'static> {
     Box::new(FieldAccessorImpl {
         name: name,
+        runtime_field_type: runtime_singular::<ProtobufTypeString>,
         fns: FieldAccessorFunctions::SingularHasGetSet {
             has: has,
-            get_set: SingularGetSet::Enum(Box::new(GetSingularEnumImpl { get: get })),
+            get_set: SingularGetSet::String(get, set),
+            clear: clear,
         },
     })
 }
@@ -519,12 +924,24 @@ pub fn make_singular_string_accessor<M : Message + 'static>(
     name: &'static str,
     has: fn(&M) -> bool,
     get: for<'a> fn(&'a M) -> &'a str,
+) -> Box<FieldAccessor + 'static> {
+    make_singular_string_accessor_mut(name, has, get, set_panic, clear_panic)
+}
+
+pub fn make_singular_bytes_accessor_mut<M : Message + 'static>(
+    name: &'static str,
+    has: fn(&M) -> bool,
+    get: for<'a> fn(&'a M) -> &'a [u8],
+    set: fn(&mut M, Vec<u8>),
+    clear: fn(&mut M),
 ) -> Box<FieldAccessor + 'static> {
     Box::new(FieldAccessorImpl {
         name: name,
+        runtime_field_type: runtime_singular::<ProtobufTypeBytes>,
         fns: FieldAccessorFunctions::SingularHasGetSet {
             has: has,
-            get_set: SingularGetSet::String(get, set_panic),
+            get_set: SingularGetSet::Bytes(get, set),
+            clear: clear,
         },
     })
 }
@@ -533,12 +950,27 @@ pub fn make_singular_bytes_accessor<M : Message + 'static>(
     name: &'static str,
     has: fn(&M) -> bool,
     get: for<'a> fn(&'a M) -> &'a [u8],
+) -> Box<FieldAccessor + 'static> {
+    make_singular_bytes_accessor_mut(name, has, get, set_panic, clear_panic)
+}
+
+pub fn make_singular_message_accessor_mut<M : Message + 'static, F : Message + 'static>(
+    name: &'static str,
+    has: fn(&M) -> bool,
+    get: for<'a> fn(&'a M) -> &'a F,
+    mut_field: for<'a> fn(&'a mut M) -> &'a mut F,
+    clear: fn(&mut M),
 ) -> Box<FieldAccessor + 'static> {
     Box::new(FieldAccessorImpl {
         name: name,
+        runtime_field_type: runtime_singular_message::<F>,
         fns: FieldAccessorFunctions::SingularHasGetSet {
             has: has,
-            get_set: SingularGetSet::Bytes(get, set_panic),
+            get_set: SingularGetSet::Message(Box::new(GetSingularMessageImpl {
+                get: get,
+                mut_field: mut_field,
+            })),
+            clear: clear,
         },
     })
 }
@@ -548,22 +980,43 @@ pub fn make_singular_message_accessor<M : Message + 'static, F : Message + 'stat
     has: fn(&M) -> bool,
     get: for<'a> fn(&'a M) -> &'a F,
 ) -> Box<FieldAccessor + 'static> {
-    Box::new(FieldAccessorImpl {
-        name: name,
-        fns: FieldAccessorFunctions::SingularHasGetSet {
-            has: has,
-            get_set: SingularGetSet::Message(Box::new(GetSingularMessageImpl { get: get })),
-        },
-    })
+    make_singular_message_accessor_mut(name, has, get, mut_field_panic, clear_panic)
 }
 
 // repeated
 
+impl<M, V> FieldAccessor2<M, ReflectRepeated> for MessageGetMut<M, Vec<V::Value>, V>
+where
+    M : Message + 'static,
+    V : ProtobufType + 'static,
+{
+    fn get_field<'a>(&self, m: &'a M) -> &'a ReflectRepeated {
+        (self.get_field)(m) as &ReflectRepeated
+    }
+
+    fn mut_field<'a>(&self, m: &'a mut M) -> &'a mut ReflectRepeated {
+        (self.mut_field)(m) as &mut ReflectRepeated
+    }
+}
+
+impl<M, V> RepeatedFieldAccessor<M> for MessageGetMut<M, Vec<V::Value>, V>
+where
+    M : Message + 'static,
+    V : ProtobufType + 'static,
+{
+    fn push(&self, m: &mut M, value: ReflectValueBox) {
+        (self.mut_field)(m).push(value_from_box::<V>(value));
+    }
+
+    fn clear_field(&self, m: &mut M) {
+        (self.mut_field)(m).clear();
+    }
+}
 
-impl<M, V> FieldAccessor2<M, ReflectRepeated> for MessageGetMut<M, Vec<V>>
+impl<M, V> FieldAccessor2<M, ReflectRepeated> for MessageGetMut<M, RepeatedField<V::Value>, V>
 where
     M : Message + 'static,
-    V : ProtobufValue + 'static,
+    V : ProtobufType + 'static,
 {
     fn get_field<'a>(&self, m: &'a M) -> &'a ReflectRepeated {
         (self.get_field)(m) as &ReflectRepeated
@@ -574,7 +1027,19 @@ where
     }
 }
 
+impl<M, V> RepeatedFieldAccessor<M> for MessageGetMut<M, RepeatedField<V::Value>, V>
+where
+    M : Message + 'static,
+    V : ProtobufType + 'static,
+{
+    fn push(&self, m: &mut M, value: ReflectValueBox) {
+        (self.mut_field)(m).push(value_from_box::<V>(value));
+    }
 
+    fn clear_field(&self, m: &mut M) {
+        (self.mut_field)(m).clear();
+    }
+}
 
 pub fn make_vec_accessor<M, V>(
     name: &'static str,
@@ -587,29 +1052,15 @@ where
 {
     Box::new(FieldAccessorImpl {
         name: name,
-        fns: FieldAccessorFunctions::Repeated(Box::new(MessageGetMut::<M, Vec<V::Value>> {
+        runtime_field_type: runtime_repeated::<V>,
+        fns: FieldAccessorFunctions::Repeated(Box::new(MessageGetMut::<M, Vec<V::Value>, V> {
             get_field: get_vec,
             mut_field: mut_vec,
+            _marker: marker::PhantomData,
         })),
     })
 }
 
-
-impl<M, V> FieldAccessor2<M, ReflectRepeated> for MessageGetMut<M, RepeatedField<V>>
-where
-    M : Message + 'static,
-    V : ProtobufValue + 'static,
-{
-    fn get_field<'a>(&self, m: &'a M) -> &'a ReflectRepeated {
-        (self.get_field)(m) as &ReflectRepeated
-    }
-
-    fn mut_field<'a>(&self, m: &'a mut M) -> &'a mut ReflectRepeated {
-        (self.mut_field)(m) as &mut ReflectRepeated
-    }
-}
-
-
 pub fn make_repeated_field_accessor<M, V>(
     name: &'static str,
     get_vec: for<'a> fn(&'a M) -> &'a RepeatedField<V::Value>,
@@ -622,19 +1073,23 @@ where
 {
     Box::new(FieldAccessorImpl {
         name: name,
+        runtime_field_type: runtime_repeated::<V>,
         fns: FieldAccessorFunctions::Repeated(
-            Box::new(MessageGetMut::<M, RepeatedField<V::Value>> {
+            Box::new(MessageGetMut::<M, RepeatedField<V::Value>, V> {
                 get_field: get_vec,
                 mut_field: mut_vec,
+                _marker: marker::PhantomData,
             }),
         ),
     })
 }
 
-impl<M, V> FieldAccessor2<M, ReflectOptional> for MessageGetMut<M, Option<V>>
+// optional
+
+impl<M, V> FieldAccessor2<M, ReflectOptional> for MessageGetMut<M, Option<V::Value>, V>
 where
     M : Message + 'static,
-    V : ProtobufValue + Clone + 'static,
+    V : ProtobufType + 'static,
 {
     fn get_field<'a>(&self, m: &'a M) -> &'a ReflectOptional {
         (self.get_field)(m) as &ReflectOptional
@@ -645,6 +1100,30 @@ where
     }
 }
 
+impl<M, V> SingularFieldAccessor<M, ReflectOptional> for MessageGetMut<M, Option<V::Value>, V>
+where
+    M : Message + 'static,
+    V : ProtobufType + 'static,
+{
+    fn set_value(&self, m: &mut M, value: ReflectValueBox) {
+        let v = value_from_box::<V>(value);
+        *(self.mut_field)(m) = Some(v);
+    }
+
+    fn clear_field(&self, m: &mut M) {
+        let f = (self.mut_field)(m);
+        *f = None;
+    }
+
+    fn mut_message<'a>(&self, m: &'a mut M, runtime_type: RuntimeType) -> &'a mut Message {
+        if (self.mut_field)(m).as_mut().is_none() {
+            self.set_value(m, new_message_box(runtime_type));
+        }
+        let v = (self.mut_field)(m).as_mut().unwrap();
+        V::as_message_mut(v).expect("not a message field")
+    }
+}
+
 pub fn make_option_accessor<M, V>(
     name: &'static str,
     get_field: for<'a> fn(&'a M) -> &'a Option<V::Value>,
@@ -656,18 +1135,21 @@ where
 {
     Box::new(FieldAccessorImpl {
         name: name,
-        fns: FieldAccessorFunctions::Optional(Box::new(MessageGetMut::<M, Option<V::Value>> {
-            get_field: get_field,
-            mut_field: mut_field,
-        })),
+        runtime_field_type: runtime_singular::<V>,
+        fns: FieldAccessorFunctions::Optional(
+            Box::new(MessageGetMut::<M, Option<V::Value>, V> {
+                get_field: get_field,
+                mut_field: mut_field,
+                _marker: marker::PhantomData,
+            }),
+        ),
     })
 }
 
-
-impl<M, V> FieldAccessor2<M, ReflectOptional> for MessageGetMut<M, SingularField<V>>
+impl<M, V> FieldAccessor2<M, ReflectOptional> for MessageGetMut<M, SingularField<V::Value>, V>
 where
     M : Message + 'static,
-    V : ProtobufValue + Clone + 'static,
+    V : ProtobufType + 'static,
 {
     fn get_field<'a>(&self, m: &'a M) -> &'a ReflectOptional {
         (self.get_field)(m) as &ReflectOptional
@@ -678,11 +1160,34 @@ where
     }
 }
 
+impl<M, V> SingularFieldAccessor<M, ReflectOptional> for MessageGetMut<M, SingularField<V::Value>, V>
+where
+    M : Message + 'static,
+    V : ProtobufType + 'static,
+{
+    fn set_value(&self, m: &mut M, value: ReflectValueBox) {
+        let v = value_from_box::<V>(value);
+        *(self.mut_field)(m) = SingularField::some(v);
+    }
+
+    fn clear_field(&self, m: &mut M) {
+        let f = (self.mut_field)(m);
+        f.clear();
+    }
+
+    fn mut_message<'a>(&self, m: &'a mut M, runtime_type: RuntimeType) -> &'a mut Message {
+        if (self.mut_field)(m).as_mut().is_none() {
+            self.set_value(m, new_message_box(runtime_type));
+        }
+        let v = (self.mut_field)(m).as_mut().unwrap();
+        V::as_message_mut(v).expect("not a message field")
+    }
+}
+
 pub fn make_singular_field_accessor<M, V>(
     name: &'static str,
     get_field: for<'a> fn(&'a M) -> &'a SingularField<V::Value>,
-    mut_field: for<'a> fn(&'a mut M)
-        -> &'a mut SingularField<V::Value>,
+    mut_field: for<'a> fn(&'a mut M) -> &'a mut SingularField<V::Value>,
 ) -> Box<FieldAccessor + 'static>
 where
     M : Message + 'static,
@@ -690,19 +1195,21 @@ where
 {
     Box::new(FieldAccessorImpl {
         name: name,
+        runtime_field_type: runtime_singular::<V>,
         fns: FieldAccessorFunctions::Optional(
-            Box::new(MessageGetMut::<M, SingularField<V::Value>> {
+            Box::new(MessageGetMut::<M, SingularField<V::Value>, V> {
                 get_field: get_field,
                 mut_field: mut_field,
+                _marker: marker::PhantomData,
             }),
         ),
     })
 }
 
-impl<M, V> FieldAccessor2<M, ReflectOptional> for MessageGetMut<M, SingularPtrField<V>>
+impl<M, V> FieldAccessor2<M, ReflectOptional> for MessageGetMut<M, SingularPtrField<V::Value>, V>
 where
     M : Message + 'static,
-    V : ProtobufValue + Clone + 'static,
+    V : ProtobufType + 'static,
 {
     fn get_field<'a>(&self, m: &'a M) -> &'a ReflectOptional {
         (self.get_field)(m) as &ReflectOptional
@@ -713,12 +1220,34 @@ where
     }
 }
 
+impl<M, V> SingularFieldAccessor<M, ReflectOptional> for MessageGetMut<M, SingularPtrField<V::Value>, V>
+where
+    M : Message + 'static,
+    V : ProtobufType + 'static,
+{
+    fn set_value(&self, m: &mut M, value: ReflectValueBox) {
+        let v = value_from_box::<V>(value);
+        *(self.mut_field)(m) = SingularPtrField::some(v);
+    }
+
+    fn clear_field(&self, m: &mut M) {
+        let f = (self.mut_field)(m);
+        f.clear();
+    }
+
+    fn mut_message<'a>(&self, m: &'a mut M, runtime_type: RuntimeType) -> &'a mut Message {
+        if (self.mut_field)(m).as_mut().is_none() {
+            self.set_value(m, new_message_box(runtime_type));
+        }
+        let v = (self.mut_field)(m).as_mut().unwrap();
+        V::as_message_mut(v).expect("not a message field")
+    }
+}
+
 pub fn make_singular_ptr_field_accessor<M, V>(
     name: &'static str,
-    get_field: for<'a> fn(&'a M)
-        -> &'a SingularPtrField<V::Value>,
-    mut_field: for<'a> fn(&'a mut M)
-        -> &'a mut SingularPtrField<V::Value>,
+    get_field: for<'a> fn(&'a M) -> &'a SingularPtrField<V::Value>,
+    mut_field: for<'a> fn(&'a mut M) -> &'a mut SingularPtrField<V::Value>,
 ) -> Box<FieldAccessor + 'static>
 where
     M : Message + 'static,
@@ -726,19 +1255,23 @@ where
 {
     Box::new(FieldAccessorImpl {
         name: name,
+        runtime_field_type: runtime_singular::<V>,
         fns: FieldAccessorFunctions::Optional(
-            Box::new(MessageGetMut::<M, SingularPtrField<V::Value>> {
+            Box::new(MessageGetMut::<M, SingularPtrField<V::Value>, V> {
                 get_field: get_field,
                 mut_field: mut_field,
+                _marker: marker::PhantomData,
             }),
         ),
     })
 }
 
-impl<M, V> FieldAccessor2<M, ProtobufValue> for MessageGetMut<M, V>
+// simple
+
+impl<M, V> FieldAccessor2<M, ProtobufValue> for SimpleGetMut<M, V>
 where
     M : Message + 'static,
-    V : ProtobufValue + Clone + 'static,
+    V : ProtobufType + 'static,
 {
     fn get_field<'a>(&self, m: &'a M) -> &'a ProtobufValue {
         (self.get_field)(m) as &ProtobufValue
@@ -749,6 +1282,25 @@ where
     }
 }
 
+impl<M, V> SingularFieldAccessor<M, ProtobufValue> for SimpleGetMut<M, V>
+where
+    M : Message + 'static,
+    V : ProtobufType + 'static,
+    V::Value : Default,
+{
+    fn set_value(&self, m: &mut M, value: ReflectValueBox) {
+        *(self.mut_field)(m) = value_from_box::<V>(value);
+    }
+
+    fn clear_field(&self, m: &mut M) {
+        *(self.mut_field)(m) = Default::default();
+    }
+
+    fn mut_message<'a>(&self, m: &'a mut M, _runtime_type: RuntimeType) -> &'a mut Message {
+        V::as_message_mut((self.mut_field)(m)).expect("not a message field")
+    }
+}
+
 pub fn make_simple_field_accessor<M, V>(
     name: &'static str,
     get_field: for<'a> fn(&'a M) -> &'a V::Value,
@@ -757,23 +1309,73 @@ pub fn make_simple_field_accessor<M, V>(
 where
     M : Message + 'static,
     V : ProtobufType + 'static,
+    V::Value : Default,
+{
+    Box::new(FieldAccessorImpl {
+        name: name,
+        runtime_field_type: runtime_singular::<V>,
+        fns: FieldAccessorFunctions::Simple(Box::new(SimpleGetMut::<M, V> {
+            get_field: get_field,
+            mut_field: mut_field,
+        })),
+    })
+}
+
+// wrapper
+
+impl<M, V, W> WrapperFieldAccessor<M> for WrapperGetMut<M, V, W>
+where
+    M : Message + 'static,
+    V : ProtobufType + 'static,
+    V::Value : Clone + From<W>,
+    W : Message + From<V::Value> + 'static,
+{
+    fn get_message(&self, m: &M) -> Option<Box<Message>> {
+        (self.get_field)(m).as_ref().map(|v| Box::new(W::from(v.clone())) as Box<Message>)
+    }
+
+    fn set_message(&self, m: &mut M, value: ReflectValueBox) {
+        let w = value.downcast::<W>().expect("wrong type");
+        *(self.mut_field)(m) = Some(w.into());
+    }
+
+    fn clear_field(&self, m: &mut M) {
+        *(self.mut_field)(m) = None;
+    }
+}
+
+/// Accessor of wrapper message field like `google.protobuf.Int32Value`
+/// generated as `Option` of wrapped value
+pub fn make_wrapper_accessor<M, V, W>(
+    name: &'static str,
+    get_field: for<'a> fn(&'a M) -> &'a Option<V::Value>,
+    mut_field: for<'a> fn(&'a mut M) -> &'a mut Option<V::Value>,
+) -> Box<FieldAccessor + 'static>
+where
+    M : Message + 'static,
+    V : ProtobufType + 'static,
+    V::Value : Clone + From<W>,
+    W : Message + From<V::Value> + 'static,
 {
     Box::new(FieldAccessorImpl {
         name: name,
-        fns: FieldAccessorFunctions::Simple(Box::new(MessageGetMut::<M, V::Value> {
+        runtime_field_type: runtime_singular_message::<W>,
+        fns: FieldAccessorFunctions::Wrapper(Box::new(WrapperGetMut::<M, V, W> {
             get_field: get_field,
             mut_field: mut_field,
+            _marker: marker::PhantomData,
         })),
     })
 }
 
+// map
 
-impl<M, K, V> FieldAccessor2<M, ReflectMap> for MessageGetMut<M, HashMap<K, V>>
+impl<M, K, V> FieldAccessor2<M, ReflectMap> for MessageGetMut<M, HashMap<K::Value, V::Value>, (K, V)>
 where
     M : Message + 'static,
-    K : ProtobufValue + 'static,
-    V : ProtobufValue + 'static,
-    K : Hash + Eq,
+    K : ProtobufType + 'static,
+    V : ProtobufType + 'static,
+    K::Value : Hash + Eq,
 {
     fn get_field<'a>(&self, m: &'a M) -> &'a ReflectMap {
         (self.get_field)(m) as &ReflectMap
@@ -784,6 +1386,28 @@ where
     }
 }
 
+impl<M, K, V> MapFieldAccessor<M> for MessageGetMut<M, HashMap<K::Value, V::Value>, (K, V)>
+where
+    M : Message + 'static,
+    K : ProtobufType + 'static,
+    V : ProtobufType + 'static,
+    K::Value : Hash + Eq,
+{
+    fn insert(&self, m: &mut M, key: ReflectValueBox, value: ReflectValueBox) {
+        let key = value_from_box::<K>(key);
+        let value = value_from_box::<V>(value);
+        (self.mut_field)(m).insert(key, value);
+    }
+
+    fn remove(&self, m: &mut M, key: ReflectValueBox) -> bool {
+        let key = value_from_box::<K>(key);
+        (self.mut_field)(m).remove(&key).is_some()
+    }
+
+    fn clear_field(&self, m: &mut M) {
+        (self.mut_field)(m).clear();
+    }
+}
 
 pub fn make_map_accessor<M, K, V>(
     name: &'static str,
@@ -800,11 +1424,45 @@ where
 {
     Box::new(FieldAccessorImpl {
         name: name,
+        runtime_field_type: runtime_map::<K, V>,
         fns: FieldAccessorFunctions::Map(
-            Box::new(MessageGetMut::<M, HashMap<K::Value, V::Value>> {
+            Box::new(MessageGetMut::<M, HashMap<K::Value, V::Value>, (K, V)> {
                 get_field: get_field,
                 mut_field: mut_field,
+                _marker: marker::PhantomData,
             }),
         ),
     })
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use well_known_types::Value;
+
+    fn old_style_accessor() -> Box<FieldAccessor + 'static> {
+        // like oneof accessor generated before mutable reflection
+        make_singular_f64_accessor::<Value>(
+            "number_value",
+            Value::has_number_value,
+            Value::get_number_value,
+        )
+    }
+
+    #[test]
+    fn test_old_style_singular_accessor_get() {
+        let mut value = Value::new();
+        value.set_number_value(1.5);
+        let accessor = old_style_accessor();
+        assert!(accessor.has_field_generic(&value));
+        assert_eq!(1.5, accessor.get_f64_generic(&value));
+    }
+
+    #[test]
+    #[should_panic(expected = "field is read-only")]
+    fn test_old_style_singular_accessor_set() {
+        let mut value = Value::new();
+        old_style_accessor().set_singular_field_generic(&mut value, ReflectValueBox::F64(2.0));
+    }
+}
@@ -0,0 +1,39 @@
+//! Links between descriptors created at runtime.
+
+use std::ptr;
+use std::sync::atomic::AtomicPtr;
+use std::sync::atomic::Ordering;
+
+
+/// Descriptors created at runtime are referenced from messages and other descriptors
+/// by `&'static` references, so they are never freed.
+pub(crate) fn leak<T>(value: T) -> &'static T {
+    unsafe { &*Box::into_raw(Box::new(value)) }
+}
+
+
+/// Reference to a descriptor which is set after both descriptors are created,
+/// so descriptors can refer to each other and to themselves.
+pub(crate) struct DescriptorCell<T : 'static> {
+    ptr: AtomicPtr<T>,
+}
+
+impl<T> DescriptorCell<T> {
+    pub fn new() -> DescriptorCell<T> {
+        DescriptorCell { ptr: AtomicPtr::new(ptr::null_mut()) }
+    }
+
+    pub fn set(&self, descriptor: &'static T) {
+        let ptr = descriptor as *const T as *mut T;
+        self.ptr.store(ptr, Ordering::Release);
+    }
+
+    pub fn get(&self) -> Option<&'static T> {
+        let ptr = self.ptr.load(Ordering::Acquire);
+        if ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { &*ptr })
+        }
+    }
+}
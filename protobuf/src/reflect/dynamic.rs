@@ -0,0 +1,1620 @@
+//! Messages without generated Rust types, described by descriptors loaded at runtime.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::fmt;
+use std::ptr;
+
+use cached_size::CachedSize;
+use clear::Clear;
+use core::Message;
+use descriptor::DescriptorProto;
+use descriptor::FieldDescriptorProto;
+use descriptor::FieldDescriptorProto_Label;
+use descriptor::FieldDescriptorProto_Type;
+use error::ProtobufResult;
+use rt;
+use rt::ProtobufVarint;
+use rt::ProtobufVarintZigzag;
+use stream::CodedInputStream;
+use stream::CodedOutputStream;
+use stream::wire_format;
+use text_format;
+use unknown::UnknownFields;
+use unknown::UnknownValueRef;
+
+use super::accessor::FieldAccessor;
+use super::cell::DescriptorCell;
+use super::cell::leak;
+use super::EnumDescriptor;
+use super::EnumValueDescriptor;
+use super::FieldDescriptor;
+use super::MessageDescriptor;
+use super::MessageFactory;
+use super::ProtobufValue;
+use super::ProtobufValueRef;
+use super::ReflectFieldRef;
+use super::ReflectValueBox;
+use super::RuntimeFieldType;
+use super::RuntimeType;
+
+
+/// Type of field values or map keys or values
+#[derive(Copy, Clone)]
+pub(super) struct DynamicType {
+    pub proto_type: FieldDescriptorProto_Type,
+    pub enum_descriptor: Option<&'static EnumDescriptor>,
+    pub message_descriptor: Option<&'static DescriptorCell<MessageDescriptor>>,
+}
+
+impl DynamicType {
+    fn runtime_type(&self) -> RuntimeType {
+        match self.proto_type {
+            FieldDescriptorProto_Type::TYPE_DOUBLE => RuntimeType::F64,
+            FieldDescriptorProto_Type::TYPE_FLOAT => RuntimeType::F32,
+            FieldDescriptorProto_Type::TYPE_INT64 |
+            FieldDescriptorProto_Type::TYPE_SINT64 |
+            FieldDescriptorProto_Type::TYPE_SFIXED64 => RuntimeType::I64,
+            FieldDescriptorProto_Type::TYPE_UINT64 |
+            FieldDescriptorProto_Type::TYPE_FIXED64 => RuntimeType::U64,
+            FieldDescriptorProto_Type::TYPE_INT32 |
+            FieldDescriptorProto_Type::TYPE_SINT32 |
+            FieldDescriptorProto_Type::TYPE_SFIXED32 => RuntimeType::I32,
+            FieldDescriptorProto_Type::TYPE_UINT32 |
+            FieldDescriptorProto_Type::TYPE_FIXED32 => RuntimeType::U32,
+            FieldDescriptorProto_Type::TYPE_BOOL => RuntimeType::Bool,
+            FieldDescriptorProto_Type::TYPE_STRING => RuntimeType::String,
+            FieldDescriptorProto_Type::TYPE_BYTES => RuntimeType::Bytes,
+            FieldDescriptorProto_Type::TYPE_ENUM => {
+                RuntimeType::Enum(self.enum_descriptor.unwrap())
+            }
+            FieldDescriptorProto_Type::TYPE_MESSAGE |
+            FieldDescriptorProto_Type::TYPE_GROUP => {
+                RuntimeType::Message(self.message_type())
+            }
+        }
+    }
+
+    fn wire_type(&self) -> wire_format::WireType {
+        match self.proto_type {
+            FieldDescriptorProto_Type::TYPE_DOUBLE |
+            FieldDescriptorProto_Type::TYPE_FIXED64 |
+            FieldDescriptorProto_Type::TYPE_SFIXED64 => wire_format::WireTypeFixed64,
+            FieldDescriptorProto_Type::TYPE_FLOAT |
+            FieldDescriptorProto_Type::TYPE_FIXED32 |
+            FieldDescriptorProto_Type::TYPE_SFIXED32 => wire_format::WireTypeFixed32,
+            FieldDescriptorProto_Type::TYPE_STRING |
+            FieldDescriptorProto_Type::TYPE_BYTES |
+            FieldDescriptorProto_Type::TYPE_MESSAGE => wire_format::WireTypeLengthDelimited,
+            FieldDescriptorProto_Type::TYPE_GROUP => wire_format::WireTypeStartGroup,
+            _ => wire_format::WireTypeVarint,
+        }
+    }
+
+    fn is_packable(&self) -> bool {
+        match self.wire_type() {
+            wire_format::WireTypeVarint |
+            wire_format::WireTypeFixed32 |
+            wire_format::WireTypeFixed64 => true,
+            _ => false,
+        }
+    }
+
+    pub fn is_message(&self) -> bool {
+        self.message_descriptor.is_some()
+    }
+
+    fn message_type(&self) -> &'static MessageDescriptor {
+        self.message_descriptor
+            .unwrap()
+            .get()
+            .expect("message descriptor is not initialized")
+    }
+
+    /// Value of a map entry key or value missing on the wire
+    fn default_value(&self) -> ReflectValueBox {
+        self.runtime_type().default_value()
+    }
+
+    /// Panic if value cannot be stored in a field of this type
+    fn check_value(&self, value: &ReflectValueBox) {
+        assert!(self.runtime_type().is_type_of(value), "wrong type");
+    }
+}
+
+#[derive(Copy, Clone)]
+pub(super) enum DynamicFieldShape {
+    Singular(DynamicType),
+    Repeated(DynamicType),
+    Map(DynamicType, DynamicType),
+}
+
+struct DynamicField {
+    proto: &'static FieldDescriptorProto,
+    shape: DynamicFieldShape,
+    /// proto3 singular field which is not serialized when it has default value
+    no_presence: bool,
+    packed: bool,
+}
+
+impl DynamicField {
+    fn new(
+        proto: &'static FieldDescriptorProto,
+        shape: DynamicFieldShape,
+        proto3: bool,
+    ) -> DynamicField {
+        let no_presence = match shape {
+            DynamicFieldShape::Singular(t) => proto3 && !t.is_message() && !proto.has_oneof_index(),
+            _ => false,
+        };
+        DynamicField {
+            proto: proto,
+            shape: shape,
+            no_presence: no_presence,
+            packed: proto.get_options().get_packed(),
+        }
+    }
+
+    fn runtime_field_type(&self) -> RuntimeFieldType {
+        match self.shape {
+            DynamicFieldShape::Singular(t) => RuntimeFieldType::Singular(t.runtime_type()),
+            DynamicFieldShape::Repeated(t) => RuntimeFieldType::Repeated(t.runtime_type()),
+            DynamicFieldShape::Map(k, v) => {
+                RuntimeFieldType::Map(k.runtime_type(), v.runtime_type())
+            }
+        }
+    }
+}
+
+/// Field layout of a dynamic message shared by all instances
+struct DynamicMessageType {
+    descriptor: &'static DescriptorCell<MessageDescriptor>,
+    fields: Vec<DynamicField>,
+    index_by_number: HashMap<u32, usize>,
+}
+
+
+/// Map key: only integral, bool and string types are allowed as map keys
+#[derive(PartialEq, Eq, Hash)]
+enum DynamicMapKey {
+    U32(u32),
+    U64(u64),
+    I32(i32),
+    I64(i64),
+    Bool(bool),
+    String(String),
+}
+
+impl DynamicMapKey {
+    fn from_box(value: ReflectValueBox) -> DynamicMapKey {
+        match value {
+            ReflectValueBox::U32(v) => DynamicMapKey::U32(v),
+            ReflectValueBox::U64(v) => DynamicMapKey::U64(v),
+            ReflectValueBox::I32(v) => DynamicMapKey::I32(v),
+            ReflectValueBox::I64(v) => DynamicMapKey::I64(v),
+            ReflectValueBox::Bool(v) => DynamicMapKey::Bool(v),
+            ReflectValueBox::String(v) => DynamicMapKey::String(v),
+            _ => panic!("wrong type"),
+        }
+    }
+}
+
+impl ProtobufValue for DynamicMapKey {
+    fn as_ref(&self) -> ProtobufValueRef {
+        match *self {
+            DynamicMapKey::U32(v) => ProtobufValueRef::U32(v),
+            DynamicMapKey::U64(v) => ProtobufValueRef::U64(v),
+            DynamicMapKey::I32(v) => ProtobufValueRef::I32(v),
+            DynamicMapKey::I64(v) => ProtobufValueRef::I64(v),
+            DynamicMapKey::Bool(v) => ProtobufValueRef::Bool(v),
+            DynamicMapKey::String(ref v) => ProtobufValueRef::String(v),
+        }
+    }
+}
+
+
+enum DynamicFieldValue {
+    Singular(Option<ReflectValueBox>),
+    Repeated(Vec<ReflectValueBox>),
+    Map(HashMap<DynamicMapKey, ReflectValueBox>),
+}
+
+impl DynamicFieldValue {
+    fn new(shape: &DynamicFieldShape) -> DynamicFieldValue {
+        match *shape {
+            DynamicFieldShape::Singular(..) => DynamicFieldValue::Singular(None),
+            DynamicFieldShape::Repeated(..) => DynamicFieldValue::Repeated(Vec::new()),
+            DynamicFieldShape::Map(..) => DynamicFieldValue::Map(HashMap::new()),
+        }
+    }
+}
+
+
+/// Message of a type known only at runtime.
+///
+/// Field values are stored as `ReflectValueBox` and accessed with reflection:
+/// `DynamicMessage` implements `Message`, so it can be parsed, serialized,
+/// printed with `text_format` and modified with `FieldDescriptor` operations.
+pub struct DynamicMessage {
+    message_type: &'static DynamicMessageType,
+    fields: Vec<DynamicFieldValue>,
+    unknown_fields: UnknownFields,
+    cached_size: CachedSize,
+}
+
+impl DynamicMessage {
+    /// Create an empty message of given type.
+    ///
+    /// Panics if descriptor is not created by `DescriptorPool`.
+    pub fn with_descriptor(descriptor: &'static MessageDescriptor) -> DynamicMessage {
+        match descriptor.new_instance().into_any().downcast::<DynamicMessage>() {
+            Ok(m) => *m,
+            Err(_) => panic!("not a dynamic message descriptor: {}", descriptor.full_name()),
+        }
+    }
+
+    fn new_for_type(message_type: &'static DynamicMessageType) -> DynamicMessage {
+        DynamicMessage {
+            message_type: message_type,
+            fields: message_type
+                .fields
+                .iter()
+                .map(|f| DynamicFieldValue::new(&f.shape))
+                .collect(),
+            unknown_fields: UnknownFields::new(),
+            cached_size: CachedSize::default(),
+        }
+    }
+
+    /// Unset other fields of the oneof given field belongs to
+    fn clear_oneof_except(&mut self, index: usize) {
+        let proto = self.message_type.fields[index].proto;
+        if !proto.has_oneof_index() {
+            return;
+        }
+        for (i, f) in self.message_type.fields.iter().enumerate() {
+            if i != index && f.proto.has_oneof_index() &&
+                f.proto.get_oneof_index() == proto.get_oneof_index()
+            {
+                self.fields[i] = DynamicFieldValue::new(&f.shape);
+            }
+        }
+    }
+
+    fn merge_fields(&mut self, is: &mut CodedInputStream) -> ProtobufResult<()> {
+        while !is.eof()? {
+            let (number, wire_type) = is.read_tag_unpack()?;
+            match self.message_type.index_by_number.get(&number) {
+                Some(&index) => self.merge_field(index, wire_type, is)?,
+                None => {
+                    rt::read_unknown_or_skip_group(
+                        number,
+                        wire_type,
+                        is,
+                        &mut self.unknown_fields,
+                    )?
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn merge_field(
+        &mut self,
+        index: usize,
+        wire_type: wire_format::WireType,
+        is: &mut CodedInputStream,
+    ) -> ProtobufResult<()> {
+        self.clear_oneof_except(index);
+        let field = &self.message_type.fields[index];
+        let number = field.proto.get_number() as u32;
+        match (field.shape, &mut self.fields[index]) {
+            (DynamicFieldShape::Singular(t), &mut DynamicFieldValue::Singular(ref mut value)) => {
+                if wire_type != t.wire_type() {
+                    return Err(rt::unexpected_wire_type(wire_type));
+                }
+                if let Some(ReflectValueBox::Message(ref mut m)) = *value {
+                    // repeated occurrences of a message field are merged
+                    return merge_message(&t, number, &mut **m, is);
+                }
+                match read_value(&t, number, is)? {
+                    WireValue::Known(v) => *value = Some(v),
+                    WireValue::UnknownEnum(v) => {
+                        self.unknown_fields.add_varint(number, v as i64 as u64)
+                    }
+                }
+            }
+            (DynamicFieldShape::Repeated(t), &mut DynamicFieldValue::Repeated(ref mut values)) => {
+                if wire_type == wire_format::WireTypeLengthDelimited && t.is_packable() {
+                    let len = is.read_raw_varint64()?;
+                    is.check_length(len, true)?;
+                    let old_limit = is.push_limit(len)?;
+                    while !is.eof()? {
+                        is.check_repeated_len(values.len())?;
+                        match read_value(&t, number, is)? {
+                            WireValue::Known(v) => values.push(v),
+                            WireValue::UnknownEnum(v) => {
+                                self.unknown_fields.add_varint(number, v as i64 as u64)
+                            }
+                        }
+                    }
+                    is.pop_limit(old_limit);
+                } else if wire_type == t.wire_type() {
+                    is.check_repeated_len(values.len())?;
+                    match read_value(&t, number, is)? {
+                        WireValue::Known(v) => values.push(v),
+                        WireValue::UnknownEnum(v) => {
+                            self.unknown_fields.add_varint(number, v as i64 as u64)
+                        }
+                    }
+                } else {
+                    return Err(rt::unexpected_wire_type(wire_type));
+                }
+            }
+            (DynamicFieldShape::Map(kt, vt), &mut DynamicFieldValue::Map(ref mut map)) => {
+                if wire_type != wire_format::WireTypeLengthDelimited {
+                    return Err(rt::unexpected_wire_type(wire_type));
+                }
+                let len = is.read_raw_varint64()?;
+                is.check_length(len, false)?;
+                let old_limit = is.push_limit(len)?;
+                let mut key = None;
+                let mut value = None;
+                while !is.eof()? {
+                    let (entry_number, entry_wire_type) = is.read_inner_tag_unpack()?;
+                    match entry_number {
+                        1 if entry_wire_type == kt.wire_type() => {
+                            key = Some(read_value(&kt, 1, is)?);
+                        }
+                        2 if entry_wire_type == vt.wire_type() => {
+                            value = Some(read_value(&vt, 2, is)?);
+                        }
+                        _ => is.skip_field(entry_wire_type)?,
+                    }
+                }
+                is.pop_limit(old_limit);
+
+                let key = match key {
+                    Some(WireValue::Known(k)) => k,
+                    _ => kt.default_value(),
+                };
+                let value = match value {
+                    Some(WireValue::Known(v)) => v,
+                    Some(WireValue::UnknownEnum(v)) => {
+                        // entry with unknown enum value is preserved as unknown field
+                        let mut entry = Vec::new();
+                        {
+                            let mut os = CodedOutputStream::vec(&mut entry);
+                            write_value(&mut os, &kt, 1, key.as_value_ref())?;
+                            os.write_enum(2, v)?;
+                            os.flush()?;
+                        }
+                        self.unknown_fields.add_length_delimited(number, entry);
+                        return Ok(());
+                    }
+                    None => vt.default_value(),
+                };
+                let key = DynamicMapKey::from_box(key);
+                if !map.contains_key(&key) {
+                    is.check_repeated_len(map.len())?;
+                }
+                map.insert(key, value);
+            }
+            _ => unreachable!(),
+        }
+        Ok(())
+    }
+}
+
+/// Value read from the wire
+enum WireValue {
+    Known(ReflectValueBox),
+    /// Enum value not defined in enum descriptor
+    UnknownEnum(i32),
+}
+
+fn read_value(
+    t: &DynamicType,
+    number: u32,
+    is: &mut CodedInputStream,
+) -> ProtobufResult<WireValue> {
+    let value = match t.proto_type {
+        FieldDescriptorProto_Type::TYPE_DOUBLE => ReflectValueBox::F64(is.read_double()?),
+        FieldDescriptorProto_Type::TYPE_FLOAT => ReflectValueBox::F32(is.read_float()?),
+        FieldDescriptorProto_Type::TYPE_INT64 => ReflectValueBox::I64(is.read_int64()?),
+        FieldDescriptorProto_Type::TYPE_UINT64 => ReflectValueBox::U64(is.read_uint64()?),
+        FieldDescriptorProto_Type::TYPE_INT32 => ReflectValueBox::I32(is.read_int32()?),
+        FieldDescriptorProto_Type::TYPE_FIXED64 => ReflectValueBox::U64(is.read_fixed64()?),
+        FieldDescriptorProto_Type::TYPE_FIXED32 => ReflectValueBox::U32(is.read_fixed32()?),
+        FieldDescriptorProto_Type::TYPE_BOOL => ReflectValueBox::Bool(is.read_bool()?),
+        FieldDescriptorProto_Type::TYPE_STRING => ReflectValueBox::String(is.read_string()?),
+        FieldDescriptorProto_Type::TYPE_BYTES => ReflectValueBox::Bytes(is.read_bytes()?),
+        FieldDescriptorProto_Type::TYPE_UINT32 => ReflectValueBox::U32(is.read_uint32()?),
+        FieldDescriptorProto_Type::TYPE_SFIXED32 => ReflectValueBox::I32(is.read_sfixed32()?),
+        FieldDescriptorProto_Type::TYPE_SFIXED64 => ReflectValueBox::I64(is.read_sfixed64()?),
+        FieldDescriptorProto_Type::TYPE_SINT32 => ReflectValueBox::I32(is.read_sint32()?),
+        FieldDescriptorProto_Type::TYPE_SINT64 => ReflectValueBox::I64(is.read_sint64()?),
+        FieldDescriptorProto_Type::TYPE_ENUM => {
+            let v = is.read_int32()?;
+            let e = t.enum_descriptor.unwrap();
+            match e.index_by_number.get(&v) {
+                Some(&index) => ReflectValueBox::Enum(&e.values[index]),
+                None => return Ok(WireValue::UnknownEnum(v)),
+            }
+        }
+        FieldDescriptorProto_Type::TYPE_MESSAGE |
+        FieldDescriptorProto_Type::TYPE_GROUP => {
+            let mut m = t.message_type().new_instance();
+            merge_message(t, number, &mut *m, is)?;
+            ReflectValueBox::Message(m)
+        }
+    };
+    Ok(WireValue::Known(value))
+}
+
+fn merge_message(
+    t: &DynamicType,
+    number: u32,
+    m: &mut Message,
+    is: &mut CodedInputStream,
+) -> ProtobufResult<()> {
+    is.incr_recursion()?;
+    let res = match t.proto_type {
+        FieldDescriptorProto_Type::TYPE_GROUP => is.merge_group(m, number),
+        _ => is.merge_message(m),
+    };
+    is.decr_recursion();
+    res
+}
+
+/// Size of value without tag, sizes of nested messages must be cached
+fn value_size_no_tag(t: &DynamicType, value: ProtobufValueRef) -> u32 {
+    match (t.proto_type, value) {
+        (FieldDescriptorProto_Type::TYPE_SINT32, ProtobufValueRef::I32(v)) => v.len_varint_zigzag(),
+        (FieldDescriptorProto_Type::TYPE_SINT64, ProtobufValueRef::I64(v)) => v.len_varint_zigzag(),
+        (FieldDescriptorProto_Type::TYPE_SFIXED32, _) |
+        (FieldDescriptorProto_Type::TYPE_FIXED32, _) |
+        (_, ProtobufValueRef::F32(..)) => 4,
+        (FieldDescriptorProto_Type::TYPE_SFIXED64, _) |
+        (FieldDescriptorProto_Type::TYPE_FIXED64, _) |
+        (_, ProtobufValueRef::F64(..)) => 8,
+        (_, ProtobufValueRef::I32(v)) => v.len_varint(),
+        (_, ProtobufValueRef::I64(v)) => v.len_varint(),
+        (_, ProtobufValueRef::U32(v)) => v.len_varint(),
+        (_, ProtobufValueRef::U64(v)) => v.len_varint(),
+        (_, ProtobufValueRef::Bool(..)) => 1,
+        (_, ProtobufValueRef::Enum(v)) => v.value().len_varint(),
+        (_, ProtobufValueRef::UnknownEnum(_, v)) => v.len_varint(),
+        (_, ProtobufValueRef::String(v)) => {
+            rt::compute_raw_varint64_size(v.len() as u64) + v.len() as u32
+        }
+        (_, ProtobufValueRef::Bytes(v)) => {
+            rt::compute_raw_varint64_size(v.len() as u64) + v.len() as u32
+        }
+        (FieldDescriptorProto_Type::TYPE_GROUP, ProtobufValueRef::Message(m)) => {
+            m.get_cached_size()
+        }
+        (_, ProtobufValueRef::Message(m)) => {
+            let size = m.get_cached_size();
+            rt::compute_raw_varint32_size(size) + size
+        }
+        // dynamic message values are stored in the message
+        (_, ProtobufValueRef::OwnedMessage(..)) => unreachable!(),
+    }
+}
+
+/// Size of value with tag, sizes of nested messages must be cached
+fn value_size(t: &DynamicType, number: u32, value: ProtobufValueRef) -> u32 {
+    let tags = match t.proto_type {
+        FieldDescriptorProto_Type::TYPE_GROUP => 2,
+        _ => 1,
+    };
+    tags * rt::tag_size(number) + value_size_no_tag(t, value)
+}
+
+/// Size of value with tag, sizes of nested messages are computed and cached
+fn compute_value_size(t: &DynamicType, number: u32, value: ProtobufValueRef) -> u32 {
+    if let ProtobufValueRef::Message(m) = value {
+        m.compute_size();
+    }
+    value_size(t, number, value)
+}
+
+fn write_value_no_tag(
+    os: &mut CodedOutputStream,
+    t: &DynamicType,
+    value: ProtobufValueRef,
+) -> ProtobufResult<()> {
+    match (t.proto_type, value) {
+        (FieldDescriptorProto_Type::TYPE_SINT32, ProtobufValueRef::I32(v)) => {
+            os.write_sint32_no_tag(v)
+        }
+        (FieldDescriptorProto_Type::TYPE_SFIXED32, ProtobufValueRef::I32(v)) => {
+            os.write_sfixed32_no_tag(v)
+        }
+        (_, ProtobufValueRef::I32(v)) => os.write_int32_no_tag(v),
+        (FieldDescriptorProto_Type::TYPE_SINT64, ProtobufValueRef::I64(v)) => {
+            os.write_sint64_no_tag(v)
+        }
+        (FieldDescriptorProto_Type::TYPE_SFIXED64, ProtobufValueRef::I64(v)) => {
+            os.write_sfixed64_no_tag(v)
+        }
+        (_, ProtobufValueRef::I64(v)) => os.write_int64_no_tag(v),
+        (FieldDescriptorProto_Type::TYPE_FIXED32, ProtobufValueRef::U32(v)) => {
+            os.write_fixed32_no_tag(v)
+        }
+        (_, ProtobufValueRef::U32(v)) => os.write_uint32_no_tag(v),
+        (FieldDescriptorProto_Type::TYPE_FIXED64, ProtobufValueRef::U64(v)) => {
+            os.write_fixed64_no_tag(v)
+        }
+        (_, ProtobufValueRef::U64(v)) => os.write_uint64_no_tag(v),
+        (_, ProtobufValueRef::F32(v)) => os.write_float_no_tag(v),
+        (_, ProtobufValueRef::F64(v)) => os.write_double_no_tag(v),
+        (_, ProtobufValueRef::Bool(v)) => os.write_bool_no_tag(v),
+        (_, ProtobufValueRef::Enum(v)) => os.write_enum_no_tag(v.value()),
+        (_, ProtobufValueRef::UnknownEnum(_, v)) => os.write_enum_no_tag(v),
+        (_, ProtobufValueRef::String(v)) => os.write_string_no_tag(v),
+        (_, ProtobufValueRef::Bytes(v)) => os.write_bytes_no_tag(v),
+        (FieldDescriptorProto_Type::TYPE_GROUP, ProtobufValueRef::Message(m)) => {
+            m.write_to_with_cached_sizes(os)
+        }
+        (_, ProtobufValueRef::Message(m)) => {
+            os.write_raw_varint32(m.get_cached_size())?;
+            m.write_to_with_cached_sizes(os)
+        }
+        // dynamic message values are stored in the message
+        (_, ProtobufValueRef::OwnedMessage(..)) => unreachable!(),
+    }
+}
+
+fn write_value(
+    os: &mut CodedOutputStream,
+    t: &DynamicType,
+    number: u32,
+    value: ProtobufValueRef,
+) -> ProtobufResult<()> {
+    os.write_tag(number, t.wire_type())?;
+    write_value_no_tag(os, t, value)?;
+    if t.proto_type == FieldDescriptorProto_Type::TYPE_GROUP {
+        os.write_tag(number, wire_format::WireTypeEndGroup)?;
+    }
+    Ok(())
+}
+
+impl Message for DynamicMessage {
+    fn descriptor(&self) -> &'static MessageDescriptor {
+        self.message_type.descriptor.get().expect("descriptor is not initialized")
+    }
+
+    fn is_initialized(&self) -> bool {
+        for (field, value) in self.message_type.fields.iter().zip(&self.fields) {
+            match *value {
+                DynamicFieldValue::Singular(None) => {
+                    if field.proto.get_label() == FieldDescriptorProto_Label::LABEL_REQUIRED {
+                        return false;
+                    }
+                }
+                DynamicFieldValue::Singular(Some(ReflectValueBox::Message(ref m))) => {
+                    if !m.is_initialized() {
+                        return false;
+                    }
+                }
+                DynamicFieldValue::Singular(Some(..)) => {}
+                DynamicFieldValue::Repeated(ref values) => {
+                    for v in values {
+                        if let ReflectValueBox::Message(ref m) = *v {
+                            if !m.is_initialized() {
+                                return false;
+                            }
+                        }
+                    }
+                }
+                DynamicFieldValue::Map(ref map) => {
+                    for v in map.values() {
+                        if let ReflectValueBox::Message(ref m) = *v {
+                            if !m.is_initialized() {
+                                return false;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut CodedInputStream) -> ProtobufResult<()> {
+        self.merge_fields(is)
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut CodedOutputStream) -> ProtobufResult<()> {
+        for (field, value) in self.message_type.fields.iter().zip(&self.fields) {
+            let number = field.proto.get_number() as u32;
+            match (field.shape, value) {
+                (DynamicFieldShape::Singular(t), &DynamicFieldValue::Singular(Some(ref v))) => {
+                    let v = v.as_value_ref();
+                    if !field.no_presence || v.is_non_zero() {
+                        write_value(os, &t, number, v)?;
+                    }
+                }
+                (DynamicFieldShape::Repeated(t), &DynamicFieldValue::Repeated(ref values)) => {
+                    if field.packed && t.is_packable() {
+                        if values.is_empty() {
+                            continue;
+                        }
+                        let data_size = values
+                            .iter()
+                            .map(|v| value_size_no_tag(&t, v.as_value_ref()))
+                            .sum();
+                        os.write_tag(number, wire_format::WireTypeLengthDelimited)?;
+                        os.write_raw_varint32(data_size)?;
+                        for v in values {
+                            write_value_no_tag(os, &t, v.as_value_ref())?;
+                        }
+                    } else {
+                        for v in values {
+                            write_value(os, &t, number, v.as_value_ref())?;
+                        }
+                    }
+                }
+                (DynamicFieldShape::Map(kt, vt), &DynamicFieldValue::Map(ref map)) => {
+                    for (k, v) in map {
+                        let entry_size = value_size(&kt, 1, k.as_ref()) +
+                            value_size(&vt, 2, v.as_value_ref());
+                        os.write_tag(number, wire_format::WireTypeLengthDelimited)?;
+                        os.write_raw_varint32(entry_size)?;
+                        write_value(os, &kt, 1, k.as_ref())?;
+                        write_value(os, &vt, 2, v.as_value_ref())?;
+                    }
+                }
+                _ => {}
+            }
+        }
+        os.write_unknown_fields(&self.unknown_fields)?;
+        Ok(())
+    }
+
+    fn compute_size(&self) -> u32 {
+        let mut size = 0;
+        for (field, value) in self.message_type.fields.iter().zip(&self.fields) {
+            let number = field.proto.get_number() as u32;
+            match (field.shape, value) {
+                (DynamicFieldShape::Singular(t), &DynamicFieldValue::Singular(Some(ref v))) => {
+                    let v = v.as_value_ref();
+                    if !field.no_presence || v.is_non_zero() {
+                        size += compute_value_size(&t, number, v);
+                    }
+                }
+                (DynamicFieldShape::Repeated(t), &DynamicFieldValue::Repeated(ref values)) => {
+                    if field.packed && t.is_packable() {
+                        if values.is_empty() {
+                            continue;
+                        }
+                        let data_size: u32 = values
+                            .iter()
+                            .map(|v| value_size_no_tag(&t, v.as_value_ref()))
+                            .sum();
+                        size += rt::tag_size(number) + rt::compute_raw_varint32_size(data_size) +
+                            data_size;
+                    } else {
+                        for v in values {
+                            size += compute_value_size(&t, number, v.as_value_ref());
+                        }
+                    }
+                }
+                (DynamicFieldShape::Map(kt, vt), &DynamicFieldValue::Map(ref map)) => {
+                    for (k, v) in map {
+                        let entry_size = compute_value_size(&kt, 1, k.as_ref()) +
+                            compute_value_size(&vt, 2, v.as_value_ref());
+                        size += rt::tag_size(number) + rt::compute_raw_varint32_size(entry_size) +
+                            entry_size;
+                    }
+                }
+                _ => {}
+            }
+        }
+        size += rt::unknown_fields_size(&self.unknown_fields);
+        self.cached_size.set(size);
+        size
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &Any {
+        self as &Any
+    }
+
+    fn as_any_mut(&mut self) -> &mut Any {
+        self as &mut Any
+    }
+
+    fn into_any(self: Box<Self>) -> Box<Any> {
+        self
+    }
+
+    fn new() -> DynamicMessage {
+        panic!("dynamic message cannot be created without descriptor");
+    }
+
+    fn default_instance() -> &'static DynamicMessage {
+        panic!("dynamic message has no default instance");
+    }
+}
+
+impl Clear for DynamicMessage {
+    fn clear(&mut self) {
+        for (field, value) in self.message_type.fields.iter().zip(&mut self.fields) {
+            *value = DynamicFieldValue::new(&field.shape);
+        }
+        self.unknown_fields.clear();
+    }
+}
+
+impl fmt::Debug for DynamicMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        text_format::fmt(self, f)
+    }
+}
+
+
+struct DynamicMessageFactory {
+    message_type: &'static DynamicMessageType,
+}
+
+impl MessageFactory for DynamicMessageFactory {
+    fn new_instance(&self) -> Box<Message> {
+        Box::new(DynamicMessage::new_for_type(self.message_type))
+    }
+}
+
+
+fn dynamic_message(m: &Message) -> &DynamicMessage {
+    m.as_any().downcast_ref::<DynamicMessage>().expect("not a dynamic message")
+}
+
+fn dynamic_message_mut(m: &mut Message) -> &mut DynamicMessage {
+    m.as_any_mut().downcast_mut::<DynamicMessage>().expect("not a dynamic message")
+}
+
+/// Accessor of `DynamicMessage` field by index
+struct DynamicFieldAccessor {
+    message_type: &'static DynamicMessageType,
+    index: usize,
+}
+
+impl DynamicFieldAccessor {
+    fn field(&self) -> &'static DynamicField {
+        &self.message_type.fields[self.index]
+    }
+
+    fn get_value<'a>(&self, m: &'a Message) -> &'a DynamicFieldValue {
+        let m = dynamic_message(m);
+        assert!(ptr::eq(m.message_type, self.message_type), "wrong message type");
+        &m.fields[self.index]
+    }
+
+    fn mut_value<'a>(&self, m: &'a mut Message) -> &'a mut DynamicFieldValue {
+        let m = dynamic_message_mut(m);
+        assert!(ptr::eq(m.message_type, self.message_type), "wrong message type");
+        &mut m.fields[self.index]
+    }
+
+    /// Like `mut_value`, but other fields of the oneof are cleared
+    fn mut_value_to_set<'a>(&self, m: &'a mut Message) -> &'a mut DynamicFieldValue {
+        let m = dynamic_message_mut(m);
+        assert!(ptr::eq(m.message_type, self.message_type), "wrong message type");
+        m.clear_oneof_except(self.index);
+        &mut m.fields[self.index]
+    }
+
+    fn get_value_option<'a>(&self, m: &'a Message) -> Option<ProtobufValueRef<'a>> {
+        match *self.get_value(m) {
+            DynamicFieldValue::Singular(Some(ref v)) => {
+                let v = v.as_value_ref();
+                if self.field().no_presence && !v.is_non_zero() {
+                    None
+                } else {
+                    Some(v)
+                }
+            }
+            DynamicFieldValue::Singular(None) => None,
+            _ => panic!("repeated"),
+        }
+    }
+}
+
+impl FieldAccessor for DynamicFieldAccessor {
+    fn name_generic(&self) -> &'static str {
+        self.field().proto.get_name()
+    }
+
+    fn has_field_generic(&self, m: &Message) -> bool {
+        match *self.get_value(m) {
+            DynamicFieldValue::Singular(..) => self.get_value_option(m).is_some(),
+            _ => panic!("has_xxx is not implemented for repeated"),
+        }
+    }
+
+    fn len_field_generic(&self, m: &Message) -> usize {
+        match *self.get_value(m) {
+            DynamicFieldValue::Repeated(ref values) => values.len(),
+            DynamicFieldValue::Map(ref map) => map.len(),
+            DynamicFieldValue::Singular(..) => panic!("not a repeated field"),
+        }
+    }
+
+    fn get_message_generic<'a>(&self, m: &'a Message) -> &'a Message {
+        match self.get_value_option(m) {
+            Some(ProtobufValueRef::Message(m)) => m,
+            Some(_) => panic!("not a message"),
+            None => panic!("field unset"),
+        }
+    }
+
+    fn get_enum_generic(&self, m: &Message) -> Result<&'static EnumValueDescriptor, i32> {
+        match self.get_value_option(m) {
+            Some(ProtobufValueRef::Enum(v)) => Ok(v),
+            Some(ProtobufValueRef::UnknownEnum(_, v)) => Err(v),
+            Some(_) => panic!("wrong type"),
+            None => panic!("field unset"),
+        }
+    }
+
+    fn get_str_generic<'a>(&self, m: &'a Message) -> &'a str {
+        match self.get_value_option(m) {
+            Some(ProtobufValueRef::String(v)) => v,
+            Some(_) => panic!("wrong type"),
+            None => "",
+        }
+    }
+
+    fn get_bytes_generic<'a>(&self, m: &'a Message) -> &'a [u8] {
+        match self.get_value_option(m) {
+            Some(ProtobufValueRef::Bytes(v)) => v,
+            Some(_) => panic!("wrong type"),
+            None => b"",
+        }
+    }
+
+    fn get_u32_generic(&self, m: &Message) -> u32 {
+        match self.get_value_option(m) {
+            Some(ProtobufValueRef::U32(v)) => v,
+            Some(_) => panic!("wrong type"),
+            None => 0,
+        }
+    }
+
+    fn get_u64_generic(&self, m: &Message) -> u64 {
+        match self.get_value_option(m) {
+            Some(ProtobufValueRef::U64(v)) => v,
+            Some(_) => panic!("wrong type"),
+            None => 0,
+        }
+    }
+
+    fn get_i32_generic(&self, m: &Message) -> i32 {
+        match self.get_value_option(m) {
+            Some(ProtobufValueRef::I32(v)) => v,
+            Some(_) => panic!("wrong type"),
+            None => 0,
+        }
+    }
+
+    fn get_i64_generic(&self, m: &Message) -> i64 {
+        match self.get_value_option(m) {
+            Some(ProtobufValueRef::I64(v)) => v,
+            Some(_) => panic!("wrong type"),
+            None => 0,
+        }
+    }
+
+    fn get_bool_generic(&self, m: &Message) -> bool {
+        match self.get_value_option(m) {
+            Some(ProtobufValueRef::Bool(v)) => v,
+            Some(_) => panic!("wrong type"),
+            None => false,
+        }
+    }
+
+    fn get_f32_generic(&self, m: &Message) -> f32 {
+        match self.get_value_option(m) {
+            Some(ProtobufValueRef::F32(v)) => v,
+            Some(_) => panic!("wrong type"),
+            None => 0.0,
+        }
+    }
+
+    fn get_f64_generic(&self, m: &Message) -> f64 {
+        match self.get_value_option(m) {
+            Some(ProtobufValueRef::F64(v)) => v,
+            Some(_) => panic!("wrong type"),
+            None => 0.0,
+        }
+    }
+
+    fn get_reflect<'a>(&self, m: &'a Message) -> ReflectFieldRef<'a> {
+        match *self.get_value(m) {
+            DynamicFieldValue::Singular(..) => ReflectFieldRef::Optional(self.get_value_option(m)),
+            DynamicFieldValue::Repeated(ref values) => ReflectFieldRef::Repeated(values),
+            DynamicFieldValue::Map(ref map) => ReflectFieldRef::Map(map),
+        }
+    }
+
+    fn runtime_field_type(&self) -> RuntimeFieldType {
+        self.field().runtime_field_type()
+    }
+
+    fn has_presence_generic(&self) -> bool {
+        match self.field().shape {
+            DynamicFieldShape::Singular(..) => !self.field().no_presence,
+            _ => false,
+        }
+    }
+
+    fn set_singular_field_generic(&self, m: &mut Message, value: ReflectValueBox) {
+        match (self.field().shape, self.mut_value_to_set(m)) {
+            (DynamicFieldShape::Singular(t), &mut DynamicFieldValue::Singular(ref mut v)) => {
+                t.check_value(&value);
+                *v = Some(value);
+            }
+            _ => panic!("not a singular field"),
+        }
+    }
+
+    fn clear_field_generic(&self, m: &mut Message) {
+        *self.mut_value(m) = DynamicFieldValue::new(&self.field().shape);
+    }
+
+    fn mut_message_generic<'a>(&self, m: &'a mut Message) -> &'a mut Message {
+        match (self.field().shape, self.mut_value_to_set(m)) {
+            (DynamicFieldShape::Singular(t), &mut DynamicFieldValue::Singular(ref mut v))
+                if t.is_message() =>
+            {
+                if v.is_none() {
+                    *v = Some(t.default_value());
+                }
+                match *v {
+                    Some(ReflectValueBox::Message(ref mut m)) => &mut **m,
+                    _ => unreachable!(),
+                }
+            }
+            _ => panic!("not a message field"),
+        }
+    }
+
+    fn push_generic(&self, m: &mut Message, value: ReflectValueBox) {
+        match (self.field().shape, self.mut_value(m)) {
+            (DynamicFieldShape::Repeated(t), &mut DynamicFieldValue::Repeated(ref mut values)) => {
+                t.check_value(&value);
+                values.push(value);
+            }
+            _ => panic!("not a repeated field"),
+        }
+    }
+
+    fn insert_generic(&self, m: &mut Message, key: ReflectValueBox, value: ReflectValueBox) {
+        match (self.field().shape, self.mut_value(m)) {
+            (DynamicFieldShape::Map(kt, vt), &mut DynamicFieldValue::Map(ref mut map)) => {
+                kt.check_value(&key);
+                vt.check_value(&value);
+                map.insert(DynamicMapKey::from_box(key), value);
+            }
+            _ => panic!("not a map field"),
+        }
+    }
+
+    fn remove_generic(&self, m: &mut Message, key: ReflectValueBox) -> bool {
+        match (self.field().shape, self.mut_value(m)) {
+            (DynamicFieldShape::Map(kt, _), &mut DynamicFieldValue::Map(ref mut map)) => {
+                kt.check_value(&key);
+                map.remove(&DynamicMapKey::from_box(key)).is_some()
+            }
+            _ => panic!("not a map field"),
+        }
+    }
+}
+
+
+/// Decode values of type `t` from a value of unknown field: a single value or packed values.
+///
+/// Values of wrong wire type and unknown enum values are skipped.
+fn decode_unknown(
+    t: &DynamicType,
+    number: u32,
+    value: UnknownValueRef,
+    values: &mut Vec<ReflectValueBox>,
+) -> ProtobufResult<()> {
+    let packed = value.wire_type() == wire_format::WireTypeLengthDelimited && t.is_packable();
+    if value.wire_type() != t.wire_type() && !packed {
+        return Ok(());
+    }
+
+    let mut bytes = Vec::new();
+    {
+        let mut os = CodedOutputStream::vec(&mut bytes);
+        match value {
+            UnknownValueRef::Fixed32(v) => os.write_raw_little_endian32(v)?,
+            UnknownValueRef::Fixed64(v) => os.write_raw_little_endian64(v)?,
+            UnknownValueRef::Varint(v) => os.write_raw_varint64(v)?,
+            UnknownValueRef::LengthDelimited(b) if packed => os.write_raw_bytes(b)?,
+            UnknownValueRef::LengthDelimited(b) => {
+                os.write_raw_varint32(b.len() as u32)?;
+                os.write_raw_bytes(b)?;
+            }
+        }
+        os.flush()?;
+    }
+
+    let mut is = CodedInputStream::from_bytes(&bytes);
+    while !is.eof()? {
+        if let WireValue::Known(v) = read_value(t, number, &mut is)? {
+            values.push(v);
+        }
+    }
+    Ok(())
+}
+
+
+/// Extension field.
+///
+/// Extension values are stored in unknown fields of the extended message,
+/// so extensions of generated and dynamic messages are accessed the same way.
+struct DynamicExtensionAccessor {
+    field: DynamicField,
+}
+
+impl DynamicExtensionAccessor {
+    fn number(&self) -> u32 {
+        self.field.proto.get_number() as u32
+    }
+
+    fn value_type(&self) -> DynamicType {
+        match self.field.shape {
+            DynamicFieldShape::Singular(t) | DynamicFieldShape::Repeated(t) => t,
+            DynamicFieldShape::Map(..) => unreachable!(),
+        }
+    }
+
+    fn is_singular(&self) -> bool {
+        match self.field.shape {
+            DynamicFieldShape::Singular(..) => true,
+            _ => false,
+        }
+    }
+
+    /// Decode values, last value of singular field wins,
+    /// and occurrences of singular message are merged.
+    ///
+    /// If any value cannot be decoded, the field is treated as not set,
+    /// like generated optional extensions are.
+    fn get_values(&self, m: &Message) -> Vec<ReflectValueBox> {
+        self.decode_values(m).unwrap_or_else(|_| Vec::new())
+    }
+
+    fn decode_values(&self, m: &Message) -> ProtobufResult<Vec<ReflectValueBox>> {
+        let number = self.number();
+        let t = self.value_type();
+        let unknown = match m.get_unknown_fields().get(number) {
+            Some(unknown) => unknown,
+            None => return Ok(Vec::new()),
+        };
+
+        let mut values = Vec::new();
+        if self.is_singular() && t.is_message() {
+            if !unknown.length_delimited.is_empty() {
+                let bytes = unknown.length_delimited.concat();
+                let value = UnknownValueRef::LengthDelimited(&bytes);
+                decode_unknown(&t, number, value, &mut values)?;
+            }
+            return Ok(values);
+        }
+
+        for value in unknown {
+            decode_unknown(&t, number, value, &mut values)?;
+        }
+        if self.is_singular() && values.len() > 1 {
+            values.drain(..values.len() - 1);
+        }
+        Ok(values)
+    }
+
+    /// Value of singular field or default value
+    fn get_singular(&self, m: &Message) -> ReflectValueBox {
+        assert!(self.is_singular(), "not a singular field");
+        match self.get_values(m).pop() {
+            Some(v) => v,
+            None => self.value_type().default_value(),
+        }
+    }
+
+    /// Append values to unknown fields of the message, values are written unpacked
+    fn add_values(&self, m: &mut Message, values: &[ReflectValueBox]) -> ProtobufResult<()> {
+        let number = self.number();
+        let t = self.value_type();
+        let mut bytes = Vec::new();
+        {
+            let mut os = CodedOutputStream::vec(&mut bytes);
+            for v in values {
+                t.check_value(v);
+                compute_value_size(&t, number, v.as_value_ref());
+                write_value(&mut os, &t, number, v.as_value_ref())?;
+            }
+            os.flush()?;
+        }
+
+        let mut is = CodedInputStream::from_bytes(&bytes);
+        while !is.eof()? {
+            let (number, wire_type) = is.read_tag_unpack()?;
+            rt::read_unknown_or_skip_group(number, wire_type, &mut is, m.mut_unknown_fields())?;
+        }
+        Ok(())
+    }
+}
+
+impl FieldAccessor for DynamicExtensionAccessor {
+    fn name_generic(&self) -> &'static str {
+        self.field.proto.get_name()
+    }
+
+    fn has_field_generic(&self, m: &Message) -> bool {
+        !self.get_values(m).is_empty()
+    }
+
+    fn len_field_generic(&self, m: &Message) -> usize {
+        self.get_values(m).len()
+    }
+
+    fn get_message_generic<'a>(&self, _m: &'a Message) -> &'a Message {
+        panic!("extension value cannot be borrowed, use `get_extension`")
+    }
+
+    fn get_enum_generic(&self, m: &Message) -> Result<&'static EnumValueDescriptor, i32> {
+        match self.get_singular(m) {
+            ReflectValueBox::Enum(v) => Ok(v),
+            ReflectValueBox::UnknownEnum(_, v) => Err(v),
+            _ => panic!("wrong type"),
+        }
+    }
+
+    fn get_str_generic<'a>(&self, _m: &'a Message) -> &'a str {
+        panic!("extension value cannot be borrowed, use `get_extension`")
+    }
+
+    fn get_bytes_generic<'a>(&self, _m: &'a Message) -> &'a [u8] {
+        panic!("extension value cannot be borrowed, use `get_extension`")
+    }
+
+    fn get_u32_generic(&self, m: &Message) -> u32 {
+        match self.get_singular(m) {
+            ReflectValueBox::U32(v) => v,
+            _ => panic!("wrong type"),
+        }
+    }
+
+    fn get_u64_generic(&self, m: &Message) -> u64 {
+        match self.get_singular(m) {
+            ReflectValueBox::U64(v) => v,
+            _ => panic!("wrong type"),
+        }
+    }
+
+    fn get_i32_generic(&self, m: &Message) -> i32 {
+        match self.get_singular(m) {
+            ReflectValueBox::I32(v) => v,
+            _ => panic!("wrong type"),
+        }
+    }
+
+    fn get_i64_generic(&self, m: &Message) -> i64 {
+        match self.get_singular(m) {
+            ReflectValueBox::I64(v) => v,
+            _ => panic!("wrong type"),
+        }
+    }
+
+    fn get_bool_generic(&self, m: &Message) -> bool {
+        match self.get_singular(m) {
+            ReflectValueBox::Bool(v) => v,
+            _ => panic!("wrong type"),
+        }
+    }
+
+    fn get_f32_generic(&self, m: &Message) -> f32 {
+        match self.get_singular(m) {
+            ReflectValueBox::F32(v) => v,
+            _ => panic!("wrong type"),
+        }
+    }
+
+    fn get_f64_generic(&self, m: &Message) -> f64 {
+        match self.get_singular(m) {
+            ReflectValueBox::F64(v) => v,
+            _ => panic!("wrong type"),
+        }
+    }
+
+    fn get_reflect<'a>(&self, _m: &'a Message) -> ReflectFieldRef<'a> {
+        panic!("extension value cannot be borrowed, use `get_extension`")
+    }
+
+    fn runtime_field_type(&self) -> RuntimeFieldType {
+        self.field.runtime_field_type()
+    }
+
+    fn set_singular_field_generic(&self, m: &mut Message, value: ReflectValueBox) {
+        assert!(self.is_singular(), "not a singular field");
+        self.clear_field_generic(m);
+        self.add_values(m, &[value]).expect("write extension");
+    }
+
+    fn clear_field_generic(&self, m: &mut Message) {
+        m.mut_unknown_fields().remove(self.number());
+    }
+
+    fn mut_message_generic<'a>(&self, _m: &'a mut Message) -> &'a mut Message {
+        panic!("extension value cannot be borrowed, use `set_singular_field`")
+    }
+
+    fn push_generic(&self, m: &mut Message, value: ReflectValueBox) {
+        assert!(!self.is_singular(), "not a repeated field");
+        self.add_values(m, &[value]).expect("write extension");
+    }
+
+    fn insert_generic(&self, _m: &mut Message, _key: ReflectValueBox, _value: ReflectValueBox) {
+        panic!("not a map field")
+    }
+
+    fn remove_generic(&self, _m: &mut Message, _key: ReflectValueBox) -> bool {
+        panic!("not a map field")
+    }
+
+    fn get_extension_generic(&self, m: &Message) -> Vec<ReflectValueBox> {
+        self.get_values(m)
+    }
+}
+
+
+/// Create descriptor of a dynamic message.
+///
+/// `shapes` are resolved types of message fields in declaration order,
+/// `cell` must be set to the returned descriptor after it is leaked.
+pub(super) fn new_message_descriptor(
+    full_name: String,
+    proto: &'static DescriptorProto,
+    proto3: bool,
+    shapes: Vec<DynamicFieldShape>,
+    cell: &'static DescriptorCell<MessageDescriptor>,
+) -> MessageDescriptor {
+    let mut fields = Vec::new();
+    let mut index_by_number = HashMap::new();
+    for (i, (f, shape)) in proto.get_field().iter().zip(shapes).enumerate() {
+        index_by_number.insert(f.get_number() as u32, i);
+        fields.push(DynamicField::new(f, shape, proto3));
+    }
+
+    let message_type = leak(DynamicMessageType {
+        descriptor: cell,
+        fields: fields,
+        index_by_number: index_by_number,
+    });
+    let accessors = (0..message_type.fields.len())
+        .map(|i| {
+            Box::new(DynamicFieldAccessor {
+                message_type: message_type,
+                index: i,
+            }) as Box<FieldAccessor + 'static>
+        })
+        .collect();
+    MessageDescriptor::new_with_factory(
+        full_name,
+        proto,
+        accessors,
+        Box::new(DynamicMessageFactory { message_type: message_type }),
+    )
+}
+
+/// Create descriptor of an extension field, `shape` must not be a map
+pub(super) fn new_extension_descriptor(
+    full_name: String,
+    proto: &'static FieldDescriptorProto,
+    shape: DynamicFieldShape,
+) -> FieldDescriptor {
+    // extensions always have presence
+    let accessor = DynamicExtensionAccessor { field: DynamicField::new(proto, shape, false) };
+    FieldDescriptor::new(full_name, Box::new(accessor), proto)
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use descriptor;
+    use descriptor::FieldDescriptorProto;
+    use descriptor::FileDescriptorProto;
+    use hex::decode_hex;
+    use hex::encode_hex;
+    use reflect::DescriptorPool;
+    use text_format;
+
+    fn descriptor_proto_pool() -> DescriptorPool {
+        let mut pool = DescriptorPool::new();
+        pool.add_generated_file(descriptor::file_descriptor_proto()).unwrap();
+        pool
+    }
+
+    #[test]
+    fn test_round_trip_descriptor_proto() {
+        let pool = descriptor_proto_pool();
+        let file_descriptor = pool
+            .find_message_by_full_name("google.protobuf.FileDescriptorProto")
+            .unwrap();
+
+        let generated = descriptor::file_descriptor_proto();
+        let bytes = generated.write_to_bytes().unwrap();
+
+        let mut dynamic = DynamicMessage::with_descriptor(file_descriptor);
+        dynamic.merge_from_bytes(&bytes).unwrap();
+        assert!(dynamic.is_initialized());
+        assert_eq!(bytes, dynamic.write_to_bytes().unwrap());
+        assert_eq!(
+            text_format::print_to_string(generated),
+            text_format::print_to_string(&dynamic)
+        );
+    }
+
+    const TEST_PROTO3_FILE: &'static str = r#"
+        name: "test.proto" package: "test" syntax: "proto3"
+        message_type {
+            name: "M"
+            field { name: "m" number: 1 label: LABEL_REPEATED type: TYPE_MESSAGE type_name: ".test.M.MEntry" }
+            field { name: "s" number: 2 label: LABEL_OPTIONAL type: TYPE_STRING oneof_index: 0 }
+            field { name: "e" number: 3 label: LABEL_OPTIONAL type: TYPE_ENUM type_name: "E" }
+            field { name: "i" number: 4 label: LABEL_REPEATED type: TYPE_SINT32 options { packed: true } }
+            field { name: "n" number: 5 label: LABEL_OPTIONAL type: TYPE_INT32 oneof_index: 0 }
+            nested_type {
+                name: "MEntry"
+                field { name: "key" number: 1 label: LABEL_OPTIONAL type: TYPE_STRING }
+                field { name: "value" number: 2 label: LABEL_OPTIONAL type: TYPE_MESSAGE type_name: "M" }
+                options { map_entry: true }
+            }
+            oneof_decl { name: "o" }
+        }
+        enum_type { name: "E" value { name: "A" number: 0 } value { name: "B" number: 1 } }
+    "#;
+
+    #[test]
+    fn test_proto3_map_oneof() {
+        let file: FileDescriptorProto = text_format::parse_from_str(TEST_PROTO3_FILE).unwrap();
+        let mut pool = DescriptorPool::new();
+        pool.add_file(file).unwrap();
+        let d = pool.find_message_by_full_name("test.M").unwrap();
+        match d.field_by_name("m").runtime_field_type() {
+            RuntimeFieldType::Map(RuntimeType::String, RuntimeType::Message(v)) => {
+                assert!(ptr::eq(d, v))
+            }
+            _ => panic!("expecting map"),
+        }
+
+        let mut m = DynamicMessage::with_descriptor(d);
+        text_format::merge_from_str(&mut m, "m { key: \"k\" value { s: \"\" e: A } } e: B i: -1 i: 2")
+            .unwrap();
+        let bytes = m.write_to_bytes().unwrap();
+
+        let mut parsed = DynamicMessage::with_descriptor(d);
+        parsed.merge_from_bytes(&bytes).unwrap();
+        assert_eq!(bytes, parsed.write_to_bytes().unwrap());
+        // oneof member is serialized even if empty, proto3 enum zero value is not
+        assert_eq!(
+            "m {key: \"k\" value {s: \"\"}} e: B i: -1 i: 2",
+            text_format::print_to_string(&parsed)
+        );
+    }
+
+    #[test]
+    fn test_oneof() {
+        let file: FileDescriptorProto = text_format::parse_from_str(TEST_PROTO3_FILE).unwrap();
+        let mut pool = DescriptorPool::new();
+        pool.add_file(file).unwrap();
+        let d = pool.find_message_by_full_name("test.M").unwrap();
+        let oneof = &d.oneofs()[0];
+        assert!(ptr::eq(oneof, d.field_by_name("n").containing_oneof().unwrap()));
+        assert!(d.field_by_name("i").containing_oneof().is_none());
+
+        let mut m = DynamicMessage::with_descriptor(d);
+        assert!(oneof.which_field_is_set(&m).is_none());
+        d.field_by_name("s").set_singular_field(&mut m, "x".into());
+        d.field_by_name("n").set_singular_field(&mut m, 3i32.into());
+        assert_eq!("n", oneof.which_field_is_set(&m).unwrap().name());
+        assert!(!d.field_by_name("s").has_field(&m));
+
+        // last oneof field on the wire wins
+        m.merge_from_bytes(&decode_hex("12 01 79")).unwrap();
+        assert_eq!("s: \"y\"", text_format::print_to_string(&m));
+
+        oneof.clear(&mut m);
+        assert!(oneof.which_field_is_set(&m).is_none());
+    }
+
+    #[test]
+    fn test_unknown_fields_preserved() {
+        let pool = descriptor_proto_pool();
+        let d = pool.find_message_by_full_name("google.protobuf.FieldDescriptorProto").unwrap();
+
+        let mut field = FieldDescriptorProto::new();
+        field.set_name("f".to_owned());
+        field.mut_unknown_fields().add_varint(1000, 17);
+        let bytes = field.write_to_bytes().unwrap();
+
+        let mut dynamic = DynamicMessage::with_descriptor(d);
+        dynamic.merge_from_bytes(&bytes).unwrap();
+        assert_eq!(Some(&[17u64][..]), dynamic.get_unknown_fields().get(1000).map(|v| &v.varint[..]));
+        assert_eq!(bytes, dynamic.write_to_bytes().unwrap());
+    }
+
+    #[test]
+    fn test_reflection() {
+        let pool = descriptor_proto_pool();
+        let d = pool.find_message_by_full_name("google.protobuf.FieldDescriptorProto").unwrap();
+        let type_enum = pool
+            .find_enum_by_full_name("google.protobuf.FieldDescriptorProto.Type")
+            .unwrap();
+
+        let mut dynamic = d.new_instance();
+        d.field_by_name("name").set_singular_field(&mut *dynamic, "f".into());
+        d.field_by_name("number").set_singular_field(&mut *dynamic, 3.into());
+        d.field_by_name("type")
+            .set_singular_field(&mut *dynamic, type_enum.value_by_name("TYPE_STRING").into());
+        {
+            let options = d.field_by_name("options").mut_message(&mut *dynamic);
+            options.descriptor()
+                .field_by_name("packed")
+                .set_singular_field(options, true.into());
+        }
+        assert_eq!("f", d.field_by_name("name").get_str(&*dynamic));
+        assert_eq!(
+            "name: \"f\" number: 3 type: TYPE_STRING options {packed: true}",
+            text_format::print_to_string(&*dynamic)
+        );
+
+        let generated: FieldDescriptorProto = ::parse_from_bytes(&dynamic.write_to_bytes().unwrap())
+            .unwrap();
+        assert_eq!(3, generated.get_number());
+        assert_eq!(true, generated.get_options().get_packed());
+    }
+
+    const TEST_EXTENSIONS_FILE: &'static str = r#"
+        name: "ext_test.proto" package: "ext_test"
+        message_type {
+            name: "M"
+            field { name: "s" number: 1 label: LABEL_OPTIONAL type: TYPE_STRING }
+            extension_range { start: 100 end: 200 }
+        }
+        extension { name: "m" number: 100 label: LABEL_OPTIONAL type: TYPE_MESSAGE type_name: "M" extendee: "M" }
+        extension { name: "nums" number: 101 label: LABEL_REPEATED type: TYPE_SINT32 extendee: ".ext_test.M" }
+    "#;
+
+    #[test]
+    fn test_extensions() {
+        let file: FileDescriptorProto = text_format::parse_from_str(TEST_EXTENSIONS_FILE).unwrap();
+        let mut pool = DescriptorPool::new();
+        pool.add_file(file).unwrap();
+        let d = pool.find_message_by_full_name("ext_test.M").unwrap();
+        let ext_m = pool.find_extension_by_number("ext_test.M", 100).unwrap();
+        let ext_nums = pool.find_extension_by_number("ext_test.M", 101).unwrap();
+        assert!(ext_nums.is_extension());
+        assert_eq!("ext_test.nums", ext_nums.full_name());
+
+        let mut m = DynamicMessage::with_descriptor(d);
+        let text = "s: 'a' [ext_test.m] {s: 'b'} [ext_test.nums]: [1, -2]";
+        // extensions are known only to the pool
+        assert!(text_format::merge_from_str(&mut m, text).is_err());
+        text_format::merge_from_str_with_pool(&mut m, text, &pool).unwrap();
+        // packed zigzag encoded 3
+        m.mut_unknown_fields().add_length_delimited(101, vec![6]);
+        assert_eq!(3, ext_nums.len_field(&m));
+        match ext_m.get_extension(&m).pop() {
+            Some(ReflectValueBox::Message(nested)) => {
+                assert_eq!("s: \"b\"", text_format::print_to_string(&*nested))
+            }
+            _ => panic!("expecting message"),
+        }
+
+        let mut parsed = DynamicMessage::with_descriptor(d);
+        parsed.merge_from_bytes(&m.write_to_bytes().unwrap()).unwrap();
+        assert_eq!(
+            "s: \"a\" [ext_test.m] {s: \"b\"} \
+             [ext_test.nums]: 1 [ext_test.nums]: -2 [ext_test.nums]: 3",
+            text_format::print_to_string_with_pool(&parsed, &pool)
+        );
+        assert_eq!("s: \"a\"", text_format::print_to_string(&parsed));
+
+        ext_m.clear_field(&mut parsed);
+        ext_nums.mut_repeated(&mut parsed).push(ReflectValueBox::I32(4));
+        assert!(!ext_m.has_field(&parsed));
+        assert_eq!(4, ext_nums.len_field(&parsed));
+
+        // truncated packed varint: the whole field is not set
+        parsed.mut_unknown_fields().add_length_delimited(101, vec![0x80]);
+        assert!(!ext_nums.has_field(&parsed));
+        assert!(ext_nums.get_extension(&parsed).is_empty());
+    }
+
+    const TEST_GROUP_FILE: &'static str = r#"
+        name: "group_test.proto" package: "group_test"
+        message_type {
+            name: "G"
+            field { name: "a" number: 1 label: LABEL_OPTIONAL type: TYPE_INT32 }
+            field { name: "item" number: 2 label: LABEL_REPEATED type: TYPE_GROUP type_name: ".group_test.G.Item" }
+            nested_type {
+                name: "Item"
+                field { name: "b" number: 3 label: LABEL_OPTIONAL type: TYPE_INT32 }
+                field { name: "c" number: 4 label: LABEL_REPEATED type: TYPE_INT32 options { packed: true } }
+                field { name: "inner" number: 5 label: LABEL_OPTIONAL type: TYPE_GROUP type_name: "Inner" }
+                field { name: "g" number: 7 label: LABEL_OPTIONAL type: TYPE_MESSAGE type_name: ".group_test.G" }
+                nested_type {
+                    name: "Inner"
+                    field { name: "d" number: 6 label: LABEL_OPTIONAL type: TYPE_STRING }
+                }
+            }
+        }
+    "#;
+
+    #[test]
+    fn test_groups() {
+        let file: FileDescriptorProto = text_format::parse_from_str(TEST_GROUP_FILE).unwrap();
+        let mut pool = DescriptorPool::new();
+        pool.add_file(file).unwrap();
+        let d = pool.find_message_by_full_name("group_test.G").unwrap();
+
+        let text = "a: 1 Item {b: 2 c: 3 c: 4 Inner {d: \"x\"} g {Item {b: 4}}} Item {b: 5}";
+        let mut m = DynamicMessage::with_descriptor(d);
+        text_format::merge_from_str(&mut m, text).unwrap();
+        let bytes = m.write_to_bytes().unwrap();
+        assert_eq!(
+            "08 01 13 18 02 22 02 03 04 2b 32 01 78 2c 3a 04 13 18 04 14 14 13 18 05 14",
+            encode_hex(&bytes)
+        );
+
+        let mut parsed = DynamicMessage::with_descriptor(d);
+        parsed.merge_from_bytes(&bytes).unwrap();
+        assert_eq!(bytes, parsed.write_to_bytes().unwrap());
+        assert_eq!(text, text_format::print_to_string(&parsed));
+
+        // unknown field inside group is kept in the group
+        let mut parsed = DynamicMessage::with_descriptor(d);
+        parsed.merge_from_bytes(&decode_hex("13 40 07 14")).unwrap();
+        assert_eq!("13 40 07 14", encode_hex(&parsed.write_to_bytes().unwrap()));
+
+        // missing or mismatched end of group
+        for hex in &["13 18 02", "13 18 02 1c", "13 2b 14 2c"] {
+            let mut parsed = DynamicMessage::with_descriptor(d);
+            assert!(parsed.merge_from_bytes(&decode_hex(hex)).is_err(), "{}", hex);
+        }
+    }
+
+    #[test]
+    fn test_unresolved_type() {
+        let mut file = FileDescriptorProto::new();
+        let mut message = DescriptorProto::new();
+        message.set_name("M".to_owned());
+        let mut field = FieldDescriptorProto::new();
+        field.set_name("f".to_owned());
+        field.set_number(1);
+        field.set_field_type(FieldDescriptorProto_Type::TYPE_MESSAGE);
+        field.set_type_name("N".to_owned());
+        message.mut_field().push(field);
+        file.mut_message_type().push(message);
+        assert!(DescriptorPool::new().add_file(file).is_err());
+    }
+}
@@ -0,0 +1,61 @@
+use std::error::Error;
+use std::fmt;
+
+
+/// Error of reflective access to a message field
+#[derive(Debug)]
+pub enum ReflectError {
+    /// Message is not of the type where the field is declared
+    WrongMessageType { field: String, message: String },
+    /// Operation does not match field shape or type, e. g. `get_str` of `int32` field
+    WrongFieldType { field: String, expected: &'static str },
+    /// Value passed to setter is not of the field type
+    WrongValueType { field: String },
+    /// Extension values are not stored in the message, so they cannot be borrowed
+    ExtensionNotBorrowable { field: String },
+    /// Wrapper message field generated as `Option` of wrapped value
+    /// is not stored as a message, so it cannot be borrowed as a message
+    WrapperNotBorrowable { field: String },
+    /// Enum field holds a value unknown to the enum definition (proto3 open enum)
+    UnknownEnumValue { field: String, value: i32 },
+}
+
+impl fmt::Display for ReflectError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ReflectError::WrongMessageType { ref field, ref message } => {
+                write!(f, "field `{}` is not a field of message `{}`", field, message)
+            }
+            ReflectError::WrongFieldType { ref field, expected } => {
+                write!(f, "field `{}` is not a {} field", field, expected)
+            }
+            ReflectError::WrongValueType { ref field } => {
+                write!(f, "value is not of type of field `{}`", field)
+            }
+            ReflectError::ExtensionNotBorrowable { ref field } => {
+                write!(f, "value of extension field `{}` cannot be borrowed", field)
+            }
+            ReflectError::WrapperNotBorrowable { ref field } => {
+                write!(f, "wrapper field `{}` cannot be borrowed as a message", field)
+            }
+            ReflectError::UnknownEnumValue { ref field, value } => {
+                write!(f, "field `{}` holds unknown enum value {}", field, value)
+            }
+        }
+    }
+}
+
+impl Error for ReflectError {
+    fn description(&self) -> &str {
+        match *self {
+            ReflectError::WrongMessageType { .. } => "wrong message type",
+            ReflectError::WrongFieldType { .. } => "wrong field type",
+            ReflectError::WrongValueType { .. } => "wrong value type",
+            ReflectError::ExtensionNotBorrowable { .. } => "extension cannot be borrowed",
+            ReflectError::WrapperNotBorrowable { .. } => "wrapper field cannot be borrowed",
+            ReflectError::UnknownEnumValue { .. } => "unknown enum value",
+        }
+    }
+}
+
+pub type ReflectResult<T> = Result<T, ReflectError>;
@@ -0,0 +1,62 @@
+use descriptor::FileDescriptorProto;
+use descriptor::FileOptions;
+
+use super::EnumDescriptor;
+use super::FieldDescriptor;
+use super::MessageDescriptor;
+use super::ServiceDescriptor;
+
+
+/// Descriptor of a `.proto` file added to a `DescriptorPool`
+pub struct FileDescriptor {
+    pub(super) proto: &'static FileDescriptorProto,
+    pub(super) dependencies: Vec<&'static FileDescriptor>,
+    pub(super) messages: Vec<&'static MessageDescriptor>,
+    pub(super) enums: Vec<&'static EnumDescriptor>,
+    pub(super) services: Vec<ServiceDescriptor>,
+    pub(super) extensions: Vec<FieldDescriptor>,
+}
+
+impl FileDescriptor {
+    pub fn proto(&self) -> &'static FileDescriptorProto {
+        self.proto
+    }
+
+    /// File name relative to the import root, e. g. `google/protobuf/any.proto`
+    pub fn name(&self) -> &'static str {
+        self.proto.get_name()
+    }
+
+    pub fn package(&self) -> &'static str {
+        self.proto.get_package()
+    }
+
+    /// Options of this file, extensions of `FileOptions` are custom options
+    pub fn options(&self) -> &'static FileOptions {
+        self.proto.get_options()
+    }
+
+    /// Imported files in declaration order
+    pub fn dependencies(&self) -> &[&'static FileDescriptor] {
+        &self.dependencies
+    }
+
+    /// Top-level messages
+    pub fn messages(&self) -> &[&'static MessageDescriptor] {
+        &self.messages
+    }
+
+    /// Top-level enums
+    pub fn enums(&self) -> &[&'static EnumDescriptor] {
+        &self.enums
+    }
+
+    pub fn services(&self) -> &[ServiceDescriptor] {
+        &self.services
+    }
+
+    /// Top-level extension declarations
+    pub fn extensions(&self) -> &[FieldDescriptor] {
+        &self.extensions
+    }
+}
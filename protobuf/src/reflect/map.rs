@@ -2,7 +2,12 @@ use std::hash::Hash;
 use std::collections::HashMap;
 use std::collections::hash_map;
 
+use core::Message;
+
+use super::accessor::FieldAccessor;
 use super::value::ProtobufValue;
+use super::value::ReflectValueBox;
+use super::ReflectFieldRef;
 
 
 /// Implemented for `HashMap` with appropriate keys and values
@@ -65,3 +70,47 @@ impl<'a> IntoIterator for &'a ReflectMap {
         self.reflect_iter()
     }
 }
+
+/// Mutable reference to map field, obtained with `FieldDescriptor::mut_map`
+pub struct ReflectMapMut<'a> {
+    accessor: &'a FieldAccessor,
+    message: &'a mut Message,
+}
+
+impl<'a> ReflectMapMut<'a> {
+    pub(crate) fn new(accessor: &'a FieldAccessor, message: &'a mut Message) -> ReflectMapMut<'a> {
+        ReflectMapMut {
+            accessor: accessor,
+            message: message,
+        }
+    }
+
+    fn map(&self) -> &ReflectMap {
+        match self.accessor.get_reflect(&*self.message) {
+            ReflectFieldRef::Map(map) => map,
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.map().len()
+    }
+
+    pub fn reflect_iter(&self) -> ReflectMapIter {
+        self.map().reflect_iter()
+    }
+
+    /// Insert an entry; panics if key or value type does not match field type
+    pub fn insert(&mut self, key: ReflectValueBox, value: ReflectValueBox) {
+        self.accessor.insert_generic(self.message, key, value)
+    }
+
+    /// Remove an entry, return `true` if the key was present
+    pub fn remove(&mut self, key: ReflectValueBox) -> bool {
+        self.accessor.remove_generic(self.message, key)
+    }
+
+    pub fn clear(&mut self) {
+        self.accessor.clear_field_generic(self.message)
+    }
+}
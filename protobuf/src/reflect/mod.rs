@@ -2,7 +2,10 @@
 
 use std::collections::HashMap;
 use std::default::Default;
+use std::f64;
 use std::marker;
+use std::mem;
+use std::ptr;
 
 use core::Message;
 use core::ProtobufEnum;
@@ -12,38 +15,77 @@ use descriptor::FieldDescriptorProto;
 use descriptor::EnumDescriptorProto;
 use descriptor::EnumValueDescriptorProto;
 use descriptor::FieldDescriptorProto_Label;
+use descriptor::EnumOptions;
+use descriptor::EnumValueOptions;
+use descriptor::FieldOptions;
+use descriptor::MessageOptions;
 use descriptorx::find_enum_by_rust_name;
 use descriptorx::find_message_by_rust_name;
+use descriptorx::WithScope;
+use json::json_name;
+use text_format::unescape_string_checked;
 use reflect::accessor::FieldAccessor;
 
 
 pub mod accessor;
+pub mod registry;
 mod map;
 mod repeated;
 mod value;
 mod optional;
+mod runtime_type;
+mod cell;
+mod dynamic;
+mod error;
+mod file;
+mod oneof;
+mod pool;
+mod service;
 
 use self::repeated::ReflectRepeated;
 use self::map::ReflectMap;
+use self::cell::DescriptorCell;
+use self::cell::leak;
 
 pub use self::value::ProtobufValue;
 pub use self::value::ProtobufValueRef;
+pub use self::value::ReflectValueBox;
+pub use self::repeated::ReflectRepeatedMut;
+pub use self::map::ReflectMapMut;
+pub use self::runtime_type::RuntimeType;
+pub use self::runtime_type::RuntimeFieldType;
+pub use self::dynamic::DynamicMessage;
+pub use self::error::ReflectError;
+pub use self::error::ReflectResult;
+pub use self::file::FileDescriptor;
+pub use self::oneof::OneofDescriptor;
+pub use self::pool::DescriptorPool;
+pub use self::pool::DescriptorError;
+pub use self::service::ServiceDescriptor;
+pub use self::service::MethodDescriptor;
 
 
 pub struct FieldDescriptor {
+    full_name: String,
     proto: &'static FieldDescriptorProto,
     accessor: Box<FieldAccessor + 'static>,
+    containing_type: DescriptorCell<MessageDescriptor>,
+    containing_oneof: DescriptorCell<OneofDescriptor>,
 }
 
 impl FieldDescriptor {
     fn new(
+        full_name: String,
         a: Box<FieldAccessor + 'static>,
         proto: &'static FieldDescriptorProto,
     ) -> FieldDescriptor {
         assert_eq!(proto.get_name(), a.name_generic());
         FieldDescriptor {
+            full_name: full_name,
             proto: proto,
             accessor: a,
+            containing_type: DescriptorCell::new(),
+            containing_oneof: DescriptorCell::new(),
         }
     }
 
@@ -55,69 +97,406 @@ impl FieldDescriptor {
         self.proto.get_name()
     }
 
+    /// Fully qualified name: message full name and field name,
+    /// or scope and name for extension fields, e. g. `rustproto.expose_oneof_all`
+    pub fn full_name(&self) -> &str {
+        &self.full_name
+    }
+
+    /// JSON name of the field: explicit `json_name` option or lowerCamelCase field name
+    pub fn json_name(&self) -> String {
+        if self.proto.has_json_name() {
+            self.proto.get_json_name().to_owned()
+        } else {
+            json_name(self.name())
+        }
+    }
+
+    /// Options of this field, extensions of `FieldOptions` are custom options
+    pub fn options(&self) -> &'static FieldOptions {
+        self.proto.get_options()
+    }
+
+    /// Value of singular field when it is not set: `[default = ...]` value
+    /// or zero value of the field type; `None` for repeated and map fields.
+    pub fn default_value(&self) -> Option<ReflectValueBox> {
+        let t = match self.runtime_field_type() {
+            RuntimeFieldType::Singular(t) => t,
+            _ => return None,
+        };
+        if self.proto.has_default_value() {
+            if let Some(v) = parse_default_value(t, self.proto.get_default_value()) {
+                return Some(v);
+            }
+        }
+        Some(t.default_value())
+    }
+
+    /// Message where this field is declared, or extended message for extension fields.
+    ///
+    /// `None` for fields of generated messages, which are not linked to their message;
+    /// set for descriptors created by `DescriptorPool` and for registered extensions.
+    pub fn containing_type(&self) -> Option<&'static MessageDescriptor> {
+        self.containing_type.get()
+    }
+
+    /// Oneof this field belongs to, including synthetic oneof of proto3 `optional` field
+    pub fn containing_oneof(&self) -> Option<&'static OneofDescriptor> {
+        self.containing_oneof.get()
+    }
+
     pub fn is_repeated(&self) -> bool {
         self.proto.get_label() == FieldDescriptorProto_Label::LABEL_REPEATED
     }
 
+    pub fn is_extension(&self) -> bool {
+        self.proto.has_extendee()
+    }
+
+    /// Values of extension field decoded from unknown fields of the message,
+    /// empty if any of the values cannot be decoded.
+    ///
+    /// Extension values are not stored in the message, so they cannot be accessed
+    /// by reference: `get_message`, `get_str`, `get_bytes` and `get_reflect`
+    /// panic for extension fields.
+    pub fn get_extension(&self, m: &Message) -> Vec<ReflectValueBox> {
+        assert!(self.is_extension(), "not an extension field");
+        self.accessor.get_extension_generic(m)
+    }
+
+    /// Wrapper message field generated as `Option` of wrapped value
+    /// (`Customize::wrappers_as_option`). Wrapper messages are created when the field
+    /// is read with `get_reflect`, so `get_message` and `mut_message` panic for such fields.
+    pub(crate) fn is_wrapper(&self) -> bool {
+        self.accessor.is_wrapper_generic()
+    }
+
     pub fn has_field(&self, m: &Message) -> bool {
         self.accessor.has_field_generic(m)
     }
 
+    /// Singular field which remembers whether it is set, e. g. proto2 field, message field
+    /// or oneof member. Proto3 scalar fields without `optional` are not set when they are zero.
+    pub fn has_presence(&self) -> bool {
+        self.accessor.has_presence_generic()
+    }
+
     pub fn len_field(&self, m: &Message) -> usize {
         self.accessor.len_field_generic(m)
     }
 
+    /// Singular message field value, `None` if the field is not set
+    pub fn try_get_message<'a>(&self, m: &'a Message) -> ReflectResult<Option<&'a Message>> {
+        self.check_get(m, "singular message", true, |t| match t {
+            RuntimeType::Message(..) => true,
+            _ => false,
+        })?;
+        if self.accessor.has_field_generic(m) {
+            Ok(Some(self.accessor.get_message_generic(m)))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Value of singular enum field, default value if the field is not set
+    pub fn try_get_enum(&self, m: &Message) -> ReflectResult<&'static EnumValueDescriptor> {
+        self.check_get(m, "singular enum", false, |t| match t {
+            RuntimeType::Enum(..) => true,
+            _ => false,
+        })?;
+        if !self.accessor.has_field_generic(m) {
+            return match self.default_value() {
+                Some(ReflectValueBox::Enum(v)) => Ok(v),
+                _ => unreachable!(),
+            };
+        }
+        self.accessor.get_enum_generic(m).map_err(|v| ReflectError::UnknownEnumValue {
+            field: self.full_name.clone(),
+            value: v,
+        })
+    }
+
+    pub fn try_get_str<'a>(&self, m: &'a Message) -> ReflectResult<&'a str> {
+        self.check_get(m, "singular string", true, |t| match t {
+            RuntimeType::String => true,
+            _ => false,
+        })?;
+        Ok(self.accessor.get_str_generic(m))
+    }
+
+    pub fn try_get_bytes<'a>(&self, m: &'a Message) -> ReflectResult<&'a [u8]> {
+        self.check_get(m, "singular bytes", true, |t| match t {
+            RuntimeType::Bytes => true,
+            _ => false,
+        })?;
+        Ok(self.accessor.get_bytes_generic(m))
+    }
+
+    pub fn try_get_u32(&self, m: &Message) -> ReflectResult<u32> {
+        self.check_get(m, "singular u32", false, |t| match t {
+            RuntimeType::U32 => true,
+            _ => false,
+        })?;
+        Ok(self.accessor.get_u32_generic(m))
+    }
+
+    pub fn try_get_u64(&self, m: &Message) -> ReflectResult<u64> {
+        self.check_get(m, "singular u64", false, |t| match t {
+            RuntimeType::U64 => true,
+            _ => false,
+        })?;
+        Ok(self.accessor.get_u64_generic(m))
+    }
+
+    pub fn try_get_i32(&self, m: &Message) -> ReflectResult<i32> {
+        self.check_get(m, "singular i32", false, |t| match t {
+            RuntimeType::I32 => true,
+            _ => false,
+        })?;
+        Ok(self.accessor.get_i32_generic(m))
+    }
+
+    pub fn try_get_i64(&self, m: &Message) -> ReflectResult<i64> {
+        self.check_get(m, "singular i64", false, |t| match t {
+            RuntimeType::I64 => true,
+            _ => false,
+        })?;
+        Ok(self.accessor.get_i64_generic(m))
+    }
+
+    pub fn try_get_bool(&self, m: &Message) -> ReflectResult<bool> {
+        self.check_get(m, "singular bool", false, |t| match t {
+            RuntimeType::Bool => true,
+            _ => false,
+        })?;
+        Ok(self.accessor.get_bool_generic(m))
+    }
+
+    pub fn try_get_f32(&self, m: &Message) -> ReflectResult<f32> {
+        self.check_get(m, "singular f32", false, |t| match t {
+            RuntimeType::F32 => true,
+            _ => false,
+        })?;
+        Ok(self.accessor.get_f32_generic(m))
+    }
+
+    pub fn try_get_f64(&self, m: &Message) -> ReflectResult<f64> {
+        self.check_get(m, "singular f64", false, |t| match t {
+            RuntimeType::F64 => true,
+            _ => false,
+        })?;
+        Ok(self.accessor.get_f64_generic(m))
+    }
+
+    pub fn try_get_reflect<'a>(&self, m: &'a Message) -> ReflectResult<ReflectFieldRef<'a>> {
+        self.check_message(m)?;
+        if self.is_extension() {
+            return Err(ReflectError::ExtensionNotBorrowable { field: self.full_name.clone() });
+        }
+        Ok(self.accessor.get_reflect(m))
+    }
+
+    /// Like `try_get_message`, but panics on error and
+    /// returns default instance if the field is not set
     pub fn get_message<'a>(&self, m: &'a Message) -> &'a Message {
+        self.try_get_message(m).unwrap_or_else(|e| panic!("{}", e));
         self.accessor.get_message_generic(m)
     }
 
     pub fn get_enum(&self, m: &Message) -> &'static EnumValueDescriptor {
-        self.accessor.get_enum_generic(m)
+        self.try_get_enum(m).unwrap_or_else(|e| panic!("{}", e))
     }
 
     pub fn get_str<'a>(&self, m: &'a Message) -> &'a str {
-        self.accessor.get_str_generic(m)
+        self.try_get_str(m).unwrap_or_else(|e| panic!("{}", e))
     }
 
     pub fn get_bytes<'a>(&self, m: &'a Message) -> &'a [u8] {
-        self.accessor.get_bytes_generic(m)
+        self.try_get_bytes(m).unwrap_or_else(|e| panic!("{}", e))
     }
 
     pub fn get_u32(&self, m: &Message) -> u32 {
-        self.accessor.get_u32_generic(m)
+        self.try_get_u32(m).unwrap_or_else(|e| panic!("{}", e))
     }
 
     pub fn get_u64(&self, m: &Message) -> u64 {
-        self.accessor.get_u64_generic(m)
+        self.try_get_u64(m).unwrap_or_else(|e| panic!("{}", e))
     }
 
     pub fn get_i32(&self, m: &Message) -> i32 {
-        self.accessor.get_i32_generic(m)
+        self.try_get_i32(m).unwrap_or_else(|e| panic!("{}", e))
     }
 
     pub fn get_i64(&self, m: &Message) -> i64 {
-        self.accessor.get_i64_generic(m)
+        self.try_get_i64(m).unwrap_or_else(|e| panic!("{}", e))
     }
 
     pub fn get_bool(&self, m: &Message) -> bool {
-        self.accessor.get_bool_generic(m)
+        self.try_get_bool(m).unwrap_or_else(|e| panic!("{}", e))
     }
 
     pub fn get_f32(&self, m: &Message) -> f32 {
-        self.accessor.get_f32_generic(m)
+        self.try_get_f32(m).unwrap_or_else(|e| panic!("{}", e))
     }
 
     pub fn get_f64(&self, m: &Message) -> f64 {
-        self.accessor.get_f64_generic(m)
+        self.try_get_f64(m).unwrap_or_else(|e| panic!("{}", e))
     }
 
     pub fn get_reflect<'a>(&self, m: &'a Message) -> ReflectFieldRef<'a> {
-        self.accessor.get_reflect(m)
+        self.try_get_reflect(m).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Error unless `m` is a message of type where this field is declared
+    fn check_message(&self, m: &Message) -> ReflectResult<()> {
+        let descriptor = m.descriptor();
+        let declared = if self.is_extension() {
+            self.containing_type().map_or(true, |t| ptr::eq(t, descriptor))
+        } else {
+            // fields of a message are stored in one slice
+            let fields = descriptor.fields();
+            let start = fields.as_ptr() as usize;
+            let end = start + fields.len() * mem::size_of::<FieldDescriptor>();
+            let field = self as *const FieldDescriptor as usize;
+            field >= start && field < end
+        };
+        if declared {
+            Ok(())
+        } else {
+            Err(ReflectError::WrongMessageType {
+                field: self.full_name.clone(),
+                message: descriptor.full_name().to_owned(),
+            })
+        }
+    }
+
+    /// Check that typed getter can be used with this field and message
+    fn check_get(
+        &self,
+        m: &Message,
+        expected: &'static str,
+        by_reference: bool,
+        matches: fn(RuntimeType) -> bool,
+    ) -> ReflectResult<()> {
+        self.check_message(m)?;
+        match self.runtime_field_type() {
+            RuntimeFieldType::Singular(t) if matches(t) => {}
+            _ => {
+                return Err(ReflectError::WrongFieldType {
+                    field: self.full_name.clone(),
+                    expected: expected,
+                })
+            }
+        }
+        if by_reference && self.is_extension() {
+            return Err(ReflectError::ExtensionNotBorrowable { field: self.full_name.clone() });
+        }
+        if by_reference && self.is_wrapper() {
+            return Err(ReflectError::WrapperNotBorrowable { field: self.full_name.clone() });
+        }
+        Ok(())
+    }
+
+    /// Shape of this field and types of its values
+    pub fn runtime_field_type(&self) -> RuntimeFieldType {
+        self.accessor.runtime_field_type()
+    }
+
+    /// Set value of singular field.
+    ///
+    /// Panics if field is repeated or value type does not match field type.
+    pub fn set_singular_field(&self, m: &mut Message, value: ReflectValueBox) {
+        self.try_set_singular_field(m, value).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Set value of singular field, error if field is repeated
+    /// or value type does not match field type
+    pub fn try_set_singular_field(
+        &self,
+        m: &mut Message,
+        value: ReflectValueBox,
+    ) -> ReflectResult<()> {
+        self.check_message(m)?;
+        match self.runtime_field_type() {
+            RuntimeFieldType::Singular(t) => {
+                if !t.is_type_of(&value) {
+                    return Err(ReflectError::WrongValueType { field: self.full_name.clone() });
+                }
+            }
+            _ => {
+                return Err(ReflectError::WrongFieldType {
+                    field: self.full_name.clone(),
+                    expected: "singular",
+                })
+            }
+        }
+        self.accessor.set_singular_field_generic(m, value);
+        Ok(())
+    }
+
+    /// Unset singular field or remove all elements of repeated field or map
+    pub fn clear_field(&self, m: &mut Message) {
+        self.accessor.clear_field_generic(m)
+    }
+
+    /// Mutable reference to singular message field, field is initialized if unset
+    pub fn mut_message<'a>(&self, m: &'a mut Message) -> &'a mut Message {
+        if self.is_wrapper() {
+            panic!("{}", ReflectError::WrapperNotBorrowable { field: self.full_name.clone() });
+        }
+        self.accessor.mut_message_generic(m)
+    }
+
+    /// Mutable reference to repeated field
+    pub fn mut_repeated<'a>(&'a self, m: &'a mut Message) -> ReflectRepeatedMut<'a> {
+        assert!(self.is_repeated() && !self.is_map(), "not a repeated field");
+        ReflectRepeatedMut::new(&*self.accessor, m)
+    }
+
+    /// Mutable reference to map field
+    pub fn mut_map<'a>(&'a self, m: &'a mut Message) -> ReflectMapMut<'a> {
+        assert!(self.is_map(), "not a map field");
+        ReflectMapMut::new(&*self.accessor, m)
+    }
+
+    fn is_map(&self) -> bool {
+        match self.runtime_field_type() {
+            RuntimeFieldType::Map(..) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Parse `default_value` of `FieldDescriptorProto`, `None` if malformed
+fn parse_default_value(t: RuntimeType, value: &str) -> Option<ReflectValueBox> {
+    fn parse_float(value: &str) -> Option<f64> {
+        match value {
+            "inf" => Some(f64::INFINITY),
+            "-inf" => Some(f64::NEG_INFINITY),
+            "nan" => Some(f64::NAN),
+            _ => value.parse().ok(),
+        }
+    }
+
+    match t {
+        RuntimeType::U32 => value.parse().ok().map(ReflectValueBox::U32),
+        RuntimeType::U64 => value.parse().ok().map(ReflectValueBox::U64),
+        RuntimeType::I32 => value.parse().ok().map(ReflectValueBox::I32),
+        RuntimeType::I64 => value.parse().ok().map(ReflectValueBox::I64),
+        RuntimeType::F32 => parse_float(value).map(|v| ReflectValueBox::F32(v as f32)),
+        RuntimeType::F64 => parse_float(value).map(ReflectValueBox::F64),
+        RuntimeType::Bool => value.parse().ok().map(ReflectValueBox::Bool),
+        RuntimeType::String => Some(ReflectValueBox::String(value.to_owned())),
+        RuntimeType::Bytes => unescape_string_checked(value).ok().map(ReflectValueBox::Bytes),
+        RuntimeType::Enum(e) => {
+            e.values().iter().find(|v| v.name() == value).map(ReflectValueBox::Enum)
+        }
+        RuntimeType::Message(..) => None,
     }
 }
 
 
-trait MessageFactory {
+trait MessageFactory : Send + Sync {
     fn new_instance(&self) -> Box<Message>;
 }
 
@@ -146,10 +525,19 @@ pub struct MessageDescriptor {
     full_name: String,
     proto: &'static DescriptorProto,
     factory: Box<MessageFactory + 'static>,
-    fields: Vec<FieldDescriptor>,
+    // leaked, so oneofs can refer to fields
+    fields: &'static [FieldDescriptor],
+    oneofs: &'static [OneofDescriptor],
 
     index_by_name: HashMap<String, usize>,
     index_by_number: HashMap<u32, usize>,
+
+    // navigation, filled by `DescriptorPool`
+    file_descriptor: DescriptorCell<FileDescriptor>,
+    containing_type: DescriptorCell<MessageDescriptor>,
+    nested_messages: Vec<&'static MessageDescriptor>,
+    nested_enums: Vec<&'static EnumDescriptor>,
+    extensions: Vec<FieldDescriptor>,
 }
 
 impl MessageDescriptor {
@@ -164,37 +552,82 @@ impl MessageDescriptor {
     ) -> MessageDescriptor {
         let proto = find_message_by_rust_name(file, rust_name);
 
+        let mut full_name = file.get_package().to_string();
+        if full_name.len() > 0 {
+            full_name.push('.');
+        }
+        // includes names of enclosing messages
+        full_name.push_str(&proto.name_to_package());
+
+        MessageDescriptor::new_with_factory(
+            full_name,
+            proto.message,
+            fields,
+            Box::new(MessageFactoryTyped::<M>::new()),
+        )
+    }
+
+    /// Used for both generated and dynamic messages
+    fn new_with_factory(
+        full_name: String,
+        proto: &'static DescriptorProto,
+        fields: Vec<Box<FieldAccessor + 'static>>,
+        factory: Box<MessageFactory + 'static>,
+    ) -> MessageDescriptor {
         let mut field_proto_by_name = HashMap::new();
-        for field_proto in proto.message.get_field() {
+        for field_proto in proto.get_field() {
             field_proto_by_name.insert(field_proto.get_name(), field_proto);
         }
 
         let mut index_by_name = HashMap::new();
         let mut index_by_number = HashMap::new();
-        for (i, f) in proto.message.get_field().iter().enumerate() {
+        for (i, f) in proto.get_field().iter().enumerate() {
             index_by_number.insert(f.get_number() as u32, i);
             index_by_name.insert(f.get_name().to_string(), i);
         }
 
-        let mut full_name = file.get_package().to_string();
-        if full_name.len() > 0 {
-            full_name.push('.');
+        let fields: &'static [FieldDescriptor] = leak(fields
+            .into_iter()
+            .map(|f| {
+                let proto = *field_proto_by_name.get(&f.name_generic()).unwrap();
+                FieldDescriptor::new(format!("{}.{}", full_name, proto.get_name()), f, proto)
+            })
+            .collect::<Vec<_>>());
+
+        let oneofs: &'static [OneofDescriptor] = leak(proto
+            .get_oneof_decl()
+            .iter()
+            .enumerate()
+            .map(|(i, oneof_proto)| OneofDescriptor {
+                proto: oneof_proto,
+                fields: fields
+                    .iter()
+                    .filter(|f| {
+                        f.proto.has_oneof_index() && f.proto.get_oneof_index() as usize == i
+                    })
+                    .collect(),
+                containing_type: DescriptorCell::new(),
+            })
+            .collect::<Vec<_>>());
+        for oneof in oneofs {
+            for field in &oneof.fields {
+                field.containing_oneof.set(oneof);
+            }
         }
-        full_name.push_str(proto.message.get_name());
 
         MessageDescriptor {
             full_name: full_name,
-            proto: proto.message,
-            factory: Box::new(MessageFactoryTyped::<M>::new()),
-            fields: fields
-                .into_iter()
-                .map(|f| {
-                    let proto = *field_proto_by_name.get(&f.name_generic()).unwrap();
-                    FieldDescriptor::new(f, proto)
-                })
-                .collect(),
+            proto: proto,
+            factory: factory,
+            fields: fields,
+            oneofs: oneofs,
             index_by_name: index_by_name,
             index_by_number: index_by_number,
+            file_descriptor: DescriptorCell::new(),
+            containing_type: DescriptorCell::new(),
+            nested_messages: Vec::new(),
+            nested_enums: Vec::new(),
+            extensions: Vec::new(),
         }
     }
 
@@ -202,27 +635,100 @@ impl MessageDescriptor {
         self.factory.new_instance()
     }
 
+    pub fn proto(&self) -> &'static DescriptorProto {
+        self.proto
+    }
+
     pub fn name(&self) -> &'static str {
         self.proto.get_name()
     }
 
+    /// Name with package and enclosing messages, e. g. `foo.Outer.Inner`
     pub fn full_name(&self) -> &str {
         &self.full_name[..]
     }
 
+    /// Options of this message, extensions of `MessageOptions` are custom options
+    pub fn options(&self) -> &'static MessageOptions {
+        self.proto.get_options()
+    }
+
     pub fn fields<'a>(&'a self) -> &'a [FieldDescriptor] {
-        &self.fields
+        self.fields
+    }
+
+    /// File where this message is declared.
+    ///
+    /// Generated descriptors are not linked to other descriptors, so this and
+    /// other navigation functions return `None` for them. Navigation is available
+    /// for descriptors created by `DescriptorPool`.
+    pub fn file_descriptor(&self) -> Option<&'static FileDescriptor> {
+        self.file_descriptor.get()
+    }
+
+    /// Message where this message is declared if this message is nested.
+    ///
+    /// `None` for top-level messages and for generated descriptors.
+    pub fn containing_type(&self) -> Option<&'static MessageDescriptor> {
+        self.containing_type.get()
+    }
+
+    /// Messages declared inside this message, `None` for generated descriptors
+    pub fn nested_messages(&self) -> Option<&[&'static MessageDescriptor]> {
+        self.file_descriptor.get().map(|_| &self.nested_messages[..])
+    }
+
+    /// Enums declared inside this message, `None` for generated descriptors
+    pub fn nested_enums(&self) -> Option<&[&'static EnumDescriptor]> {
+        self.file_descriptor.get().map(|_| &self.nested_enums[..])
+    }
+
+    /// Oneofs declared in this message, including synthetic oneofs
+    pub fn oneofs(&self) -> &[OneofDescriptor] {
+        self.oneofs
+    }
+
+    /// Extensions declared inside this message, `None` for generated descriptors
+    pub fn extensions(&self) -> Option<&[FieldDescriptor]> {
+        self.file_descriptor.get().map(|_| &self.extensions[..])
     }
 
+    /// Synthetic map entry message of given map field of this message
+    pub(crate) fn map_entry_proto(&self, field: &FieldDescriptor) -> &'static DescriptorProto {
+        let entry_name = field.proto().get_type_name().rsplit('.').next().unwrap();
+        self.proto
+            .get_nested_type()
+            .iter()
+            .find(|n| n.get_name() == entry_name)
+            .expect("map entry type not found")
+    }
+
+    /// Find field by name, panics if there is no such field
     pub fn field_by_name<'a>(&'a self, name: &str) -> &'a FieldDescriptor {
-        // TODO: clone is weird
-        let &index = self.index_by_name.get(&name.to_string()).unwrap();
-        &self.fields[index]
+        match self.get_field_by_name(name) {
+            Some(f) => f,
+            None => panic!("message `{}` has no field `{}`", self.full_name, name),
+        }
     }
 
+    /// Find field by number, panics if there is no such field
     pub fn field_by_number<'a>(&'a self, number: u32) -> &'a FieldDescriptor {
-        let &index = self.index_by_number.get(&number).unwrap();
-        &self.fields[index]
+        match self.get_field_by_number(number) {
+            Some(f) => f,
+            None => panic!("message `{}` has no field number {}", self.full_name, number),
+        }
+    }
+
+    pub fn get_field_by_name<'a>(&'a self, name: &str) -> Option<&'a FieldDescriptor> {
+        self.index_by_name.get(name).map(|&index| &self.fields[index])
+    }
+
+    pub fn get_field_by_number<'a>(&'a self, number: u32) -> Option<&'a FieldDescriptor> {
+        self.index_by_number.get(&number).map(|&index| &self.fields[index])
+    }
+
+    pub fn get_oneof_by_name<'a>(&'a self, name: &str) -> Option<&'a OneofDescriptor> {
+        self.oneofs.iter().find(|o| o.name() == name)
     }
 }
 
@@ -241,14 +747,27 @@ impl EnumValueDescriptor {
     pub fn value(&self) -> i32 {
         self.proto.get_number()
     }
+
+    pub fn proto(&self) -> &'static EnumValueDescriptorProto {
+        self.proto
+    }
+
+    pub fn options(&self) -> &'static EnumValueOptions {
+        self.proto.get_options()
+    }
 }
 
 pub struct EnumDescriptor {
+    full_name: String,
     proto: &'static EnumDescriptorProto,
     values: Vec<EnumValueDescriptor>,
 
     index_by_name: HashMap<String, usize>,
     index_by_number: HashMap<i32, usize>,
+
+    // navigation, filled by `DescriptorPool`
+    file_descriptor: DescriptorCell<FileDescriptor>,
+    containing_type: DescriptorCell<MessageDescriptor>,
 }
 
 impl EnumDescriptor {
@@ -256,40 +775,100 @@ impl EnumDescriptor {
         self.proto.get_name()
     }
 
+    /// Name with package and enclosing messages, e. g. `foo.Outer.Kind`
+    pub fn full_name(&self) -> &str {
+        &self.full_name[..]
+    }
+
+    pub fn proto(&self) -> &'static EnumDescriptorProto {
+        self.proto
+    }
+
+    pub fn options(&self) -> &'static EnumOptions {
+        self.proto.get_options()
+    }
+
+    /// File where this enum is declared.
+    ///
+    /// `None` for generated descriptors, which are not linked to other descriptors;
+    /// available for descriptors created by `DescriptorPool`.
+    pub fn file_descriptor(&self) -> Option<&'static FileDescriptor> {
+        self.file_descriptor.get()
+    }
+
+    /// Message where this enum is declared if this enum is nested.
+    ///
+    /// `None` for top-level enums and for generated descriptors.
+    pub fn containing_type(&self) -> Option<&'static MessageDescriptor> {
+        self.containing_type.get()
+    }
+
     pub fn for_type<E : ProtobufEnum>() -> &'static EnumDescriptor {
         ProtobufEnum::enum_descriptor_static(None::<E>)
     }
 
     pub fn new(rust_name: &'static str, file: &'static FileDescriptorProto) -> EnumDescriptor {
         let proto = find_enum_by_rust_name(file, rust_name);
+
+        let mut full_name = file.get_package().to_string();
+        if full_name.len() > 0 {
+            full_name.push('.');
+        }
+        full_name.push_str(&proto.name_to_package());
+
+        EnumDescriptor::new_dynamic(full_name, proto.en)
+    }
+
+    /// Descriptor of an enum without generated Rust type
+    fn new_dynamic(full_name: String, proto: &'static EnumDescriptorProto) -> EnumDescriptor {
         let mut index_by_name = HashMap::new();
         let mut index_by_number = HashMap::new();
-        for (i, v) in proto.en.get_value().iter().enumerate() {
+        for (i, v) in proto.get_value().iter().enumerate() {
             index_by_number.insert(v.get_number(), i);
             index_by_name.insert(v.get_name().to_string(), i);
         }
         EnumDescriptor {
-            proto: proto.en,
+            full_name: full_name,
+            proto: proto,
             values: proto
-                .en
                 .get_value()
                 .iter()
                 .map(|v| EnumValueDescriptor { proto: v })
                 .collect(),
             index_by_name: index_by_name,
             index_by_number: index_by_number,
+            file_descriptor: DescriptorCell::new(),
+            containing_type: DescriptorCell::new(),
         }
     }
 
+    /// All values of this enum in declaration order
+    pub fn values(&self) -> &[EnumValueDescriptor] {
+        &self.values
+    }
+
+    /// Find value by name, panics if there is no such value
     pub fn value_by_name<'a>(&'a self, name: &str) -> &'a EnumValueDescriptor {
-        // TODO: clone is weird
-        let &index = self.index_by_name.get(&name.to_string()).unwrap();
-        &self.values[index]
+        match self.get_value_by_name(name) {
+            Some(v) => v,
+            None => panic!("enum `{}` has no value `{}`", self.full_name, name),
+        }
     }
 
+    /// Find value by number, panics if there is no such value
     pub fn value_by_number<'a>(&'a self, number: i32) -> &'a EnumValueDescriptor {
-        let &index = self.index_by_number.get(&number).unwrap();
-        &self.values[index]
+        match self.get_value_by_number(number) {
+            Some(v) => v,
+            None => panic!("enum `{}` has no value {}", self.full_name, number),
+        }
+    }
+
+    pub fn get_value_by_name<'a>(&'a self, name: &str) -> Option<&'a EnumValueDescriptor> {
+        self.index_by_name.get(name).map(|&index| &self.values[index])
+    }
+
+    pub fn get_value_by_number<'a>(&'a self, number: i32) -> Option<&'a EnumValueDescriptor> {
+        self.index_by_number.get(&number).map(|&index| &self.values[index])
     }
 }
 
@@ -299,3 +878,218 @@ pub enum ReflectFieldRef<'a> {
     Map(&'a ReflectMap),
     Optional(Option<ProtobufValueRef<'a>>),
 }
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use descriptor::FieldDescriptorProto_Type;
+    use descriptor::FileDescriptorProto;
+    use well_known_types::Struct;
+    use well_known_types::Value;
+
+    #[test]
+    fn test_mut_singular_and_repeated() {
+        let mut file = FileDescriptorProto::new();
+        let d = file.descriptor();
+
+        d.field_by_name("name").set_singular_field(&mut file, "a.proto".into());
+        assert_eq!("a.proto", file.get_name());
+        d.field_by_name("name").clear_field(&mut file);
+        assert!(!file.has_name());
+
+        {
+            let options = d.field_by_name("options").mut_message(&mut file);
+            options
+                .descriptor()
+                .field_by_name("java_package")
+                .set_singular_field(options, "foo".into());
+        }
+        assert_eq!("foo", file.get_options().get_java_package());
+
+        {
+            let field = d.field_by_name("public_dependency");
+            let mut repeated = field.mut_repeated(&mut file);
+            repeated.push(ReflectValueBox::I32(3));
+            repeated.push(ReflectValueBox::I32(4));
+            assert_eq!(2, repeated.len());
+        }
+        assert_eq!(&[3, 4], file.get_public_dependency());
+
+        {
+            let field = d.field_by_name("message_type");
+            let mut repeated = field.mut_repeated(&mut file);
+            repeated.push(ReflectValueBox::Message(Box::new(::descriptor::DescriptorProto::new())));
+        }
+        assert_eq!(1, file.get_message_type().len());
+
+        let mut field = ::descriptor::FieldDescriptorProto::new();
+        let type_value = FieldDescriptorProto_Type::TYPE_BYTES.descriptor();
+        field.descriptor().field_by_name("type").set_singular_field(&mut field, type_value.into());
+        assert_eq!(FieldDescriptorProto_Type::TYPE_BYTES, field.get_field_type());
+    }
+
+    #[test]
+    fn test_get_enum() {
+        // proto2 optional enum field
+        let mut field = ::descriptor::FieldDescriptorProto::new();
+        let type_field = field.descriptor().field_by_name("type");
+        assert_eq!("TYPE_DOUBLE", type_field.get_enum(&field).name());
+        field.set_field_type(FieldDescriptorProto_Type::TYPE_BYTES);
+        assert_eq!("TYPE_BYTES", type_field.try_get_enum(&field).unwrap().name());
+
+        // proto2 `[default = SPEED]`
+        let options = ::descriptor::FileOptions::new();
+        let optimize_for = options.descriptor().field_by_name("optimize_for");
+        assert_eq!("SPEED", optimize_for.get_enum(&options).name());
+
+        // proto3 enum field
+        let mut f = ::well_known_types::Field::new();
+        let kind = f.descriptor().field_by_name("kind");
+        assert_eq!("TYPE_UNKNOWN", kind.get_enum(&f).name());
+        f.set_kind(::well_known_types::Field_Kind::TYPE_STRING);
+        assert_eq!("TYPE_STRING", kind.try_get_enum(&f).unwrap().name());
+
+        match f.descriptor().field_by_name("name").try_get_enum(&f) {
+            Err(ReflectError::WrongFieldType { .. }) => {}
+            r => panic!("{:?}", r.map(|v| v.name())),
+        }
+    }
+
+    #[test]
+    fn test_mut_map_and_oneof() {
+        let mut s = Struct::new();
+        {
+            let field = s.descriptor().field_by_name("fields");
+            let mut map = field.mut_map(&mut s);
+            let mut value = Value::new();
+            value.set_bool_value(true);
+            map.insert("a".into(), ReflectValueBox::Message(Box::new(value)));
+            map.insert("b".into(), ReflectValueBox::Message(Box::new(Value::new())));
+            assert!(map.remove("b".into()));
+            assert!(!map.remove("c".into()));
+            assert_eq!(1, map.len());
+        }
+        assert_eq!(true, s.get_fields()["a"].get_bool_value());
+
+        let mut value = Value::new();
+        let d = value.descriptor();
+        d.field_by_name("number_value").set_singular_field(&mut value, 1.5f64.into());
+        assert_eq!(1.5, value.get_number_value());
+
+        let kind = &d.oneofs()[0];
+        assert_eq!("kind", kind.name());
+        assert_eq!(6, kind.fields().len());
+        assert!(ptr::eq(kind, d.field_by_name("bool_value").containing_oneof().unwrap()));
+        assert_eq!("number_value", kind.which_field_is_set(&value).unwrap().name());
+        kind.clear(&mut value);
+        assert!(kind.which_field_is_set(&value).is_none());
+        d.field_by_name("number_value").set_singular_field(&mut value, 1.5f64.into());
+        {
+            let list = d.field_by_name("list_value").mut_message(&mut value);
+            list.descriptor()
+                .field_by_name("values")
+                .mut_repeated(list)
+                .push(ReflectValueBox::Message(Box::new(Value::new())));
+        }
+        assert_eq!(1, value.get_list_value().get_values().len());
+        d.field_by_name("list_value").clear_field(&mut value);
+        assert!(!value.has_list_value());
+    }
+
+    #[test]
+    fn test_nested_full_names() {
+        use descriptor::DescriptorProto_ExtensionRange;
+        use descriptor::FieldDescriptorProto_Type;
+
+        let d = MessageDescriptor::for_type::<DescriptorProto_ExtensionRange>();
+        assert_eq!("google.protobuf.DescriptorProto.ExtensionRange", d.full_name());
+        assert_eq!(
+            "google.protobuf.DescriptorProto.ExtensionRange.start",
+            d.field_by_name("start").full_name());
+        let e = EnumDescriptor::for_type::<FieldDescriptorProto_Type>();
+        assert_eq!("google.protobuf.FieldDescriptorProto.Type", e.full_name());
+    }
+
+    #[test]
+    fn test_generated_descriptors_not_navigable() {
+        let d = MessageDescriptor::for_type::<::descriptor::DescriptorProto>();
+        assert!(d.file_descriptor().is_none());
+        assert!(d.nested_messages().is_none());
+        assert!(d.nested_enums().is_none());
+        assert!(d.extensions().is_none());
+        assert!(d.field_by_name("name").containing_type().is_none());
+
+        let mut pool = DescriptorPool::new();
+        pool.add_generated_file(::descriptor::file_descriptor_proto()).unwrap();
+        let d = pool.find_message_by_full_name("google.protobuf.DescriptorProto").unwrap();
+        assert_eq!("google/protobuf/descriptor.proto", d.file_descriptor().unwrap().name());
+        assert_eq!(2, d.nested_messages().unwrap().len());
+        assert!(ptr::eq(d, d.nested_messages().unwrap()[0].containing_type().unwrap()));
+    }
+
+    #[test]
+    fn test_field_json_name_default_value_options() {
+        use descriptor::FileOptions;
+        use descriptor::FileOptions_OptimizeMode;
+
+        let d = MessageDescriptor::for_type::<FileOptions>();
+        let optimize_for = d.field_by_name("optimize_for");
+        assert_eq!("optimizeFor", optimize_for.json_name());
+        match optimize_for.default_value() {
+            Some(ReflectValueBox::Enum(v)) => {
+                assert_eq!(FileOptions_OptimizeMode::SPEED.value(), v.value())
+            }
+            _ => panic!("expecting enum"),
+        }
+        match d.field_by_name("java_package").default_value() {
+            Some(ReflectValueBox::String(ref s)) if s.is_empty() => {}
+            _ => panic!("expecting empty string"),
+        }
+        assert!(d.field_by_name("uninterpreted_option").default_value().is_none());
+
+        assert!(d.field_by_name("java_generate_equals_and_hash").options().get_deprecated());
+        assert!(!optimize_for.options().get_deprecated());
+    }
+
+    #[test]
+    fn test_lookups_and_errors() {
+        let mut file = FileDescriptorProto::new();
+        file.set_name("a.proto".to_owned());
+        let d = file.descriptor();
+        assert!(d.get_field_by_name("foo").is_none());
+        assert!(d.get_field_by_number(1000).is_none());
+        assert_eq!("name", d.get_field_by_number(1).unwrap().name());
+        let e = EnumDescriptor::for_type::<FieldDescriptorProto_Type>();
+        assert!(e.get_value_by_name("TYPE_FOO").is_none());
+        assert_eq!("TYPE_BOOL", e.get_value_by_number(8).unwrap().name());
+
+        let name = d.field_by_name("name");
+        assert_eq!("a.proto", name.try_get_str(&file).unwrap());
+        assert_eq!(
+            "field `google.protobuf.FileDescriptorProto.name` is not a singular i32 field",
+            name.try_get_i32(&file).unwrap_err().to_string());
+        assert!(d.field_by_name("dependency").try_get_str(&file).is_err());
+        assert!(d.field_by_name("options").try_get_message(&file).unwrap().is_none());
+        assert_eq!(
+            "field `google.protobuf.FileDescriptorProto.name` \
+             is not a field of message `google.protobuf.Struct`",
+            name.try_get_str(&Struct::new()).unwrap_err().to_string());
+
+        match name.try_set_singular_field(&mut file, ReflectValueBox::U32(1)) {
+            Err(ReflectError::WrongValueType { .. }) => {}
+            _ => panic!("expecting wrong value type"),
+        }
+        name.try_set_singular_field(&mut file, "b.proto".into()).unwrap();
+        assert_eq!("b.proto", file.get_name());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_set_wrong_type() {
+        let mut file = FileDescriptorProto::new();
+        let d = file.descriptor();
+        d.field_by_name("name").set_singular_field(&mut file, ReflectValueBox::U32(1));
+    }
+}
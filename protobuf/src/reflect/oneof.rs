@@ -0,0 +1,54 @@
+use core::Message;
+use descriptor::OneofDescriptorProto;
+
+use super::cell::DescriptorCell;
+use super::FieldDescriptor;
+use super::MessageDescriptor;
+
+
+/// Descriptor of a oneof: group of message fields at most one of which is set
+pub struct OneofDescriptor {
+    pub(super) proto: &'static OneofDescriptorProto,
+    pub(super) fields: Vec<&'static FieldDescriptor>,
+    pub(super) containing_type: DescriptorCell<MessageDescriptor>,
+}
+
+impl OneofDescriptor {
+    pub fn proto(&self) -> &'static OneofDescriptorProto {
+        self.proto
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.proto.get_name()
+    }
+
+    /// Message where this oneof is declared.
+    ///
+    /// `None` for oneofs of generated messages, which are not linked to their message;
+    /// set for descriptors created by `DescriptorPool`.
+    pub fn containing_type(&self) -> Option<&'static MessageDescriptor> {
+        self.containing_type.get()
+    }
+
+    /// Fields of this oneof in declaration order
+    pub fn fields(&self) -> &[&'static FieldDescriptor] {
+        &self.fields
+    }
+
+    /// Oneof generated by `protoc` for proto3 `optional` field
+    pub fn is_synthetic(&self) -> bool {
+        self.fields.len() == 1 && self.fields[0].proto().get_proto3_optional()
+    }
+
+    /// Field of this oneof which is set in given message
+    pub fn which_field_is_set(&self, m: &Message) -> Option<&'static FieldDescriptor> {
+        self.fields.iter().cloned().find(|f| f.has_field(m))
+    }
+
+    /// Unset field of this oneof which is set in given message
+    pub fn clear(&self, m: &mut Message) {
+        if let Some(field) = self.which_field_is_set(m) {
+            field.clear_field(m);
+        }
+    }
+}
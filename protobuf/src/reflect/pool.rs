@@ -0,0 +1,1035 @@
+//! Registry of descriptors of `.proto` files loaded at runtime.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt;
+use std::ptr;
+use std::sync::Mutex;
+
+use descriptor::DescriptorProto;
+use descriptor::EnumDescriptorProto;
+use descriptor::FieldDescriptorProto;
+use descriptor::FieldDescriptorProto_Label;
+use descriptor::FieldDescriptorProto_Type;
+use descriptor::FileDescriptorProto;
+use descriptor::FileDescriptorSet;
+use descriptor::ServiceDescriptorProto;
+use lazy::Lazy;
+use lazy::ONCE_INIT;
+
+use super::cell::DescriptorCell;
+use super::cell::leak;
+use super::dynamic::new_extension_descriptor;
+use super::dynamic::new_message_descriptor;
+use super::dynamic::DynamicFieldShape;
+use super::dynamic::DynamicType;
+use super::EnumDescriptor;
+use super::FieldDescriptor;
+use super::FileDescriptor;
+use super::MessageDescriptor;
+use super::MethodDescriptor;
+use super::ServiceDescriptor;
+
+
+/// Error building descriptors from `FileDescriptorProto`s
+#[derive(Debug)]
+pub struct DescriptorError {
+    message: String,
+}
+
+impl DescriptorError {
+    fn new(message: String) -> DescriptorError {
+        DescriptorError { message: message }
+    }
+}
+
+impl fmt::Display for DescriptorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl Error for DescriptorError {
+    fn description(&self) -> &str {
+        &self.message
+    }
+}
+
+type DescriptorResult<T> = Result<T, DescriptorError>;
+
+
+/// Message or enum which can be referenced from fields
+#[derive(Copy, Clone)]
+enum TypeEntry {
+    Message(&'static DescriptorCell<MessageDescriptor>),
+    Enum(&'static EnumDescriptor),
+}
+
+#[derive(Copy, Clone)]
+struct PoolType {
+    entry: TypeEntry,
+    /// Name of the file where the type is declared
+    file: &'static str,
+}
+
+
+/// Descriptors of `.proto` files, types declared in them, and links between them.
+///
+/// Files are added one by one after the files they import,
+/// type names are resolved following protobuf scoping rules.
+/// Messages of types added to the pool are represented by `DynamicMessage`.
+/// Extensions declared in the files are known only to the pool, pass the pool to
+/// `text_format::print_to_string_with_pool` or `parse_from_str_with_pool`
+/// to print and parse them.
+///
+/// Descriptors are never freed, so they outlive the pool. Adding a file equal to a file
+/// added to any pool before, with the same imports, reuses its descriptors,
+/// so loading the same files into new pools again and again does not take more memory.
+///
+/// Descriptors are immutable once built, so the pool and descriptors
+/// can be shared between threads.
+pub struct DescriptorPool {
+    files: HashMap<String, &'static FileDescriptor>,
+    types: HashMap<String, PoolType>,
+    extensions: HashMap<(String, u32), &'static FieldDescriptor>,
+    services: HashMap<String, &'static ServiceDescriptor>,
+}
+
+impl DescriptorPool {
+    pub fn new() -> DescriptorPool {
+        DescriptorPool {
+            files: HashMap::new(),
+            types: HashMap::new(),
+            extensions: HashMap::new(),
+            services: HashMap::new(),
+        }
+    }
+
+    /// Add a file to the pool.
+    ///
+    /// All files imported by this file must be added before.
+    /// Adding a file equal to already added file is a no-op.
+    pub fn add_file(
+        &mut self,
+        file: FileDescriptorProto,
+    ) -> Result<&'static FileDescriptor, DescriptorError> {
+        self.add_file_impl(FileProto::Owned(file))
+    }
+
+    /// Add a file of generated code, e. g. `descriptor::file_descriptor_proto()`.
+    pub fn add_generated_file(
+        &mut self,
+        file: &'static FileDescriptorProto,
+    ) -> Result<&'static FileDescriptor, DescriptorError> {
+        self.add_file_impl(FileProto::Static(file))
+    }
+
+    /// Add all files of `FileDescriptorSet`, e. g. produced by
+    /// `protoc --include_imports --descriptor_set_out`.
+    ///
+    /// Files may be listed in any order. Files added before an error
+    /// are left in the pool.
+    pub fn add_file_descriptor_set(&mut self, mut set: FileDescriptorSet) -> Result<(), DescriptorError> {
+        let mut pending = set.take_file().into_vec();
+        while !pending.is_empty() {
+            let ready = pending.iter().position(|f| {
+                f.get_dependency().iter().all(|d| self.files.contains_key(d))
+            });
+            match ready {
+                Some(i) => {
+                    let file = pending.remove(i);
+                    self.add_file_impl(FileProto::Owned(file))?;
+                }
+                // reports missing import
+                None => return self.add_file_impl(FileProto::Owned(pending.remove(0))).map(|_| ()),
+            }
+        }
+        Ok(())
+    }
+
+    /// Find file by name, e. g. `google/protobuf/any.proto`
+    pub fn find_file_by_name(&self, name: &str) -> Option<&'static FileDescriptor> {
+        self.files.get(name).map(|f| *f)
+    }
+
+    /// Find message by full name without leading dot, e. g. `google.protobuf.Empty`
+    pub fn find_message_by_full_name(&self, full_name: &str) -> Option<&'static MessageDescriptor> {
+        match self.types.get(full_name) {
+            Some(&PoolType { entry: TypeEntry::Message(cell), .. }) => cell.get(),
+            _ => None,
+        }
+    }
+
+    /// Find enum by full name without leading dot
+    pub fn find_enum_by_full_name(&self, full_name: &str) -> Option<&'static EnumDescriptor> {
+        match self.types.get(full_name) {
+            Some(&PoolType { entry: TypeEntry::Enum(e), .. }) => Some(e),
+            _ => None,
+        }
+    }
+
+    /// Find extension of given message by field number
+    pub fn find_extension_by_number(
+        &self,
+        extendee_full_name: &str,
+        number: u32,
+    ) -> Option<&'static FieldDescriptor> {
+        self.extensions.get(&(extendee_full_name.to_owned(), number)).map(|e| *e)
+    }
+
+    /// Find extension by full name without leading dot, e. g. `rustproto.expose_oneof_all`
+    pub fn find_extension_by_full_name(&self, full_name: &str) -> Option<&'static FieldDescriptor> {
+        self.extensions.values().map(|e| *e).find(|e| e.full_name() == full_name)
+    }
+
+    /// Extensions of given message ordered by field number
+    pub fn find_extensions_by_extendee(
+        &self,
+        extendee_full_name: &str,
+    ) -> Vec<&'static FieldDescriptor> {
+        let mut r: Vec<_> = self.extensions.iter()
+            .filter(|&(&(ref extendee, _), _)| extendee == extendee_full_name)
+            .map(|(&(_, number), e)| (number, *e))
+            .collect();
+        r.sort_by_key(|&(number, _)| number);
+        r.into_iter().map(|(_, e)| e).collect()
+    }
+
+    /// Find service by full name without leading dot
+    pub fn find_service(&self, full_name: &str) -> Option<&'static ServiceDescriptor> {
+        self.services.get(full_name).map(|s| *s)
+    }
+
+    fn add_file_impl(&mut self, proto: FileProto) -> DescriptorResult<&'static FileDescriptor> {
+        let dependencies = {
+            let proto = proto.get();
+            if let Some(&existing) = self.files.get(proto.get_name()) {
+                if existing.proto() == proto {
+                    return Ok(existing);
+                }
+                return Err(DescriptorError::new(
+                    format!("file `{}` is already added", proto.get_name()),
+                ));
+            }
+
+            let mut dependencies = Vec::new();
+            for name in proto.get_dependency() {
+                match self.files.get(name) {
+                    Some(&file) => dependencies.push(file),
+                    None => {
+                        return Err(DescriptorError::new(format!(
+                            "file `{}` imports `{}` which is not added",
+                            proto.get_name(),
+                            name
+                        )))
+                    }
+                }
+            }
+            for &index in proto.get_public_dependency() {
+                if index < 0 || index as usize >= dependencies.len() {
+                    return Err(DescriptorError::new(format!(
+                        "file `{}`: public dependency index {} is out of range",
+                        proto.get_name(),
+                        index
+                    )));
+                }
+            }
+
+            dependencies
+        };
+
+        // lock is held while building, so the same file is not built twice
+        let mut built_files = built_files().lock().unwrap();
+        if let Some(built) = find_built_file(&built_files, proto.get(), &dependencies) {
+            self.check_built_file(built)?;
+            self.insert_built_file(built);
+            return Ok(built.file);
+        }
+
+        let built = self.build_file(proto.into_static(), dependencies)?;
+        built_files
+            .entry(built.file.name().to_owned())
+            .or_insert_with(Vec::new)
+            .push(built);
+        self.insert_built_file(built);
+        Ok(built.file)
+    }
+
+    fn build_file(
+        &self,
+        proto: &'static FileDescriptorProto,
+        dependencies: Vec<&'static FileDescriptor>,
+    ) -> DescriptorResult<&'static BuiltFile> {
+        let mut visible_files = HashSet::new();
+        for &file in &dependencies {
+            add_visible_file(file, &mut visible_files);
+        }
+
+        let mut builder = FileBuilder {
+            pool: self,
+            proto: proto,
+            proto3: proto.get_syntax() == "proto3",
+            visible_files: visible_files,
+            messages: Vec::new(),
+            enums: Vec::new(),
+            types: HashMap::new(),
+        };
+        let file = builder.build(dependencies)?;
+        let extensions = builder.link_extensions(file)?;
+        Ok(leak(BuiltFile {
+            file: file,
+            messages: builder.messages.into_iter().map(|m| (m.full_name, m.cell)).collect(),
+            enums: builder.enums.into_iter().map(|e| e.descriptor).collect(),
+            extensions: extensions,
+        }))
+    }
+
+    /// Check that names declared in the file built for other pool are not used in this pool
+    fn check_built_file(&self, built: &BuiltFile) -> DescriptorResult<()> {
+        let message_names = built.messages.iter().map(|&(ref name, _)| &name[..]);
+        let enum_names = built.enums.iter().map(|e| e.full_name());
+        for full_name in message_names.chain(enum_names) {
+            if let Some(t) = self.types.get(full_name) {
+                return Err(DescriptorError::new(
+                    format!("type `{}` is already declared in `{}`", full_name, t.file),
+                ));
+            }
+        }
+        for &(ref key, _) in &built.extensions {
+            if self.extensions.contains_key(key) {
+                return Err(DescriptorError::new(format!(
+                    "extension number {} of `{}` is already used",
+                    key.1,
+                    key.0
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    fn insert_built_file(&mut self, built: &'static BuiltFile) {
+        let file = built.file;
+        for &(ref full_name, cell) in &built.messages {
+            self.types.insert(full_name.clone(), PoolType {
+                entry: TypeEntry::Message(cell),
+                file: file.name(),
+            });
+        }
+        for &e in &built.enums {
+            self.types.insert(e.full_name().to_owned(), PoolType {
+                entry: TypeEntry::Enum(e),
+                file: file.name(),
+            });
+        }
+        for &(ref key, extension) in &built.extensions {
+            self.extensions.insert(key.clone(), extension);
+        }
+        for service in file.services() {
+            self.services.insert(service.full_name().to_owned(), service);
+        }
+        self.files.insert(file.name().to_owned(), file);
+    }
+}
+
+/// File passed to the pool, leaked only if its descriptors are built
+enum FileProto {
+    Owned(FileDescriptorProto),
+    Static(&'static FileDescriptorProto),
+}
+
+impl FileProto {
+    fn get(&self) -> &FileDescriptorProto {
+        match *self {
+            FileProto::Owned(ref proto) => proto,
+            FileProto::Static(proto) => proto,
+        }
+    }
+
+    fn into_static(self) -> &'static FileDescriptorProto {
+        match self {
+            FileProto::Owned(proto) => leak(proto),
+            FileProto::Static(proto) => proto,
+        }
+    }
+}
+
+/// Descriptors of a file and everything a pool needs to register them
+struct BuiltFile {
+    file: &'static FileDescriptor,
+    messages: Vec<(String, &'static DescriptorCell<MessageDescriptor>)>,
+    enums: Vec<&'static EnumDescriptor>,
+    extensions: Vec<((String, u32), &'static FieldDescriptor)>,
+}
+
+type BuiltFiles = HashMap<String, Vec<&'static BuiltFile>>;
+
+/// Files built for all pools by file name
+static mut BUILT_FILES: Lazy<Mutex<BuiltFiles>> = Lazy {
+    lock: ONCE_INIT,
+    ptr: 0 as *const Mutex<BuiltFiles>,
+};
+
+fn built_files() -> &'static Mutex<BuiltFiles> {
+    unsafe { BUILT_FILES.get(|| Mutex::new(HashMap::new())) }
+}
+
+/// Find file built before from the same proto with the same imports.
+/// Descriptors of such file are exactly the descriptors which would be built now.
+fn find_built_file(
+    files: &BuiltFiles,
+    proto: &FileDescriptorProto,
+    dependencies: &[&'static FileDescriptor],
+) -> Option<&'static BuiltFile> {
+    let candidates = match files.get(proto.get_name()) {
+        Some(candidates) => candidates,
+        None => return None,
+    };
+    candidates.iter().map(|b| *b).find(|b| {
+        b.file.proto() == proto &&
+            b.file.dependencies().len() == dependencies.len() &&
+            b.file.dependencies().iter().zip(dependencies).all(|(a, b)| ptr::eq(*a, *b))
+    })
+}
+
+/// Types of a file are visible in files which import it, and
+/// in files which import files which publicly import it.
+fn add_visible_file(file: &'static FileDescriptor, visible_files: &mut HashSet<&'static str>) {
+    if !visible_files.insert(file.name()) {
+        return;
+    }
+    for &index in file.proto().get_public_dependency() {
+        add_visible_file(file.dependencies()[index as usize], visible_files);
+    }
+}
+
+fn concat_name(scope: &str, name: &str) -> String {
+    if scope.is_empty() {
+        name.to_owned()
+    } else {
+        format!("{}.{}", scope, name)
+    }
+}
+
+
+struct MessageEntry {
+    full_name: String,
+    proto: &'static DescriptorProto,
+    cell: &'static DescriptorCell<MessageDescriptor>,
+    /// Index of containing message
+    parent: Option<usize>,
+}
+
+struct EnumEntry {
+    descriptor: &'static EnumDescriptor,
+    parent: Option<usize>,
+}
+
+/// Builds descriptors of one file
+struct FileBuilder<'a> {
+    pool: &'a DescriptorPool,
+    proto: &'static FileDescriptorProto,
+    proto3: bool,
+    /// Files other than this one whose types can be referenced
+    visible_files: HashSet<&'static str>,
+    /// Messages declared in this file, containing messages first
+    messages: Vec<MessageEntry>,
+    enums: Vec<EnumEntry>,
+    types: HashMap<String, TypeEntry>,
+}
+
+impl<'a> FileBuilder<'a> {
+    fn add_type(&mut self, full_name: &str, entry: TypeEntry) -> DescriptorResult<()> {
+        if let Some(t) = self.pool.types.get(full_name) {
+            return Err(DescriptorError::new(
+                format!("type `{}` is already declared in `{}`", full_name, t.file),
+            ));
+        }
+        if self.types.contains_key(full_name) {
+            return Err(DescriptorError::new(format!("duplicate type `{}`", full_name)));
+        }
+        self.types.insert(full_name.to_owned(), entry);
+        Ok(())
+    }
+
+    fn add_enums(
+        &mut self,
+        scope: &str,
+        protos: &'static [EnumDescriptorProto],
+        parent: Option<usize>,
+    ) -> DescriptorResult<()> {
+        for proto in protos {
+            let full_name = concat_name(scope, proto.get_name());
+            let descriptor = leak(EnumDescriptor::new_dynamic(full_name.clone(), proto));
+            self.add_type(&full_name, TypeEntry::Enum(descriptor))?;
+            self.enums.push(EnumEntry {
+                descriptor: descriptor,
+                parent: parent,
+            });
+        }
+        Ok(())
+    }
+
+    fn add_messages(
+        &mut self,
+        scope: &str,
+        protos: &'static [DescriptorProto],
+        parent: Option<usize>,
+    ) -> DescriptorResult<()> {
+        for proto in protos {
+            let full_name = concat_name(scope, proto.get_name());
+            let cell = leak(DescriptorCell::new());
+            self.add_type(&full_name, TypeEntry::Message(cell))?;
+            let index = self.messages.len();
+            self.messages.push(MessageEntry {
+                full_name: full_name.clone(),
+                proto: proto,
+                cell: cell,
+                parent: parent,
+            });
+            self.add_messages(&full_name, proto.get_nested_type(), Some(index))?;
+            self.add_enums(&full_name, proto.get_enum_type(), Some(index))?;
+        }
+        Ok(())
+    }
+
+    fn lookup(&self, full_name: &str) -> Option<TypeEntry> {
+        if let Some(&entry) = self.types.get(full_name) {
+            return Some(entry);
+        }
+        match self.pool.types.get(full_name) {
+            Some(t) if self.visible_files.contains(t.file) => Some(t.entry),
+            _ => None,
+        }
+    }
+
+    /// Find message or enum referenced from given scope
+    /// following protobuf scoping rules: innermost scope first.
+    fn resolve(&self, scope: &str, type_name: &str) -> Option<(String, TypeEntry)> {
+        if type_name.starts_with('.') {
+            let full_name = &type_name[1..];
+            return self.lookup(full_name).map(|e| (full_name.to_owned(), e));
+        }
+
+        let mut scope = scope;
+        loop {
+            let candidate = concat_name(scope, type_name);
+            if let Some(entry) = self.lookup(&candidate) {
+                return Some((candidate, entry));
+            }
+            if scope.is_empty() {
+                return None;
+            }
+            scope = match scope.rfind('.') {
+                Some(pos) => &scope[..pos],
+                None => "",
+            };
+        }
+    }
+
+    fn resolve_message(
+        &self,
+        scope: &str,
+        type_name: &str,
+    ) -> DescriptorResult<&'static DescriptorCell<MessageDescriptor>> {
+        match self.resolve(scope, type_name) {
+            Some((_, TypeEntry::Message(cell))) => Ok(cell),
+            Some((full_name, TypeEntry::Enum(..))) => {
+                Err(DescriptorError::new(format!("`{}` is not a message", full_name)))
+            }
+            None => Err(DescriptorError::new(
+                format!("`{}`: message `{}` not found", scope, type_name),
+            )),
+        }
+    }
+
+    fn field_type(&self, scope: &str, field: &FieldDescriptorProto) -> DescriptorResult<DynamicType> {
+        let mut t = DynamicType {
+            proto_type: field.get_field_type(),
+            enum_descriptor: None,
+            message_descriptor: None,
+        };
+        if !field.has_type_name() {
+            return match t.proto_type {
+                FieldDescriptorProto_Type::TYPE_ENUM |
+                FieldDescriptorProto_Type::TYPE_MESSAGE |
+                FieldDescriptorProto_Type::TYPE_GROUP => {
+                    Err(DescriptorError::new(
+                        format!("field `{}.{}` has no type name", scope, field.get_name()),
+                    ))
+                }
+                _ => Ok(t),
+            };
+        }
+
+        let (full_name, entry) = match self.resolve(scope, field.get_type_name()) {
+            Some(r) => r,
+            None => {
+                return Err(DescriptorError::new(format!(
+                    "field `{}.{}`: type `{}` not found",
+                    scope,
+                    field.get_name(),
+                    field.get_type_name()
+                )));
+            }
+        };
+        match entry {
+            TypeEntry::Enum(e) => {
+                t.enum_descriptor = Some(e);
+                if !field.has_field_type() {
+                    t.proto_type = FieldDescriptorProto_Type::TYPE_ENUM;
+                }
+            }
+            TypeEntry::Message(cell) => {
+                t.message_descriptor = Some(cell);
+                if !field.has_field_type() {
+                    t.proto_type = FieldDescriptorProto_Type::TYPE_MESSAGE;
+                }
+            }
+        }
+        match (t.proto_type, t.enum_descriptor.is_some()) {
+            (FieldDescriptorProto_Type::TYPE_ENUM, true) |
+            (FieldDescriptorProto_Type::TYPE_MESSAGE, false) |
+            (FieldDescriptorProto_Type::TYPE_GROUP, false) => Ok(t),
+            _ => Err(DescriptorError::new(format!(
+                "field `{}.{}`: type `{}` does not match field type",
+                scope,
+                field.get_name(),
+                full_name
+            ))),
+        }
+    }
+
+    /// Full name and proto of message referenced by the cell
+    fn message_proto(
+        &self,
+        cell: &'static DescriptorCell<MessageDescriptor>,
+    ) -> (String, &'static DescriptorProto) {
+        match self.messages.iter().find(|m| ptr::eq(m.cell, cell)) {
+            Some(m) => (m.full_name.clone(), m.proto),
+            None => {
+                // declared in other file, so already built
+                let d = cell.get().unwrap();
+                (d.full_name().to_owned(), d.proto())
+            }
+        }
+    }
+
+    fn field_shape(&self, scope: &str, field: &FieldDescriptorProto) -> DescriptorResult<DynamicFieldShape> {
+        let t = self.field_type(scope, field)?;
+        if field.get_label() != FieldDescriptorProto_Label::LABEL_REPEATED ||
+            t.proto_type != FieldDescriptorProto_Type::TYPE_MESSAGE
+        {
+            return Ok(match field.get_label() {
+                FieldDescriptorProto_Label::LABEL_REPEATED => DynamicFieldShape::Repeated(t),
+                _ => DynamicFieldShape::Singular(t),
+            });
+        }
+
+        let (entry_name, entry) = self.message_proto(t.message_descriptor.unwrap());
+        if !entry.get_options().get_map_entry() {
+            return Ok(DynamicFieldShape::Repeated(t));
+        }
+        let entry_field = |number| {
+            match entry.get_field().iter().find(|f| f.get_number() == number) {
+                Some(f) => self.field_type(&entry_name, f),
+                None => Err(DescriptorError::new(
+                    format!("map entry `{}` has no field {}", entry_name, number),
+                )),
+            }
+        };
+        Ok(DynamicFieldShape::Map(entry_field(1)?, entry_field(2)?))
+    }
+
+    fn extension(&self, scope: &str, proto: &'static FieldDescriptorProto) -> DescriptorResult<FieldDescriptor> {
+        let shape = self.field_shape(scope, proto)?;
+        if let DynamicFieldShape::Map(..) = shape {
+            return Err(DescriptorError::new(
+                format!("extension `{}.{}` cannot be a map", scope, proto.get_name()),
+            ));
+        }
+        Ok(new_extension_descriptor(concat_name(scope, proto.get_name()), proto, shape))
+    }
+
+    /// Build descriptor of message, nested messages must be already built
+    fn build_message(&self, index: usize) -> DescriptorResult<()> {
+        let entry = &self.messages[index];
+        let mut shapes = Vec::new();
+        for f in entry.proto.get_field() {
+            shapes.push(self.field_shape(&entry.full_name, f)?);
+        }
+
+        let mut descriptor = new_message_descriptor(
+            entry.full_name.clone(),
+            entry.proto,
+            self.proto3,
+            shapes,
+            entry.cell,
+        );
+        descriptor.nested_messages = self.messages
+            .iter()
+            .filter(|m| m.parent == Some(index))
+            .map(|m| m.cell.get().unwrap())
+            .collect();
+        descriptor.nested_enums = self.enums
+            .iter()
+            .filter(|e| e.parent == Some(index))
+            .map(|e| e.descriptor)
+            .collect();
+        for f in entry.proto.get_extension() {
+            descriptor.extensions.push(self.extension(&entry.full_name, f)?);
+        }
+
+        let descriptor = leak(descriptor);
+        for field in descriptor.fields() {
+            field.containing_type.set(descriptor);
+        }
+        for oneof in descriptor.oneofs() {
+            oneof.containing_type.set(descriptor);
+        }
+        entry.cell.set(descriptor);
+        Ok(())
+    }
+
+    fn build_service(&self, proto: &'static ServiceDescriptorProto) -> DescriptorResult<ServiceDescriptor> {
+        let full_name = concat_name(self.proto.get_package(), proto.get_name());
+        let mut methods = Vec::new();
+        for method in proto.get_method() {
+            methods.push(MethodDescriptor {
+                proto: method,
+                input_type: self.resolve_message(&full_name, method.get_input_type())?,
+                output_type: self.resolve_message(&full_name, method.get_output_type())?,
+            });
+        }
+        Ok(ServiceDescriptor {
+            proto: proto,
+            full_name: full_name,
+            methods: methods,
+            file_descriptor: DescriptorCell::new(),
+        })
+    }
+
+    /// Build descriptors of all types and services declared in the file
+    fn build(
+        &mut self,
+        dependencies: Vec<&'static FileDescriptor>,
+    ) -> DescriptorResult<&'static FileDescriptor> {
+        let package = self.proto.get_package();
+        self.add_messages(package, self.proto.get_message_type(), None)?;
+        self.add_enums(package, self.proto.get_enum_type(), None)?;
+
+        // nested messages first
+        for i in (0..self.messages.len()).rev() {
+            self.build_message(i)?;
+        }
+
+        let mut extensions = Vec::new();
+        for f in self.proto.get_extension() {
+            extensions.push(self.extension(package, f)?);
+        }
+        let mut services = Vec::new();
+        for s in self.proto.get_service() {
+            services.push(self.build_service(s)?);
+        }
+
+        let file = leak(FileDescriptor {
+            proto: self.proto,
+            dependencies: dependencies,
+            messages: self.messages
+                .iter()
+                .filter(|m| m.parent.is_none())
+                .map(|m| m.cell.get().unwrap())
+                .collect(),
+            enums: self.enums
+                .iter()
+                .filter(|e| e.parent.is_none())
+                .map(|e| e.descriptor)
+                .collect(),
+            services: services,
+            extensions: extensions,
+        });
+
+        for m in &self.messages {
+            let descriptor = m.cell.get().unwrap();
+            descriptor.file_descriptor.set(file);
+            if let Some(parent) = m.parent {
+                descriptor.containing_type.set(self.messages[parent].cell.get().unwrap());
+            }
+        }
+        for e in &self.enums {
+            e.descriptor.file_descriptor.set(file);
+            if let Some(parent) = e.parent {
+                e.descriptor.containing_type.set(self.messages[parent].cell.get().unwrap());
+            }
+        }
+        for service in file.services() {
+            service.file_descriptor.set(file);
+        }
+        Ok(file)
+    }
+
+    /// Resolve extended messages of all extensions declared in the file
+    fn link_extensions(
+        &self,
+        file: &'static FileDescriptor,
+    ) -> DescriptorResult<Vec<((String, u32), &'static FieldDescriptor)>> {
+        let mut extensions = Vec::new();
+        for extension in file.extensions() {
+            extensions.push((file.package().to_owned(), extension));
+        }
+        for m in &self.messages {
+            for extension in &m.cell.get().unwrap().extensions {
+                extensions.push((m.full_name.clone(), extension));
+            }
+        }
+
+        let mut result = Vec::new();
+        let mut numbers = HashSet::new();
+        for (scope, extension) in extensions {
+            let proto = extension.proto();
+            let extendee = self.resolve_message(&scope, proto.get_extendee())?;
+            let (extendee_name, extendee_proto) = self.message_proto(extendee);
+            let number = proto.get_number();
+            let in_range = extendee_proto
+                .get_extension_range()
+                .iter()
+                .any(|r| number >= r.get_start() && number < r.get_end());
+            if !in_range {
+                return Err(DescriptorError::new(format!(
+                    "extension `{}.{}`: {} is not an extension number of `{}`",
+                    scope,
+                    proto.get_name(),
+                    number,
+                    extendee_name
+                )));
+            }
+
+            let key = (extendee_name, number as u32);
+            if self.pool.extensions.contains_key(&key) || !numbers.insert(key.clone()) {
+                return Err(DescriptorError::new(format!(
+                    "extension number {} of `{}` is already used",
+                    key.1,
+                    key.0
+                )));
+            }
+            extension.containing_type.set(extendee.get().unwrap());
+            result.push((key, extension));
+        }
+        Ok(result)
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use descriptor;
+    use reflect::RuntimeFieldType;
+    use reflect::RuntimeType;
+    use text_format;
+
+    fn parse_file(text: &str) -> FileDescriptorProto {
+        text_format::parse_from_str(text).unwrap()
+    }
+
+    const BASE_FILE: &'static str = r#"
+        name: "base.proto" package: "base"
+        message_type {
+            name: "Outer"
+            field { name: "inner" number: 1 label: LABEL_OPTIONAL type: TYPE_MESSAGE type_name: "Inner" }
+            field { name: "a" number: 2 label: LABEL_OPTIONAL type: TYPE_STRING oneof_index: 0 }
+            field { name: "b" number: 3 label: LABEL_OPTIONAL type: TYPE_INT32 oneof_index: 0 }
+            nested_type { name: "Inner" enum_type { name: "Kind" value { name: "K" number: 0 } } }
+            oneof_decl { name: "choice" }
+            extension_range { start: 100 end: 200 }
+        }
+    "#;
+
+    const USER_FILE: &'static str = r#"
+        name: "user.proto" package: "user" dependency: "base.proto"
+        message_type {
+            name: "User"
+            field { name: "outer" number: 1 label: LABEL_OPTIONAL type: TYPE_MESSAGE type_name: ".base.Outer" }
+            field { name: "kind" number: 2 label: LABEL_OPTIONAL type: TYPE_ENUM type_name: "base.Outer.Inner.Kind" }
+        }
+        extension { name: "tag" number: 100 label: LABEL_OPTIONAL type: TYPE_STRING extendee: ".base.Outer" }
+        service {
+            name: "Users"
+            method { name: "Get" input_type: ".base.Outer" output_type: ".user.User" server_streaming: true }
+        }
+    "#;
+
+    #[test]
+    fn test_cross_file() {
+        let mut pool = DescriptorPool::new();
+        pool.add_file(parse_file(BASE_FILE)).unwrap();
+        let file = pool.add_file(parse_file(USER_FILE)).unwrap();
+
+        let user = pool.find_message_by_full_name("user.User").unwrap();
+        let outer = pool.find_message_by_full_name("base.Outer").unwrap();
+        assert!(ptr::eq(file, user.file_descriptor().unwrap()));
+        assert_eq!("base.proto", file.dependencies()[0].name());
+        assert!(ptr::eq(user, user.field_by_name("outer").containing_type().unwrap()));
+
+        let service = pool.find_service("user.Users").unwrap();
+        let method = service.method_by_name("Get").unwrap();
+        assert!(ptr::eq(outer, method.input_type()));
+        assert!(ptr::eq(user, method.output_type()));
+        assert!(method.is_server_streaming());
+        assert!(ptr::eq(file, service.file_descriptor()));
+
+        let tag = pool.find_extension_by_number("base.Outer", 100).unwrap();
+        assert_eq!("tag", tag.name());
+        assert!(ptr::eq(outer, tag.containing_type().unwrap()));
+        assert!(pool.find_extension_by_number("base.Outer", 101).is_none());
+    }
+
+    #[test]
+    fn test_navigation() {
+        let mut pool = DescriptorPool::new();
+        let file = pool.add_file(parse_file(BASE_FILE)).unwrap();
+
+        let outer = file.messages()[0];
+        assert_eq!("base.Outer", outer.full_name());
+        assert!(outer.containing_type().is_none());
+
+        let inner = outer.nested_messages().unwrap()[0];
+        assert_eq!("base.Outer.Inner", inner.full_name());
+        assert!(ptr::eq(outer, inner.containing_type().unwrap()));
+        assert!(ptr::eq(file, inner.file_descriptor().unwrap()));
+
+        let kind = pool.find_enum_by_full_name("base.Outer.Inner.Kind").unwrap();
+        assert!(ptr::eq(kind, inner.nested_enums().unwrap()[0]));
+        assert!(ptr::eq(inner, kind.containing_type().unwrap()));
+
+        let oneof = &outer.oneofs()[0];
+        assert_eq!("choice", oneof.name());
+        assert!(ptr::eq(outer, oneof.containing_type().unwrap()));
+        let names: Vec<_> = oneof.fields().iter().map(|f| f.name()).collect();
+        assert_eq!(vec!["a", "b"], names);
+    }
+
+    #[test]
+    fn test_missing_import() {
+        let mut pool = DescriptorPool::new();
+        let err = pool.add_file(parse_file(USER_FILE)).err().unwrap();
+        assert_eq!("file `user.proto` imports `base.proto` which is not added", err.to_string());
+        assert!(pool.find_file_by_name("user.proto").is_none());
+    }
+
+    #[test]
+    fn test_types_of_indirect_imports_not_visible() {
+        let mut pool = DescriptorPool::new();
+        pool.add_file(parse_file(BASE_FILE)).unwrap();
+        pool.add_file(parse_file(USER_FILE)).unwrap();
+        let file = parse_file(r#"
+            name: "other.proto" dependency: "user.proto"
+            message_type {
+                name: "M"
+                field { name: "f" number: 1 label: LABEL_OPTIONAL type: TYPE_MESSAGE type_name: ".base.Outer" }
+            }
+        "#);
+        assert!(pool.add_file(file).is_err());
+    }
+
+    #[test]
+    fn test_file_descriptor_set() {
+        let mut set = FileDescriptorSet::new();
+        set.mut_file().push(parse_file(USER_FILE));
+        set.mut_file().push(parse_file(BASE_FILE));
+        set.mut_file().push(descriptor::file_descriptor_proto().clone());
+
+        let mut pool = DescriptorPool::new();
+        pool.add_generated_file(descriptor::file_descriptor_proto()).unwrap();
+        pool.add_file_descriptor_set(set).unwrap();
+        assert!(pool.find_message_by_full_name("user.User").is_some());
+        assert!(pool.find_message_by_full_name("google.protobuf.FileDescriptorSet").is_some());
+    }
+
+    #[test]
+    fn test_equal_files_reuse_descriptors() {
+        let mut a = DescriptorPool::new();
+        let a_base = a.add_file(parse_file(BASE_FILE)).unwrap();
+        let a_user = a.add_file(parse_file(USER_FILE)).unwrap();
+        let mut b = DescriptorPool::new();
+        assert!(ptr::eq(a_base, b.add_file(parse_file(BASE_FILE)).unwrap()));
+        assert!(ptr::eq(a_user, b.add_file(parse_file(USER_FILE)).unwrap()));
+        assert!(ptr::eq(
+            a.find_message_by_full_name("base.Outer.Inner").unwrap(),
+            b.find_message_by_full_name("base.Outer.Inner").unwrap(),
+        ));
+        assert!(b.find_extension_by_number("base.Outer", 100).is_some());
+
+        // same file importing different descriptors is built again
+        let mut c = DescriptorPool::new();
+        let base = parse_file(&format!("{} message_type {{ name: \"Other\" }}", BASE_FILE));
+        c.add_file(base).unwrap();
+        let c_user = c.add_file(parse_file(USER_FILE)).unwrap();
+        assert!(!ptr::eq(a_user, c_user));
+        let outer = c_user.messages()[0].field_by_name("outer");
+        match outer.runtime_field_type() {
+            RuntimeFieldType::Singular(RuntimeType::Message(d)) => {
+                assert!(ptr::eq(c.find_message_by_full_name("base.Outer").unwrap(), d));
+            }
+            _ => panic!("wrong type"),
+        }
+    }
+
+    #[test]
+    fn test_share_between_threads() {
+        use std::thread;
+
+        let mut pool = DescriptorPool::new();
+        let base = pool.add_file(parse_file(BASE_FILE)).unwrap();
+        let outer = pool.find_message_by_full_name("base.Outer").unwrap();
+
+        let other = thread::spawn(move || {
+            // file built on another thread is reused
+            let mut other = DescriptorPool::new();
+            assert!(ptr::eq(base, other.add_file(parse_file(BASE_FILE)).unwrap()));
+            let mut m = outer.new_instance();
+            text_format::merge_from_str(&mut *m, "a: 'x'").unwrap();
+            (other, m)
+        });
+        let (other, m) = other.join().unwrap();
+        assert_eq!("a: \"x\"", text_format::print_to_string(&*m));
+        assert!(ptr::eq(outer, other.find_message_by_full_name("base.Outer").unwrap()));
+    }
+
+    #[test]
+    fn test_reused_file_conflicts_with_pool() {
+        let mut a = DescriptorPool::new();
+        a.add_file(parse_file(BASE_FILE)).unwrap();
+        let mut b = DescriptorPool::new();
+        b.add_file(parse_file(r#"name: "other.proto" package: "base" message_type { name: "Outer" }"#))
+            .unwrap();
+        let err = b.add_file(parse_file(BASE_FILE)).err().unwrap();
+        assert_eq!("type `base.Outer` is already declared in `other.proto`", err.to_string());
+    }
+
+    #[test]
+    fn test_pools_extensions_do_not_interfere() {
+        let ext = |t: &str| parse_file(&format!(r#"
+            name: "ext.proto" package: "ext" dependency: "base.proto"
+            extension {{ name: "tag" number: 100 label: LABEL_OPTIONAL type: {} extendee: ".base.Outer" }}
+        "#, t));
+        let mut strings = DescriptorPool::new();
+        strings.add_file(parse_file(BASE_FILE)).unwrap();
+        strings.add_file(ext("TYPE_STRING")).unwrap();
+        let mut ints = DescriptorPool::new();
+        ints.add_file(parse_file(BASE_FILE)).unwrap();
+        ints.add_file(ext("TYPE_INT32")).unwrap();
+
+        let outer = ints.find_message_by_full_name("base.Outer").unwrap();
+        let mut m = outer.new_instance();
+        assert!(text_format::merge_from_str(&mut *m, "[ext.tag]: 5").is_err());
+        assert!(text_format::merge_from_str_with_pool(&mut *m, "[ext.tag]: 5", &strings).is_err());
+        text_format::merge_from_str_with_pool(&mut *m, "[ext.tag]: 5", &ints).unwrap();
+        assert_eq!("[ext.tag]: 5", text_format::print_to_string_with_pool(&*m, &ints));
+
+        let mut m = outer.new_instance();
+        text_format::merge_from_str_with_pool(&mut *m, "[ext.tag]: \"x\"", &strings).unwrap();
+        assert_eq!("[ext.tag]: \"x\"", text_format::print_to_string_with_pool(&*m, &strings));
+    }
+}
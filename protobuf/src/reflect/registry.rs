@@ -0,0 +1,241 @@
+//! Registry of generated message types and of extensions by full name.
+//!
+//! Messages packed into `Any` can be decoded and printed only if their types
+//! are registered. Well-known types are always registered, other generated types
+//! are registered with `register_message` or with `register_message_types` function
+//! of the generated file.
+//!
+//! Extensions are printed and parsed by `text_format` only if they are registered.
+//! Generated extensions are registered by `register_message_types`.
+//! Extensions of files added to `DescriptorPool` are not registered,
+//! they are known only to the pool.
+
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use core::Message;
+use descriptor::FieldDescriptorProto_Label;
+use descriptor::FileDescriptorProto;
+use lazy::Lazy;
+use lazy::ONCE_INIT;
+use types::ProtobufType;
+use well_known_types;
+use well_known_types_util::any::type_name_from_type_url;
+
+use super::cell::DescriptorCell;
+use super::cell::leak;
+use super::dynamic::new_extension_descriptor;
+use super::dynamic::DynamicFieldShape;
+use super::dynamic::DynamicType;
+use super::FieldDescriptor;
+use super::MessageDescriptor;
+use super::RuntimeType;
+
+
+static mut REGISTRY: Lazy<RwLock<HashMap<String, &'static MessageDescriptor>>> = Lazy {
+    lock: ONCE_INIT,
+    ptr: 0 as *const RwLock<HashMap<String, &'static MessageDescriptor>>,
+};
+
+fn insert<M : Message>(map: &mut HashMap<String, &'static MessageDescriptor>) {
+    let descriptor = MessageDescriptor::for_type::<M>();
+    map.insert(descriptor.full_name().to_owned(), descriptor);
+}
+
+fn registry() -> &'static RwLock<HashMap<String, &'static MessageDescriptor>> {
+    unsafe {
+        REGISTRY.get(|| {
+            let mut map = HashMap::new();
+            insert::<well_known_types::Any>(&mut map);
+            insert::<well_known_types::Api>(&mut map);
+            insert::<well_known_types::BoolValue>(&mut map);
+            insert::<well_known_types::BytesValue>(&mut map);
+            insert::<well_known_types::DoubleValue>(&mut map);
+            insert::<well_known_types::Duration>(&mut map);
+            insert::<well_known_types::Empty>(&mut map);
+            insert::<well_known_types::Enum>(&mut map);
+            insert::<well_known_types::EnumValue>(&mut map);
+            insert::<well_known_types::Field>(&mut map);
+            insert::<well_known_types::FieldMask>(&mut map);
+            insert::<well_known_types::FloatValue>(&mut map);
+            insert::<well_known_types::Int32Value>(&mut map);
+            insert::<well_known_types::Int64Value>(&mut map);
+            insert::<well_known_types::ListValue>(&mut map);
+            insert::<well_known_types::Method>(&mut map);
+            insert::<well_known_types::Mixin>(&mut map);
+            insert::<well_known_types::Option>(&mut map);
+            insert::<well_known_types::SourceContext>(&mut map);
+            insert::<well_known_types::StringValue>(&mut map);
+            insert::<well_known_types::Struct>(&mut map);
+            insert::<well_known_types::Timestamp>(&mut map);
+            insert::<well_known_types::Type>(&mut map);
+            insert::<well_known_types::UInt32Value>(&mut map);
+            insert::<well_known_types::UInt64Value>(&mut map);
+            insert::<well_known_types::Value>(&mut map);
+            RwLock::new(map)
+        })
+    }
+}
+
+/// Register generated message type.
+///
+/// Registering the same type again is a no-op.
+pub fn register_message<M : Message>() {
+    insert::<M>(&mut registry().write().unwrap());
+}
+
+/// Find registered message type by full name, e. g. `google.protobuf.Duration`
+pub fn find_message_by_full_name(full_name: &str) -> Option<&'static MessageDescriptor> {
+    registry().read().unwrap().get(full_name).cloned()
+}
+
+/// Find registered message type by `Any` type URL,
+/// e. g. `type.googleapis.com/google.protobuf.Duration`
+pub fn find_message_by_type_url(type_url: &str) -> Option<&'static MessageDescriptor> {
+    find_message_by_full_name(type_name_from_type_url(type_url)?)
+}
+
+
+#[derive(Default)]
+struct Extensions {
+    by_full_name: HashMap<String, &'static FieldDescriptor>,
+    by_extendee: HashMap<String, Vec<&'static FieldDescriptor>>,
+}
+
+static mut EXTENSIONS: Lazy<RwLock<Extensions>> = Lazy {
+    lock: ONCE_INIT,
+    ptr: 0 as *const RwLock<Extensions>,
+};
+
+fn extensions() -> &'static RwLock<Extensions> {
+    unsafe { EXTENSIONS.get(|| RwLock::new(Extensions::default())) }
+}
+
+/// Register extension descriptor, extended message of the descriptor must be known.
+///
+/// Registering an extension with the same full name again is a no-op.
+pub fn register_extension_descriptor(extension: &'static FieldDescriptor) {
+    let extendee = extension
+        .containing_type()
+        .expect("extended message is unknown")
+        .full_name()
+        .to_owned();
+    let mut extensions = extensions().write().unwrap();
+    if extensions.by_full_name.contains_key(extension.full_name()) {
+        return;
+    }
+    extensions
+        .by_full_name
+        .insert(extension.full_name().to_owned(), extension);
+    extensions
+        .by_extendee
+        .entry(extendee)
+        .or_insert_with(Vec::new)
+        .push(extension);
+}
+
+/// Register extension of message `M` with values of type `T` declared in generated file.
+///
+/// Panics if the file does not declare such extension.
+pub fn register_extension<M : Message, T : ProtobufType>(
+    file: &'static FileDescriptorProto,
+    field_number: u32,
+) {
+    let extendee = MessageDescriptor::for_type::<M>();
+    let proto = file.get_extension()
+        .iter()
+        .find(|f| {
+            f.get_number() as u32 == field_number &&
+                f.get_extendee().trim_left_matches('.') == extendee.full_name()
+        })
+        .unwrap_or_else(|| {
+            panic!("extension {} of `{}` is not declared in `{}`",
+                field_number, extendee.full_name(), file.get_name())
+        });
+    let full_name = if file.get_package().is_empty() {
+        proto.get_name().to_owned()
+    } else {
+        format!("{}.{}", file.get_package(), proto.get_name())
+    };
+    if find_extension_by_full_name(&full_name).is_some() {
+        return;
+    }
+
+    let value_type = DynamicType {
+        proto_type: proto.get_field_type(),
+        enum_descriptor: match T::runtime_type() {
+            RuntimeType::Enum(e) => Some(e),
+            _ => None,
+        },
+        message_descriptor: match T::runtime_type() {
+            RuntimeType::Message(m) => {
+                let cell = leak(DescriptorCell::new());
+                cell.set(m);
+                Some(cell)
+            }
+            _ => None,
+        },
+    };
+    let shape = match proto.get_label() {
+        FieldDescriptorProto_Label::LABEL_REPEATED => DynamicFieldShape::Repeated(value_type),
+        _ => DynamicFieldShape::Singular(value_type),
+    };
+    let extension = leak(new_extension_descriptor(full_name, proto, shape));
+    extension.containing_type.set(extendee);
+    register_extension_descriptor(extension);
+}
+
+/// Find registered extension by full name, e. g. `rustproto.expose_oneof_all`
+pub fn find_extension_by_full_name(full_name: &str) -> Option<&'static FieldDescriptor> {
+    extensions().read().unwrap().by_full_name.get(full_name).cloned()
+}
+
+/// Registered extensions of message with given full name
+pub fn find_extensions_by_extendee(extendee_full_name: &str) -> Vec<&'static FieldDescriptor> {
+    match extensions().read().unwrap().by_extendee.get(extendee_full_name) {
+        Some(extensions) => extensions.clone(),
+        None => Vec::new(),
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use descriptor::FileOptions;
+    use descriptor::GeneratedCodeInfo;
+    use rustproto;
+    use reflect::ReflectValueBox;
+
+    #[test]
+    fn test_register() {
+        assert!(find_message_by_full_name("google.protobuf.GeneratedCodeInfo").is_none());
+        register_message::<GeneratedCodeInfo>();
+        let d = find_message_by_type_url("type.googleapis.com/google.protobuf.GeneratedCodeInfo");
+        assert_eq!("GeneratedCodeInfo", d.unwrap().name());
+    }
+
+    #[test]
+    fn test_well_known_types_registered() {
+        let d = find_message_by_full_name("google.protobuf.Duration").unwrap();
+        assert_eq!("Duration", d.name());
+        assert!(find_message_by_type_url("google.protobuf.Duration").is_none());
+    }
+
+    #[test]
+    fn test_register_extension() {
+        rustproto::register_message_types();
+        let e = find_extension_by_full_name("rustproto.expose_oneof_all").unwrap();
+        assert_eq!("google.protobuf.FileOptions", e.containing_type().unwrap().full_name());
+        assert!(find_extensions_by_extendee("google.protobuf.FileOptions")
+            .iter()
+            .any(|e| e.name() == "generate_accessors_all"));
+
+        let mut options = FileOptions::new();
+        e.set_singular_field(&mut options, ReflectValueBox::Bool(true));
+        assert_eq!(Some(true), rustproto::exts::expose_oneof_all.get(&options));
+        assert_eq!(true, e.get_bool(&options));
+        assert_eq!(1, e.get_extension(&options).len());
+    }
+}
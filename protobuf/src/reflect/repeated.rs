@@ -1,7 +1,12 @@
 use std::slice;
 
+use core::Message;
+
+use super::accessor::FieldAccessor;
 use super::value::ProtobufValue;
 use super::value::ProtobufValueRef;
+use super::value::ReflectValueBox;
+use super::ReflectFieldRef;
 
 use repeated::RepeatedField;
 
@@ -189,3 +194,42 @@ impl<'a> IntoIterator for &'a ReflectRepeatedRef<'a> {
         }
     }
 }
+
+/// Mutable reference to repeated field, obtained with `FieldDescriptor::mut_repeated`
+pub struct ReflectRepeatedMut<'a> {
+    accessor: &'a FieldAccessor,
+    message: &'a mut Message,
+}
+
+impl<'a> ReflectRepeatedMut<'a> {
+    pub(crate) fn new(accessor: &'a FieldAccessor, message: &'a mut Message) -> ReflectRepeatedMut<'a> {
+        ReflectRepeatedMut {
+            accessor: accessor,
+            message: message,
+        }
+    }
+
+    fn repeated(&self) -> &ReflectRepeated {
+        match self.accessor.get_reflect(&*self.message) {
+            ReflectFieldRef::Repeated(repeated) => repeated,
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.repeated().len()
+    }
+
+    pub fn get(&self, index: usize) -> ProtobufValueRef {
+        self.repeated().get(index).as_ref()
+    }
+
+    /// Append an element; panics if value type does not match field type
+    pub fn push(&mut self, value: ReflectValueBox) {
+        self.accessor.push_generic(self.message, value)
+    }
+
+    pub fn clear(&mut self) {
+        self.accessor.clear_field_generic(self.message)
+    }
+}
@@ -0,0 +1,92 @@
+use std::ptr;
+
+use core::Message;
+use core::ProtobufEnum;
+
+use super::EnumDescriptor;
+use super::MessageDescriptor;
+use super::ReflectValueBox;
+
+
+/// Type of a value which can be stored in a field: a singular field value,
+/// an element of a repeated field, or a map key or value.
+///
+/// Different protobuf types with the same Rust representation
+/// (e. g. `int32`, `sint32` and `sfixed32`) have the same runtime type.
+#[derive(Copy, Clone)]
+pub enum RuntimeType {
+    U32,
+    U64,
+    I32,
+    I64,
+    F32,
+    F64,
+    Bool,
+    String,
+    Bytes,
+    Enum(&'static EnumDescriptor),
+    Message(&'static MessageDescriptor),
+}
+
+impl RuntimeType {
+    /// Runtime type of generated enum type
+    pub fn for_enum<E : ProtobufEnum>() -> RuntimeType {
+        RuntimeType::Enum(EnumDescriptor::for_type::<E>())
+    }
+
+    /// Runtime type of generated message type
+    pub fn for_message<M : Message>() -> RuntimeType {
+        RuntimeType::Message(MessageDescriptor::for_type::<M>())
+    }
+
+    /// Value can be stored in a field of this type
+    pub fn is_type_of(&self, value: &ReflectValueBox) -> bool {
+        match (*self, value) {
+            (RuntimeType::U32, &ReflectValueBox::U32(..)) |
+            (RuntimeType::U64, &ReflectValueBox::U64(..)) |
+            (RuntimeType::I32, &ReflectValueBox::I32(..)) |
+            (RuntimeType::I64, &ReflectValueBox::I64(..)) |
+            (RuntimeType::F32, &ReflectValueBox::F32(..)) |
+            (RuntimeType::F64, &ReflectValueBox::F64(..)) |
+            (RuntimeType::Bool, &ReflectValueBox::Bool(..)) |
+            (RuntimeType::String, &ReflectValueBox::String(..)) |
+            (RuntimeType::Bytes, &ReflectValueBox::Bytes(..)) => true,
+            (RuntimeType::Enum(e), &ReflectValueBox::Enum(v)) => {
+                e.values().iter().any(|ev| ptr::eq(ev, v))
+            }
+            (RuntimeType::Enum(e), &ReflectValueBox::UnknownEnum(d, _)) => ptr::eq(e, d),
+            (RuntimeType::Message(d), &ReflectValueBox::Message(ref m)) => {
+                ptr::eq(m.descriptor(), d)
+            }
+            _ => false,
+        }
+    }
+
+    /// Zero value of this type: first value of enum, empty message for message type
+    pub fn default_value(&self) -> ReflectValueBox {
+        match *self {
+            RuntimeType::U32 => ReflectValueBox::U32(0),
+            RuntimeType::U64 => ReflectValueBox::U64(0),
+            RuntimeType::I32 => ReflectValueBox::I32(0),
+            RuntimeType::I64 => ReflectValueBox::I64(0),
+            RuntimeType::F32 => ReflectValueBox::F32(0.0),
+            RuntimeType::F64 => ReflectValueBox::F64(0.0),
+            RuntimeType::Bool => ReflectValueBox::Bool(false),
+            RuntimeType::String => ReflectValueBox::String(String::new()),
+            RuntimeType::Bytes => ReflectValueBox::Bytes(Vec::new()),
+            RuntimeType::Enum(e) => ReflectValueBox::Enum(&e.values()[0]),
+            RuntimeType::Message(m) => ReflectValueBox::Message(m.new_instance()),
+        }
+    }
+}
+
+/// Shape and element types of a field.
+#[derive(Copy, Clone)]
+pub enum RuntimeFieldType {
+    /// Singular field (required, optional or proto3 field)
+    Singular(RuntimeType),
+    /// Repeated field
+    Repeated(RuntimeType),
+    /// Map field: key type and value type
+    Map(RuntimeType, RuntimeType),
+}
@@ -0,0 +1,87 @@
+use descriptor::MethodDescriptorProto;
+use descriptor::MethodOptions;
+use descriptor::ServiceDescriptorProto;
+use descriptor::ServiceOptions;
+
+use super::cell::DescriptorCell;
+use super::FileDescriptor;
+use super::MessageDescriptor;
+
+
+/// Descriptor of a service added to a `DescriptorPool`
+pub struct ServiceDescriptor {
+    pub(super) proto: &'static ServiceDescriptorProto,
+    pub(super) full_name: String,
+    pub(super) methods: Vec<MethodDescriptor>,
+    pub(super) file_descriptor: DescriptorCell<FileDescriptor>,
+}
+
+impl ServiceDescriptor {
+    pub fn proto(&self) -> &'static ServiceDescriptorProto {
+        self.proto
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.proto.get_name()
+    }
+
+    /// Name with package, e. g. `foo.bar.Baz`
+    pub fn full_name(&self) -> &str {
+        &self.full_name
+    }
+
+    pub fn options(&self) -> &'static ServiceOptions {
+        self.proto.get_options()
+    }
+
+    pub fn methods(&self) -> &[MethodDescriptor] {
+        &self.methods
+    }
+
+    pub fn method_by_name(&self, name: &str) -> Option<&MethodDescriptor> {
+        self.methods.iter().find(|m| m.name() == name)
+    }
+
+    /// File where this service is declared
+    pub fn file_descriptor(&self) -> &'static FileDescriptor {
+        self.file_descriptor.get().expect("file descriptor is not initialized")
+    }
+}
+
+
+/// Descriptor of a service method
+pub struct MethodDescriptor {
+    pub(super) proto: &'static MethodDescriptorProto,
+    pub(super) input_type: &'static DescriptorCell<MessageDescriptor>,
+    pub(super) output_type: &'static DescriptorCell<MessageDescriptor>,
+}
+
+impl MethodDescriptor {
+    pub fn proto(&self) -> &'static MethodDescriptorProto {
+        self.proto
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.proto.get_name()
+    }
+
+    pub fn options(&self) -> &'static MethodOptions {
+        self.proto.get_options()
+    }
+
+    pub fn input_type(&self) -> &'static MessageDescriptor {
+        self.input_type.get().expect("input type is not initialized")
+    }
+
+    pub fn output_type(&self) -> &'static MessageDescriptor {
+        self.output_type.get().expect("output type is not initialized")
+    }
+
+    pub fn is_client_streaming(&self) -> bool {
+        self.proto.get_client_streaming()
+    }
+
+    pub fn is_server_streaming(&self) -> bool {
+        self.proto.get_server_streaming()
+    }
+}
@@ -6,6 +6,7 @@ use bytes::Bytes;
 use chars::Chars;
 
 use core::*;
+use enum_or_unknown::EnumOrUnknown;
 use super::*;
 
 pub trait ProtobufValue: Any + 'static {
@@ -31,9 +32,11 @@ pub trait ProtobufValue: Any + 'static {
             ProtobufValueRef::F32(v) => ProtobufValueRef::F32(v),
             ProtobufValueRef::F64(v) => ProtobufValueRef::F64(v),
             ProtobufValueRef::Enum(v) => ProtobufValueRef::Enum(v),
+            ProtobufValueRef::UnknownEnum(d, v) => ProtobufValueRef::UnknownEnum(d, v),
             ProtobufValueRef::String(..) |
             ProtobufValueRef::Bytes(..) |
-            ProtobufValueRef::Message(..) => unreachable!(),
+            ProtobufValueRef::Message(..) |
+            ProtobufValueRef::OwnedMessage(..) => unreachable!(),
         }
     }
 }
@@ -139,7 +142,12 @@ pub enum ProtobufValueRef<'a> {
     String(&'a str),
     Bytes(&'a [u8]),
     Enum(&'static EnumValueDescriptor),
+    /// Number of given enum type which has no enum value
+    UnknownEnum(&'static EnumDescriptor, i32),
     Message(&'a Message),
+    /// Message which is not stored in the containing message, e. g. wrapper message
+    /// of field generated as `Option` of wrapped value
+    OwnedMessage(Box<Message>),
 }
 
 impl<'a> ProtobufValueRef<'a> {
@@ -155,7 +163,187 @@ impl<'a> ProtobufValueRef<'a> {
             ProtobufValueRef::String(v) => !v.is_empty(),
             ProtobufValueRef::Bytes(v) => !v.is_empty(),
             ProtobufValueRef::Enum(v) => v.value() != 0,
-            ProtobufValueRef::Message(_) => true,
+            ProtobufValueRef::UnknownEnum(_, v) => v != 0,
+            ProtobufValueRef::Message(_) |
+            ProtobufValueRef::OwnedMessage(_) => true,
         }
     }
+
+    /// Message value, `None` if the value is not a message
+    pub fn as_message(&self) -> Option<&Message> {
+        match *self {
+            ProtobufValueRef::Message(m) => Some(m),
+            ProtobufValueRef::OwnedMessage(ref m) => Some(&**m),
+            _ => None,
+        }
+    }
+}
+
+
+/// Owned dynamically typed value, input of mutable reflection operations
+pub enum ReflectValueBox {
+    U32(u32),
+    U64(u64),
+    I32(i32),
+    I64(i64),
+    F32(f32),
+    F64(f64),
+    Bool(bool),
+    String(String),
+    Bytes(Vec<u8>),
+    Enum(&'static EnumValueDescriptor),
+    /// Number of given enum type which has no enum value
+    UnknownEnum(&'static EnumDescriptor, i32),
+    Message(Box<Message>),
+}
+
+impl ReflectValueBox {
+    pub fn as_value_ref(&self) -> ProtobufValueRef {
+        match *self {
+            ReflectValueBox::U32(v) => ProtobufValueRef::U32(v),
+            ReflectValueBox::U64(v) => ProtobufValueRef::U64(v),
+            ReflectValueBox::I32(v) => ProtobufValueRef::I32(v),
+            ReflectValueBox::I64(v) => ProtobufValueRef::I64(v),
+            ReflectValueBox::F32(v) => ProtobufValueRef::F32(v),
+            ReflectValueBox::F64(v) => ProtobufValueRef::F64(v),
+            ReflectValueBox::Bool(v) => ProtobufValueRef::Bool(v),
+            ReflectValueBox::String(ref v) => ProtobufValueRef::String(v),
+            ReflectValueBox::Bytes(ref v) => ProtobufValueRef::Bytes(v),
+            ReflectValueBox::Enum(v) => ProtobufValueRef::Enum(v),
+            ReflectValueBox::UnknownEnum(d, v) => ProtobufValueRef::UnknownEnum(d, v),
+            ReflectValueBox::Message(ref v) => ProtobufValueRef::Message(&**v),
+        }
+    }
+
+    /// Unwrap value of given non-enum type, `None` if type does not match
+    pub(crate) fn downcast<V : 'static>(self) -> Option<V> {
+        let any: Box<Any> = match self {
+            ReflectValueBox::U32(v) => Box::new(v),
+            ReflectValueBox::U64(v) => Box::new(v),
+            ReflectValueBox::I32(v) => Box::new(v),
+            ReflectValueBox::I64(v) => Box::new(v),
+            ReflectValueBox::F32(v) => Box::new(v),
+            ReflectValueBox::F64(v) => Box::new(v),
+            ReflectValueBox::Bool(v) => Box::new(v),
+            ReflectValueBox::String(v) => Box::new(v),
+            ReflectValueBox::Bytes(v) => Box::new(v),
+            ReflectValueBox::Enum(..) |
+            ReflectValueBox::UnknownEnum(..) => return None,
+            ReflectValueBox::Message(v) => {
+                if !v.as_any().is::<V>() {
+                    return None;
+                }
+                v.into_any()
+            }
+        };
+        any.downcast::<V>().ok().map(|v| *v)
+    }
+
+    /// Unwrap enum value, `None` if value is not a value of enum `E`
+    pub(crate) fn downcast_enum<E : ProtobufEnum>(self) -> Option<E> {
+        match self {
+            ReflectValueBox::Enum(v) => {
+                let values = E::enum_descriptor_static(None).values();
+                if values.iter().any(|e| e as *const _ == v as *const _) {
+                    E::from_i32(v.value())
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Unwrap known or unknown value of enum `E`, `None` if value is of other type
+    pub(crate) fn downcast_enum_or_unknown<E : ProtobufEnum>(self) -> Option<EnumOrUnknown<E>> {
+        match self {
+            ReflectValueBox::UnknownEnum(d, v) => {
+                if d as *const _ == E::enum_descriptor_static(None) as *const _ {
+                    Some(EnumOrUnknown::from_i32(v))
+                } else {
+                    None
+                }
+            }
+            v => v.downcast_enum::<E>().map(EnumOrUnknown::new),
+        }
+    }
+}
+
+/// Dynamic messages store field values boxed
+impl ProtobufValue for ReflectValueBox {
+    fn as_ref(&self) -> ProtobufValueRef {
+        self.as_value_ref()
+    }
+}
+
+impl From<u32> for ReflectValueBox {
+    fn from(v: u32) -> ReflectValueBox {
+        ReflectValueBox::U32(v)
+    }
+}
+
+impl From<u64> for ReflectValueBox {
+    fn from(v: u64) -> ReflectValueBox {
+        ReflectValueBox::U64(v)
+    }
+}
+
+impl From<i32> for ReflectValueBox {
+    fn from(v: i32) -> ReflectValueBox {
+        ReflectValueBox::I32(v)
+    }
+}
+
+impl From<i64> for ReflectValueBox {
+    fn from(v: i64) -> ReflectValueBox {
+        ReflectValueBox::I64(v)
+    }
+}
+
+impl From<f32> for ReflectValueBox {
+    fn from(v: f32) -> ReflectValueBox {
+        ReflectValueBox::F32(v)
+    }
+}
+
+impl From<f64> for ReflectValueBox {
+    fn from(v: f64) -> ReflectValueBox {
+        ReflectValueBox::F64(v)
+    }
+}
+
+impl From<bool> for ReflectValueBox {
+    fn from(v: bool) -> ReflectValueBox {
+        ReflectValueBox::Bool(v)
+    }
+}
+
+impl From<String> for ReflectValueBox {
+    fn from(v: String) -> ReflectValueBox {
+        ReflectValueBox::String(v)
+    }
+}
+
+impl<'a> From<&'a str> for ReflectValueBox {
+    fn from(v: &'a str) -> ReflectValueBox {
+        ReflectValueBox::String(v.to_owned())
+    }
+}
+
+impl From<Vec<u8>> for ReflectValueBox {
+    fn from(v: Vec<u8>) -> ReflectValueBox {
+        ReflectValueBox::Bytes(v)
+    }
+}
+
+impl From<&'static EnumValueDescriptor> for ReflectValueBox {
+    fn from(v: &'static EnumValueDescriptor) -> ReflectValueBox {
+        ReflectValueBox::Enum(v)
+    }
+}
+
+impl From<Box<Message>> for ReflectValueBox {
+    fn from(v: Box<Message>) -> ReflectValueBox {
+        ReflectValueBox::Message(v)
+    }
 }
@@ -13,6 +13,9 @@ use std::ops::DerefMut;
 use std::cmp::Ordering;
 use std::fmt;
 
+#[cfg(feature = "with-serde")]
+use serde;
+
 use clear::Clear;
 
 /// Wrapper around vector to avoid deallocations on clear.
@@ -412,6 +415,21 @@ impl<T : fmt::Debug> fmt::Debug for RepeatedField<T> {
     }
 }
 
+/// Serialized as sequence.
+#[cfg(feature = "with-serde")]
+impl<T : serde::Serialize> serde::Serialize for RepeatedField<T> {
+    fn serialize<S : serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_ref().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "with-serde")]
+impl<'de, T : serde::Deserialize<'de>> serde::Deserialize<'de> for RepeatedField<T> {
+    fn deserialize<D : serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::deserialize(deserializer).map(RepeatedField::from_vec)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::RepeatedField;
@@ -435,4 +453,16 @@ mod test {
         v.clear();
         assert_eq!("".to_string(), *v.push_default());
     }
+
+    #[cfg(feature = "with-serde")]
+    #[test]
+    fn serde() {
+        use serde_test::assert_tokens;
+        use serde_test::Token;
+
+        let mut v = RepeatedField::from_vec(vec![1, 2, 3]);
+        v.clear();
+        v.push(4);
+        assert_tokens(&v, &[Token::Seq { len: Some(1) }, Token::I32(4), Token::SeqEnd]);
+    }
 }
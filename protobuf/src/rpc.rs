@@ -0,0 +1,294 @@
+//! Transport-independent RPC support for code generated from `service` definitions.
+//!
+//! For each service generated code contains:
+//!
+//! * a trait with a method per RPC
+//! * a `MethodDescriptor` constant per RPC
+//! * a client which implements the trait by calling methods over a `Transport`
+//! * a function which creates a `ServiceDefinition` dispatching calls to a trait implementation
+//!
+//! Messages are passed to a `Transport` serialized, so any RPC framework can provide one.
+//! `LoopbackTransport` calls services in the same process.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::marker::PhantomData;
+
+use core::Message;
+use core::parse_from_bytes;
+use error::ProtobufError;
+use reflect::MessageDescriptor;
+
+
+/// RPC error
+#[derive(Debug)]
+pub enum RpcError {
+    /// Failed to serialize or parse a message
+    Protobuf(ProtobufError),
+    /// No method with given path
+    UnknownMethod(String),
+    /// Unary request or response is missing or there are several of them
+    WrongMessageCount,
+    /// Error returned by a service implementation or by a transport
+    Other(String),
+}
+
+impl fmt::Display for RpcError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            RpcError::Protobuf(ref e) => write!(f, "{}", e),
+            RpcError::UnknownMethod(ref path) => write!(f, "unknown method: {}", path),
+            RpcError::WrongMessageCount => write!(f, "expecting exactly one message"),
+            RpcError::Other(ref message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl Error for RpcError {
+    fn description(&self) -> &str {
+        match *self {
+            RpcError::Protobuf(ref e) => e.description(),
+            RpcError::UnknownMethod(..) => "unknown method",
+            RpcError::WrongMessageCount => "expecting exactly one message",
+            RpcError::Other(ref message) => message,
+        }
+    }
+}
+
+impl From<ProtobufError> for RpcError {
+    fn from(e: ProtobufError) -> RpcError {
+        RpcError::Protobuf(e)
+    }
+}
+
+pub type RpcResult<T> = Result<T, RpcError>;
+
+/// Messages of streaming request or response
+pub type RpcStream<T> = Box<Iterator<Item = RpcResult<T>> + Send>;
+
+/// Stream of given messages
+pub fn stream_from_vec<T : Send + 'static>(messages: Vec<T>) -> RpcStream<T> {
+    Box::new(messages.into_iter().map(Ok))
+}
+
+
+/// Whether requests and responses of a method are streams
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MethodType {
+    Unary,
+    ClientStreaming,
+    ServerStreaming,
+    BidiStreaming,
+}
+
+impl MethodType {
+    pub fn new(client_streaming: bool, server_streaming: bool) -> MethodType {
+        match (client_streaming, server_streaming) {
+            (false, false) => MethodType::Unary,
+            (true, false) => MethodType::ClientStreaming,
+            (false, true) => MethodType::ServerStreaming,
+            (true, true) => MethodType::BidiStreaming,
+        }
+    }
+}
+
+/// Method of a service with request type `Req` and response type `Resp`
+pub struct MethodDescriptor<Req, Resp> {
+    /// Method name as declared in `.proto` file
+    pub name: &'static str,
+    /// Path used to call the method: `/package.Service/Method`
+    pub full_path: &'static str,
+    pub method_type: MethodType,
+    pub phantom: PhantomData<(Req, Resp)>,
+}
+
+impl<Req : Message, Resp : Message> MethodDescriptor<Req, Resp> {
+    pub fn input_type(&self) -> &'static MessageDescriptor {
+        MessageDescriptor::for_type::<Req>()
+    }
+
+    pub fn output_type(&self) -> &'static MessageDescriptor {
+        MessageDescriptor::for_type::<Resp>()
+    }
+}
+
+
+/// Transport passing serialized messages between client and server.
+///
+/// Calls of all method types are represented as streaming calls,
+/// unary request or response is a stream of one message.
+pub trait Transport {
+    fn call(&self, full_path: &str, requests: RpcStream<Vec<u8>>) -> RpcResult<RpcStream<Vec<u8>>>;
+}
+
+fn encode_stream<M : Message>(messages: RpcStream<M>) -> RpcStream<Vec<u8>> {
+    Box::new(messages.map(|m| Ok(m?.write_to_bytes()?)))
+}
+
+fn decode_stream<M : Message>(messages: RpcStream<Vec<u8>>) -> RpcStream<M> {
+    Box::new(messages.map(|m| Ok(parse_from_bytes(&m?)?)))
+}
+
+fn single<M>(mut messages: RpcStream<M>) -> RpcResult<M> {
+    let message = match messages.next() {
+        Some(message) => message?,
+        None => return Err(RpcError::WrongMessageCount),
+    };
+    match messages.next() {
+        Some(..) => Err(RpcError::WrongMessageCount),
+        None => Ok(message),
+    }
+}
+
+/// Call unary method, used by generated clients
+pub fn call_unary<Req : Message, Resp : Message>(
+    transport: &Transport,
+    method: &MethodDescriptor<Req, Resp>,
+    request: Req,
+) -> RpcResult<Resp> {
+    let requests = encode_stream(stream_from_vec(vec![request]));
+    single(decode_stream(transport.call(method.full_path, requests)?))
+}
+
+/// Call server streaming method, used by generated clients
+pub fn call_server_streaming<Req : Message, Resp : Message>(
+    transport: &Transport,
+    method: &MethodDescriptor<Req, Resp>,
+    request: Req,
+) -> RpcResult<RpcStream<Resp>> {
+    let requests = encode_stream(stream_from_vec(vec![request]));
+    Ok(decode_stream(transport.call(method.full_path, requests)?))
+}
+
+/// Call client streaming method, used by generated clients
+pub fn call_client_streaming<Req : Message, Resp : Message>(
+    transport: &Transport,
+    method: &MethodDescriptor<Req, Resp>,
+    requests: RpcStream<Req>,
+) -> RpcResult<Resp> {
+    single(decode_stream(transport.call(method.full_path, encode_stream(requests))?))
+}
+
+/// Call bidirectional streaming method, used by generated clients
+pub fn call_bidi_streaming<Req : Message, Resp : Message>(
+    transport: &Transport,
+    method: &MethodDescriptor<Req, Resp>,
+    requests: RpcStream<Req>,
+) -> RpcResult<RpcStream<Resp>> {
+    Ok(decode_stream(transport.call(method.full_path, encode_stream(requests))?))
+}
+
+
+type MethodHandler =
+    Box<Fn(RpcStream<Vec<u8>>) -> RpcResult<RpcStream<Vec<u8>>> + Send + Sync>;
+
+/// Handlers of methods of a service implementation, created by generated code
+pub struct ServiceDefinition {
+    name: String,
+    methods: HashMap<String, MethodHandler>,
+}
+
+impl ServiceDefinition {
+    /// Empty definition of service with given full name
+    pub fn new(name: &str) -> ServiceDefinition {
+        ServiceDefinition {
+            name: name.to_owned(),
+            methods: HashMap::new(),
+        }
+    }
+
+    /// Full name of the service, e. g. `helloworld.Greeter`
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn add_handler(&mut self, full_path: &str, handler: MethodHandler) {
+        self.methods.insert(full_path.to_owned(), handler);
+    }
+
+    pub fn add_unary<Req, Resp, F>(&mut self, method: &MethodDescriptor<Req, Resp>, f: F)
+    where
+        Req : Message,
+        Resp : Message,
+        F : Fn(Req) -> RpcResult<Resp> + Send + Sync + 'static,
+    {
+        self.add_handler(method.full_path, Box::new(move |requests| {
+            let response = f(single(decode_stream(requests))?)?;
+            Ok(encode_stream(stream_from_vec(vec![response])))
+        }));
+    }
+
+    pub fn add_server_streaming<Req, Resp, F>(&mut self, method: &MethodDescriptor<Req, Resp>, f: F)
+    where
+        Req : Message,
+        Resp : Message,
+        F : Fn(Req) -> RpcResult<RpcStream<Resp>> + Send + Sync + 'static,
+    {
+        self.add_handler(method.full_path, Box::new(move |requests| {
+            Ok(encode_stream(f(single(decode_stream(requests))?)?))
+        }));
+    }
+
+    pub fn add_client_streaming<Req, Resp, F>(&mut self, method: &MethodDescriptor<Req, Resp>, f: F)
+    where
+        Req : Message,
+        Resp : Message,
+        F : Fn(RpcStream<Req>) -> RpcResult<Resp> + Send + Sync + 'static,
+    {
+        self.add_handler(method.full_path, Box::new(move |requests| {
+            let response = f(decode_stream(requests))?;
+            Ok(encode_stream(stream_from_vec(vec![response])))
+        }));
+    }
+
+    pub fn add_bidi_streaming<Req, Resp, F>(&mut self, method: &MethodDescriptor<Req, Resp>, f: F)
+    where
+        Req : Message,
+        Resp : Message,
+        F : Fn(RpcStream<Req>) -> RpcResult<RpcStream<Resp>> + Send + Sync + 'static,
+    {
+        self.add_handler(method.full_path, Box::new(move |requests| {
+            Ok(encode_stream(f(decode_stream(requests))?))
+        }));
+    }
+
+    /// Handle call of a method of this service
+    pub fn call(&self, full_path: &str, requests: RpcStream<Vec<u8>>) -> RpcResult<RpcStream<Vec<u8>>> {
+        match self.methods.get(full_path) {
+            Some(handler) => handler(requests),
+            None => Err(RpcError::UnknownMethod(full_path.to_owned())),
+        }
+    }
+}
+
+
+/// Transport calling services added to it in the same process.
+///
+/// Messages are serialized and parsed as they would be by a network transport,
+/// so it can be used to test services and clients.
+pub struct LoopbackTransport {
+    services: HashMap<String, ServiceDefinition>,
+}
+
+impl LoopbackTransport {
+    pub fn new() -> LoopbackTransport {
+        LoopbackTransport { services: HashMap::new() }
+    }
+
+    pub fn add_service(&mut self, service: ServiceDefinition) {
+        self.services.insert(service.name().to_owned(), service);
+    }
+}
+
+impl Transport for LoopbackTransport {
+    fn call(&self, full_path: &str, requests: RpcStream<Vec<u8>>) -> RpcResult<RpcStream<Vec<u8>>> {
+        // path is `/package.Service/Method`
+        let service_name = full_path.split('/').nth(1).unwrap_or("");
+        match self.services.get(service_name) {
+            Some(service) => service.call(full_path, requests),
+            None => Err(RpcError::UnknownMethod(full_path.to_owned())),
+        }
+    }
+}
+
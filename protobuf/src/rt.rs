@@ -11,12 +11,14 @@ use bytes::Bytes;
 use chars::Chars;
 
 use core::*;
+use enum_or_unknown::EnumOrUnknown;
 use zigzag::*;
 use stream::wire_format;
 use stream::wire_format::WireType;
 use stream::wire_format::WireTypeFixed32;
 use stream::wire_format::WireTypeFixed64;
 use stream::wire_format::WireTypeLengthDelimited;
+use stream::wire_format::WireTypeStartGroup;
 use stream::wire_format::WireTypeVarint;
 use error::ProtobufError;
 use error::ProtobufResult;
@@ -27,6 +29,7 @@ use repeated::RepeatedField;
 use stream::CodedInputStream;
 use stream::CodedOutputStream;
 use types::*;
+use reflect::ProtobufValue;
 
 use unknown::UnknownFields;
 
@@ -151,6 +154,13 @@ pub fn vec_packed_enum_data_size<E : ProtobufEnum>(vec: &[E]) -> u32 {
         .fold(0, |a, i| a + i)
 }
 
+/// Size of serialized repeated packed `EnumOrUnknown` field, excluding length and tag.
+pub fn vec_packed_enum_or_unknown_data_size<E : ProtobufEnum>(vec: &[EnumOrUnknown<E>]) -> u32 {
+    vec.iter()
+        .map(|e| e.value().len_varint())
+        .fold(0, |a, i| a + i)
+}
+
 /// Size of serialized data with length prefix and tag
 pub fn vec_packed_varint_size<T : ProtobufVarint>(field_number: u32, vec: &[T]) -> u32 {
     if vec.is_empty() {
@@ -184,6 +194,19 @@ pub fn vec_packed_enum_size<E : ProtobufEnum>(field_number: u32, vec: &[E]) -> u
     }
 }
 
+/// Size of serialized data with length prefix and tag
+pub fn vec_packed_enum_or_unknown_size<E : ProtobufEnum>(
+    field_number: u32,
+    vec: &[EnumOrUnknown<E>],
+) -> u32 {
+    if vec.is_empty() {
+        0
+    } else {
+        let data_size = vec_packed_enum_or_unknown_data_size(vec);
+        tag_size(field_number) + data_size.len_varint() + data_size
+    }
+}
+
 /// Compute tag size. Size of tag does not depend on wire type.
 pub fn tag_size(field_number: u32) -> u32 {
     wire_format::Tag::make(field_number, WireTypeFixed64)
@@ -224,6 +247,11 @@ pub fn enum_size<E : ProtobufEnum>(field_number: u32, value: E) -> u32 {
     tag_size(field_number) + enum_size_no_tag(value)
 }
 
+/// Size of encoded `EnumOrUnknown` field value.
+pub fn enum_or_unknown_size<E : ProtobufEnum>(field_number: u32, value: EnumOrUnknown<E>) -> u32 {
+    tag_size(field_number) + value.value().len_varint()
+}
+
 fn bytes_size_no_tag(bytes: &[u8]) -> u32 {
     compute_raw_varint64_size(bytes.len() as u64) + bytes.len() as u32
 }
@@ -272,6 +300,7 @@ pub fn read_repeated_int32_into(
     match wire_type {
         WireTypeLengthDelimited => is.read_repeated_packed_int32_into(target),
         WireTypeVarint => {
+            is.check_repeated_len(target.len())?;
             target.push(is.read_int32()?);
             Ok(())
         }
@@ -288,6 +317,7 @@ pub fn read_repeated_int64_into(
     match wire_type {
         WireTypeLengthDelimited => is.read_repeated_packed_int64_into(target),
         WireTypeVarint => {
+            is.check_repeated_len(target.len())?;
             target.push(is.read_int64()?);
             Ok(())
         }
@@ -304,6 +334,7 @@ pub fn read_repeated_uint32_into(
     match wire_type {
         WireTypeLengthDelimited => is.read_repeated_packed_uint32_into(target),
         WireTypeVarint => {
+            is.check_repeated_len(target.len())?;
             target.push(is.read_uint32()?);
             Ok(())
         }
@@ -320,6 +351,7 @@ pub fn read_repeated_uint64_into(
     match wire_type {
         WireTypeLengthDelimited => is.read_repeated_packed_uint64_into(target),
         WireTypeVarint => {
+            is.check_repeated_len(target.len())?;
             target.push(is.read_uint64()?);
             Ok(())
         }
@@ -336,6 +368,7 @@ pub fn read_repeated_sint32_into(
     match wire_type {
         WireTypeLengthDelimited => is.read_repeated_packed_sint32_into(target),
         WireTypeVarint => {
+            is.check_repeated_len(target.len())?;
             target.push(is.read_sint32()?);
             Ok(())
         }
@@ -352,6 +385,7 @@ pub fn read_repeated_sint64_into(
     match wire_type {
         WireTypeLengthDelimited => is.read_repeated_packed_sint64_into(target),
         WireTypeVarint => {
+            is.check_repeated_len(target.len())?;
             target.push(is.read_sint64()?);
             Ok(())
         }
@@ -368,6 +402,7 @@ pub fn read_repeated_fixed32_into(
     match wire_type {
         WireTypeLengthDelimited => is.read_repeated_packed_fixed32_into(target),
         WireTypeFixed32 => {
+            is.check_repeated_len(target.len())?;
             target.push(is.read_fixed32()?);
             Ok(())
         }
@@ -384,6 +419,7 @@ pub fn read_repeated_fixed64_into(
     match wire_type {
         WireTypeLengthDelimited => is.read_repeated_packed_fixed64_into(target),
         WireTypeFixed64 => {
+            is.check_repeated_len(target.len())?;
             target.push(is.read_fixed64()?);
             Ok(())
         }
@@ -400,6 +436,7 @@ pub fn read_repeated_sfixed32_into(
     match wire_type {
         WireTypeLengthDelimited => is.read_repeated_packed_sfixed32_into(target),
         WireTypeFixed32 => {
+            is.check_repeated_len(target.len())?;
             target.push(is.read_sfixed32()?);
             Ok(())
         }
@@ -416,6 +453,7 @@ pub fn read_repeated_sfixed64_into(
     match wire_type {
         WireTypeLengthDelimited => is.read_repeated_packed_sfixed64_into(target),
         WireTypeFixed64 => {
+            is.check_repeated_len(target.len())?;
             target.push(is.read_sfixed64()?);
             Ok(())
         }
@@ -432,6 +470,7 @@ pub fn read_repeated_double_into(
     match wire_type {
         WireTypeLengthDelimited => is.read_repeated_packed_double_into(target),
         WireTypeFixed64 => {
+            is.check_repeated_len(target.len())?;
             target.push(is.read_double()?);
             Ok(())
         }
@@ -448,6 +487,7 @@ pub fn read_repeated_float_into(
     match wire_type {
         WireTypeLengthDelimited => is.read_repeated_packed_float_into(target),
         WireTypeFixed32 => {
+            is.check_repeated_len(target.len())?;
             target.push(is.read_float()?);
             Ok(())
         }
@@ -464,6 +504,7 @@ pub fn read_repeated_bool_into(
     match wire_type {
         WireTypeLengthDelimited => is.read_repeated_packed_bool_into(target),
         WireTypeVarint => {
+            is.check_repeated_len(target.len())?;
             target.push(is.read_bool()?);
             Ok(())
         }
@@ -481,6 +522,7 @@ pub fn read_repeated_enum_into<E : ProtobufEnum>(
     match wire_type {
         WireTypeLengthDelimited => is.read_repeated_packed_enum_into(target),
         WireTypeVarint => {
+            is.check_repeated_len(target.len())?;
             target.push(is.read_enum()?);
             Ok(())
         }
@@ -513,8 +555,10 @@ fn read_repeated_packed_enum_with_unknown_fields_into<E : ProtobufEnum>(
     unknown_fields: &mut UnknownFields,
 ) -> ProtobufResult<()> {
     let len = is.read_raw_varint64()?;
+    is.check_length(len, true)?;
     let old_limit = is.push_limit(len)?;
     while !is.eof()? {
+        is.check_repeated_len(target.len())?;
         read_enum_with_unknown_fields_into(is, |e| target.push(e), field_number, unknown_fields)?;
     }
     is.pop_limit(old_limit);
@@ -540,6 +584,7 @@ pub fn read_repeated_enum_with_unknown_fields_into<E : ProtobufEnum>(
                 is, target, field_number, unknown_fields)
         },
         WireTypeVarint => {
+            is.check_repeated_len(target.len())?;
             read_enum_with_unknown_fields_into(is, |e| target.push(e), field_number, unknown_fields)
         }
         _ => Err(unexpected_wire_type(wire_type)),
@@ -588,6 +633,23 @@ pub fn read_proto2_enum_with_unknown_fields_into<E : ProtobufEnum>(
     read_enum_with_unknown_fields_into(is, |e| { *target = Some(e) }, field_number, unknown_fields)
 }
 
+/// Read repeated `enum` field into given vec keeping unknown values in the field.
+pub fn read_repeated_enum_or_unknown_into<E : ProtobufEnum>(
+    wire_type: WireType,
+    is: &mut CodedInputStream,
+    target: &mut Vec<EnumOrUnknown<E>>,
+) -> ProtobufResult<()> {
+    match wire_type {
+        WireTypeLengthDelimited => is.read_repeated_packed_enum_or_unknown_into(target),
+        WireTypeVarint => {
+            is.check_repeated_len(target.len())?;
+            target.push(is.read_enum_or_unknown()?);
+            Ok(())
+        }
+        _ => Err(unexpected_wire_type(wire_type)),
+    }
+}
+
 /// Read repeated `string` field into given vec.
 pub fn read_repeated_string_into(
     wire_type: WireType,
@@ -596,8 +658,10 @@ pub fn read_repeated_string_into(
 ) -> ProtobufResult<()> {
     match wire_type {
         WireTypeLengthDelimited => {
+            is.check_repeated_len(target.len())?;
+            let index = target.len();
             let tmp = target.push_default();
-            is.read_string_into(tmp)
+            is.read_string_into(tmp).map_err(|e| is.locate_repeated_error(e, index))
         }
         _ => Err(unexpected_wire_type(wire_type)),
     }
@@ -612,6 +676,7 @@ pub fn read_repeated_carllerche_string_into(
 ) -> ProtobufResult<()> {
     match wire_type {
         WireTypeLengthDelimited => {
+            is.check_repeated_len(target.len())?;
             target.push(is.read_carllerche_chars()?);
             Ok(())
         }
@@ -686,8 +751,10 @@ pub fn read_repeated_bytes_into(
 ) -> ProtobufResult<()> {
     match wire_type {
         WireTypeLengthDelimited => {
+            is.check_repeated_len(target.len())?;
+            let index = target.len();
             let tmp = target.push_default();
-            is.read_bytes_into(tmp)
+            is.read_bytes_into(tmp).map_err(|e| is.locate_repeated_error(e, index))
         }
         _ => Err(unexpected_wire_type(wire_type)),
     }
@@ -702,6 +769,7 @@ pub fn read_repeated_carllerche_bytes_into(
 ) -> ProtobufResult<()> {
     match wire_type {
         WireTypeLengthDelimited => {
+            is.check_repeated_len(target.len())?;
             target.push(is.read_carllerche_bytes()?);
             Ok(())
         }
@@ -777,10 +845,12 @@ pub fn read_repeated_message_into<M : Message + Default>(
     match wire_type {
         WireTypeLengthDelimited => {
             is.incr_recursion()?;
+            is.check_repeated_len(target.len())?;
+            let index = target.len();
             let tmp = target.push_default();
             let res = is.merge_message(tmp);
             is.decr_recursion();
-            res
+            res.map_err(|e| is.locate_repeated_error(e, index))
         }
         _ => Err(unexpected_wire_type(wire_type)),
     }
@@ -804,13 +874,120 @@ pub fn read_singular_message_into<M : Message + Default>(
     }
 }
 
+/// Read repeated `group` field.
+pub fn read_repeated_group_into<M : Message + Default>(
+    field_number: u32,
+    wire_type: WireType,
+    is: &mut CodedInputStream,
+    target: &mut RepeatedField<M>,
+) -> ProtobufResult<()> {
+    match wire_type {
+        WireTypeStartGroup => {
+            is.incr_recursion()?;
+            is.check_repeated_len(target.len())?;
+            let index = target.len();
+            let tmp = target.push_default();
+            let res = is.merge_group(tmp, field_number);
+            is.decr_recursion();
+            res.map_err(|e| is.locate_repeated_error(e, index))
+        }
+        _ => Err(unexpected_wire_type(wire_type)),
+    }
+}
+
+/// Read singular `group` field.
+pub fn read_singular_group_into<M : Message + Default>(
+    field_number: u32,
+    wire_type: WireType,
+    is: &mut CodedInputStream,
+    target: &mut SingularPtrField<M>,
+) -> ProtobufResult<()> {
+    match wire_type {
+        WireTypeStartGroup => {
+            is.incr_recursion()?;
+            let tmp = target.set_default();
+            let res = is.merge_group(tmp, field_number);
+            is.decr_recursion();
+            res
+        }
+        _ => Err(unexpected_wire_type(wire_type)),
+    }
+}
+
+/// Read singular wrapper message field (e. g. `google.protobuf.Int32Value`)
+/// stored as `Option` of wrapped value. Unknown fields of the wrapper are dropped.
+pub fn read_singular_wrapper_into<V : ProtobufType>(
+    wire_type: WireType,
+    is: &mut CodedInputStream,
+    target: &mut Option<V::Value>,
+) -> ProtobufResult<()>
+where
+    V::Value : Default,
+{
+    match wire_type {
+        WireTypeLengthDelimited => {
+            let len = is.read_raw_varint32()?;
+            is.check_length(len as u64, false)?;
+            let old_limit = is.push_limit(len as u64)?;
+            let mut value = target.take().unwrap_or_default();
+            while !is.eof()? {
+                let (field_number, wire_type) = is.read_inner_tag_unpack()?;
+                if field_number == 1 && wire_type == V::wire_type() {
+                    value = V::read(is)?;
+                } else {
+                    is.skip_field(wire_type)?;
+                }
+            }
+            is.pop_limit(old_limit);
+            *target = Some(value);
+            Ok(())
+        }
+        _ => Err(unexpected_wire_type(wire_type)),
+    }
+}
+
+/// Size of wrapper message content, default value is not serialized
+fn wrapper_content_size<V : ProtobufType>(value: &V::Value) -> u32 {
+    if value.is_non_zero() {
+        tag_size(1) + V::compute_size_with_length_delimiter(value)
+    } else {
+        0
+    }
+}
+
+/// Compute serialized size of wrapper message field stored as wrapped value.
+pub fn wrapper_size<V : ProtobufType>(field_number: u32, value: &V::Value) -> u32 {
+    let len = wrapper_content_size::<V>(value);
+    tag_size(field_number) + compute_raw_varint32_size(len) + len
+}
+
+/// Write wrapper message field stored as wrapped value.
+pub fn write_wrapper_with_cached_size<V : ProtobufType>(
+    field_number: u32,
+    value: &V::Value,
+    os: &mut CodedOutputStream,
+) -> ProtobufResult<()> {
+    os.write_tag(field_number, WireTypeLengthDelimited)?;
+    os.write_raw_varint32(wrapper_content_size::<V>(value))?;
+    if value.is_non_zero() {
+        V::write_with_cached_size(1, value, os)?;
+    }
+    Ok(())
+}
+
 fn skip_group(is: &mut CodedInputStream) -> ProtobufResult<()> {
     loop {
-        let (_, wire_type) = is.read_tag_unpack()?;
-        if wire_type == wire_format::WireTypeEndGroup {
-            return Ok(());
+        let (_, wire_type) = is.read_inner_tag_unpack()?;
+        match wire_type {
+            wire_format::WireTypeEndGroup => return Ok(()),
+            wire_format::WireTypeStartGroup => {
+                is.incr_recursion()?;
+                let res = skip_group(is);
+                is.decr_recursion();
+                res?;
+            }
+            _ => is.skip_field(wire_type)?,
         }
-        is.skip_field(wire_type)?;
     }
 }
 
@@ -911,9 +1088,10 @@ where
     let mut value = None;
 
     let len = is.read_raw_varint32()?;
+    is.check_length(len as u64, false)?;
     let old_limit = is.push_limit(len as u64)?;
     while !is.eof()? {
-        let (field_number, wire_type) = is.read_tag_unpack()?;
+        let (field_number, wire_type) = is.read_inner_tag_unpack()?;
         match field_number {
             1 => {
                 if wire_type != K::wire_type() {
@@ -935,6 +1113,9 @@ where
     match (key, value) {
         (None, _) | (_, None) => return Err(ProtobufError::WireError(WireError::IncompleteMap)),
         (Some(key), Some(value)) => {
+            if !target.contains_key(&key) {
+                is.check_repeated_len(target.len())?;
+            }
             target.insert(key, value);
         }
     }
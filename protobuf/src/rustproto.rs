@@ -34,6 +34,16 @@ pub mod exts {
 
     pub const carllerche_bytes_for_string_all: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FileOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17012, phantom: ::std::marker::PhantomData };
 
+    pub const serde_derive_all: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FileOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17030, phantom: ::std::marker::PhantomData };
+
+    pub const wrappers_as_option_all: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FileOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17040, phantom: ::std::marker::PhantomData };
+
+    pub const gen_views_all: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FileOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17041, phantom: ::std::marker::PhantomData };
+
+    pub const gen_services_all: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FileOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17042, phantom: ::std::marker::PhantomData };
+
+    pub const enum_or_unknown_all: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FileOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17043, phantom: ::std::marker::PhantomData };
+
     pub const expose_oneof: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::MessageOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17001, phantom: ::std::marker::PhantomData };
 
     pub const expose_fields: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::MessageOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17003, phantom: ::std::marker::PhantomData };
@@ -44,6 +54,8 @@ pub mod exts {
 
     pub const carllerche_bytes_for_string: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::MessageOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17012, phantom: ::std::marker::PhantomData };
 
+    pub const serde_derive: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::MessageOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17030, phantom: ::std::marker::PhantomData };
+
     pub const expose_fields_field: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FieldOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17003, phantom: ::std::marker::PhantomData };
 
     pub const generate_accessors_field: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FieldOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17004, phantom: ::std::marker::PhantomData };
@@ -53,6 +65,30 @@ pub mod exts {
     pub const carllerche_bytes_for_string_field: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FieldOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17012, phantom: ::std::marker::PhantomData };
 }
 
+// Register message types and extensions of this file in `::protobuf::reflect::registry`
+pub fn register_message_types() {
+    exts::expose_oneof_all.register(file_descriptor_proto());
+    exts::expose_fields_all.register(file_descriptor_proto());
+    exts::generate_accessors_all.register(file_descriptor_proto());
+    exts::carllerche_bytes_for_bytes_all.register(file_descriptor_proto());
+    exts::carllerche_bytes_for_string_all.register(file_descriptor_proto());
+    exts::serde_derive_all.register(file_descriptor_proto());
+    exts::wrappers_as_option_all.register(file_descriptor_proto());
+    exts::gen_views_all.register(file_descriptor_proto());
+    exts::gen_services_all.register(file_descriptor_proto());
+    exts::enum_or_unknown_all.register(file_descriptor_proto());
+    exts::expose_oneof.register(file_descriptor_proto());
+    exts::expose_fields.register(file_descriptor_proto());
+    exts::generate_accessors.register(file_descriptor_proto());
+    exts::carllerche_bytes_for_bytes.register(file_descriptor_proto());
+    exts::carllerche_bytes_for_string.register(file_descriptor_proto());
+    exts::serde_derive.register(file_descriptor_proto());
+    exts::expose_fields_field.register(file_descriptor_proto());
+    exts::generate_accessors_field.register(file_descriptor_proto());
+    exts::carllerche_bytes_for_bytes_field.register(file_descriptor_proto());
+    exts::carllerche_bytes_for_string_field.register(file_descriptor_proto());
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x0frustproto.proto\x12\trustproto\x1a\x20google/protobuf/descriptor.p\
     roto:H\n\x10expose_oneof_all\x18\xe9\x84\x01\x20\x01(\x08\x12\x1c.google\
@@ -63,27 +99,36 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     che_bytes_for_bytes_all\x18\xf3\x84\x01\x20\x01(\x08\x12\x1c.google.prot\
     obuf.FileOptionsR\x1acarllercheBytesForBytesAll:d\n\x1fcarllerche_bytes_\
     for_string_all\x18\xf4\x84\x01\x20\x01(\x08\x12\x1c.google.protobuf.File\
-    OptionsR\x1bcarllercheBytesForStringAll:D\n\x0cexpose_oneof\x18\xe9\x84\
-    \x01\x20\x01(\x08\x12\x1f.google.protobuf.MessageOptionsR\x0bexposeOneof\
-    :F\n\rexpose_fields\x18\xeb\x84\x01\x20\x01(\x08\x12\x1f.google.protobuf\
-    .MessageOptionsR\x0cexposeFields:P\n\x12generate_accessors\x18\xec\x84\
-    \x01\x20\x01(\x08\x12\x1f.google.protobuf.MessageOptionsR\x11generateAcc\
-    essors:^\n\x1acarllerche_bytes_for_bytes\x18\xf3\x84\x01\x20\x01(\x08\
-    \x12\x1f.google.protobuf.MessageOptionsR\x17carllercheBytesForBytes:`\n\
-    \x1bcarllerche_bytes_for_string\x18\xf4\x84\x01\x20\x01(\x08\x12\x1f.goo\
-    gle.protobuf.MessageOptionsR\x18carllercheBytesForString:O\n\x13expose_f\
-    ields_field\x18\xeb\x84\x01\x20\x01(\x08\x12\x1d.google.protobuf.FieldOp\
-    tionsR\x11exposeFieldsField:Y\n\x18generate_accessors_field\x18\xec\x84\
-    \x01\x20\x01(\x08\x12\x1d.google.protobuf.FieldOptionsR\x16generateAcces\
-    sorsField:g\n\x20carllerche_bytes_for_bytes_field\x18\xf3\x84\x01\x20\
-    \x01(\x08\x12\x1d.google.protobuf.FieldOptionsR\x1ccarllercheBytesForByt\
-    esField:i\n!carllerche_bytes_for_string_field\x18\xf4\x84\x01\x20\x01(\
-    \x08\x12\x1d.google.protobuf.FieldOptionsR\x1dcarllercheBytesForStringFi\
-    eldJ\xf4\x0e\n\x06\x12\x04\0\0,\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\t\
-    \n\x02\x03\0\x12\x03\x02\x07)\nh\n\x01\x02\x12\x03\x07\x08\x112^\x20see\
-    \x20https://github.com/gogo/protobuf/blob/master/gogoproto/gogo.proto\n\
-    \x20for\x20the\x20original\x20idea\n\n\t\n\x01\x07\x12\x04\t\0\x14\x01\n\
-    7\n\x02\x07\0\x12\x03\x0b\x04+\x1a,\x20When\x20true,\x20oneof\x20field\
+    OptionsR\x1bcarllercheBytesForStringAll:H\n\x10serde_derive_all\x18\x86\
+    \x85\x01\x20\x01(\x08\x12\x1c.google.protobuf.FileOptionsR\x0eserdeDeriv\
+    eAll:S\n\x16wrappers_as_option_all\x18\x90\x85\x01\x20\x01(\x08\x12\x1c.\
+    google.protobuf.FileOptionsR\x13wrappersAsOptionAll:B\n\rgen_views_all\
+    \x18\x91\x85\x01\x20\x01(\x08\x12\x1c.google.protobuf.FileOptionsR\x0bge\
+    nViewsAll:H\n\x10gen_services_all\x18\x92\x85\x01\x20\x01(\x08\x12\x1c.g\
+    oogle.protobuf.FileOptionsR\x0egenServicesAll:M\n\x13enum_or_unknown_all\
+    \x18\x93\x85\x01\x20\x01(\x08\x12\x1c.google.protobuf.FileOptionsR\x10en\
+    umOrUnknownAll:D\n\x0cexpose_oneof\x18\xe9\x84\x01\x20\x01(\x08\x12\x1f.\
+    google.protobuf.MessageOptionsR\x0bexposeOneof:F\n\rexpose_fields\x18\
+    \xeb\x84\x01\x20\x01(\x08\x12\x1f.google.protobuf.MessageOptionsR\x0cexp\
+    oseFields:P\n\x12generate_accessors\x18\xec\x84\x01\x20\x01(\x08\x12\x1f\
+    .google.protobuf.MessageOptionsR\x11generateAccessors:^\n\x1acarllerche_\
+    bytes_for_bytes\x18\xf3\x84\x01\x20\x01(\x08\x12\x1f.google.protobuf.Mes\
+    sageOptionsR\x17carllercheBytesForBytes:`\n\x1bcarllerche_bytes_for_stri\
+    ng\x18\xf4\x84\x01\x20\x01(\x08\x12\x1f.google.protobuf.MessageOptionsR\
+    \x18carllercheBytesForString:D\n\x0cserde_derive\x18\x86\x85\x01\x20\x01\
+    (\x08\x12\x1f.google.protobuf.MessageOptionsR\x0bserdeDerive:O\n\x13expo\
+    se_fields_field\x18\xeb\x84\x01\x20\x01(\x08\x12\x1d.google.protobuf.Fie\
+    ldOptionsR\x11exposeFieldsField:Y\n\x18generate_accessors_field\x18\xec\
+    \x84\x01\x20\x01(\x08\x12\x1d.google.protobuf.FieldOptionsR\x16generateA\
+    ccessorsField:g\n\x20carllerche_bytes_for_bytes_field\x18\xf3\x84\x01\
+    \x20\x01(\x08\x12\x1d.google.protobuf.FieldOptionsR\x1ccarllercheBytesFo\
+    rBytesField:i\n!carllerche_bytes_for_string_field\x18\xf4\x84\x01\x20\
+    \x01(\x08\x12\x1d.google.protobuf.FieldOptionsR\x1dcarllercheBytesForStr\
+    ingFieldJ\xf4\x0e\n\x06\x12\x04\0\0,\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\
+    \n\t\n\x02\x03\0\x12\x03\x02\x07)\nh\n\x01\x02\x12\x03\x07\x08\x112^\x20\
+    see\x20https://github.com/gogo/protobuf/blob/master/gogoproto/gogo.proto\
+    \n\x20for\x20the\x20original\x20idea\n\n\t\n\x01\x07\x12\x04\t\0\x14\x01\
+    \n7\n\x02\x07\0\x12\x03\x0b\x04+\x1a,\x20When\x20true,\x20oneof\x20field\
     \x20is\x20generated\x20public\n\n\n\n\x03\x07\0\x02\x12\x03\t\x07\"\n\n\
     \n\x03\x07\0\x04\x12\x03\x0b\x04\x0c\n\n\n\x03\x07\0\x05\x12\x03\x0b\r\
     \x11\n\n\n\x03\x07\0\x01\x12\x03\x0b\x12\"\n\n\n\x03\x07\0\x03\x12\x03\
@@ -5,6 +5,9 @@ use std::default::Default;
 use std::fmt;
 use std::mem;
 
+#[cfg(feature = "with-serde")]
+use serde;
+
 use clear::Clear;
 
 
@@ -466,6 +469,36 @@ impl<'a, T> IntoIterator for &'a SingularPtrField<T> {
     }
 }
 
+/// Serialized as `Option`.
+#[cfg(feature = "with-serde")]
+impl<T : serde::Serialize> serde::Serialize for SingularField<T> {
+    fn serialize<S : serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_ref().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "with-serde")]
+impl<'de, T : serde::Deserialize<'de> + Default> serde::Deserialize<'de> for SingularField<T> {
+    fn deserialize<D : serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Option::deserialize(deserializer).map(SingularField::from_option)
+    }
+}
+
+/// Serialized as `Option`.
+#[cfg(feature = "with-serde")]
+impl<T : serde::Serialize> serde::Serialize for SingularPtrField<T> {
+    fn serialize<S : serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_ref().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "with-serde")]
+impl<'de, T : serde::Deserialize<'de>> serde::Deserialize<'de> for SingularPtrField<T> {
+    fn deserialize<D : serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Option::deserialize(deserializer).map(SingularPtrField::from_option)
+    }
+}
+
 
 #[cfg(test)]
 mod test {
@@ -495,4 +528,39 @@ mod test {
         x.set_default();
         assert_eq!(0, x.as_ref().unwrap().b);
     }
+
+    #[cfg(feature = "with-serde")]
+    #[test]
+    fn test_serde() {
+        use serde_test::assert_tokens;
+        use serde_test::Token;
+        use super::SingularPtrField;
+
+        let mut x = SingularField::some("aa".to_owned());
+        x.clear();
+        assert_tokens(&x, &[Token::None]);
+        let x = SingularPtrField::some(7u32);
+        assert_tokens(&x, &[Token::Some, Token::U32(7)]);
+    }
+
+    #[cfg(feature = "with-serde")]
+    #[test]
+    fn test_serde_generated() {
+        use serde_test::assert_tokens;
+        use serde_test::Token;
+        use well_known_types::Value;
+        use well_known_types::NullValue;
+
+        let mut value = Value::new();
+        value.set_null_value(NullValue::NULL_VALUE);
+        assert_tokens(&value, &[
+            Token::Struct { name: "Value", len: 1 },
+            Token::Str("kind"),
+            Token::Some,
+            Token::Enum { name: "Value_oneof_kind" },
+            Token::Str("null_value"),
+            Token::UnitVariant { name: "NullValue", variant: "NULL_VALUE" },
+            Token::StructEnd,
+        ]);
+    }
 }
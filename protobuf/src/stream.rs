@@ -14,6 +14,7 @@ use misc::remaining_capacity_as_slice_mut;
 use misc::remove_lifetime_mut;
 use core::Message;
 use core::ProtobufEnum;
+use enum_or_unknown::EnumOrUnknown;
 use unknown::UnknownFields;
 use unknown::UnknownValue;
 use unknown::UnknownValueRef;
@@ -24,6 +25,7 @@ use zigzag::encode_zig_zag_64;
 use error::ProtobufResult;
 use error::ProtobufError;
 use error::WireError;
+use error::ErrorLocation;
 use buf_read_iter::BufReadIter;
 
 // Equal to the default buffer size of `BufWriter`, so when
@@ -31,7 +33,7 @@ use buf_read_iter::BufReadIter;
 const OUTPUT_STREAM_BUFFER_SIZE: usize = 8 * 1024;
 
 // Default recursion level limit. 100 is the default value of C++'s implementation.
-const DEFAULT_RECURSION_LIMIT: u32 = 100;
+pub(crate) const DEFAULT_RECURSION_LIMIT: u32 = 100;
 
 
 pub mod wire_format {
@@ -121,10 +123,46 @@ pub mod wire_format {
 
 }
 
+/// Limits applied by `CodedInputStream` to protect from hostile input,
+/// all unlimited by default, and error reporting options
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// Max number of bytes read from the stream,
+    /// `WireError::MessageTooLarge` when exceeded
+    pub max_total_bytes: Option<u64>,
+    /// Max length of `string`, `bytes`, packed repeated or unknown length-delimited field,
+    /// `WireError::FieldTooLarge` when exceeded
+    pub max_field_length: Option<u64>,
+    /// Max number of elements of single repeated or map field,
+    /// `WireError::TooManyElements` when exceeded
+    pub max_repeated_len: Option<u64>,
+    /// Wrap decoding errors in `ProtobufError::Located` with byte offset, field number
+    /// and field path; report missing required fields with their paths
+    /// in `ProtobufError::MissingFields`
+    pub locate_errors: bool,
+}
+
 pub struct CodedInputStream<'a> {
     source: BufReadIter<'a>,
     recursion_level: u32,
     recursion_limit: u32,
+    parse_options: ParseOptions,
+    // number of the field of current message being read, reported in errors
+    field_number: Option<u32>,
+    // number of limits pushed
+    limit_depth: u32,
+    // group being read by `merge_group`
+    group: Option<GroupState>,
+    // tag read by `eof` inside a group, returned by next `read_tag`
+    pending_tag: Option<wire_format::Tag>,
+}
+
+#[derive(Copy, Clone)]
+struct GroupState {
+    field_number: u32,
+    // `limit_depth` when group started; `eof` looks for end of group only at this depth
+    limit_depth: u32,
+    ended: bool,
 }
 
 impl<'a> CodedInputStream<'a> {
@@ -150,6 +188,11 @@ impl<'a> CodedInputStream<'a> {
             source: source,
             recursion_level: 0,
             recursion_limit: DEFAULT_RECURSION_LIMIT,
+            parse_options: ParseOptions::default(),
+            field_number: None,
+            limit_depth: 0,
+            group: None,
+            pending_tag: None,
         }
     }
 
@@ -158,6 +201,59 @@ impl<'a> CodedInputStream<'a> {
         self.recursion_limit = limit;
     }
 
+    /// Set size limits checked while reading.
+    pub fn set_parse_options(&mut self, options: ParseOptions) {
+        self.parse_options = options;
+    }
+
+    pub fn parse_options(&self) -> &ParseOptions {
+        &self.parse_options
+    }
+
+    /// Check declared length of length-delimited value before reading it;
+    /// `field_length_limited` is false for nested messages.
+    pub(crate) fn check_length(&self, len: u64, field_length_limited: bool) -> ProtobufResult<()> {
+        if field_length_limited {
+            if let Some(max) = self.parse_options.max_field_length {
+                if len > max {
+                    return Err(ProtobufError::WireError(WireError::FieldTooLarge(len)));
+                }
+            }
+        }
+        if let Some(max) = self.parse_options.max_total_bytes {
+            let end = self.pos().saturating_add(len);
+            if end > max {
+                return Err(ProtobufError::WireError(WireError::MessageTooLarge(end)));
+            }
+        }
+        Ok(())
+    }
+
+    /// Check that one more element can be added to repeated or map field of `len` elements.
+    #[inline]
+    pub(crate) fn check_repeated_len(&self, len: usize) -> ProtobufResult<()> {
+        if let Some(max) = self.parse_options.max_repeated_len {
+            if len as u64 >= max {
+                return Err(ProtobufError::WireError(WireError::TooManyElements(len as u64 + 1)));
+            }
+        }
+        Ok(())
+    }
+
+    /// Read length of packed repeated field, check it and reserve space for fixed-size elements
+    fn read_packed_len<T>(&mut self, target: &mut Vec<T>, elem_size: u64) -> ProtobufResult<u64> {
+        let len = self.read_raw_varint64()?;
+        self.check_length(len, true)?;
+        if elem_size != 0 {
+            let mut count = len / elem_size;
+            if let Some(max) = self.parse_options.max_repeated_len {
+                count = ::std::cmp::min(count, max);
+            }
+            target.reserve(count as usize);
+        }
+        Ok(len)
+    }
+
     #[inline]
     pub(crate) fn incr_recursion(&mut self) -> ProtobufResult<()> {
         if self.recursion_level >= self.recursion_limit {
@@ -196,16 +292,52 @@ impl<'a> CodedInputStream<'a> {
     }
 
     pub fn push_limit(&mut self, limit: u64) -> ProtobufResult<u64> {
-        self.source.push_limit(limit)
+        let old_limit = self.source.push_limit(limit)?;
+        self.limit_depth += 1;
+        Ok(old_limit)
     }
 
     pub fn pop_limit(&mut self, old_limit: u64) {
         self.source.pop_limit(old_limit);
+        self.limit_depth -= 1;
     }
 
+    /// End of current limit, or end of group when reading group fields
     #[inline(always)]
     pub fn eof(&mut self) -> ProtobufResult<bool> {
-        self.source.eof()
+        if let Some(max) = self.parse_options.max_total_bytes {
+            if self.pos() > max {
+                return Err(ProtobufError::WireError(WireError::MessageTooLarge(self.pos())));
+            }
+        }
+        if self.group.is_none() {
+            return self.source.eof();
+        }
+        self.group_eof()
+    }
+
+    fn group_eof(&mut self) -> ProtobufResult<bool> {
+        if self.pending_tag.is_some() {
+            return Ok(false);
+        }
+        let group = match self.group {
+            Some(group) if group.limit_depth == self.limit_depth => group,
+            _ => return self.source.eof(),
+        };
+        if group.ended {
+            return Ok(true);
+        }
+        if self.source.eof()? {
+            // missing end of group is reported by `merge_group`
+            return Ok(true);
+        }
+        let tag = self.read_tag()?;
+        if tag.unpack() == (group.field_number, wire_format::WireTypeEndGroup) {
+            self.group = Some(GroupState { ended: true, ..group });
+            return Ok(true);
+        }
+        self.pending_tag = Some(tag);
+        Ok(false)
     }
 
     pub fn check_eof(&mut self) -> ProtobufResult<()> {
@@ -328,9 +460,16 @@ impl<'a> CodedInputStream<'a> {
 
     #[inline]
     pub fn read_tag(&mut self) -> ProtobufResult<wire_format::Tag> {
+        if let Some(tag) = self.pending_tag.take() {
+            return Ok(tag);
+        }
+        self.field_number = None;
         let v = self.read_raw_varint32()?;
         match wire_format::Tag::new(v) {
-            Some(tag) => Ok(tag),
+            Some(tag) => {
+                self.field_number = Some(tag.field_number());
+                Ok(tag)
+            }
             None => Err(ProtobufError::WireError(WireError::IncorrectTag(v))),
         }
     }
@@ -341,6 +480,80 @@ impl<'a> CodedInputStream<'a> {
         self.read_tag().map(|t| t.unpack())
     }
 
+    /// Read tag inside map entry, wrapper or group,
+    /// errors are reported at the enclosing field of the message
+    pub(crate) fn read_inner_tag_unpack(
+        &mut self,
+    ) -> ProtobufResult<(u32, wire_format::WireType)> {
+        let field_number = self.field_number;
+        let r = self.read_tag_unpack();
+        self.field_number = field_number;
+        r
+    }
+
+    fn split_error_location(&self, e: ProtobufError) -> (Box<ProtobufError>, ErrorLocation) {
+        match e {
+            ProtobufError::Located { error, location } => (error, location),
+            e => (Box::new(e), ErrorLocation::new(self.pos(), self.field_number)),
+        }
+    }
+
+    /// Add current field of `message` to the location of error.
+    ///
+    /// Messages without descriptor (`LITE_RUNTIME`) are reported by field numbers.
+    fn locate_error<M : Message + ?Sized>(&self, e: ProtobufError, message: &M) -> ProtobufError {
+        if !self.parse_options.locate_errors {
+            return e;
+        }
+        let (error, mut location) = self.split_error_location(e);
+        let descriptor = message.descriptor_if_available();
+        let message_name = descriptor.map_or("", |d| d.name());
+        match self.field_number {
+            Some(number) => {
+                let field = descriptor.and_then(|d| d.get_field_by_number(number));
+                let name = match field {
+                    Some(field) => field.name().to_owned(),
+                    None => number.to_string(),
+                };
+                location.push_field(message_name, name);
+            }
+            None => location.set_message(message_name),
+        }
+        ProtobufError::Located {
+            error: error,
+            location: location,
+        }
+    }
+
+    /// Add index of repeated field element to the location of error
+    pub(crate) fn locate_repeated_error(&self, e: ProtobufError, index: usize) -> ProtobufError {
+        if !self.parse_options.locate_errors {
+            return e;
+        }
+        let (error, mut location) = self.split_error_location(e);
+        location.set_index(index);
+        ProtobufError::Located {
+            error: error,
+            location: location,
+        }
+    }
+
+    /// Read fields of `message` with `merge`, errors get location in input
+    /// and path of the field where decoding failed if `ParseOptions::locate_errors` is set
+    pub(crate) fn merge_located<M, F>(&mut self, message: &mut M, merge: F) -> ProtobufResult<()>
+    where
+        M : Message + ?Sized,
+        F : FnOnce(&mut M, &mut CodedInputStream<'a>) -> ProtobufResult<()>,
+    {
+        let field_number = self.field_number.take();
+        let r = match merge(message, self) {
+            Ok(()) => Ok(()),
+            Err(e) => Err(self.locate_error(e, &*message)),
+        };
+        self.field_number = field_number;
+        r
+    }
+
     pub fn read_double(&mut self) -> ProtobufResult<f64> {
         let bits = self.read_raw_little_endian64()?;
         unsafe { Ok(mem::transmute::<u64, f64>(bits)) }
@@ -403,16 +616,19 @@ impl<'a> CodedInputStream<'a> {
         }
     }
 
+    /// Read `enum` as `EnumOrUnknown`, unknown numbers are not an error
+    pub fn read_enum_or_unknown<E : ProtobufEnum>(&mut self) -> ProtobufResult<EnumOrUnknown<E>> {
+        Ok(EnumOrUnknown::from_i32(self.read_int32()?))
+    }
+
     pub fn read_repeated_packed_double_into(
         &mut self,
         target: &mut Vec<f64>,
     ) -> ProtobufResult<()> {
-        let len = self.read_raw_varint64()?;
-
-        target.reserve((len / 4) as usize);
-
+        let len = self.read_packed_len(target, 8)?;
         let old_limit = self.push_limit(len)?;
         while !self.eof()? {
+            self.check_repeated_len(target.len())?;
             target.push(self.read_double()?);
         }
         self.pop_limit(old_limit);
@@ -420,12 +636,10 @@ impl<'a> CodedInputStream<'a> {
     }
 
     pub fn read_repeated_packed_float_into(&mut self, target: &mut Vec<f32>) -> ProtobufResult<()> {
-        let len = self.read_raw_varint64()?;
-
-        target.reserve((len / 4) as usize);
-
+        let len = self.read_packed_len(target, 4)?;
         let old_limit = self.push_limit(len)?;
         while !self.eof()? {
+            self.check_repeated_len(target.len())?;
             target.push(self.read_float()?);
         }
         self.pop_limit(old_limit);
@@ -433,9 +647,10 @@ impl<'a> CodedInputStream<'a> {
     }
 
     pub fn read_repeated_packed_int64_into(&mut self, target: &mut Vec<i64>) -> ProtobufResult<()> {
-        let len = self.read_raw_varint64()?;
-        let old_limit = self.push_limit(len as u64)?;
+        let len = self.read_packed_len(target, 0)?;
+        let old_limit = self.push_limit(len)?;
         while !self.eof()? {
+            self.check_repeated_len(target.len())?;
             target.push(self.read_int64()?);
         }
         self.pop_limit(old_limit);
@@ -443,9 +658,10 @@ impl<'a> CodedInputStream<'a> {
     }
 
     pub fn read_repeated_packed_int32_into(&mut self, target: &mut Vec<i32>) -> ProtobufResult<()> {
-        let len = self.read_raw_varint64()?;
+        let len = self.read_packed_len(target, 0)?;
         let old_limit = self.push_limit(len)?;
         while !self.eof()? {
+            self.check_repeated_len(target.len())?;
             target.push(self.read_int32()?);
         }
         self.pop_limit(old_limit);
@@ -456,9 +672,10 @@ impl<'a> CodedInputStream<'a> {
         &mut self,
         target: &mut Vec<u64>,
     ) -> ProtobufResult<()> {
-        let len = self.read_raw_varint64()?;
+        let len = self.read_packed_len(target, 0)?;
         let old_limit = self.push_limit(len)?;
         while !self.eof()? {
+            self.check_repeated_len(target.len())?;
             target.push(self.read_uint64()?);
         }
         self.pop_limit(old_limit);
@@ -469,9 +686,10 @@ impl<'a> CodedInputStream<'a> {
         &mut self,
         target: &mut Vec<u32>,
     ) -> ProtobufResult<()> {
-        let len = self.read_raw_varint64()?;
+        let len = self.read_packed_len(target, 0)?;
         let old_limit = self.push_limit(len)?;
         while !self.eof()? {
+            self.check_repeated_len(target.len())?;
             target.push(self.read_uint32()?);
         }
         self.pop_limit(old_limit);
@@ -482,9 +700,10 @@ impl<'a> CodedInputStream<'a> {
         &mut self,
         target: &mut Vec<i64>,
     ) -> ProtobufResult<()> {
-        let len = self.read_raw_varint64()?;
+        let len = self.read_packed_len(target, 0)?;
         let old_limit = self.push_limit(len)?;
         while !self.eof()? {
+            self.check_repeated_len(target.len())?;
             target.push(self.read_sint64()?);
         }
         self.pop_limit(old_limit);
@@ -495,9 +714,10 @@ impl<'a> CodedInputStream<'a> {
         &mut self,
         target: &mut Vec<i32>,
     ) -> ProtobufResult<()> {
-        let len = self.read_raw_varint64()?;
+        let len = self.read_packed_len(target, 0)?;
         let old_limit = self.push_limit(len)?;
         while !self.eof()? {
+            self.check_repeated_len(target.len())?;
             target.push(self.read_sint32()?);
         }
         self.pop_limit(old_limit);
@@ -508,12 +728,10 @@ impl<'a> CodedInputStream<'a> {
         &mut self,
         target: &mut Vec<u64>,
     ) -> ProtobufResult<()> {
-        let len = self.read_raw_varint64()?;
-
-        target.reserve((len / 8) as usize);
-
+        let len = self.read_packed_len(target, 8)?;
         let old_limit = self.push_limit(len)?;
         while !self.eof()? {
+            self.check_repeated_len(target.len())?;
             target.push(self.read_fixed64()?);
         }
         self.pop_limit(old_limit);
@@ -524,12 +742,10 @@ impl<'a> CodedInputStream<'a> {
         &mut self,
         target: &mut Vec<u32>,
     ) -> ProtobufResult<()> {
-        let len = self.read_raw_varint64()?;
-
-        target.reserve((len / 4) as usize);
-
+        let len = self.read_packed_len(target, 4)?;
         let old_limit = self.push_limit(len)?;
         while !self.eof()? {
+            self.check_repeated_len(target.len())?;
             target.push(self.read_fixed32()?);
         }
         self.pop_limit(old_limit);
@@ -540,12 +756,10 @@ impl<'a> CodedInputStream<'a> {
         &mut self,
         target: &mut Vec<i64>,
     ) -> ProtobufResult<()> {
-        let len = self.read_raw_varint64()?;
-
-        target.reserve((len / 8) as usize);
-
+        let len = self.read_packed_len(target, 8)?;
         let old_limit = self.push_limit(len)?;
         while !self.eof()? {
+            self.check_repeated_len(target.len())?;
             target.push(self.read_sfixed64()?);
         }
         self.pop_limit(old_limit);
@@ -556,12 +770,10 @@ impl<'a> CodedInputStream<'a> {
         &mut self,
         target: &mut Vec<i32>,
     ) -> ProtobufResult<()> {
-        let len = self.read_raw_varint64()?;
-
-        target.reserve((len / 4) as usize);
-
+        let len = self.read_packed_len(target, 4)?;
         let old_limit = self.push_limit(len)?;
         while !self.eof()? {
+            self.check_repeated_len(target.len())?;
             target.push(self.read_sfixed32()?);
         }
         self.pop_limit(old_limit);
@@ -569,13 +781,10 @@ impl<'a> CodedInputStream<'a> {
     }
 
     pub fn read_repeated_packed_bool_into(&mut self, target: &mut Vec<bool>) -> ProtobufResult<()> {
-        let len = self.read_raw_varint64()?;
-
-        // regular bool value is 1-byte size
-        target.reserve(len as usize);
-
+        let len = self.read_packed_len(target, 1)?;
         let old_limit = self.push_limit(len)?;
         while !self.eof()? {
+            self.check_repeated_len(target.len())?;
             target.push(self.read_bool()?);
         }
         self.pop_limit(old_limit);
@@ -586,15 +795,30 @@ impl<'a> CodedInputStream<'a> {
         &mut self,
         target: &mut Vec<E>,
     ) -> ProtobufResult<()> {
-        let len = self.read_raw_varint64()?;
+        let len = self.read_packed_len(target, 0)?;
         let old_limit = self.push_limit(len)?;
         while !self.eof()? {
+            self.check_repeated_len(target.len())?;
             target.push(self.read_enum()?);
         }
         self.pop_limit(old_limit);
         Ok(())
     }
 
+    pub fn read_repeated_packed_enum_or_unknown_into<E : ProtobufEnum>(
+        &mut self,
+        target: &mut Vec<EnumOrUnknown<E>>,
+    ) -> ProtobufResult<()> {
+        let len = self.read_packed_len(target, 0)?;
+        let old_limit = self.push_limit(len)?;
+        while !self.eof()? {
+            self.check_repeated_len(target.len())?;
+            target.push(self.read_enum_or_unknown()?);
+        }
+        self.pop_limit(old_limit);
+        Ok(())
+    }
+
     pub fn read_unknown(
         &mut self,
         wire_type: wire_format::WireType,
@@ -607,6 +831,7 @@ impl<'a> CodedInputStream<'a> {
             wire_format::WireTypeFixed32 => self.read_fixed32().map(|v| UnknownValue::Fixed32(v)),
             wire_format::WireTypeLengthDelimited => {
                 let len = self.read_raw_varint32()?;
+                self.check_length(len as u64, true)?;
                 self.read_raw_bytes(len)
                     .map(|v| UnknownValue::LengthDelimited(v))
             }
@@ -655,6 +880,7 @@ impl<'a> CodedInputStream<'a> {
     #[cfg(feature = "bytes")]
     pub fn read_carllerche_bytes(&mut self) -> ProtobufResult<Bytes> {
         let len = self.read_raw_varint32()?;
+        self.check_length(len as u64, true)?;
         self.read_raw_callerche_bytes(len as usize)
     }
 
@@ -666,6 +892,7 @@ impl<'a> CodedInputStream<'a> {
 
     pub fn read_bytes_into(&mut self, target: &mut Vec<u8>) -> ProtobufResult<()> {
         let len = self.read_raw_varint32()?;
+        self.check_length(len as u64, true)?;
         self.read_raw_bytes_into(len, target)?;
         Ok(())
     }
@@ -691,10 +918,11 @@ impl<'a> CodedInputStream<'a> {
         Ok(())
     }
 
-    pub fn merge_message<M : Message>(&mut self, message: &mut M) -> ProtobufResult<()> {
+    pub fn merge_message<M : Message + ?Sized>(&mut self, message: &mut M) -> ProtobufResult<()> {
         let len = self.read_raw_varint64()?;
+        self.check_length(len, false)?;
         let old_limit = self.push_limit(len)?;
-        message.merge_from(self)?;
+        self.merge_located(message, |m, is| m.merge_from(is))?;
         self.pop_limit(old_limit);
         Ok(())
     }
@@ -705,6 +933,35 @@ impl<'a> CodedInputStream<'a> {
         r.check_initialized()?;
         Ok(r)
     }
+
+    /// Merge fields of group `field_number` after its start tag
+    /// up to and including the matching end tag.
+    pub fn merge_group<M : Message + ?Sized>(
+        &mut self,
+        message: &mut M,
+        field_number: u32,
+    ) -> ProtobufResult<()> {
+        let group = GroupState {
+            field_number: field_number,
+            limit_depth: self.limit_depth,
+            ended: false,
+        };
+        let outer = mem::replace(&mut self.group, Some(group));
+        let r = self.merge_located(message, |m, is| m.merge_from(is));
+        let group = mem::replace(&mut self.group, outer);
+        r?;
+        match group {
+            Some(GroupState { ended: true, .. }) => Ok(()),
+            _ => Err(ProtobufError::WireError(WireError::UnexpectedEof)),
+        }
+    }
+
+    pub fn read_group<M : Message>(&mut self, field_number: u32) -> ProtobufResult<M> {
+        let mut r: M = Message::new();
+        self.merge_group(&mut r, field_number)?;
+        r.check_initialized()?;
+        Ok(r)
+    }
 }
 
 impl<'a> Read for CodedInputStream<'a> {
@@ -1224,6 +1481,15 @@ impl<'a> CodedOutputStream<'a> {
         self.write_message_no_tag(msg)?;
         Ok(())
     }
+
+    /// Write group with start and end tags
+    pub fn write_group<M : Message>(&mut self, field_number: u32, msg: &M) -> ProtobufResult<()> {
+        self.write_tag(field_number, wire_format::WireTypeStartGroup)?;
+        msg.compute_size();
+        msg.write_to_with_cached_sizes(self)?;
+        self.write_tag(field_number, wire_format::WireTypeEndGroup)?;
+        Ok(())
+    }
 }
 
 impl<'a> Write for CodedOutputStream<'a> {
@@ -1250,12 +1516,19 @@ mod test {
 
     use hex::encode_hex;
     use hex::decode_hex;
+    use core::Message;
+    use core::parse_from_bytes_with_options;
     use error::ProtobufResult;
     use error::ProtobufError;
+    use error::WireError;
+    use well_known_types::ListValue;
+    use well_known_types::Struct;
+    use well_known_types::Value;
 
     use super::wire_format;
     use super::CodedInputStream;
     use super::CodedOutputStream;
+    use super::ParseOptions;
 
     fn test_read_partial<F>(hex: &str, mut callback: F)
     where
@@ -1406,6 +1679,94 @@ mod test {
         });
     }
 
+    fn parse_options(
+        total: Option<u64>,
+        field: Option<u64>,
+        repeated: Option<u64>,
+    ) -> ParseOptions {
+        ParseOptions {
+            max_total_bytes: total,
+            max_field_length: field,
+            max_repeated_len: repeated,
+            locate_errors: false,
+        }
+    }
+
+    fn wire_error<T : Debug>(r: ProtobufResult<T>) -> WireError {
+        match r {
+            Err(ProtobufError::WireError(e)) => e,
+            r => panic!("expecting wire error: {:?}", r),
+        }
+    }
+
+    #[test]
+    fn test_input_stream_parse_options() {
+        test_read_partial("03 61 62 63", |is| {
+            is.set_parse_options(parse_options(None, Some(2), None));
+            match wire_error(is.read_string()) {
+                WireError::FieldTooLarge(3) => {}
+                e => panic!("{:?}", e),
+            }
+        });
+        test_read_partial("03 61 62 63", |is| {
+            is.set_parse_options(parse_options(Some(3), None, None));
+            match wire_error(is.read_bytes()) {
+                WireError::MessageTooLarge(4) => {}
+                e => panic!("{:?}", e),
+            }
+        });
+        test_read("03 01 02 03", |is| {
+            is.set_parse_options(parse_options(Some(4), Some(3), Some(3)));
+            let mut v = Vec::new();
+            is.read_repeated_packed_int32_into(&mut v).unwrap();
+            assert_eq!(vec![1, 2, 3], v);
+        });
+        test_read_partial("03 01 02 03", |is| {
+            is.set_parse_options(parse_options(None, None, Some(2)));
+            match wire_error(is.read_repeated_packed_int32_into(&mut Vec::new())) {
+                WireError::TooManyElements(3) => {}
+                e => panic!("{:?}", e),
+            }
+        });
+        test_read_partial("08 01 08 02", |is| {
+            is.set_parse_options(parse_options(Some(3), None, None));
+            assert_eq!((1, wire_format::WireTypeVarint), is.read_tag_unpack().unwrap());
+            assert_eq!(1, is.read_int32().unwrap());
+            assert!(!is.eof().unwrap());
+            is.read_tag_unpack().unwrap();
+            is.read_int32().unwrap();
+            match wire_error(is.eof()) {
+                WireError::MessageTooLarge(4) => {}
+                e => panic!("{:?}", e),
+            }
+        });
+    }
+
+    #[test]
+    fn test_parse_repeated_and_map_with_options() {
+        let mut list = ListValue::new();
+        for i in 0..3 {
+            list.mut_values().push(Value::from(i));
+        }
+        let bytes = list.write_to_bytes().unwrap();
+        let options = parse_options(None, None, Some(3));
+        assert_eq!(list, parse_from_bytes_with_options(&bytes, &options).unwrap());
+        let options = parse_options(None, None, Some(2));
+        match wire_error(parse_from_bytes_with_options::<ListValue>(&bytes, &options)) {
+            WireError::TooManyElements(3) => {}
+            e => panic!("{:?}", e),
+        }
+
+        let s = Struct::new().with_field("a", 1).with_field("b", 2);
+        let bytes = s.write_to_bytes().unwrap();
+        assert_eq!(s, parse_from_bytes_with_options(&bytes, &options).unwrap());
+        let options = parse_options(None, None, Some(1));
+        match wire_error(parse_from_bytes_with_options::<Struct>(&bytes, &options)) {
+            WireError::TooManyElements(2) => {}
+            e => panic!("{:?}", e),
+        }
+    }
+
     #[test]
     fn test_input_stream_io_read() {
         test_read("aa bb cc", |is| {
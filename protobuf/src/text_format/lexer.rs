@@ -0,0 +1,218 @@
+//! Tokenizer for protobuf text format.
+
+use super::parse::ParseError;
+use super::unescape_string_checked;
+
+
+/// Position in text, both components are 1-based
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Loc {
+    pub line: u32,
+    pub col: u32,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Token {
+    /// Identifier or keyword like `true` or `inf`
+    Ident(String),
+    /// Single-character punctuation: `{ } < > [ ] : , ; - / .`
+    Symbol(char),
+    /// Numeric literal as written, interpretation depends on field type
+    Number(String),
+    /// Quoted string with escapes already decoded
+    Str(Vec<u8>),
+}
+
+pub struct Lexer<'a> {
+    input: &'a str,
+    pos: usize,
+    loc: Loc,
+}
+
+fn is_ident_start(c: char) -> bool {
+    c == '_' || c.is_ascii_alphabetic()
+}
+
+fn is_ident_part(c: char) -> bool {
+    c == '_' || c.is_ascii_alphanumeric()
+}
+
+impl<'a> Lexer<'a> {
+    pub fn new(input: &'a str) -> Lexer<'a> {
+        Lexer {
+            input: input,
+            pos: 0,
+            loc: Loc { line: 1, col: 1 },
+        }
+    }
+
+    pub fn loc(&self) -> Loc {
+        self.loc
+    }
+
+    fn rem(&self) -> &'a str {
+        &self.input[self.pos..]
+    }
+
+    fn peek_char(&self) -> Option<char> {
+        self.rem().chars().next()
+    }
+
+    fn next_char(&mut self) -> Option<char> {
+        let c = self.peek_char()?;
+        self.pos += c.len_utf8();
+        if c == '\n' {
+            self.loc.line += 1;
+            self.loc.col = 1;
+        } else {
+            self.loc.col += 1;
+        }
+        Some(c)
+    }
+
+    fn skip_ws_and_comments(&mut self) {
+        loop {
+            match self.peek_char() {
+                Some(c) if c.is_whitespace() => {
+                    self.next_char();
+                }
+                Some('#') => {
+                    while let Some(c) = self.next_char() {
+                        if c == '\n' {
+                            break;
+                        }
+                    }
+                }
+                _ => return,
+            }
+        }
+    }
+
+    fn error<T>(&self, loc: Loc, message: String) -> Result<T, ParseError> {
+        Err(ParseError::new(loc, message))
+    }
+
+    fn next_ident(&mut self) -> String {
+        let start = self.pos;
+        while let Some(c) = self.peek_char() {
+            if !is_ident_part(c) {
+                break;
+            }
+            self.next_char();
+        }
+        self.input[start..self.pos].to_owned()
+    }
+
+    fn next_number(&mut self) -> String {
+        let start = self.pos;
+        let hex = self.rem().starts_with("0x") || self.rem().starts_with("0X");
+        let mut prev = '\0';
+        while let Some(c) = self.peek_char() {
+            let exp_sign = !hex && (prev == 'e' || prev == 'E') && (c == '+' || c == '-');
+            if !(is_ident_part(c) || c == '.' || exp_sign) {
+                break;
+            }
+            prev = c;
+            self.next_char();
+        }
+        self.input[start..self.pos].to_owned()
+    }
+
+    fn next_str(&mut self, loc: Loc) -> Result<Vec<u8>, ParseError> {
+        let quote = self.next_char().unwrap();
+        let start = self.pos;
+        loop {
+            match self.next_char() {
+                None | Some('\n') => {
+                    return self.error(loc, "unterminated string literal".to_owned());
+                }
+                Some('\\') => {
+                    // escaped char cannot terminate the literal
+                    if self.next_char().is_none() {
+                        return self.error(loc, "unterminated string literal".to_owned());
+                    }
+                }
+                Some(c) if c == quote => break,
+                Some(_) => {}
+            }
+        }
+        let content = &self.input[start..self.pos - 1];
+        match unescape_string_checked(content) {
+            Ok(bytes) => Ok(bytes),
+            Err(message) => self.error(loc, message),
+        }
+    }
+
+    /// Read next token with its starting position, `None` on EOF
+    pub fn next_token(&mut self) -> Result<Option<(Token, Loc)>, ParseError> {
+        self.skip_ws_and_comments();
+        let loc = self.loc;
+        let c = match self.peek_char() {
+            Some(c) => c,
+            None => return Ok(None),
+        };
+
+        let token = if is_ident_start(c) {
+            Token::Ident(self.next_ident())
+        } else if c.is_ascii_digit() ||
+            (c == '.' && self.rem()[1..].starts_with(|c: char| c.is_ascii_digit()))
+        {
+            Token::Number(self.next_number())
+        } else if c == '"' || c == '\'' {
+            Token::Str(self.next_str(loc)?)
+        } else if "{}<>[]:,;-/.".contains(c) {
+            self.next_char();
+            Token::Symbol(c)
+        } else {
+            return self.error(loc, format!("unexpected character `{}`", c));
+        };
+
+        Ok(Some((token, loc)))
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn tokens(input: &str) -> Vec<Token> {
+        let mut lexer = Lexer::new(input);
+        let mut r = Vec::new();
+        while let Some((token, _)) = lexer.next_token().unwrap() {
+            r.push(token);
+        }
+        r
+    }
+
+    #[test]
+    fn test_tokens() {
+        assert_eq!(
+            vec![
+                Token::Ident("a".to_owned()),
+                Token::Symbol(':'),
+                Token::Symbol('-'),
+                Token::Number("1.5e-3f".to_owned()),
+                Token::Number("0x1F".to_owned()),
+                Token::Str(b"x\ny".to_vec()),
+                Token::Symbol('{'),
+            ],
+            tokens("a: # comment\n -1.5e-3f 0x1F 'x\\ny' {")
+        );
+    }
+
+    #[test]
+    fn test_loc() {
+        let mut lexer = Lexer::new("a\n  bb");
+        assert_eq!(Loc { line: 1, col: 1 }, lexer.next_token().unwrap().unwrap().1);
+        assert_eq!(Loc { line: 2, col: 3 }, lexer.next_token().unwrap().unwrap().1);
+        assert_eq!(None, lexer.next_token().unwrap());
+    }
+
+    #[test]
+    fn test_unterminated_string() {
+        let mut lexer = Lexer::new("\n  \"abc");
+        let e = lexer.next_token().unwrap_err();
+        assert_eq!((2, 3), (e.line, e.col));
+    }
+}
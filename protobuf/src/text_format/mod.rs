@@ -2,9 +2,23 @@ use std;
 use std::fmt;
 use std::fmt::Write;
 use core::Message;
+use descriptor::FieldDescriptorProto;
+use descriptor::FieldDescriptorProto_Type;
 use reflect::ReflectFieldRef;
 use reflect::ProtobufValueRef;
+use reflect::registry;
+use reflect::DescriptorPool;
+use reflect::FieldDescriptor;
+use well_known_types::Any;
 
+mod lexer;
+mod parse;
+
+pub use self::parse::ParseError;
+pub use self::parse::parse_from_str;
+pub use self::parse::merge_from_str;
+pub use self::parse::parse_from_str_with_pool;
+pub use self::parse::merge_from_str_with_pool;
 
 fn quote_bytes_to(bytes: &[u8], buf: &mut String) {
     for &c in bytes {
@@ -38,54 +52,77 @@ pub fn quote_escape_bytes(bytes: &[u8]) -> String {
 }
 
 pub fn unescape_string(string: &str) -> Vec<u8> {
-    fn parse_if_digit(chars: &mut std::str::Chars) -> u8 {
+    unescape_string_checked(string).unwrap()
+}
+
+/// Decode C-style escapes in the string literal content (without quotes),
+/// returning an error message on malformed escape sequence.
+pub(crate) fn unescape_string_checked(string: &str) -> Result<Vec<u8>, String> {
+    fn parse_if_digit(chars: &mut std::str::Chars) -> u32 {
         let mut copy = chars.clone();
         let f = match copy.next() {
             None => return 0,
             Some(f) => f,
         };
         let d = match f {
-            '0'...'9' => (f as u8 - b'0'),
+            '0'...'9' => (f as u32 - '0' as u32),
             _ => return 0,
         };
         *chars = copy;
         d
     }
 
-    fn parse_hex_digit(chars: &mut std::str::Chars) -> u8 {
-        match chars.next().unwrap() {
-            c @ '0'...'9' => (c as u8) - b'0',
-            c @ 'a'...'f' => (c as u8) - b'a' + 10,
-            c @ 'A'...'F' => (c as u8) - b'A' + 10,
-            _ => panic!("incorrect hex escape"),
+    fn parse_hex_digit(chars: &mut std::str::Chars) -> Result<u8, String> {
+        match chars.next() {
+            Some(c @ '0'...'9') => Ok((c as u8) - b'0'),
+            Some(c @ 'a'...'f') => Ok((c as u8) - b'a' + 10),
+            Some(c @ 'A'...'F') => Ok((c as u8) - b'A' + 10),
+            _ => Err("incorrect hex escape".to_owned()),
         }
     }
 
-    fn parse_escape_rem(chars: &mut std::str::Chars) -> u8 {
-        let n = chars.next().unwrap();
-        match n {
-            'a' => return b'\x07',
-            'b' => return b'\x08',
-            'f' => return b'\x0c',
-            'n' => return b'\n',
-            'r' => return b'\r',
-            't' => return b'\t',
-            'v' => return b'\x0b',
-            '"' => return b'"',
-            '\'' => return b'\'',
+    fn parse_escape_rem(chars: &mut std::str::Chars, r: &mut Vec<u8>) -> Result<(), String> {
+        let n = match chars.next() {
+            Some(n) => n,
+            None => return Err("incomplete escape sequence".to_owned()),
+        };
+        let b = match n {
+            'a' => b'\x07',
+            'b' => b'\x08',
+            'f' => b'\x0c',
+            'n' => b'\n',
+            'r' => b'\r',
+            't' => b'\t',
+            'v' => b'\x0b',
+            '"' => b'"',
+            '\'' => b'\'',
             '0'...'9' => {
-                let d1 = n as u8 - b'0';
+                let d1 = n as u32 - '0' as u32;
                 let d2 = parse_if_digit(chars);
                 let d3 = parse_if_digit(chars);
-                return (d1 * 64 + d2 * 8 + d3) as u8;
+                let v = d1 * 64 + d2 * 8 + d3;
+                if v > 0xff {
+                    return Err("octal escape is out of range".to_owned());
+                }
+                v as u8
             },
             'x' => {
-                let d1 = parse_hex_digit(chars);
-                let d2 = parse_hex_digit(chars);
-                return d1 * 16 + d2;
+                let d1 = parse_hex_digit(chars)?;
+                let d2 = parse_hex_digit(chars)?;
+                d1 * 16 + d2
+            }
+            c => {
+                push_char(c, r);
+                return Ok(());
             }
-            c => return c as u8, // TODO: validate ASCII
         };
+        r.push(b);
+        Ok(())
+    }
+
+    fn push_char(c: char, r: &mut Vec<u8>) {
+        let mut buf = [0; 4];
+        r.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
     }
 
     let mut chars = string.chars();
@@ -93,14 +130,14 @@ pub fn unescape_string(string: &str) -> Vec<u8> {
 
     loop {
         let f = match chars.next() {
-            None => return r,
+            None => return Ok(r),
             Some(f) => f,
         };
 
         if f == '\\' {
-            r.push(parse_escape_rem(&mut chars));
+            parse_escape_rem(&mut chars, &mut r)?;
         } else {
-            r.push(f as u8); // TODO: escape UTF-8
+            push_char(f, &mut r);
         }
     }
 }
@@ -110,6 +147,17 @@ fn print_str_to(s: &str, buf: &mut String) {
     quote_escape_bytes_to(s.as_bytes(), buf);
 }
 
+/// Name of field in text format: group field is named by its message type
+fn field_text_name(proto: &FieldDescriptorProto) -> &str {
+    match proto.get_field_type() {
+        FieldDescriptorProto_Type::TYPE_GROUP => {
+            let type_name = proto.get_type_name();
+            &type_name[type_name.rfind('.').map(|i| i + 1).unwrap_or(0)..]
+        }
+        _ => proto.get_name(),
+    }
+}
+
 fn do_indent(buf: &mut String, pretty: bool, indent: usize) {
     if pretty && indent > 0 {
         for _ in 0..indent {
@@ -146,16 +194,17 @@ fn print_field(
     first: &mut bool,
     field_name: &str,
     value: ProtobufValueRef,
+    pool: Option<&DescriptorPool>,
 ) {
     print_start_field(buf, pretty, indent, first, field_name);
 
     match value {
-        ProtobufValueRef::Message(m) => {
+        ProtobufValueRef::Message(..) | ProtobufValueRef::OwnedMessage(..) => {
             buf.push_str(" {");
             if pretty {
                 buf.push_str("\n");
             }
-            print_to_internal(m, buf, pretty, indent + 1);
+            print_to_internal(value.as_message().unwrap(), buf, pretty, indent + 1, pool);
             do_indent(buf, pretty, indent);
             buf.push_str("}");
         }
@@ -163,6 +212,10 @@ fn print_field(
             buf.push_str(": ");
             buf.push_str(e.name());
         }
+        ProtobufValueRef::UnknownEnum(_, v) => {
+            buf.push_str(": ");
+            buf.push_str(&v.to_string());
+        }
         ProtobufValueRef::String(s) => {
             buf.push_str(": ");
             print_str_to(s, buf);
@@ -197,14 +250,49 @@ fn print_field(
     print_end_field(buf, pretty);
 }
 
-fn print_to_internal(m: &Message, buf: &mut String, pretty: bool, indent: usize) {
-    let d = m.descriptor();
+/// Extensions of the message known to the pool or registered in `registry`
+fn find_extensions(
+    extendee_full_name: &str,
+    pool: Option<&DescriptorPool>,
+) -> Vec<&'static FieldDescriptor> {
+    let mut r = match pool {
+        Some(pool) => pool.find_extensions_by_extendee(extendee_full_name),
+        None => Vec::new(),
+    };
+    for e in registry::find_extensions_by_extendee(extendee_full_name) {
+        if !r.iter().any(|p| p.proto().get_number() == e.proto().get_number()) {
+            r.push(e);
+        }
+    }
+    r
+}
+
+fn print_to_internal(
+    m: &Message,
+    buf: &mut String,
+    pretty: bool,
+    indent: usize,
+    pool: Option<&DescriptorPool>,
+) {
     let mut first = true;
+
+    // print content of `Any` if its type is registered
+    if let Some(any) = m.as_any().downcast_ref::<Any>() {
+        if let Ok(Some(content)) = any.unpack_dyn() {
+            let name = format!("[{}]", any.get_type_url());
+            let content = ProtobufValueRef::Message(&*content);
+            print_field(buf, pretty, indent, &mut first, &name, content, pool);
+            return;
+        }
+    }
+
+    let d = m.descriptor();
     for f in d.fields() {
+        let name = field_text_name(f.proto());
         match f.get_reflect(m) {
             ReflectFieldRef::Map(map) => {
                 for (k, v) in map {
-                    print_start_field(buf, pretty, indent, &mut first, f.name());
+                    print_start_field(buf, pretty, indent, &mut first, name);
                     buf.push_str(" {");
                     if pretty {
                         buf.push_str("\n");
@@ -212,7 +300,15 @@ fn print_to_internal(m: &Message, buf: &mut String, pretty: bool, indent: usize)
 
                     let mut entry_first = true;
 
-                    print_field(buf, pretty, indent + 1, &mut entry_first, "key", k.as_ref());
+                    print_field(
+                        buf,
+                        pretty,
+                        indent + 1,
+                        &mut entry_first,
+                        "key",
+                        k.as_ref(),
+                        pool,
+                    );
                     print_field(
                         buf,
                         pretty,
@@ -220,6 +316,7 @@ fn print_to_internal(m: &Message, buf: &mut String, pretty: bool, indent: usize)
                         &mut entry_first,
                         "value",
                         v.as_ref(),
+                        pool,
                     );
                     do_indent(buf, pretty, indent);
                     buf.push_str("}");
@@ -229,37 +326,57 @@ fn print_to_internal(m: &Message, buf: &mut String, pretty: bool, indent: usize)
             ReflectFieldRef::Repeated(repeated) => {
                 // TODO: do not print zeros for v3
                 for v in repeated {
-                    print_field(buf, pretty, indent, &mut first, f.name(), v.as_ref());
+                    print_field(buf, pretty, indent, &mut first, name, v.as_ref(), pool);
                 }
             }
             ReflectFieldRef::Optional(optional) => {
                 if let Some(v) = optional {
-                    print_field(buf, pretty, indent, &mut first, f.name(), v);
+                    print_field(buf, pretty, indent, &mut first, name, v, pool);
                 }
             }
         }
     }
 
+    // values of known extensions are decoded from unknown fields
+    if m.get_unknown_fields().iter().next().is_some() {
+        for e in find_extensions(d.full_name(), pool) {
+            let name = format!("[{}]", e.full_name());
+            for v in e.get_extension(m) {
+                print_field(buf, pretty, indent, &mut first, &name, v.as_value_ref(), pool);
+            }
+        }
+    }
+
     // TODO: unknown fields
 }
 
 pub fn print_to(m: &Message, buf: &mut String) {
-    print_to_internal(m, buf, false, 0)
+    print_to_internal(m, buf, false, 0, None)
 }
 
-fn print_to_string_internal(m: &Message, pretty: bool) -> String {
+fn print_to_string_internal(m: &Message, pretty: bool, pool: Option<&DescriptorPool>) -> String {
     let mut r = String::new();
-    print_to_internal(m, &mut r, pretty, 0);
+    print_to_internal(m, &mut r, pretty, 0, pool);
     r.to_string()
 }
 
 pub fn print_to_string(m: &Message) -> String {
-    print_to_string_internal(m, false)
+    print_to_string_internal(m, false, None)
+}
+
+/// Like `print_to_string`, but also prints extensions declared in files of the pool
+pub fn print_to_string_with_pool(m: &Message, pool: &DescriptorPool) -> String {
+    print_to_string_internal(m, false, Some(pool))
+}
+
+/// Multiline output like `{:#?}`, also prints extensions declared in files of the pool
+pub fn print_to_string_pretty_with_pool(m: &Message, pool: &DescriptorPool) -> String {
+    print_to_string_internal(m, true, Some(pool))
 }
 
 pub fn fmt(m: &Message, f: &mut fmt::Formatter) -> fmt::Result {
     let pretty = f.alternate();
-    f.write_str(&print_to_string_internal(m, pretty))
+    f.write_str(&print_to_string_internal(m, pretty, None))
 }
 
 #[cfg(test)]
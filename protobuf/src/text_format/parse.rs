@@ -0,0 +1,880 @@
+//! Text format parser.
+//!
+//! Text is parsed using reflection and encoded into protobuf binary
+//! format, which is then merged into the message.
+
+use std::error::Error;
+use std::fmt;
+use std::ptr;
+
+use core::Message;
+use descriptor::FieldDescriptorProto;
+use descriptor::FieldDescriptorProto_Type;
+use error::ProtobufResult;
+use reflect::DescriptorPool;
+use reflect::EnumDescriptor;
+use reflect::FieldDescriptor;
+use reflect::MessageDescriptor;
+use reflect::OneofDescriptor;
+use reflect::RuntimeFieldType;
+use reflect::RuntimeType;
+use reflect::registry;
+use stream::CodedOutputStream;
+use stream::DEFAULT_RECURSION_LIMIT;
+use stream::wire_format;
+
+use super::field_text_name;
+use super::lexer::Lexer;
+use super::lexer::Loc;
+use super::lexer::Token;
+
+
+/// Text format parse error
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    /// 1-based line number
+    pub line: u32,
+    /// 1-based column number
+    pub col: u32,
+    /// Error description
+    pub message: String,
+}
+
+impl ParseError {
+    pub(crate) fn new(loc: Loc, message: String) -> ParseError {
+        ParseError {
+            line: loc.line,
+            col: loc.col,
+            message: message,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.col, self.message)
+    }
+}
+
+impl Error for ParseError {
+    fn description(&self) -> &str {
+        &self.message
+    }
+}
+
+type ParseResult<T> = Result<T, ParseError>;
+
+
+/// Field as seen by parser: regular message field or map entry key or value
+struct FieldInfo {
+    name: &'static str,
+    number: u32,
+    field_type: FieldDescriptorProto_Type,
+    runtime_field_type: RuntimeFieldType,
+    /// Key and value fields of map entry, for map fields
+    map_entry: Option<(&'static FieldDescriptorProto, &'static FieldDescriptorProto)>,
+    /// Oneof of the field, only one field of a oneof may be specified
+    oneof: Option<&'static OneofDescriptor>,
+}
+
+impl FieldInfo {
+    fn new(proto: &'static FieldDescriptorProto, runtime_field_type: RuntimeFieldType) -> FieldInfo {
+        FieldInfo {
+            name: field_text_name(proto),
+            number: proto.get_number() as u32,
+            field_type: proto.get_field_type(),
+            runtime_field_type: runtime_field_type,
+            map_entry: None,
+            oneof: None,
+        }
+    }
+
+    fn message_fields(descriptor: &'static MessageDescriptor) -> Vec<FieldInfo> {
+        descriptor
+            .fields()
+            .iter()
+            .map(|f| {
+                let mut info = FieldInfo::new(f.proto(), f.runtime_field_type());
+                if let RuntimeFieldType::Map(..) = info.runtime_field_type {
+                    let entry = descriptor.map_entry_proto(f);
+                    info.map_entry = Some((&entry.get_field()[0], &entry.get_field()[1]));
+                }
+                info.oneof = match f.containing_oneof() {
+                    Some(oneof) if !oneof.is_synthetic() => Some(oneof),
+                    _ => None,
+                };
+                info
+            })
+            .collect()
+    }
+}
+
+fn parse_u64_literal(s: &str) -> Option<u64> {
+    if s.starts_with("0x") || s.starts_with("0X") {
+        u64::from_str_radix(&s[2..], 16).ok()
+    } else if s.len() > 1 && s.starts_with("0") {
+        u64::from_str_radix(&s[1..], 8).ok()
+    } else {
+        s.parse().ok()
+    }
+}
+
+fn parse_f64_literal(s: &str) -> Option<f64> {
+    let s = if s.ends_with("f") || s.ends_with("F") {
+        &s[..s.len() - 1]
+    } else {
+        s
+    };
+    s.parse().ok()
+}
+
+
+struct Parser<'a> {
+    lexer: Lexer<'a>,
+    next: Option<(Token, Loc)>,
+    /// Extensions of files of the pool can be parsed in addition to registered extensions
+    pool: Option<&'a DescriptorPool>,
+    /// Nesting level of messages being parsed
+    depth: u32,
+}
+
+impl<'a> Parser<'a> {
+    fn new(input: &'a str, pool: Option<&'a DescriptorPool>) -> ParseResult<Parser<'a>> {
+        let mut lexer = Lexer::new(input);
+        let next = lexer.next_token()?;
+        Ok(Parser {
+            lexer: lexer,
+            next: next,
+            pool: pool,
+            depth: 0,
+        })
+    }
+
+    fn find_extension(&self, full_name: &str) -> Option<&'static FieldDescriptor> {
+        self.pool
+            .and_then(|pool| pool.find_extension_by_full_name(full_name))
+            .or_else(|| registry::find_extension_by_full_name(full_name))
+    }
+
+    fn advance(&mut self) -> ParseResult<()> {
+        self.next = self.lexer.next_token()?;
+        Ok(())
+    }
+
+    fn loc(&self) -> Loc {
+        match self.next {
+            Some((_, loc)) => loc,
+            None => self.lexer.loc(),
+        }
+    }
+
+    fn error<T>(&self, message: String) -> ParseResult<T> {
+        Err(ParseError::new(self.loc(), message))
+    }
+
+    fn write_result(&self, r: ProtobufResult<()>) -> ParseResult<()> {
+        r.map_err(|e| ParseError::new(self.loc(), format!("{}", e)))
+    }
+
+    fn next_is_symbol(&self, c: char) -> bool {
+        match self.next {
+            Some((Token::Symbol(s), _)) => s == c,
+            _ => false,
+        }
+    }
+
+    fn next_symbol_if_eq(&mut self, c: char) -> ParseResult<bool> {
+        if self.next_is_symbol(c) {
+            self.advance()?;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    fn expect_symbol(&mut self, c: char) -> ParseResult<()> {
+        if !self.next_symbol_if_eq(c)? {
+            return self.error(format!("expecting `{}`", c));
+        }
+        Ok(())
+    }
+
+    fn next_ident(&mut self) -> ParseResult<String> {
+        let ident = match self.next {
+            Some((Token::Ident(ref ident), _)) => ident.clone(),
+            _ => return self.error("expecting identifier".to_owned()),
+        };
+        self.advance()?;
+        Ok(ident)
+    }
+
+    /// Read name in square brackets after `[`: type URL of `Any` content
+    fn next_bracketed_name(&mut self) -> ParseResult<String> {
+        let mut name = self.next_ident()?;
+        loop {
+            if self.next_symbol_if_eq(']')? {
+                return Ok(name);
+            } else if self.next_symbol_if_eq('.')? {
+                name.push('.');
+            } else if self.next_symbol_if_eq('/')? {
+                name.push('/');
+            } else {
+                return self.error("expecting `]`".to_owned());
+            }
+            name.push_str(&self.next_ident()?);
+        }
+    }
+
+    /// Read optional minus sign followed by number or identifier
+    /// like `inf`; return sign and token text
+    fn next_signed_literal(&mut self) -> ParseResult<(bool, Token)> {
+        let neg = self.next_symbol_if_eq('-')?;
+        let token = match self.next {
+            Some((ref t @ Token::Number(..), _)) |
+            Some((ref t @ Token::Ident(..), _)) => t.clone(),
+            _ => return self.error("expecting number".to_owned()),
+        };
+        self.advance()?;
+        Ok((neg, token))
+    }
+
+    fn next_i64_in_range(&mut self, min: i64, max: i64) -> ParseResult<i64> {
+        let loc = self.loc();
+        let (neg, v) = match self.next_signed_literal()? {
+            (neg, Token::Number(ref s)) => match parse_u64_literal(s) {
+                Some(v) => (neg, v),
+                None => return Err(ParseError::new(loc, "expecting integer".to_owned())),
+            },
+            _ => return Err(ParseError::new(loc, "expecting integer".to_owned())),
+        };
+        let v = if neg {
+            if v > (i64::max_value() as u64) + 1 {
+                None
+            } else {
+                Some((v as i64).wrapping_neg())
+            }
+        } else if v > i64::max_value() as u64 {
+            None
+        } else {
+            Some(v as i64)
+        };
+        match v {
+            Some(v) if v >= min && v <= max => Ok(v),
+            _ => Err(ParseError::new(loc, "integer is out of range".to_owned())),
+        }
+    }
+
+    fn next_u64_in_range(&mut self, max: u64) -> ParseResult<u64> {
+        let loc = self.loc();
+        match self.next_signed_literal()? {
+            (false, Token::Number(ref s)) => match parse_u64_literal(s) {
+                Some(v) if v <= max => Ok(v),
+                Some(_) => Err(ParseError::new(loc, "integer is out of range".to_owned())),
+                None => Err(ParseError::new(loc, "expecting integer".to_owned())),
+            },
+            (true, Token::Number(..)) => {
+                Err(ParseError::new(loc, "integer is out of range".to_owned()))
+            }
+            _ => Err(ParseError::new(loc, "expecting integer".to_owned())),
+        }
+    }
+
+    fn next_f64(&mut self) -> ParseResult<f64> {
+        let loc = self.loc();
+        let (neg, v) = match self.next_signed_literal()? {
+            (neg, Token::Number(ref s)) => match parse_f64_literal(s) {
+                Some(v) => (neg, v),
+                None => match parse_u64_literal(s) {
+                    Some(v) => (neg, v as f64),
+                    None => return Err(ParseError::new(loc, "expecting float".to_owned())),
+                },
+            },
+            (neg, Token::Ident(ref s)) => match &s.to_lowercase()[..] {
+                "inf" | "inff" | "infinity" | "infinityf" => (neg, ::std::f64::INFINITY),
+                "nan" | "nanf" => (neg, ::std::f64::NAN),
+                _ => return Err(ParseError::new(loc, "expecting float".to_owned())),
+            },
+            _ => unreachable!(),
+        };
+        Ok(if neg { -v } else { v })
+    }
+
+    fn next_bool(&mut self) -> ParseResult<bool> {
+        let v = match self.next {
+            Some((Token::Ident(ref s), _)) => match &s[..] {
+                "true" | "True" | "t" => true,
+                "false" | "False" | "f" => false,
+                _ => return self.error("expecting bool".to_owned()),
+            },
+            Some((Token::Number(ref s), _)) => match &s[..] {
+                "1" => true,
+                "0" => false,
+                _ => return self.error("expecting bool".to_owned()),
+            },
+            _ => return self.error("expecting bool".to_owned()),
+        };
+        self.advance()?;
+        Ok(v)
+    }
+
+    fn next_enum_value(&mut self, descriptor: &EnumDescriptor) -> ParseResult<i32> {
+        let loc = self.loc();
+        let found = match self.next {
+            Some((Token::Ident(ref name), _)) => {
+                let found = descriptor.values().iter().find(|v| v.name() == name);
+                self.advance()?;
+                found
+            }
+            _ => {
+                // numbers unknown to descriptor are allowed like in JSON:
+                // they are stored in unknown fields or in `EnumOrUnknown` field
+                return Ok(self.next_i64_in_range(
+                    i32::min_value() as i64, i32::max_value() as i64)? as i32);
+            }
+        };
+        match found {
+            Some(v) => Ok(v.value()),
+            None => Err(ParseError::new(
+                loc,
+                format!("unknown value of enum `{}`", descriptor.name()),
+            )),
+        }
+    }
+
+    /// Read string literal; adjacent literals are concatenated
+    fn next_str_lit(&mut self) -> ParseResult<Vec<u8>> {
+        let mut r = match self.next {
+            Some((Token::Str(ref s), _)) => s.clone(),
+            _ => return self.error("expecting string literal".to_owned()),
+        };
+        self.advance()?;
+        while let Some((Token::Str(ref s), _)) = self.next {
+            r.extend_from_slice(s);
+            self.advance()?;
+        }
+        Ok(r)
+    }
+
+    /// Read `{` or `<`, return matching closing bracket
+    fn next_message_start(&mut self) -> ParseResult<char> {
+        if self.next_symbol_if_eq('{')? {
+            Ok('}')
+        } else if self.next_symbol_if_eq('<')? {
+            Ok('>')
+        } else {
+            self.error("expecting `{` or `<`".to_owned())
+        }
+    }
+
+    fn merge_value(
+        &mut self,
+        number: u32,
+        field_type: FieldDescriptorProto_Type,
+        runtime_type: RuntimeType,
+        os: &mut CodedOutputStream,
+    ) -> ParseResult<()> {
+        use self::FieldDescriptorProto_Type::*;
+
+        let r = match (field_type, runtime_type) {
+            (TYPE_MESSAGE, RuntimeType::Message(descriptor)) => {
+                let end = self.next_message_start()?;
+                let bytes = self.merge_message_bytes(descriptor, Some(end))?;
+                os.write_bytes(number, &bytes)
+            }
+            (TYPE_ENUM, RuntimeType::Enum(descriptor)) => {
+                let v = self.next_enum_value(descriptor)?;
+                os.write_enum(number, v)
+            }
+            (TYPE_STRING, _) => {
+                let loc = self.loc();
+                match String::from_utf8(self.next_str_lit()?) {
+                    Ok(s) => os.write_string(number, &s),
+                    Err(_) => {
+                        return Err(ParseError::new(loc, "string is not valid UTF-8".to_owned()))
+                    }
+                }
+            }
+            (TYPE_BYTES, _) => {
+                let v = self.next_str_lit()?;
+                os.write_bytes(number, &v)
+            }
+            (TYPE_BOOL, _) => {
+                let v = self.next_bool()?;
+                os.write_bool(number, v)
+            }
+            (TYPE_INT32, _) | (TYPE_SINT32, _) | (TYPE_SFIXED32, _) => {
+                let v = self.next_i64_in_range(
+                    i32::min_value() as i64, i32::max_value() as i64)? as i32;
+                match field_type {
+                    TYPE_INT32 => os.write_int32(number, v),
+                    TYPE_SINT32 => os.write_sint32(number, v),
+                    _ => os.write_sfixed32(number, v),
+                }
+            }
+            (TYPE_INT64, _) | (TYPE_SINT64, _) | (TYPE_SFIXED64, _) => {
+                let v = self.next_i64_in_range(i64::min_value(), i64::max_value())?;
+                match field_type {
+                    TYPE_INT64 => os.write_int64(number, v),
+                    TYPE_SINT64 => os.write_sint64(number, v),
+                    _ => os.write_sfixed64(number, v),
+                }
+            }
+            (TYPE_UINT32, _) | (TYPE_FIXED32, _) => {
+                let v = self.next_u64_in_range(u32::max_value() as u64)? as u32;
+                match field_type {
+                    TYPE_UINT32 => os.write_uint32(number, v),
+                    _ => os.write_fixed32(number, v),
+                }
+            }
+            (TYPE_UINT64, _) | (TYPE_FIXED64, _) => {
+                let v = self.next_u64_in_range(u64::max_value())?;
+                match field_type {
+                    TYPE_UINT64 => os.write_uint64(number, v),
+                    _ => os.write_fixed64(number, v),
+                }
+            }
+            (TYPE_FLOAT, _) => {
+                let v = self.next_f64()?;
+                os.write_float(number, v as f32)
+            }
+            (TYPE_DOUBLE, _) => {
+                let v = self.next_f64()?;
+                os.write_double(number, v)
+            }
+            (TYPE_GROUP, RuntimeType::Message(descriptor)) => {
+                let end = self.next_message_start()?;
+                let bytes = self.merge_message_bytes(descriptor, Some(end))?;
+                os.write_tag(number, wire_format::WireTypeStartGroup)
+                    .and_then(|_| os.write_raw_bytes(&bytes))
+                    .and_then(|_| os.write_tag(number, wire_format::WireTypeEndGroup))
+            }
+            (TYPE_MESSAGE, _) | (TYPE_ENUM, _) | (TYPE_GROUP, _) => unreachable!(),
+        };
+        self.write_result(r)
+    }
+
+    fn merge_field_value(&mut self, field: &FieldInfo, os: &mut CodedOutputStream) -> ParseResult<()> {
+        match field.runtime_field_type {
+            RuntimeFieldType::Map(k, v) => {
+                let (key, value) = field.map_entry.unwrap();
+                let entry_fields = [
+                    FieldInfo::new(key, RuntimeFieldType::Singular(k)),
+                    FieldInfo::new(value, RuntimeFieldType::Singular(v)),
+                ];
+                let end = self.next_message_start()?;
+                let bytes = self.merge_fields_bytes(field.name, &entry_fields, Some(end))?;
+                let r = os.write_bytes(field.number, &bytes);
+                self.write_result(r)
+            }
+            RuntimeFieldType::Singular(t) | RuntimeFieldType::Repeated(t) => {
+                self.merge_value(field.number, field.field_type, t, os)
+            }
+        }
+    }
+
+    fn merge_field(&mut self, field: &FieldInfo, os: &mut CodedOutputStream) -> ParseResult<()> {
+        let (repeated, message) = match field.runtime_field_type {
+            RuntimeFieldType::Map(..) => (true, true),
+            RuntimeFieldType::Repeated(RuntimeType::Message(..)) => (true, true),
+            RuntimeFieldType::Repeated(..) => (true, false),
+            RuntimeFieldType::Singular(RuntimeType::Message(..)) => (false, true),
+            RuntimeFieldType::Singular(..) => (false, false),
+        };
+
+        // colon is optional before message value
+        if message {
+            self.next_symbol_if_eq(':')?;
+        } else {
+            self.expect_symbol(':')?;
+        }
+
+        if repeated && self.next_symbol_if_eq('[')? {
+            if self.next_symbol_if_eq(']')? {
+                return Ok(());
+            }
+            loop {
+                self.merge_field_value(field, os)?;
+                if self.next_symbol_if_eq(']')? {
+                    return Ok(());
+                }
+                self.expect_symbol(',')?;
+            }
+        }
+
+        self.merge_field_value(field, os)
+    }
+
+    /// Parse expanded `Any` content `[type_url] { ... }` after type URL
+    fn merge_any_content(
+        &mut self,
+        loc: Loc,
+        type_url: &str,
+        os: &mut CodedOutputStream,
+    ) -> ParseResult<()> {
+        let descriptor = match registry::find_message_by_type_url(type_url) {
+            Some(d) => d,
+            None => {
+                return Err(ParseError::new(
+                    loc,
+                    format!("cannot resolve `Any` type URL `{}`", type_url),
+                ));
+            }
+        };
+        self.next_symbol_if_eq(':')?;
+        let end = self.next_message_start()?;
+        let bytes = self.merge_message_bytes(descriptor, Some(end))?;
+        let r = os.write_string(1, type_url).and_then(|_| os.write_bytes(2, &bytes));
+        self.write_result(r)
+    }
+
+    fn merge_fields(
+        &mut self,
+        message_name: &str,
+        fields: &[FieldInfo],
+        end: Option<char>,
+        os: &mut CodedOutputStream,
+    ) -> ParseResult<()> {
+        // oneof fields specified so far
+        let mut oneof_fields: Vec<&FieldInfo> = Vec::new();
+        loop {
+            match end {
+                Some(end) => {
+                    if self.next_symbol_if_eq(end)? {
+                        return Ok(());
+                    }
+                    if self.next.is_none() {
+                        return self.error(format!("expecting `{}`", end));
+                    }
+                }
+                None => {
+                    if self.next.is_none() {
+                        return Ok(());
+                    }
+                }
+            }
+
+            let loc = self.loc();
+            if self.next_symbol_if_eq('[')? {
+                let name = self.next_bracketed_name()?;
+                if message_name == "google.protobuf.Any" {
+                    self.merge_any_content(loc, &name, os)?;
+                } else {
+                    let extension = match self.find_extension(&name) {
+                        Some(e) if e.containing_type().unwrap().full_name() == message_name => e,
+                        _ => {
+                            return Err(ParseError::new(
+                                loc,
+                                format!("message `{}` has no extension `{}`", message_name, name),
+                            ));
+                        }
+                    };
+                    let field = FieldInfo::new(extension.proto(), extension.runtime_field_type());
+                    self.merge_field(&field, os)?;
+                }
+            } else {
+                let name = self.next_ident()?;
+                let field = match fields.iter().find(|f| f.name == name) {
+                    Some(field) => field,
+                    None => {
+                        return Err(ParseError::new(
+                            loc,
+                            format!("message `{}` has no field `{}`", message_name, name),
+                        ));
+                    }
+                };
+
+                if let Some(oneof) = field.oneof {
+                    let other = oneof_fields
+                        .iter()
+                        .find(|f| ptr::eq(f.oneof.unwrap(), oneof) && f.name != field.name);
+                    if let Some(other) = other {
+                        return Err(ParseError::new(
+                            loc,
+                            format!(
+                                "field `{}` is specified along with field `{}` of oneof `{}`",
+                                field.name,
+                                other.name,
+                                oneof.name()
+                            ),
+                        ));
+                    }
+                    oneof_fields.push(field);
+                }
+
+                self.merge_field(field, os)?;
+            }
+
+            if !self.next_symbol_if_eq(',')? {
+                self.next_symbol_if_eq(';')?;
+            }
+        }
+    }
+
+    fn merge_fields_bytes(
+        &mut self,
+        message_name: &str,
+        fields: &[FieldInfo],
+        end: Option<char>,
+    ) -> ParseResult<Vec<u8>> {
+        if self.depth > DEFAULT_RECURSION_LIMIT {
+            return self.error("over recursion limit".to_owned());
+        }
+        self.depth += 1;
+        let mut bytes = Vec::new();
+        let r = {
+            let mut os = CodedOutputStream::vec(&mut bytes);
+            self.merge_fields(message_name, fields, end, &mut os)
+                .and_then(|_| {
+                    let r = os.flush();
+                    self.write_result(r)
+                })
+        };
+        self.depth -= 1;
+        r.map(|_| bytes)
+    }
+
+    fn merge_message_bytes(
+        &mut self,
+        descriptor: &'static MessageDescriptor,
+        end: Option<char>,
+    ) -> ParseResult<Vec<u8>> {
+        let fields = FieldInfo::message_fields(descriptor);
+        self.merge_fields_bytes(descriptor.full_name(), &fields, end)
+    }
+}
+
+/// Merge message content, return position of input end
+fn merge_from_str_loc(
+    message: &mut Message,
+    input: &str,
+    pool: Option<&DescriptorPool>,
+) -> ParseResult<Loc> {
+    let mut parser = Parser::new(input, pool)?;
+    let bytes = parser.merge_message_bytes(message.descriptor(), None)?;
+    let loc = parser.loc();
+    message
+        .merge_from_bytes(&bytes)
+        .map_err(|e| ParseError::new(loc, format!("{}", e)))?;
+    Ok(loc)
+}
+
+/// Parse text format and merge parsed fields into given message.
+///
+/// Singular fields present in text overwrite fields of the message,
+/// repeated fields are appended.
+pub fn merge_from_str(message: &mut Message, input: &str) -> Result<(), ParseError> {
+    merge_from_str_loc(message, input, None).map(|_| ())
+}
+
+/// Like `merge_from_str`, but also accepts extensions declared in files of the pool
+pub fn merge_from_str_with_pool(
+    message: &mut Message,
+    input: &str,
+    pool: &DescriptorPool,
+) -> Result<(), ParseError> {
+    merge_from_str_loc(message, input, Some(pool)).map(|_| ())
+}
+
+/// Parse message from text format.
+///
+/// Results in error if text is malformed or required fields are missing.
+pub fn parse_from_str<M : Message>(input: &str) -> Result<M, ParseError> {
+    parse_from_str_impl(input, None)
+}
+
+/// Like `parse_from_str`, but also accepts extensions declared in files of the pool
+pub fn parse_from_str_with_pool<M : Message>(
+    input: &str,
+    pool: &DescriptorPool,
+) -> Result<M, ParseError> {
+    parse_from_str_impl(input, Some(pool))
+}
+
+fn parse_from_str_impl<M : Message>(
+    input: &str,
+    pool: Option<&DescriptorPool>,
+) -> Result<M, ParseError> {
+    let mut message = M::new();
+    let loc = merge_from_str_loc(&mut message, input, pool)?;
+    if !message.is_initialized() {
+        return Err(ParseError::new(
+            loc,
+            format!("message `{}` is missing required fields", message.descriptor().full_name()),
+        ));
+    }
+    Ok(message)
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use descriptor::DescriptorProto;
+    use descriptor::FieldDescriptorProto;
+    use descriptor::FieldDescriptorProto_Label;
+    use descriptor::FieldDescriptorProto_Type;
+    use descriptor::FieldOptions;
+    use descriptor::FileDescriptorProto;
+    use descriptor::FileOptions;
+    use text_format::print_to_string;
+    use well_known_types::Struct;
+    use well_known_types::Value;
+
+    #[test]
+    fn test_parse_nested_and_repeated() {
+        let file: FileDescriptorProto = parse_from_str(
+            r#"
+            name: "a.proto"  # comment
+            dependency: ["b.proto", 'c' ".proto"]
+            message_type {
+                name: "Foo"
+                field < name: "bar" number: 0x11 label: LABEL_REPEATED type: 9 >
+            }
+            message_type { name: "Baz" }
+            "#,
+        ).unwrap();
+
+        assert_eq!("a.proto", file.get_name());
+        assert_eq!(&["b.proto", "c.proto"], file.get_dependency());
+        assert_eq!(2, file.get_message_type().len());
+        let field = &file.get_message_type()[0].get_field()[0];
+        assert_eq!("bar", field.get_name());
+        assert_eq!(17, field.get_number());
+        assert_eq!(FieldDescriptorProto_Label::LABEL_REPEATED, field.get_label());
+        assert_eq!(FieldDescriptorProto_Type::TYPE_STRING, field.get_field_type());
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut message = DescriptorProto::new();
+        message.set_name("Foo".to_owned());
+        message.mut_field().push(FieldDescriptorProto::new());
+        merge_from_str(&mut message, "field { name: 'x' } field { name: 'y' }").unwrap();
+        assert_eq!("Foo", message.get_name());
+        assert_eq!(3, message.get_field().len());
+    }
+
+    #[test]
+    fn test_map() {
+        let s: Struct = parse_from_str(
+            r#"fields { key: "a" value { number_value: -1.5 } }
+               fields [{ key: "b" value { bool_value: true } }]"#,
+        ).unwrap();
+        assert_eq!(-1.5, s.get_fields()["a"].get_number_value());
+        assert_eq!(true, s.get_fields()["b"].get_bool_value());
+    }
+
+    #[test]
+    fn test_print_parse() {
+        let mut file = FileDescriptorProto::new();
+        file.set_name("x\n\"y\"".to_owned());
+        file.mut_options().set_java_multiple_files(true);
+        file.mut_options().set_optimize_for(::descriptor::FileOptions_OptimizeMode::CODE_SIZE);
+        file.mut_public_dependency().push(-3);
+        let text = print_to_string(&file);
+        assert_eq!(file, parse_from_str::<FileDescriptorProto>(&text).unwrap());
+    }
+
+    #[test]
+    fn test_unknown_enum_number() {
+        // proto2 enum field: unknown number goes to unknown fields
+        let options: FileOptions = parse_from_str("optimize_for: 100").unwrap();
+        assert!(!options.has_optimize_for());
+        assert_eq!(&[100], options.get_unknown_fields().get(9).unwrap().varint.as_slice());
+    }
+
+    #[test]
+    fn test_any() {
+        let mut duration = ::well_known_types::Duration::new();
+        duration.set_seconds(3);
+        let any = ::well_known_types::Any::pack(&duration).unwrap();
+        let text = print_to_string(&any);
+        assert_eq!("[type.googleapis.com/google.protobuf.Duration] {seconds: 3}", text);
+        assert_eq!(any, parse_from_str(&text).unwrap());
+
+        let e = parse_from_str::<::well_known_types::Any>("[foo.com/x.Y] {}").unwrap_err();
+        assert_eq!("cannot resolve `Any` type URL `foo.com/x.Y`", e.message);
+    }
+
+    #[test]
+    fn test_extensions() {
+        ::rustproto::register_message_types();
+        let mut options = FileOptions::new();
+        options.set_java_package("p".to_owned());
+        ::rustproto::exts::expose_oneof_all.set(&mut options, true);
+        let text = print_to_string(&options);
+        assert_eq!("java_package: \"p\" [rustproto.expose_oneof_all]: true", text);
+        assert_eq!(options, parse_from_str(&text).unwrap());
+
+        let e = parse_from_str::<FieldOptions>("[rustproto.expose_oneof_all]: true").unwrap_err();
+        assert_eq!(
+            "message `google.protobuf.FieldOptions` has no extension `rustproto.expose_oneof_all`",
+            e.message
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        fn error(input: &str) -> (u32, u32, String) {
+            let e = parse_from_str::<FileDescriptorProto>(input).unwrap_err();
+            (e.line, e.col, e.message)
+        }
+
+        assert_eq!(
+            (2, 1, "message `google.protobuf.FileDescriptorProto` has no field `foo`".to_owned()),
+            error("name: 'a'\nfoo: 1"));
+        assert_eq!((1, 7), {
+            let e = error("name: 1");
+            (e.0, e.1)
+        });
+        assert_eq!(
+            (1, 20, "integer is out of range".to_owned()),
+            error("public_dependency: 2147483648"));
+        assert_eq!(
+            (1, 26, "expecting `}`".to_owned()),
+            error("message_type { name: 'a' "));
+        assert_eq!(
+            (1, 25, "unknown value of enum `OptimizeMode`".to_owned()),
+            error("options { optimize_for: FAST }"));
+        assert_eq!(
+            (1, 6, "expecting `:`".to_owned()),
+            error("name 'a'"));
+    }
+
+    #[test]
+    fn test_recursion_limit() {
+        fn nested(depth: usize) -> String {
+            "nested_type { ".repeat(depth) + &"}".repeat(depth)
+        }
+
+        assert!(parse_from_str::<DescriptorProto>(&nested(100)).is_ok());
+        let e = parse_from_str::<DescriptorProto>(&nested(101)).unwrap_err();
+        assert_eq!((1, 1415, "over recursion limit".to_owned()), (e.line, e.col, e.message));
+
+        let input = "struct_value { fields { key: 'a' value { ".repeat(100000);
+        let e = parse_from_str::<Value>(&input).unwrap_err();
+        assert_eq!("over recursion limit", e.message);
+    }
+
+    #[test]
+    fn test_oneof() {
+        let value: Value = parse_from_str("bool_value: true bool_value: false").unwrap();
+        assert_eq!(false, value.get_bool_value());
+
+        let e = parse_from_str::<Value>("bool_value: true\nnumber_value: 1").unwrap_err();
+        assert_eq!(
+            "field `number_value` is specified along with field `bool_value` of oneof `kind`",
+            e.message);
+        assert_eq!((2, 1), (e.line, e.col));
+    }
+
+    #[test]
+    fn test_not_initialized() {
+        use descriptor::UninterpretedOption_NamePart;
+
+        let e = parse_from_str::<UninterpretedOption_NamePart>("name_part: 'a'").unwrap_err();
+        assert!(e.message.ends_with("is missing required fields"), "{}", e.message);
+        assert_eq!((1, 15), (e.line, e.col));
+    }
+}
@@ -9,10 +9,13 @@ use stream::CodedInputStream;
 use stream::CodedOutputStream;
 use error::ProtobufResult;
 use core::ProtobufEnum;
+use enum_or_unknown::EnumOrUnknown;
 use core::Message;
 use wire_format::WireType;
 use rt;
 use reflect::ProtobufValue;
+use reflect::ReflectValueBox;
+use reflect::RuntimeType;
 use unknown::UnknownValues;
 
 pub trait ProtobufType {
@@ -20,6 +23,20 @@ pub trait ProtobufType {
 
     fn wire_type() -> WireType;
 
+    /// Runtime type of values of this type, used in reflection
+    fn runtime_type() -> RuntimeType;
+
+    /// Convert reflection value into value of this type,
+    /// `None` if the value is of different type
+    fn value_from_box(value: ReflectValueBox) -> Option<Self::Value> {
+        value.downcast()
+    }
+
+    /// Value as message, `None` if this is not a message type
+    fn as_message_mut(_value: &mut Self::Value) -> Option<&mut Message> {
+        None
+    }
+
     fn read(is: &mut CodedInputStream) -> ProtobufResult<Self::Value>;
 
     fn compute_size(value: &Self::Value) -> u32;
@@ -86,6 +103,7 @@ pub struct ProtobufTypeCarllercheBytes;
 pub struct ProtobufTypeCarllercheChars;
 
 pub struct ProtobufTypeEnum<E : ProtobufEnum>(marker::PhantomData<E>);
+pub struct ProtobufTypeEnumOrUnknown<E : ProtobufEnum>(marker::PhantomData<E>);
 pub struct ProtobufTypeMessage<M : Message>(marker::PhantomData<M>);
 
 impl ProtobufType for ProtobufTypeFloat {
@@ -95,6 +113,10 @@ impl ProtobufType for ProtobufTypeFloat {
         WireType::WireTypeFixed32
     }
 
+    fn runtime_type() -> RuntimeType {
+        RuntimeType::F32
+    }
+
     fn read(is: &mut CodedInputStream) -> ProtobufResult<f32> {
         is.read_float()
     }
@@ -119,6 +141,10 @@ impl ProtobufType for ProtobufTypeDouble {
         WireType::WireTypeFixed64
     }
 
+    fn runtime_type() -> RuntimeType {
+        RuntimeType::F64
+    }
+
     fn read(is: &mut CodedInputStream) -> ProtobufResult<f64> {
         is.read_double()
     }
@@ -143,6 +169,10 @@ impl ProtobufType for ProtobufTypeInt32 {
         WireType::WireTypeVarint
     }
 
+    fn runtime_type() -> RuntimeType {
+        RuntimeType::I32
+    }
+
     fn read(is: &mut CodedInputStream) -> ProtobufResult<i32> {
         is.read_int32()
     }
@@ -167,6 +197,10 @@ impl ProtobufType for ProtobufTypeInt64 {
         WireType::WireTypeVarint
     }
 
+    fn runtime_type() -> RuntimeType {
+        RuntimeType::I64
+    }
+
     fn read(is: &mut CodedInputStream) -> ProtobufResult<i64> {
         is.read_int64()
     }
@@ -191,6 +225,10 @@ impl ProtobufType for ProtobufTypeUint32 {
         WireType::WireTypeVarint
     }
 
+    fn runtime_type() -> RuntimeType {
+        RuntimeType::U32
+    }
+
     fn read(is: &mut CodedInputStream) -> ProtobufResult<u32> {
         is.read_uint32()
     }
@@ -215,6 +253,10 @@ impl ProtobufType for ProtobufTypeUint64 {
         WireType::WireTypeVarint
     }
 
+    fn runtime_type() -> RuntimeType {
+        RuntimeType::U64
+    }
+
     fn read(is: &mut CodedInputStream) -> ProtobufResult<u64> {
         is.read_uint64()
     }
@@ -239,6 +281,10 @@ impl ProtobufType for ProtobufTypeSint32 {
         WireType::WireTypeVarint
     }
 
+    fn runtime_type() -> RuntimeType {
+        RuntimeType::I32
+    }
+
     fn read(is: &mut CodedInputStream) -> ProtobufResult<i32> {
         is.read_sint32()
     }
@@ -263,6 +309,10 @@ impl ProtobufType for ProtobufTypeSint64 {
         WireType::WireTypeVarint
     }
 
+    fn runtime_type() -> RuntimeType {
+        RuntimeType::I64
+    }
+
     fn read(is: &mut CodedInputStream) -> ProtobufResult<i64> {
         is.read_sint64()
     }
@@ -287,6 +337,10 @@ impl ProtobufType for ProtobufTypeFixed32 {
         WireType::WireTypeFixed32
     }
 
+    fn runtime_type() -> RuntimeType {
+        RuntimeType::U32
+    }
+
     fn read(is: &mut CodedInputStream) -> ProtobufResult<u32> {
         is.read_fixed32()
     }
@@ -311,6 +365,10 @@ impl ProtobufType for ProtobufTypeFixed64 {
         WireType::WireTypeFixed64
     }
 
+    fn runtime_type() -> RuntimeType {
+        RuntimeType::U64
+    }
+
     fn read(is: &mut CodedInputStream) -> ProtobufResult<u64> {
         is.read_fixed64()
     }
@@ -335,6 +393,10 @@ impl ProtobufType for ProtobufTypeSfixed32 {
         WireType::WireTypeFixed32
     }
 
+    fn runtime_type() -> RuntimeType {
+        RuntimeType::I32
+    }
+
     fn read(is: &mut CodedInputStream) -> ProtobufResult<i32> {
         is.read_sfixed32()
     }
@@ -359,6 +421,10 @@ impl ProtobufType for ProtobufTypeSfixed64 {
         WireType::WireTypeFixed64
     }
 
+    fn runtime_type() -> RuntimeType {
+        RuntimeType::I64
+    }
+
     fn read(is: &mut CodedInputStream) -> ProtobufResult<i64> {
         is.read_sfixed64()
     }
@@ -383,6 +449,10 @@ impl ProtobufType for ProtobufTypeBool {
         WireType::WireTypeVarint
     }
 
+    fn runtime_type() -> RuntimeType {
+        RuntimeType::Bool
+    }
+
     fn read(is: &mut CodedInputStream) -> ProtobufResult<bool> {
         is.read_bool()
     }
@@ -411,6 +481,10 @@ impl ProtobufType for ProtobufTypeString {
         WireType::WireTypeLengthDelimited
     }
 
+    fn runtime_type() -> RuntimeType {
+        RuntimeType::String
+    }
+
     fn read(is: &mut CodedInputStream) -> ProtobufResult<String> {
         is.read_string()
     }
@@ -435,6 +509,10 @@ impl ProtobufType for ProtobufTypeBytes {
         WireType::WireTypeLengthDelimited
     }
 
+    fn runtime_type() -> RuntimeType {
+        RuntimeType::Bytes
+    }
+
     fn read(is: &mut CodedInputStream) -> ProtobufResult<Vec<u8>> {
         is.read_bytes()
     }
@@ -460,6 +538,14 @@ impl ProtobufType for ProtobufTypeCarllercheBytes {
         ProtobufTypeBytes::wire_type()
     }
 
+    fn runtime_type() -> RuntimeType {
+        RuntimeType::Bytes
+    }
+
+    fn value_from_box(value: ReflectValueBox) -> Option<Bytes> {
+        value.downcast::<Vec<u8>>().map(Bytes::from)
+    }
+
     fn read(is: &mut CodedInputStream) -> ProtobufResult<Self::Value> {
         is.read_carllerche_bytes()
     }
@@ -485,6 +571,14 @@ impl ProtobufType for ProtobufTypeCarllercheChars {
         ProtobufTypeBytes::wire_type()
     }
 
+    fn runtime_type() -> RuntimeType {
+        RuntimeType::String
+    }
+
+    fn value_from_box(value: ReflectValueBox) -> Option<Chars> {
+        value.downcast::<String>().map(Chars::from)
+    }
+
     fn read(is: &mut CodedInputStream) -> ProtobufResult<Self::Value> {
         is.read_carllerche_chars()
     }
@@ -509,6 +603,14 @@ impl<E : ProtobufEnum + ProtobufValue> ProtobufType for ProtobufTypeEnum<E> {
         WireType::WireTypeVarint
     }
 
+    fn runtime_type() -> RuntimeType {
+        RuntimeType::for_enum::<E>()
+    }
+
+    fn value_from_box(value: ReflectValueBox) -> Option<E> {
+        value.downcast_enum()
+    }
+
     fn read(is: &mut CodedInputStream) -> ProtobufResult<E> {
         is.read_enum()
     }
@@ -526,6 +628,38 @@ impl<E : ProtobufEnum + ProtobufValue> ProtobufType for ProtobufTypeEnum<E> {
     }
 }
 
+impl<E : ProtobufEnum> ProtobufType for ProtobufTypeEnumOrUnknown<E> {
+    type Value = EnumOrUnknown<E>;
+
+    fn wire_type() -> WireType {
+        WireType::WireTypeVarint
+    }
+
+    fn runtime_type() -> RuntimeType {
+        RuntimeType::for_enum::<E>()
+    }
+
+    fn value_from_box(value: ReflectValueBox) -> Option<EnumOrUnknown<E>> {
+        value.downcast_enum_or_unknown()
+    }
+
+    fn read(is: &mut CodedInputStream) -> ProtobufResult<EnumOrUnknown<E>> {
+        is.read_enum_or_unknown()
+    }
+
+    fn compute_size(value: &EnumOrUnknown<E>) -> u32 {
+        rt::compute_raw_varint64_size(value.value() as i64 as u64)
+    }
+
+    fn write_with_cached_size(
+        field_number: u32,
+        value: &EnumOrUnknown<E>,
+        os: &mut CodedOutputStream,
+    ) -> ProtobufResult<()> {
+        os.write_enum(field_number, value.value())
+    }
+}
+
 impl<M : Message + Clone + ProtobufValue> ProtobufType for ProtobufTypeMessage<M> {
     type Value = M;
 
@@ -533,6 +667,14 @@ impl<M : Message + Clone + ProtobufValue> ProtobufType for ProtobufTypeMessage<M
         WireType::WireTypeLengthDelimited
     }
 
+    fn runtime_type() -> RuntimeType {
+        RuntimeType::for_message::<M>()
+    }
+
+    fn as_message_mut(value: &mut M) -> Option<&mut Message> {
+        Some(value)
+    }
+
     fn read(is: &mut CodedInputStream) -> ProtobufResult<M> {
         is.read_message()
     }
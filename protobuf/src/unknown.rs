@@ -175,6 +175,13 @@ impl UnknownFields {
             None => None,
         }
     }
+
+    /// Remove all values of the field
+    pub fn remove(&mut self, field_number: u32) {
+        if let Some(ref mut map) = self.fields {
+            map.remove(&field_number);
+        }
+    }
 }
 
 impl Clear for UnknownFields {
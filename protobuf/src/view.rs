@@ -0,0 +1,420 @@
+//! Borrowed views of messages.
+//!
+//! Views are generated with `Customize::gen_views` as `FooRef<'a>` next to `Foo`.
+//! A view is parsed directly from `&'a [u8]`: string and bytes fields borrow
+//! from the input, and repeated and message fields are decoded only when accessed.
+//!
+//! Singular message field occurring several times in the input is not merged:
+//! getter of such field returns the view of the last occurrence only,
+//! which is what the regular parser of this crate keeps too.
+//! `to_owned` parses the input with the regular parser,
+//! so owned message is always exact.
+
+use std::marker::PhantomData;
+use std::str;
+
+use core::Message;
+use core::parse_from_bytes;
+use core::ProtobufEnum;
+use enum_or_unknown::EnumOrUnknown;
+use error::ProtobufError;
+use error::ProtobufResult;
+use error::WireError;
+use stream::CodedInputStream;
+use types::*;
+use wire_format::WireType;
+
+/// Borrowed view of a message
+pub trait MessageView<'a> : Sized {
+    /// Regular generated message type
+    type Owned : Message;
+
+    /// Parse view, nested messages and repeated fields are not parsed
+    fn parse_view(bytes: &'a [u8]) -> ProtobufResult<Self>;
+
+    /// Bytes this view is parsed from
+    fn view_bytes(&self) -> &'a [u8];
+
+    /// Parse owned message from the bytes of this view
+    fn to_owned_message(&self) -> ProtobufResult<Self::Owned> {
+        parse_from_bytes(self.view_bytes())
+    }
+}
+
+/// Input stream over a byte slice which returns borrowed strings and bytes
+pub struct ViewInputStream<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> ViewInputStream<'a> {
+    pub fn new(bytes: &'a [u8]) -> ViewInputStream<'a> {
+        ViewInputStream { bytes: bytes, pos: 0 }
+    }
+
+    pub fn eof(&self) -> bool {
+        self.pos == self.bytes.len()
+    }
+
+    /// Read with `CodedInputStream` over the rest of the input
+    fn read_coded<T, F>(&mut self, read: F) -> ProtobufResult<T>
+    where
+        F : FnOnce(&mut CodedInputStream) -> ProtobufResult<T>,
+    {
+        let (value, len) = {
+            let mut is = CodedInputStream::from_bytes(&self.bytes[self.pos..]);
+            let value = read(&mut is)?;
+            (value, is.pos() as usize)
+        };
+        self.pos += len;
+        Ok(value)
+    }
+
+    pub fn read_tag_unpack(&mut self) -> ProtobufResult<(u32, WireType)> {
+        self.read_coded(|is| is.read_tag_unpack())
+    }
+
+    /// Read length-delimited value
+    pub fn read_bytes(&mut self) -> ProtobufResult<&'a [u8]> {
+        let len = self.read_coded(|is| is.read_raw_varint32())? as usize;
+        if self.bytes.len() - self.pos < len {
+            return Err(ProtobufError::WireError(WireError::UnexpectedEof));
+        }
+        let bytes = &self.bytes[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    pub fn read_str(&mut self) -> ProtobufResult<&'a str> {
+        let bytes = self.read_bytes()?;
+        str::from_utf8(bytes).map_err(|_| ProtobufError::WireError(WireError::Utf8Error))
+    }
+
+    /// Skip field value, group is skipped up to the matching end group tag
+    pub fn skip_field(&mut self, field_number: u32, wire_type: WireType) -> ProtobufResult<()> {
+        match wire_type {
+            WireType::WireTypeLengthDelimited => self.read_bytes().map(|_| ()),
+            WireType::WireTypeStartGroup => self.skip_group(field_number),
+            _ => self.read_coded(|is| is.skip_field(wire_type)),
+        }
+    }
+
+    // not recursive, so deeply nested groups do not overflow the stack
+    fn skip_group(&mut self, field_number: u32) -> ProtobufResult<()> {
+        let mut groups = vec![field_number];
+        while let Some(&group) = groups.last() {
+            if self.eof() {
+                return Err(ProtobufError::WireError(WireError::UnexpectedEof));
+            }
+            let (field_number, wire_type) = self.read_tag_unpack()?;
+            match wire_type {
+                WireType::WireTypeStartGroup => groups.push(field_number),
+                WireType::WireTypeEndGroup if field_number == group => {
+                    groups.pop();
+                }
+                WireType::WireTypeEndGroup => {
+                    return Err(ProtobufError::WireError(WireError::UnexpectedWireType(wire_type)));
+                }
+                _ => self.skip_field(field_number, wire_type)?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Read value of singular field, `None` if the value is an unknown enum value
+    pub fn read_field<T : ViewType<'a>>(
+        &mut self,
+        wire_type: WireType,
+    ) -> ProtobufResult<Option<T::Value>> {
+        if wire_type != T::wire_type() {
+            return Err(ProtobufError::WireError(WireError::UnexpectedWireType(wire_type)));
+        }
+        T::read(self)
+    }
+}
+
+/// Type of field values in views
+pub trait ViewType<'a> {
+    type Value;
+
+    fn wire_type() -> WireType;
+
+    /// Read value, `None` if the value is an unknown enum value
+    fn read(is: &mut ViewInputStream<'a>) -> ProtobufResult<Option<Self::Value>>;
+}
+
+macro_rules! impl_view_type_scalar {
+    ($t:ty, $v:ty) => {
+        impl<'a> ViewType<'a> for $t {
+            type Value = $v;
+
+            fn wire_type() -> WireType {
+                <$t as ProtobufType>::wire_type()
+            }
+
+            fn read(is: &mut ViewInputStream<'a>) -> ProtobufResult<Option<$v>> {
+                is.read_coded(|is| <$t as ProtobufType>::read(is)).map(Some)
+            }
+        }
+    };
+}
+
+impl_view_type_scalar!(ProtobufTypeFloat, f32);
+impl_view_type_scalar!(ProtobufTypeDouble, f64);
+impl_view_type_scalar!(ProtobufTypeInt32, i32);
+impl_view_type_scalar!(ProtobufTypeInt64, i64);
+impl_view_type_scalar!(ProtobufTypeUint32, u32);
+impl_view_type_scalar!(ProtobufTypeUint64, u64);
+impl_view_type_scalar!(ProtobufTypeSint32, i32);
+impl_view_type_scalar!(ProtobufTypeSint64, i64);
+impl_view_type_scalar!(ProtobufTypeFixed32, u32);
+impl_view_type_scalar!(ProtobufTypeFixed64, u64);
+impl_view_type_scalar!(ProtobufTypeSfixed32, i32);
+impl_view_type_scalar!(ProtobufTypeSfixed64, i64);
+impl_view_type_scalar!(ProtobufTypeBool, bool);
+
+impl<'a, E : ProtobufEnum> ViewType<'a> for ProtobufTypeEnum<E> {
+    type Value = E;
+
+    fn wire_type() -> WireType {
+        WireType::WireTypeVarint
+    }
+
+    fn read(is: &mut ViewInputStream<'a>) -> ProtobufResult<Option<E>> {
+        let value = is.read_coded(|is| is.read_int32())?;
+        Ok(E::from_i32(value))
+    }
+}
+
+impl<'a, E : ProtobufEnum> ViewType<'a> for ProtobufTypeEnumOrUnknown<E> {
+    type Value = EnumOrUnknown<E>;
+
+    fn wire_type() -> WireType {
+        WireType::WireTypeVarint
+    }
+
+    fn read(is: &mut ViewInputStream<'a>) -> ProtobufResult<Option<EnumOrUnknown<E>>> {
+        is.read_coded(|is| is.read_enum_or_unknown()).map(Some)
+    }
+}
+
+/// `string` field borrowed as `&str`
+pub struct ViewTypeStr;
+
+impl<'a> ViewType<'a> for ViewTypeStr {
+    type Value = &'a str;
+
+    fn wire_type() -> WireType {
+        WireType::WireTypeLengthDelimited
+    }
+
+    fn read(is: &mut ViewInputStream<'a>) -> ProtobufResult<Option<&'a str>> {
+        is.read_str().map(Some)
+    }
+}
+
+/// `bytes` field borrowed as `&[u8]`
+pub struct ViewTypeBytes;
+
+impl<'a> ViewType<'a> for ViewTypeBytes {
+    type Value = &'a [u8];
+
+    fn wire_type() -> WireType {
+        WireType::WireTypeLengthDelimited
+    }
+
+    fn read(is: &mut ViewInputStream<'a>) -> ProtobufResult<Option<&'a [u8]>> {
+        is.read_bytes().map(Some)
+    }
+}
+
+/// Message field read as view
+pub struct ViewTypeMessage<V>(PhantomData<V>);
+
+impl<'a, V : MessageView<'a>> ViewType<'a> for ViewTypeMessage<V> {
+    type Value = V;
+
+    fn wire_type() -> WireType {
+        WireType::WireTypeLengthDelimited
+    }
+
+    fn read(is: &mut ViewInputStream<'a>) -> ProtobufResult<Option<V>> {
+        V::parse_view(is.read_bytes()?).map(Some)
+    }
+}
+
+/// Message field read as owned message, used for types which have no views,
+/// e. g. well-known types
+pub struct ViewTypeOwnedMessage<M>(PhantomData<M>);
+
+impl<'a, M : Message> ViewType<'a> for ViewTypeOwnedMessage<M> {
+    type Value = M;
+
+    fn wire_type() -> WireType {
+        WireType::WireTypeLengthDelimited
+    }
+
+    fn read(is: &mut ViewInputStream<'a>) -> ProtobufResult<Option<M>> {
+        parse_from_bytes(is.read_bytes()?).map(Some)
+    }
+}
+
+/// Repeated field of a view, values are decoded while iterating
+pub struct RepeatedView<'a, T> {
+    bytes: &'a [u8],
+    field_number: u32,
+    phantom: PhantomData<T>,
+}
+
+impl<'a, T> Clone for RepeatedView<'a, T> {
+    fn clone(&self) -> Self {
+        RepeatedView::new(self.bytes, self.field_number)
+    }
+}
+
+impl<'a, T> Copy for RepeatedView<'a, T> {}
+
+impl<'a, T> RepeatedView<'a, T> {
+    /// View of field `field_number` of message serialized in `bytes`
+    pub fn new(bytes: &'a [u8], field_number: u32) -> RepeatedView<'a, T> {
+        RepeatedView {
+            bytes: bytes,
+            field_number: field_number,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, T : ViewType<'a>> RepeatedView<'a, T> {
+    pub fn iter(&self) -> RepeatedViewIter<'a, T> {
+        RepeatedViewIter {
+            is: ViewInputStream::new(self.bytes),
+            packed: ViewInputStream::new(&[]),
+            field_number: self.field_number,
+            done: false,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Decode all values
+    pub fn to_vec(&self) -> ProtobufResult<Vec<T::Value>> {
+        self.iter().collect()
+    }
+}
+
+impl<'a, T : ViewType<'a>> IntoIterator for RepeatedView<'a, T> {
+    type Item = ProtobufResult<T::Value>;
+    type IntoIter = RepeatedViewIter<'a, T>;
+
+    fn into_iter(self) -> RepeatedViewIter<'a, T> {
+        self.iter()
+    }
+}
+
+/// Iterator over values of `RepeatedView`, stops after the first error
+pub struct RepeatedViewIter<'a, T> {
+    is: ViewInputStream<'a>,
+    // remaining part of current packed value
+    packed: ViewInputStream<'a>,
+    field_number: u32,
+    done: bool,
+    phantom: PhantomData<T>,
+}
+
+impl<'a, T : ViewType<'a>> RepeatedViewIter<'a, T> {
+    fn next_value(&mut self) -> ProtobufResult<Option<T::Value>> {
+        loop {
+            if !self.packed.eof() {
+                if let Some(value) = T::read(&mut self.packed)? {
+                    return Ok(Some(value));
+                }
+                continue;
+            }
+            if self.is.eof() {
+                return Ok(None);
+            }
+            let (field_number, wire_type) = self.is.read_tag_unpack()?;
+            if field_number != self.field_number {
+                self.is.skip_field(field_number, wire_type)?;
+            } else if wire_type == T::wire_type() {
+                if let Some(value) = T::read(&mut self.is)? {
+                    return Ok(Some(value));
+                }
+            } else if wire_type == WireType::WireTypeLengthDelimited {
+                self.packed = ViewInputStream::new(self.is.read_bytes()?);
+            } else {
+                return Err(ProtobufError::WireError(WireError::UnexpectedWireType(wire_type)));
+            }
+        }
+    }
+}
+
+impl<'a, T : ViewType<'a>> Iterator for RepeatedViewIter<'a, T> {
+    type Item = ProtobufResult<T::Value>;
+
+    fn next(&mut self) -> Option<ProtobufResult<T::Value>> {
+        if self.done {
+            return None;
+        }
+        match self.next_value() {
+            Ok(Some(value)) => Some(Ok(value)),
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(e) => {
+                self.done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_skip_group() {
+        // field 1: group { field 2: 5, field 3: group {} }, field 4: 6
+        let bytes = b"\x0b\x10\x05\x1b\x1c\x0c\x20\x06";
+        let mut is = ViewInputStream::new(bytes);
+        let (field_number, wire_type) = is.read_tag_unpack().unwrap();
+        is.skip_field(field_number, wire_type).unwrap();
+        let view: RepeatedView<ProtobufTypeInt32> = RepeatedView::new(bytes, 4);
+        assert_eq!(vec![6], view.to_vec().unwrap());
+        assert_eq!((4, WireType::WireTypeVarint), is.read_tag_unpack().unwrap());
+    }
+
+    #[test]
+    fn test_skip_group_unmatched_end() {
+        // group 1 closed by end of group 2
+        let mut is = ViewInputStream::new(b"\x0b\x14");
+        assert!(is.skip_field(1, WireType::WireTypeStartGroup).is_err());
+        // missing end of group
+        let mut is = ViewInputStream::new(b"\x10\x05");
+        assert!(is.skip_field(1, WireType::WireTypeStartGroup).is_err());
+    }
+
+    #[test]
+    fn test_repeated_packed_and_unpacked() {
+        // field 1: 1, packed [2, 3], 4; field 2: "x"
+        let bytes = b"\x08\x01\x0a\x02\x02\x03\x12\x01x\x08\x04";
+        let view: RepeatedView<ProtobufTypeInt32> = RepeatedView::new(bytes, 1);
+        assert_eq!(vec![1, 2, 3, 4], view.to_vec().unwrap());
+        let strings: RepeatedView<ViewTypeStr> = RepeatedView::new(bytes, 2);
+        assert_eq!(vec!["x"], strings.to_vec().unwrap());
+    }
+
+    #[test]
+    fn test_repeated_error() {
+        // truncated second value
+        let bytes = b"\x08\x01\x08";
+        let view: RepeatedView<ProtobufTypeInt32> = RepeatedView::new(bytes, 1);
+        let mut iter = view.iter();
+        assert_eq!(1, iter.next().unwrap().unwrap());
+        assert!(iter.next().unwrap().is_err());
+        assert!(iter.next().is_none());
+    }
+}
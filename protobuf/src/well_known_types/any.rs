@@ -22,12 +22,15 @@ use protobuf::Message as Message_imported_for_functions;
 use protobuf::ProtobufEnum as ProtobufEnum_imported_for_functions;
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
 pub struct Any {
     // message fields
     pub type_url: ::std::string::String,
     pub value: ::std::vec::Vec<u8>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     cached_size: ::protobuf::CachedSize,
 }
 
@@ -226,6 +229,11 @@ impl ::protobuf::reflect::ProtobufValue for Any {
     }
 }
 
+// Register message types and extensions of this file in `::protobuf::reflect::registry`
+pub fn register_message_types() {
+    ::protobuf::reflect::registry::register_message::<Any>();
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x19google/protobuf/any.proto\x12\x0fgoogle.protobuf\"6\n\x03Any\x12\
     \x19\n\x08type_url\x18\x01\x20\x01(\tR\x07typeUrl\x12\x14\n\x05value\x18\
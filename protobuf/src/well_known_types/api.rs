@@ -22,6 +22,7 @@ use protobuf::Message as Message_imported_for_functions;
 use protobuf::ProtobufEnum as ProtobufEnum_imported_for_functions;
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
 pub struct Api {
     // message fields
     pub name: ::std::string::String,
@@ -32,7 +33,9 @@ pub struct Api {
     pub mixins: ::protobuf::RepeatedField<Mixin>,
     pub syntax: ::protobuf::well_known_types::Syntax,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     cached_size: ::protobuf::CachedSize,
 }
 
@@ -462,6 +465,7 @@ impl ::protobuf::reflect::ProtobufValue for Api {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
 pub struct Method {
     // message fields
     pub name: ::std::string::String,
@@ -472,7 +476,9 @@ pub struct Method {
     pub options: ::protobuf::RepeatedField<::protobuf::well_known_types::Option>,
     pub syntax: ::protobuf::well_known_types::Syntax,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     cached_size: ::protobuf::CachedSize,
 }
 
@@ -859,12 +865,15 @@ impl ::protobuf::reflect::ProtobufValue for Method {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
 pub struct Mixin {
     // message fields
     pub name: ::std::string::String,
     pub root: ::std::string::String,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     cached_size: ::protobuf::CachedSize,
 }
 
@@ -1063,6 +1072,13 @@ impl ::protobuf::reflect::ProtobufValue for Mixin {
     }
 }
 
+// Register message types and extensions of this file in `::protobuf::reflect::registry`
+pub fn register_message_types() {
+    ::protobuf::reflect::registry::register_message::<Api>();
+    ::protobuf::reflect::registry::register_message::<Method>();
+    ::protobuf::reflect::registry::register_message::<Mixin>();
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x19google/protobuf/api.proto\x12\x0fgoogle.protobuf\x1a$google/protob\
     uf/source_context.proto\x1a\x1agoogle/protobuf/type.proto\"\xc1\x02\n\
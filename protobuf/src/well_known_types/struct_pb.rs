@@ -22,11 +22,14 @@ use protobuf::Message as Message_imported_for_functions;
 use protobuf::ProtobufEnum as ProtobufEnum_imported_for_functions;
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
 pub struct Struct {
     // message fields
     pub fields: ::std::collections::HashMap<::std::string::String, Value>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     cached_size: ::protobuf::CachedSize,
 }
 
@@ -180,15 +183,19 @@ impl ::protobuf::reflect::ProtobufValue for Struct {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
 pub struct Value {
     // message oneof groups
     kind: ::std::option::Option<Value_oneof_kind>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     cached_size: ::protobuf::CachedSize,
 }
 
 #[derive(Clone,PartialEq)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
 pub enum Value_oneof_kind {
     null_value(NullValue),
     number_value(f64),
@@ -591,35 +598,47 @@ impl ::protobuf::Message for Value {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor::<_, NullValue>(
+                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor_mut::<_, NullValue>(
                     "null_value",
                     Value::has_null_value,
                     Value::get_null_value,
+                    Value::set_null_value,
+                    Value::clear_null_value,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_f64_accessor::<_>(
+                fields.push(::protobuf::reflect::accessor::make_singular_f64_accessor_mut::<_>(
                     "number_value",
                     Value::has_number_value,
                     Value::get_number_value,
+                    Value::set_number_value,
+                    Value::clear_number_value,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor_mut::<_>(
                     "string_value",
                     Value::has_string_value,
                     Value::get_string_value,
+                    Value::set_string_value,
+                    Value::clear_string_value,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor::<_>(
+                fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor_mut::<_>(
                     "bool_value",
                     Value::has_bool_value,
                     Value::get_bool_value,
+                    Value::set_bool_value,
+                    Value::clear_bool_value,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Struct>(
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor_mut::<_, Struct>(
                     "struct_value",
                     Value::has_struct_value,
                     Value::get_struct_value,
+                    Value::mut_struct_value,
+                    Value::clear_struct_value,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, ListValue>(
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor_mut::<_, ListValue>(
                     "list_value",
                     Value::has_list_value,
                     Value::get_list_value,
+                    Value::mut_list_value,
+                    Value::clear_list_value,
                 ));
                 ::protobuf::reflect::MessageDescriptor::new::<Value>(
                     "Value",
@@ -666,11 +685,14 @@ impl ::protobuf::reflect::ProtobufValue for Value {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
 pub struct ListValue {
     // message fields
     pub values: ::protobuf::RepeatedField<Value>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     cached_size: ::protobuf::CachedSize,
 }
 
@@ -836,6 +858,7 @@ impl ::protobuf::reflect::ProtobufValue for ListValue {
 }
 
 #[derive(Clone,PartialEq,Eq,Debug,Hash)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
 pub enum NullValue {
     NULL_VALUE = 0,
 }
@@ -859,6 +882,10 @@ impl ::protobuf::ProtobufEnum for NullValue {
         values
     }
 
+    fn get_values() -> ::std::option::Option<&'static [Self]> {
+        ::std::option::Option::Some(<Self as ::protobuf::ProtobufEnum>::values())
+    }
+
     fn enum_descriptor_static(_: ::std::option::Option<NullValue>) -> &'static ::protobuf::reflect::EnumDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
@@ -870,6 +897,10 @@ impl ::protobuf::ProtobufEnum for NullValue {
             })
         }
     }
+
+    fn get_enum_descriptor_static(_: ::std::option::Option<NullValue>) -> ::std::option::Option<&'static ::protobuf::reflect::EnumDescriptor> {
+        ::std::option::Option::Some(<NullValue as ::protobuf::ProtobufEnum>::enum_descriptor_static(::std::option::Option::None))
+    }
 }
 
 impl ::std::marker::Copy for NullValue {
@@ -887,6 +918,13 @@ impl ::protobuf::reflect::ProtobufValue for NullValue {
     }
 }
 
+// Register message types and extensions of this file in `::protobuf::reflect::registry`
+pub fn register_message_types() {
+    ::protobuf::reflect::registry::register_message::<Struct>();
+    ::protobuf::reflect::registry::register_message::<Value>();
+    ::protobuf::reflect::registry::register_message::<ListValue>();
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x1cgoogle/protobuf/struct.proto\x12\x0fgoogle.protobuf\"\x98\x01\n\
     \x06Struct\x12;\n\x06fields\x18\x01\x20\x03(\x0b2#.google.protobuf.Struc\
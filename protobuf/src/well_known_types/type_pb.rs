@@ -22,6 +22,7 @@ use protobuf::Message as Message_imported_for_functions;
 use protobuf::ProtobufEnum as ProtobufEnum_imported_for_functions;
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
 pub struct Type {
     // message fields
     pub name: ::std::string::String,
@@ -31,7 +32,9 @@ pub struct Type {
     pub source_context: ::protobuf::SingularPtrField<::protobuf::well_known_types::SourceContext>,
     pub syntax: Syntax,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     cached_size: ::protobuf::CachedSize,
 }
 
@@ -412,6 +415,7 @@ impl ::protobuf::reflect::ProtobufValue for Type {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
 pub struct Field {
     // message fields
     pub kind: Field_Kind,
@@ -425,7 +429,9 @@ pub struct Field {
     pub json_name: ::std::string::String,
     pub default_value: ::std::string::String,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     cached_size: ::protobuf::CachedSize,
 }
 
@@ -917,6 +923,7 @@ impl ::protobuf::reflect::ProtobufValue for Field {
 }
 
 #[derive(Clone,PartialEq,Eq,Debug,Hash)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
 pub enum Field_Kind {
     TYPE_UNKNOWN = 0,
     TYPE_DOUBLE = 1,
@@ -994,6 +1001,10 @@ impl ::protobuf::ProtobufEnum for Field_Kind {
         values
     }
 
+    fn get_values() -> ::std::option::Option<&'static [Self]> {
+        ::std::option::Option::Some(<Self as ::protobuf::ProtobufEnum>::values())
+    }
+
     fn enum_descriptor_static(_: ::std::option::Option<Field_Kind>) -> &'static ::protobuf::reflect::EnumDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
@@ -1005,6 +1016,10 @@ impl ::protobuf::ProtobufEnum for Field_Kind {
             })
         }
     }
+
+    fn get_enum_descriptor_static(_: ::std::option::Option<Field_Kind>) -> ::std::option::Option<&'static ::protobuf::reflect::EnumDescriptor> {
+        ::std::option::Option::Some(<Field_Kind as ::protobuf::ProtobufEnum>::enum_descriptor_static(::std::option::Option::None))
+    }
 }
 
 impl ::std::marker::Copy for Field_Kind {
@@ -1023,6 +1038,7 @@ impl ::protobuf::reflect::ProtobufValue for Field_Kind {
 }
 
 #[derive(Clone,PartialEq,Eq,Debug,Hash)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
 pub enum Field_Cardinality {
     CARDINALITY_UNKNOWN = 0,
     CARDINALITY_OPTIONAL = 1,
@@ -1055,6 +1071,10 @@ impl ::protobuf::ProtobufEnum for Field_Cardinality {
         values
     }
 
+    fn get_values() -> ::std::option::Option<&'static [Self]> {
+        ::std::option::Option::Some(<Self as ::protobuf::ProtobufEnum>::values())
+    }
+
     fn enum_descriptor_static(_: ::std::option::Option<Field_Cardinality>) -> &'static ::protobuf::reflect::EnumDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
@@ -1066,6 +1086,10 @@ impl ::protobuf::ProtobufEnum for Field_Cardinality {
             })
         }
     }
+
+    fn get_enum_descriptor_static(_: ::std::option::Option<Field_Cardinality>) -> ::std::option::Option<&'static ::protobuf::reflect::EnumDescriptor> {
+        ::std::option::Option::Some(<Field_Cardinality as ::protobuf::ProtobufEnum>::enum_descriptor_static(::std::option::Option::None))
+    }
 }
 
 impl ::std::marker::Copy for Field_Cardinality {
@@ -1084,6 +1108,7 @@ impl ::protobuf::reflect::ProtobufValue for Field_Cardinality {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
 pub struct Enum {
     // message fields
     pub name: ::std::string::String,
@@ -1092,7 +1117,9 @@ pub struct Enum {
     pub source_context: ::protobuf::SingularPtrField<::protobuf::well_known_types::SourceContext>,
     pub syntax: Syntax,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     cached_size: ::protobuf::CachedSize,
 }
 
@@ -1433,13 +1460,16 @@ impl ::protobuf::reflect::ProtobufValue for Enum {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
 pub struct EnumValue {
     // message fields
     pub name: ::std::string::String,
     pub number: i32,
     pub options: ::protobuf::RepeatedField<Option>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     cached_size: ::protobuf::CachedSize,
 }
 
@@ -1680,12 +1710,15 @@ impl ::protobuf::reflect::ProtobufValue for EnumValue {
 }
 
 #[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
 pub struct Option {
     // message fields
     pub name: ::std::string::String,
     pub value: ::protobuf::SingularPtrField<::protobuf::well_known_types::Any>,
     // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
     cached_size: ::protobuf::CachedSize,
 }
 
@@ -1900,6 +1933,7 @@ impl ::protobuf::reflect::ProtobufValue for Option {
 }
 
 #[derive(Clone,PartialEq,Eq,Debug,Hash)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
 pub enum Syntax {
     SYNTAX_PROTO2 = 0,
     SYNTAX_PROTO3 = 1,
@@ -1926,6 +1960,10 @@ impl ::protobuf::ProtobufEnum for Syntax {
         values
     }
 
+    fn get_values() -> ::std::option::Option<&'static [Self]> {
+        ::std::option::Option::Some(<Self as ::protobuf::ProtobufEnum>::values())
+    }
+
     fn enum_descriptor_static(_: ::std::option::Option<Syntax>) -> &'static ::protobuf::reflect::EnumDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
@@ -1937,6 +1975,10 @@ impl ::protobuf::ProtobufEnum for Syntax {
             })
         }
     }
+
+    fn get_enum_descriptor_static(_: ::std::option::Option<Syntax>) -> ::std::option::Option<&'static ::protobuf::reflect::EnumDescriptor> {
+        ::std::option::Option::Some(<Syntax as ::protobuf::ProtobufEnum>::enum_descriptor_static(::std::option::Option::None))
+    }
 }
 
 impl ::std::marker::Copy for Syntax {
@@ -1954,6 +1996,15 @@ impl ::protobuf::reflect::ProtobufValue for Syntax {
     }
 }
 
+// Register message types and extensions of this file in `::protobuf::reflect::registry`
+pub fn register_message_types() {
+    ::protobuf::reflect::registry::register_message::<Type>();
+    ::protobuf::reflect::registry::register_message::<Field>();
+    ::protobuf::reflect::registry::register_message::<Enum>();
+    ::protobuf::reflect::registry::register_message::<EnumValue>();
+    ::protobuf::reflect::registry::register_message::<Option>();
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x1agoogle/protobuf/type.proto\x12\x0fgoogle.protobuf\x1a\x19google/pr\
     otobuf/any.proto\x1a$google/protobuf/source_context.proto\"\x8d\x02\n\
@@ -0,0 +1,91 @@
+use core::Message;
+use core::parse_from_bytes;
+use error::ProtobufResult;
+use reflect::MessageDescriptor;
+use reflect::registry;
+use well_known_types::Any;
+
+
+const TYPE_URL_PREFIX: &'static str = "type.googleapis.com";
+
+/// Type name from `Any` type URL, e. g. `google.protobuf.Duration`
+/// from `type.googleapis.com/google.protobuf.Duration`
+pub(crate) fn type_name_from_type_url(type_url: &str) -> Option<&str> {
+    match type_url.rfind('/') {
+        Some(pos) => Some(&type_url[pos + 1..]),
+        None => None,
+    }
+}
+
+impl Any {
+    /// Pack a message into `Any` with `type.googleapis.com` type URL prefix
+    pub fn pack<M : Message>(message: &M) -> ProtobufResult<Any> {
+        Any::pack_dyn(message)
+    }
+
+    /// Pack a message of type known only at runtime, e. g. `DynamicMessage`
+    pub fn pack_dyn(message: &Message) -> ProtobufResult<Any> {
+        let mut any = Any::new();
+        any.set_type_url(format!("{}/{}", TYPE_URL_PREFIX, message.descriptor().full_name()));
+        any.set_value(message.write_to_bytes()?);
+        Ok(any)
+    }
+
+    /// Check if `Any` contains a message of given type
+    pub fn is<M : Message>(&self) -> bool {
+        let descriptor = MessageDescriptor::for_type::<M>();
+        type_name_from_type_url(self.get_type_url()) == Some(descriptor.full_name())
+    }
+
+    /// Decode contained message if it is of given type, return `None` otherwise
+    pub fn unpack<M : Message>(&self) -> ProtobufResult<Option<M>> {
+        if !self.is::<M>() {
+            return Ok(None);
+        }
+        Ok(Some(parse_from_bytes(self.get_value())?))
+    }
+
+    /// Decode contained message if its type is registered in `reflect::registry`,
+    /// return `None` otherwise
+    pub fn unpack_dyn(&self) -> ProtobufResult<Option<Box<Message>>> {
+        let descriptor = match registry::find_message_by_type_url(self.get_type_url()) {
+            Some(descriptor) => descriptor,
+            None => return Ok(None),
+        };
+        let mut message = descriptor.new_instance();
+        message.merge_from_bytes(self.get_value())?;
+        Ok(Some(message))
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use well_known_types::Duration;
+    use well_known_types::Timestamp;
+
+    #[test]
+    fn test_pack_unpack() {
+        let mut duration = Duration::new();
+        duration.set_seconds(10);
+        let any = Any::pack(&duration).unwrap();
+        assert_eq!("type.googleapis.com/google.protobuf.Duration", any.get_type_url());
+
+        assert!(any.is::<Duration>());
+        assert!(!any.is::<Timestamp>());
+        assert_eq!(Some(duration.clone()), any.unpack::<Duration>().unwrap());
+        assert_eq!(None, any.unpack::<Timestamp>().unwrap());
+
+        let unpacked = any.unpack_dyn().unwrap().unwrap();
+        assert_eq!(Some(&duration), unpacked.as_any().downcast_ref::<Duration>());
+    }
+
+    #[test]
+    fn test_unpack_dyn_unknown_type() {
+        let mut any = Any::new();
+        any.set_type_url("type.googleapis.com/foo.Bar".to_owned());
+        assert!(any.unpack_dyn().unwrap().is_none());
+    }
+}
@@ -0,0 +1,221 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+use std::time;
+
+use json::rfc_3339;
+use well_known_types::Duration;
+
+use super::TimeError;
+
+
+/// Max absolute value of `Duration.seconds`, approximately 10000 years
+pub(crate) const MAX_DURATION_SECONDS: i64 = 315576000000;
+
+pub(crate) const NANOS_PER_SECOND: i64 = 1_000_000_000;
+
+/// Carry nanos to seconds and make signs of seconds and nanos equal;
+/// `None` on overflow
+fn normalize_parts(seconds: i64, nanos: i64) -> Option<(i64, i32)> {
+    let mut seconds = seconds.checked_add(nanos / NANOS_PER_SECOND)?;
+    let mut nanos = nanos % NANOS_PER_SECOND;
+    if seconds > 0 && nanos < 0 {
+        seconds -= 1;
+        nanos += NANOS_PER_SECOND;
+    } else if seconds < 0 && nanos > 0 {
+        seconds += 1;
+        nanos -= NANOS_PER_SECOND;
+    }
+    Some((seconds, nanos as i32))
+}
+
+impl Duration {
+    /// Duration from seconds and nanos of any sign and magnitude
+    pub fn normalized(seconds: i64, nanos: i64) -> Result<Duration, TimeError> {
+        let (seconds, nanos) = normalize_parts(seconds, nanos).ok_or(TimeError::OutOfRange)?;
+        if seconds < -MAX_DURATION_SECONDS || seconds > MAX_DURATION_SECONDS {
+            return Err(TimeError::OutOfRange);
+        }
+        let mut d = Duration::new();
+        d.set_seconds(seconds);
+        d.set_nanos(nanos);
+        Ok(d)
+    }
+
+    /// Seconds and nanos are in range and have the same sign
+    pub fn is_valid(&self) -> bool {
+        let (seconds, nanos) = (self.get_seconds(), self.get_nanos() as i64);
+        seconds >= -MAX_DURATION_SECONDS && seconds <= MAX_DURATION_SECONDS &&
+            nanos > -NANOS_PER_SECOND && nanos < NANOS_PER_SECOND &&
+            !(seconds < 0 && nanos > 0) && !(seconds > 0 && nanos < 0)
+    }
+
+    pub fn from_std(d: time::Duration) -> Result<Duration, TimeError> {
+        if d.as_secs() > MAX_DURATION_SECONDS as u64 {
+            return Err(TimeError::OutOfRange);
+        }
+        Duration::normalized(d.as_secs() as i64, d.subsec_nanos() as i64)
+    }
+
+    /// Convert to `std::time::Duration`, negative durations are out of range
+    pub fn to_std(&self) -> Result<time::Duration, TimeError> {
+        if !self.is_valid() || self.get_seconds() < 0 || self.get_nanos() < 0 {
+            return Err(TimeError::OutOfRange);
+        }
+        Ok(time::Duration::new(self.get_seconds() as u64, self.get_nanos() as u32))
+    }
+
+    pub fn checked_add(&self, other: &Duration) -> Option<Duration> {
+        let seconds = self.get_seconds().checked_add(other.get_seconds())?;
+        Duration::normalized(seconds, self.get_nanos() as i64 + other.get_nanos() as i64).ok()
+    }
+
+    pub fn checked_sub(&self, other: &Duration) -> Option<Duration> {
+        self.checked_add(&other.checked_neg()?)
+    }
+
+    pub fn checked_neg(&self) -> Option<Duration> {
+        let seconds = self.get_seconds().checked_neg()?;
+        Duration::normalized(seconds, -(self.get_nanos() as i64)).ok()
+    }
+
+    /// Compare seconds and nanos, e. g. `durations.sort_by(Duration::cmp_value)`.
+    ///
+    /// Unlike `==` unknown fields are ignored.
+    pub fn cmp_value(&self, other: &Duration) -> Ordering {
+        (self.get_seconds(), self.get_nanos()).cmp(&(other.get_seconds(), other.get_nanos()))
+    }
+}
+
+/// Format used in JSON like `1.5s` or `-0.000001s`
+impl fmt::Display for Duration {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (seconds, nanos) = match normalize_parts(self.get_seconds(), self.get_nanos() as i64) {
+            Some(parts) => parts,
+            None => return write!(f, "{}s {}ns", self.get_seconds(), self.get_nanos()),
+        };
+        let mut s = String::new();
+        if seconds < 0 || nanos < 0 {
+            s.push('-');
+        }
+        s.push_str(&(seconds.wrapping_abs() as u64).to_string());
+        rfc_3339::write_nanos(nanos.abs() as u32, &mut s);
+        s.push('s');
+        f.write_str(&s)
+    }
+}
+
+/// Parse format used in JSON like `1.5s`
+impl FromStr for Duration {
+    type Err = TimeError;
+
+    fn from_str(s: &str) -> Result<Duration, TimeError> {
+        let err = || TimeError::Parse(s.to_owned());
+
+        if !s.ends_with('s') {
+            return Err(err());
+        }
+        let (neg, unsigned) = if s.starts_with('-') {
+            (true, &s[1..s.len() - 1])
+        } else {
+            (false, &s[..s.len() - 1])
+        };
+        let (seconds_str, nanos_str) = match unsigned.find('.') {
+            Some(pos) => (&unsigned[..pos], &unsigned[pos + 1..]),
+            None => (unsigned, ""),
+        };
+        if seconds_str.is_empty() || !seconds_str.bytes().all(|c| c >= b'0' && c <= b'9') ||
+            nanos_str.len() > 9 || !nanos_str.bytes().all(|c| c >= b'0' && c <= b'9') ||
+            (unsigned.contains('.') && nanos_str.is_empty())
+        {
+            return Err(err());
+        }
+
+        let seconds = match seconds_str.parse::<i64>() {
+            Ok(s) if s <= MAX_DURATION_SECONDS => s,
+            Ok(..) => return Err(TimeError::OutOfRange),
+            Err(..) => return Err(err()),
+        };
+        let mut nanos = 0;
+        for c in nanos_str.bytes() {
+            nanos = nanos * 10 + (c - b'0') as i64;
+        }
+        for _ in nanos_str.len()..9 {
+            nanos *= 10;
+        }
+
+        if neg {
+            Duration::normalized(-seconds, -nanos)
+        } else {
+            Duration::normalized(seconds, nanos)
+        }
+    }
+}
+
+/// Seconds and nanos are compared. Values with equal seconds and nanos
+/// but different unknown fields are not `==`, so they are not comparable.
+impl PartialOrd for Duration {
+    fn partial_cmp(&self, other: &Duration) -> Option<Ordering> {
+        match self.cmp_value(other) {
+            Ordering::Equal if self != other => None,
+            ordering => Some(ordering),
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn duration(seconds: i64, nanos: i32) -> Duration {
+        let mut d = Duration::new();
+        d.set_seconds(seconds);
+        d.set_nanos(nanos);
+        d
+    }
+
+    #[test]
+    fn test_normalized() {
+        assert_eq!(duration(1, 500_000_000), Duration::normalized(0, 1_500_000_000).unwrap());
+        assert_eq!(duration(0, -500_000_000), Duration::normalized(1, -1_500_000_000).unwrap());
+        assert_eq!(duration(-1, -1), Duration::normalized(-2, 999_999_999).unwrap());
+        assert_eq!(Err(TimeError::OutOfRange), Duration::normalized(MAX_DURATION_SECONDS + 1, 0));
+        assert!(!duration(1, -1).is_valid());
+    }
+
+    #[test]
+    fn test_std() {
+        let d = Duration::from_std(time::Duration::new(3, 5)).unwrap();
+        assert_eq!(duration(3, 5), d);
+        assert_eq!(time::Duration::new(3, 5), d.to_std().unwrap());
+        assert_eq!(Err(TimeError::OutOfRange), duration(-3, 0).to_std());
+        assert!(Duration::from_std(time::Duration::new(u64::max_value(), 0)).is_err());
+    }
+
+    #[test]
+    fn test_string() {
+        assert_eq!("1.500s", duration(1, 500_000_000).to_string());
+        assert_eq!("-0.000001s", duration(0, -1000).to_string());
+        assert_eq!("0s", Duration::new().to_string());
+        assert_eq!(duration(-1, -500_000_000), "-1.5s".parse().unwrap());
+        assert_eq!(duration(0, 1), "0.000000001s".parse().unwrap());
+        assert!("1.s".parse::<Duration>().is_err());
+        assert!("1".parse::<Duration>().is_err());
+        assert_eq!(Err(TimeError::OutOfRange), "315576000001s".parse::<Duration>());
+    }
+
+    #[test]
+    fn test_arithmetic_and_ord() {
+        let a = duration(1, 600_000_000);
+        let b = duration(0, 700_000_000);
+        assert_eq!(Some(duration(2, 300_000_000)), a.checked_add(&b));
+        assert_eq!(Some(duration(0, -900_000_000)), b.checked_sub(&a));
+        assert_eq!(None, duration(MAX_DURATION_SECONDS, 0).checked_add(&a));
+        assert_eq!(Ordering::Less, b.cmp_value(&a));
+        assert_eq!(Ordering::Less, duration(-1, 0).cmp_value(&duration(0, -1)));
+        assert!(b < a);
+        assert!(duration(-1, 0) < duration(0, -1));
+        assert_eq!(None, duration(i64::min_value(), 0).checked_add(&duration(0, -1)));
+    }
+}
@@ -0,0 +1,513 @@
+//! `FieldMask` operations driven by reflection.
+
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+
+use core::Message;
+use reflect::FieldDescriptor;
+use reflect::MessageDescriptor;
+use reflect::ProtobufValueRef;
+use reflect::ReflectFieldRef;
+use reflect::ReflectValueBox;
+use reflect::RuntimeFieldType;
+use reflect::RuntimeType;
+use stream::CodedOutputStream;
+use unknown::UnknownFields;
+use well_known_types::FieldMask;
+
+
+/// Path of field mask does not match message type
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldMaskError {
+    message: String,
+}
+
+impl fmt::Display for FieldMaskError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl Error for FieldMaskError {
+    fn description(&self) -> &str {
+        &self.message
+    }
+}
+
+/// Options for `merge_message_to`
+#[derive(Default, Debug, Clone)]
+pub struct FieldMaskMergeOptions {
+    /// Replace message fields instead of merging them, clear when unset in source
+    pub replace_message_fields: bool,
+    /// Replace repeated fields and maps instead of appending source elements
+    pub replace_repeated_fields: bool,
+    /// Clear scalar fields unset in source instead of keeping destination value
+    pub replace_primitive_fields: bool,
+}
+
+fn find_field<'a>(descriptor: &'a MessageDescriptor, name: &str) -> Option<&'a FieldDescriptor> {
+    descriptor.fields().iter().find(|f| f.name() == name)
+}
+
+/// Descriptor of message type of singular message field
+fn singular_message_type(field: &FieldDescriptor) -> Option<&'static MessageDescriptor> {
+    match field.runtime_field_type() {
+        RuntimeFieldType::Singular(RuntimeType::Message(d)) => Some(d),
+        _ => None,
+    }
+}
+
+impl FieldMask {
+    /// Field mask with given paths
+    pub fn from_paths<S : Into<String>, I : IntoIterator<Item = S>>(paths: I) -> FieldMask {
+        let mut mask = FieldMask::new();
+        mask.set_paths(paths.into_iter().map(|p| p.into()).collect());
+        mask
+    }
+
+    /// Check that each path is a chain of singular message fields ending with any field
+    pub fn validate(&self, descriptor: &MessageDescriptor) -> Result<(), FieldMaskError> {
+        for path in self.get_paths() {
+            let mut d = descriptor;
+            let names: Vec<&str> = path.split('.').collect();
+            for (i, name) in names.iter().enumerate() {
+                let field = match find_field(d, name) {
+                    Some(field) => field,
+                    None => {
+                        return Err(FieldMaskError {
+                            message: format!(
+                                "path `{}`: message `{}` has no field `{}`",
+                                path,
+                                d.full_name(),
+                                name
+                            ),
+                        });
+                    }
+                };
+                if i + 1 == names.len() {
+                    break;
+                }
+                d = match singular_message_type(field) {
+                    Some(d) => d,
+                    None => {
+                        return Err(FieldMaskError {
+                            message: format!(
+                                "path `{}`: field `{}` is not a singular message field",
+                                path,
+                                field.full_name()
+                            ),
+                        });
+                    }
+                };
+            }
+        }
+        Ok(())
+    }
+
+    pub fn is_valid_for(&self, descriptor: &MessageDescriptor) -> bool {
+        self.validate(descriptor).is_ok()
+    }
+
+    /// Sorted paths without duplicates and without paths covered by other paths,
+    /// e. g. `a.b` is removed if mask contains `a`
+    pub fn normalize(&self) -> FieldMask {
+        let mut paths: Vec<&str> = self.get_paths().iter().map(|p| &p[..]).collect();
+        paths.sort();
+        paths.dedup();
+        let mut r: Vec<&str> = Vec::new();
+        for path in paths {
+            if path.is_empty() || r.iter().any(|&p| is_prefix(p, path)) {
+                continue;
+            }
+            r.push(path);
+        }
+        FieldMask::from_paths(r)
+    }
+
+    /// Paths of both masks, normalized
+    pub fn union(&self, other: &FieldMask) -> FieldMask {
+        FieldMask::from_paths(self.get_paths().iter().chain(other.get_paths()).map(|p| &p[..]))
+            .normalize()
+    }
+
+    /// Paths covered by both masks, normalized
+    pub fn intersect(&self, other: &FieldMask) -> FieldMask {
+        let mut r = Vec::new();
+        for a in self.get_paths() {
+            for b in other.get_paths() {
+                if a == b || is_prefix(a, b) {
+                    r.push(&b[..]);
+                } else if is_prefix(b, a) {
+                    r.push(&a[..]);
+                }
+            }
+        }
+        FieldMask::from_paths(r).normalize()
+    }
+}
+
+/// Path `prefix` covers `path`
+fn is_prefix(prefix: &str, path: &str) -> bool {
+    path.len() > prefix.len() && path.starts_with(prefix) && path.as_bytes()[prefix.len()] == b'.'
+}
+
+
+/// Paths of a mask as a tree; node without children is a field included as a whole
+#[derive(Default)]
+struct FieldMaskTree {
+    children: BTreeMap<String, FieldMaskTree>,
+}
+
+impl FieldMaskTree {
+    fn new(mask: &FieldMask) -> FieldMaskTree {
+        let mut root = FieldMaskTree::default();
+        for path in mask.normalize().get_paths() {
+            let mut node = &mut root;
+            for name in path.split('.') {
+                node = { node }.children.entry(name.to_owned()).or_insert_with(Default::default);
+            }
+        }
+        root
+    }
+
+    fn is_leaf(&self) -> bool {
+        self.children.is_empty()
+    }
+}
+
+/// Binary merge of messages of the same type; required fields are not checked,
+/// but message nested deeper than recursion limit is an error
+fn merge_message(src: &Message, dst: &mut Message) -> Result<(), FieldMaskError> {
+    let mut bytes = Vec::new();
+    src.compute_size();
+    {
+        let mut os = CodedOutputStream::vec(&mut bytes);
+        src.write_to_with_cached_sizes(&mut os).expect("write to vec");
+        os.flush().expect("write to vec");
+    }
+    dst.merge_from_bytes(&bytes).map_err(|e| FieldMaskError {
+        message: format!("merge message `{}`: {}", src.descriptor().full_name(), e),
+    })
+}
+
+fn value_to_box(value: ProtobufValueRef) -> Result<ReflectValueBox, FieldMaskError> {
+    Ok(match value {
+        ProtobufValueRef::U32(v) => ReflectValueBox::U32(v),
+        ProtobufValueRef::U64(v) => ReflectValueBox::U64(v),
+        ProtobufValueRef::I32(v) => ReflectValueBox::I32(v),
+        ProtobufValueRef::I64(v) => ReflectValueBox::I64(v),
+        ProtobufValueRef::F32(v) => ReflectValueBox::F32(v),
+        ProtobufValueRef::F64(v) => ReflectValueBox::F64(v),
+        ProtobufValueRef::Bool(v) => ReflectValueBox::Bool(v),
+        ProtobufValueRef::String(v) => ReflectValueBox::String(v.to_owned()),
+        ProtobufValueRef::Bytes(v) => ReflectValueBox::Bytes(v.to_vec()),
+        ProtobufValueRef::Enum(v) => ReflectValueBox::Enum(v),
+        ProtobufValueRef::UnknownEnum(d, v) => ReflectValueBox::UnknownEnum(d, v),
+        ProtobufValueRef::Message(m) => {
+            let mut copy = m.descriptor().new_instance();
+            merge_message(m, &mut *copy)?;
+            ReflectValueBox::Message(copy)
+        }
+        ProtobufValueRef::OwnedMessage(m) => ReflectValueBox::Message(m),
+    })
+}
+
+fn singular_value<'a>(field: &FieldDescriptor, m: &'a Message) -> Option<ProtobufValueRef<'a>> {
+    match field.get_reflect(m) {
+        ReflectFieldRef::Optional(v) => v,
+        _ => None,
+    }
+}
+
+/// Update singular message field of `dst` with `merge`; wrapper message field
+/// is not stored in the message, so its copy is updated and set back
+fn merge_into_field<F>(
+    field: &FieldDescriptor,
+    dst: &mut Message,
+    merge: F,
+) -> Result<(), FieldMaskError>
+where
+    F : FnOnce(&mut Message) -> Result<(), FieldMaskError>,
+{
+    if !field.is_wrapper() {
+        return merge(field.mut_message(dst));
+    }
+    let mut copy = match singular_value(field, dst) {
+        Some(ProtobufValueRef::OwnedMessage(m)) => m,
+        _ => singular_message_type(field).expect("message field").new_instance(),
+    };
+    merge(&mut *copy)?;
+    field.set_singular_field(dst, ReflectValueBox::Message(copy));
+    Ok(())
+}
+
+fn merge_field(
+    field: &FieldDescriptor,
+    src: &Message,
+    dst: &mut Message,
+    options: &FieldMaskMergeOptions,
+) -> Result<(), FieldMaskError> {
+    match field.get_reflect(src) {
+        ReflectFieldRef::Map(map) => {
+            if options.replace_repeated_fields {
+                field.clear_field(dst);
+            }
+            let mut dst_map = field.mut_map(dst);
+            for (k, v) in map {
+                dst_map.insert(value_to_box(k.as_ref())?, value_to_box(v.as_ref())?);
+            }
+        }
+        ReflectFieldRef::Repeated(repeated) => {
+            if options.replace_repeated_fields {
+                field.clear_field(dst);
+            }
+            let mut dst_repeated = field.mut_repeated(dst);
+            for v in repeated {
+                dst_repeated.push(value_to_box(v.as_ref())?);
+            }
+        }
+        ReflectFieldRef::Optional(Some(ref v)) if v.as_message().is_some() => {
+            if options.replace_message_fields {
+                field.clear_field(dst);
+            }
+            let m = v.as_message().unwrap();
+            merge_into_field(field, dst, |dst| merge_message(m, dst))?;
+        }
+        ReflectFieldRef::Optional(Some(v)) => {
+            field.set_singular_field(dst, value_to_box(v)?);
+        }
+        ReflectFieldRef::Optional(None) if !field.has_presence() => {
+            // proto3 field is unset when it is zero, so zero is copied
+            field.clear_field(dst);
+        }
+        ReflectFieldRef::Optional(None) => {
+            let replace = match singular_message_type(field) {
+                Some(..) => options.replace_message_fields,
+                None => options.replace_primitive_fields,
+            };
+            if replace && field.has_field(dst) {
+                field.clear_field(dst);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn merge_with_tree(
+    tree: &FieldMaskTree,
+    src: &Message,
+    dst: &mut Message,
+    options: &FieldMaskMergeOptions,
+) -> Result<(), FieldMaskError> {
+    let descriptor = src.descriptor();
+    for (name, child) in &tree.children {
+        let field = find_field(descriptor, name).expect("validated");
+        if child.is_leaf() {
+            merge_field(field, src, dst, options)?;
+            continue;
+        }
+        let src_value = singular_value(field, src);
+        let src_value = src_value.as_ref().and_then(|v| v.as_message());
+        if src_value.is_none() && !field.has_field(dst) {
+            continue;
+        }
+        match src_value {
+            Some(m) => {
+                merge_into_field(field, dst, |dst| merge_with_tree(child, m, dst, options))?;
+            }
+            None => {
+                let empty = singular_message_type(field).expect("validated").new_instance();
+                merge_into_field(field, dst, |dst| merge_with_tree(child, &*empty, dst, options))?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Copy fields specified by mask from `src` to `dst` of the same type
+pub fn merge_message_to(
+    src: &Message,
+    dst: &mut Message,
+    mask: &FieldMask,
+    options: &FieldMaskMergeOptions,
+) -> Result<(), FieldMaskError> {
+    if src.descriptor().full_name() != dst.descriptor().full_name() {
+        return Err(FieldMaskError {
+            message: format!(
+                "source message `{}` and destination message `{}` have different types",
+                src.descriptor().full_name(),
+                dst.descriptor().full_name()
+            ),
+        });
+    }
+    mask.validate(src.descriptor())?;
+    merge_with_tree(&FieldMaskTree::new(mask), src, dst, options)
+}
+
+fn trim_with_tree(tree: &FieldMaskTree, m: &mut Message) {
+    let descriptor = m.descriptor();
+    for field in descriptor.fields() {
+        match tree.children.get(field.name()) {
+            Some(child) if child.is_leaf() => {}
+            Some(child) => {
+                if field.has_field(m) {
+                    let r = merge_into_field(field, m, |m| {
+                        trim_with_tree(child, m);
+                        Ok(())
+                    });
+                    r.expect("trim does not fail");
+                }
+            }
+            None => {
+                let set = match field.get_reflect(m) {
+                    ReflectFieldRef::Optional(v) => v.is_some(),
+                    ReflectFieldRef::Repeated(r) => r.len() != 0,
+                    ReflectFieldRef::Map(map) => map.len() != 0,
+                };
+                // clearing unset oneof member would clear other member
+                if set {
+                    field.clear_field(m);
+                }
+            }
+        }
+    }
+    *m.mut_unknown_fields() = UnknownFields::new();
+}
+
+/// Clear all fields of message which are not specified in mask
+pub fn trim_message(m: &mut Message, mask: &FieldMask) -> Result<(), FieldMaskError> {
+    mask.validate(m.descriptor())?;
+    trim_with_tree(&FieldMaskTree::new(mask), m);
+    Ok(())
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use descriptor::DescriptorProto;
+    use descriptor::FieldDescriptorProto;
+    use descriptor::FileDescriptorProto;
+    use well_known_types::Duration;
+
+    fn file() -> FileDescriptorProto {
+        let mut file = FileDescriptorProto::new();
+        file.set_name("a.proto".to_owned());
+        file.set_package("p".to_owned());
+        file.mut_dependency().push("b.proto".to_owned());
+        file.mut_options().set_java_package("j".to_owned());
+        file.mut_options().set_go_package("g".to_owned());
+        let mut message = DescriptorProto::new();
+        message.set_name("M".to_owned());
+        message.mut_field().push(FieldDescriptorProto::new());
+        file.mut_message_type().push(message);
+        file
+    }
+
+    #[test]
+    fn test_validate() {
+        let d = FileDescriptorProto::new().descriptor();
+        assert!(FieldMask::from_paths(vec!["name", "options.java_package"]).is_valid_for(d));
+        assert!(!FieldMask::from_paths(vec!["nme"]).is_valid_for(d));
+        assert!(!FieldMask::from_paths(vec!["message_type.name"]).is_valid_for(d));
+        assert!(!FieldMask::from_paths(vec!["name.x"]).is_valid_for(d));
+    }
+
+    #[test]
+    fn test_normalize_union_intersect() {
+        let a = FieldMask::from_paths(vec!["b.c", "a", "b", "a.x", "a-y", "a"]);
+        assert_eq!(&["a", "a-y", "b"], a.normalize().get_paths());
+
+        let b = FieldMask::from_paths(vec!["c", "a.x"]);
+        assert_eq!(&["a", "a-y", "b", "c"], a.union(&b).get_paths());
+        assert_eq!(&["a.x"], a.intersect(&b).get_paths());
+        assert!(a.intersect(&FieldMask::new()).get_paths().is_empty());
+    }
+
+    #[test]
+    fn test_merge() {
+        let src = file();
+        let mut dst = FileDescriptorProto::new();
+        dst.set_name("dst.proto".to_owned());
+        dst.mut_dependency().push("c.proto".to_owned());
+        dst.mut_options().set_cc_generic_services(true);
+
+        let mask =
+            FieldMask::from_paths(vec!["package", "dependency", "options.java_package", "syntax"]);
+        merge_message_to(&src, &mut dst, &mask, &Default::default()).unwrap();
+        assert_eq!("dst.proto", dst.get_name());
+        assert_eq!("p", dst.get_package());
+        assert_eq!(&["c.proto", "b.proto"], dst.get_dependency());
+        assert_eq!("j", dst.get_options().get_java_package());
+        assert!(!dst.get_options().has_go_package());
+        assert!(dst.get_options().get_cc_generic_services());
+
+        let options = FieldMaskMergeOptions {
+            replace_message_fields: true,
+            replace_repeated_fields: true,
+            replace_primitive_fields: true,
+        };
+        let mask = FieldMask::from_paths(
+            vec!["dependency", "options", "source_code_info", "name", "message_type"],
+        );
+        let mut src = file();
+        src.clear_name();
+        merge_message_to(&src, &mut dst, &mask, &options).unwrap();
+        assert!(!dst.has_name());
+        assert_eq!(&["b.proto"], dst.get_dependency());
+        assert!(!dst.get_options().has_cc_generic_services());
+        assert_eq!("g", dst.get_options().get_go_package());
+        assert_eq!(src.get_message_type(), dst.get_message_type());
+
+        let mask = FieldMask::from_paths(vec!["message_type.name"]);
+        assert!(merge_message_to(&src, &mut dst, &mask, &options).is_err());
+    }
+
+    #[test]
+    fn test_merge_errors() {
+        let mut dst = FileDescriptorProto::new();
+        let mask = FieldMask::from_paths(vec!["name"]);
+        assert!(merge_message_to(&Duration::new(), &mut dst, &mask, &Default::default()).is_err());
+
+        let mut message = DescriptorProto::new();
+        for _ in 0..200 {
+            let mut outer = DescriptorProto::new();
+            outer.mut_nested_type().push(message);
+            message = outer;
+        }
+        let mut src = FileDescriptorProto::new();
+        src.mut_message_type().push(message);
+        let mask = FieldMask::from_paths(vec!["message_type"]);
+        assert!(merge_message_to(&src, &mut dst, &mask, &Default::default()).is_err());
+    }
+
+    #[test]
+    fn test_merge_proto3_zero() {
+        let mut src = Duration::new();
+        src.set_nanos(7);
+        let mut dst = Duration::new();
+        dst.set_seconds(5);
+        dst.set_nanos(1);
+        let d = dst.descriptor();
+        assert!(!d.field_by_name("seconds").has_presence());
+        assert!(file().descriptor().field_by_name("name").has_presence());
+
+        let mask = FieldMask::from_paths(vec!["seconds", "nanos"]);
+        merge_message_to(&src, &mut dst, &mask, &Default::default()).unwrap();
+        assert_eq!(src, dst);
+    }
+
+    #[test]
+    fn test_trim() {
+        let mut m = file();
+        m.mut_unknown_fields().add_varint(1000, 1);
+        trim_message(&mut m, &FieldMask::from_paths(vec!["name", "options.go_package"])).unwrap();
+
+        let mut expected = FileDescriptorProto::new();
+        expected.set_name("a.proto".to_owned());
+        expected.mut_options().set_go_package("g".to_owned());
+        assert_eq!(expected, m);
+    }
+}
@@ -0,0 +1,39 @@
+//! Functions of well-known types which are not generated.
+
+use std::error::Error;
+use std::fmt;
+
+pub(crate) mod any;
+pub mod field_mask;
+mod duration;
+mod struct_pb;
+mod timestamp;
+pub(crate) mod wrappers;
+
+
+/// Error of conversion or parsing of `Timestamp` or `Duration`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TimeError {
+    /// Value is outside of range of `Timestamp` or `Duration`, or of target type
+    OutOfRange,
+    /// Malformed string representation
+    Parse(String),
+}
+
+impl fmt::Display for TimeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TimeError::OutOfRange => f.write_str("time is out of range"),
+            TimeError::Parse(ref s) => write!(f, "incorrect time string: `{}`", s),
+        }
+    }
+}
+
+impl Error for TimeError {
+    fn description(&self) -> &str {
+        match *self {
+            TimeError::OutOfRange => "time is out of range",
+            TimeError::Parse(..) => "incorrect time string",
+        }
+    }
+}
@@ -0,0 +1,384 @@
+use std::collections::HashMap;
+use std::iter::FromIterator;
+use std::ops::Index;
+use std::ops::IndexMut;
+
+use json;
+use lazy;
+use well_known_types::ListValue;
+use well_known_types::NullValue;
+use well_known_types::Struct;
+use well_known_types::Value;
+
+
+/// Shared `null` returned by indexing with missing key or index
+fn null_value() -> &'static Value {
+    static mut NULL: lazy::Lazy<Value> = lazy::Lazy {
+        lock: lazy::ONCE_INIT,
+        ptr: 0 as *const Value,
+    };
+    unsafe { NULL.get(Value::null) }
+}
+
+impl Value {
+    /// `Value` with `null_value` set
+    pub fn null() -> Value {
+        let mut v = Value::new();
+        v.set_null_value(NullValue::NULL_VALUE);
+        v
+    }
+
+    pub fn is_null(&self) -> bool {
+        self.has_null_value()
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        if self.has_number_value() { Some(self.get_number_value()) } else { None }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        if self.has_string_value() { Some(self.get_string_value()) } else { None }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        if self.has_bool_value() { Some(self.get_bool_value()) } else { None }
+    }
+
+    pub fn as_struct(&self) -> Option<&Struct> {
+        if self.has_struct_value() { Some(self.get_struct_value()) } else { None }
+    }
+
+    pub fn as_list(&self) -> Option<&ListValue> {
+        if self.has_list_value() { Some(self.get_list_value()) } else { None }
+    }
+
+    /// Member of struct value, `None` if value is not a struct or has no such member
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.as_struct().and_then(|s| s.get_fields().get(name))
+    }
+
+    /// Element of list value, `None` if value is not a list or index is out of bounds
+    pub fn get_index(&self, index: usize) -> Option<&Value> {
+        self.as_list().and_then(|l| l.get_values().get(index))
+    }
+
+    /// JSON representation, e. g. `{"a":[1,"b",null]}`
+    pub fn to_json(&self) -> Result<String, json::PrintError> {
+        json::print_to_string(self)
+    }
+
+    /// Parse any JSON value
+    pub fn from_json(s: &str) -> Result<Value, json::ParseError> {
+        json::parse_from_str(s)
+    }
+}
+
+impl Struct {
+    /// Builder-style member insertion
+    pub fn with_field<S : Into<String>, V : Into<Value>>(mut self, name: S, value: V) -> Struct {
+        self.insert(name, value);
+        self
+    }
+
+    /// Insert a member, return previous value if any
+    pub fn insert<S, V>(&mut self, name: S, value: V) -> Option<Value>
+    where
+        S : Into<String>,
+        V : Into<Value>,
+    {
+        self.mut_fields().insert(name.into(), value.into())
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.get_fields().get(name)
+    }
+
+    /// JSON object representation
+    pub fn to_json(&self) -> Result<String, json::PrintError> {
+        json::print_to_string(self)
+    }
+
+    /// Parse JSON object
+    pub fn from_json(s: &str) -> Result<Struct, json::ParseError> {
+        json::parse_from_str(s)
+    }
+}
+
+impl ListValue {
+    /// Builder-style element append
+    pub fn with_value<V : Into<Value>>(mut self, value: V) -> ListValue {
+        self.push(value);
+        self
+    }
+
+    pub fn push<V : Into<Value>>(&mut self, value: V) {
+        self.mut_values().push(value.into());
+    }
+
+    /// JSON array representation
+    pub fn to_json(&self) -> Result<String, json::PrintError> {
+        json::print_to_string(self)
+    }
+
+    /// Parse JSON array
+    pub fn from_json(s: &str) -> Result<ListValue, json::ParseError> {
+        json::parse_from_str(s)
+    }
+}
+
+impl From<NullValue> for Value {
+    fn from(_: NullValue) -> Value {
+        Value::null()
+    }
+}
+
+impl From<f64> for Value {
+    fn from(v: f64) -> Value {
+        let mut r = Value::new();
+        r.set_number_value(v);
+        r
+    }
+}
+
+impl From<f32> for Value {
+    fn from(v: f32) -> Value {
+        Value::from(v as f64)
+    }
+}
+
+impl From<i32> for Value {
+    fn from(v: i32) -> Value {
+        Value::from(v as f64)
+    }
+}
+
+impl From<u32> for Value {
+    fn from(v: u32) -> Value {
+        Value::from(v as f64)
+    }
+}
+
+impl From<bool> for Value {
+    fn from(v: bool) -> Value {
+        let mut r = Value::new();
+        r.set_bool_value(v);
+        r
+    }
+}
+
+impl From<String> for Value {
+    fn from(v: String) -> Value {
+        let mut r = Value::new();
+        r.set_string_value(v);
+        r
+    }
+}
+
+impl<'a> From<&'a str> for Value {
+    fn from(v: &'a str) -> Value {
+        Value::from(v.to_owned())
+    }
+}
+
+impl From<Struct> for Value {
+    fn from(v: Struct) -> Value {
+        let mut r = Value::new();
+        r.set_struct_value(v);
+        r
+    }
+}
+
+impl From<ListValue> for Value {
+    fn from(v: ListValue) -> Value {
+        let mut r = Value::new();
+        r.set_list_value(v);
+        r
+    }
+}
+
+/// `None` is converted to `null`
+impl<T : Into<Value>> From<Option<T>> for Value {
+    fn from(v: Option<T>) -> Value {
+        match v {
+            Some(v) => v.into(),
+            None => Value::null(),
+        }
+    }
+}
+
+impl<T : Into<Value>> From<Vec<T>> for Value {
+    fn from(v: Vec<T>) -> Value {
+        Value::from(ListValue::from(v))
+    }
+}
+
+impl<T : Into<Value>> From<HashMap<String, T>> for Value {
+    fn from(v: HashMap<String, T>) -> Value {
+        Value::from(Struct::from(v))
+    }
+}
+
+impl<T : Into<Value>> From<Vec<T>> for ListValue {
+    fn from(v: Vec<T>) -> ListValue {
+        v.into_iter().collect()
+    }
+}
+
+impl<T : Into<Value>> From<HashMap<String, T>> for Struct {
+    fn from(v: HashMap<String, T>) -> Struct {
+        v.into_iter().collect()
+    }
+}
+
+impl From<ListValue> for Vec<Value> {
+    fn from(mut v: ListValue) -> Vec<Value> {
+        v.take_values().into_vec()
+    }
+}
+
+impl From<Struct> for HashMap<String, Value> {
+    fn from(mut v: Struct) -> HashMap<String, Value> {
+        v.take_fields()
+    }
+}
+
+impl<T : Into<Value>> FromIterator<T> for ListValue {
+    fn from_iter<I : IntoIterator<Item = T>>(iter: I) -> ListValue {
+        let mut r = ListValue::new();
+        for v in iter {
+            r.push(v);
+        }
+        r
+    }
+}
+
+impl<S : Into<String>, T : Into<Value>> FromIterator<(S, T)> for Struct {
+    fn from_iter<I : IntoIterator<Item = (S, T)>>(iter: I) -> Struct {
+        let mut r = Struct::new();
+        for (k, v) in iter {
+            r.insert(k, v);
+        }
+        r
+    }
+}
+
+/// Struct member or `null` if value is not a struct or has no such member
+impl<'a> Index<&'a str> for Value {
+    type Output = Value;
+
+    fn index(&self, name: &str) -> &Value {
+        self.get(name).unwrap_or(null_value())
+    }
+}
+
+/// List element or `null` if value is not a list or index is out of bounds
+impl Index<usize> for Value {
+    type Output = Value;
+
+    fn index(&self, index: usize) -> &Value {
+        self.get_index(index).unwrap_or(null_value())
+    }
+}
+
+/// Insert `null` member if absent; `null` or unset value is replaced with empty struct.
+///
+/// # Panics
+///
+/// If value is neither struct nor `null`
+impl<'a> IndexMut<&'a str> for Value {
+    fn index_mut(&mut self, name: &str) -> &mut Value {
+        if self.has_null_value() || self == &Value::new() {
+            self.set_struct_value(Struct::new());
+        }
+        if !self.has_struct_value() {
+            panic!("cannot index non-struct value with `{}`: {:?}", name, self);
+        }
+        self.mut_struct_value().mut_fields().entry(name.to_owned()).or_insert_with(Value::null)
+    }
+}
+
+/// # Panics
+///
+/// If value is not a list or index is out of bounds
+impl IndexMut<usize> for Value {
+    fn index_mut(&mut self, index: usize) -> &mut Value {
+        if !self.has_list_value() {
+            panic!("cannot index non-list value with {}: {:?}", index, self);
+        }
+        &mut self.mut_list_value().mut_values()[index]
+    }
+}
+
+/// Member or `null` if there is no such member
+impl<'a> Index<&'a str> for Struct {
+    type Output = Value;
+
+    fn index(&self, name: &str) -> &Value {
+        self.get(name).unwrap_or(null_value())
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_from() {
+        let v = Value::from(vec![Value::from(1), Value::from("a"), Value::from(None::<bool>)]);
+        assert_eq!(Some(1.0), v[0].as_f64());
+        assert_eq!(Some("a"), v[1].as_str());
+        assert!(v[2].is_null());
+        assert!(v[3].is_null());
+
+        let mut m = HashMap::new();
+        m.insert("x".to_owned(), true);
+        let v = Value::from(m);
+        assert_eq!(Some(true), v["x"].as_bool());
+        assert!(v["y"].is_null());
+        assert_eq!(1, HashMap::from(v.get_struct_value().clone()).len());
+    }
+
+    #[test]
+    fn test_builder_and_index() {
+        let s = Struct::new()
+            .with_field("a", ListValue::new().with_value(1).with_value("b"))
+            .with_field("c", Struct::new().with_field("d", false));
+        let mut v = Value::from(s);
+        assert_eq!(Some("b"), v["a"][1].as_str());
+        assert_eq!(Some(false), v["c"]["d"].as_bool());
+        assert!(v["c"]["e"][5].is_null());
+
+        v["c"]["e"] = Value::from(2.5);
+        v["a"][0] = Value::from("z");
+        assert_eq!(Some(2.5), v["c"]["e"].as_f64());
+        assert_eq!(Some("z"), v.get("a").and_then(|a| a.get_index(0)).and_then(Value::as_str));
+
+        let mut n = Value::null();
+        n["x"]["y"] = Value::from(1);
+        assert_eq!(Some(1.0), n["x"]["y"].as_f64());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_mut_non_struct() {
+        let mut v = Value::from(1);
+        v["a"] = Value::null();
+    }
+
+    #[test]
+    fn test_json() {
+        let json = r#"{"a":[0.1,-0,1e300,"s",true,null],"b":{}}"#;
+        let v = Value::from_json(json).unwrap();
+        assert_eq!(Some(0.1), v["a"][0].as_f64());
+        assert_eq!(Some(1e300), v["a"][2].as_f64());
+        assert_eq!(v, Value::from_json(&v.to_json().unwrap()).unwrap());
+
+        let s = Struct::from_json(json).unwrap();
+        assert_eq!(v, Value::from(s));
+        assert!(Struct::from_json("[]").is_err());
+        let l = ListValue::from(vec![Value::from(1), "x".into()]);
+        assert_eq!("[1,\"x\"]", l.to_json().unwrap());
+        assert!(Value::from(::std::f64::NAN).to_json().is_err());
+    }
+}
@@ -0,0 +1,215 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+use std::time;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use json::rfc_3339;
+use well_known_types::Duration;
+use well_known_types::Timestamp;
+
+use super::TimeError;
+use super::duration::NANOS_PER_SECOND;
+
+
+impl Timestamp {
+    /// Current time
+    pub fn now() -> Timestamp {
+        Timestamp::from_system_time(SystemTime::now()).expect("current time is out of range")
+    }
+
+    /// Timestamp from seconds and nanos since epoch of any sign and magnitude
+    pub fn normalized(seconds: i64, nanos: i64) -> Result<Timestamp, TimeError> {
+        let mut seconds = seconds
+            .checked_add(nanos / NANOS_PER_SECOND)
+            .ok_or(TimeError::OutOfRange)?;
+        let mut nanos = nanos % NANOS_PER_SECOND;
+        if nanos < 0 {
+            seconds = seconds.checked_sub(1).ok_or(TimeError::OutOfRange)?;
+            nanos += NANOS_PER_SECOND;
+        }
+        if seconds < rfc_3339::MIN_SECONDS || seconds > rfc_3339::MAX_SECONDS {
+            return Err(TimeError::OutOfRange);
+        }
+        let mut t = Timestamp::new();
+        t.set_seconds(seconds);
+        t.set_nanos(nanos as i32);
+        Ok(t)
+    }
+
+    /// Time is between years 1 and 9999 and nanos are in range `0..1e9`
+    pub fn is_valid(&self) -> bool {
+        let seconds = self.get_seconds();
+        seconds >= rfc_3339::MIN_SECONDS && seconds <= rfc_3339::MAX_SECONDS &&
+            self.get_nanos() >= 0 && (self.get_nanos() as i64) < NANOS_PER_SECOND
+    }
+
+    pub fn from_system_time(t: SystemTime) -> Result<Timestamp, TimeError> {
+        let (before_epoch, d) = match t.duration_since(UNIX_EPOCH) {
+            Ok(d) => (false, d),
+            Err(e) => (true, e.duration()),
+        };
+        if d.as_secs() > i64::max_value() as u64 {
+            return Err(TimeError::OutOfRange);
+        }
+        let (seconds, nanos) = (d.as_secs() as i64, d.subsec_nanos() as i64);
+        if before_epoch {
+            Timestamp::normalized(-seconds, -nanos)
+        } else {
+            Timestamp::normalized(seconds, nanos)
+        }
+    }
+
+    pub fn to_system_time(&self) -> Result<SystemTime, TimeError> {
+        if !self.is_valid() {
+            return Err(TimeError::OutOfRange);
+        }
+        let nanos = time::Duration::new(0, self.get_nanos() as u32);
+        let t = if self.get_seconds() >= 0 {
+            UNIX_EPOCH.checked_add(time::Duration::new(self.get_seconds() as u64, 0))
+        } else {
+            UNIX_EPOCH.checked_sub(time::Duration::new(-self.get_seconds() as u64, 0))
+        };
+        t.and_then(|t| t.checked_add(nanos)).ok_or(TimeError::OutOfRange)
+    }
+
+    pub fn checked_add(&self, d: &Duration) -> Option<Timestamp> {
+        let seconds = self.get_seconds().checked_add(d.get_seconds())?;
+        Timestamp::normalized(seconds, self.get_nanos() as i64 + d.get_nanos() as i64).ok()
+    }
+
+    pub fn checked_sub(&self, d: &Duration) -> Option<Timestamp> {
+        self.checked_add(&d.checked_neg()?)
+    }
+
+    /// Compare seconds and nanos, e. g. `timestamps.sort_by(Timestamp::cmp_value)`.
+    ///
+    /// Unlike `==` unknown fields are ignored.
+    pub fn cmp_value(&self, other: &Timestamp) -> Ordering {
+        (self.get_seconds(), self.get_nanos()).cmp(&(other.get_seconds(), other.get_nanos()))
+    }
+
+    /// Duration from `earlier` to `self`, negative if `earlier` is later
+    pub fn checked_duration_since(&self, earlier: &Timestamp) -> Option<Duration> {
+        let seconds = self.get_seconds().checked_sub(earlier.get_seconds())?;
+        Duration::normalized(seconds, self.get_nanos() as i64 - earlier.get_nanos() as i64).ok()
+    }
+}
+
+/// RFC 3339 format like `1972-01-01T10:00:20.021Z`
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_valid() {
+            f.write_str(&rfc_3339::format(self.get_seconds(), self.get_nanos() as u32))
+        } else {
+            write!(f, "{}s {}ns", self.get_seconds(), self.get_nanos())
+        }
+    }
+}
+
+/// Parse RFC 3339 format
+impl FromStr for Timestamp {
+    type Err = TimeError;
+
+    fn from_str(s: &str) -> Result<Timestamp, TimeError> {
+        let (seconds, nanos) = rfc_3339::parse(s).map_err(|_| TimeError::Parse(s.to_owned()))?;
+        let mut t = Timestamp::new();
+        t.set_seconds(seconds);
+        t.set_nanos(nanos as i32);
+        Ok(t)
+    }
+}
+
+/// Seconds and nanos are compared. Values with equal seconds and nanos
+/// but different unknown fields are not `==`, so they are not comparable.
+impl PartialOrd for Timestamp {
+    fn partial_cmp(&self, other: &Timestamp) -> Option<Ordering> {
+        match self.cmp_value(other) {
+            Ordering::Equal if self != other => None,
+            ordering => Some(ordering),
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use core::Message;
+
+    fn timestamp(seconds: i64, nanos: i32) -> Timestamp {
+        let mut t = Timestamp::new();
+        t.set_seconds(seconds);
+        t.set_nanos(nanos);
+        t
+    }
+
+    fn duration(seconds: i64, nanos: i32) -> Duration {
+        let mut d = Duration::new();
+        d.set_seconds(seconds);
+        d.set_nanos(nanos);
+        d
+    }
+
+    #[test]
+    fn test_system_time() {
+        let t = UNIX_EPOCH + time::Duration::new(10, 20);
+        assert_eq!(timestamp(10, 20), Timestamp::from_system_time(t).unwrap());
+        assert_eq!(t, timestamp(10, 20).to_system_time().unwrap());
+
+        let t = UNIX_EPOCH - time::Duration::new(10, 20);
+        assert_eq!(timestamp(-11, 999_999_980), Timestamp::from_system_time(t).unwrap());
+        assert_eq!(t, timestamp(-11, 999_999_980).to_system_time().unwrap());
+
+        assert_eq!(Err(TimeError::OutOfRange), timestamp(0, -1).to_system_time());
+        assert!(Timestamp::now().is_valid());
+    }
+
+    #[test]
+    fn test_string() {
+        let t = timestamp(63108020, 21000000);
+        assert_eq!("1972-01-01T10:00:20.021Z", t.to_string());
+        assert_eq!(t, "1972-01-01T10:00:20.021Z".parse().unwrap());
+        assert_eq!(
+            Err(TimeError::Parse("1972-01-01".to_owned())),
+            "1972-01-01".parse::<Timestamp>()
+        );
+    }
+
+    #[test]
+    fn test_arithmetic_and_ord() {
+        let t = timestamp(10, 900_000_000);
+        assert_eq!(Some(timestamp(12, 100_000_000)), t.checked_add(&duration(1, 200_000_000)));
+        assert_eq!(Some(timestamp(9, 700_000_000)), t.checked_sub(&duration(1, 200_000_000)));
+        assert_eq!(
+            Some(duration(-1, -200_000_000)),
+            timestamp(9, 700_000_000).checked_duration_since(&t)
+        );
+        assert_eq!(None, timestamp(rfc_3339::MAX_SECONDS, 0).checked_add(&duration(1, 0)));
+        assert_eq!(None, Timestamp::normalized(rfc_3339::MIN_SECONDS, -1).ok());
+        assert_eq!(Ordering::Less, timestamp(-1, 999_999_999).cmp_value(&timestamp(0, 0)));
+        assert!(timestamp(-1, 999_999_999) < timestamp(0, 0));
+    }
+
+    #[test]
+    fn test_overflow() {
+        assert_eq!(Err(TimeError::OutOfRange), Timestamp::normalized(i64::min_value(), -1));
+        let min = timestamp(i64::min_value(), 0);
+        assert_eq!(None, min.checked_add(&duration(0, -1)));
+        assert_eq!(None, min.checked_sub(&duration(0, 1)));
+    }
+
+    #[test]
+    fn test_cmp_unknown_fields() {
+        let a = timestamp(1, 2);
+        let mut b = a.clone();
+        b.mut_unknown_fields().add_varint(100, 1);
+        assert!(a != b);
+        assert_eq!(Ordering::Equal, a.cmp_value(&b));
+        assert_eq!(None, a.partial_cmp(&b));
+        assert!(a < timestamp(1, 3));
+        assert!(!(a < b) && !(a > b));
+    }
+}
@@ -0,0 +1,105 @@
+use well_known_types::BoolValue;
+use well_known_types::BytesValue;
+use well_known_types::DoubleValue;
+use well_known_types::FloatValue;
+use well_known_types::Int32Value;
+use well_known_types::Int64Value;
+use well_known_types::StringValue;
+use well_known_types::UInt32Value;
+use well_known_types::UInt64Value;
+
+
+/// Conversions between wrapper message and wrapped value
+macro_rules! wrapper_from {
+    ($wrapper:ident, $value:ty) => {
+        impl From<$value> for $wrapper {
+            fn from(value: $value) -> $wrapper {
+                let mut r = $wrapper::new();
+                r.value = value;
+                r
+            }
+        }
+
+        impl From<$wrapper> for $value {
+            fn from(wrapper: $wrapper) -> $value {
+                wrapper.value
+            }
+        }
+    };
+}
+
+wrapper_from!(DoubleValue, f64);
+wrapper_from!(FloatValue, f32);
+wrapper_from!(Int64Value, i64);
+wrapper_from!(UInt64Value, u64);
+wrapper_from!(Int32Value, i32);
+wrapper_from!(UInt32Value, u32);
+wrapper_from!(BoolValue, bool);
+wrapper_from!(StringValue, String);
+wrapper_from!(BytesValue, Vec<u8>);
+
+impl<'a> From<&'a str> for StringValue {
+    fn from(value: &'a str) -> StringValue {
+        StringValue::from(value.to_owned())
+    }
+}
+
+impl<'a> From<&'a [u8]> for BytesValue {
+    fn from(value: &'a [u8]) -> BytesValue {
+        BytesValue::from(value.to_owned())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use core::Message;
+    use core::parse_from_bytes;
+    use rt;
+    use stream::CodedInputStream;
+    use stream::CodedOutputStream;
+    use types::ProtobufTypeInt32;
+    use types::ProtobufTypeString;
+
+    #[test]
+    fn test_from() {
+        assert_eq!(10, Int32Value::from(10).get_value());
+        assert_eq!("ab", String::from(StringValue::from("ab")));
+        assert_eq!(vec![1u8], BytesValue::from(&[1u8][..]).get_value());
+        assert_eq!(true, bool::from(BoolValue::from(true)));
+    }
+
+    fn write_wrapper<V : ::types::ProtobufType>(value: &V::Value) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        {
+            let mut os = CodedOutputStream::vec(&mut bytes);
+            rt::write_wrapper_with_cached_size::<V>(3, value, &mut os).unwrap();
+            os.flush().unwrap();
+        }
+        assert_eq!(rt::wrapper_size::<V>(3, value) as usize, bytes.len());
+        bytes
+    }
+
+    #[test]
+    fn test_wire_format_same_as_message() {
+        // field 3 of type `Int32Value`
+        let mut expected = vec![0x1a];
+        let wrapper = Int32Value::from(150);
+        expected.push(wrapper.compute_size() as u8);
+        expected.extend(wrapper.write_to_bytes().unwrap());
+        assert_eq!(expected, write_wrapper::<ProtobufTypeInt32>(&150));
+        assert_eq!(vec![0x1a, 0], write_wrapper::<ProtobufTypeString>(&String::new()));
+
+        let mut target = Some(1);
+        {
+            let mut is = CodedInputStream::from_bytes(&expected[1..]);
+            let wire_type = ::wire_format::WireTypeLengthDelimited;
+            rt::read_singular_wrapper_into::<ProtobufTypeInt32>(wire_type, &mut is, &mut target)
+                .unwrap();
+        }
+        assert_eq!(Some(150), target);
+        let parsed: Int32Value = parse_from_bytes(&expected[2..]).unwrap();
+        assert_eq!(wrapper, parsed);
+    }
+}